@@ -0,0 +1,40 @@
+//! Compares building each JSON log message as a `serde_json::Value`, as Goose used to, with
+//! serializing it directly into a reused buffer, as the log writers now do.
+//!
+//! Run with `cargo bench --bench log_format`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use goose::metrics::GooseRequestMetric;
+use serde_json::json;
+
+// A typical request log message.
+const REQUEST: &str = r#"{"elapsed":23102,"raw":{"method":"Get","url":"http://local.dev/node/1439","headers":["accept: text/html"],"body":""},"name":"(Anon) node page","final_url":"http://local.dev/node/1439","redirected":false,"response_time":7,"status_code":200,"success":true,"update":false,"user":12,"error":"","coordinated_omission_elapsed":0,"user_cadence":0,"slow":false,"affinity_violation":false,"dimensions":{}}"#;
+
+fn log_format(c: &mut Criterion) {
+    let request: GooseRequestMetric = serde_json::from_str(REQUEST).unwrap();
+
+    let mut group = c.benchmark_group("json request log");
+    group.bench_function("json! to_string", |b| {
+        b.iter(|| {
+            let mut message = json!(black_box(&request));
+            message["run_id"] = json!("nightly");
+            message.to_string()
+        })
+    });
+    let mut buffer = Vec::with_capacity(64 * 1024);
+    group.bench_function("to_writer into buffer", |b| {
+        b.iter(|| {
+            buffer.clear();
+            serde_json::to_writer(&mut buffer, black_box(&request)).unwrap();
+            buffer.pop();
+            buffer.extend_from_slice(b",\"run_id\":");
+            serde_json::to_writer(&mut buffer, "nightly").unwrap();
+            buffer.push(b'}');
+            buffer.len()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, log_format);
+criterion_main!(benches);
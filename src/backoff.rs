@@ -0,0 +1,83 @@
+//! Optionally back off after failed requests.
+//!
+//! Users running a task set configured with
+//! [`GooseTaskSet::set_error_backoff`](../goose/struct.GooseTaskSet.html#method.set_error_backoff)
+//! count their consecutive failed requests. After each task they wait the minimum back
+//! off, doubled for each further consecutive failure, up to the maximum back off. Each
+//! successful request reduces the count by one, so users recover gradually instead of
+//! immediately returning to full speed.
+
+use std::time::Duration;
+
+use crate::goose::GooseUser;
+
+impl GooseUser {
+    // If backing off after errors, count consecutive failed requests, reducing the count
+    // by one after each successful request.
+    pub(crate) fn record_backoff(&mut self, success: bool) {
+        if self.error_backoff.is_none() {
+            return;
+        }
+        if success {
+            self.consecutive_errors = self.consecutive_errors.saturating_sub(1);
+        } else {
+            self.consecutive_errors += 1;
+        }
+    }
+
+    // How long to back off before running the next task.
+    pub(crate) fn backoff_wait(&self) -> Duration {
+        match self.error_backoff {
+            Some((min_backoff, max_backoff)) if self.consecutive_errors > 0 => {
+                let factor = 2_u32.saturating_pow(self.consecutive_errors as u32 - 1);
+                min_backoff
+                    .checked_mul(factor)
+                    .map_or(max_backoff, |backoff| backoff.min(max_backoff))
+            }
+            _ => Duration::from_millis(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::GooseConfiguration;
+    use gumdrop::Options;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    #[test]
+    fn backoff() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let mut user =
+            GooseUser::single("http://localhost:8080".parse().unwrap(), &configuration).unwrap();
+
+        // Without back off configured, failures are ignored.
+        user.record_backoff(false);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(0));
+
+        // The back off doubles with each consecutive failure, up to the maximum.
+        user.error_backoff = Some((Duration::from_millis(100), Duration::from_millis(500)));
+        user.record_backoff(false);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(100));
+        user.record_backoff(false);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(200));
+        user.record_backoff(false);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(400));
+        for _ in 0..100 {
+            user.record_backoff(false);
+        }
+        assert_eq!(user.backoff_wait(), Duration::from_millis(500));
+
+        // Each success reduces the back off gradually.
+        user.consecutive_errors = 3;
+        user.record_backoff(true);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(200));
+        user.record_backoff(true);
+        user.record_backoff(true);
+        assert_eq!(user.backoff_wait(), Duration::from_millis(0));
+        user.record_backoff(true);
+        assert_eq!(user.consecutive_errors, 0);
+    }
+}
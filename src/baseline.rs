@@ -0,0 +1,95 @@
+//! Optional measurement of the network round-trip time to each host before the load test.
+//!
+//! Response times include the network distance between the load generator and the target,
+//! so results from load generators in different locations can't be compared directly.
+//! With the `--rtt-samples VALUE` run-time option (or `GooseDefault::RttSamples`) Goose
+//! times VALUE TCP connects to each host before running any `test_start` task or launching
+//! any user. A TCP connect completes after one round trip, so the connect times are a
+//! baseline that can be subtracted from response times. Connects are made one at a time,
+//! and closed without sending a request.
+//!
+//! The baselines are included in
+//! [`GooseMetrics::network_baselines`](../metrics/struct.GooseMetrics.html#structfield.network_baselines),
+//! and displayed with the metrics and in the html report.
+
+use std::time::{Duration, Instant};
+use url::Url;
+
+use crate::metrics::GooseNetworkBaseline;
+
+/// How long to wait for each connect before counting it as failed.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Measures the network round-trip time to each host, in the order given. Hosts on a Unix
+/// domain socket aren't measured.
+pub(crate) async fn measure_hosts(hosts: &[String], samples: usize) -> Vec<GooseNetworkBaseline> {
+    let mut baselines = Vec::new();
+    for host in hosts {
+        let url = match Url::parse(host) {
+            Ok(url) if url.scheme() != "unix" => url,
+            _ => continue,
+        };
+        info!("measuring network round-trip time to {}...", host);
+        baselines.push(measure(host, &url, samples).await);
+    }
+    baselines
+}
+
+/// Times each TCP connect to the first address the host resolves to.
+async fn measure(host: &str, url: &Url, samples: usize) -> GooseNetworkBaseline {
+    let mut baseline = GooseNetworkBaseline {
+        host: host.to_string(),
+        address: None,
+        round_trip_times: Vec::with_capacity(samples),
+        failures: 0,
+    };
+
+    // Resolve the host once, so only connects are timed.
+    let address = match (url.host_str(), url.port_or_known_default()) {
+        (Some(hostname), Some(port)) => {
+            // IPv6 addresses are bracketed in URLs.
+            let hostname = hostname.trim_start_matches('[').trim_end_matches(']');
+            match tokio::net::lookup_host((hostname, port)).await {
+                Ok(mut addresses) => addresses.next(),
+                Err(e) => {
+                    warn!("failed to resolve {}: {}", host, e);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let address = match address {
+        Some(address) => address,
+        None => {
+            baseline.failures = samples;
+            return baseline;
+        }
+    };
+    baseline.address = Some(address.to_string());
+
+    for _ in 0..samples {
+        let started = Instant::now();
+        match tokio::time::timeout(CONNECT_TIMEOUT, tokio::net::TcpStream::connect(address)).await {
+            Ok(Ok(_stream)) => baseline
+                .round_trip_times
+                .push(started.elapsed().as_secs_f32() * 1_000.0),
+            Ok(Err(e)) => {
+                debug!("failed to connect to {}: {}", address, e);
+                baseline.failures += 1;
+            }
+            Err(_) => {
+                debug!("timed out connecting to {}", address);
+                baseline.failures += 1;
+            }
+        }
+    }
+    if baseline.failures > 0 {
+        warn!(
+            "{} of {} connects to {} failed",
+            baseline.failures, samples, host
+        );
+    }
+
+    baseline
+}
@@ -0,0 +1,86 @@
+//! Optional tracking of responses served by an intermediary cache.
+//!
+//! When tuning a CDN or caching proxy, the response times of the origin are hidden among
+//! the response times of cached responses. Enabling the `--cache-status` run-time option
+//! (or `GooseDefault::CacheStatus`) classifies each response as served by a cache or by
+//! the origin, counting each separately with their own response time percentiles.
+//!
+//! A response is served by a cache if a cache status header such as `X-Cache` or
+//! `CF-Cache-Status` reports a hit, or if there's no cache status header and the `Age`
+//! header is greater than zero. Intermediaries add a `Via` header to every response they
+//! forward, so a `Via` header without a cache status or age is counted as served by the
+//! origin.
+
+use reqwest::header::{HeaderMap, AGE};
+
+/// Response headers reporting whether an intermediary cache served the response.
+const CACHE_STATUS_HEADERS: [&str; 4] = [
+    "x-cache",
+    "x-cache-status",
+    "cf-cache-status",
+    "x-proxy-cache",
+];
+
+// Determine whether a response was served by an intermediary cache.
+pub(crate) fn served_by_cache(headers: &HeaderMap) -> bool {
+    let mut statuses = CACHE_STATUS_HEADERS
+        .iter()
+        .flat_map(|name| headers.get_all(*name).iter())
+        .filter_map(|value| value.to_str().ok())
+        .peekable();
+    // Chained caches can report a list of statuses, such as `MISS, HIT`.
+    if statuses.peek().is_some() {
+        return statuses.any(|status| status.to_uppercase().contains("HIT"));
+    }
+
+    let age = headers
+        .get(AGE)
+        .and_then(|age| age.to_str().ok())
+        .and_then(|age| age.trim().parse::<u64>().ok());
+    matches!(age, Some(age) if age > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Build response headers from name-value pairs.
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn cache_status() {
+        // Cache status headers report hits and misses.
+        assert!(served_by_cache(&headers(&[("x-cache", "HIT")])));
+        assert!(served_by_cache(&headers(&[(
+            "x-cache",
+            "Hit from cloudfront"
+        )])));
+        assert!(served_by_cache(&headers(&[("x-cache", "MISS, HIT")])));
+        assert!(served_by_cache(&headers(&[("cf-cache-status", "HIT")])));
+        assert!(!served_by_cache(&headers(&[("x-cache", "MISS")])));
+        assert!(!served_by_cache(&headers(&[(
+            "cf-cache-status",
+            "DYNAMIC"
+        )])));
+
+        // The cache status takes precedence over the age.
+        assert!(!served_by_cache(&headers(&[
+            ("x-cache-status", "EXPIRED"),
+            ("age", "10")
+        ])));
+
+        // Without a cache status, the age is used.
+        assert!(served_by_cache(&headers(&[("age", "10")])));
+        assert!(!served_by_cache(&headers(&[("age", "0")])));
+
+        // A response forwarded by an intermediary was served by the origin.
+        assert!(!served_by_cache(&headers(&[("via", "1.1 varnish")])));
+        assert!(!served_by_cache(&headers(&[])));
+    }
+}
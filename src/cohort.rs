@@ -0,0 +1,168 @@
+//! Optional cohorts users are assigned to.
+//!
+//! Features are often rolled out gradually, enabled for a growing percentage of users
+//! with a feature flag. Enabling the `--cohorts` run-time option (or
+//! [`GooseDefault::Cohorts`](../config/enum.GooseDefault.html#variant.Cohorts)) assigns
+//! a percentage of users to each named cohort, so the load of a rollout can be tested
+//! before it happens.
+//!
+//! The option is a comma-separated list of cohorts and the percent of users assigned
+//! each cohort, for example `beta:10` or `beta:10,canary:1`. Percents can add up to less
+//! than 100, the remaining users aren't assigned a cohort. Each user is randomly assigned
+//! a cohort when it starts, using its own random number generator, so assignments are
+//! reproducible with `--seed`.
+//!
+//! With the `--cohort-header NAME` run-time option (or
+//! [`GooseDefault::CohortHeader`](../config/enum.GooseDefault.html#variant.CohortHeader))
+//! each request made by a user in a cohort sets the NAME header to the name of its
+//! cohort, unless the request already sets the same header. The cohort is also recorded
+//! as the `cohort` dimension of each request, so response times are reported per cohort
+//! and the cohort is included in the request log. Load tests can also branch on
+//! [`GooseUser::cohort`].
+
+use rand::Rng;
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::Request;
+use std::str::FromStr;
+
+use crate::goose::GooseUser;
+
+/// Parse a comma-separated list of cohorts and the percent of users assigned each
+/// cohort, for example `beta:10,canary:1`.
+pub(crate) fn parse_cohorts(cohorts: &str) -> Result<Vec<(String, usize)>, String> {
+    let mut parsed: Vec<(String, usize)> = Vec::new();
+    for cohort in cohorts.split(',') {
+        let (name, percent) = match cohort.split_once(':') {
+            Some((name, percent)) => (name.trim(), percent.trim()),
+            None => {
+                return Err(format!(
+                    "cohort {} must be formatted as NAME:PERCENT",
+                    cohort
+                ))
+            }
+        };
+        // The name is sent as the value of the cohort header.
+        if name.is_empty() || HeaderValue::from_str(name).is_err() {
+            return Err(format!("cohort name {} is not valid", name));
+        }
+        if parsed.iter().any(|(parsed_name, _)| parsed_name == name) {
+            return Err(format!("cohort {} is defined more than once", name));
+        }
+        let percent = match percent.parse::<usize>() {
+            Ok(percent) if percent > 0 => percent,
+            _ => {
+                return Err(format!(
+                    "percent of cohort {} must be a number greater than 0",
+                    name
+                ))
+            }
+        };
+        parsed.push((name.to_string(), percent));
+    }
+    let total: usize = parsed.iter().map(|(_, percent)| percent).sum();
+    if total > 100 {
+        return Err(format!(
+            "cohort percents must add up to at most 100, not {}",
+            total
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Validate the name of the header users send their cohort in.
+pub(crate) fn validate_cohort_header(cohort_header: &str) -> Result<(), String> {
+    match HeaderName::from_str(cohort_header) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("{} is not a valid header name", cohort_header)),
+    }
+}
+
+impl GooseUser {
+    /// The name of the cohort this user was assigned to with `--cohorts`, if any.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(loadtest_index);
+    ///
+    /// /// Load the front page, and if in the beta cohort the new search page.
+    /// async fn loadtest_index(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     if user.cohort() == Some("beta") {
+    ///         let _goose = user.get("search/new").await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn cohort(&self) -> Option<&str> {
+        self.cohort.as_deref()
+    }
+
+    // Randomly assign this user to a cohort, if configured with `--cohorts`.
+    pub(crate) fn assign_cohort(&mut self) {
+        self.cohort = None;
+        if self.config.cohorts.is_empty() {
+            return;
+        }
+        // The configuration was validated at startup.
+        let cohorts = match parse_cohorts(&self.config.cohorts) {
+            Ok(cohorts) => cohorts,
+            Err(_) => return,
+        };
+        let mut selected = self.rng.gen_range(0..100);
+        for (cohort, percent) in cohorts {
+            if selected < percent {
+                self.cohort = Some(cohort);
+                return;
+            }
+            selected -= percent;
+        }
+    }
+
+    // Set the cohort header of this user, unless the request already sets it.
+    pub(crate) fn set_cohort_header(&self, request: &mut Request) {
+        if self.config.cohort_header.is_empty() {
+            return;
+        }
+        if let Some(cohort) = self.cohort.as_ref() {
+            // Both the header name and the cohort name were validated at startup.
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_str(&self.config.cohort_header),
+                HeaderValue::from_str(cohort),
+            ) {
+                request.headers_mut().entry(name).or_insert(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cohorts() {
+        assert_eq!(
+            parse_cohorts("beta:10, canary : 1").unwrap(),
+            vec![("beta".to_string(), 10), ("canary".to_string(), 1)]
+        );
+        assert_eq!(
+            parse_cohorts("everyone:100").unwrap(),
+            vec![("everyone".to_string(), 100)]
+        );
+
+        // Invalid cohorts.
+        assert!(parse_cohorts("beta").is_err());
+        assert!(parse_cohorts(":10").is_err());
+        assert!(parse_cohorts("beta:0").is_err());
+        assert!(parse_cohorts("beta:10,beta:20").is_err());
+        assert!(parse_cohorts("beta:60,canary:50").is_err());
+        assert!(parse_cohorts("be\u{7}ta:10").is_err());
+
+        assert!(validate_cohort_header("x-feature-flags").is_ok());
+        assert!(validate_cohort_header("x feature flags").is_err());
+    }
+}
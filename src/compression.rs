@@ -0,0 +1,109 @@
+//! Optional control of response compression, and response body size metrics.
+//!
+//! By default each [`GooseUser`] sends a `gzip` Accept-Encoding header and transparently
+//! decompresses gzip-compressed responses, unless disabled with `--no-gzip`. The
+//! `--accept-encoding VALUE` run-time option (or `GooseDefault::AcceptEncoding`) instead
+//! sends the provided Accept-Encoding header, for example `identity` to request
+//! uncompressed responses, unless a request sets its own.
+//!
+//! Compression trades bandwidth for CPU on both the server and the client. Enabling the
+//! `--body-sizes` run-time option (or `GooseDefault::BodySizes`) records the size of each
+//! response body as received and after decompression, so the effect of compression can be
+//! measured. Goose then reads each response body before returning the response, and
+//! decompresses gzip and deflate bodies itself. Bodies compressed with other encodings
+//! are returned as received, and counted at the same size before and after decompression.
+//! Response times don't include reading the body.
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use reqwest::header::{HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use std::io::Read;
+
+use crate::goose::GooseUser;
+use crate::metrics::GooseRequestMetric;
+
+// Start building a new response with the same status, version, url and headers.
+pub(crate) fn response_builder(response: &Response) -> http::response::Builder {
+    let mut builder = http::Response::builder()
+        .status(response.status())
+        .version(response.version())
+        .url(response.url().clone());
+    if let Some(headers) = builder.headers_mut() {
+        *headers = response.headers().clone();
+    }
+    builder
+}
+
+// Decompress a response body, returning None if it isn't validly compressed.
+fn decompress<R: Read>(mut decoder: R) -> Option<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    decoder.read_to_end(&mut decompressed).ok()?;
+    Some(decompressed)
+}
+
+impl GooseUser {
+    // Set the configured Accept-Encoding header, unless the request already sets it. When
+    // tracking body sizes Goose decompresses responses itself, so it requests gzip unless
+    // disabled with `--no-gzip`.
+    pub(crate) fn set_accept_encoding(&self, request: &mut Request) {
+        let accept_encoding = if !self.config.accept_encoding.is_empty() {
+            self.config.accept_encoding.as_str()
+        } else if self.config.body_sizes && !self.config.no_gzip {
+            "gzip"
+        } else {
+            return;
+        };
+        // The header value was validated at startup.
+        if let Ok(value) = HeaderValue::from_str(accept_encoding) {
+            request
+                .headers_mut()
+                .entry(ACCEPT_ENCODING)
+                .or_insert(value);
+        }
+    }
+
+    // If tracking body sizes, read the response body and record its size as received and
+    // after decompression. A new response is returned with the same status and headers,
+    // and the decompressed body.
+    pub(crate) async fn measure_body(
+        &self,
+        response: Result<Response, reqwest::Error>,
+        request_metric: &mut GooseRequestMetric,
+    ) -> Result<Response, reqwest::Error> {
+        if !self.config.body_sizes {
+            return response;
+        }
+        let response = response?;
+
+        let encoding = response
+            .headers()
+            .get(CONTENT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_lowercase());
+        let mut builder = response_builder(&response);
+        let body = response.bytes().await?;
+
+        let decompressed = match encoding.as_deref() {
+            Some("gzip") | Some("x-gzip") => decompress(GzDecoder::new(&body[..])),
+            Some("deflate") => decompress(ZlibDecoder::new(&body[..])),
+            _ => None,
+        };
+        request_metric.body_size = Some(body.len());
+        request_metric.decompressed_body_size =
+            Some(decompressed.as_ref().map_or(body.len(), |d| d.len()));
+
+        // The builder was created from a valid response, so this can't fail.
+        match decompressed {
+            Some(decompressed) => {
+                // As when decompressed by reqwest, remove the headers describing the
+                // compressed body.
+                if let Some(headers) = builder.headers_mut() {
+                    headers.remove(CONTENT_ENCODING);
+                    headers.remove(CONTENT_LENGTH);
+                }
+                Ok(Response::from(builder.body(decompressed).unwrap()))
+            }
+            None => Ok(Response::from(builder.body(body).unwrap())),
+        }
+    }
+}
@@ -6,18 +6,33 @@
 //! Goose can be configured programmatically with [`GooseDefaultType::set_default`].
 
 use gumdrop::Options;
+use num_format::Locale;
 use serde::{Deserialize, Serialize};
 use simplelog::*;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::logger::GooseLogFormat;
-use crate::metrics::GooseCoordinatedOmissionMitigation;
+use crate::cohort;
+use crate::device;
+use crate::identity;
+use crate::logger::{self, GooseLogFailure, GooseLogFormat};
+use crate::metrics::{GooseCoordinatedOmissionMitigation, GooseOutput};
+use crate::rotate;
+use crate::ssh;
+use crate::test_plan::GooseTestPlan;
 use crate::util;
 use crate::{GooseAttack, GooseError};
 
 /// Constant defining Goose's default port when running a Gaggle.
 const DEFAULT_PORT: &str = "5115";
 
+/// Constant defining the recording proxy's default port.
+const DEFAULT_RECORD_PORT: &str = "5118";
+
+/// Constant defining how many seconds the Manager waits to hear from a Worker before it's
+/// lost.
+const DEFAULT_WORKER_TIMEOUT: usize = 10;
+
 /// Runtime options available when launching a Goose load test.
 ///
 /// Custom defaults can be programmatically set for most of these options using the
@@ -52,13 +67,25 @@ const DEFAULT_PORT: &str = "5115";
 ///
 /// Metrics:
 /// --running-metrics TIME     How often to optionally print running metrics
+/// --output MODE              Sets what is printed to stdout (verbose, summary, json, quiet)
 /// --no-reset-metrics         Doesn't reset metrics after all users have started
 /// --no-metrics               Doesn't track metrics
 /// --no-task-metrics          Doesn't track task metrics
+/// --no-histograms            Doesn't track response time histograms
+/// --no-time-series           Doesn't track metrics over time
 /// --no-error-summary         Doesn't display an error summary
+/// --number-locale LOCALE     Groups digits as in LOCALE (ie en, de, fr)
+/// --scale-units              Displays times in ms or s, bytes with SI prefixes
 /// --report-file NAME         Create an html-formatted report
+/// --report-interval VALUE    Writes interim reports every VALUE minutes
+/// --report-timestamp         Adds a timestamp to interim report file names
+/// --plan-file NAME           Writes allocation plan as JSON before starting
+/// --checkpoint-file NAME     Periodically saves metrics to a checkpoint file
+/// --checkpoint-interval TIME How often to save metrics checkpoints (default: 60)
+/// --resume NAME              Resumes from a metrics checkpoint file
+/// --baseline-file NAME       Compares metrics with a baseline metrics file
 /// -R, --request-log NAME     Sets request log file name
-/// --request-format FORMAT    Sets request log format (csv, json, raw, pretty)
+/// --request-format FORMAT    Sets request log format (csv, json, raw, pretty, combined)
 /// --request-body             Include the request body in the request log
 /// -T, --task-log NAME        Sets task log file name
 /// --task-format FORMAT       Sets task log format (csv, json, raw, pretty)
@@ -67,6 +94,20 @@ const DEFAULT_PORT: &str = "5115";
 /// -D, --debug-log NAME       Sets debug log file name
 /// --debug-format FORMAT      Sets debug log format (csv, json, raw, pretty)
 /// --no-debug-body            Do not include the response body in the debug log
+/// --redact-headers NAMES     Also masks these headers in logs (ie x-api-key)
+/// --no-redact-headers        Does not mask any headers in logs
+/// --continue-without-logs    Runs without any log that can't be opened
+/// --log-failure POLICY       Sets what to do when writing a log fails (drop, stderr, abort)
+/// --log-rotate-size SIZE     Rotates file logs larger than SIZE (ie 500M)
+/// --log-rotate-interval TIME Rotates file logs every TIME (ie 1h)
+/// --log-rotate-keep VALUE    Keeps VALUE rotated files of each log (default: 5)
+/// --log-rotate-compress      Gzip-compresses rotated log files
+/// --affinity-header NAME     Verifies session affinity with response header NAME
+/// --header-dimensions NAMES  Tracks metrics by values of response headers NAMES
+/// --label KEY=VALUE          Adds KEY=VALUE metadata to reports and metrics
+/// --run-id ID                Marks requests, logs and reports with run ID
+/// --body-sizes               Tracks compressed and decompressed body sizes
+/// --cache-status             Tracks responses served by a cache separately
 /// --status-codes             Tracks additional status code metrics
 ///
 /// Advanced:
@@ -78,19 +119,43 @@ const DEFAULT_PORT: &str = "5115";
 /// --websocket-port PORT      Sets WebSocket Controller TCP port (default: 5117)
 /// --no-autostart             Doesn't automatically start load test
 /// --no-gzip                  Doesn't set the gzip Accept-Encoding header
+/// --accept-encoding VALUE    Sets Accept-Encoding header (ie gzip, identity)
+/// --no-tls-resumption        Doesn't resume TLS sessions (requires rustls-tls)
+/// --resolve-hosts            Resolves hosts once before users start
+/// --warm-up VALUE            Opens VALUE connections per user before starting
+/// --validation-workers VALUE Sets background body validators (default: CPU cores)
 /// --co-mitigation STRATEGY   Sets coordinated omission mitigation strategy
+/// --co-cadence VALUE         Sets expected milliseconds between user requests
 /// --throttle-requests VALUE  Sets maximum requests per second
+/// --adaptive-throttle        Adapts throttle to server rate-limit headers
+/// --replay-speed SPEED       Replays recorded timings SPEED times as fast (ie 2, 0.5)
 /// --sticky-follow            Follows base_url redirect with subsequent requests
+/// --variant-host HOST        Sends variant traffic to HOST
+/// --variant-header HEADER    Adds "NAME: VALUE" HEADER to variant traffic
+/// --variant-percent PERCENT  Sets percent of traffic sent to variant (default: 50)
+/// --cache-buster PATTERN     Adds unique "NAME={}" query or "NAME: {}" header
+/// --cache-buster-names NAMES Only busts caches of requests named NAMES
+/// --device-profiles MIX      Assigns device profiles to users by percent
+/// --cohorts MIX              Assigns users to named cohorts by percent
+/// --cohort-header NAME       Sends the cohort of each user in header NAME
+/// --client-certs PATH        Assigns users client certificates from PATH
+/// --record NAME              Records a browser session into load test source NAME
+/// --record-port PORT         Sets recording proxy TCP port (default: 5118)
 ///
 /// Gaggle:
 /// --manager                  Enables distributed load test Manager mode
 /// --expect-workers VALUE     Sets number of Workers to expect
 /// --no-hash-check            Tells Manager to ignore load test checksum
+/// --worker-timeout VALUE     Sets seconds until silent Worker is lost (default: 10)
+/// --redistribute-users       Moves users of lost Workers to other Workers
 /// --manager-bind-host HOST   Sets host Manager listens on (default: 0.0.0.0)
 /// --manager-bind-port PORT   Sets port Manager listens on (default: 5115)
+/// --manager-tls-cert PATH    Enables TLS on Manager with certificate and key in PATH
 /// --worker                   Enables distributed load test Worker mode
 /// --manager-host HOST        Sets host Worker connects to (default: 127.0.0.1)
 /// --manager-port PORT        Sets port Worker connects to (default: 5115)
+/// --manager-tls-ca PATH      Connects to Manager with TLS, verified with CA in PATH
+/// --gaggle-key KEY           Sets pre-shared key Workers authenticate with
 /// ```
 ///
 /// Goose leverages [`gumdrop`](https://docs.rs/gumdrop/) to derive the above help from
@@ -123,6 +188,21 @@ pub struct GooseConfiguration {
     /// Stops load test after (30s, 20m, 3h, 1h30m, etc)
     #[options(short = "t", meta = "TIME")]
     pub run_time: String,
+    /// Sets per-second user stop rate (default: all at once)
+    #[options(no_short, meta = "RATE")]
+    pub stop_rate: Option<String>,
+    /// Ramps users through steps (ie 10,30s;50,2m;0,30s)
+    #[options(no_short, meta = "PLAN")]
+    pub test_plan: String,
+    /// Launches users evenly over (30s, 20m, 3h, 1h30m, etc)
+    #[options(no_short, meta = "TIME")]
+    pub ramp_up: String,
+    /// Holds all users after ramping up for (30s, 20m, 3h, 1h30m, etc)
+    #[options(no_short, meta = "TIME")]
+    pub hold: String,
+    /// Stops users evenly after holding over (30s, 20m, 3h, 1h30m, etc)
+    #[options(no_short, meta = "TIME")]
+    pub ramp_down: String,
     /// Enables Goose log file and sets name
     #[options(short = "G", meta = "NAME")]
     pub goose_log: String,
@@ -141,6 +221,9 @@ pub struct GooseConfiguration {
     /// How often to optionally print running metrics
     #[options(no_short, meta = "TIME")]
     pub running_metrics: Option<usize>,
+    /// Sets what is printed to stdout (verbose, summary, json, quiet)
+    #[options(no_short, meta = "MODE")]
+    pub output: Option<GooseOutput>,
     /// Doesn't reset metrics after all users have started
     #[options(no_short)]
     pub no_reset_metrics: bool,
@@ -150,16 +233,49 @@ pub struct GooseConfiguration {
     /// Doesn't track task metrics
     #[options(no_short)]
     pub no_task_metrics: bool,
+    /// Doesn't track response time histograms
+    #[options(no_short)]
+    pub no_histograms: bool,
+    /// Doesn't track metrics over time
+    #[options(no_short)]
+    pub no_time_series: bool,
     /// Doesn't display an error summary
     #[options(no_short)]
     pub no_error_summary: bool,
+    /// Groups digits as in LOCALE (ie en, de, fr)
+    #[options(no_short, meta = "LOCALE")]
+    pub number_locale: String,
+    /// Displays times in ms or s, bytes with SI prefixes
+    #[options(no_short)]
+    pub scale_units: bool,
     /// Create an html-formatted report
     #[options(no_short, meta = "NAME")]
     pub report_file: String,
+    /// Writes interim reports every VALUE minutes
+    #[options(no_short, meta = "VALUE")]
+    pub report_interval: Option<usize>,
+    /// Adds a timestamp to interim report file names
+    #[options(no_short)]
+    pub report_timestamp: bool,
+    /// Writes allocation plan as JSON before starting
+    #[options(no_short, meta = "NAME")]
+    pub plan_file: String,
+    /// Periodically saves metrics to a checkpoint file
+    #[options(no_short, meta = "NAME")]
+    pub checkpoint_file: String,
+    /// How often to save metrics checkpoints (default: 60)
+    #[options(no_short, meta = "TIME")]
+    pub checkpoint_interval: Option<usize>,
+    /// Resumes from a metrics checkpoint file
+    #[options(no_short, meta = "NAME")]
+    pub resume: String,
+    /// Compares metrics with a baseline metrics file
+    #[options(no_short, meta = "NAME")]
+    pub baseline_file: String,
     /// Sets request log file name
     #[options(short = "R", meta = "NAME")]
     pub request_log: String,
-    /// Sets request log format (csv, json, raw, pretty)
+    /// Sets request log format (csv, json, raw, pretty, combined)
     #[options(no_short, meta = "FORMAT")]
     pub request_format: Option<GooseLogFormat>,
     /// Include the request body in the request log
@@ -186,6 +302,72 @@ pub struct GooseConfiguration {
     /// Do not include the response body in the debug log
     #[options(no_short)]
     pub no_debug_body: bool,
+    /// Truncates response bodies in the debug log to BYTES
+    #[options(no_short, meta = "BYTES")]
+    pub debug_log_body_limit: Option<usize>,
+    /// Only logs response bodies with these status codes (ie 500,503)
+    #[options(no_short, meta = "CODES")]
+    pub debug_log_body_status: String,
+    /// Also masks these headers in logs (ie x-api-key)
+    #[options(no_short, meta = "NAMES")]
+    pub redact_headers: String,
+    /// Does not mask any headers in logs
+    #[options(no_short)]
+    pub no_redact_headers: bool,
+    /// Runs without any log that can't be opened
+    #[options(no_short)]
+    pub continue_without_logs: bool,
+    /// Sets what to do when writing a log fails (drop, stderr, abort)
+    #[options(no_short, meta = "POLICY")]
+    pub log_failure: Option<GooseLogFailure>,
+    /// Rotates file logs larger than SIZE (ie 500M)
+    #[options(no_short, meta = "SIZE")]
+    pub log_rotate_size: String,
+    /// Rotates file logs every TIME (ie 1h)
+    #[options(no_short, meta = "TIME")]
+    pub log_rotate_interval: String,
+    /// Keeps VALUE rotated files of each log (default: 5)
+    #[options(no_short, meta = "VALUE")]
+    pub log_rotate_keep: Option<usize>,
+    /// Gzip-compresses rotated log files
+    #[options(no_short)]
+    pub log_rotate_compress: bool,
+    /// Verifies session affinity with response header NAME
+    #[options(no_short, meta = "NAME")]
+    pub affinity_header: String,
+    /// Tracks metrics by values of response headers NAMES
+    #[options(no_short, meta = "NAMES")]
+    pub header_dimensions: String,
+    /// Adds KEY=VALUE metadata to reports and metrics
+    #[options(no_short, meta = "KEY=VALUE")]
+    pub label: Vec<String>,
+    /// Marks requests, logs and reports with run ID
+    #[options(no_short, meta = "ID")]
+    pub run_id: String,
+    /// Tracks compressed and decompressed body sizes
+    #[options(no_short)]
+    pub body_sizes: bool,
+    /// Tracks responses served by a cache separately
+    #[options(no_short)]
+    pub cache_status: bool,
+    /// Serves live metrics for Prometheus on PORT
+    #[options(no_short, meta = "PORT")]
+    pub prometheus_port: u16,
+    /// Sends request metrics to StatsD at HOST:PORT
+    #[options(no_short, meta = "HOST:PORT")]
+    pub statsd_host: String,
+    /// Streams requests to InfluxDB write URL
+    #[options(no_short, meta = "URL")]
+    pub influxdb_url: String,
+    /// Authenticates to InfluxDB v2 with TOKEN
+    #[options(no_short, meta = "TOKEN")]
+    pub influxdb_token: String,
+    /// Writes up to VALUE requests to InfluxDB at once (default: 5000)
+    #[options(no_short, meta = "VALUE")]
+    pub influxdb_batch_size: Option<usize>,
+    /// How often to write requests to InfluxDB (default: 1)
+    #[options(no_short, meta = "TIME")]
+    pub influxdb_flush_interval: Option<usize>,
     /// Tracks additional status code metrics
     // Add a blank line and then an Advanced: header after this option
     #[options(no_short, help = "Tracks additional status code metrics\n\nAdvanced:")]
@@ -215,18 +397,92 @@ pub struct GooseConfiguration {
     /// Doesn't set the gzip Accept-Encoding header
     #[options(no_short)]
     pub no_gzip: bool,
+    /// Sets Accept-Encoding header (ie gzip, identity)
+    #[options(no_short, meta = "VALUE")]
+    pub accept_encoding: String,
+    /// Doesn't resume TLS sessions (requires rustls-tls)
+    #[options(no_short)]
+    pub no_tls_resumption: bool,
+    /// Resolves hosts once before users start
+    #[options(no_short)]
+    pub resolve_hosts: bool,
+    /// Opens VALUE connections per user before starting
+    #[options(no_short, meta = "VALUE")]
+    pub warm_up: usize,
+    /// Measures network RTT with VALUE connects before starting
+    #[options(no_short, meta = "VALUE")]
+    pub rtt_samples: usize,
+    /// Sets background body validators (default: CPU cores)
+    #[options(no_short, meta = "VALUE")]
+    pub validation_workers: usize,
+    /// Tunnels requests through SSH jump HOST ([USER@]HOST[:PORT])
+    #[options(no_short, meta = "HOST")]
+    pub ssh_jump_host: String,
+    /// Authenticates to SSH jump host with private key FILE
+    #[options(no_short, meta = "FILE")]
+    pub ssh_identity: String,
     /// Sets coordinated omission mitigation strategy
     #[options(no_short, meta = "STRATEGY")]
     pub co_mitigation: Option<GooseCoordinatedOmissionMitigation>,
+    /// Sets expected milliseconds between user requests
+    #[options(no_short, meta = "VALUE")]
+    pub co_cadence: usize,
     /// Sets maximum requests per second
     #[options(no_short, meta = "VALUE")]
     pub throttle_requests: usize,
+    /// Adapts throttle to server rate-limit headers
+    #[options(no_short)]
+    pub adaptive_throttle: bool,
+    /// Seeds each user's random number generator
+    #[options(no_short, meta = "VALUE")]
+    pub seed: Option<usize>,
+    /// Replays recorded timings SPEED times as fast (ie 2, 0.5)
+    #[options(no_short, meta = "SPEED")]
+    pub replay_speed: Option<String>,
     /// Follows base_url redirect with subsequent requests
+    #[options(no_short)]
+    pub sticky_follow: bool,
+    /// Sends variant traffic to HOST
+    #[options(no_short, meta = "HOST")]
+    pub variant_host: String,
+    /// Adds "NAME: VALUE" HEADER to variant traffic
+    #[options(no_short, meta = "HEADER")]
+    pub variant_header: String,
+    /// Sets percent of traffic sent to variant (default: 50)
+    #[options(no_short, meta = "PERCENT")]
+    pub variant_percent: Option<usize>,
+    /// Adds unique "NAME={}" query or "NAME: {}" header
+    #[options(no_short, meta = "PATTERN")]
+    pub cache_buster: String,
+    /// Only busts caches of requests named NAMES
+    #[options(no_short, meta = "NAMES")]
+    pub cache_buster_names: String,
+    /// Assigns device profiles to users by percent
+    #[options(no_short, meta = "MIX")]
+    pub device_profiles: String,
+    /// Assigns users to named cohorts by percent
+    #[options(no_short, meta = "MIX")]
+    pub cohorts: String,
+    /// Sends the cohort of each user in header NAME
+    #[options(no_short, meta = "NAME")]
+    pub cohort_header: String,
+    /// Assigns users client certificates from PATH
     #[options(
         no_short,
-        help = "Follows base_url redirect with subsequent requests\n\nGaggle:"
+        meta = "PATH",
+        help = "Assigns users client certificates from PATH"
     )]
-    pub sticky_follow: bool,
+    pub client_certs: String,
+    /// Records a browser session into load test source NAME
+    #[options(no_short, meta = "NAME")]
+    pub record: String,
+    /// Sets recording proxy TCP port (default: 5118)
+    #[options(
+        no_short,
+        meta = "PORT",
+        help = "Sets recording proxy TCP port (default: 5118)\n\nGaggle:"
+    )]
+    pub record_port: u16,
 
     /// Enables distributed load test Manager mode
     #[options(no_short)]
@@ -237,12 +493,21 @@ pub struct GooseConfiguration {
     /// Tells Manager to ignore load test checksum
     #[options(no_short)]
     pub no_hash_check: bool,
+    /// Sets seconds until silent Worker is lost (default: 10)
+    #[options(no_short, meta = "VALUE")]
+    pub worker_timeout: Option<usize>,
+    /// Moves users of lost Workers to other Workers
+    #[options(no_short)]
+    pub redistribute_users: bool,
     /// Sets host Manager listens on (default: 0.0.0.0)
     #[options(no_short, meta = "HOST")]
     pub manager_bind_host: String,
     /// Sets port Manager listens on (default: 5115)
     #[options(no_short, meta = "PORT")]
     pub manager_bind_port: u16,
+    /// Enables TLS on Manager with certificate and key in PATH
+    #[options(no_short, meta = "PATH")]
+    pub manager_tls_cert: String,
     /// Enables distributed load test Worker mode
     #[options(no_short)]
     pub worker: bool,
@@ -252,6 +517,12 @@ pub struct GooseConfiguration {
     /// Sets port Worker connects to (default: 5115)
     #[options(no_short, meta = "PORT")]
     pub manager_port: u16,
+    /// Connects to Manager with TLS, verified with CA in PATH
+    #[options(no_short, meta = "PATH")]
+    pub manager_tls_ca: String,
+    /// Sets pre-shared key Workers authenticate with
+    #[options(no_short, meta = "KEY")]
+    pub gaggle_key: String,
 }
 
 /// Optional default values for Goose run-time options.
@@ -269,6 +540,16 @@ pub(crate) struct GooseDefaults {
     pub startup_time: Option<usize>,
     /// An optional default number of seconds for the test to run.
     pub run_time: Option<usize>,
+    /// An optional default number of users to stop per second.
+    pub stop_rate: Option<String>,
+    /// An optional default test plan ramping users through steps.
+    pub test_plan: Option<String>,
+    /// An optional default number of seconds to ramp up users.
+    pub ramp_up: Option<usize>,
+    /// An optional default number of seconds to hold all users.
+    pub hold: Option<usize>,
+    /// An optional default number of seconds to ramp down users.
+    pub ramp_down: Option<usize>,
     /// An optional default log level.
     pub log_level: Option<u8>,
     /// An optional default for the goose log file name.
@@ -277,16 +558,36 @@ pub(crate) struct GooseDefaults {
     pub verbose: Option<u8>,
     /// An optional default for printing running metrics.
     pub running_metrics: Option<usize>,
+    /// An optional default for what is printed to stdout.
+    pub output: Option<GooseOutput>,
     /// An optional default for not resetting metrics after all users started.
     pub no_reset_metrics: Option<bool>,
     /// An optional default for not tracking metrics.
     pub no_metrics: Option<bool>,
     /// An optional default for not tracking task metrics.
     pub no_task_metrics: Option<bool>,
+    /// An optional default for not tracking response time histograms.
+    pub no_histograms: Option<bool>,
+    /// An optional default for not tracking metrics over time.
+    pub no_time_series: Option<bool>,
     /// An optional default for not displaying an error summary.
     pub no_error_summary: Option<bool>,
+    /// An optional default for the locale digits are grouped as.
+    pub number_locale: Option<String>,
+    /// An optional default for scaling the units of times and byte counts.
+    pub scale_units: Option<bool>,
     /// An optional default for the html-formatted report file name.
     pub report_file: Option<String>,
+    /// An optional default for how often to write interim reports, in minutes.
+    pub report_interval: Option<usize>,
+    /// An optional default for adding a timestamp to interim report file names.
+    pub report_timestamp: Option<bool>,
+    /// An optional default for the allocation plan file name.
+    pub plan_file: Option<String>,
+    /// An optional default for the metrics checkpoint file name.
+    pub checkpoint_file: Option<String>,
+    /// An optional default for how often to write metrics checkpoints, in seconds.
+    pub checkpoint_interval: Option<usize>,
     /// An optional default for the requests log file name.
     pub request_log: Option<String>,
     /// An optional default for the requests log file format.
@@ -307,6 +608,26 @@ pub(crate) struct GooseDefaults {
     pub debug_format: Option<GooseLogFormat>,
     /// An optional default for not logging response body in debug log.
     pub no_debug_body: Option<bool>,
+    /// An optional default for truncating response bodies in the debug log.
+    pub debug_log_body_limit: Option<usize>,
+    /// An optional default for which status codes response bodies are debug logged for.
+    pub debug_log_body_status: Option<String>,
+    /// An optional default for which headers are masked in logs.
+    pub redact_headers: Option<String>,
+    /// An optional default for not masking any headers in logs.
+    pub no_redact_headers: Option<bool>,
+    /// An optional default to run without any log that can't be opened.
+    pub continue_without_logs: Option<bool>,
+    /// An optional default for what to do when writing a log fails.
+    pub log_failure: Option<GooseLogFailure>,
+    /// An optional default for rotating file logs larger than a size.
+    pub log_rotate_size: Option<String>,
+    /// An optional default for how often to rotate file logs, in seconds.
+    pub log_rotate_interval: Option<usize>,
+    /// An optional default for how many rotated files of each log to keep.
+    pub log_rotate_keep: Option<usize>,
+    /// An optional default for gzip-compressing rotated log files.
+    pub log_rotate_compress: Option<bool>,
     /// An optional default for not enabling telnet Controller thread.
     pub no_telnet: Option<bool>,
     /// An optional default for not enabling WebSocket Controller thread.
@@ -315,20 +636,94 @@ pub(crate) struct GooseDefaults {
     pub no_autostart: Option<bool>,
     /// An optional default for not setting the gzip Accept-Encoding header.
     pub no_gzip: Option<bool>,
+    /// An optional default Accept-Encoding header sent with each request.
+    pub accept_encoding: Option<String>,
+    /// An optional default for not resuming TLS sessions.
+    pub no_tls_resumption: Option<bool>,
+    /// An optional default for resolving hosts once before users start.
+    pub resolve_hosts: Option<bool>,
+    /// An optional default number of connections each user opens before starting.
+    pub warm_up: Option<usize>,
+    /// An optional default number of connects timed to measure the network round-trip time.
+    pub rtt_samples: Option<usize>,
+    /// An optional default number of background validators of response bodies.
+    pub validation_workers: Option<usize>,
+    /// An optional default SSH jump host requests are tunneled through.
+    pub ssh_jump_host: Option<String>,
+    /// An optional default private key file authenticating to the SSH jump host.
+    pub ssh_identity: Option<String>,
     /// An optional default for coordinated omission mitigation.
     pub co_mitigation: Option<GooseCoordinatedOmissionMitigation>,
+    /// An optional default for the expected milliseconds between each user's requests.
+    pub co_cadence: Option<usize>,
     /// An optional default to track additional status code metrics.
     pub status_codes: Option<bool>,
+    /// An optional default for the response header identifying the backend, to verify session affinity.
+    pub affinity_header: Option<String>,
+    /// An optional default for the response headers whose values are tracked as metrics dimensions.
+    pub header_dimensions: Option<String>,
+    /// An optional default comma-separated list of KEY=VALUE labels describing the load test.
+    pub labels: Option<String>,
+    /// An optional default for the run ID marking requests, logs and reports.
+    pub run_id: Option<String>,
+    /// An optional default for tracking compressed and decompressed response body sizes.
+    pub body_sizes: Option<bool>,
+    /// An optional default for tracking responses served by an intermediary cache separately.
+    pub cache_status: Option<bool>,
+    /// An optional default for the port live metrics are served on for Prometheus.
+    pub prometheus_port: Option<u16>,
+    /// An optional default for the StatsD host and port request metrics are sent to.
+    pub statsd_host: Option<String>,
+    /// An optional default for the InfluxDB write URL requests are streamed to.
+    pub influxdb_url: Option<String>,
+    /// An optional default for the token authenticating to InfluxDB v2.
+    pub influxdb_token: Option<String>,
+    /// An optional default for how many requests to write to InfluxDB at once.
+    pub influxdb_batch_size: Option<usize>,
+    /// An optional default for how often to write requests to InfluxDB, in seconds.
+    pub influxdb_flush_interval: Option<usize>,
     /// An optional default maximum requests per second.
     pub throttle_requests: Option<usize>,
+    /// An optional default for adapting the throttle to server rate-limit headers.
+    pub adaptive_throttle: Option<bool>,
+    /// An optional default seed for the random number generator of each GooseUser.
+    pub seed: Option<usize>,
+    /// An optional default for how many times as fast recorded timings are replayed.
+    pub replay_speed: Option<String>,
     /// An optional default to follows base_url redirect with subsequent request.
     pub sticky_follow: Option<bool>,
+    /// An optional default for the variant host.
+    pub variant_host: Option<String>,
+    /// An optional default for the variant header.
+    pub variant_header: Option<String>,
+    /// An optional default for the percent of traffic sent to the variant.
+    pub variant_percent: Option<usize>,
+    /// An optional default for the query parameter or header added to bust caches.
+    pub cache_buster: Option<String>,
+    /// An optional default for the names of the requests caches are busted for.
+    pub cache_buster_names: Option<String>,
+    /// An optional default for the device profiles assigned to users.
+    pub device_profiles: Option<String>,
+    /// An optional default for the cohorts users are assigned to.
+    pub cohorts: Option<String>,
+    /// An optional default for the header users send their cohort in.
+    pub cohort_header: Option<String>,
+    /// An optional default for the client certificates assigned to users.
+    pub client_certs: Option<String>,
+    /// An optional default for recording a browser session into a load test.
+    pub record: Option<String>,
+    /// An optional default for the port the recording proxy listens on.
+    pub record_port: Option<u16>,
     /// An optional default to enable Manager mode.
     pub manager: Option<bool>,
     /// An optional default for number of Workers to expect.
     pub expect_workers: Option<usize>,
     /// An optional default for Manager to ignore load test checksum.
     pub no_hash_check: Option<bool>,
+    /// An optional default for how many seconds until a silent Worker is lost.
+    pub worker_timeout: Option<usize>,
+    /// An optional default for moving users of lost Workers to other Workers.
+    pub redistribute_users: Option<bool>,
     /// An optional default for host telnet Controller listens on.
     pub telnet_host: Option<String>,
     /// An optional default for port telnet Controller listens on.
@@ -341,12 +736,18 @@ pub(crate) struct GooseDefaults {
     pub manager_bind_host: Option<String>,
     /// An optional default for port Manager listens on.
     pub manager_bind_port: Option<u16>,
+    /// An optional default for the certificate and key Manager enables TLS with.
+    pub manager_tls_cert: Option<String>,
     /// An optional default to enable Worker mode.
     pub worker: Option<bool>,
     /// An optional default for host Worker connects to.
     pub manager_host: Option<String>,
     /// An optional default for port Worker connects to.
     pub manager_port: Option<u16>,
+    /// An optional default for the CA Worker verifies the Manager with over TLS.
+    pub manager_tls_ca: Option<String>,
+    /// An optional default for the pre-shared key Workers authenticate with.
+    pub gaggle_key: Option<String>,
 }
 
 /// Defines all [`GooseConfiguration`] options that can be programmatically configured with
@@ -365,6 +766,16 @@ pub enum GooseDefault {
     StartupTime,
     /// An optional default number of seconds for the test to run.
     RunTime,
+    /// An optional default number of users to stop per second.
+    StopRate,
+    /// An optional default test plan ramping users through steps.
+    TestPlan,
+    /// An optional default number of seconds to ramp up users.
+    RampUp,
+    /// An optional default number of seconds to hold all users.
+    Hold,
+    /// An optional default number of seconds to ramp down users.
+    RampDown,
     /// An optional default log level.
     LogLevel,
     /// An optional default for the log file name.
@@ -373,16 +784,36 @@ pub enum GooseDefault {
     Verbose,
     /// An optional default for printing running metrics.
     RunningMetrics,
+    /// An optional default for what is printed to stdout.
+    Output,
     /// An optional default for not resetting metrics after all users started.
     NoResetMetrics,
     /// An optional default for not tracking metrics.
     NoMetrics,
     /// An optional default for not tracking task metrics.
     NoTaskMetrics,
+    /// An optional default for not tracking response time histograms.
+    NoHistograms,
+    /// An optional default for not tracking metrics over time.
+    NoTimeSeries,
     /// An optional default for not displaying an error summary.
     NoErrorSummary,
+    /// An optional default for the locale digits are grouped as.
+    NumberLocale,
+    /// An optional default for scaling the units of times and byte counts.
+    ScaleUnits,
     /// An optional default for the report file name.
     ReportFile,
+    /// An optional default for how often to write interim reports, in minutes.
+    ReportInterval,
+    /// An optional default for adding a timestamp to interim report file names.
+    ReportTimestamp,
+    /// An optional default for the allocation plan file name.
+    PlanFile,
+    /// An optional default for the metrics checkpoint file name.
+    CheckpointFile,
+    /// An optional default for how often to write metrics checkpoints, in seconds.
+    CheckpointInterval,
     /// An optional default for the request log file name.
     RequestLog,
     /// An optional default for the request log file format.
@@ -403,28 +834,122 @@ pub enum GooseDefault {
     DebugFormat,
     /// An optional default for not logging the response body in the debug log.
     NoDebugBody,
+    /// An optional default for truncating response bodies in the debug log, in bytes.
+    DebugLogBodyLimit,
+    /// An optional default for which status codes response bodies are debug logged for.
+    DebugLogBodyStatus,
+    /// An optional default for which headers are masked in logs.
+    RedactHeaders,
+    /// An optional default for not masking any headers in logs.
+    NoRedactHeaders,
+    /// An optional default to run without any log that can't be opened.
+    ContinueWithoutLogs,
+    /// An optional default for what to do when writing a log fails.
+    LogFailure,
+    /// An optional default for rotating file logs larger than a size.
+    LogRotateSize,
+    /// An optional default for how often to rotate file logs, in seconds.
+    LogRotateInterval,
+    /// An optional default for how many rotated files of each log to keep.
+    LogRotateKeep,
+    /// An optional default for gzip-compressing rotated log files.
+    LogRotateCompress,
     /// An optional default for not enabling telnet Controller thread.
     NoTelnet,
     /// An optional default for not enabling WebSocket Controller thread.
     NoWebSocket,
     /// An optional default for coordinated omission mitigation.
     CoordinatedOmissionMitigation,
+    /// An optional default for the expected milliseconds between each user's requests.
+    CoCadence,
     /// An optional default for not automatically starting load test.
     NoAutoStart,
     /// An optional default for not setting the gzip Accept-Encoding header.
     NoGzip,
+    /// An optional default Accept-Encoding header sent with each request.
+    AcceptEncoding,
+    /// An optional default for not resuming TLS sessions.
+    NoTlsResumption,
+    /// An optional default for resolving hosts once before users start.
+    ResolveHosts,
+    /// An optional default number of connections each user opens before starting.
+    WarmUp,
+    /// An optional default number of connects timed to measure the network round-trip time.
+    RttSamples,
+    /// An optional default number of background validators of response bodies.
+    ValidationWorkers,
+    /// An optional default SSH jump host requests are tunneled through.
+    SshJumpHost,
+    /// An optional default private key file authenticating to the SSH jump host.
+    SshIdentity,
     /// An optional default to track additional status code metrics.
     StatusCodes,
+    /// An optional default for the response header identifying the backend, to verify session affinity.
+    AffinityHeader,
+    /// An optional default for the response headers whose values are tracked as metrics dimensions.
+    HeaderDimensions,
+    /// An optional default comma-separated list of KEY=VALUE labels describing the load test.
+    Labels,
+    /// An optional default for the run ID marking requests, logs and reports.
+    RunId,
+    /// An optional default for tracking compressed and decompressed response body sizes.
+    BodySizes,
+    /// An optional default for tracking responses served by an intermediary cache separately.
+    CacheStatus,
+    /// An optional default for the port live metrics are served on for Prometheus.
+    PrometheusPort,
+    /// An optional default for the StatsD host and port request metrics are sent to.
+    StatsdHost,
+    /// An optional default for the InfluxDB write URL requests are streamed to.
+    InfluxdbUrl,
+    /// An optional default for the token authenticating to InfluxDB v2.
+    InfluxdbToken,
+    /// An optional default for how many requests to write to InfluxDB at once.
+    InfluxdbBatchSize,
+    /// An optional default for how often to write requests to InfluxDB, in seconds.
+    InfluxdbFlushInterval,
     /// An optional default maximum requests per second.
     ThrottleRequests,
+    /// An optional default for adapting the throttle to server rate-limit headers.
+    AdaptiveThrottle,
+    /// An optional default seed for the random number generator of each GooseUser.
+    Seed,
+    /// An optional default for how many times as fast recorded timings are replayed.
+    ReplaySpeed,
     /// An optional default to follows base_url redirect with subsequent request.
     StickyFollow,
+    /// An optional default for the variant host.
+    VariantHost,
+    /// An optional default for the variant header.
+    VariantHeader,
+    /// An optional default for the percent of traffic sent to the variant.
+    VariantPercent,
+    /// An optional default for the query parameter or header added to bust caches.
+    CacheBuster,
+    /// An optional default for the names of the requests caches are busted for.
+    CacheBusterNames,
+    /// An optional default for the device profiles assigned to users.
+    DeviceProfiles,
+    /// An optional default for the cohorts users are assigned to.
+    Cohorts,
+    /// An optional default for the header users send their cohort in.
+    CohortHeader,
+    /// An optional default for the client certificates assigned to users.
+    ClientCerts,
+    /// An optional default for recording a browser session into a load test.
+    Record,
+    /// An optional default for the port the recording proxy listens on.
+    RecordPort,
     /// An optional default to enable Manager mode.
     Manager,
     /// An optional default for number of Workers to expect.
     ExpectWorkers,
     /// An optional default for Manager to ignore load test checksum.
     NoHashCheck,
+    /// An optional default for how many seconds until a silent Worker is lost.
+    WorkerTimeout,
+    /// An optional default for moving users of lost Workers to other Workers.
+    RedistributeUsers,
     /// An optional default for host telnet Controller listens on.
     TelnetHost,
     /// An optional default for port telnet Controller listens on.
@@ -437,12 +962,18 @@ pub enum GooseDefault {
     ManagerBindHost,
     /// An optional default for port Manager listens on.
     ManagerBindPort,
+    /// An optional default for the certificate and key Manager enables TLS with.
+    ManagerTlsCert,
     /// An optional default to enable Worker mode.
     Worker,
     /// An optional default for host Worker connects to.
     ManagerHost,
     /// An optional default for port Worker connects to.
     ManagerPort,
+    /// An optional default for the CA Worker verifies the Manager with over TLS.
+    ManagerTlsCa,
+    /// An optional default for the pre-shared key Workers authenticate with.
+    GaggleKey,
 }
 
 /// Most run-time options can be programmatically configured with custom defaults.
@@ -482,44 +1013,107 @@ pub enum GooseDefault {
 ///  - [`GooseDefault::TaskLog`]
 ///  - [`GooseDefault::ErrorLog`]
 ///  - [`GooseDefault::DebugLog`]
+///  - [`GooseDefault::DebugLogBodyStatus`]
+///  - [`GooseDefault::RedactHeaders`]
 ///  - [`GooseDefault::TelnetHost`]
 ///  - [`GooseDefault::WebSocketHost`]
 ///  - [`GooseDefault::ManagerBindHost`]
 ///  - [`GooseDefault::ManagerHost`]
+///  - [`GooseDefault::ManagerTlsCert`]
+///  - [`GooseDefault::ManagerTlsCa`]
+///  - [`GooseDefault::GaggleKey`]
+///  - [`GooseDefault::CheckpointFile`]
+///  - [`GooseDefault::LogRotateSize`]
+///  - [`GooseDefault::AffinityHeader`]
+///  - [`GooseDefault::HeaderDimensions`]
+///  - [`GooseDefault::Labels`]
+///  - [`GooseDefault::RunId`]
+///  - [`GooseDefault::StatsdHost`]
+///  - [`GooseDefault::InfluxdbUrl`]
+///  - [`GooseDefault::InfluxdbToken`]
+///  - [`GooseDefault::SshJumpHost`]
+///  - [`GooseDefault::SshIdentity`]
+///  - [`GooseDefault::VariantHost`]
+///  - [`GooseDefault::VariantHeader`]
+///  - [`GooseDefault::CacheBuster`]
+///  - [`GooseDefault::CacheBusterNames`]
+///  - [`GooseDefault::DeviceProfiles`]
+///  - [`GooseDefault::Cohorts`]
+///  - [`GooseDefault::CohortHeader`]
+///  - [`GooseDefault::ClientCerts`]
+///  - [`GooseDefault::Record`]
+///  - [`GooseDefault::PlanFile`]
+///  - [`GooseDefault::AcceptEncoding`]
+///  - [`GooseDefault::ReplaySpeed`]
+///  - [`GooseDefault::NumberLocale`]
+///  - [`GooseDefault::TestPlan`]
 ///
 /// The following run-time options can be configured with a custom default using a
 /// [`usize`] integer:
 ///  - [`GooseDefault::Users`]
 ///  - [`GooseDefault::HatchRate`]
+///  - [`GooseDefault::StopRate`]
 ///  - [`GooseDefault::StartupTime`]
 ///  - [`GooseDefault::RunTime`]
+///  - [`GooseDefault::RampUp`]
+///  - [`GooseDefault::Hold`]
+///  - [`GooseDefault::RampDown`]
 ///  - [`GooseDefault::RunningMetrics`]
 ///  - [`GooseDefault::LogLevel`]
 ///  - [`GooseDefault::Verbose`]
 ///  - [`GooseDefault::ThrottleRequests`]
+///  - [`GooseDefault::Seed`]
 ///  - [`GooseDefault::ExpectWorkers`]
+///  - [`GooseDefault::WorkerTimeout`]
 ///  - [`GooseDefault::TelnetPort`]
 ///  - [`GooseDefault::WebSocketPort`]
 ///  - [`GooseDefault::ManagerBindPort`]
 ///  - [`GooseDefault::ManagerPort`]
+///  - [`GooseDefault::ReportInterval`]
+///  - [`GooseDefault::CheckpointInterval`]
+///  - [`GooseDefault::LogRotateInterval`]
+///  - [`GooseDefault::LogRotateKeep`]
+///  - [`GooseDefault::DebugLogBodyLimit`]
+///  - [`GooseDefault::VariantPercent`]
+///  - [`GooseDefault::WarmUp`]
+///  - [`GooseDefault::RttSamples`]
+///  - [`GooseDefault::CoCadence`]
+///  - [`GooseDefault::ValidationWorkers`]
+///  - [`GooseDefault::RecordPort`]
+///  - [`GooseDefault::PrometheusPort`]
+///  - [`GooseDefault::InfluxdbBatchSize`]
+///  - [`GooseDefault::InfluxdbFlushInterval`]
 ///
 /// The following run-time flags can be configured with a custom default using a
 /// [`bool`] (and otherwise default to [`false`]).
 ///  - [`GooseDefault::NoResetMetrics`]
 ///  - [`GooseDefault::NoMetrics`]
 ///  - [`GooseDefault::NoTaskMetrics`]
+///  - [`GooseDefault::NoHistograms`]
+///  - [`GooseDefault::NoTimeSeries`]
 ///  - [`GooseDefault::RequestBody`]
 ///  - [`GooseDefault::NoErrorSummary`]
 ///  - [`GooseDefault::NoDebugBody`]
+///  - [`GooseDefault::NoRedactHeaders`]
+///  - [`GooseDefault::ContinueWithoutLogs`]
+///  - [`GooseDefault::LogRotateCompress`]
 ///  - [`GooseDefault::NoTelnet`]
 ///  - [`GooseDefault::NoWebSocket`]
 ///  - [`GooseDefault::NoAutoStart`]
 ///  - [`GooseDefault::NoGzip`]
+///  - [`GooseDefault::NoTlsResumption`]
+///  - [`GooseDefault::ResolveHosts`]
 ///  - [`GooseDefault::StatusCodes`]
 ///  - [`GooseDefault::StickyFollow`]
 ///  - [`GooseDefault::Manager`]
 ///  - [`GooseDefault::NoHashCheck`]
+///  - [`GooseDefault::RedistributeUsers`]
 ///  - [`GooseDefault::Worker`]
+///  - [`GooseDefault::ReportTimestamp`]
+///  - [`GooseDefault::BodySizes`]
+///  - [`GooseDefault::CacheStatus`]
+///  - [`GooseDefault::ScaleUnits`]
+///  - [`GooseDefault::AdaptiveThrottle`]
 ///
 /// The following run-time flags can be configured with a custom default using a
 /// [`GooseLogFormat`].
@@ -528,6 +1122,14 @@ pub enum GooseDefault {
 ///  - [`GooseDefault::DebugFormat`]
 ///
 /// The following run-time flags can be configured with a custom default using a
+/// [`GooseLogFailure`].
+///  - [`GooseDefault::LogFailure`]
+///
+/// The following run-time flags can be configured with a custom default using a
+/// [`GooseOutput`].
+///  - [`GooseDefault::Output`]
+///
+/// The following run-time flags can be configured with a custom default using a
 /// [`GooseCoordinatedOmissionMitigation`].
 ///  - [`GooseDefault::CoordinatedOmissionMitigation`]
 pub trait GooseDefaultType<T> {
@@ -559,6 +1161,7 @@ impl GooseDefaultType<&str> for GooseAttack {
         match key {
             // Set valid defaults.
             GooseDefault::HatchRate => self.defaults.hatch_rate = Some(value.to_string()),
+            GooseDefault::StopRate => self.defaults.stop_rate = Some(value.to_string()),
             GooseDefault::Host => self.defaults.host = Some(value.to_string()),
             GooseDefault::GooseLog => self.defaults.goose_log = Some(value.to_string()),
             GooseDefault::ReportFile => self.defaults.report_file = Some(value.to_string()),
@@ -566,24 +1169,81 @@ impl GooseDefaultType<&str> for GooseAttack {
             GooseDefault::TaskLog => self.defaults.task_log = Some(value.to_string()),
             GooseDefault::ErrorLog => self.defaults.error_log = Some(value.to_string()),
             GooseDefault::DebugLog => self.defaults.debug_log = Some(value.to_string()),
+            GooseDefault::DebugLogBodyStatus => {
+                self.defaults.debug_log_body_status = Some(value.to_string())
+            }
+            GooseDefault::RedactHeaders => self.defaults.redact_headers = Some(value.to_string()),
             GooseDefault::TelnetHost => self.defaults.telnet_host = Some(value.to_string()),
             GooseDefault::WebSocketHost => self.defaults.websocket_host = Some(value.to_string()),
             GooseDefault::ManagerBindHost => {
                 self.defaults.manager_bind_host = Some(value.to_string())
             }
             GooseDefault::ManagerHost => self.defaults.manager_host = Some(value.to_string()),
+            GooseDefault::ManagerTlsCert => {
+                self.defaults.manager_tls_cert = Some(value.to_string())
+            }
+            GooseDefault::ManagerTlsCa => self.defaults.manager_tls_ca = Some(value.to_string()),
+            GooseDefault::GaggleKey => self.defaults.gaggle_key = Some(value.to_string()),
+            GooseDefault::CheckpointFile => self.defaults.checkpoint_file = Some(value.to_string()),
+            GooseDefault::LogRotateSize => self.defaults.log_rotate_size = Some(value.to_string()),
+            GooseDefault::AffinityHeader => self.defaults.affinity_header = Some(value.to_string()),
+            GooseDefault::HeaderDimensions => {
+                self.defaults.header_dimensions = Some(value.to_string())
+            }
+            GooseDefault::Labels => self.defaults.labels = Some(value.to_string()),
+            GooseDefault::RunId => self.defaults.run_id = Some(value.to_string()),
+            GooseDefault::StatsdHost => self.defaults.statsd_host = Some(value.to_string()),
+            GooseDefault::InfluxdbUrl => self.defaults.influxdb_url = Some(value.to_string()),
+            GooseDefault::InfluxdbToken => self.defaults.influxdb_token = Some(value.to_string()),
+            GooseDefault::SshJumpHost => self.defaults.ssh_jump_host = Some(value.to_string()),
+            GooseDefault::SshIdentity => self.defaults.ssh_identity = Some(value.to_string()),
+            GooseDefault::VariantHost => self.defaults.variant_host = Some(value.to_string()),
+            GooseDefault::VariantHeader => self.defaults.variant_header = Some(value.to_string()),
+            GooseDefault::CacheBuster => self.defaults.cache_buster = Some(value.to_string()),
+            GooseDefault::CacheBusterNames => {
+                self.defaults.cache_buster_names = Some(value.to_string())
+            }
+            GooseDefault::DeviceProfiles => self.defaults.device_profiles = Some(value.to_string()),
+            GooseDefault::Cohorts => self.defaults.cohorts = Some(value.to_string()),
+            GooseDefault::CohortHeader => self.defaults.cohort_header = Some(value.to_string()),
+            GooseDefault::ClientCerts => self.defaults.client_certs = Some(value.to_string()),
+            GooseDefault::Record => self.defaults.record = Some(value.to_string()),
+            GooseDefault::PlanFile => self.defaults.plan_file = Some(value.to_string()),
+            GooseDefault::AcceptEncoding => self.defaults.accept_encoding = Some(value.to_string()),
+            GooseDefault::ReplaySpeed => self.defaults.replay_speed = Some(value.to_string()),
+            GooseDefault::NumberLocale => self.defaults.number_locale = Some(value.to_string()),
+            GooseDefault::TestPlan => self.defaults.test_plan = Some(value.to_string()),
             // Otherwise display a helpful and explicit error.
             GooseDefault::Users
             | GooseDefault::StartupTime
             | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
             | GooseDefault::LogLevel
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
             | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort
             | GooseDefault::ManagerBindPort
-            | GooseDefault::ManagerPort => {
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: value.to_string(),
@@ -597,18 +1257,31 @@ impl GooseDefaultType<&str> for GooseAttack {
             | GooseDefault::NoResetMetrics
             | GooseDefault::NoMetrics
             | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
             | GooseDefault::RequestBody
             | GooseDefault::NoErrorSummary
             | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
             | GooseDefault::NoAutoStart
             | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
             | GooseDefault::StatusCodes
             | GooseDefault::StickyFollow
             | GooseDefault::Manager
             | GooseDefault::NoHashCheck
-            | GooseDefault::Worker => {
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: value.to_string(),
@@ -641,6 +1314,26 @@ impl GooseDefaultType<&str> for GooseAttack {
                     ),
                 });
             }
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseLogFailure value, received &str",
+                        key, value
+                    ),
+                });
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseOutput value, received &str",
+                        key, value
+                    ),
+                });
+            }
         }
         Ok(Box::new(self))
     }
@@ -652,28 +1345,80 @@ impl GooseDefaultType<usize> for GooseAttack {
             GooseDefault::Users => self.defaults.users = Some(value),
             GooseDefault::StartupTime => self.defaults.startup_time = Some(value),
             GooseDefault::RunTime => self.defaults.run_time = Some(value),
+            GooseDefault::RampUp => self.defaults.ramp_up = Some(value),
+            GooseDefault::Hold => self.defaults.hold = Some(value),
+            GooseDefault::RampDown => self.defaults.ramp_down = Some(value),
             GooseDefault::RunningMetrics => self.defaults.running_metrics = Some(value),
             GooseDefault::LogLevel => self.defaults.log_level = Some(value as u8),
             GooseDefault::Verbose => self.defaults.verbose = Some(value as u8),
             GooseDefault::ThrottleRequests => self.defaults.throttle_requests = Some(value),
+            GooseDefault::Seed => self.defaults.seed = Some(value),
             GooseDefault::ExpectWorkers => self.defaults.expect_workers = Some(value),
+            GooseDefault::WorkerTimeout => self.defaults.worker_timeout = Some(value),
             GooseDefault::TelnetPort => self.defaults.telnet_port = Some(value as u16),
             GooseDefault::WebSocketPort => self.defaults.websocket_port = Some(value as u16),
             GooseDefault::ManagerBindPort => self.defaults.manager_bind_port = Some(value as u16),
             GooseDefault::ManagerPort => self.defaults.manager_port = Some(value as u16),
+            GooseDefault::ReportInterval => self.defaults.report_interval = Some(value),
+            GooseDefault::CheckpointInterval => self.defaults.checkpoint_interval = Some(value),
+            GooseDefault::LogRotateInterval => self.defaults.log_rotate_interval = Some(value),
+            GooseDefault::LogRotateKeep => self.defaults.log_rotate_keep = Some(value),
+            GooseDefault::DebugLogBodyLimit => self.defaults.debug_log_body_limit = Some(value),
+            GooseDefault::VariantPercent => self.defaults.variant_percent = Some(value),
+            GooseDefault::WarmUp => self.defaults.warm_up = Some(value),
+            GooseDefault::RttSamples => self.defaults.rtt_samples = Some(value),
+            GooseDefault::CoCadence => self.defaults.co_cadence = Some(value),
+            GooseDefault::ValidationWorkers => self.defaults.validation_workers = Some(value),
+            GooseDefault::RecordPort => self.defaults.record_port = Some(value as u16),
+            GooseDefault::PrometheusPort => self.defaults.prometheus_port = Some(value as u16),
+            GooseDefault::InfluxdbBatchSize => self.defaults.influxdb_batch_size = Some(value),
+            GooseDefault::InfluxdbFlushInterval => {
+                self.defaults.influxdb_flush_interval = Some(value)
+            }
             // Otherwise display a helpful and explicit error.
             GooseDefault::Host
             | GooseDefault::HatchRate
+            | GooseDefault::StopRate
             | GooseDefault::GooseLog
             | GooseDefault::ReportFile
             | GooseDefault::RequestLog
             | GooseDefault::TaskLog
             | GooseDefault::ErrorLog
             | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
             | GooseDefault::TelnetHost
             | GooseDefault::WebSocketHost
             | GooseDefault::ManagerBindHost
-            | GooseDefault::ManagerHost => {
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -686,18 +1431,31 @@ impl GooseDefaultType<usize> for GooseAttack {
             GooseDefault::NoResetMetrics
             | GooseDefault::NoMetrics
             | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
             | GooseDefault::RequestBody
             | GooseDefault::NoErrorSummary
             | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
             | GooseDefault::NoAutoStart
             | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
             | GooseDefault::StatusCodes
             | GooseDefault::StickyFollow
             | GooseDefault::Manager
             | GooseDefault::NoHashCheck
-            | GooseDefault::Worker => {
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -730,6 +1488,26 @@ impl GooseDefaultType<usize> for GooseAttack {
                     ),
                 });
             }
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseLogFailure value, received usize",
+                        key, value
+                    ),
+                });
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseOutput value, received usize",
+                        key, value
+                    ),
+                });
+            }
         }
         Ok(Box::new(self))
     }
@@ -741,18 +1519,31 @@ impl GooseDefaultType<bool> for GooseAttack {
             GooseDefault::NoResetMetrics => self.defaults.no_reset_metrics = Some(value),
             GooseDefault::NoMetrics => self.defaults.no_metrics = Some(value),
             GooseDefault::NoTaskMetrics => self.defaults.no_task_metrics = Some(value),
+            GooseDefault::NoHistograms => self.defaults.no_histograms = Some(value),
+            GooseDefault::NoTimeSeries => self.defaults.no_time_series = Some(value),
             GooseDefault::RequestBody => self.defaults.request_body = Some(value),
             GooseDefault::NoErrorSummary => self.defaults.no_error_summary = Some(value),
             GooseDefault::NoDebugBody => self.defaults.no_debug_body = Some(value),
+            GooseDefault::NoRedactHeaders => self.defaults.no_redact_headers = Some(value),
+            GooseDefault::ContinueWithoutLogs => self.defaults.continue_without_logs = Some(value),
+            GooseDefault::LogRotateCompress => self.defaults.log_rotate_compress = Some(value),
             GooseDefault::NoTelnet => self.defaults.no_telnet = Some(value),
             GooseDefault::NoWebSocket => self.defaults.no_websocket = Some(value),
             GooseDefault::NoAutoStart => self.defaults.no_autostart = Some(value),
             GooseDefault::NoGzip => self.defaults.no_gzip = Some(value),
+            GooseDefault::NoTlsResumption => self.defaults.no_tls_resumption = Some(value),
+            GooseDefault::ResolveHosts => self.defaults.resolve_hosts = Some(value),
             GooseDefault::StatusCodes => self.defaults.status_codes = Some(value),
             GooseDefault::StickyFollow => self.defaults.sticky_follow = Some(value),
             GooseDefault::Manager => self.defaults.manager = Some(value),
             GooseDefault::NoHashCheck => self.defaults.no_hash_check = Some(value),
+            GooseDefault::RedistributeUsers => self.defaults.redistribute_users = Some(value),
             GooseDefault::Worker => self.defaults.worker = Some(value),
+            GooseDefault::ReportTimestamp => self.defaults.report_timestamp = Some(value),
+            GooseDefault::BodySizes => self.defaults.body_sizes = Some(value),
+            GooseDefault::CacheStatus => self.defaults.cache_status = Some(value),
+            GooseDefault::ScaleUnits => self.defaults.scale_units = Some(value),
+            GooseDefault::AdaptiveThrottle => self.defaults.adaptive_throttle = Some(value),
             // Otherwise display a helpful and explicit error.
             GooseDefault::Host
             | GooseDefault::GooseLog
@@ -762,10 +1553,40 @@ impl GooseDefaultType<bool> for GooseAttack {
             | GooseDefault::RunningMetrics
             | GooseDefault::ErrorLog
             | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
             | GooseDefault::TelnetHost
             | GooseDefault::WebSocketHost
             | GooseDefault::ManagerBindHost
-            | GooseDefault::ManagerHost => {
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -777,16 +1598,36 @@ impl GooseDefaultType<bool> for GooseAttack {
             }
             GooseDefault::Users
             | GooseDefault::HatchRate
+            | GooseDefault::StopRate
             | GooseDefault::StartupTime
             | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
             | GooseDefault::LogLevel
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
             | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort
             | GooseDefault::ManagerBindPort
-            | GooseDefault::ManagerPort => {
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{}", value),
@@ -819,6 +1660,26 @@ impl GooseDefaultType<bool> for GooseAttack {
                     ),
                 });
             }
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseLogFailure value, received bool",
+                        key, value
+                    ),
+                });
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: value.to_string(),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {}) expected GooseOutput value, received bool",
+                        key, value
+                    ),
+                });
+            }
         }
         Ok(Box::new(self))
     }
@@ -836,18 +1697,31 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             GooseDefault::NoResetMetrics
             | GooseDefault::NoMetrics
             | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
             | GooseDefault::RequestBody
             | GooseDefault::NoErrorSummary
             | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
             | GooseDefault::NoAutoStart
             | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
             | GooseDefault::StatusCodes
             | GooseDefault::StickyFollow
             | GooseDefault::Manager
             | GooseDefault::NoHashCheck
-            | GooseDefault::Worker => {
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -865,10 +1739,40 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             | GooseDefault::RunningMetrics
             | GooseDefault::ErrorLog
             | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
             | GooseDefault::TelnetHost
             | GooseDefault::WebSocketHost
             | GooseDefault::ManagerBindHost
-            | GooseDefault::ManagerHost => {
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -880,16 +1784,36 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
             }
             GooseDefault::Users
             | GooseDefault::HatchRate
+            | GooseDefault::StopRate
             | GooseDefault::StartupTime
             | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
             | GooseDefault::LogLevel
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
             | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort
             | GooseDefault::ManagerBindPort
-            | GooseDefault::ManagerPort => {
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -912,6 +1836,26 @@ impl GooseDefaultType<GooseCoordinatedOmissionMitigation> for GooseAttack {
                     ),
                 })
             }
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseLogFailure value, received GooseCoordinatedOmissionMitigation",
+                        key, value
+                    ),
+                })
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseOutput value, received GooseCoordinatedOmissionMitigation",
+                        key, value
+                    ),
+                })
+            }
         }
         Ok(Box::new(self))
     }
@@ -932,18 +1876,31 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             GooseDefault::NoResetMetrics
             | GooseDefault::NoMetrics
             | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
             | GooseDefault::RequestBody
             | GooseDefault::NoErrorSummary
             | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
             | GooseDefault::NoTelnet
             | GooseDefault::NoWebSocket
             | GooseDefault::NoAutoStart
             | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
             | GooseDefault::StatusCodes
             | GooseDefault::StickyFollow
             | GooseDefault::Manager
             | GooseDefault::NoHashCheck
-            | GooseDefault::Worker => {
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -961,10 +1918,40 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             | GooseDefault::RunningMetrics
             | GooseDefault::ErrorLog
             | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
             | GooseDefault::TelnetHost
             | GooseDefault::WebSocketHost
             | GooseDefault::ManagerBindHost
-            | GooseDefault::ManagerHost => {
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -976,16 +1963,36 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
             }
             GooseDefault::Users
             | GooseDefault::HatchRate
+            | GooseDefault::StopRate
             | GooseDefault::StartupTime
             | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
             | GooseDefault::LogLevel
             | GooseDefault::Verbose
             | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
             | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
             | GooseDefault::TelnetPort
             | GooseDefault::WebSocketPort
             | GooseDefault::ManagerBindPort
-            | GooseDefault::ManagerPort => {
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
                 return Err(GooseError::InvalidOption {
                     option: format!("GooseDefault::{:?}", key),
                     value: format!("{:?}", value),
@@ -1006,476 +2013,1725 @@ impl GooseDefaultType<GooseLogFormat> for GooseAttack {
                 })
 
             }
-        }
-        Ok(Box::new(self))
-    }
-}
-
-/// Used internally to configure [`GooseConfiguration`] values based on precedence rules.
-#[derive(Debug, Clone)]
-pub(crate) struct GooseValue<'a, T> {
-    /// The optional value to set.
-    pub(crate) value: Option<T>,
-    /// Filter using this value if true.
-    pub(crate) filter: bool,
-    /// An optional INFO level Goose log message.
-    pub(crate) message: &'a str,
-}
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseLogFailure value, received GooseLogFormat",
+                        key, value
+                    ),
+                })
 
-pub(crate) trait GooseConfigure<T> {
-    /// Set [`GooseValue`] with supported type.
-    fn get_value(&self, values: Vec<GooseValue<T>>) -> Option<T>;
-}
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseOutput value, received GooseLogFormat",
+                        key, value
+                    ),
+                })
 
-impl GooseConfigure<usize> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`usize`] value.
-    fn get_value(&self, values: Vec<GooseValue<usize>>) -> Option<usize> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
             }
         }
-        None
+        Ok(Box::new(self))
     }
 }
-impl GooseConfigure<u16> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`usize`] value.
-    fn get_value(&self, values: Vec<GooseValue<u16>>) -> Option<u16> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
+
+impl GooseDefaultType<GooseLogFailure> for GooseAttack {
+    /// Sets [`GooseDefault`] to a [`GooseLogFailure`] value.
+    fn set_default(
+        mut self,
+        key: GooseDefault,
+        value: GooseLogFailure,
+    ) -> Result<Box<Self>, GooseError> {
+        match key {
+            GooseDefault::LogFailure => self.defaults.log_failure = Some(value),
+            // Otherwise display a helpful and explicit error.
+            GooseDefault::NoResetMetrics
+            | GooseDefault::NoMetrics
+            | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
+            | GooseDefault::RequestBody
+            | GooseDefault::NoErrorSummary
+            | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
+            | GooseDefault::NoTelnet
+            | GooseDefault::NoWebSocket
+            | GooseDefault::NoAutoStart
+            | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
+            | GooseDefault::StatusCodes
+            | GooseDefault::StickyFollow
+            | GooseDefault::Manager
+            | GooseDefault::NoHashCheck
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected bool value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
             }
-        }
-        None
-    }
-}
-impl GooseConfigure<u8> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`u8`] value.
-    fn get_value(&self, values: Vec<GooseValue<u8>>) -> Option<u8> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
+            GooseDefault::Host
+            | GooseDefault::GooseLog
+            | GooseDefault::ReportFile
+            | GooseDefault::RequestLog
+            | GooseDefault::TaskLog
+            | GooseDefault::RunningMetrics
+            | GooseDefault::ErrorLog
+            | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
+            | GooseDefault::TelnetHost
+            | GooseDefault::WebSocketHost
+            | GooseDefault::ManagerBindHost
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected &str value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
             }
-        }
-        None
-    }
-}
-impl GooseConfigure<f32> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`f32`] value.
-    fn get_value(&self, values: Vec<GooseValue<f32>>) -> Option<f32> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
+            GooseDefault::Users
+            | GooseDefault::HatchRate
+            | GooseDefault::StopRate
+            | GooseDefault::StartupTime
+            | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
+            | GooseDefault::LogLevel
+            | GooseDefault::Verbose
+            | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
+            | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
+            | GooseDefault::TelnetPort
+            | GooseDefault::WebSocketPort
+            | GooseDefault::ManagerBindPort
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected usize value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
             }
-        }
-        None
-    }
-}
-impl GooseConfigure<String> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`String`] value.
-    fn get_value(&self, values: Vec<GooseValue<String>>) -> Option<String> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
+            GooseDefault::CoordinatedOmissionMitigation => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseCoordinatedOmissionMitigation value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
+
             }
-        }
-        None
-    }
-}
-impl GooseConfigure<bool> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`bool`] value.
-    fn get_value(&self, values: Vec<GooseValue<bool>>) -> Option<bool> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {}", value.message, v)
-                    }
-                    return Some(v);
-                }
+            GooseDefault::RequestFormat
+            | GooseDefault::DebugFormat
+            | GooseDefault::ErrorFormat
+            | GooseDefault::TaskFormat => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseLogFormat value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
+
+            }
+            GooseDefault::Output => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseOutput value, received GooseLogFailure",
+                        key, value
+                    ),
+                })
             }
         }
-        None
+        Ok(Box::new(self))
     }
 }
-impl GooseConfigure<GooseLogFormat> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`GooseLogFormat`] value.
-    fn get_value(&self, values: Vec<GooseValue<GooseLogFormat>>) -> Option<GooseLogFormat> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {:?}", value.message, v)
-                    }
-                    return Some(v);
-                }
+
+impl GooseDefaultType<GooseOutput> for GooseAttack {
+    /// Sets [`GooseDefault`] to a [`GooseOutput`] value.
+    fn set_default(
+        mut self,
+        key: GooseDefault,
+        value: GooseOutput,
+    ) -> Result<Box<Self>, GooseError> {
+        match key {
+            GooseDefault::Output => self.defaults.output = Some(value),
+            // Otherwise display a helpful and explicit error.
+            GooseDefault::NoResetMetrics
+            | GooseDefault::NoMetrics
+            | GooseDefault::NoTaskMetrics
+            | GooseDefault::NoHistograms
+            | GooseDefault::NoTimeSeries
+            | GooseDefault::RequestBody
+            | GooseDefault::NoErrorSummary
+            | GooseDefault::NoDebugBody
+            | GooseDefault::NoRedactHeaders
+            | GooseDefault::ContinueWithoutLogs
+            | GooseDefault::LogRotateCompress
+            | GooseDefault::NoTelnet
+            | GooseDefault::NoWebSocket
+            | GooseDefault::NoAutoStart
+            | GooseDefault::NoGzip
+            | GooseDefault::NoTlsResumption
+            | GooseDefault::ResolveHosts
+            | GooseDefault::StatusCodes
+            | GooseDefault::StickyFollow
+            | GooseDefault::Manager
+            | GooseDefault::NoHashCheck
+            | GooseDefault::RedistributeUsers
+            | GooseDefault::Worker
+            | GooseDefault::ReportTimestamp
+            | GooseDefault::BodySizes
+            | GooseDefault::CacheStatus
+            | GooseDefault::ScaleUnits
+            | GooseDefault::AdaptiveThrottle => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected bool value, received GooseOutput",
+                        key, value
+                    ),
+                })
+            }
+            GooseDefault::Host
+            | GooseDefault::GooseLog
+            | GooseDefault::ReportFile
+            | GooseDefault::RequestLog
+            | GooseDefault::TaskLog
+            | GooseDefault::RunningMetrics
+            | GooseDefault::ErrorLog
+            | GooseDefault::DebugLog
+            | GooseDefault::DebugLogBodyStatus
+            | GooseDefault::RedactHeaders
+            | GooseDefault::TelnetHost
+            | GooseDefault::WebSocketHost
+            | GooseDefault::ManagerBindHost
+            | GooseDefault::ManagerHost
+            | GooseDefault::ManagerTlsCert
+            | GooseDefault::ManagerTlsCa
+            | GooseDefault::GaggleKey
+            | GooseDefault::CheckpointFile
+            | GooseDefault::LogRotateSize
+            | GooseDefault::AffinityHeader
+            | GooseDefault::HeaderDimensions
+            | GooseDefault::Labels
+            | GooseDefault::RunId
+            | GooseDefault::StatsdHost
+            | GooseDefault::InfluxdbUrl
+            | GooseDefault::InfluxdbToken
+            | GooseDefault::SshJumpHost
+            | GooseDefault::SshIdentity
+            | GooseDefault::VariantHost
+            | GooseDefault::VariantHeader
+            | GooseDefault::CacheBuster
+            | GooseDefault::CacheBusterNames
+            | GooseDefault::DeviceProfiles
+            | GooseDefault::Cohorts
+            | GooseDefault::CohortHeader
+            | GooseDefault::ClientCerts
+            | GooseDefault::Record
+            | GooseDefault::PlanFile
+            | GooseDefault::AcceptEncoding
+            | GooseDefault::ReplaySpeed
+            | GooseDefault::NumberLocale
+            | GooseDefault::TestPlan => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected &str value, received GooseOutput",
+                        key, value
+                    ),
+                })
+            }
+            GooseDefault::Users
+            | GooseDefault::HatchRate
+            | GooseDefault::StopRate
+            | GooseDefault::StartupTime
+            | GooseDefault::RunTime
+            | GooseDefault::RampUp
+            | GooseDefault::Hold
+            | GooseDefault::RampDown
+            | GooseDefault::LogLevel
+            | GooseDefault::Verbose
+            | GooseDefault::ThrottleRequests
+            | GooseDefault::Seed
+            | GooseDefault::ExpectWorkers
+            | GooseDefault::WorkerTimeout
+            | GooseDefault::TelnetPort
+            | GooseDefault::WebSocketPort
+            | GooseDefault::ManagerBindPort
+            | GooseDefault::ManagerPort
+            | GooseDefault::ReportInterval
+            | GooseDefault::CheckpointInterval
+            | GooseDefault::LogRotateInterval
+            | GooseDefault::LogRotateKeep
+            | GooseDefault::DebugLogBodyLimit
+            | GooseDefault::VariantPercent
+            | GooseDefault::WarmUp
+            | GooseDefault::RttSamples
+            | GooseDefault::CoCadence
+            | GooseDefault::ValidationWorkers
+            | GooseDefault::RecordPort
+            | GooseDefault::PrometheusPort
+            | GooseDefault::InfluxdbBatchSize
+            | GooseDefault::InfluxdbFlushInterval => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected usize value, received GooseOutput",
+                        key, value
+                    ),
+                })
+            }
+            GooseDefault::CoordinatedOmissionMitigation => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseCoordinatedOmissionMitigation value, received GooseOutput",
+                        key, value
+                    ),
+                })
+
+            }
+            GooseDefault::RequestFormat
+            | GooseDefault::DebugFormat
+            | GooseDefault::ErrorFormat
+            | GooseDefault::TaskFormat => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseLogFormat value, received GooseOutput",
+                        key, value
+                    ),
+                })
+
+            }
+            GooseDefault::LogFailure => {
+                return Err(GooseError::InvalidOption {
+                    option: format!("GooseDefault::{:?}", key),
+                    value: format!("{:?}", value),
+                    detail: format!(
+                        "set_default(GooseDefault::{:?}, {:?}) expected GooseLogFailure value, received GooseOutput",
+                        key, value
+                    ),
+                })
             }
         }
-        None
+        Ok(Box::new(self))
     }
 }
-impl GooseConfigure<GooseCoordinatedOmissionMitigation> for GooseConfiguration {
-    /// Use [`GooseValue`] to set a [`GooseCoordinatedOmissionMitigation`] value.
-    fn get_value(
-        &self,
-        values: Vec<GooseValue<GooseCoordinatedOmissionMitigation>>,
-    ) -> Option<GooseCoordinatedOmissionMitigation> {
-        for value in values {
-            if let Some(v) = value.value {
-                if value.filter {
-                    continue;
-                } else {
-                    if !value.message.is_empty() {
-                        info!("{} = {:?}", value.message, v)
-                    }
-                    return Some(v);
-                }
-            }
+
+/// Typed settings for a load test, an alternative to configuring custom defaults one at a
+/// time with [`GooseDefaultType::set_default()`].
+///
+/// Settings are built with [`GooseSettings::builder()`], where each run-time option is set
+/// with a value of its own type, such as a [`Duration`] for how long the load test runs,
+/// so mistakes are caught when the load test is compiled. They are then applied with
+/// [`GooseAttack::set_settings()`]. Like all defaults, they can still be overridden with
+/// run-time options.
+///
+/// Durations are rounded down to whole seconds.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), GooseError> {
+///     let settings = GooseSettings::builder()
+///         .host("http://local.dev/")
+///         .users(50)
+///         .hatch_rate(2.5)
+///         .run_time(Duration::from_secs(300))
+///         .no_reset_metrics(true)
+///         .build();
+///
+///     GooseAttack::initialize()?.set_settings(settings);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GooseSettings {
+    host: Option<String>,
+    users: Option<usize>,
+    hatch_rate: Option<f32>,
+    startup_time: Option<Duration>,
+    run_time: Option<Duration>,
+    stop_rate: Option<f32>,
+    test_plan: Option<String>,
+    log_level: Option<u8>,
+    verbose: Option<u8>,
+    goose_log: Option<String>,
+    running_metrics: Option<Duration>,
+    output: Option<GooseOutput>,
+    no_reset_metrics: Option<bool>,
+    no_metrics: Option<bool>,
+    status_codes: Option<bool>,
+    report_file: Option<String>,
+    request_log: Option<String>,
+    co_mitigation: Option<GooseCoordinatedOmissionMitigation>,
+    throttle_requests: Option<usize>,
+    seed: Option<usize>,
+}
+impl GooseSettings {
+    /// Start building [`GooseSettings`], with no settings configured.
+    pub fn builder() -> GooseSettingsBuilder {
+        GooseSettingsBuilder {
+            settings: GooseSettings::default(),
         }
-        None
     }
 }
 
-impl GooseConfiguration {
-    /// Implement precedence rules for all [`GooseConfiguration`] values.
-    pub(crate) fn configure(&mut self, defaults: &GooseDefaults) {
-        // Configure `verbose`.
-        self.verbose = self
+/// Builds [`GooseSettings`], see [`GooseSettings::builder()`].
+#[derive(Clone, Debug)]
+pub struct GooseSettingsBuilder {
+    settings: GooseSettings,
+}
+impl GooseSettingsBuilder {
+    /// The host to load test, the same as `--host`.
+    pub fn host(mut self, host: &str) -> Self {
+        self.settings.host = Some(host.to_string());
+        self
+    }
+
+    /// How many users to launch, the same as `--users`.
+    pub fn users(mut self, users: usize) -> Self {
+        self.settings.users = Some(users);
+        self
+    }
+
+    /// How many users to launch per second, the same as `--hatch-rate`.
+    pub fn hatch_rate(mut self, hatch_rate: f32) -> Self {
+        self.settings.hatch_rate = Some(hatch_rate);
+        self
+    }
+
+    /// How long to take launching all users, the same as `--startup-time`.
+    pub fn startup_time(mut self, startup_time: Duration) -> Self {
+        self.settings.startup_time = Some(startup_time);
+        self
+    }
+
+    /// How long the load test runs, the same as `--run-time`.
+    pub fn run_time(mut self, run_time: Duration) -> Self {
+        self.settings.run_time = Some(run_time);
+        self
+    }
+
+    /// How many users to stop per second, the same as `--stop-rate`.
+    pub fn stop_rate(mut self, stop_rate: f32) -> Self {
+        self.settings.stop_rate = Some(stop_rate);
+        self
+    }
+
+    /// Steps ramping users up and down, the same as `--test-plan`.
+    pub fn test_plan(mut self, test_plan: &str) -> Self {
+        self.settings.test_plan = Some(test_plan.to_string());
+        self
+    }
+
+    /// The Goose log level, the same as `-g`.
+    pub fn log_level(mut self, log_level: u8) -> Self {
+        self.settings.log_level = Some(log_level);
+        self
+    }
+
+    /// The Goose verbosity, the same as `-v`.
+    pub fn verbose(mut self, verbose: u8) -> Self {
+        self.settings.verbose = Some(verbose);
+        self
+    }
+
+    /// The name of the Goose log file, the same as `--goose-log`.
+    pub fn goose_log(mut self, goose_log: &str) -> Self {
+        self.settings.goose_log = Some(goose_log.to_string());
+        self
+    }
+
+    /// How often to print running metrics, the same as `--running-metrics`.
+    pub fn running_metrics(mut self, running_metrics: Duration) -> Self {
+        self.settings.running_metrics = Some(running_metrics);
+        self
+    }
+
+    /// What is printed to stdout, the same as `--output`.
+    pub fn output(mut self, output: GooseOutput) -> Self {
+        self.settings.output = Some(output);
+        self
+    }
+
+    /// Whether to keep metrics collected while users are launched, the same as
+    /// `--no-reset-metrics`.
+    pub fn no_reset_metrics(mut self, no_reset_metrics: bool) -> Self {
+        self.settings.no_reset_metrics = Some(no_reset_metrics);
+        self
+    }
+
+    /// Whether to not track metrics, the same as `--no-metrics`.
+    pub fn no_metrics(mut self, no_metrics: bool) -> Self {
+        self.settings.no_metrics = Some(no_metrics);
+        self
+    }
+
+    /// Whether to track status codes, the same as `--status-codes`.
+    pub fn status_codes(mut self, status_codes: bool) -> Self {
+        self.settings.status_codes = Some(status_codes);
+        self
+    }
+
+    /// The name of the html report file, the same as `--report-file`.
+    pub fn report_file(mut self, report_file: &str) -> Self {
+        self.settings.report_file = Some(report_file.to_string());
+        self
+    }
+
+    /// The name of the request log file, the same as `--request-log`.
+    pub fn request_log(mut self, request_log: &str) -> Self {
+        self.settings.request_log = Some(request_log.to_string());
+        self
+    }
+
+    /// The coordinated omission mitigation strategy, the same as `--co-mitigation`.
+    pub fn co_mitigation(mut self, co_mitigation: GooseCoordinatedOmissionMitigation) -> Self {
+        self.settings.co_mitigation = Some(co_mitigation);
+        self
+    }
+
+    /// The most requests per second, the same as `--throttle-requests`.
+    pub fn throttle_requests(mut self, throttle_requests: usize) -> Self {
+        self.settings.throttle_requests = Some(throttle_requests);
+        self
+    }
+
+    /// The seed of each user's random number generator, the same as `--seed`.
+    pub fn seed(mut self, seed: usize) -> Self {
+        self.settings.seed = Some(seed);
+        self
+    }
+
+    /// Finish building the [`GooseSettings`].
+    pub fn build(self) -> GooseSettings {
+        self.settings
+    }
+}
+
+/// Converts a [`Duration`] setting to the whole seconds of its run-time option, rounding
+/// up so a sub-second duration doesn't become `0`, which disables the option.
+fn whole_seconds(duration: Duration) -> usize {
+    (duration.as_secs() + u64::from(duration.subsec_nanos() > 0)) as usize
+}
+
+impl GooseAttack {
+    /// Apply typed [`GooseSettings`] as custom defaults, replacing any default previously
+    /// set for the same run-time option. Settings that weren't configured are left alone.
+    /// Durations are rounded up to whole seconds, the unit of their run-time options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     GooseAttack::initialize()?
+    ///         .set_settings(
+    ///             GooseSettings::builder()
+    ///                 .users(10)
+    ///                 .startup_time(Duration::from_secs(30))
+    ///                 .build(),
+    ///         )
+    ///         // Settings and defaults can be combined.
+    ///         .set_default(GooseDefault::RequestLog, "goose-request.log")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_settings(mut self, settings: GooseSettings) -> Self {
+        let defaults = &mut self.defaults;
+        if let Some(host) = settings.host {
+            defaults.host = Some(host);
+        }
+        if let Some(users) = settings.users {
+            defaults.users = Some(users);
+        }
+        if let Some(hatch_rate) = settings.hatch_rate {
+            defaults.hatch_rate = Some(hatch_rate.to_string());
+        }
+        if let Some(startup_time) = settings.startup_time {
+            defaults.startup_time = Some(whole_seconds(startup_time));
+        }
+        if let Some(run_time) = settings.run_time {
+            defaults.run_time = Some(whole_seconds(run_time));
+        }
+        if let Some(stop_rate) = settings.stop_rate {
+            defaults.stop_rate = Some(stop_rate.to_string());
+        }
+        if let Some(test_plan) = settings.test_plan {
+            defaults.test_plan = Some(test_plan);
+        }
+        if let Some(log_level) = settings.log_level {
+            defaults.log_level = Some(log_level);
+        }
+        if let Some(verbose) = settings.verbose {
+            defaults.verbose = Some(verbose);
+        }
+        if let Some(goose_log) = settings.goose_log {
+            defaults.goose_log = Some(goose_log);
+        }
+        if let Some(running_metrics) = settings.running_metrics {
+            defaults.running_metrics = Some(whole_seconds(running_metrics));
+        }
+        if let Some(output) = settings.output {
+            defaults.output = Some(output);
+        }
+        if let Some(no_reset_metrics) = settings.no_reset_metrics {
+            defaults.no_reset_metrics = Some(no_reset_metrics);
+        }
+        if let Some(no_metrics) = settings.no_metrics {
+            defaults.no_metrics = Some(no_metrics);
+        }
+        if let Some(status_codes) = settings.status_codes {
+            defaults.status_codes = Some(status_codes);
+        }
+        if let Some(report_file) = settings.report_file {
+            defaults.report_file = Some(report_file);
+        }
+        if let Some(request_log) = settings.request_log {
+            defaults.request_log = Some(request_log);
+        }
+        if let Some(co_mitigation) = settings.co_mitigation {
+            defaults.co_mitigation = Some(co_mitigation);
+        }
+        if let Some(throttle_requests) = settings.throttle_requests {
+            defaults.throttle_requests = Some(throttle_requests);
+        }
+        if let Some(seed) = settings.seed {
+            defaults.seed = Some(seed);
+        }
+        self
+    }
+}
+
+/// Used internally to configure [`GooseConfiguration`] values based on precedence rules.
+#[derive(Debug, Clone)]
+pub(crate) struct GooseValue<'a, T> {
+    /// The optional value to set.
+    pub(crate) value: Option<T>,
+    /// Filter using this value if true.
+    pub(crate) filter: bool,
+    /// An optional INFO level Goose log message.
+    pub(crate) message: &'a str,
+}
+
+pub(crate) trait GooseConfigure<T> {
+    /// Set [`GooseValue`] with supported type.
+    fn get_value(&self, values: Vec<GooseValue<T>>) -> Option<T>;
+}
+
+impl GooseConfigure<usize> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`usize`] value.
+    fn get_value(&self, values: Vec<GooseValue<usize>>) -> Option<usize> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<u16> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`usize`] value.
+    fn get_value(&self, values: Vec<GooseValue<u16>>) -> Option<u16> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<u8> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`u8`] value.
+    fn get_value(&self, values: Vec<GooseValue<u8>>) -> Option<u8> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<f32> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`f32`] value.
+    fn get_value(&self, values: Vec<GooseValue<f32>>) -> Option<f32> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<String> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`String`] value.
+    fn get_value(&self, values: Vec<GooseValue<String>>) -> Option<String> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<bool> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`bool`] value.
+    fn get_value(&self, values: Vec<GooseValue<bool>>) -> Option<bool> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<GooseLogFormat> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`GooseLogFormat`] value.
+    fn get_value(&self, values: Vec<GooseValue<GooseLogFormat>>) -> Option<GooseLogFormat> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {:?}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<GooseLogFailure> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`GooseLogFailure`] value.
+    fn get_value(&self, values: Vec<GooseValue<GooseLogFailure>>) -> Option<GooseLogFailure> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {:?}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<GooseOutput> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`GooseOutput`] value.
+    fn get_value(&self, values: Vec<GooseValue<GooseOutput>>) -> Option<GooseOutput> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {:?}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+impl GooseConfigure<GooseCoordinatedOmissionMitigation> for GooseConfiguration {
+    /// Use [`GooseValue`] to set a [`GooseCoordinatedOmissionMitigation`] value.
+    fn get_value(
+        &self,
+        values: Vec<GooseValue<GooseCoordinatedOmissionMitigation>>,
+    ) -> Option<GooseCoordinatedOmissionMitigation> {
+        for value in values {
+            if let Some(v) = value.value {
+                if value.filter {
+                    continue;
+                } else {
+                    if !value.message.is_empty() {
+                        info!("{} = {:?}", value.message, v)
+                    }
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl GooseConfiguration {
+    /// Implement precedence rules for all [`GooseConfiguration`] values.
+    pub(crate) fn configure(&mut self, defaults: &GooseDefaults) {
+        // Configure `verbose`.
+        self.verbose = self
+            .get_value(vec![
+                // Use --verbose if set.
+                GooseValue {
+                    value: Some(self.verbose),
+                    filter: self.verbose == 0,
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.verbose,
+                    filter: defaults.verbose.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `log_level`.
+        self.log_level = self
+            .get_value(vec![
+                // Use --log-level if set.
+                GooseValue {
+                    value: Some(self.log_level),
+                    filter: self.log_level == 0,
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.log_level,
+                    filter: defaults.log_level.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `goose_log`.
+        self.goose_log = self
+            .get_value(vec![
+                // Use --log-level if set.
+                GooseValue {
+                    value: Some(self.goose_log.to_string()),
+                    filter: self.goose_log.is_empty(),
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.goose_log.clone(),
+                    filter: defaults.goose_log.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
+        // Configure `output`, before initializing the Goose logger which depends on it.
+        self.output = self.get_value(vec![
+            // Use --output if set.
+            GooseValue {
+                value: self.output,
+                filter: self.output.is_none(),
+                message: "",
+            },
+            // Otherwise use GooseDefault if set.
+            GooseValue {
+                value: defaults.output,
+                filter: defaults.output.is_none(),
+                message: "",
+            },
+        ]);
+
+        // Initialize the Goose logger.
+        self.initialize_goose_logger();
+
+        // Configure loggers.
+        self.configure_loggers(defaults);
+
+        // Configure `manager`. (DEVELOPER NOTE: manager and worker must be configured
+        // immediately after the loggers.)
+        self.manager = self
+            .get_value(vec![
+                // Use --manager if set.
+                GooseValue {
+                    value: Some(self.manager),
+                    filter: !self.manager,
+                    message: "",
+                },
+                // Otherwise use default.
+                GooseValue {
+                    value: defaults.manager,
+                    filter: defaults.manager.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `worker`. (DEVELOPER NOTE: manager and worker must be configured
+        // immediately after the loggers.)
+        self.worker = self
+            .get_value(vec![
+                // Use --worker if set.
+                GooseValue {
+                    value: Some(self.worker),
+                    filter: !self.worker,
+                    message: "",
+                },
+                // Otherwise use default.
+                GooseValue {
+                    value: defaults.worker,
+                    filter: defaults.worker.is_none(),
+                    message: "",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `test_plan`.
+        self.test_plan = match self.get_value(vec![
+            // Use --test-plan if set.
+            GooseValue {
+                value: Some(self.test_plan.to_string()),
+                filter: self.test_plan.is_empty(),
+                message: "test_plan",
+            },
+            // Otherwise use GooseDefault if set and not on Worker.
+            GooseValue {
+                value: defaults.test_plan.clone(),
+                filter: defaults.test_plan.is_none() || self.worker,
+                message: "test_plan",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `users`.
+        let test_plan_users = GooseTestPlan::parse(&self.test_plan)
+            .ok()
+            .map(|test_plan| test_plan.max_users());
+        self.users = self.get_value(vec![
+            // Use --users if set.
+            GooseValue {
+                value: self.users,
+                filter: false,
+                message: "users",
+            },
+            // Otherwise use GooseDefault if set and not on Worker.
+            GooseValue {
+                value: defaults.users,
+                filter: defaults.users.is_none() || self.worker,
+                message: "users",
+            },
+            // Otherwise use the most users running at once in the test plan.
+            GooseValue {
+                value: test_plan_users,
+                filter: test_plan_users.is_none() || self.worker,
+                message: "users defaulted to test plan",
+            },
+            // Otherwise use detected number of CPUs if not on Worker.
+            GooseValue {
+                value: Some(num_cpus::get()),
+                filter: self.worker,
+                message: "users defaulted to number of CPUs",
+            },
+        ]);
+
+        // Configure `startup_time`.
+        self.startup_time = self
+            .get_value(vec![
+                // Use --startup-time if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.startup_time)),
+                    filter: util::parse_timespan(&self.startup_time) == 0,
+                    message: "startup_time",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: defaults.startup_time,
+                    filter: defaults.startup_time.is_none() || self.worker,
+                    message: "startup_time",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Configure `run_time`.
+        self.run_time = self
+            .get_value(vec![
+                // Use --run-time if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.run_time)),
+                    filter: util::parse_timespan(&self.run_time) == 0,
+                    message: "run_time",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: defaults.run_time,
+                    filter: defaults.run_time.is_none() || self.worker,
+                    message: "run_time",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Configure `ramp_up`.
+        self.ramp_up = self
+            .get_value(vec![
+                // Use --ramp-up if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.ramp_up)),
+                    filter: util::parse_timespan(&self.ramp_up) == 0,
+                    message: "ramp_up",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: defaults.ramp_up,
+                    filter: defaults.ramp_up.is_none() || self.worker,
+                    message: "ramp_up",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Configure `hold`.
+        self.hold = self
+            .get_value(vec![
+                // Use --hold if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.hold)),
+                    filter: util::parse_timespan(&self.hold) == 0,
+                    message: "hold",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: defaults.hold,
+                    filter: defaults.hold.is_none() || self.worker,
+                    message: "hold",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Configure `ramp_down`.
+        self.ramp_down = self
+            .get_value(vec![
+                // Use --ramp-down if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.ramp_down)),
+                    filter: util::parse_timespan(&self.ramp_down) == 0,
+                    message: "ramp_down",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: defaults.ramp_down,
+                    filter: defaults.ramp_down.is_none() || self.worker,
+                    message: "ramp_down",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Configure `hatch_rate`.
+        self.hatch_rate = self
+            .get_value(vec![
+                // Use --hatch-rate if set.
+                GooseValue {
+                    value: Some(util::get_hatch_rate(self.hatch_rate.clone())),
+                    filter: self.hatch_rate.is_none(),
+                    message: "hatch_rate",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: Some(util::get_hatch_rate(defaults.hatch_rate.clone())),
+                    filter: defaults.hatch_rate.is_none() || self.worker,
+                    message: "hatch_rate",
+                },
+            ])
+            .map(|v| v.to_string());
+
+        // Configure `stop_rate`.
+        self.stop_rate = self
+            .get_value(vec![
+                // Use --stop-rate if set.
+                GooseValue {
+                    value: Some(util::get_hatch_rate(self.stop_rate.clone())),
+                    filter: self.stop_rate.is_none(),
+                    message: "stop_rate",
+                },
+                // Otherwise use GooseDefault if set and not on Worker.
+                GooseValue {
+                    value: Some(util::get_hatch_rate(defaults.stop_rate.clone())),
+                    filter: defaults.stop_rate.is_none() || self.worker,
+                    message: "stop_rate",
+                },
+            ])
+            .map(|v| v.to_string());
+
+        // Configure `running_metrics`.
+        self.running_metrics = self.get_value(vec![
+            // Use --running-metrics if set.
+            GooseValue {
+                value: self.running_metrics,
+                filter: self.running_metrics.is_none(),
+                message: "running_metrics",
+            },
+            // Otherwise use GooseDefault if set.
+            GooseValue {
+                value: defaults.running_metrics,
+                filter: defaults.running_metrics.is_none() || self.worker,
+                message: "running_metrics",
+            },
+        ]);
+
+        // Configure `no_reset_metrics`.
+        self.no_reset_metrics = self
+            .get_value(vec![
+                // Use --no-reset-metrics if set.
+                GooseValue {
+                    value: Some(self.no_reset_metrics),
+                    filter: !self.no_reset_metrics,
+                    message: "no_reset_metrics",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.no_reset_metrics,
+                    filter: defaults.no_reset_metrics.is_none() || self.worker,
+                    message: "no_reset_metrics",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `no_metrics`.
+        self.no_metrics = self
+            .get_value(vec![
+                // Use --no-metrics if set.
+                GooseValue {
+                    value: Some(self.no_metrics),
+                    filter: !self.no_metrics,
+                    message: "no_metrics",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.no_metrics,
+                    filter: defaults.no_metrics.is_none() || self.worker,
+                    message: "no_metrics",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `no_task_metrics`.
+        self.no_task_metrics = self
             .get_value(vec![
-                // Use --verbose if set.
+                // Use --no-task-metrics if set.
                 GooseValue {
-                    value: Some(self.verbose),
-                    filter: self.verbose == 0,
-                    message: "",
+                    value: Some(self.no_task_metrics),
+                    filter: !self.no_task_metrics,
+                    message: "no_task_metrics",
                 },
                 // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.verbose,
-                    filter: defaults.verbose.is_none(),
-                    message: "",
+                    value: defaults.no_task_metrics,
+                    filter: defaults.no_task_metrics.is_none() || self.worker,
+                    message: "no_task_metrics",
                 },
             ])
-            .unwrap_or(0);
+            .unwrap_or(false);
 
-        // Configure `log_level`.
-        self.log_level = self
+        // Configure `no_histograms`.
+        self.no_histograms = self
             .get_value(vec![
-                // Use --log-level if set.
+                // Use --no-histograms if set.
                 GooseValue {
-                    value: Some(self.log_level),
-                    filter: self.log_level == 0,
-                    message: "",
+                    value: Some(self.no_histograms),
+                    filter: !self.no_histograms,
+                    message: "no_histograms",
                 },
                 // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.log_level,
-                    filter: defaults.log_level.is_none(),
-                    message: "",
+                    value: defaults.no_histograms,
+                    filter: defaults.no_histograms.is_none() || self.worker,
+                    message: "no_histograms",
                 },
             ])
-            .unwrap_or(0);
+            .unwrap_or(false);
 
-        // Configure `goose_log`.
-        self.goose_log = self
+        // Configure `no_time_series`.
+        self.no_time_series = self
             .get_value(vec![
-                // Use --log-level if set.
+                // Use --no-time-series if set.
                 GooseValue {
-                    value: Some(self.goose_log.to_string()),
-                    filter: self.goose_log.is_empty(),
-                    message: "",
+                    value: Some(self.no_time_series),
+                    filter: !self.no_time_series,
+                    message: "no_time_series",
                 },
                 // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.goose_log.clone(),
-                    filter: defaults.goose_log.is_none(),
-                    message: "",
+                    value: defaults.no_time_series,
+                    filter: defaults.no_time_series.is_none() || self.worker,
+                    message: "no_time_series",
                 },
             ])
-            .unwrap_or_else(|| "".to_string());
+            .unwrap_or(false);
 
-        // Initialize the Goose logger.
-        self.initialize_goose_logger();
+        // Configure `no_error_summary`.
+        self.no_error_summary = self
+            .get_value(vec![
+                // Use --no-error-summary if set.
+                GooseValue {
+                    value: Some(self.no_error_summary),
+                    filter: !self.no_error_summary,
+                    message: "no_error_summary",
+                },
+                // Otherwise use GooseDefault if set.
+                GooseValue {
+                    value: defaults.no_error_summary,
+                    filter: defaults.no_error_summary.is_none() || self.worker,
+                    message: "no_error_summary",
+                },
+            ])
+            .unwrap_or(false);
 
-        // Configure loggers.
-        self.configure_loggers(defaults);
+        // Configure `number_locale`.
+        self.number_locale = match self.get_value(vec![
+            // Use --number-locale if set.
+            GooseValue {
+                value: Some(self.number_locale.to_string()),
+                filter: self.number_locale.is_empty(),
+                message: "number_locale",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.number_locale.clone(),
+                filter: defaults.number_locale.is_none() || self.worker,
+                message: "number_locale",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
 
-        // Configure `manager`. (DEVELOPER NOTE: manager and worker must be configured
-        // immediately after the loggers.)
-        self.manager = self
+        // Configure `scale_units`.
+        self.scale_units = self
             .get_value(vec![
-                // Use --manager if set.
+                // Use --scale-units if set.
                 GooseValue {
-                    value: Some(self.manager),
-                    filter: !self.manager,
-                    message: "",
+                    value: Some(self.scale_units),
+                    filter: !self.scale_units,
+                    message: "scale_units",
                 },
-                // Otherwise use default.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.manager,
-                    filter: defaults.manager.is_none(),
-                    message: "",
+                    value: defaults.scale_units,
+                    filter: defaults.scale_units.is_none() || self.worker,
+                    message: "scale_units",
                 },
             ])
             .unwrap_or(false);
 
-        // Configure `worker`. (DEVELOPER NOTE: manager and worker must be configured
-        // immediately after the loggers.)
-        self.worker = self
+        // Configure `report_file`.
+        self.report_file = match self.get_value(vec![
+            // Use --report-file if set.
+            GooseValue {
+                value: Some(self.report_file.to_string()),
+                filter: self.report_file.is_empty(),
+                message: "report_file",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.report_file.clone(),
+                filter: defaults.report_file.is_none() || self.manager,
+                message: "report_file",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `report_interval`.
+        self.report_interval = self.get_value(vec![
+            // Use --report-interval if set.
+            GooseValue {
+                value: self.report_interval,
+                filter: self.report_interval.is_none(),
+                message: "report_interval",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.report_interval,
+                filter: defaults.report_interval.is_none() || self.manager,
+                message: "report_interval",
+            },
+        ]);
+
+        // Configure `report_timestamp`.
+        self.report_timestamp = self
             .get_value(vec![
-                // Use --worker if set.
+                // Use --report-timestamp if set.
                 GooseValue {
-                    value: Some(self.worker),
-                    filter: !self.worker,
-                    message: "",
+                    value: Some(self.report_timestamp),
+                    filter: !self.report_timestamp,
+                    message: "report_timestamp",
                 },
-                // Otherwise use default.
+                // Otherwise use GooseDefault if set and not Manager.
                 GooseValue {
-                    value: defaults.worker,
-                    filter: defaults.worker.is_none(),
-                    message: "",
+                    value: defaults.report_timestamp,
+                    filter: defaults.report_timestamp.is_none() || self.manager,
+                    message: "report_timestamp",
                 },
             ])
             .unwrap_or(false);
 
-        // Configure `users`.
-        self.users = self.get_value(vec![
-            // Use --users if set.
+        // Configure `plan_file`.
+        self.plan_file = match self.get_value(vec![
+            // Use --plan-file if set.
             GooseValue {
-                value: self.users,
-                filter: false,
-                message: "users",
+                value: Some(self.plan_file.to_string()),
+                filter: self.plan_file.is_empty(),
+                message: "plan_file",
             },
-            // Otherwise use GooseDefault if set and not on Worker.
+            // Otherwise use GooseDefault if set and not Worker.
             GooseValue {
-                value: defaults.users,
-                filter: defaults.users.is_none() || self.worker,
-                message: "users",
+                value: defaults.plan_file.clone(),
+                filter: defaults.plan_file.is_none() || self.worker,
+                message: "plan_file",
             },
-            // Otherwise use detected number of CPUs if not on Worker.
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `checkpoint_file`.
+        self.checkpoint_file = match self.get_value(vec![
+            // Use --checkpoint-file if set.
             GooseValue {
-                value: Some(num_cpus::get()),
-                filter: self.worker,
-                message: "users defaulted to number of CPUs",
+                value: Some(self.checkpoint_file.to_string()),
+                filter: self.checkpoint_file.is_empty(),
+                message: "checkpoint_file",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.checkpoint_file.clone(),
+                filter: defaults.checkpoint_file.is_none() || self.manager,
+                message: "checkpoint_file",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `checkpoint_interval`.
+        self.checkpoint_interval = self.get_value(vec![
+            // Use --checkpoint-interval if set.
+            GooseValue {
+                value: self.checkpoint_interval,
+                filter: self.checkpoint_interval.is_none(),
+                message: "checkpoint_interval",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.checkpoint_interval,
+                filter: defaults.checkpoint_interval.is_none() || self.manager,
+                message: "checkpoint_interval",
+            },
+            // Otherwise default to 60 seconds if saving checkpoints.
+            GooseValue {
+                value: Some(60),
+                filter: self.checkpoint_file.is_empty(),
+                message: "checkpoint_interval",
             },
         ]);
 
-        // Configure `startup_time`.
-        self.startup_time = self
+        // Configure `no_debug_body`.
+        self.no_debug_body = self
             .get_value(vec![
-                // Use --startup-time if set.
+                // Use --no-debug-body if set.
                 GooseValue {
-                    value: Some(util::parse_timespan(&self.startup_time)),
-                    filter: util::parse_timespan(&self.startup_time) == 0,
-                    message: "startup_time",
+                    value: Some(self.no_debug_body),
+                    filter: !self.no_debug_body,
+                    message: "no_debug_body",
                 },
-                // Otherwise use GooseDefault if set and not on Worker.
+                // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.startup_time,
-                    filter: defaults.startup_time.is_none() || self.worker,
-                    message: "startup_time",
+                    value: defaults.no_debug_body,
+                    filter: defaults.no_debug_body.is_none() || self.manager,
+                    message: "no_debug_body",
                 },
             ])
-            .map_or_else(|| "0".to_string(), |v| v.to_string());
+            .unwrap_or(false);
 
-        // Configure `run_time`.
-        self.run_time = self
+        // Configure `no_redact_headers`.
+        self.no_redact_headers = self
             .get_value(vec![
-                // Use --run-time if set.
+                // Use --no-redact-headers if set.
                 GooseValue {
-                    value: Some(util::parse_timespan(&self.run_time)),
-                    filter: util::parse_timespan(&self.run_time) == 0,
-                    message: "run_time",
+                    value: Some(self.no_redact_headers),
+                    filter: !self.no_redact_headers,
+                    message: "no_redact_headers",
                 },
-                // Otherwise use GooseDefault if set and not on Worker.
+                // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.run_time,
-                    filter: defaults.run_time.is_none() || self.worker,
-                    message: "run_time",
+                    value: defaults.no_redact_headers,
+                    filter: defaults.no_redact_headers.is_none() || self.manager,
+                    message: "no_redact_headers",
                 },
             ])
-            .map_or_else(|| "0".to_string(), |v| v.to_string());
+            .unwrap_or(false);
 
-        // Configure `hatch_rate`.
-        self.hatch_rate = self
+        // Configure `continue_without_logs`.
+        self.continue_without_logs = self
             .get_value(vec![
-                // Use --hatch-rate if set.
+                // Use --continue-without-logs if set.
                 GooseValue {
-                    value: Some(util::get_hatch_rate(self.hatch_rate.clone())),
-                    filter: self.hatch_rate.is_none(),
-                    message: "hatch_rate",
+                    value: Some(self.continue_without_logs),
+                    filter: !self.continue_without_logs,
+                    message: "continue_without_logs",
                 },
-                // Otherwise use GooseDefault if set and not on Worker.
+                // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: Some(util::get_hatch_rate(defaults.hatch_rate.clone())),
-                    filter: defaults.hatch_rate.is_none() || self.worker,
-                    message: "hatch_rate",
+                    value: defaults.continue_without_logs,
+                    filter: defaults.continue_without_logs.is_none() || self.manager,
+                    message: "continue_without_logs",
                 },
             ])
-            .map(|v| v.to_string());
+            .unwrap_or(false);
 
-        // Configure `running_metrics`.
-        self.running_metrics = self.get_value(vec![
-            // Use --running-metrics if set.
+        // Configure `affinity_header`.
+        self.affinity_header = match self.get_value(vec![
+            // Use --affinity-header if set.
             GooseValue {
-                value: self.running_metrics,
-                filter: self.running_metrics.is_none(),
-                message: "running_metrics",
+                value: Some(self.affinity_header.to_string()),
+                filter: self.affinity_header.is_empty(),
+                message: "affinity_header",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.affinity_header.clone(),
+                filter: defaults.affinity_header.is_none() || self.manager,
+                message: "affinity_header",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `header_dimensions`.
+        self.header_dimensions = match self.get_value(vec![
+            // Use --header-dimensions if set.
+            GooseValue {
+                value: Some(self.header_dimensions.to_string()),
+                filter: self.header_dimensions.is_empty(),
+                message: "header_dimensions",
+            },
+            // Otherwise use GooseDefault if set and not Manager.
+            GooseValue {
+                value: defaults.header_dimensions.clone(),
+                filter: defaults.header_dimensions.is_none() || self.manager,
+                message: "header_dimensions",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `label`.
+        if self.label.is_empty() {
+            // Use GooseDefault if --label is not set.
+            if let Some(labels) = self.get_value(vec![GooseValue {
+                value: defaults.labels.clone(),
+                filter: defaults.labels.is_none(),
+                message: "labels",
+            }]) {
+                self.label = labels.split(',').map(|l| l.trim().to_string()).collect();
+            }
+        }
+
+        // Configure `run_id`.
+        self.run_id = match self.get_value(vec![
+            // Use --run-id if set.
+            GooseValue {
+                value: Some(self.run_id.to_string()),
+                filter: self.run_id.is_empty(),
+                message: "run_id",
             },
             // Otherwise use GooseDefault if set.
             GooseValue {
-                value: defaults.running_metrics,
-                filter: defaults.running_metrics.is_none() || self.worker,
-                message: "running_metrics",
+                value: defaults.run_id.clone(),
+                filter: defaults.run_id.is_none(),
+                message: "run_id",
             },
-        ]);
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
 
-        // Configure `no_reset_metrics`.
-        self.no_reset_metrics = self
+        // Configure `body_sizes`.
+        self.body_sizes = self
             .get_value(vec![
-                // Use --no-reset-metrics if set.
+                // Use --body-sizes if set.
                 GooseValue {
-                    value: Some(self.no_reset_metrics),
-                    filter: !self.no_reset_metrics,
-                    message: "no_reset_metrics",
+                    value: Some(self.body_sizes),
+                    filter: !self.body_sizes,
+                    message: "body_sizes",
                 },
-                // Otherwise use GooseDefault if set.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.no_reset_metrics,
-                    filter: defaults.no_reset_metrics.is_none() || self.worker,
-                    message: "no_reset_metrics",
+                    value: defaults.body_sizes,
+                    filter: defaults.body_sizes.is_none() || self.worker,
+                    message: "body_sizes",
                 },
             ])
             .unwrap_or(false);
 
-        // Configure `no_metrics`.
-        self.no_metrics = self
+        // Configure `cache_status`.
+        self.cache_status = self
             .get_value(vec![
-                // Use --no-metrics if set.
+                // Use --cache-status if set.
                 GooseValue {
-                    value: Some(self.no_metrics),
-                    filter: !self.no_metrics,
-                    message: "no_metrics",
+                    value: Some(self.cache_status),
+                    filter: !self.cache_status,
+                    message: "cache_status",
                 },
-                // Otherwise use GooseDefault if set.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.no_metrics,
-                    filter: defaults.no_metrics.is_none() || self.worker,
-                    message: "no_metrics",
+                    value: defaults.cache_status,
+                    filter: defaults.cache_status.is_none() || self.worker,
+                    message: "cache_status",
                 },
             ])
             .unwrap_or(false);
 
-        // Configure `no_task_metrics`.
-        self.no_task_metrics = self
+        // Configure `prometheus_port`.
+        self.prometheus_port = self
             .get_value(vec![
-                // Use --no-task-metrics if set.
+                // Use --prometheus-port if configured.
                 GooseValue {
-                    value: Some(self.no_task_metrics),
-                    filter: !self.no_task_metrics,
-                    message: "no_task_metrics",
+                    value: Some(self.prometheus_port),
+                    filter: self.prometheus_port == 0,
+                    message: "prometheus_port",
                 },
-                // Otherwise use GooseDefault if set.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.no_task_metrics,
-                    filter: defaults.no_task_metrics.is_none() || self.worker,
-                    message: "no_task_metrics",
+                    value: defaults.prometheus_port,
+                    filter: defaults.prometheus_port.is_none() || self.worker,
+                    message: "prometheus_port",
                 },
             ])
-            .unwrap_or(false);
+            .unwrap_or(0);
 
-        // Configure `no_error_summary`.
-        self.no_error_summary = self
+        // Configure `statsd_host`.
+        self.statsd_host = self
             .get_value(vec![
-                // Use --no-error-summary if set.
+                // Use --statsd-host if configured.
                 GooseValue {
-                    value: Some(self.no_error_summary),
-                    filter: !self.no_error_summary,
-                    message: "no_error_summary",
+                    value: Some(self.statsd_host.to_string()),
+                    filter: self.statsd_host.is_empty(),
+                    message: "statsd_host",
                 },
-                // Otherwise use GooseDefault if set.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.no_error_summary,
-                    filter: defaults.no_error_summary.is_none() || self.worker,
-                    message: "no_error_summary",
+                    value: defaults.statsd_host.clone(),
+                    filter: defaults.statsd_host.is_none() || self.worker,
+                    message: "statsd_host",
                 },
             ])
-            .unwrap_or(false);
+            .unwrap_or_default();
 
-        // Configure `report_file`.
-        self.report_file = match self.get_value(vec![
-            // Use --report-file if set.
-            GooseValue {
-                value: Some(self.report_file.to_string()),
-                filter: self.report_file.is_empty(),
-                message: "report_file",
-            },
-            // Otherwise use GooseDefault if set and not Manager.
-            GooseValue {
-                value: defaults.report_file.clone(),
-                filter: defaults.report_file.is_none() || self.manager,
-                message: "report_file",
-            },
-        ]) {
-            Some(v) => v,
-            None => "".to_string(),
-        };
+        // Configure `influxdb_url`.
+        self.influxdb_url = self
+            .get_value(vec![
+                // Use --influxdb-url if configured.
+                GooseValue {
+                    value: Some(self.influxdb_url.to_string()),
+                    filter: self.influxdb_url.is_empty(),
+                    message: "influxdb_url",
+                },
+                // Otherwise use GooseDefault if set and not Worker.
+                GooseValue {
+                    value: defaults.influxdb_url.clone(),
+                    filter: defaults.influxdb_url.is_none() || self.worker,
+                    message: "influxdb_url",
+                },
+            ])
+            .unwrap_or_default();
 
-        // Configure `no_debug_body`.
-        self.no_debug_body = self
+        // Configure `influxdb_token`.
+        self.influxdb_token = self
             .get_value(vec![
-                // Use --no-debug-body if set.
+                // Use --influxdb-token if configured.
                 GooseValue {
-                    value: Some(self.no_debug_body),
-                    filter: !self.no_debug_body,
-                    message: "no_debug_body",
+                    value: Some(self.influxdb_token.to_string()),
+                    filter: self.influxdb_token.is_empty(),
+                    message: "influxdb_token",
                 },
-                // Otherwise use GooseDefault if set.
+                // Otherwise use GooseDefault if set and not Worker.
                 GooseValue {
-                    value: defaults.no_debug_body,
-                    filter: defaults.no_debug_body.is_none() || self.manager,
-                    message: "no_debug_body",
+                    value: defaults.influxdb_token.clone(),
+                    filter: defaults.influxdb_token.is_none() || self.worker,
+                    message: "influxdb_token",
                 },
             ])
-            .unwrap_or(false);
+            .unwrap_or_default();
+
+        // Configure `influxdb_batch_size`.
+        self.influxdb_batch_size = self.get_value(vec![
+            // Use --influxdb-batch-size if set.
+            GooseValue {
+                value: self.influxdb_batch_size,
+                filter: self.influxdb_batch_size.is_none(),
+                message: "influxdb_batch_size",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.influxdb_batch_size,
+                filter: defaults.influxdb_batch_size.is_none() || self.worker,
+                message: "influxdb_batch_size",
+            },
+            // Otherwise default to 5,000 requests if streaming to InfluxDB.
+            GooseValue {
+                value: Some(5_000),
+                filter: self.influxdb_url.is_empty(),
+                message: "influxdb_batch_size",
+            },
+        ]);
+
+        // Configure `influxdb_flush_interval`.
+        self.influxdb_flush_interval = self.get_value(vec![
+            // Use --influxdb-flush-interval if set.
+            GooseValue {
+                value: self.influxdb_flush_interval,
+                filter: self.influxdb_flush_interval.is_none(),
+                message: "influxdb_flush_interval",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.influxdb_flush_interval,
+                filter: defaults.influxdb_flush_interval.is_none() || self.worker,
+                message: "influxdb_flush_interval",
+            },
+            // Otherwise default to 1 second if streaming to InfluxDB.
+            GooseValue {
+                value: Some(1),
+                filter: self.influxdb_url.is_empty(),
+                message: "influxdb_flush_interval",
+            },
+        ]);
 
         // Configure `status_codes`.
         self.status_codes = self
@@ -1572,69 +3828,500 @@ impl GooseConfiguration {
                 },
                 // Use GooseDefault if not already set and not Worker.
                 GooseValue {
-                    value: defaults.no_gzip,
-                    filter: defaults.no_gzip.is_none() || self.worker,
-                    message: "no_gzip",
+                    value: defaults.no_gzip,
+                    filter: defaults.no_gzip.is_none() || self.worker,
+                    message: "no_gzip",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `accept_encoding`.
+        self.accept_encoding = match self.get_value(vec![
+            // Use --accept-encoding if set.
+            GooseValue {
+                value: Some(self.accept_encoding.to_string()),
+                filter: self.accept_encoding.is_empty(),
+                message: "accept_encoding",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.accept_encoding.clone(),
+                filter: defaults.accept_encoding.is_none() || self.worker,
+                message: "accept_encoding",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `no_tls_resumption`.
+        self.no_tls_resumption = self
+            .get_value(vec![
+                // Use --no-tls-resumption if set.
+                GooseValue {
+                    value: Some(self.no_tls_resumption),
+                    filter: !self.no_tls_resumption,
+                    message: "no_tls_resumption",
+                },
+                // Use GooseDefault if not already set and not Worker.
+                GooseValue {
+                    value: defaults.no_tls_resumption,
+                    filter: defaults.no_tls_resumption.is_none() || self.worker,
+                    message: "no_tls_resumption",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `resolve_hosts`.
+        self.resolve_hosts = self
+            .get_value(vec![
+                // Use --resolve-hosts if set.
+                GooseValue {
+                    value: Some(self.resolve_hosts),
+                    filter: !self.resolve_hosts,
+                    message: "resolve_hosts",
+                },
+                // Use GooseDefault if not already set and not Worker.
+                GooseValue {
+                    value: defaults.resolve_hosts,
+                    filter: defaults.resolve_hosts.is_none() || self.worker,
+                    message: "resolve_hosts",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `warm_up`.
+        self.warm_up = self
+            .get_value(vec![
+                // Use --warm-up if set.
+                GooseValue {
+                    value: Some(self.warm_up),
+                    filter: self.warm_up == 0,
+                    message: "warm_up",
+                },
+                // Otherwise use GooseDefault if set and not Worker.
+                GooseValue {
+                    value: defaults.warm_up,
+                    filter: defaults.warm_up.is_none() || self.worker,
+                    message: "warm_up",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `rtt_samples`.
+        self.rtt_samples = self
+            .get_value(vec![
+                // Use --rtt-samples if set.
+                GooseValue {
+                    value: Some(self.rtt_samples),
+                    filter: self.rtt_samples == 0,
+                    message: "rtt_samples",
+                },
+                // Otherwise use GooseDefault if set and not Worker.
+                GooseValue {
+                    value: defaults.rtt_samples,
+                    filter: defaults.rtt_samples.is_none() || self.worker,
+                    message: "rtt_samples",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `validation_workers`.
+        self.validation_workers = self
+            .get_value(vec![
+                // Use --validation-workers if set.
+                GooseValue {
+                    value: Some(self.validation_workers),
+                    filter: self.validation_workers == 0,
+                    message: "validation_workers",
+                },
+                // Otherwise use GooseDefault if set and not Worker.
+                GooseValue {
+                    value: defaults.validation_workers,
+                    filter: defaults.validation_workers.is_none() || self.worker,
+                    message: "validation_workers",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `ssh_jump_host`.
+        self.ssh_jump_host = match self.get_value(vec![
+            // Use --ssh-jump-host if set.
+            GooseValue {
+                value: Some(self.ssh_jump_host.to_string()),
+                filter: self.ssh_jump_host.is_empty(),
+                message: "ssh_jump_host",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.ssh_jump_host.clone(),
+                filter: defaults.ssh_jump_host.is_none() || self.worker,
+                message: "ssh_jump_host",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `ssh_identity`.
+        self.ssh_identity = match self.get_value(vec![
+            // Use --ssh-identity if set.
+            GooseValue {
+                value: Some(self.ssh_identity.to_string()),
+                filter: self.ssh_identity.is_empty(),
+                message: "ssh_identity",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.ssh_identity.clone(),
+                filter: defaults.ssh_identity.is_none() || self.worker,
+                message: "ssh_identity",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        self.co_mitigation = self.get_value(vec![
+            // Use --co-mitigation if set.
+            GooseValue {
+                value: self.co_mitigation.clone(),
+                filter: self.co_mitigation.is_none(),
+                message: "co_mitigation",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.co_mitigation.clone(),
+                filter: defaults.co_mitigation.is_none() || self.worker,
+                message: "co_mitigation",
+            },
+            // Otherwise default to GooseCoordinaatedOmissionMitigation::Disabled.
+            GooseValue {
+                value: Some(GooseCoordinatedOmissionMitigation::Disabled),
+                filter: self.worker,
+                message: "",
+            },
+        ]);
+
+        // Configure `co_cadence`.
+        self.co_cadence = self
+            .get_value(vec![
+                // Use --co-cadence if set.
+                GooseValue {
+                    value: Some(self.co_cadence),
+                    filter: self.co_cadence == 0,
+                    message: "co_cadence",
+                },
+                // Otherwise use GooseDefault if set and not Worker.
+                GooseValue {
+                    value: defaults.co_cadence,
+                    filter: defaults.co_cadence.is_none() || self.worker,
+                    message: "co_cadence",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `throttle_requests`.
+        self.throttle_requests = self
+            .get_value(vec![
+                // Use --throttle-requests if set.
+                GooseValue {
+                    value: Some(self.throttle_requests),
+                    filter: self.throttle_requests == 0,
+                    message: "throttle_requests",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.throttle_requests,
+                    filter: defaults.throttle_requests.is_none() || self.manager,
+                    message: "throttle_requests",
+                },
+            ])
+            .unwrap_or(0);
+
+        // Configure `adaptive_throttle`.
+        self.adaptive_throttle = self
+            .get_value(vec![
+                // Use --adaptive-throttle if set.
+                GooseValue {
+                    value: Some(self.adaptive_throttle),
+                    filter: !self.adaptive_throttle,
+                    message: "adaptive_throttle",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.adaptive_throttle,
+                    filter: defaults.adaptive_throttle.is_none() || self.manager,
+                    message: "adaptive_throttle",
+                },
+            ])
+            .unwrap_or(false);
+
+        // Configure `seed`.
+        self.seed = self.get_value(vec![
+            // Use --seed if set.
+            GooseValue {
+                value: self.seed,
+                filter: false,
+                message: "seed",
+            },
+            // Otherwise use GooseDefault if set.
+            GooseValue {
+                value: defaults.seed,
+                filter: false,
+                message: "seed",
+            },
+        ]);
+
+        // Configure `replay_speed`.
+        self.replay_speed = self.get_value(vec![
+            // Use --replay-speed if set.
+            GooseValue {
+                value: self.replay_speed.clone(),
+                filter: self.replay_speed.is_none(),
+                message: "replay_speed",
+            },
+            // Otherwise use GooseDefault if set.
+            GooseValue {
+                value: defaults.replay_speed.clone(),
+                filter: defaults.replay_speed.is_none(),
+                message: "replay_speed",
+            },
+        ]);
+
+        // Configure `sticky_follow`.
+        self.sticky_follow = self
+            .get_value(vec![
+                // Use --sticky-follow if set.
+                GooseValue {
+                    value: Some(self.sticky_follow),
+                    filter: !self.sticky_follow,
+                    message: "sticky_follow",
+                },
+                // Use GooseDefault if not already set and not Worker.
+                GooseValue {
+                    value: defaults.sticky_follow,
+                    filter: defaults.sticky_follow.is_none() || self.worker,
+                    message: "sticky_follow",
                 },
             ])
             .unwrap_or(false);
 
-        self.co_mitigation = self.get_value(vec![
-            // Use --co-mitigation if set.
+        // Configure `variant_host`.
+        self.variant_host = match self.get_value(vec![
+            // Use --variant-host if set.
             GooseValue {
-                value: self.co_mitigation.clone(),
-                filter: self.co_mitigation.is_none(),
-                message: "co_mitigation",
+                value: Some(self.variant_host.to_string()),
+                filter: self.variant_host.is_empty(),
+                message: "variant_host",
             },
             // Otherwise use GooseDefault if set and not Worker.
             GooseValue {
-                value: defaults.co_mitigation.clone(),
-                filter: defaults.co_mitigation.is_none() || self.worker,
-                message: "co_mitigation",
+                value: defaults.variant_host.clone(),
+                filter: defaults.variant_host.is_none() || self.worker,
+                message: "variant_host",
             },
-            // Otherwise default to GooseCoordinaatedOmissionMitigation::Disabled.
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `variant_header`.
+        self.variant_header = match self.get_value(vec![
+            // Use --variant-header if set.
             GooseValue {
-                value: Some(GooseCoordinatedOmissionMitigation::Disabled),
-                filter: self.worker,
-                message: "",
+                value: Some(self.variant_header.to_string()),
+                filter: self.variant_header.is_empty(),
+                message: "variant_header",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.variant_header.clone(),
+                filter: defaults.variant_header.is_none() || self.worker,
+                message: "variant_header",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `variant_percent`.
+        self.variant_percent = self.get_value(vec![
+            // Use --variant-percent if set.
+            GooseValue {
+                value: self.variant_percent,
+                filter: self.variant_percent.is_none(),
+                message: "variant_percent",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.variant_percent,
+                filter: defaults.variant_percent.is_none() || self.worker,
+                message: "variant_percent",
+            },
+            // Otherwise default to 50 percent if there's a variant.
+            GooseValue {
+                value: Some(50),
+                filter: self.variant_host.is_empty() && self.variant_header.is_empty(),
+                message: "variant_percent",
             },
         ]);
 
-        // Configure `throttle_requests`.
-        self.throttle_requests = self
+        // Configure `cache_buster`.
+        self.cache_buster = match self.get_value(vec![
+            // Use --cache-buster if set.
+            GooseValue {
+                value: Some(self.cache_buster.to_string()),
+                filter: self.cache_buster.is_empty(),
+                message: "cache_buster",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.cache_buster.clone(),
+                filter: defaults.cache_buster.is_none() || self.worker,
+                message: "cache_buster",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `cache_buster_names`.
+        self.cache_buster_names = match self.get_value(vec![
+            // Use --cache-buster-names if set.
+            GooseValue {
+                value: Some(self.cache_buster_names.to_string()),
+                filter: self.cache_buster_names.is_empty(),
+                message: "cache_buster_names",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.cache_buster_names.clone(),
+                filter: defaults.cache_buster_names.is_none() || self.worker,
+                message: "cache_buster_names",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `device_profiles`.
+        self.device_profiles = match self.get_value(vec![
+            // Use --device-profiles if set.
+            GooseValue {
+                value: Some(self.device_profiles.to_string()),
+                filter: self.device_profiles.is_empty(),
+                message: "device_profiles",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.device_profiles.clone(),
+                filter: defaults.device_profiles.is_none() || self.worker,
+                message: "device_profiles",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `cohorts`.
+        self.cohorts = match self.get_value(vec![
+            // Use --cohorts if set.
+            GooseValue {
+                value: Some(self.cohorts.to_string()),
+                filter: self.cohorts.is_empty(),
+                message: "cohorts",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.cohorts.clone(),
+                filter: defaults.cohorts.is_none() || self.worker,
+                message: "cohorts",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `cohort_header`.
+        self.cohort_header = match self.get_value(vec![
+            // Use --cohort-header if set.
+            GooseValue {
+                value: Some(self.cohort_header.to_string()),
+                filter: self.cohort_header.is_empty(),
+                message: "cohort_header",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.cohort_header.clone(),
+                filter: defaults.cohort_header.is_none() || self.worker,
+                message: "cohort_header",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `client_certs`.
+        self.client_certs = match self.get_value(vec![
+            // Use --client-certs if set.
+            GooseValue {
+                value: Some(self.client_certs.to_string()),
+                filter: self.client_certs.is_empty(),
+                message: "client_certs",
+            },
+            // Otherwise use GooseDefault if set and not Worker.
+            GooseValue {
+                value: defaults.client_certs.clone(),
+                filter: defaults.client_certs.is_none() || self.worker,
+                message: "client_certs",
+            },
+        ]) {
+            Some(v) => v,
+            None => "".to_string(),
+        };
+
+        // Configure `record`.
+        self.record = self
             .get_value(vec![
-                // Use --throttle-requests if set.
+                // Use --record if set.
                 GooseValue {
-                    value: Some(self.throttle_requests),
-                    filter: self.throttle_requests == 0,
-                    message: "throttle_requests",
+                    value: Some(self.record.to_string()),
+                    filter: self.record.is_empty(),
+                    message: "record",
                 },
-                // Otherwise use GooseDefault if set and not on Manager.
+                // Otherwise use GooseDefault if set.
                 GooseValue {
-                    value: defaults.throttle_requests,
-                    filter: defaults.throttle_requests.is_none() || self.manager,
-                    message: "throttle_requests",
+                    value: defaults.record.clone(),
+                    filter: defaults.record.is_none(),
+                    message: "record",
                 },
             ])
-            .unwrap_or(0);
+            .unwrap_or_else(|| "".to_string());
 
-        // Configure `sticky_follow`.
-        self.sticky_follow = self
+        // Set `record_port` when recording.
+        self.record_port = self
             .get_value(vec![
-                // Use --sticky-follow if set.
+                // Use --record-port if configured.
                 GooseValue {
-                    value: Some(self.sticky_follow),
-                    filter: !self.sticky_follow,
-                    message: "sticky_follow",
+                    value: Some(self.record_port),
+                    filter: self.record_port == 0,
+                    message: "record_port",
                 },
-                // Use GooseDefault if not already set and not Worker.
+                // Otherwise use default if set and recording.
                 GooseValue {
-                    value: defaults.sticky_follow,
-                    filter: defaults.sticky_follow.is_none() || self.worker,
-                    message: "sticky_follow",
+                    value: defaults.record_port,
+                    filter: defaults.record_port.is_none() || self.record.is_empty(),
+                    message: "record_port",
+                },
+                // Otherwise default to DEFAULT_RECORD_PORT if recording.
+                GooseValue {
+                    value: Some(DEFAULT_RECORD_PORT.to_string().parse().unwrap()),
+                    filter: self.record.is_empty(),
+                    message: "record_port",
                 },
             ])
-            .unwrap_or(false);
+            .unwrap_or(0);
 
         // Configure `expect_workers`.
         self.expect_workers = self.get_value(vec![
@@ -1670,6 +4357,46 @@ impl GooseConfiguration {
             ])
             .unwrap_or(false);
 
+        // Configure `worker_timeout` on Manager.
+        self.worker_timeout = self.get_value(vec![
+            // Use --worker-timeout if configured.
+            GooseValue {
+                value: self.worker_timeout,
+                filter: self.worker_timeout.is_none(),
+                message: "worker_timeout",
+            },
+            // Otherwise use default if set and on Manager.
+            GooseValue {
+                value: defaults.worker_timeout,
+                filter: defaults.worker_timeout.is_none() || !self.manager,
+                message: "worker_timeout",
+            },
+            // Otherwise use DEFAULT_WORKER_TIMEOUT if on Manager.
+            GooseValue {
+                value: Some(DEFAULT_WORKER_TIMEOUT),
+                filter: !self.manager,
+                message: "worker_timeout",
+            },
+        ]);
+
+        // Configure `redistribute_users`.
+        self.redistribute_users = self
+            .get_value(vec![
+                // Use --redistribute-users if set.
+                GooseValue {
+                    value: Some(self.redistribute_users),
+                    filter: !self.redistribute_users,
+                    message: "redistribute_users",
+                },
+                // Otherwise use default if set and on Manager.
+                GooseValue {
+                    value: defaults.redistribute_users,
+                    filter: defaults.redistribute_users.is_none() || !self.manager,
+                    message: "redistribute_users",
+                },
+            ])
+            .unwrap_or(false);
+
         // Set `manager_bind_host` on Manager.
         self.manager_bind_host = self
             .get_value(vec![
@@ -1765,6 +4492,60 @@ impl GooseConfiguration {
                 },
             ])
             .unwrap_or(0);
+
+        // Set `manager_tls_cert` on Manager.
+        self.manager_tls_cert = self
+            .get_value(vec![
+                // Use --manager-tls-cert if configured.
+                GooseValue {
+                    value: Some(self.manager_tls_cert.to_string()),
+                    filter: self.manager_tls_cert.is_empty(),
+                    message: "manager_tls_cert",
+                },
+                // Otherwise use default if set and on Manager.
+                GooseValue {
+                    value: defaults.manager_tls_cert.clone(),
+                    filter: defaults.manager_tls_cert.is_none() || !self.manager,
+                    message: "manager_tls_cert",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
+        // Set `manager_tls_ca` on Worker.
+        self.manager_tls_ca = self
+            .get_value(vec![
+                // Use --manager-tls-ca if configured.
+                GooseValue {
+                    value: Some(self.manager_tls_ca.to_string()),
+                    filter: self.manager_tls_ca.is_empty(),
+                    message: "manager_tls_ca",
+                },
+                // Otherwise use default if set and on Worker.
+                GooseValue {
+                    value: defaults.manager_tls_ca.clone(),
+                    filter: defaults.manager_tls_ca.is_none() || !self.worker,
+                    message: "manager_tls_ca",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
+        // Set `gaggle_key` on Manager and Worker.
+        self.gaggle_key = self
+            .get_value(vec![
+                // Use --gaggle-key if configured.
+                GooseValue {
+                    value: Some(self.gaggle_key.to_string()),
+                    filter: self.gaggle_key.is_empty(),
+                    message: "gaggle_key",
+                },
+                // Otherwise use default if set and on Manager or Worker.
+                GooseValue {
+                    value: defaults.gaggle_key.clone(),
+                    filter: defaults.gaggle_key.is_none() || !(self.manager || self.worker),
+                    message: "gaggle_key",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
     }
 
     /// Validate configured [`GooseConfiguration`] values.
@@ -1824,6 +4605,48 @@ impl GooseConfiguration {
                     detail: "`configuration.no_debug_body` can not be set on the Manager."
                         .to_string(),
                 });
+            } else if let Some(debug_log_body_limit) = self.debug_log_body_limit {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.debug_log_body_limit`".to_string(),
+                    value: debug_log_body_limit.to_string(),
+                    detail: "`configuration.debug_log_body_limit` can not be set on the Manager."
+                        .to_string(),
+                });
+            } else if !self.debug_log_body_status.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.debug_log_body_status`".to_string(),
+                    value: self.debug_log_body_status.to_string(),
+                    detail: "`configuration.debug_log_body_status` can not be set on the Manager."
+                        .to_string(),
+                });
+            } else if !self.redact_headers.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.redact_headers`".to_string(),
+                    value: self.redact_headers.to_string(),
+                    detail: "`configuration.redact_headers` can not be set on the Manager."
+                        .to_string(),
+                });
+            } else if self.no_redact_headers {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_redact_headers`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.no_redact_headers` can not be set on the Manager."
+                        .to_string(),
+                });
+            } else if self.continue_without_logs {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.continue_without_logs`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.continue_without_logs` can not be set on the Manager."
+                        .to_string(),
+                });
+            } else if let Some(log_failure) = self.log_failure.as_ref() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.log_failure`".to_string(),
+                    value: format!("{:?}", log_failure),
+                    detail: "`configuration.log_failure` can not be set on the Manager."
+                        .to_string(),
+                });
             // Can not set `throttle_requests` on Manager.
             } else if self.throttle_requests > 0 {
                 return Err(GooseError::InvalidOption {
@@ -1877,6 +4700,94 @@ impl GooseConfiguration {
                         .to_string(),
                 });
             }
+            // Don't allow `worker_timeout` if not running as Manager.
+            if let Some(worker_timeout) = self.worker_timeout {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.worker_timeout`".to_string(),
+                    value: worker_timeout.to_string(),
+                    detail: "`configuration.worker_timeout` can not be set unless on the Manager."
+                        .to_string(),
+                });
+            }
+            // Don't allow `redistribute_users` if not running as Manager.
+            if self.redistribute_users {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.redistribute_users`".to_string(),
+                    value: true.to_string(),
+                    detail:
+                        "`configuration.redistribute_users` can not be set unless on the Manager."
+                            .to_string(),
+                });
+            }
+            // Don't allow `manager_tls_cert` if not running as Manager.
+            if !self.manager_tls_cert.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.manager_tls_cert`".to_string(),
+                    value: self.manager_tls_cert.to_string(),
+                    detail:
+                        "`configuration.manager_tls_cert` can not be set unless on the Manager."
+                            .to_string(),
+                });
+            }
+        }
+
+        // Don't allow `manager_tls_ca` if not running as Worker.
+        if !self.worker && !self.manager_tls_ca.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.manager_tls_ca`".to_string(),
+                value: self.manager_tls_ca.to_string(),
+                detail: "`configuration.manager_tls_ca` can not be set unless on a Worker."
+                    .to_string(),
+            });
+        }
+
+        // Don't allow `gaggle_key` if not running in a Gaggle.
+        if !self.manager && !self.worker && !self.gaggle_key.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.gaggle_key`".to_string(),
+                value: self.gaggle_key.to_string(),
+                detail:
+                    "`configuration.gaggle_key` can not be set unless on the Manager or a Worker."
+                        .to_string(),
+            });
+        }
+
+        // Workers send the gaggle key when they connect, so it must be encrypted with TLS.
+        if !self.gaggle_key.is_empty()
+            && ((self.manager && self.manager_tls_cert.is_empty())
+                || (self.worker && self.manager_tls_ca.is_empty()))
+        {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.gaggle_key`".to_string(),
+                value: self.gaggle_key.to_string(),
+                detail: "`configuration.gaggle_key` requires TLS, enabled with `configuration.manager_tls_cert` on the Manager and `configuration.manager_tls_ca` on Workers.".to_string(),
+            });
+        }
+
+        // The Gaggle can only be secured with TLS when nng is built with TLS support.
+        #[cfg(not(feature = "gaggle-tls"))]
+        if !self.manager_tls_cert.is_empty() || !self.manager_tls_ca.is_empty() {
+            return Err(GooseError::FeatureNotEnabled {
+                feature: "gaggle-tls".to_string(),
+                detail: "Load test must be recompiled with `--features gaggle-tls` to secure the Gaggle with TLS.".to_string(),
+            });
+        }
+
+        // The TLS certificate and key, and the CA, must be readable.
+        if !self.manager_tls_cert.is_empty() && std::fs::File::open(&self.manager_tls_cert).is_err()
+        {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.manager_tls_cert`".to_string(),
+                value: self.manager_tls_cert.to_string(),
+                detail: "`configuration.manager_tls_cert` must be a readable file containing a certificate and private key.".to_string(),
+            });
+        }
+        if !self.manager_tls_ca.is_empty() && std::fs::File::open(&self.manager_tls_ca).is_err() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.manager_tls_ca`".to_string(),
+                value: self.manager_tls_ca.to_string(),
+                detail: "`configuration.manager_tls_ca` must be a readable file containing a CA certificate.".to_string(),
+            });
         }
 
         // Validate nothing incompatible is enabled with --worker.
@@ -1941,6 +4852,22 @@ impl GooseConfiguration {
                     detail: "`configuration.no_task_metrics` can not be set in Worker mode."
                         .to_string(),
                 });
+            // Can't set `no_histograms` on Worker.
+            } else if self.no_histograms {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_histograms".to_string(),
+                    value: self.no_histograms.to_string(),
+                    detail: "`configuration.no_histograms` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can't set `no_time_series` on Worker.
+            } else if self.no_time_series {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_time_series".to_string(),
+                    value: self.no_time_series.to_string(),
+                    detail: "`configuration.no_time_series` can not be set in Worker mode."
+                        .to_string(),
+                });
             // Can't set `no_error_summary` on Worker.
             } else if self.no_error_summary {
                 return Err(GooseError::InvalidOption {
@@ -1957,20 +4884,59 @@ impl GooseConfiguration {
                     detail: "`configuration.status_codes` can not be set in Worker mode."
                         .to_string(),
                 });
-            // Can't set `no_autostart` on Worker.
-            } else if self.no_autostart {
+            // Can't set `no_autostart` on Worker.
+            } else if self.no_autostart {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_autostart`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.no_autostart` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can't set `no_gzip` on Worker.
+            } else if self.no_gzip {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_gzip`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.no_gzip` can not be set in Worker mode.".to_string(),
+                });
+            // Can't set `no_tls_resumption` on Worker.
+            } else if self.no_tls_resumption {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_tls_resumption`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.no_tls_resumption` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can't set `resolve_hosts` on Worker.
+            } else if self.resolve_hosts {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.no_autostart`".to_string(),
+                    option: "`configuration.resolve_hosts`".to_string(),
                     value: true.to_string(),
-                    detail: "`configuration.no_autostart` can not be set in Worker mode."
+                    detail: "`configuration.resolve_hosts` can not be set in Worker mode."
                         .to_string(),
                 });
-            // Can't set `no_gzip` on Worker.
-            } else if self.no_gzip {
+            // Can't set `warm_up` on Worker.
+            } else if self.warm_up > 0 {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.no_gzip`".to_string(),
-                    value: true.to_string(),
-                    detail: "`configuration.no_gzip` can not be set in Worker mode.".to_string(),
+                    option: "`configuration.warm_up`".to_string(),
+                    value: self.warm_up.to_string(),
+                    detail: "`configuration.warm_up` can not be set in Worker mode.".to_string(),
+                });
+            // Can't set `ssh_jump_host` on Worker.
+            } else if !self.ssh_jump_host.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.ssh_jump_host`".to_string(),
+                    value: self.ssh_jump_host.to_string(),
+                    detail: "`configuration.ssh_jump_host` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can't set `ssh_identity` on Worker.
+            } else if !self.ssh_identity.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.ssh_identity`".to_string(),
+                    value: self.ssh_identity.to_string(),
+                    detail: "`configuration.ssh_identity` can not be set in Worker mode."
+                        .to_string(),
                 });
             } else if self
                 .co_mitigation
@@ -1984,6 +4950,21 @@ impl GooseConfiguration {
                     detail: "`configuration.co_mitigation` can not be set in Worker mode."
                         .to_string(),
                 });
+            // Can't set `co_cadence` on Worker.
+            } else if self.co_cadence > 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.co_cadence`".to_string(),
+                    value: self.co_cadence.to_string(),
+                    detail: "`configuration.co_cadence` can not be set in Worker mode.".to_string(),
+                });
+            // Can't set `validation_workers` on Worker.
+            } else if self.validation_workers > 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.validation_workers`".to_string(),
+                    value: self.validation_workers.to_string(),
+                    detail: "`configuration.validation_workers` can not be set in Worker mode."
+                        .to_string(),
+                });
             // Can't set `manager_bind_host` on Worker.
             } else if !self.manager_bind_host.is_empty() {
                 return Err(GooseError::InvalidOption {
@@ -2024,6 +5005,99 @@ impl GooseConfiguration {
                     detail: "`configuration.sticky_follow` can not be set in Worker mode."
                         .to_string(),
                 });
+            // Can not set `variant_host` on Worker.
+            } else if !self.variant_host.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.variant_host`".to_string(),
+                    value: self.variant_host.to_string(),
+                    detail: "`configuration.variant_host` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `variant_header` on Worker.
+            } else if !self.variant_header.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.variant_header`".to_string(),
+                    value: self.variant_header.to_string(),
+                    detail: "`configuration.variant_header` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `cache_buster` on Worker.
+            } else if !self.cache_buster.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cache_buster`".to_string(),
+                    value: self.cache_buster.to_string(),
+                    detail: "`configuration.cache_buster` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `cache_buster_names` on Worker.
+            } else if !self.cache_buster_names.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cache_buster_names`".to_string(),
+                    value: self.cache_buster_names.to_string(),
+                    detail: "`configuration.cache_buster_names` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `accept_encoding` on Worker.
+            } else if !self.accept_encoding.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.accept_encoding`".to_string(),
+                    value: self.accept_encoding.to_string(),
+                    detail: "`configuration.accept_encoding` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `body_sizes` on Worker.
+            } else if self.body_sizes {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.body_sizes`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.body_sizes` can not be set in Worker mode.".to_string(),
+                });
+            // Can not set `cache_status` on Worker.
+            } else if self.cache_status {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cache_status`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.cache_status` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `device_profiles` on Worker.
+            } else if !self.device_profiles.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.device_profiles`".to_string(),
+                    value: self.device_profiles.to_string(),
+                    detail: "`configuration.device_profiles` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `cohorts` on Worker.
+            } else if !self.cohorts.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cohorts`".to_string(),
+                    value: self.cohorts.to_string(),
+                    detail: "`configuration.cohorts` can not be set in Worker mode.".to_string(),
+                });
+            // Can not set `cohort_header` on Worker.
+            } else if !self.cohort_header.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cohort_header`".to_string(),
+                    value: self.cohort_header.to_string(),
+                    detail: "`configuration.cohort_header` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `client_certs` on Worker.
+            } else if !self.client_certs.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.client_certs`".to_string(),
+                    value: self.client_certs.to_string(),
+                    detail: "`configuration.client_certs` can not be set in Worker mode."
+                        .to_string(),
+                });
+            // Can not set `plan_file` on Worker.
+            } else if !self.plan_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.plan_file`".to_string(),
+                    value: self.plan_file.to_string(),
+                    detail: "`configuration.plan_file` can not be set in Worker mode.".to_string(),
+                });
             // Can not set `no_hash_check` on Worker.
             } else if self.no_hash_check {
                 return Err(GooseError::InvalidOption {
@@ -2046,6 +5120,115 @@ impl GooseConfiguration {
             }
         }
 
+        // If set, stop rate must be non-zero, and users are only stopped gradually when not
+        // in Gaggle mode.
+        if let Some(stop_rate) = self.stop_rate.as_ref() {
+            if util::get_hatch_rate(Some(stop_rate.to_string())) <= 0.0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.stop_rate`".to_string(),
+                    value: stop_rate.to_string(),
+                    detail: "`configuration.stop_rate` must be greater than 0.".to_string(),
+                });
+            } else if self.manager || self.worker {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.stop_rate`".to_string(),
+                    value: stop_rate.to_string(),
+                    detail: "`configuration.stop_rate` can not be set in Gaggle mode.".to_string(),
+                });
+            }
+        }
+
+        // Live metrics are only served for Prometheus when not in Gaggle mode.
+        if self.prometheus_port > 0 && (self.manager || self.worker) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.prometheus_port`".to_string(),
+                value: self.prometheus_port.to_string(),
+                detail: "`configuration.prometheus_port` can not be set in Gaggle mode."
+                    .to_string(),
+            });
+        }
+
+        // Request metrics are only sent to StatsD when not in Gaggle mode.
+        if !self.statsd_host.is_empty() && (self.manager || self.worker) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.statsd_host`".to_string(),
+                value: self.statsd_host.to_string(),
+                detail: "`configuration.statsd_host` can not be set in Gaggle mode.".to_string(),
+            });
+        }
+
+        // The network round-trip time is only measured when not in Gaggle mode, as each
+        // Worker would measure from a different location.
+        if self.rtt_samples > 0 && (self.manager || self.worker) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.rtt_samples`".to_string(),
+                value: self.rtt_samples.to_string(),
+                detail: "`configuration.rtt_samples` can not be set in Gaggle mode.".to_string(),
+            });
+        }
+
+        // Requests are only streamed to InfluxDB when not in Gaggle mode.
+        if !self.influxdb_url.is_empty() && (self.manager || self.worker) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.influxdb_url`".to_string(),
+                value: self.influxdb_url.to_string(),
+                detail: "`configuration.influxdb_url` can not be set in Gaggle mode.".to_string(),
+            });
+        }
+
+        // An InfluxDB write URL must be a valid URL.
+        if !self.influxdb_url.is_empty() {
+            util::is_valid_host(&self.influxdb_url)?;
+        }
+
+        // Validate `influxdb_token`.
+        if !self.influxdb_token.is_empty() && self.influxdb_url.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.influxdb_token`".to_string(),
+                value: self.influxdb_token.to_string(),
+                detail:
+                    "`configuration.influxdb_token` requires `configuration.influxdb_url` be set."
+                        .to_string(),
+            });
+        }
+
+        // Validate `influxdb_batch_size`.
+        if let Some(influxdb_batch_size) = self.influxdb_batch_size {
+            if self.influxdb_url.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.influxdb_batch_size`".to_string(),
+                    value: influxdb_batch_size.to_string(),
+                    detail: "`configuration.influxdb_batch_size` requires `configuration.influxdb_url` be set.".to_string(),
+                });
+            } else if influxdb_batch_size == 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.influxdb_batch_size`".to_string(),
+                    value: influxdb_batch_size.to_string(),
+                    detail: "`configuration.influxdb_batch_size` must be set to at least 1."
+                        .to_string(),
+                });
+            }
+        }
+
+        // Validate `influxdb_flush_interval`.
+        if let Some(influxdb_flush_interval) = self.influxdb_flush_interval {
+            if self.influxdb_url.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.influxdb_flush_interval`".to_string(),
+                    value: influxdb_flush_interval.to_string(),
+                    detail: "`configuration.influxdb_flush_interval` requires `configuration.influxdb_url` be set.".to_string(),
+                });
+            } else if influxdb_flush_interval == 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.influxdb_flush_interval`".to_string(),
+                    value: influxdb_flush_interval.to_string(),
+                    detail:
+                        "`configuration.influxdb_flush_interval` must be set to at least 1 second."
+                            .to_string(),
+                });
+            }
+        }
+
         // Validate `users`.
         if let Some(users) = self.users.as_ref() {
             if users == &0 {
@@ -2057,81 +5240,398 @@ impl GooseConfiguration {
             }
         }
 
-        // Validate `startup_time`.
-        if self.startup_time != "0" {
-            // Startup time can't be set with hatch rate.
-            if self.hatch_rate.is_some() {
+        // Validate `startup_time`.
+        if self.startup_time != "0" {
+            // Startup time can't be set with hatch rate.
+            if self.hatch_rate.is_some() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.startup_time`".to_string(),
+                    value: self.startup_time.to_string(),
+                    detail: "`configuration.startup_time` can not be set with `configuration.hatch_rate`.".to_string(),
+                });
+            }
+
+            // Startup time requires at least 2 users.
+            if let Some(users) = self.users.as_ref() {
+                if users < &2 {
+                    return Err(GooseError::InvalidOption {
+                        option: "configuration.users".to_string(),
+                        value: users.to_string(),
+                        detail: "`configuration.users` must be set to at least 2 when `configuration.startup_time` is set.".to_string(),
+                    });
+                }
+            }
+        }
+
+        // Validate `no_metrics`.
+        if self.no_metrics {
+            // Status codes are not collected if metrics are disabled.
+            if self.status_codes {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.no_metrics`".to_string(),
+                    value: true.to_string(),
+                    detail: "`configuration.no_metrics` can not be set with `configuration.status_codes`.".to_string(),
+                });
+            // Request log can't be written if metrics are disabled.
+            } else if !self.request_log.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.request_log`".to_string(),
+                    value: self.request_log.to_string(),
+                    detail: "`configuration.request_log` can not be set with `configuration.no_metrics`.".to_string(),
+                });
+            // Task log can't be written if metrics are disabled.
+            } else if !self.task_log.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.task_log`".to_string(),
+                    value: self.task_log.to_string(),
+                    detail:
+                        "`configuration.task_log` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Error log can't be written if metrics are disabled.
+            } else if !self.error_log.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.error_log`".to_string(),
+                    value: self.error_log.to_string(),
+                    detail:
+                        "`configuration.error_log` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Report file can't be written if metrics are disabled.
+            } else if !self.report_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.report_file`".to_string(),
+                    value: self.report_file.to_string(),
+                    detail:
+                        "`configuration.report_file` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Metrics checkpoints can't be saved or resumed if metrics are disabled.
+            } else if !self.checkpoint_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.checkpoint_file`".to_string(),
+                    value: self.checkpoint_file.to_string(),
+                    detail: "`configuration.checkpoint_file` can not be set with `configuration.no_metrics`."
+                        .to_string(),
+                });
+            } else if !self.resume.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.resume`".to_string(),
+                    value: self.resume.to_string(),
+                    detail:
+                        "`configuration.resume` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Metrics can't be compared with a baseline if metrics are disabled.
+            } else if !self.baseline_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.baseline_file`".to_string(),
+                    value: self.baseline_file.to_string(),
+                    detail: "`configuration.baseline_file` can not be set with `configuration.no_metrics`."
+                        .to_string(),
+                });
+            // Request metrics can't be sent to StatsD if metrics are disabled.
+            } else if !self.statsd_host.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.statsd_host`".to_string(),
+                    value: self.statsd_host.to_string(),
+                    detail:
+                        "`configuration.statsd_host` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Requests can't be streamed to InfluxDB if metrics are disabled.
+            } else if !self.influxdb_url.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.influxdb_url`".to_string(),
+                    value: self.influxdb_url.to_string(),
+                    detail:
+                        "`configuration.influxdb_url` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // The network round-trip time is reported with the metrics.
+            } else if self.rtt_samples > 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.rtt_samples`".to_string(),
+                    value: self.rtt_samples.to_string(),
+                    detail:
+                        "`configuration.rtt_samples` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            // Coordinated Omission Mitigation can't be enabled if metrics are disabled.
+            } else if self.co_mitigation.as_ref().unwrap()
+                != &GooseCoordinatedOmissionMitigation::Disabled
+            {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.co_mitigation`".to_string(),
+                    value: format!("{:?}", self.co_mitigation.as_ref().unwrap()),
+                    detail: "`configuration.co_mitigation` can not be set with `configuration.no_metrircs`."
+                        .to_string(),
+                });
+            // Requests can't be backfilled if metrics are disabled.
+            } else if self.co_cadence > 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.co_cadence`".to_string(),
+                    value: self.co_cadence.to_string(),
+                    detail:
+                        "`configuration.co_cadence` can not be set with `configuration.no_metrics`."
+                            .to_string(),
+                });
+            }
+        }
+
+        // TLS sessions can only be controlled when using rustls.
+        #[cfg(not(feature = "rustls-tls"))]
+        if self.no_tls_resumption {
+            return Err(GooseError::FeatureNotEnabled {
+                feature: "rustls-tls".to_string(),
+                detail: "Load test must be recompiled with `--features rustls-tls` to disable TLS session resumption.".to_string(),
+            });
+        }
+
+        // Interim reports are written to the report file.
+        if let Some(report_interval) = self.report_interval {
+            if self.report_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.report_interval`".to_string(),
+                    value: report_interval.to_string(),
+                    detail: "`configuration.report_interval` requires `configuration.report_file` be set.".to_string(),
+                });
+            } else if report_interval == 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.report_interval`".to_string(),
+                    value: report_interval.to_string(),
+                    detail: "`configuration.report_interval` must be set to at least 1 minute."
+                        .to_string(),
+                });
+            }
+        } else if self.report_timestamp {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.report_timestamp`".to_string(),
+                value: true.to_string(),
+                detail: "`configuration.report_timestamp` requires `configuration.report_interval` be set.".to_string(),
+            });
+        }
+
+        // Metrics checkpoints are only supported by single-process load tests.
+        if self.manager || self.worker {
+            if !self.checkpoint_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.checkpoint_file`".to_string(),
+                    value: self.checkpoint_file.to_string(),
+                    detail: "`configuration.checkpoint_file` can not be set in Gaggle mode."
+                        .to_string(),
+                });
+            } else if !self.resume.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.resume`".to_string(),
+                    value: self.resume.to_string(),
+                    detail: "`configuration.resume` can not be set in Gaggle mode.".to_string(),
+                });
+            }
+        }
+
+        // Metrics are only compared with a baseline by single-process load tests.
+        if (self.manager || self.worker) && !self.baseline_file.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.baseline_file`".to_string(),
+                value: self.baseline_file.to_string(),
+                detail: "`configuration.baseline_file` can not be set in Gaggle mode.".to_string(),
+            });
+        }
+        if let Some(checkpoint_interval) = self.checkpoint_interval {
+            if self.checkpoint_file.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.checkpoint_interval`".to_string(),
+                    value: checkpoint_interval.to_string(),
+                    detail: "`configuration.checkpoint_interval` requires `configuration.checkpoint_file` be set.".to_string(),
+                });
+            } else if checkpoint_interval == 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.checkpoint_interval`".to_string(),
+                    value: checkpoint_interval.to_string(),
+                    detail: "`configuration.checkpoint_interval` must be set to at least 1 second."
+                        .to_string(),
+                });
+            }
+        }
+
+        // A variant host must be a valid URL, and a variant header must be formatted as
+        // "NAME: VALUE".
+        if !self.variant_host.is_empty() {
+            util::is_valid_host(&self.variant_host)?;
+        }
+        if !self.variant_header.is_empty() && util::parse_header(&self.variant_header).is_none() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.variant_header`".to_string(),
+                value: self.variant_header.to_string(),
+                detail: "`configuration.variant_header` must be formatted as \"NAME: VALUE\"."
+                    .to_string(),
+            });
+        }
+        if let Some(variant_percent) = self.variant_percent {
+            if self.variant_host.is_empty() && self.variant_header.is_empty() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.variant_percent`".to_string(),
+                    value: variant_percent.to_string(),
+                    detail: "`configuration.variant_percent` requires `configuration.variant_host` or `configuration.variant_header` be set.".to_string(),
+                });
+            } else if variant_percent > 100 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.variant_percent`".to_string(),
+                    value: variant_percent.to_string(),
+                    detail: "`configuration.variant_percent` can not be more than 100.".to_string(),
+                });
+            }
+        }
+
+        // A cache buster must be formatted as "NAME={}" or "NAME: {}", and only busting
+        // caches of some requests requires a cache buster.
+        if !self.cache_buster.is_empty() {
+            if let Err(detail) = util::validate_cache_buster(&self.cache_buster) {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cache_buster`".to_string(),
+                    value: self.cache_buster.to_string(),
+                    detail,
+                });
+            }
+        } else if !self.cache_buster_names.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.cache_buster_names`".to_string(),
+                value: self.cache_buster_names.to_string(),
+                detail: "`configuration.cache_buster_names` requires `configuration.cache_buster` be set.".to_string(),
+            });
+        }
+
+        // Device profiles must be formatted as "PROFILE:PERCENT,...".
+        if !self.device_profiles.is_empty() {
+            if let Err(detail) = device::parse_device_profiles(&self.device_profiles) {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.startup_time`".to_string(),
-                    value: self.startup_time.to_string(),
-                    detail: "`configuration.startup_time` can not be set with `configuration.hatch_rate`.".to_string(),
+                    option: "`configuration.device_profiles`".to_string(),
+                    value: self.device_profiles.to_string(),
+                    detail,
                 });
             }
+        }
 
-            // Startup time requires at least 2 users.
-            if let Some(users) = self.users.as_ref() {
-                if users < &2 {
-                    return Err(GooseError::InvalidOption {
-                        option: "configuration.users".to_string(),
-                        value: users.to_string(),
-                        detail: "`configuration.users` must be set to at least 2 when `configuration.startup_time` is set.".to_string(),
-                    });
-                }
+        // Cohorts must be formatted as "NAME:PERCENT,...".
+        if !self.cohorts.is_empty() {
+            if let Err(detail) = cohort::parse_cohorts(&self.cohorts) {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.cohorts`".to_string(),
+                    value: self.cohorts.to_string(),
+                    detail,
+                });
             }
         }
 
-        // Validate `no_metrics`.
-        if self.no_metrics {
-            // Status codes are not collected if metrics are disabled.
-            if self.status_codes {
+        // The cohort header must be a valid header name, and requires cohorts be set.
+        if !self.cohort_header.is_empty() {
+            if self.cohorts.is_empty() {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.no_metrics`".to_string(),
-                    value: true.to_string(),
-                    detail: "`configuration.no_metrics` can not be set with `configuration.status_codes`.".to_string(),
+                    option: "`configuration.cohort_header`".to_string(),
+                    value: self.cohort_header.to_string(),
+                    detail:
+                        "`configuration.cohort_header` requires `configuration.cohorts` be set."
+                            .to_string(),
                 });
-            // Request log can't be written if metrics are disabled.
-            } else if !self.request_log.is_empty() {
+            } else if let Err(detail) = cohort::validate_cohort_header(&self.cohort_header) {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.request_log`".to_string(),
-                    value: self.request_log.to_string(),
-                    detail: "`configuration.request_log` can not be set with `configuration.no_metrics`.".to_string(),
+                    option: "`configuration.cohort_header`".to_string(),
+                    value: self.cohort_header.to_string(),
+                    detail,
                 });
-            // Task log can't be written if metrics are disabled.
-            } else if !self.task_log.is_empty() {
+            }
+        }
+
+        // Client certificates must be found, and loaded, from a directory or manifest.
+        if !self.client_certs.is_empty() {
+            if let Err(detail) = identity::validate_client_certs(&self.client_certs) {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.task_log`".to_string(),
-                    value: self.task_log.to_string(),
-                    detail:
-                        "`configuration.task_log` can not be set with `configuration.no_metrics`."
-                            .to_string(),
+                    option: "`configuration.client_certs`".to_string(),
+                    value: self.client_certs.to_string(),
+                    detail,
                 });
-            // Error log can't be written if metrics are disabled.
-            } else if !self.error_log.is_empty() {
+            }
+        }
+
+        // The SSH jump host must be formatted as [USER@]HOST[:PORT], and authenticating with a
+        // private key requires a jump host.
+        if !self.ssh_jump_host.is_empty() {
+            if let Err(detail) = ssh::jump_host_args(&self.ssh_jump_host) {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.error_log`".to_string(),
-                    value: self.error_log.to_string(),
-                    detail:
-                        "`configuration.error_log` can not be set with `configuration.no_metrics`."
-                            .to_string(),
+                    option: "`configuration.ssh_jump_host`".to_string(),
+                    value: self.ssh_jump_host.to_string(),
+                    detail,
                 });
-            // Report file can't be written if metrics are disabled.
-            } else if !self.report_file.is_empty() {
+            }
+        }
+        if !self.ssh_identity.is_empty() {
+            if self.ssh_jump_host.is_empty() {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.report_file`".to_string(),
-                    value: self.report_file.to_string(),
-                    detail:
-                        "`configuration.report_file` can not be set with `configuration.no_metrics`."
-                            .to_string(),
+                    option: "`configuration.ssh_identity`".to_string(),
+                    value: self.ssh_identity.to_string(),
+                    detail: "`configuration.ssh_identity` requires `configuration.ssh_jump_host` be set.".to_string(),
                 });
-            // Coordinated Omission Mitigation can't be enabled if metrics are disabled.
-            } else if self.co_mitigation.as_ref().unwrap()
-                != &GooseCoordinatedOmissionMitigation::Disabled
-            {
+            } else if !std::path::Path::new(&self.ssh_identity).is_file() {
                 return Err(GooseError::InvalidOption {
-                    option: "`configuration.co_mitigation`".to_string(),
-                    value: format!("{:?}", self.co_mitigation.as_ref().unwrap()),
-                    detail: "`configuration.co_mitigation` can not be set with `configuration.no_metrircs`."
+                    option: "`configuration.ssh_identity`".to_string(),
+                    value: self.ssh_identity.to_string(),
+                    detail: "`configuration.ssh_identity` must be an existing private key file."
+                        .to_string(),
+                });
+            }
+        }
+
+        // Through an SSH tunnel only the round-trip time to the local end of the tunnel could
+        // be measured.
+        if self.rtt_samples > 0 && !self.ssh_jump_host.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.rtt_samples`".to_string(),
+                value: self.rtt_samples.to_string(),
+                detail:
+                    "`configuration.rtt_samples` can not be set with `configuration.ssh_jump_host`."
                         .to_string(),
+            });
+        }
+
+        // Recording a browser session doesn't run a load test, so isn't possible in a Gaggle.
+        if !self.record.is_empty() && (self.manager || self.worker) {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.record`".to_string(),
+                value: self.record.to_string(),
+                detail: "`configuration.record` can not be set on the Manager or a Worker."
+                    .to_string(),
+            });
+        }
+
+        // The Accept-Encoding header must be a valid header value.
+        if !self.accept_encoding.is_empty()
+            && reqwest::header::HeaderValue::from_str(&self.accept_encoding).is_err()
+        {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.accept_encoding`".to_string(),
+                value: self.accept_encoding.to_string(),
+                detail: "`configuration.accept_encoding` must be a valid header value.".to_string(),
+            });
+        }
+
+        // The run ID is sent in a header, so must be a valid header value.
+        if !self.run_id.is_empty() && reqwest::header::HeaderValue::from_str(&self.run_id).is_err()
+        {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.run_id`".to_string(),
+                value: self.run_id.to_string(),
+                detail: "`configuration.run_id` must be a valid header value.".to_string(),
+            });
+        }
+
+        // Labels must be formatted as "KEY=VALUE".
+        for label in &self.label {
+            if util::parse_label(label).is_none() {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.label`".to_string(),
+                    value: label.to_string(),
+                    detail: "`configuration.label` must be formatted as \"KEY=VALUE\".".to_string(),
                 });
             }
         }
@@ -2146,6 +5646,22 @@ impl GooseConfiguration {
             });
         }
 
+        // The expected cadence replaces the cadence Coordinated Omission Mitigation calculates.
+        if self.co_cadence > 0
+            && self
+                .co_mitigation
+                .as_ref()
+                .unwrap_or(&GooseCoordinatedOmissionMitigation::Disabled)
+                != &GooseCoordinatedOmissionMitigation::Disabled
+        {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.co_cadence`".to_string(),
+                value: self.co_cadence.to_string(),
+                detail: "`configuration.co_cadence` can not be set together with `configuration.co_mitigation`."
+                    .to_string(),
+            });
+        }
+
         /* @TODO:
         if let Some(co_mitigation) = self.co_mitigation.as_ref() {
             if co_mitigation != &GooseCoordinatedOmissionMitigation::Disabled
@@ -2165,6 +5681,23 @@ impl GooseConfiguration {
         }
         */
 
+        // If set, replay speed must be a number greater than 0.
+        if let Some(replay_speed) = self.replay_speed.as_ref() {
+            if !replay_speed
+                .parse::<f32>()
+                .map(|speed| speed.is_finite() && speed > 0.0)
+                .unwrap_or(false)
+            {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.replay_speed`".to_string(),
+                    value: replay_speed.to_string(),
+                    detail:
+                        "`configuration.replay_speed` must be a number greater than 0, ie 2 or 0.5."
+                            .to_string(),
+                });
+            }
+        }
+
         if self.throttle_requests > 0 {
             // Be sure throttle_requests is in allowed range.
             if self.throttle_requests == 0 {
@@ -2182,18 +5715,204 @@ impl GooseConfiguration {
             }
         }
 
+        // The adaptive throttle never exceeds the maximum set with `--throttle-requests`.
+        if self.adaptive_throttle && self.throttle_requests == 0 {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.adaptive_throttle`".to_string(),
+                value: self.adaptive_throttle.to_string(),
+                detail:
+                    "`configuration.adaptive_throttle` requires `configuration.throttle_requests`."
+                        .to_string(),
+            });
+        }
+
+        // The test plan controls how many users are running and for how long.
+        if !self.test_plan.is_empty() {
+            let test_plan = GooseTestPlan::parse(&self.test_plan)?;
+            let invalid = |detail: &str| GooseError::InvalidOption {
+                option: "`configuration.test_plan`".to_string(),
+                value: self.test_plan.to_string(),
+                detail: detail.to_string(),
+            };
+            if self.manager || self.worker {
+                return Err(invalid(
+                    "`configuration.test_plan` can not be set in Gaggle mode.",
+                ));
+            } else if self.users != Some(test_plan.max_users()) {
+                return Err(invalid(
+                    "`configuration.users` must match the most users in `configuration.test_plan`.",
+                ));
+            } else if self.hatch_rate.is_some() {
+                return Err(invalid(
+                    "`configuration.test_plan` can not be set together with `configuration.hatch_rate`.",
+                ));
+            } else if self.startup_time != "0" {
+                return Err(invalid(
+                    "`configuration.test_plan` can not be set together with `configuration.startup_time`.",
+                ));
+            } else if self.run_time != "0" {
+                return Err(invalid(
+                    "`configuration.test_plan` can not be set together with `configuration.run_time`.",
+                ));
+            }
+        }
+
+        // A ramp-hold-ramp profile follows its own steps, the same as a test plan.
+        for (option, value) in [
+            ("`configuration.ramp_up`", &self.ramp_up),
+            ("`configuration.hold`", &self.hold),
+            ("`configuration.ramp_down`", &self.ramp_down),
+        ] {
+            if util::parse_timespan(value) == 0 {
+                continue;
+            }
+            let invalid = |detail: String| GooseError::InvalidOption {
+                option: option.to_string(),
+                value: value.to_string(),
+                detail,
+            };
+            if self.manager || self.worker {
+                return Err(invalid(format!(
+                    "{} can not be set in Gaggle mode.",
+                    option
+                )));
+            } else if !self.test_plan.is_empty() {
+                return Err(invalid(format!(
+                    "{} can not be set together with `configuration.test_plan`.",
+                    option
+                )));
+            } else if self.hatch_rate.is_some() {
+                return Err(invalid(format!(
+                    "{} can not be set together with `configuration.hatch_rate`.",
+                    option
+                )));
+            } else if self.startup_time != "0" {
+                return Err(invalid(format!(
+                    "{} can not be set together with `configuration.startup_time`.",
+                    option
+                )));
+            } else if self.run_time != "0" {
+                return Err(invalid(format!(
+                    "{} can not be set together with `configuration.run_time`.",
+                    option
+                )));
+            }
+        }
+
+        // Response bodies can only be debug logged for valid status codes.
+        if logger::parse_status_codes(&self.debug_log_body_status).is_none() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.debug_log_body_status`".to_string(),
+                value: self.debug_log_body_status.to_string(),
+                detail: "`configuration.debug_log_body_status` must be a comma-separated list of status codes, ie 500,503.".to_string(),
+            });
+        }
+
+        // Headers can only be masked if they're valid header names.
+        if logger::parse_header_names(&self.redact_headers).is_none() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.redact_headers`".to_string(),
+                value: self.redact_headers.to_string(),
+                detail: "`configuration.redact_headers` must be a comma-separated list of header names, ie authorization,x-api-key.".to_string(),
+            });
+        }
+
+        // Only the request log can be written in the Apache combined log format.
+        for (option, format) in [
+            ("`configuration.debug_format`", &self.debug_format),
+            ("`configuration.error_format`", &self.error_format),
+            ("`configuration.task_format`", &self.task_format),
+        ] {
+            if format == &Some(GooseLogFormat::Combined) {
+                return Err(GooseError::InvalidOption {
+                    option: option.to_string(),
+                    value: "combined".to_string(),
+                    detail: format!(
+                        "{} can not be combined, only `configuration.request_format` can.",
+                        option
+                    ),
+                });
+            }
+        }
+
+        // Logs are rotated at a valid size, keeping at least one rotated file.
+        if !self.log_rotate_size.is_empty() && rotate::parse_size(&self.log_rotate_size).is_none() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.log_rotate_size`".to_string(),
+                value: self.log_rotate_size.to_string(),
+                detail: "`configuration.log_rotate_size` must be a size, ie 100K, 500M or 2G."
+                    .to_string(),
+            });
+        }
+        let rotating =
+            !self.log_rotate_size.is_empty() || util::parse_timespan(&self.log_rotate_interval) > 0;
+        if let Some(log_rotate_keep) = self.log_rotate_keep {
+            if !rotating {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.log_rotate_keep`".to_string(),
+                    value: log_rotate_keep.to_string(),
+                    detail: "`configuration.log_rotate_keep` requires `configuration.log_rotate_size` or `configuration.log_rotate_interval` be set.".to_string(),
+                });
+            } else if log_rotate_keep == 0 {
+                return Err(GooseError::InvalidOption {
+                    option: "`configuration.log_rotate_keep`".to_string(),
+                    value: log_rotate_keep.to_string(),
+                    detail: "`configuration.log_rotate_keep` must be set to at least 1."
+                        .to_string(),
+                });
+            }
+        }
+        if self.log_rotate_compress && !rotating {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.log_rotate_compress`".to_string(),
+                value: true.to_string(),
+                detail: "`configuration.log_rotate_compress` requires `configuration.log_rotate_size` or `configuration.log_rotate_interval` be set.".to_string(),
+            });
+        }
+
+        // Digits can only be grouped as in a known locale.
+        if !self.number_locale.is_empty() && Locale::from_name(&self.number_locale).is_err() {
+            return Err(GooseError::InvalidOption {
+                option: "`configuration.number_locale`".to_string(),
+                value: self.number_locale.to_string(),
+                detail:
+                    "`configuration.number_locale` must be the name of a locale, ie en, de or fr."
+                        .to_string(),
+            });
+        }
+
+        // Running metrics are only printed with verbose output.
+        if let Some(output) = self.output {
+            if let Some(running_metrics) = self.running_metrics {
+                if output != GooseOutput::Verbose {
+                    return Err(GooseError::InvalidOption {
+                        option: "`configuration.running_metrics`".to_string(),
+                        value: running_metrics.to_string(),
+                        detail: format!(
+                            "`configuration.running_metrics` can not be set together with `configuration.output` {:?}.",
+                            output
+                        ),
+                    });
+                }
+            }
+        }
+
         Ok(())
     }
 
     /// Optionally initialize the Goose logger which writes to standard out and/or to
     /// a configurable log file.
     pub(crate) fn initialize_goose_logger(&self) {
-        // Configure debug output level.
-        let debug_level = match self.verbose {
-            0 => LevelFilter::Warn,
-            1 => LevelFilter::Info,
-            2 => LevelFilter::Debug,
-            _ => LevelFilter::Trace,
+        // Configure debug output level. Only errors are displayed, on stderr, if stdout
+        // is reserved for the final metrics as JSON or is quiet.
+        let debug_level = match self.output {
+            Some(GooseOutput::Json) | Some(GooseOutput::Quiet) => LevelFilter::Error,
+            _ => match self.verbose {
+                0 => LevelFilter::Warn,
+                1 => LevelFilter::Info,
+                2 => LevelFilter::Debug,
+                _ => LevelFilter::Trace,
+            },
         };
 
         // Configure Goose log level.
@@ -2255,16 +5974,46 @@ mod test {
         let goose_log = "custom-goose.log".to_string();
         let verbose: usize = 0;
         let report_file = "custom-goose-report.html".to_string();
+        let report_interval: usize = 10;
+        let checkpoint_file = "custom-goose-checkpoint.json".to_string();
+        let affinity_header = "x-backend".to_string();
+        let header_dimensions = "x-cache,cf-cache-status".to_string();
+        let labels = "build=1.2.3,env=staging".to_string();
+        let run_id = "nightly-42".to_string();
+        let statsd_host = "127.0.0.1:8125".to_string();
+        let influxdb_url = "http://localhost:8086/write?db=goose".to_string();
+        let influxdb_token = "secret".to_string();
+        let influxdb_batch_size: usize = 1_000;
+        let influxdb_flush_interval: usize = 5;
+        let plan_file = "plan.json".to_string();
+        let variant_host = "http://canary.example.com/".to_string();
+        let variant_header = "x-variant: canary".to_string();
+        let variant_percent: usize = 10;
+        let cache_buster = "cb={}".to_string();
+        let cache_buster_names = "Index,/about/".to_string();
+        let device_profiles = "desktop:60,mobile:35,bot:5".to_string();
+        let cohorts = "beta:10".to_string();
+        let cohort_header = "x-feature-flags".to_string();
+        let client_certs = "certs/".to_string();
+        let record = "recorded.rs".to_string();
+        let record_port: usize = 5128;
+        let replay_speed = "0.5".to_string();
+        let accept_encoding = "identity".to_string();
+        let checkpoint_interval: usize = 30;
         let request_log = "custom-goose-request.log".to_string();
         let task_log = "custom-goose-task.log".to_string();
         let debug_log = "custom-goose-debug.log".to_string();
         let error_log = "custom-goose-error.log".to_string();
         let throttle_requests: usize = 25;
+        let seed: usize = 42;
         let expect_workers: usize = 5;
         let manager_bind_host = "127.0.0.1".to_string();
         let manager_bind_port: usize = 1221;
         let manager_host = "127.0.0.1".to_string();
         let manager_port: usize = 1221;
+        let manager_tls_cert = "manager.pem".to_string();
+        let manager_tls_ca = "ca.pem".to_string();
+        let gaggle_key = "secret".to_string();
 
         let goose_attack = GooseAttack::initialize()
             .unwrap()
@@ -2276,6 +6025,8 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::HatchRate, hatch_rate.as_str())
             .unwrap()
+            .set_default(GooseDefault::StopRate, "2")
+            .unwrap()
             .set_default(GooseDefault::LogLevel, log_level)
             .unwrap()
             .set_default(GooseDefault::GooseLog, goose_log.as_str())
@@ -2284,12 +6035,18 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::RunningMetrics, 15)
             .unwrap()
+            .set_default(GooseDefault::Output, GooseOutput::Summary)
+            .unwrap()
             .set_default(GooseDefault::NoResetMetrics, true)
             .unwrap()
             .set_default(GooseDefault::NoMetrics, true)
             .unwrap()
             .set_default(GooseDefault::NoTaskMetrics, true)
             .unwrap()
+            .set_default(GooseDefault::NoHistograms, true)
+            .unwrap()
+            .set_default(GooseDefault::NoTimeSeries, true)
+            .unwrap()
             .set_default(GooseDefault::NoErrorSummary, true)
             .unwrap()
             .set_default(GooseDefault::NoTelnet, true)
@@ -2300,8 +6057,32 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::NoGzip, true)
             .unwrap()
+            .set_default(GooseDefault::NoTlsResumption, true)
+            .unwrap()
+            .set_default(GooseDefault::ResolveHosts, true)
+            .unwrap()
+            .set_default(GooseDefault::WarmUp, 4)
+            .unwrap()
+            .set_default(GooseDefault::RttSamples, 5)
+            .unwrap()
+            .set_default(GooseDefault::CoCadence, 250)
+            .unwrap()
+            .set_default(GooseDefault::ValidationWorkers, 2)
+            .unwrap()
+            .set_default(GooseDefault::SshJumpHost, "deploy@bastion.example.com:2222")
+            .unwrap()
+            .set_default(GooseDefault::SshIdentity, "id_ed25519")
+            .unwrap()
             .set_default(GooseDefault::ReportFile, report_file.as_str())
             .unwrap()
+            .set_default(GooseDefault::ReportInterval, report_interval)
+            .unwrap()
+            .set_default(GooseDefault::ReportTimestamp, true)
+            .unwrap()
+            .set_default(GooseDefault::CheckpointFile, checkpoint_file.as_str())
+            .unwrap()
+            .set_default(GooseDefault::CheckpointInterval, checkpoint_interval)
+            .unwrap()
             .set_default(GooseDefault::RequestLog, request_log.as_str())
             .unwrap()
             .set_default(GooseDefault::RequestFormat, GooseLogFormat::Raw)
@@ -2322,8 +6103,38 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::NoDebugBody, true)
             .unwrap()
+            .set_default(GooseDefault::DebugLogBodyLimit, 1024)
+            .unwrap()
+            .set_default(GooseDefault::DebugLogBodyStatus, "500,503")
+            .unwrap()
+            .set_default(GooseDefault::RedactHeaders, "x-api-key")
+            .unwrap()
+            .set_default(GooseDefault::NoRedactHeaders, true)
+            .unwrap()
+            .set_default(GooseDefault::ContinueWithoutLogs, true)
+            .unwrap()
+            .set_default(GooseDefault::LogFailure, GooseLogFailure::Stderr)
+            .unwrap()
+            .set_default(GooseDefault::LogRotateSize, "500M")
+            .unwrap()
+            .set_default(GooseDefault::LogRotateInterval, 3600)
+            .unwrap()
+            .set_default(GooseDefault::LogRotateKeep, 2)
+            .unwrap()
+            .set_default(GooseDefault::LogRotateCompress, true)
+            .unwrap()
             .set_default(GooseDefault::StatusCodes, true)
             .unwrap()
+            .set_default(GooseDefault::AffinityHeader, affinity_header.as_str())
+            .unwrap()
+            .set_default(GooseDefault::HeaderDimensions, header_dimensions.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Labels, labels.as_str())
+            .unwrap()
+            .set_default(GooseDefault::RunId, run_id.as_str())
+            .unwrap()
+            .set_default(GooseDefault::PlanFile, plan_file.as_str())
+            .unwrap()
             .set_default(
                 GooseDefault::CoordinatedOmissionMitigation,
                 GooseCoordinatedOmissionMitigation::Disabled,
@@ -2331,14 +6142,76 @@ mod test {
             .unwrap()
             .set_default(GooseDefault::ThrottleRequests, throttle_requests)
             .unwrap()
+            .set_default(GooseDefault::Seed, seed)
+            .unwrap()
             .set_default(GooseDefault::StickyFollow, true)
             .unwrap()
+            .set_default(GooseDefault::VariantHost, variant_host.as_str())
+            .unwrap()
+            .set_default(GooseDefault::VariantHeader, variant_header.as_str())
+            .unwrap()
+            .set_default(GooseDefault::VariantPercent, variant_percent)
+            .unwrap()
+            .set_default(GooseDefault::CacheBuster, cache_buster.as_str())
+            .unwrap()
+            .set_default(GooseDefault::CacheBusterNames, cache_buster_names.as_str())
+            .unwrap()
+            .set_default(GooseDefault::DeviceProfiles, device_profiles.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Cohorts, cohorts.as_str())
+            .unwrap()
+            .set_default(GooseDefault::CohortHeader, cohort_header.as_str())
+            .unwrap()
+            .set_default(GooseDefault::ClientCerts, client_certs.as_str())
+            .unwrap()
+            .set_default(GooseDefault::Record, record.as_str())
+            .unwrap()
+            .set_default(GooseDefault::RecordPort, record_port)
+            .unwrap()
+            .set_default(GooseDefault::PrometheusPort, 9090)
+            .unwrap()
+            .set_default(GooseDefault::StatsdHost, statsd_host.as_str())
+            .unwrap()
+            .set_default(GooseDefault::InfluxdbUrl, influxdb_url.as_str())
+            .unwrap()
+            .set_default(GooseDefault::InfluxdbToken, influxdb_token.as_str())
+            .unwrap()
+            .set_default(GooseDefault::InfluxdbBatchSize, influxdb_batch_size)
+            .unwrap()
+            .set_default(GooseDefault::InfluxdbFlushInterval, influxdb_flush_interval)
+            .unwrap()
+            .set_default(GooseDefault::ReplaySpeed, replay_speed.as_str())
+            .unwrap()
+            .set_default(GooseDefault::AcceptEncoding, accept_encoding.as_str())
+            .unwrap()
+            .set_default(GooseDefault::BodySizes, true)
+            .unwrap()
+            .set_default(GooseDefault::CacheStatus, true)
+            .unwrap()
+            .set_default(GooseDefault::NumberLocale, "de")
+            .unwrap()
+            .set_default(GooseDefault::TestPlan, "10,30s;0,30s")
+            .unwrap()
+            .set_default(GooseDefault::RampUp, 300)
+            .unwrap()
+            .set_default(GooseDefault::Hold, 1800)
+            .unwrap()
+            .set_default(GooseDefault::RampDown, 300)
+            .unwrap()
+            .set_default(GooseDefault::ScaleUnits, true)
+            .unwrap()
+            .set_default(GooseDefault::AdaptiveThrottle, true)
+            .unwrap()
             .set_default(GooseDefault::Manager, true)
             .unwrap()
             .set_default(GooseDefault::ExpectWorkers, expect_workers)
             .unwrap()
             .set_default(GooseDefault::NoHashCheck, true)
             .unwrap()
+            .set_default(GooseDefault::WorkerTimeout, 30)
+            .unwrap()
+            .set_default(GooseDefault::RedistributeUsers, true)
+            .unwrap()
             .set_default(GooseDefault::ManagerBindHost, manager_bind_host.as_str())
             .unwrap()
             .set_default(GooseDefault::ManagerBindPort, manager_bind_port)
@@ -2348,27 +6221,62 @@ mod test {
             .set_default(GooseDefault::ManagerHost, manager_host.as_str())
             .unwrap()
             .set_default(GooseDefault::ManagerPort, manager_port)
+            .unwrap()
+            .set_default(GooseDefault::ManagerTlsCert, manager_tls_cert.as_str())
+            .unwrap()
+            .set_default(GooseDefault::ManagerTlsCa, manager_tls_ca.as_str())
+            .unwrap()
+            .set_default(GooseDefault::GaggleKey, gaggle_key.as_str())
             .unwrap();
 
         assert!(goose_attack.defaults.host == Some(host));
         assert!(goose_attack.defaults.users == Some(users));
         assert!(goose_attack.defaults.run_time == Some(run_time));
         assert!(goose_attack.defaults.hatch_rate == Some(hatch_rate));
+        assert!(goose_attack.defaults.stop_rate == Some("2".to_string()));
         assert!(goose_attack.defaults.log_level == Some(log_level as u8));
         assert!(goose_attack.defaults.goose_log == Some(goose_log));
         assert!(goose_attack.defaults.request_body == Some(true));
         assert!(goose_attack.defaults.no_debug_body == Some(true));
+        assert!(goose_attack.defaults.debug_log_body_limit == Some(1024));
+        assert!(goose_attack.defaults.debug_log_body_status == Some("500,503".to_string()));
+        assert!(goose_attack.defaults.redact_headers == Some("x-api-key".to_string()));
+        assert!(goose_attack.defaults.no_redact_headers == Some(true));
+        assert!(goose_attack.defaults.continue_without_logs == Some(true));
+        assert!(goose_attack.defaults.log_failure == Some(GooseLogFailure::Stderr));
+        assert!(goose_attack.defaults.log_rotate_size == Some("500M".to_string()));
+        assert!(goose_attack.defaults.log_rotate_interval == Some(3600));
+        assert!(goose_attack.defaults.log_rotate_keep == Some(2));
+        assert!(goose_attack.defaults.log_rotate_compress == Some(true));
         assert!(goose_attack.defaults.verbose == Some(verbose as u8));
         assert!(goose_attack.defaults.running_metrics == Some(15));
+        assert!(goose_attack.defaults.output == Some(GooseOutput::Summary));
         assert!(goose_attack.defaults.no_reset_metrics == Some(true));
         assert!(goose_attack.defaults.no_metrics == Some(true));
         assert!(goose_attack.defaults.no_task_metrics == Some(true));
+        assert!(goose_attack.defaults.no_histograms == Some(true));
+        assert!(goose_attack.defaults.no_time_series == Some(true));
         assert!(goose_attack.defaults.no_error_summary == Some(true));
         assert!(goose_attack.defaults.no_telnet == Some(true));
         assert!(goose_attack.defaults.no_websocket == Some(true));
         assert!(goose_attack.defaults.no_autostart == Some(true));
         assert!(goose_attack.defaults.no_gzip == Some(true));
+        assert!(goose_attack.defaults.no_tls_resumption == Some(true));
+        assert!(goose_attack.defaults.resolve_hosts == Some(true));
+        assert!(goose_attack.defaults.warm_up == Some(4));
+        assert!(goose_attack.defaults.rtt_samples == Some(5));
+        assert!(goose_attack.defaults.co_cadence == Some(250));
+        assert!(goose_attack.defaults.validation_workers == Some(2));
+        assert!(
+            goose_attack.defaults.ssh_jump_host
+                == Some("deploy@bastion.example.com:2222".to_string())
+        );
+        assert!(goose_attack.defaults.ssh_identity == Some("id_ed25519".to_string()));
         assert!(goose_attack.defaults.report_file == Some(report_file));
+        assert!(goose_attack.defaults.report_interval == Some(report_interval));
+        assert!(goose_attack.defaults.report_timestamp == Some(true));
+        assert!(goose_attack.defaults.checkpoint_file == Some(checkpoint_file));
+        assert!(goose_attack.defaults.checkpoint_interval == Some(checkpoint_interval));
         assert!(goose_attack.defaults.request_log == Some(request_log));
         assert!(goose_attack.defaults.request_format == Some(GooseLogFormat::Raw));
         assert!(goose_attack.defaults.error_log == Some(error_log));
@@ -2376,19 +6284,95 @@ mod test {
         assert!(goose_attack.defaults.debug_log == Some(debug_log));
         assert!(goose_attack.defaults.debug_format == Some(GooseLogFormat::Csv));
         assert!(goose_attack.defaults.status_codes == Some(true));
+        assert!(goose_attack.defaults.affinity_header == Some(affinity_header));
+        assert!(goose_attack.defaults.header_dimensions == Some(header_dimensions));
+        assert!(goose_attack.defaults.labels == Some(labels));
+        assert!(goose_attack.defaults.run_id == Some(run_id));
+        assert!(goose_attack.defaults.plan_file == Some(plan_file));
         assert!(
             goose_attack.defaults.co_mitigation
                 == Some(GooseCoordinatedOmissionMitigation::Disabled)
         );
         assert!(goose_attack.defaults.throttle_requests == Some(throttle_requests));
+        assert!(goose_attack.defaults.seed == Some(seed));
         assert!(goose_attack.defaults.sticky_follow == Some(true));
+        assert!(goose_attack.defaults.variant_host == Some(variant_host));
+        assert!(goose_attack.defaults.variant_header == Some(variant_header));
+        assert!(goose_attack.defaults.variant_percent == Some(variant_percent));
+        assert!(goose_attack.defaults.cache_buster == Some(cache_buster));
+        assert!(goose_attack.defaults.cache_buster_names == Some(cache_buster_names));
+        assert!(goose_attack.defaults.device_profiles == Some(device_profiles));
+        assert!(goose_attack.defaults.cohorts == Some(cohorts));
+        assert!(goose_attack.defaults.cohort_header == Some(cohort_header));
+        assert!(goose_attack.defaults.client_certs == Some(client_certs));
+        assert!(goose_attack.defaults.record == Some(record));
+        assert!(goose_attack.defaults.record_port == Some(record_port as u16));
+        assert!(goose_attack.defaults.prometheus_port == Some(9090));
+        assert!(goose_attack.defaults.statsd_host == Some(statsd_host));
+        assert!(goose_attack.defaults.influxdb_url == Some(influxdb_url));
+        assert!(goose_attack.defaults.influxdb_token == Some(influxdb_token));
+        assert!(goose_attack.defaults.influxdb_batch_size == Some(influxdb_batch_size));
+        assert!(goose_attack.defaults.influxdb_flush_interval == Some(influxdb_flush_interval));
+        assert!(goose_attack.defaults.replay_speed == Some(replay_speed));
+        assert!(goose_attack.defaults.accept_encoding == Some(accept_encoding));
+        assert!(goose_attack.defaults.body_sizes == Some(true));
+        assert!(goose_attack.defaults.cache_status == Some(true));
+        assert!(goose_attack.defaults.number_locale == Some("de".to_string()));
+        assert!(goose_attack.defaults.test_plan == Some("10,30s;0,30s".to_string()));
+        assert!(goose_attack.defaults.ramp_up == Some(300));
+        assert!(goose_attack.defaults.hold == Some(1800));
+        assert!(goose_attack.defaults.ramp_down == Some(300));
+        assert!(goose_attack.defaults.scale_units == Some(true));
+        assert!(goose_attack.defaults.adaptive_throttle == Some(true));
         assert!(goose_attack.defaults.manager == Some(true));
         assert!(goose_attack.defaults.expect_workers == Some(expect_workers));
         assert!(goose_attack.defaults.no_hash_check == Some(true));
+        assert!(goose_attack.defaults.worker_timeout == Some(30));
+        assert!(goose_attack.defaults.redistribute_users == Some(true));
         assert!(goose_attack.defaults.manager_bind_host == Some(manager_bind_host));
         assert!(goose_attack.defaults.manager_bind_port == Some(manager_bind_port as u16));
         assert!(goose_attack.defaults.worker == Some(true));
         assert!(goose_attack.defaults.manager_host == Some(manager_host));
         assert!(goose_attack.defaults.manager_port == Some(manager_port as u16));
+        assert!(goose_attack.defaults.manager_tls_cert == Some(manager_tls_cert));
+        assert!(goose_attack.defaults.manager_tls_ca == Some(manager_tls_ca));
+        assert!(goose_attack.defaults.gaggle_key == Some(gaggle_key));
+    }
+
+    #[test]
+    fn set_settings() {
+        let goose_attack = GooseAttack::initialize()
+            .unwrap()
+            .set_default(GooseDefault::Users, 10)
+            .unwrap()
+            .set_default(GooseDefault::RequestLog, "requests.log")
+            .unwrap()
+            .set_settings(
+                GooseSettings::builder()
+                    .host("http://example.com/")
+                    .users(50)
+                    .hatch_rate(2.5)
+                    .startup_time(Duration::from_millis(500))
+                    .run_time(Duration::from_millis(300_900))
+                    .running_metrics(Duration::from_secs(15))
+                    .output(GooseOutput::Summary)
+                    .no_reset_metrics(true)
+                    .build(),
+            );
+
+        // Settings replace defaults, and are converted to the type of each default, rounding
+        // durations up to whole seconds.
+        assert!(goose_attack.defaults.host == Some("http://example.com/".to_string()));
+        assert!(goose_attack.defaults.users == Some(50));
+        assert!(goose_attack.defaults.hatch_rate == Some("2.5".to_string()));
+        assert!(goose_attack.defaults.startup_time == Some(1));
+        assert!(goose_attack.defaults.run_time == Some(301));
+        assert!(goose_attack.defaults.running_metrics == Some(15));
+        assert!(goose_attack.defaults.output == Some(GooseOutput::Summary));
+        assert!(goose_attack.defaults.no_reset_metrics == Some(true));
+
+        // Defaults that weren't in the settings are left alone.
+        assert!(goose_attack.defaults.request_log == Some("requests.log".to_string()));
+        assert!(goose_attack.defaults.stop_rate.is_none());
     }
 }
@@ -4,9 +4,10 @@
 //! real-time control of the running load test.
 
 use crate::config::GooseConfiguration;
-use crate::metrics::GooseMetrics;
+use crate::goose::GooseUserCommand;
+use crate::metrics::{GooseAnnotation, GooseMetrics};
 use crate::util;
-use crate::{AttackPhase, GooseAttack, GooseAttackRunState, GooseError};
+use crate::{AttackMode, AttackPhase, GooseAttack, GooseAttackRunState, GooseError};
 
 use async_trait::async_trait;
 use futures::{SinkExt, StreamExt};
@@ -15,6 +16,7 @@ use serde::{Deserialize, Serialize};
 use std::io;
 use std::str;
 use std::str::FromStr;
+use std::sync::atomic::Ordering;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
 use tokio_tungstenite::tungstenite::Message;
@@ -73,7 +75,9 @@ pub enum GooseControllerCommand {
     /// users 100
     /// ```
     ///
-    /// Goose must be idle to process this command.
+    /// This can be configured when Goose is idle as well as when a Goose load test is running,
+    /// in which case users are added at the hatch rate or the most recently launched users
+    /// exit.
     Users,
     /// Configure how quickly new [`GooseUser`](../goose/struct.GooseUser.html)s are launched.
     ///
@@ -93,8 +97,51 @@ pub enum GooseControllerCommand {
     /// runtime 60
     /// ```
     ///
+    /// A leading `+` or `-` extends or shortens the configured run time instead. If no run
+    /// time is configured, `+` stops the running load test after it has run for that much
+    /// longer.
+    ///
+    /// # Example
+    /// Tells Goose to keep running the load test for another 30 minutes.
+    /// ```notest
+    /// runtime +30m
+    /// ```
+    ///
     /// This can be configured when Goose is idle as well as when a Goose load test is running.
     RunTime,
+    /// Enable a [`GooseTaskSet`](../goose/struct.GooseTaskSet.html), by name.
+    ///
+    /// # Example
+    /// Tells all [`GooseUser`](../goose/struct.GooseUser.html)s running the `HeavyReport`
+    /// task set to start running tasks.
+    /// ```notest
+    /// enable HeavyReport
+    /// ```
+    ///
+    /// This can be configured when Goose is idle as well as when a Goose load test is running.
+    Enable,
+    /// Disable a [`GooseTaskSet`](../goose/struct.GooseTaskSet.html), by name.
+    ///
+    /// # Example
+    /// Tells all [`GooseUser`](../goose/struct.GooseUser.html)s running the `HeavyReport`
+    /// task set to idle until it is enabled again.
+    /// ```notest
+    /// disable HeavyReport
+    /// ```
+    ///
+    /// This can be configured when Goose is idle as well as when a Goose load test is running.
+    Disable,
+    /// Record a named annotation marking the current moment of the running load test, see
+    /// [`GooseMetrics`](../metrics/struct.GooseMetrics.html)`.annotations`.
+    ///
+    /// # Example
+    /// Marks when a new version of the target server was deployed.
+    /// ```notest
+    /// annotate deployed v2.3
+    /// ```
+    ///
+    /// This can only be configured when a Goose load test is running.
+    Annotate,
     /// Display the current [`GooseConfiguration`](../struct.GooseConfiguration.html)s.
     ///
     /// # Example
@@ -537,6 +584,36 @@ impl GooseControllerState {
                 command: GooseControllerCommand::RunTime,
                 value: Some(run_time.to_string()),
             })
+        } else if matches.matched(GooseControllerCommand::Enable as usize) {
+            // Perform a second regex to capture the task set name.
+            let caps = self.captures[GooseControllerCommand::Enable as usize]
+                .captures(command_string)
+                .unwrap();
+            let task_set = caps.get(2).map_or("", |m| m.as_str());
+            Ok(GooseControllerRequestMessage {
+                command: GooseControllerCommand::Enable,
+                value: Some(task_set.to_string()),
+            })
+        } else if matches.matched(GooseControllerCommand::Disable as usize) {
+            // Perform a second regex to capture the task set name.
+            let caps = self.captures[GooseControllerCommand::Disable as usize]
+                .captures(command_string)
+                .unwrap();
+            let task_set = caps.get(2).map_or("", |m| m.as_str());
+            Ok(GooseControllerRequestMessage {
+                command: GooseControllerCommand::Disable,
+                value: Some(task_set.to_string()),
+            })
+        } else if matches.matched(GooseControllerCommand::Annotate as usize) {
+            // Perform a second regex to capture the annotation name.
+            let caps = self.captures[GooseControllerCommand::Annotate as usize]
+                .captures(command_string)
+                .unwrap();
+            let name = caps.get(2).map_or("", |m| m.as_str());
+            Ok(GooseControllerRequestMessage {
+                command: GooseControllerCommand::Annotate,
+                value: Some(name.to_string()),
+            })
         } else {
             Err(())
         }
@@ -609,7 +686,10 @@ impl GooseControllerState {
                 if let GooseControllerResponseMessage::Bool(true) = response {
                     Ok("users configured".to_string())
                 } else {
-                    Err("load test not idle, failed to reconfigure users".to_string())
+                    Err(
+                        "failed to reconfigure users, be sure load test is idle or running"
+                            .to_string(),
+                    )
                 }
             }
             GooseControllerCommand::HatchRate => {
@@ -626,6 +706,27 @@ impl GooseControllerState {
                     Err("failed to configure run_time".to_string())
                 }
             }
+            GooseControllerCommand::Enable => {
+                if let GooseControllerResponseMessage::Bool(true) = response {
+                    Ok("task set enabled".to_string())
+                } else {
+                    Err("failed to enable task set, be sure task set name is valid".to_string())
+                }
+            }
+            GooseControllerCommand::Disable => {
+                if let GooseControllerResponseMessage::Bool(true) = response {
+                    Ok("task set disabled".to_string())
+                } else {
+                    Err("failed to disable task set, be sure task set name is valid".to_string())
+                }
+            }
+            GooseControllerCommand::Annotate => {
+                if let GooseControllerResponseMessage::Bool(true) = response {
+                    Ok("annotation recorded".to_string())
+                } else {
+                    Err("failed to annotate, be sure load test is running".to_string())
+                }
+            }
             GooseControllerCommand::Config => {
                 if let GooseControllerResponseMessage::Config(config) = response {
                     Ok(format!("{:#?}", config))
@@ -978,11 +1079,14 @@ pub(crate) async fn controller_main(
     // against a command. The second time to capture specific matched values. This is a
     // limitiation of RegexSet as documented at:
     // https://docs.rs/regex/1.5.4/regex/struct.RegexSet.html#limitations
-    let host_regex = r"(?i)^(host|hostname|host_name|host-name) ((https?)://.+)$";
+    let host_regex = r"(?i)^(host|hostname|host_name|host-name) ((https?|unix)://.+)$";
     let users_regex = r"(?i)^(users?) (\d+)$";
     let hatchrate_regex = r"(?i)^(hatchrate|hatch_rate|hatch-rate) ([0-9]*(\.[0-9]*)?){1}$";
     let runtime_regex =
-        r"(?i)^(run|runtime|run_time|run-time|) (\d+|((\d+?)h)?((\d+?)m)?((\d+?)s)?)$";
+        r"(?i)^(run|runtime|run_time|run-time|) ([+-]?(\d+|((\d+?)h)?((\d+?)m)?((\d+?)s)?))$";
+    let enable_regex = r"(?i)^(enable) (.+)$";
+    let disable_regex = r"(?i)^(disable) (.+)$";
+    let annotate_regex = r"(?i)^(annotate|annotation) (.+)$";
 
     // The following RegexSet is matched against all commands received through the controller.
     // Developer note: The order commands are defined here must match the order in which
@@ -997,6 +1101,12 @@ pub(crate) async fn controller_main(
         hatchrate_regex,
         // Modify how long the load test will run.
         runtime_regex,
+        // Enable a task set.
+        enable_regex,
+        // Disable a task set.
+        disable_regex,
+        // Annotate the running load test.
+        annotate_regex,
         // Display the current load test configuration.
         r"(?i)^config$",
         // Display the current load test configuration in json.
@@ -1025,6 +1135,9 @@ pub(crate) async fn controller_main(
         Regex::new(users_regex).unwrap(),
         Regex::new(hatchrate_regex).unwrap(),
         Regex::new(runtime_regex).unwrap(),
+        Regex::new(enable_regex).unwrap(),
+        Regex::new(disable_regex).unwrap(),
+        Regex::new(annotate_regex).unwrap(),
     ];
 
     // Counter increments each time a controller client connects with this protocol.
@@ -1082,6 +1195,10 @@ fn display_help() -> String {
  users INT          set number of simulated users
  hatchrate FLOAT    set per-second rate users hatch
  runtime TIME       set how long to run test, ie 1h30m5s
+ runtime +/-TIME    extend or shorten how long to run test, ie +30m
+ enable TASKSET     run tasks in the named task set
+ disable TASKSET    idle users running the named task set
+ annotate TEXT      mark running load test, ie deployed v2.3
  config             display load test configuration
  config-json        display load test configuration in json format
  metrics            display metrics for current load test
@@ -1093,6 +1210,35 @@ fn display_help() -> String {
 
 /// The parent process side of the Controller functionality.
 impl GooseAttack {
+    /// Resolves the run time requested by a Controller. A leading `+` or `-` extends or
+    /// shortens the current run time, returning `None` if there's no run time to shorten.
+    fn controller_run_time(&self, run_time: &str) -> Option<String> {
+        if let Some(extend) = run_time.strip_prefix('+') {
+            // Without a run time the load test runs until stopped, so extend from now.
+            let current = if self.run_time > 0 {
+                self.run_time
+            } else if self.attack_phase == AttackPhase::Idle {
+                0
+            } else {
+                self.started
+                    .map_or(0, |started| started.elapsed().as_secs() as usize)
+            };
+            Some((current + util::parse_timespan(extend)).to_string())
+        } else if let Some(shorten) = run_time.strip_prefix('-') {
+            if self.run_time == 0 {
+                return None;
+            }
+            // Never shorten to 0, which would instead run the load test until stopped.
+            let shortened = self
+                .run_time
+                .saturating_sub(util::parse_timespan(shorten))
+                .max(1);
+            Some(shortened.to_string())
+        } else {
+            Some(run_time.to_string())
+        }
+    }
+
     /// Use the provided oneshot channel to reply to a controller client request.
     pub(crate) fn reply_to_controller(
         &mut self,
@@ -1112,6 +1258,107 @@ impl GooseAttack {
         }
     }
 
+    /// Change how many users are running while the load test is running. New users are
+    /// launched at the hatch rate, keeping the balance between task sets, and the most
+    /// recently launched users are told to exit first. Returns false if the users can't
+    /// be allocated to the task sets.
+    pub(crate) fn change_running_users(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+        users: usize,
+    ) -> bool {
+        let running = goose_attack_run_state.user_channels.len();
+        // Users explicitly added are launched even if a GooseUser stopped launching users.
+        goose_attack_run_state
+            .hatching_stopped
+            .store(false, Ordering::SeqCst);
+        if users >= running {
+            let previous_users = self.configuration.users;
+            self.configuration.users = Some(users);
+            let mut allocated_users = match self.allocate_users() {
+                Ok(allocated_users) => allocated_users,
+                Err(e) => {
+                    info!("failed to allocate {} users: {}", users, e);
+                    self.configuration.users = previous_users;
+                    return false;
+                }
+            };
+            // Only allocate users for task sets that don't have enough running.
+            for task_sets_index in &goose_attack_run_state.user_task_sets {
+                if let Some(position) = allocated_users.iter().position(|i| i == task_sets_index) {
+                    allocated_users.remove(position);
+                }
+            }
+            allocated_users.truncate(users - running);
+            // Users are launched from the end of the list.
+            allocated_users.reverse();
+            match self.create_task_set_users(allocated_users) {
+                Ok(weighted_users) => self.weighted_users = weighted_users,
+                Err(e) => {
+                    info!("failed to create users: {}", e);
+                    self.configuration.users = previous_users;
+                    return false;
+                }
+            }
+            goose_attack_run_state.spawn_user_in_ms = 0;
+        } else {
+            self.configuration.users = Some(users);
+            self.weighted_users.clear();
+            for index in users..running {
+                let send_to_user = goose_attack_run_state.user_channels.pop().unwrap();
+                goose_attack_run_state.user_task_sets.pop();
+                if send_to_user.send(GooseUserCommand::Exit).is_err() {
+                    debug!("failed to tell user {} to exit", index);
+                }
+            }
+        }
+        true
+    }
+
+    /// Enable or disable the named task set, telling any running users to idle or resume
+    /// running tasks. Returns false if there is no task set with the given name.
+    fn set_task_set_disabled(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+        name: &str,
+        disabled: bool,
+    ) -> bool {
+        let task_sets_index = match self.task_sets.iter().position(|t| t.name == name) {
+            Some(index) => index,
+            None => {
+                info!("no task set named {}", name);
+                return false;
+            }
+        };
+        info!(
+            "{} task set {}",
+            if disabled { "disabling" } else { "enabling" },
+            name
+        );
+        // Users launched from now on start in this state.
+        self.task_sets[task_sets_index].disabled = disabled;
+
+        // Tell already running users of this task set to idle or resume.
+        let command = if disabled {
+            GooseUserCommand::Wait
+        } else {
+            GooseUserCommand::Run
+        };
+        for (index, (send_to_user, user_task_sets_index)) in goose_attack_run_state
+            .user_channels
+            .iter()
+            .zip(&goose_attack_run_state.user_task_sets)
+            .enumerate()
+        {
+            if *user_task_sets_index == task_sets_index
+                && send_to_user.send(command.clone()).is_err()
+            {
+                debug!("failed to send {:?} to user {}", command, index);
+            }
+        }
+        true
+    }
+
     /// Handle Controller requests.
     pub(crate) async fn handle_controller_requests(
         &mut self,
@@ -1268,6 +1515,30 @@ impl GooseAttack {
                                         &message.request
                                     );
                                 }
+                            // Users can also be added or removed while a standalone load test
+                            // is running.
+                            } else if self.attack_phase == AttackPhase::Running
+                                && self.attack_mode == AttackMode::StandAlone
+                            {
+                                // Use unwrap_or_default() as Controller uses regex to validate
+                                // this is an integer.
+                                let users = message
+                                    .request
+                                    .value
+                                    .as_deref()
+                                    .and_then(|users| usize::from_str(users).ok())
+                                    .unwrap_or_default();
+                                info!(
+                                    "changing running users from {} to {}",
+                                    goose_attack_run_state.user_channels.len(),
+                                    users
+                                );
+                                let changed = users > 0
+                                    && self.change_running_users(goose_attack_run_state, users);
+                                self.reply_to_controller(
+                                    message,
+                                    GooseControllerResponseMessage::Bool(changed),
+                                );
                             } else {
                                 self.reply_to_controller(
                                     message,
@@ -1301,19 +1572,61 @@ impl GooseAttack {
                             // this is a valid run time, so simply use it with further
                             // validation.
                             if let Some(run_time) = &message.request.value {
-                                info!(
-                                    "changing run_time from {:?} to {}",
-                                    self.configuration.run_time, run_time
+                                if let Some(run_time) = self.controller_run_time(run_time) {
+                                    info!(
+                                        "changing run_time from {:?} to {}",
+                                        self.configuration.run_time, run_time
+                                    );
+                                    self.configuration.run_time = run_time;
+                                    self.set_run_time()?;
+                                    self.reply_to_controller(
+                                        message,
+                                        GooseControllerResponseMessage::Bool(true),
+                                    );
+                                } else {
+                                    self.reply_to_controller(
+                                        message,
+                                        GooseControllerResponseMessage::Bool(false),
+                                    );
+                                }
+                            } else {
+                                warn!(
+                                    "Controller didn't provide run_time: {:#?}",
+                                    &message.request
+                                );
+                            }
+                        }
+                        GooseControllerCommand::Enable | GooseControllerCommand::Disable => {
+                            if let Some(task_set) = &message.request.value {
+                                let disabled =
+                                    message.request.command == GooseControllerCommand::Disable;
+                                let success = self.set_task_set_disabled(
+                                    goose_attack_run_state,
+                                    task_set,
+                                    disabled,
                                 );
-                                self.configuration.run_time = run_time.clone();
-                                self.set_run_time()?;
                                 self.reply_to_controller(
                                     message,
-                                    GooseControllerResponseMessage::Bool(true),
+                                    GooseControllerResponseMessage::Bool(success),
                                 );
                             } else {
                                 warn!(
-                                    "Controller didn't provide run_time: {:#?}",
+                                    "Controller didn't provide task set: {:#?}",
+                                    &message.request
+                                );
+                            }
+                        }
+                        GooseControllerCommand::Annotate => {
+                            if let Some(name) = &message.request.value {
+                                let success = self.attack_phase != AttackPhase::Idle
+                                    && self.record_annotation(GooseAnnotation::new(name));
+                                self.reply_to_controller(
+                                    message,
+                                    GooseControllerResponseMessage::Bool(success),
+                                );
+                            } else {
+                                warn!(
+                                    "Controller didn't provide annotation: {:#?}",
                                     &message.request
                                 );
                             }
@@ -0,0 +1,205 @@
+//! Device profiles assigned to users.
+//!
+//! By default every [`GooseUser`] identifies itself with a `goose/VERSION` User-Agent
+//! header. Many websites serve different content to different devices, for example a
+//! lighter mobile theme, or a cached page for search engine crawlers. Enabling the
+//! `--device-profiles` run-time option (or
+//! [`GooseDefault::DeviceProfiles`](../config/enum.GooseDefault.html#variant.DeviceProfiles))
+//! assigns each user one of the built-in [`GooseDeviceProfile`]s, so a realistic mix of
+//! devices is load tested.
+//!
+//! The option is a comma-separated list of profiles and the percent of users assigned
+//! each profile, for example `desktop:60,mobile:35,bot:5`. Each user is randomly assigned
+//! a profile when it starts, using its own random number generator, so assignments are
+//! reproducible with `--seed`.
+//!
+//! Each request made by a user sets the headers of its profile, unless the request
+//! already sets the same header. The profile is also recorded as the `device` dimension
+//! of each request, so response times are reported per device and the profile is
+//! included in the request log.
+//!
+//! # Example
+//! ```rust
+//! use goose::device::GooseDeviceProfile;
+//! use goose::prelude::*;
+//!
+//! let mut a_task = task!(loadtest_index);
+//!
+//! /// Load the front page, and if on a mobile device the mobile menu.
+//! async fn loadtest_index(user: &mut GooseUser) -> GooseTaskResult {
+//!     let _goose = user.get("").await?;
+//!
+//!     if user.device_profile() == Some(GooseDeviceProfile::Mobile) {
+//!         let _goose = user.get("menu/mobile").await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use rand::Rng;
+use reqwest::header::{HeaderValue, ACCEPT_LANGUAGE, USER_AGENT};
+use reqwest::Request;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::goose::GooseUser;
+
+/// A built-in device profile, setting the headers sent by a typical device of its type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GooseDeviceProfile {
+    /// A desktop web browser.
+    Desktop,
+    /// A mobile phone web browser.
+    Mobile,
+    /// A search engine crawler.
+    Bot,
+}
+impl GooseDeviceProfile {
+    /// The User-Agent header sent by this device.
+    pub fn user_agent(&self) -> &'static str {
+        match self {
+            GooseDeviceProfile::Desktop => "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/95.0.4638.69 Safari/537.36",
+            GooseDeviceProfile::Mobile => "Mozilla/5.0 (iPhone; CPU iPhone OS 15_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.1 Mobile/15E148 Safari/604.1",
+            GooseDeviceProfile::Bot => "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+        }
+    }
+
+    /// The Accept-Language header sent by this device, if any.
+    pub fn accept_language(&self) -> Option<&'static str> {
+        match self {
+            GooseDeviceProfile::Desktop | GooseDeviceProfile::Mobile => Some("en-US,en;q=0.9"),
+            GooseDeviceProfile::Bot => None,
+        }
+    }
+}
+impl fmt::Display for GooseDeviceProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GooseDeviceProfile::Desktop => write!(f, "desktop"),
+            GooseDeviceProfile::Mobile => write!(f, "mobile"),
+            GooseDeviceProfile::Bot => write!(f, "bot"),
+        }
+    }
+}
+impl FromStr for GooseDeviceProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "desktop" => Ok(GooseDeviceProfile::Desktop),
+            "mobile" => Ok(GooseDeviceProfile::Mobile),
+            "bot" => Ok(GooseDeviceProfile::Bot),
+            _ => Err(format!(
+                "unknown device profile {}, expected: desktop, mobile or bot",
+                s
+            )),
+        }
+    }
+}
+
+/// Parse a comma-separated list of device profiles and the percent of users assigned
+/// each profile, for example `desktop:60,mobile:35,bot:5`.
+pub(crate) fn parse_device_profiles(
+    device_profiles: &str,
+) -> Result<Vec<(GooseDeviceProfile, usize)>, String> {
+    let mut parsed = Vec::new();
+    for device_profile in device_profiles.split(',') {
+        let (name, percent) = match device_profile.split_once(':') {
+            Some((name, percent)) => (name.trim(), percent.trim()),
+            None => {
+                return Err(format!(
+                    "device profile {} must be formatted as PROFILE:PERCENT",
+                    device_profile
+                ))
+            }
+        };
+        let percent = match percent.parse::<usize>() {
+            Ok(percent) if percent > 0 => percent,
+            _ => {
+                return Err(format!(
+                    "percent of device profile {} must be a number greater than 0",
+                    name
+                ))
+            }
+        };
+        parsed.push((name.parse()?, percent));
+    }
+    let total: usize = parsed.iter().map(|(_, percent)| percent).sum();
+    if total != 100 {
+        return Err(format!(
+            "device profile percents must add up to 100, not {}",
+            total
+        ));
+    }
+    Ok(parsed)
+}
+
+impl GooseUser {
+    /// The device profile assigned to this user with `--device-profiles`, if any.
+    pub fn device_profile(&self) -> Option<GooseDeviceProfile> {
+        self.device_profile
+    }
+
+    // Randomly assign a device profile to this user, if configured with `--device-profiles`.
+    pub(crate) fn assign_device_profile(&mut self) {
+        if self.config.device_profiles.is_empty() {
+            return;
+        }
+        // The configuration was validated at startup.
+        let device_profiles = match parse_device_profiles(&self.config.device_profiles) {
+            Ok(device_profiles) => device_profiles,
+            Err(_) => return,
+        };
+        let mut selected = self.rng.gen_range(0..100);
+        for (device_profile, percent) in device_profiles {
+            if selected < percent {
+                self.device_profile = Some(device_profile);
+                return;
+            }
+            selected -= percent;
+        }
+    }
+
+    // Set the headers of this user's device profile, unless the request already sets them.
+    pub(crate) fn set_device_headers(&self, request: &mut Request) {
+        if let Some(device_profile) = self.device_profile {
+            let headers = request.headers_mut();
+            headers
+                .entry(USER_AGENT)
+                .or_insert_with(|| HeaderValue::from_static(device_profile.user_agent()));
+            if let Some(accept_language) = device_profile.accept_language() {
+                headers
+                    .entry(ACCEPT_LANGUAGE)
+                    .or_insert_with(|| HeaderValue::from_static(accept_language));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_profiles() {
+        assert_eq!(
+            parse_device_profiles("desktop:60, mobile:35,BOT:5").unwrap(),
+            vec![
+                (GooseDeviceProfile::Desktop, 60),
+                (GooseDeviceProfile::Mobile, 35),
+                (GooseDeviceProfile::Bot, 5),
+            ]
+        );
+        assert_eq!(
+            parse_device_profiles("mobile:100").unwrap(),
+            vec![(GooseDeviceProfile::Mobile, 100)]
+        );
+
+        // Invalid device profiles.
+        assert!(parse_device_profiles("mobile").is_err());
+        assert!(parse_device_profiles("tablet:100").is_err());
+        assert!(parse_device_profiles("mobile:0,desktop:100").is_err());
+        assert!(parse_device_profiles("mobile:50,desktop:40").is_err());
+    }
+}
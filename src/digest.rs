@@ -0,0 +1,344 @@
+//! Optional HTTP Digest authentication.
+//!
+//! Some targets, such as embedded devices, only accept HTTP Digest authentication, which
+//! reqwest doesn't support. Once credentials are set with
+//! [`GooseUser::set_digest_credentials`], a request that is challenged with a
+//! `401 Unauthorized` response offering `Digest` authentication is retried once with an
+//! `Authorization` header answering the challenge. The challenge is then stored on the
+//! user, and further requests are authorized in advance with the same nonce and an
+//! incremented nonce count, until the server challenges the user again, for example
+//! because the nonce is stale.
+//!
+//! The `MD5` and `MD5-sess` algorithms are supported, with the `auth` and `auth-int`
+//! qualities of protection, or without one as in RFC 2069. The response time of a
+//! challenged request includes the retry, and only the final response is returned and
+//! included in the metrics. Requests with a streaming body can't be retried, and return
+//! the challenge.
+
+use rand::Rng;
+use reqwest::header::{HeaderValue, AUTHORIZATION, WWW_AUTHENTICATE};
+use reqwest::{Client, Request, Response, StatusCode};
+
+use crate::goose::GooseUser;
+use crate::md::{hex, md5};
+
+/// Credentials used to answer Digest authentication challenges, and the most recent
+/// challenge received by the user.
+#[derive(Clone, Debug)]
+pub(crate) struct DigestAuth {
+    username: String,
+    password: String,
+    challenge: Option<DigestChallenge>,
+}
+
+/// The parameters of a Digest authentication challenge.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct DigestChallenge {
+    realm: String,
+    nonce: String,
+    opaque: Option<String>,
+    algorithm: Option<String>,
+    qop: Option<String>,
+    /// How many requests have been authorized with this nonce.
+    nonce_count: u32,
+}
+
+// Split the parameters of a challenge into names and unquoted values.
+fn parse_parameters(parameters: &str) -> Vec<(String, String)> {
+    let mut parsed = Vec::new();
+    let mut chars = parameters.chars().peekable();
+    loop {
+        // Skip separators, then read the parameter name.
+        while matches!(chars.peek(), Some(c) if *c == ',' || c.is_whitespace()) {
+            chars.next();
+        }
+        let name: String = chars
+            .by_ref()
+            .take_while(|c| *c != '=')
+            .collect::<String>()
+            .trim()
+            .to_lowercase();
+        if name.is_empty() {
+            break;
+        }
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+        // Read a quoted value, allowing escaped characters, or a token.
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => value.extend(chars.next()),
+                    '"' => break,
+                    _ => value.push(c),
+                }
+            }
+        } else {
+            value = chars
+                .by_ref()
+                .take_while(|c| *c != ',')
+                .collect::<String>()
+                .trim()
+                .to_string();
+        }
+        parsed.push((name, value));
+    }
+    parsed
+}
+
+// Parse a WWW-Authenticate header value offering Digest authentication.
+fn parse_challenge(value: &str) -> Option<DigestChallenge> {
+    let value = value.trim();
+    let scheme = value.get(..6)?;
+    if !scheme.eq_ignore_ascii_case("Digest") {
+        return None;
+    }
+    let mut challenge = DigestChallenge::default();
+    let mut nonce = None;
+    for (name, value) in parse_parameters(&value[6..]) {
+        match name.as_str() {
+            "realm" => challenge.realm = value,
+            "nonce" => nonce = Some(value),
+            "opaque" => challenge.opaque = Some(value),
+            "algorithm" => challenge.algorithm = Some(value),
+            // Prefer "auth" when the server offers more than one quality of protection.
+            "qop" => {
+                let offered: Vec<&str> = value.split(',').map(str::trim).collect();
+                challenge.qop = ["auth", "auth-int"]
+                    .iter()
+                    .find(|qop| offered.contains(qop))
+                    .map(|qop| qop.to_string());
+                // A quality of protection was required, but none are supported.
+                challenge.qop.as_ref()?;
+            }
+            _ => {}
+        }
+    }
+    challenge.nonce = nonce?;
+    // Only the MD5 algorithms are supported.
+    match challenge.algorithm.as_deref() {
+        None => {}
+        Some(algorithm)
+            if algorithm.eq_ignore_ascii_case("MD5")
+                || algorithm.eq_ignore_ascii_case("MD5-sess") => {}
+        Some(_) => return None,
+    }
+    Some(challenge)
+}
+
+// Find a supported Digest challenge in a 401 response.
+fn challenge(response: &Response) -> Option<DigestChallenge> {
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return None;
+    }
+    response
+        .headers()
+        .get_all(WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(parse_challenge)
+}
+
+// The lowercase hexadecimal MD5 digest of a string.
+fn md5_hex(value: &[u8]) -> String {
+    hex(&md5(value))
+}
+
+impl DigestAuth {
+    // Build the Authorization header value for a request, answering the challenge with
+    // the given nonce count and client nonce.
+    fn authorization(
+        &self,
+        challenge: &DigestChallenge,
+        method: &str,
+        uri: &str,
+        body: &[u8],
+        cnonce: &str,
+    ) -> String {
+        let nc = format!("{:08x}", challenge.nonce_count);
+        let mut ha1 =
+            md5_hex(format!("{}:{}:{}", self.username, challenge.realm, self.password).as_bytes());
+        let session = match &challenge.algorithm {
+            Some(algorithm) => algorithm.eq_ignore_ascii_case("MD5-sess"),
+            None => false,
+        };
+        if session {
+            ha1 = md5_hex(format!("{}:{}:{}", ha1, challenge.nonce, cnonce).as_bytes());
+        }
+        let ha2 = match challenge.qop.as_deref() {
+            Some("auth-int") => md5_hex(format!("{}:{}:{}", method, uri, md5_hex(body)).as_bytes()),
+            _ => md5_hex(format!("{}:{}", method, uri).as_bytes()),
+        };
+        let response = match &challenge.qop {
+            Some(qop) => md5_hex(
+                format!(
+                    "{}:{}:{}:{}:{}:{}",
+                    ha1, challenge.nonce, nc, cnonce, qop, ha2
+                )
+                .as_bytes(),
+            ),
+            None => md5_hex(format!("{}:{}:{}", ha1, challenge.nonce, ha2).as_bytes()),
+        };
+
+        let mut authorization = format!(
+            r#"Digest username="{}", realm="{}", nonce="{}", uri="{}", response="{}""#,
+            self.username, challenge.realm, challenge.nonce, uri, response
+        );
+        if let Some(algorithm) = &challenge.algorithm {
+            authorization.push_str(&format!(", algorithm={}", algorithm));
+        }
+        if let Some(qop) = &challenge.qop {
+            authorization.push_str(&format!(r#", qop={}, nc={}, cnonce="{}""#, qop, nc, cnonce));
+        }
+        if let Some(opaque) = &challenge.opaque {
+            authorization.push_str(&format!(r#", opaque="{}""#, opaque));
+        }
+        authorization
+    }
+
+    // Authorize a request with the stored challenge, incrementing the nonce count.
+    fn authorize(&mut self, request: &mut Request) {
+        let mut challenge = match self.challenge.take() {
+            Some(challenge) => challenge,
+            None => return,
+        };
+        challenge.nonce_count += 1;
+        let uri = match request.url().query() {
+            Some(query) => format!("{}?{}", request.url().path(), query),
+            None => request.url().path().to_string(),
+        };
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .unwrap_or_default();
+        let cnonce = hex(&rand::thread_rng().gen::<[u8; 8]>());
+        let authorization =
+            self.authorization(&challenge, request.method().as_str(), &uri, body, &cnonce);
+        if let Ok(value) = HeaderValue::from_str(&authorization) {
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+        self.challenge = Some(challenge);
+    }
+}
+
+impl GooseUser {
+    /// Answer HTTP Digest authentication challenges with these credentials.
+    ///
+    /// A request that receives a `401 Unauthorized` response offering `Digest`
+    /// authentication is retried once, answering the challenge. Further requests made by
+    /// this user are then authorized in advance, tracking the nonce count, until the
+    /// server challenges the user again. The `MD5` and `MD5-sess` algorithms are
+    /// supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(login).set_on_start();
+    ///
+    /// /// Authenticate all requests made by this user as "admin".
+    /// async fn login(user: &mut GooseUser) -> GooseTaskResult {
+    ///     user.set_digest_credentials("admin", "secret");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_digest_credentials(&mut self, username: &str, password: &str) {
+        self.digest = Some(DigestAuth {
+            username: username.to_string(),
+            password: password.to_string(),
+            challenge: None,
+        });
+    }
+
+    // If a Digest challenge was already received, authorize the request in advance.
+    pub(crate) fn set_digest_authorization(&mut self, request: &mut Request) {
+        if let Some(digest) = self.digest.as_mut() {
+            digest.authorize(request);
+        }
+    }
+
+    // If challenged, store the challenge and retry the request once, answering it.
+    // Otherwise, or if the request can't be retried, return the original response.
+    pub(crate) async fn digest_authenticate(
+        &mut self,
+        client: &Client,
+        request: Option<Request>,
+        response: Result<Response, reqwest::Error>,
+    ) -> Result<Response, reqwest::Error> {
+        let (digest, mut request) = match (self.digest.as_mut(), request) {
+            (Some(digest), Some(request)) => (digest, request),
+            _ => return response,
+        };
+        let response = response?;
+        digest.challenge = match challenge(&response) {
+            Some(challenge) => Some(challenge),
+            None => return Ok(response),
+        };
+
+        // Read the body so the connection can be reused for the retry.
+        let _ = response.bytes().await;
+        digest.authorize(&mut request);
+        client.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The challenge from the example in RFC 2617 section 3.5.
+    const CHALLENGE: &str = r#"Digest
+        realm="testrealm@host.com",
+        qop="auth,auth-int",
+        nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093",
+        opaque="5ccc069c403ebaf9f0171e9517f40e41""#;
+
+    #[test]
+    fn parse_challenges() {
+        let challenge = parse_challenge(CHALLENGE).unwrap();
+        assert_eq!(challenge.realm, "testrealm@host.com");
+        assert_eq!(challenge.nonce, "dcd98b7102dd2f0e8b11d0f600bfb0c093");
+        assert_eq!(
+            challenge.opaque.as_deref(),
+            Some("5ccc069c403ebaf9f0171e9517f40e41")
+        );
+        assert_eq!(challenge.qop.as_deref(), Some("auth"));
+        assert_eq!(challenge.algorithm, None);
+
+        // Quoted values can contain separators and escaped quotes.
+        let challenge =
+            parse_challenge(r#"digest nonce="a,b", realm="say \"hi\"", stale=TRUE"#).unwrap();
+        assert_eq!(challenge.nonce, "a,b");
+        assert_eq!(challenge.realm, r#"say "hi""#);
+
+        // Other schemes, unsupported algorithms and challenges without a nonce are ignored.
+        assert_eq!(parse_challenge(r#"Basic realm="test""#), None);
+        assert_eq!(
+            parse_challenge(r#"Digest realm="test", nonce="abc", algorithm=SHA-256"#),
+            None
+        );
+        assert_eq!(parse_challenge(r#"Digest realm="test""#), None);
+    }
+
+    #[test]
+    fn authorization() {
+        let digest = DigestAuth {
+            username: "Mufasa".to_string(),
+            password: "Circle Of Life".to_string(),
+            challenge: None,
+        };
+        let mut challenge = parse_challenge(CHALLENGE).unwrap();
+        challenge.nonce_count = 1;
+
+        // The response from the example in RFC 2617 section 3.5.
+        let authorization =
+            digest.authorization(&challenge, "GET", "/dir/index.html", b"", "0a4f113b");
+        assert_eq!(
+            authorization,
+            r#"Digest username="Mufasa", realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", uri="/dir/index.html", response="6629fae49393a05397450978507c4ef1", qop=auth, nc=00000001, cnonce="0a4f113b", opaque="5ccc069c403ebaf9f0171e9517f40e41""#
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Pluggable exporters of live metrics.
+//!
+//! Goose natively streams metrics to Prometheus, StatsD and InfluxDB. Load tests that
+//! report to other telemetry systems, for example a bespoke company-internal one,
+//! implement the [`GooseMetricsExporter`] trait and register it with
+//! [`GooseAttack::register_metrics_exporter`](../struct.GooseAttack.html#method.register_metrics_exporter).
+//!
+//! While the load test runs, each exporter receives a snapshot of all metrics aggregated
+//! so far about once per second, and the final metrics once the load test stops. Each
+//! exporter runs in its own task, so a slow telemetry system never slows down the load
+//! test: if an exporter is still busy with the previous snapshot, newer snapshots are
+//! skipped until it is done. Errors returned by an exporter are logged, and don't stop
+//! the load test.
+//!
+//! Exporters aren't run when metrics are disabled, or by the Workers of a Gaggle, as the
+//! Manager aggregates their metrics.
+//!
+//! # Example
+//! ```rust
+//! use goose::export::GooseMetricsExporter;
+//! use goose::metrics::GooseMetrics;
+//! use goose::prelude::*;
+//!
+//! /// Logs how many requests have been made so far.
+//! struct RequestCounter;
+//! #[async_trait::async_trait]
+//! impl GooseMetricsExporter for RequestCounter {
+//!     async fn export(&mut self, metrics: &GooseMetrics) -> Result<(), String> {
+//!         let requests: usize = metrics
+//!             .requests
+//!             .values()
+//!             .map(|request| request.success_count + request.fail_count)
+//!             .sum();
+//!         println!("{} requests after {} seconds", requests, metrics.duration);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), GooseError> {
+//!     GooseAttack::initialize()?
+//!         .register_taskset(taskset!("ExampleTasks")
+//!             .register_task(task!(example_task))
+//!             // Goose must run against a host, point to localhost so test starts.
+//!             .set_host("http://localhost")
+//!         )
+//!         .register_metrics_exporter(RequestCounter)
+//!         // Exit after one second so test doesn't run forever.
+//!         .set_default(GooseDefault::RunTime, 1)?
+//!         .execute()
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//!
+//! async fn example_task(user: &mut GooseUser) -> GooseTaskResult {
+//!     let _goose = user.get("/").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use async_trait::async_trait;
+use std::fmt;
+
+use crate::metrics::GooseMetrics;
+
+/// A destination that live metrics are exported to.
+#[async_trait]
+pub trait GooseMetricsExporter: Send + Sync {
+    /// Exports a snapshot of all metrics aggregated so far, sent about once per second
+    /// while the load test runs. Returned errors are logged.
+    async fn export(&mut self, metrics: &GooseMetrics) -> Result<(), String>;
+
+    /// Exports the final metrics once the load test stops. By default these are exported
+    /// the same as any other snapshot, with [`GooseMetricsExporter::export`].
+    async fn finish(&mut self, metrics: &GooseMetrics) -> Result<(), String> {
+        self.export(metrics).await
+    }
+}
+
+/// Snapshots of metrics sent to the task running an exporter.
+enum GooseMetricsExport {
+    /// A snapshot of all metrics aggregated so far.
+    Interval(Box<GooseMetrics>),
+    /// The final metrics, after which the task exits.
+    Final(Box<GooseMetrics>),
+}
+
+/// A running exporter: the channel sending it metrics, and the join handle of its task,
+/// which resolves to the exporter so it can be reused by later load tests.
+pub(crate) struct GooseMetricsExporterHandle {
+    export_tx: flume::Sender<GooseMetricsExport>,
+    handle: tokio::task::JoinHandle<Box<dyn GooseMetricsExporter>>,
+}
+// Can't derive Debug as the exporter is a trait object.
+impl fmt::Debug for GooseMetricsExporterHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GooseMetricsExporterHandle").finish()
+    }
+}
+
+/// Spawns a task for each exporter.
+pub(crate) fn spawn_exporters(
+    exporters: Vec<Box<dyn GooseMetricsExporter>>,
+) -> Vec<GooseMetricsExporterHandle> {
+    exporters
+        .into_iter()
+        .map(|exporter| {
+            // Only one snapshot is queued, later snapshots are skipped while the exporter
+            // is busy.
+            let (export_tx, export_rx) = flume::bounded(1);
+            GooseMetricsExporterHandle {
+                export_tx,
+                handle: tokio::spawn(exporter_main(exporter, export_rx)),
+            }
+        })
+        .collect()
+}
+
+/// Sends a snapshot of all metrics aggregated so far to each exporter that isn't busy.
+pub(crate) fn export_interval(exporters: &[GooseMetricsExporterHandle], metrics: &GooseMetrics) {
+    for exporter in exporters {
+        // Ignore errors, the exporter is still busy with the previous snapshot.
+        let _ = exporter
+            .export_tx
+            .try_send(GooseMetricsExport::Interval(Box::new(metrics.clone())));
+    }
+}
+
+/// Sends the final metrics to each exporter and waits for them to be exported, returning
+/// the exporters.
+pub(crate) async fn stop_exporters(
+    exporters: Vec<GooseMetricsExporterHandle>,
+    metrics: &GooseMetrics,
+) -> Vec<Box<dyn GooseMetricsExporter>> {
+    for exporter in &exporters {
+        let _ = exporter
+            .export_tx
+            .send_async(GooseMetricsExport::Final(Box::new(metrics.clone())))
+            .await;
+    }
+    let mut stopped_exporters = Vec::with_capacity(exporters.len());
+    for exporter in exporters {
+        match exporter.handle.await {
+            Ok(exporter) => stopped_exporters.push(exporter),
+            Err(e) => warn!("metrics exporter failed: {}", e),
+        }
+    }
+    stopped_exporters
+}
+
+/// Exports each snapshot received until the final metrics are exported.
+async fn exporter_main(
+    mut exporter: Box<dyn GooseMetricsExporter>,
+    export_rx: flume::Receiver<GooseMetricsExport>,
+) -> Box<dyn GooseMetricsExporter> {
+    while let Ok(export) = export_rx.recv_async().await {
+        match export {
+            GooseMetricsExport::Interval(metrics) => {
+                if let Err(e) = exporter.export(&metrics).await {
+                    warn!("failed to export metrics: {}", e);
+                }
+            }
+            GooseMetricsExport::Final(metrics) => {
+                if let Err(e) = exporter.finish(&metrics).await {
+                    warn!("failed to export final metrics: {}", e);
+                }
+                break;
+            }
+        }
+    }
+    exporter
+}
@@ -0,0 +1,343 @@
+//! Optional synthetic data generation, for building realistic request payloads.
+//!
+//! Enabled with the `faker` compile-time feature. Each function generates a random value,
+//! such as a name, an email address, a postal address or lorem ipsum text, using the
+//! provided random number generator. Passing a [`GooseUser`](../goose/struct.GooseUser.html)'s
+//! own random number generator from [`GooseUser::rng`](../goose/struct.GooseUser.html#method.rng)
+//! makes the generated data reproducible when the load test is run with `--seed`.
+//!
+//! All generated email addresses use domains reserved for documentation, so load tests
+//! never email real people.
+//!
+//! # Example
+//! ```rust
+//! use goose::faker;
+//! use goose::prelude::*;
+//!
+//! let mut a_task = task!(register);
+//!
+//! /// Register a new account with realistic details.
+//! async fn register(user: &mut GooseUser) -> GooseTaskResult {
+//!     let params = [
+//!         ("name", faker::name(user.rng())),
+//!         ("email", faker::email(user.rng())),
+//!         ("address", faker::address(user.rng())),
+//!         ("bio", faker::paragraph(user.rng())),
+//!     ];
+//!     let _goose = user.post_form("register", &params).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Amir", "Ana", "Ben", "Carla", "Chen", "David", "Elena", "Emma", "Fatima", "Felix",
+    "Grace", "Hana", "Hugo", "Isabel", "Ivan", "James", "Julia", "Kai", "Laura", "Leo", "Lucia",
+    "Maria", "Mateo", "Mei", "Nadia", "Noah", "Olivia", "Omar", "Priya", "Rafael", "Rosa",
+    "Samuel", "Sofia", "Tom", "Uma", "Victor", "Wei", "Yara", "Zoe",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Adams",
+    "Baker",
+    "Brown",
+    "Campbell",
+    "Chen",
+    "Costa",
+    "Davis",
+    "Diaz",
+    "Evans",
+    "Garcia",
+    "Gomez",
+    "Green",
+    "Hall",
+    "Hernandez",
+    "Ito",
+    "Jackson",
+    "Johnson",
+    "Kim",
+    "Lee",
+    "Lopez",
+    "Martin",
+    "Miller",
+    "Moore",
+    "Nguyen",
+    "Novak",
+    "Patel",
+    "Perez",
+    "Rossi",
+    "Sato",
+    "Schmidt",
+    "Silva",
+    "Smith",
+    "Taylor",
+    "Thomas",
+    "Walker",
+    "White",
+    "Williams",
+    "Wilson",
+    "Young",
+    "Zhang",
+];
+
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.net", "example.org"];
+
+const STREET_NAMES: &[&str] = &[
+    "Ash", "Birch", "Cedar", "Cherry", "Chestnut", "Elm", "Hickory", "Highland", "Hill", "Lake",
+    "Maple", "Meadow", "Mill", "Oak", "Park", "Pine", "River", "Spring", "Sunset", "Walnut",
+];
+
+const STREET_SUFFIXES: &[&str] = &[
+    "Avenue",
+    "Boulevard",
+    "Court",
+    "Drive",
+    "Lane",
+    "Place",
+    "Road",
+    "Street",
+    "Way",
+];
+
+const CITIES: &[&str] = &[
+    "Ashford",
+    "Bayview",
+    "Bridgeport",
+    "Brookfield",
+    "Cedar Falls",
+    "Clearwater",
+    "Fairview",
+    "Franklin",
+    "Georgetown",
+    "Greenville",
+    "Kingston",
+    "Lakewood",
+    "Madison",
+    "Millbrook",
+    "Oakland",
+    "Riverside",
+    "Salem",
+    "Springfield",
+    "Westfield",
+    "Woodland",
+];
+
+const STATES: &[&str] = &[
+    "AZ", "CA", "CO", "FL", "GA", "IL", "MA", "MI", "MN", "NC", "NY", "OH", "OR", "PA", "TX", "VA",
+    "WA", "WI",
+];
+
+const LOREM_WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+    "occaecat",
+    "cupidatat",
+    "non",
+    "proident",
+    "sunt",
+    "culpa",
+    "qui",
+    "officia",
+    "deserunt",
+    "mollit",
+    "anim",
+    "id",
+    "est",
+    "laborum",
+];
+
+// Choose a random value from a non-empty list.
+fn choose<R: Rng + ?Sized>(rng: &mut R, values: &[&'static str]) -> &'static str {
+    // All lists are defined above and are not empty, so unwrap() is safe.
+    values.choose(rng).unwrap()
+}
+
+/// Returns a random first name.
+pub fn first_name<R: Rng + ?Sized>(rng: &mut R) -> String {
+    choose(rng, FIRST_NAMES).to_string()
+}
+
+/// Returns a random last name.
+pub fn last_name<R: Rng + ?Sized>(rng: &mut R) -> String {
+    choose(rng, LAST_NAMES).to_string()
+}
+
+/// Returns a random full name, for example `Grace Hernandez`.
+pub fn name<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!("{} {}", choose(rng, FIRST_NAMES), choose(rng, LAST_NAMES))
+}
+
+/// Returns a random email address on a domain reserved for documentation, for example
+/// `grace.hernandez42@example.org`.
+pub fn email<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!(
+        "{}.{}{}@{}",
+        choose(rng, FIRST_NAMES).to_lowercase(),
+        choose(rng, LAST_NAMES).to_lowercase(),
+        rng.gen_range(1..1000),
+        choose(rng, EMAIL_DOMAINS)
+    )
+}
+
+/// Returns a random street address, for example `1234 Maple Avenue`.
+pub fn street_address<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!(
+        "{} {} {}",
+        rng.gen_range(1..10_000),
+        choose(rng, STREET_NAMES),
+        choose(rng, STREET_SUFFIXES)
+    )
+}
+
+/// Returns a random city name.
+pub fn city<R: Rng + ?Sized>(rng: &mut R) -> String {
+    choose(rng, CITIES).to_string()
+}
+
+/// Returns a random two letter state abbreviation.
+pub fn state<R: Rng + ?Sized>(rng: &mut R) -> String {
+    choose(rng, STATES).to_string()
+}
+
+/// Returns a random five digit postal code.
+pub fn postal_code<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!("{:05}", rng.gen_range(1..100_000))
+}
+
+/// Returns a random full address on one line, for example
+/// `1234 Maple Avenue, Springfield, OR 04321`.
+pub fn address<R: Rng + ?Sized>(rng: &mut R) -> String {
+    format!(
+        "{}, {}, {} {}",
+        street_address(rng),
+        choose(rng, CITIES),
+        choose(rng, STATES),
+        postal_code(rng)
+    )
+}
+
+/// Returns the provided number of random lorem ipsum words, separated by spaces.
+pub fn words<R: Rng + ?Sized>(rng: &mut R, count: usize) -> String {
+    (0..count)
+        .map(|_| choose(rng, LOREM_WORDS))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns a random lorem ipsum sentence of 4 to 12 words, capitalized and ending with a
+/// period.
+pub fn sentence<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let count = rng.gen_range(4..=12);
+    let sentence = words(rng, count);
+    let mut chars = sentence.chars();
+    // Sentences always contain at least one word, so unwrap() is safe.
+    let first = chars.next().unwrap().to_uppercase();
+    format!("{}{}.", first, chars.as_str())
+}
+
+/// Returns a random lorem ipsum paragraph of 3 to 6 sentences.
+pub fn paragraph<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let count = rng.gen_range(3..=6);
+    (0..count)
+        .map(|_| sentence(rng))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn generated_data() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let name = name(&mut rng);
+        assert_eq!(name.split(' ').count(), 2);
+
+        let email = email(&mut rng);
+        let (local, domain) = email.split_once('@').unwrap();
+        assert!(local.contains('.'));
+        assert!(EMAIL_DOMAINS.contains(&domain));
+
+        let postal_code = postal_code(&mut rng);
+        assert_eq!(postal_code.len(), 5);
+        assert!(postal_code.chars().all(|c| c.is_ascii_digit()));
+
+        let address = address(&mut rng);
+        assert_eq!(address.split(", ").count(), 3);
+
+        assert_eq!(words(&mut rng, 5).split(' ').count(), 5);
+        assert_eq!(words(&mut rng, 0), "");
+
+        let sentence = sentence(&mut rng);
+        assert!(sentence.ends_with('.'));
+        assert!(sentence.chars().next().unwrap().is_uppercase());
+
+        let paragraph = paragraph(&mut rng);
+        assert!(paragraph.matches('.').count() >= 3);
+    }
+
+    #[test]
+    fn reproducible() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut same_rng = StdRng::seed_from_u64(7);
+
+        // The same seed generates the same data.
+        assert_eq!(name(&mut rng), name(&mut same_rng));
+        assert_eq!(address(&mut rng), address(&mut same_rng));
+        assert_eq!(paragraph(&mut rng), paragraph(&mut same_rng));
+    }
+}
@@ -289,25 +289,47 @@
 
 use downcast_rs::{impl_downcast, Downcast};
 use http::method::Method;
+use rand::rngs::StdRng;
+use rand::Rng;
 use reqwest::{header, Client, ClientBuilder, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::{fmt, str};
 use std::{future::Future, pin::Pin, time::Instant};
 use tokio::sync::RwLock;
 use url::Url;
 
-use crate::logger::GooseLog;
+use crate::cache;
+use crate::device::GooseDeviceProfile;
+use crate::identity::GooseClientCert;
+use crate::integrity;
+use crate::logger::{self, GooseLog};
 use crate::metrics::{
-    GooseCoordinatedOmissionMitigation, GooseMetric, GooseRawRequest, GooseRequestMetric,
+    GooseAnnotation, GooseCoordinatedOmissionMitigation, GooseMetric, GooseRawRequest,
+    GooseRequestMetric, GooseTransportError,
 };
+use crate::network::GooseNetworkProfile;
+use crate::range::{self, GooseRange};
+use crate::throttle::{self, GooseRateLimit};
+use crate::util;
+use crate::validate;
+use crate::wait::GooseWaitTime;
 use crate::{GooseConfiguration, GooseError, WeightedGooseTasks};
 
 /// By default Goose sets the following User-Agent header when making requests.
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
+/// The header marking requests with the load test run ID configured with `--run-id`, and
+/// the user making the request, for example `X-Goose-Run: nightly-42; user=3`.
+pub const RUN_ID_HEADER: &str = "x-goose-run";
+
+/// Counts cache busted requests, so each is given a unique value.
+static CACHE_BUSTER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 /// `task!(foo)` expands to `GooseTask::new(foo)`, but also does some boxing to work around a limitation in the compiler.
 #[macro_export]
 macro_rules! task {
@@ -366,6 +388,40 @@ pub enum GooseTaskError {
         /// The unrecognized HTTP request method.
         method: Method,
     },
+    /// A [`GooseTemplate`](../template/struct.GooseTemplate.html) could not be parsed or
+    /// rendered.
+    InvalidTemplate {
+        /// The placeholder that could not be rendered, or the template that could not
+        /// be parsed.
+        placeholder: String,
+        /// Why the template could not be parsed or rendered.
+        detail: String,
+    },
+    /// An XML document or XPath expression could not be parsed, see the
+    /// [`xml`](../xml/index.html) module.
+    InvalidXml {
+        /// Why the XML document or XPath expression could not be parsed.
+        detail: String,
+    },
+    /// A request selected a named client that was never added with
+    /// [`GooseUser::set_named_client_builder`].
+    InvalidClient {
+        /// The unrecognized client name.
+        name: String,
+    },
+    /// A [`setup`](./struct.GooseTaskSet.html#method.set_setup_task) or
+    /// [`teardown`](./struct.GooseTaskSet.html#method.set_teardown_task) task found the
+    /// load tested data wasn't as expected.
+    VerificationFailed {
+        /// What wasn't as expected.
+        detail: String,
+    },
+    /// A request to a Unix domain socket failed without a response, or used a feature
+    /// that isn't supported with a Unix domain socket.
+    UnixSocket {
+        /// Why the request failed.
+        detail: String,
+    },
 }
 /// Implement a helper to provide a text description of all possible types of errors.
 impl GooseTaskError {
@@ -380,6 +436,11 @@ impl GooseTaskError {
             GooseTaskError::MetricsFailed { .. } => "failed to send metrics to parent thread",
             GooseTaskError::LoggerFailed { .. } => "failed to send log message to logger thread",
             GooseTaskError::InvalidMethod { .. } => "unrecognized HTTP request method",
+            GooseTaskError::InvalidTemplate { .. } => "invalid template",
+            GooseTaskError::InvalidXml { .. } => "invalid XML",
+            GooseTaskError::InvalidClient { .. } => "unrecognized client name",
+            GooseTaskError::VerificationFailed { .. } => "verification failed",
+            GooseTaskError::UnixSocket { .. } => "Unix domain socket request failed",
         }
     }
 }
@@ -404,6 +465,28 @@ impl fmt::Display for GooseTaskError {
             GooseTaskError::LoggerFailed { ref source } => {
                 write!(f, "GooseTaskError: {} ({})", self.describe(), source)
             }
+            GooseTaskError::InvalidTemplate {
+                ref placeholder,
+                ref detail,
+            } => write!(
+                f,
+                "GooseTaskError: {} ({}: {})",
+                self.describe(),
+                placeholder,
+                detail
+            ),
+            GooseTaskError::InvalidXml { ref detail } => {
+                write!(f, "GooseTaskError: {} ({})", self.describe(), detail)
+            }
+            GooseTaskError::InvalidClient { ref name } => {
+                write!(f, "GooseTaskError: {} ({})", self.describe(), name)
+            }
+            GooseTaskError::VerificationFailed { ref detail } => {
+                write!(f, "GooseTaskError: {} ({})", self.describe(), detail)
+            }
+            GooseTaskError::UnixSocket { ref detail } => {
+                write!(f, "GooseTaskError: {} ({})", self.describe(), detail)
+            }
             _ => write!(f, "GooseTaskError: {}", self.describe()),
         }
     }
@@ -437,6 +520,23 @@ impl From<url::ParseError> for GooseTaskError {
     }
 }
 
+/// Auto-convert template errors.
+impl From<crate::template::GooseTemplateError> for GooseTaskError {
+    fn from(err: crate::template::GooseTemplateError) -> GooseTaskError {
+        GooseTaskError::InvalidTemplate {
+            placeholder: err.placeholder,
+            detail: err.detail,
+        }
+    }
+}
+
+/// Auto-convert XML errors.
+impl From<crate::xml::GooseXmlError> for GooseTaskError {
+    fn from(err: crate::xml::GooseXmlError) -> GooseTaskError {
+        GooseTaskError::InvalidXml { detail: err.detail }
+    }
+}
+
 /// When the throttle is enabled and the load test ends, the throttle channel is
 /// shut down. This causes a
 /// [`flume::SendError`](https://docs.rs/flume/*/flume/struct.SendError.html),
@@ -475,6 +575,9 @@ pub struct GooseTaskSet {
     /// A [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html) range defining the
     /// minimum and maximum time a [`GooseUser`] should sleep after running a task.
     pub task_wait: Option<(Duration, Duration)>,
+    /// An optional [`GooseWaitTime`](../wait/trait.GooseWaitTime.html) computing how long a
+    /// [`GooseUser`] should sleep after running a task, used instead of `task_wait`.
+    pub wait_time_provider: Option<Arc<dyn GooseWaitTime>>,
     /// A vector containing one copy of each [`GooseTask`](./struct.GooseTask.html) that will
     /// run by users running this task set.
     pub tasks: Vec<GooseTask>,
@@ -489,8 +592,40 @@ pub struct GooseTaskSet {
     /// [`on_stop`](./struct.GooseTask.html#method.set_on_stop)
     /// [`GooseTask`](./struct.GooseTask.html)s are run when the user first starts.
     pub weighted_on_stop_tasks: WeightedGooseTasks,
+    /// Named groups of tasks registered with
+    /// [`register_group`](./struct.GooseTaskSet.html#method.register_group). The tasks of
+    /// each group are moved into `tasks`.
+    pub groups: Vec<GooseTaskGroup>,
+    /// For each of the `weighted_tasks`, the index of the group if it is the last task of a
+    /// pass through that group, used to time each pass.
+    pub weighted_group_ends: Vec<Option<usize>>,
     /// An optional default host to run this `GooseTaskSet` against.
     pub host: Option<String>,
+    /// An optional hatch rate at which this `GooseTaskSet` launches its users, independent
+    /// of the global hatch rate.
+    pub hatch_rate: Option<String>,
+    /// An optional fixed number of users that run this `GooseTaskSet`, instead of a share
+    /// of `--users` proportional to its weight.
+    pub users: Option<usize>,
+    /// A flag indicating whether users running this `GooseTaskSet` are idle, until the task
+    /// set is enabled by a Controller.
+    pub disabled: bool,
+    /// Optional network conditions emulated by users running this `GooseTaskSet`.
+    pub network_profile: Option<GooseNetworkProfile>,
+    /// An optional task run once before any user is launched, for example to seed data.
+    pub setup_task: Option<GooseTask>,
+    /// An optional task run once after all users have exited, for example to verify data.
+    pub teardown_task: Option<GooseTask>,
+    /// An optional [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html)
+    /// range defining the minimum and maximum time a [`GooseUser`] backs off after
+    /// consecutive failed requests.
+    pub error_backoff: Option<(Duration, Duration)>,
+    /// Headers added to every request made by users running this `GooseTaskSet`, unless
+    /// the request already sets them.
+    pub default_headers: Vec<(String, String)>,
+    /// Query parameters added to every request made by users running this `GooseTaskSet`,
+    /// unless the request already sets them.
+    pub default_query: Vec<(String, String)>,
 }
 impl GooseTaskSet {
     /// Creates a new [`GooseTaskSet`](./struct.GooseTaskSet.html). Once created, a
@@ -511,11 +646,23 @@ impl GooseTaskSet {
             task_sets_index: usize::max_value(),
             weight: 1,
             task_wait: None,
+            wait_time_provider: None,
             tasks: Vec::new(),
             weighted_tasks: Vec::new(),
             weighted_on_start_tasks: Vec::new(),
             weighted_on_stop_tasks: Vec::new(),
+            groups: Vec::new(),
+            weighted_group_ends: Vec::new(),
             host: None,
+            hatch_rate: None,
+            users: None,
+            disabled: false,
+            network_profile: None,
+            setup_task: None,
+            teardown_task: None,
+            error_backoff: None,
+            default_headers: Vec::new(),
+            default_query: Vec::new(),
         }
     }
 
@@ -545,6 +692,72 @@ impl GooseTaskSet {
         self
     }
 
+    /// Registers a [`GooseTaskGroup`](./struct.GooseTaskGroup.html) with a
+    /// [`GooseTaskSet`](./struct.GooseTaskSet.html). The tasks of the group are stored in
+    /// the [`GooseTaskSet`](./struct.GooseTaskSet.html)`.tasks` vector, and always run
+    /// together as one pass through the group, ordered by their own weights and sequences.
+    /// Passes through each group are then run as often as the weight of the group.
+    ///
+    /// Each pass through a group is timed, including the time users wait between its
+    /// tasks, and displayed in the per-group metrics. A pass fails if any of its tasks
+    /// fail. Group tasks that run [`on_start`](./struct.GooseTask.html#method.set_on_start)
+    /// or [`on_stop`](./struct.GooseTask.html#method.set_on_stop) run with the other tasks
+    /// of the task set, and aren't part of a pass.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::goose::GooseTaskGroup;
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut example_tasks = taskset!("ExampleTasks")
+    ///         .register_group(
+    ///             GooseTaskGroup::new("browse")
+    ///                 .set_weight(7)?
+    ///                 .register_task(task!(index).set_sequence(1))
+    ///                 .register_task(task!(about).set_sequence(2)),
+    ///         )
+    ///         .register_group(
+    ///             GooseTaskGroup::new("purchase")
+    ///                 .set_weight(3)?
+    ///                 .register_task(task!(index).set_sequence(1))
+    ///                 .register_task(task!(checkout).set_sequence(2)),
+    ///         );
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn index(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn about(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("about/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn checkout(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.post("checkout/", "").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn register_group(mut self, mut group: GooseTaskGroup) -> Self {
+        trace!("{} register_group: {}", self.name, group.name);
+        let group_index = self.groups.len();
+        for mut task in group.tasks.drain(..) {
+            task.tasks_index = self.tasks.len();
+            task.group = Some(group_index);
+            self.tasks.push(task);
+        }
+        self.groups.push(group);
+        self
+    }
+
     /// Sets a weight on a task set. The larger the value of weight, the more often the task set will
     /// be assigned to users. For example, if you have task set foo with a weight of 3, and task set
     /// bar with a weight of 1, and you spin up a load test with 8 users, 6 of them will be running
@@ -592,6 +805,189 @@ impl GooseTaskSet {
         self
     }
 
+    /// Set a hatch rate for the task set, how many users running this task set are launched
+    /// per second. By default users from all task sets are launched at the global hatch rate
+    /// configured with `--hatch-rate` or `--startup-time`. Task sets with their own hatch
+    /// rate instead launch their users on an independent schedule, allowing for example a
+    /// background job scenario to ramp up slowly while a browsing scenario ramps up quickly.
+    ///
+    /// As with `--hatch-rate`, the rate can be a decimal, for example `0.1` launches one
+    /// user every 10 seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut example_tasks = taskset!("ExampleTasks").set_hatch_rate("0.5")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_hatch_rate(mut self, hatch_rate: &str) -> Result<Self, GooseError> {
+        trace!("{} set_hatch_rate: {}", self.name, hatch_rate);
+        match hatch_rate.parse::<f32>() {
+            Ok(h) if h > 0.0 => (),
+            _ => {
+                return Err(GooseError::InvalidOption {
+                    option: "GooseTaskSet.set_hatch_rate()".to_string(),
+                    value: hatch_rate.to_string(),
+                    detail: "Hatch rate must be a number greater than 0.".to_string(),
+                });
+            }
+        }
+        self.hatch_rate = Some(hatch_rate.to_string());
+
+        Ok(self)
+    }
+
+    /// Set a fixed number of users that run the task set. By default users are assigned to
+    /// task sets in proportion to their weight. A task set with a fixed number of users
+    /// instead always runs exactly that many users, and the rest of the users configured with
+    /// `--users` are assigned to the remaining task sets by weight. This makes it possible to
+    /// hold the concurrency of one scenario constant while scaling another.
+    ///
+    /// For example, with a `Checkout` task set set to 20 users and a `Browse` task set
+    /// without a fixed number of users, running the load test with `--users 500` launches
+    /// 20 `Checkout` users and 480 `Browse` users.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut example_tasks = taskset!("ExampleTasks").set_users(20)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_users(mut self, users: usize) -> Result<Self, GooseError> {
+        trace!("{} set_users: {}", self.name, users);
+        if users == 0 {
+            return Err(GooseError::InvalidOption {
+                option: "GooseTaskSet.set_users()".to_string(),
+                value: users.to_string(),
+                detail: "Users must be set to at least 1.".to_string(),
+            });
+        }
+        self.users = Some(users);
+
+        Ok(self)
+    }
+
+    /// Disable the task set when the load test starts. Users running a disabled task set are
+    /// launched as usual, but idle without running any tasks until the task set is enabled
+    /// with the Controller `enable` command. This makes it possible to introduce a scenario
+    /// partway through a load test, for example adding a heavy reporting scenario 20 minutes
+    /// into a steady-state test.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_tasks = taskset!("ExampleTasks").set_disabled();
+    /// ```
+    pub fn set_disabled(mut self) -> Self {
+        trace!("{} set_disabled", self.name);
+        self.disabled = true;
+        self
+    }
+
+    /// Make users running the task set emulate a slower network connection, such as a
+    /// mobile phone on a 3G network. Each request is delayed by the profile's latency, and
+    /// request and response bodies are transferred no faster than the profile's bandwidth.
+    /// Refer to the [`network`](../network/index.html) module for details.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::network::GooseNetworkProfile;
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_tasks =
+    ///     taskset!("ExampleTasks").set_network_profile(GooseNetworkProfile::slow_3g());
+    /// ```
+    pub fn set_network_profile(mut self, network_profile: GooseNetworkProfile) -> Self {
+        trace!("{} set_network_profile: {:?}", self.name, network_profile);
+        self.network_profile = Some(network_profile);
+        self
+    }
+
+    /// Set a task that is run one time before any user is launched, for example to seed
+    /// the data this task set's users load test. Unlike
+    /// [`on_start`](./struct.GooseTask.html#method.set_on_start) tasks, it is not run by
+    /// each user.
+    ///
+    /// The task is usually a closure capturing shared state, such as a database connection
+    /// pool, making it possible to prepare data without HTTP requests. It runs with a
+    /// one-time-use [`GooseUser`] against this task set's host, after any
+    /// [`test_start`](../struct.GooseAttack.html#method.test_start) task. How long it takes
+    /// and whether it succeeded is reported separately from the task metrics.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_tasks = taskset!("ExampleTasks").set_setup_task(task!(seed_data));
+    ///
+    /// /// Create the article the users of this task set load.
+    /// async fn seed_data(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.post("article", "title=Goose").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_setup_task(mut self, task: GooseTask) -> Self {
+        trace!("{} set_setup_task: {}", self.name, task.name);
+        self.setup_task = Some(task);
+        self
+    }
+
+    /// Set a task that is run one time after all users have exited, for example to verify
+    /// the data written by this task set's users, or to remove it. Unlike
+    /// [`on_stop`](./struct.GooseTask.html#method.set_on_stop) tasks, it is not run by
+    /// each user.
+    ///
+    /// The task is usually a closure capturing shared state, such as a database connection
+    /// pool, returning [`GooseTaskError::VerificationFailed`] if the data isn't as
+    /// expected. It runs with a one-time-use [`GooseUser`] against this task set's host, before
+    /// any [`test_stop`](../struct.GooseAttack.html#method.test_stop) task. How long it
+    /// takes and whether it succeeded is reported separately from the task metrics, so a
+    /// failed verification is visible alongside the load test results.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// // Shared state, counting the articles written by users.
+    /// let written = Arc::new(AtomicUsize::new(0));
+    ///
+    /// // Verify all articles were written.
+    /// let verify_written = written.clone();
+    /// let verify: GooseTaskFunction = Arc::new(move |_user| {
+    ///     let written = verify_written.load(Ordering::SeqCst);
+    ///     Box::pin(async move {
+    ///         if written == 0 {
+    ///             return Err(GooseTaskError::VerificationFailed {
+    ///                 detail: "no articles were written".to_string(),
+    ///             });
+    ///         }
+    ///
+    ///         Ok(())
+    ///     })
+    /// });
+    ///
+    /// let mut example_tasks = taskset!("ExampleTasks").set_teardown_task(GooseTask::new(verify));
+    /// ```
+    pub fn set_teardown_task(mut self, task: GooseTask) -> Self {
+        trace!("{} set_teardown_task: {}", self.name, task.name);
+        self.teardown_task = Some(task);
+        self
+    }
+
     /// Configure a task_set to to pause after running each task. The length of the pause will be randomly
     /// selected from `min_wait` to `max_wait` inclusively.
     ///
@@ -631,15 +1027,226 @@ impl GooseTaskSet {
 
         Ok(self)
     }
+
+    /// Configure a task set to pause after running each task for as long as a
+    /// [`GooseWaitTime`](../wait/trait.GooseWaitTime.html) provider returns, for example a
+    /// think time that depends on the time of day, or recorded gaps between requests
+    /// replayed with [`GooseReplayedWaitTime`](../wait/struct.GooseReplayedWaitTime.html).
+    /// The provider is used instead of any [`set_wait_time`](#method.set_wait_time) range.
+    /// Refer to the [`wait`](../wait/index.html) module for details.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use goose::wait::GooseReplayedWaitTime;
+    /// use std::time::Duration;
+    ///
+    /// let mut example_tasks = taskset!("ExampleTasks").set_wait_time_provider(
+    ///     GooseReplayedWaitTime::new(vec![Duration::from_secs(2), Duration::from_secs(1)]),
+    /// );
+    /// ```
+    pub fn set_wait_time_provider<T: GooseWaitTime + 'static>(mut self, provider: T) -> Self {
+        trace!("{} set_wait_time_provider", self.name);
+        self.wait_time_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Configure users running the task set to back off after failed requests, modeling
+    /// real users giving up on a broken site rather than hammering it. After each task, a
+    /// user that made consecutive failed requests pauses for `min_backoff`, doubled for
+    /// each further consecutive failure up to `max_backoff`, in addition to any
+    /// [`set_wait_time`](#method.set_wait_time) pause. Each successful request reduces the
+    /// count of consecutive failures by one, so users recover gradually.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     taskset!("ExampleTasks")
+    ///         .set_error_backoff(Duration::from_millis(500), Duration::from_secs(30))?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_error_backoff(
+        mut self,
+        min_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Result<Self, GooseError> {
+        trace!(
+            "{} set_error_backoff: min: {:?} max: {:?}",
+            self.name,
+            min_backoff,
+            max_backoff
+        );
+        if min_backoff.as_millis() == 0 || min_backoff > max_backoff {
+            return Err(GooseError::InvalidWaitTime {
+                min_wait: min_backoff,
+                max_wait: max_backoff,
+                detail: "The min_backoff option must be at least 1 millisecond, and can not be set to a larger value than the max_backoff option.".to_string(),
+            });
+        }
+        self.error_backoff = Some((min_backoff, max_backoff));
+
+        Ok(self)
+    }
+
+    /// Add a header to every request made by users running the task set, for example to
+    /// identify synthetic traffic so the target can filter it out of its analytics. The
+    /// header isn't added to requests that already set it, including headers set by the
+    /// [`GooseRequestBuilder`] or a custom client.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut example_tasks =
+    ///         taskset!("ExampleTasks").set_default_header("X-Test-Run", "20220301")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_default_header(mut self, name: &str, value: &str) -> Result<Self, GooseError> {
+        trace!("{} set_default_header: {}: {}", self.name, name, value);
+        if let Err(e) = header::HeaderName::from_bytes(name.as_bytes()) {
+            return Err(GooseError::InvalidOption {
+                option: "GooseTaskSet.set_default_header()".to_string(),
+                value: name.to_string(),
+                detail: format!("Invalid header name: {}.", e),
+            });
+        }
+        if let Err(e) = header::HeaderValue::from_str(value) {
+            return Err(GooseError::InvalidOption {
+                option: "GooseTaskSet.set_default_header()".to_string(),
+                value: value.to_string(),
+                detail: format!("Invalid header value: {}.", e),
+            });
+        }
+        self.default_headers
+            .push((name.to_string(), value.to_string()));
+
+        Ok(self)
+    }
+
+    /// Add a query parameter to every request made by users running the task set, for
+    /// example to route synthetic traffic to a test tenant. The value is URL-encoded, and
+    /// isn't added to requests that already set a query parameter with the same name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut example_tasks = taskset!("ExampleTasks").set_default_query("tenant", "loadtest");
+    /// ```
+    pub fn set_default_query(mut self, name: &str, value: &str) -> Self {
+        trace!("{} set_default_query: {}={}", self.name, name, value);
+        self.default_query
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A named group of [`GooseTask`](./struct.GooseTask.html)s within a
+/// [`GooseTaskSet`](./struct.GooseTaskSet.html), run together with their own weight, for
+/// example to model one journey through the load tested site.
+#[derive(Clone, Hash)]
+pub struct GooseTaskGroup {
+    /// The name of the group, used when displaying metrics.
+    pub name: String,
+    /// An integer value that controls how often a pass through this group is run,
+    /// compared to other groups in the same task set.
+    pub weight: usize,
+    /// The tasks of the group, until it is registered with a
+    /// [`GooseTaskSet`](./struct.GooseTaskSet.html).
+    pub tasks: Vec<GooseTask>,
+}
+impl GooseTaskGroup {
+    /// Creates a new [`GooseTaskGroup`](./struct.GooseTaskGroup.html). Once created,
+    /// [`GooseTask`](./struct.GooseTask.html)s must be assigned to it, and finally it must
+    /// be registered with a [`GooseTaskSet`](./struct.GooseTaskSet.html).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::goose::GooseTaskGroup;
+    ///
+    /// let mut browse = GooseTaskGroup::new("browse");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        trace!("new task group: name: {}", &name);
+        GooseTaskGroup {
+            name: name.to_string(),
+            weight: 1,
+            tasks: Vec::new(),
+        }
+    }
+
+    /// Registers a [`GooseTask`](./struct.GooseTask.html) with a
+    /// [`GooseTaskGroup`](./struct.GooseTaskGroup.html). Tasks run in the order of their
+    /// sequence, and as often as their weight within each pass through the group.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::goose::GooseTaskGroup;
+    /// use goose::prelude::*;
+    ///
+    /// let mut browse = GooseTaskGroup::new("browse").register_task(task!(a_task_function));
+    ///
+    /// /// A very simple task that loads the "a" page.
+    /// async fn a_task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("a/").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn register_task(mut self, task: GooseTask) -> Self {
+        trace!("{} register_task: {}", self.name, task.name);
+        self.tasks.push(task);
+        self
+    }
+
+    /// Sets a weight on a task group. The larger the value of weight, the more often a pass
+    /// through the group is run. For example, if group browse has a weight of 7 and group
+    /// purchase has a weight of 3, users run 7 passes through browse for every 3 passes
+    /// through purchase.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::goose::GooseTaskGroup;
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut browse = GooseTaskGroup::new("browse").set_weight(7)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_weight(mut self, weight: usize) -> Result<Self, GooseError> {
+        trace!("{} set_weight: {}", self.name, weight);
+        if weight == 0 {
+            return Err(GooseError::InvalidWeight {
+                weight,
+                detail: ("Weight must be set to at least 1.".to_string()),
+            });
+        }
+        self.weight = weight;
+
+        Ok(self)
+    }
 }
 
 /// Commands sent from the parent thread to the user threads, and from the manager to the
 /// worker processes.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GooseUserCommand {
-    /// Tell worker process to pause load test.
+    /// Tell worker process to pause load test, or user thread to idle.
     Wait,
-    /// Tell worker process to start load test.
+    /// Tell worker process to start load test, or idle user thread to resume running tasks.
     Run,
     /// Tell user thread or worker process to exit.
     Exit,
@@ -700,34 +1307,70 @@ impl GooseResponse {
     }
 }
 
+/// The version of the [`GooseDebug`] schema, included in every debug log message.
+///
+/// The version is incremented whenever a field is added, removed or changes type, so
+/// parsers of the debug log can detect messages they don't understand. Version 1 was the
+/// untyped format with only `tag`, `request`, `header` and `body` fields.
+pub const GOOSE_DEBUG_SCHEMA_VERSION: u32 = 2;
+
 /// Object created by [`log_debug()`](struct.GooseUser.html#method.log_debug) and written
 /// to log to assist in debugging.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GooseDebug {
+    /// The [`GOOSE_DEBUG_SCHEMA_VERSION`] this message was written with.
+    pub schema_version: u32,
+    /// Unix timestamp when the message was logged, in milliseconds.
+    pub timestamp: u64,
+    /// Milliseconds since the user started when the message was logged.
+    pub elapsed: u64,
+    /// Which [`GooseUser`] logged the message.
+    pub user: usize,
+    /// Optional name of the task that was running.
+    pub task_name: Option<String>,
+    /// Optional name of the request made.
+    pub request_name: Option<String>,
     /// String to identify the source of the log message.
     pub tag: String,
     /// Optional request made.
     pub request: Option<GooseRequestMetric>,
-    /// Optional headers returned by server.
-    pub header: Option<String>,
+    /// Optional headers returned by server, as name and value pairs.
+    pub headers: Option<Vec<(String, String)>>,
     /// Optional body text returned by server.
     pub body: Option<String>,
 }
 impl GooseDebug {
     fn new(
+        user: &GooseUser,
         tag: &str,
         request: Option<&GooseRequestMetric>,
-        header: Option<&header::HeaderMap>,
+        headers: Option<&header::HeaderMap>,
         body: Option<&str>,
     ) -> Self {
         GooseDebug {
+            schema_version: GOOSE_DEBUG_SCHEMA_VERSION,
+            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+            elapsed: user.started.elapsed().as_millis() as u64,
+            user: user.weighted_users_index,
+            task_name: user.task_name.clone(),
+            // If request is defined, copy its name.
+            request_name: request.map(|r| r.name.to_string()),
             // Convert tag from &str to string.
             tag: tag.to_string(),
             // If request is defined, clone it.
             request: request.cloned(),
-            // If header is defined, convert it to a string.
-            header: header.map(|h| format!("{:?}", h)),
-            // If header is defined, convert from &str to string.
+            // If headers are defined, convert each to a name and value pair.
+            headers: headers.map(|h| {
+                h.iter()
+                    .map(|(name, value)| {
+                        (
+                            name.to_string(),
+                            String::from_utf8_lossy(value.as_bytes()).to_string(),
+                        )
+                    })
+                    .collect()
+            }),
+            // If body is defined, convert from &str to string.
             body: body.map(|b| b.to_string()),
         }
     }
@@ -791,6 +1434,12 @@ struct GooseRequestCadence {
     /// If -1 coordinated_omission_mitigation was never enabled. Otherwise is a counter of how
     /// many times the mitigation triggered.
     coordinated_omission_counter: isize,
+    /// How many milliseconds the load test had been running when the previous request
+    /// started, if `--co-cadence` is enabled.
+    previous_request_elapsed: Option<u64>,
+    /// How many milliseconds this GooseUser had slept between GooseTasks when the previous
+    /// request started, if `--co-cadence` is enabled.
+    previous_request_slept: u64,
 }
 impl GooseRequestCadence {
     // Return a new, empty RequestCadence object.
@@ -806,6 +1455,8 @@ impl GooseRequestCadence {
             coordinated_omission_mitigation: 0,
             user_cadence: 0,
             coordinated_omission_counter: -1,
+            previous_request_elapsed: None,
+            previous_request_slept: 0,
         }
     }
 }
@@ -830,6 +1481,16 @@ pub trait GooseUserData: Downcast + Send + Sync + 'static {}
 impl_downcast!(GooseUserData);
 impl<T: Send + Sync + 'static> GooseUserData for T {}
 
+/// Shared by all [`GooseUser`]s so any of them can abort the load test with
+/// [`GooseUser::abort_test`], recording why.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GooseAbort {
+    /// Set to stop the load test, the same as with ctrl-c.
+    pub(crate) canceled: Arc<AtomicBool>,
+    /// Why the load test was aborted, from the first call to [`GooseUser::abort_test`].
+    pub(crate) reason: Arc<Mutex<Option<String>>>,
+}
+
 /// An individual user state, repeatedly running all [`GooseTask`](./struct.GooseTask.html)s
 /// in a specific [`GooseTaskSet`](./struct.GooseTaskSet.html).
 pub struct GooseUser {
@@ -840,6 +1501,9 @@ pub struct GooseUser {
     pub task_sets_index: usize,
     /// Client used to make requests, managing sessions and cookies.
     pub client: Client,
+    /// Additional named clients, added with [`GooseUser::set_named_client_builder`] and
+    /// selected per request with [`GooseRequestBuilder::client`].
+    clients: HashMap<String, Client>,
     /// The base URL to prepend to all relative paths.
     pub base_url: Url,
     /// A local copy of the global [`GooseConfiguration`](../struct.GooseConfiguration.html).
@@ -852,6 +1516,12 @@ pub struct GooseUser {
     /// [`test_start`](../struct.GooseAttack.html#method.test_start) and
     /// [`test_stop`](../struct.GooseAttack.html#method.test_stop) tasks are not.
     pub is_throttled: bool,
+    /// Channel reporting the target server's rate limits to the adaptive throttle.
+    pub(crate) rate_limit: Option<flume::Sender<GooseRateLimit>>,
+    /// Allows this user to abort the load test.
+    pub(crate) abort: Option<GooseAbort>,
+    /// Allows this user to stop launching more users.
+    pub(crate) hatching_stopped: Option<Arc<AtomicBool>>,
     /// Channel to parent.
     pub channel_to_parent: Option<flume::Sender<GooseMetric>>,
     /// An index into the internal [`GooseAttack`](../struct.GooseAttack.html)`.weighted_users`
@@ -869,6 +1539,65 @@ pub struct GooseUser {
     /// Optional per-user session data of a generic type implementing the
     /// [`GooseUserData`] trait.
     session_data: Option<Box<dyn GooseUserData>>,
+    /// Per-user random number generator, optionally seeded with `--seed`.
+    pub(crate) rng: StdRng,
+    /// Optional network conditions emulated by this user, from its task set.
+    pub(crate) network_profile: Option<GooseNetworkProfile>,
+    /// Optional device profile assigned to this user with `--device-profiles`.
+    pub(crate) device_profile: Option<GooseDeviceProfile>,
+    /// Optional cohort assigned to this user with `--cohorts`.
+    pub(crate) cohort: Option<String>,
+    /// Channel to the background validators, while the load test runs.
+    pub(crate) validation: Option<validate::GooseValidationTx>,
+    /// JSON Schemas registered with `GooseAttack::register_json_schema`, while the load
+    /// test runs.
+    #[cfg(feature = "json-schema")]
+    pub(crate) json_schemas: Option<crate::schema::GooseJsonSchemas>,
+    /// Optional client certificate assigned to this user with `--client-certs`.
+    pub(crate) client_cert: Option<GooseClientCert>,
+    /// Optional range of time this user backs off after failed requests, from its task set.
+    pub(crate) error_backoff: Option<(Duration, Duration)>,
+    /// Headers added to each request unless already set, from its task set.
+    pub(crate) default_headers: Vec<(String, String)>,
+    /// Query parameters added to each request unless already set, from its task set.
+    pub(crate) default_query: Vec<(String, String)>,
+    /// How many more requests have recently failed than succeeded, if backing off.
+    pub(crate) consecutive_errors: usize,
+    /// The backend that served this user's most recent response, if verifying session
+    /// affinity with `--affinity-header`.
+    backend: Option<String>,
+    /// The base URL of variant traffic, if configured with `--variant-host`.
+    variant_base_url: Option<Url>,
+    /// Whether requests are made to a Unix domain socket.
+    pub(crate) unix_socket: bool,
+    /// The client requests are made with when load testing a Unix domain socket.
+    #[cfg(unix)]
+    unix_client: Option<crate::unix::GooseUnixClient>,
+    /// Optional credentials used to complete NTLM authentication challenges.
+    #[cfg(feature = "ntlm")]
+    pub(crate) ntlm: Option<crate::ntlm::NtlmCredentials>,
+    /// Optional credentials and most recent challenge used for Digest authentication.
+    pub(crate) digest: Option<crate::digest::DigestAuth>,
+    /// Idempotency key of the current pass through the user's tasks, if one was requested.
+    pub(crate) idempotency_key: Option<String>,
+    /// ETags remembered with [`GooseUser::remember_etag`], by name.
+    pub(crate) etags: HashMap<String, String>,
+    /// How many bytes of response bodies are written to the debug log, if limited.
+    debug_body_limit: Option<usize>,
+    /// Only write response bodies with these status codes to the debug log, if not empty.
+    debug_body_status: Vec<u16>,
+}
+// GooseUser can't derive Debug as its session data is a trait object.
+impl fmt::Debug for GooseUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GooseUser")
+            .field("task_sets_index", &self.task_sets_index)
+            .field("weighted_users_index", &self.weighted_users_index)
+            .field("base_url", &self.base_url)
+            .field("client", &self.client)
+            .field("has_session_data", &self.session_data.is_some())
+            .finish()
+    }
 }
 impl GooseUser {
     /// Create a new user state.
@@ -879,22 +1608,50 @@ impl GooseUser {
         load_test_hash: u64,
     ) -> Result<Self, GooseError> {
         trace!("new GooseUser");
-        let client = Client::builder()
-            .user_agent(APP_USER_AGENT)
-            .cookie_store(true)
-            // Enable gzip unless `--no-gzip` flag is enabled.
-            .gzip(!configuration.no_gzip)
-            .build()?;
+
+        // Requests to a host only reachable through an SSH jump host are made through a
+        // tunnel, which must be open before the client is built.
+        let base_url = if configuration.ssh_jump_host.is_empty() || base_url.scheme() == "unix" {
+            base_url
+        } else {
+            crate::ssh::tunnel_base_url(&base_url, configuration)?
+        };
+        let builder = client_builder(configuration, None);
+
+        // Requests to a Unix domain socket are made with their own client.
+        let unix_socket = base_url.scheme() == "unix";
+        #[cfg(unix)]
+        let (base_url, unix_client) = if unix_socket {
+            let (base_url, unix_client) =
+                crate::unix::unix_client(&base_url, configuration, APP_USER_AGENT)?;
+            (base_url, Some(unix_client))
+        } else {
+            (base_url, None)
+        };
+        #[cfg(not(unix))]
+        if unix_socket {
+            return Err(GooseError::InvalidOption {
+                option: "--host".to_string(),
+                value: base_url.to_string(),
+                detail: "Unix domain sockets are only supported on Unix platforms.".to_string(),
+            });
+        }
+
+        let client = builder.build()?;
 
         Ok(GooseUser {
             started: Instant::now(),
             task_sets_index,
             client,
+            clients: HashMap::new(),
             base_url,
             config: configuration.clone(),
             logger: None,
             throttle: None,
             is_throttled: true,
+            rate_limit: None,
+            abort: None,
+            hatching_stopped: None,
             channel_to_parent: None,
             // A value of max_value() indicates this user isn't fully initialized yet.
             weighted_users_index: usize::max_value(),
@@ -903,6 +1660,31 @@ impl GooseUser {
             slept: 0,
             task_name: None,
             session_data: None,
+            rng: crate::random::user_rng(configuration.seed, 0),
+            network_profile: None,
+            device_profile: None,
+            cohort: None,
+            validation: None,
+            #[cfg(feature = "json-schema")]
+            json_schemas: None,
+            client_cert: None,
+            error_backoff: None,
+            default_headers: Vec::new(),
+            default_query: Vec::new(),
+            consecutive_errors: 0,
+            backend: None,
+            variant_base_url: Url::parse(&configuration.variant_host).ok(),
+            unix_socket,
+            #[cfg(unix)]
+            unix_client,
+            #[cfg(feature = "ntlm")]
+            ntlm: None,
+            digest: None,
+            idempotency_key: None,
+            etags: HashMap::new(),
+            debug_body_limit: configuration.debug_log_body_limit,
+            debug_body_status: logger::parse_status_codes(&configuration.debug_log_body_status)
+                .unwrap_or_default(),
         })
     }
 
@@ -1069,6 +1851,107 @@ impl GooseUser {
         self.session_data.replace(Box::new(data));
     }
 
+    // Add the default headers and query parameters of this user's task set, unless the
+    // request already sets them.
+    fn set_task_set_defaults(&self, request: &mut reqwest::Request) {
+        for (name, value) in &self.default_headers {
+            // Headers were validated when added to the task set.
+            if let (Ok(name), Ok(value)) = (
+                header::HeaderName::from_bytes(name.as_bytes()),
+                header::HeaderValue::from_str(value),
+            ) {
+                request.headers_mut().entry(name).or_insert(value);
+            }
+        }
+
+        let query: Vec<&(String, String)> = self
+            .default_query
+            .iter()
+            .filter(|(name, _)| !request.url().query_pairs().any(|(n, _)| n == name.as_str()))
+            .collect();
+        // Only touch the query if adding parameters, to not append an empty "?".
+        if !query.is_empty() {
+            let mut pairs = request.url_mut().query_pairs_mut();
+            for (name, value) in query {
+                pairs.append_pair(name, value);
+            }
+        }
+    }
+
+    // Mark the request with the run ID and the index of this user, as included in the
+    // request log, so the target can tell load test traffic apart.
+    fn set_run_id_header(&self, request: &mut reqwest::Request) {
+        if self.config.run_id.is_empty() {
+            return;
+        }
+        // The run ID was validated at startup.
+        if let Ok(value) = header::HeaderValue::from_str(&format!(
+            "{}; user={}",
+            self.config.run_id, self.weighted_users_index
+        )) {
+            request.headers_mut().insert(RUN_ID_HEADER, value);
+        }
+    }
+
+    // Add a unique query parameter or header to the request if configured with
+    // `--cache-buster`, so it bypasses caches. Requests are named before this, so the
+    // unique value doesn't split their metrics.
+    fn set_cache_buster(&self, request: &mut reqwest::Request, request_name: &str) {
+        if self.config.cache_buster.is_empty()
+            || (!self.config.cache_buster_names.is_empty()
+                && !self
+                    .config
+                    .cache_buster_names
+                    .split(',')
+                    .any(|name| name.trim() == request_name))
+        {
+            return;
+        }
+
+        // Unique across users, and across load tests as the time is included.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or(0);
+        let unique = format!(
+            "{}-{}",
+            now,
+            CACHE_BUSTER_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        // The cache buster was validated at startup.
+        match util::parse_cache_buster(&self.config.cache_buster) {
+            Some(util::CacheBuster::Query(name, value)) => {
+                request
+                    .url_mut()
+                    .query_pairs_mut()
+                    .append_pair(name, &value.replace("{}", &unique));
+            }
+            Some(util::CacheBuster::Header(name, value)) => {
+                if let (Ok(name), Ok(value)) = (
+                    header::HeaderName::from_bytes(name.as_bytes()),
+                    header::HeaderValue::from_str(&value.replace("{}", &unique)),
+                ) {
+                    request.headers_mut().insert(name, value);
+                }
+            }
+            None => {}
+        }
+    }
+
+    // Take over the clients and session data of a user from a previous load test, so
+    // connections, cookies and session data carry over.
+    pub(crate) fn reuse(&mut self, previous_user: GooseUser) {
+        self.client = previous_user.client;
+        self.clients = previous_user.clients;
+        self.session_data = previous_user.session_data;
+        self.client_cert = previous_user.client_cert;
+        self.digest = previous_user.digest;
+        #[cfg(feature = "ntlm")]
+        {
+            self.ntlm = previous_user.ntlm;
+        }
+    }
+
     /// A helper that prepends a `base_url` to all relative paths.
     ///
     /// A `base_url` is determined per user thread, using the following order
@@ -1079,15 +1962,7 @@ impl GooseUser {
     ///  3. [`GooseDefault::Host`](../config/enum.GooseDefault.html#variant.Host) (default host
     /// defined for the current load test)
     pub fn build_url(&self, path: &str) -> Result<String, GooseTaskError> {
-        // If URL includes a host, simply use it.
-        if let Ok(parsed_path) = Url::parse(path) {
-            if let Some(_host) = parsed_path.host() {
-                return Ok(path.to_string());
-            }
-        }
-
-        // Otherwise use the `base_url`.
-        Ok(self.base_url.join(path)?.to_string())
+        join_url(&self.base_url, path)
     }
 
     /// A helper to make a `GET` request of a path and collect relevant metrics.
@@ -1167,6 +2042,51 @@ impl GooseUser {
         Ok(self.request(goose_request).await?)
     }
 
+    /// A helper to make a `GET` request of a path with query parameters and collect
+    /// relevant metrics. Automatically prepends the correct host.
+    ///
+    /// Parameter names and values are URL-encoded and appended to any query already
+    /// included in the path, so values don't need to be escaped. The request is named
+    /// after the path without the parameters, so requests with different parameter values
+    /// are grouped together in metrics. To name the request differently, or to add query
+    /// parameters to other methods, use [`GooseRequestBuilder::query`].
+    ///
+    /// Calls to `get_with_params()` return a [`GooseResponse`](./struct.GooseResponse.html) object which
+    /// contains a copy of the request you made ([`GooseRequestMetric`](./struct.GooseRequestMetric.html)),
+    /// and the response ([`reqwest::Response`](https://docs.rs/reqwest/*/reqwest/struct.Response.html)).
+    ///
+    /// # Example
+    /// GET a search page, tracking all searches as `GET search` in collected metrics.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut task = task!(get_function);
+    ///
+    /// /// A very simple task that makes a GET request with query parameters.
+    /// async fn get_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let page = 2.to_string();
+    ///     let _goose = user
+    ///         .get_with_params("search", &[("q", "rust & goose"), ("page", &page)])
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_with_params(
+        &mut self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<GooseResponse, GooseTaskError> {
+        // GET path with query parameters.
+        let mut goose_request = GooseRequest::builder().method(GooseMethod::Get).path(path);
+        for (name, value) in params {
+            goose_request = goose_request.query(name, value);
+        }
+
+        // Make the request and return the GooseResponse.
+        self.request(goose_request.build()).await
+    }
+
     /// A helper to make a `POST` request of a path and collect relevant metrics.
     /// Automatically prepends the correct host.
     ///
@@ -1422,18 +2342,104 @@ impl GooseUser {
         method: &GooseMethod,
         path: &str,
     ) -> Result<RequestBuilder, GooseTaskError> {
-        // Prepend the `base_url` to all relative paths.
-        let url = self.build_url(path)?;
+        self.build_request_builder(&self.client, method, &self.build_url(path)?)
+    }
+
+    // Randomly decide if a request is sent to the variant configured with `--variant-host`
+    // and/or `--variant-header`, sending `--variant-percent` of all requests to the variant.
+    fn is_variant(&mut self) -> bool {
+        match self.config.variant_percent {
+            Some(variant_percent) => self.rng.gen_range(0..100) < variant_percent,
+            None => false,
+        }
+    }
+
+    // Record which backend served a response, flagging an affinity violation if a different
+    // backend served this user's previous response. The user then sticks to the new backend,
+    // so each switch is only counted once.
+    fn record_backend(&mut self, request_metric: &mut GooseRequestMetric, backend: Option<String>) {
+        if let Some(backend) = backend {
+            if let Some(previous_backend) = self.backend.as_ref() {
+                if previous_backend != &backend {
+                    debug!(
+                        "user {} moved from backend {} to {}",
+                        self.weighted_users_index + 1,
+                        previous_backend,
+                        backend
+                    );
+                    request_metric.affinity_violation = true;
+                }
+            }
+            self.backend = Some(backend.clone());
+            request_metric.backend = Some(backend);
+        }
+    }
+
+    // Describe why requests can't be made, if they use a feature that isn't supported with
+    // a Unix domain socket. Authentication challenges are answered by retrying the request
+    // with the default client, which can't connect to a Unix domain socket.
+    #[cfg(unix)]
+    fn unix_socket_unsupported(&self) -> Option<&'static str> {
+        self.unix_client.as_ref()?;
+        #[cfg(feature = "ntlm")]
+        if self.ntlm.is_some() {
+            return Some("NTLM authentication is not supported with a Unix domain socket");
+        }
+        if self.digest.is_some() {
+            return Some("Digest authentication is not supported with a Unix domain socket");
+        }
+        None
+    }
+
+    // Record a request to a Unix domain socket that failed without a response, returning
+    // the error to return from the request.
+    #[cfg(unix)]
+    async fn unix_request_failed(
+        &mut self,
+        mut request_metric: GooseRequestMetric,
+        request_name: &str,
+        started: Instant,
+        unix_error: crate::unix::UnixRequestError,
+    ) -> GooseTaskError {
+        warn!("{:?}: {}", &request_metric.raw.url, unix_error);
+        request_metric.set_response_time(started.elapsed().as_millis());
+        request_metric.success = false;
+        request_metric.set_status_code(None);
+        request_metric.error = unix_error.describe(request_name);
+        request_metric.transport_error = unix_error.transport_error;
+
+        match self.coordinated_omission_mitigation(&request_metric).await {
+            Ok(user_cadence) => request_metric.user_cadence = user_cadence,
+            Err(e) => return e,
+        }
+        self.record_backoff(false);
+        if !self.config.no_metrics {
+            if let Err(e) = self.send_request_metric_to_parent(request_metric) {
+                return e;
+            }
+        }
 
+        GooseTaskError::UnixSocket {
+            detail: unix_error.to_string(),
+        }
+    }
+
+    // Build a RequestBuilder for the provided URL with the provided client.
+    fn build_request_builder(
+        &self,
+        client: &Client,
+        method: &GooseMethod,
+        url: &str,
+    ) -> Result<RequestBuilder, GooseTaskError> {
         // Invoke appropriate Reqwest convenience function to generate an
         // appropriate RequestBuilder.
         Ok(match method {
-            GooseMethod::Delete => self.client.delete(&url),
-            GooseMethod::Get => self.client.get(&url),
-            GooseMethod::Head => self.client.head(&url),
-            GooseMethod::Patch => self.client.patch(&url),
-            GooseMethod::Post => self.client.post(&url),
-            GooseMethod::Put => self.client.put(&url),
+            GooseMethod::Delete => client.delete(url),
+            GooseMethod::Get => client.get(url),
+            GooseMethod::Head => client.head(url),
+            GooseMethod::Patch => client.patch(url),
+            GooseMethod::Post => client.post(url),
+            GooseMethod::Put => client.put(url),
         })
     }
 
@@ -1474,16 +2480,76 @@ impl GooseUser {
         &mut self,
         mut request: GooseRequest<'_>,
     ) -> Result<GooseResponse, GooseTaskError> {
+        // Use the named client if one was selected, otherwise the default client.
+        let client = match request.client {
+            Some(name) => match self.clients.get(name) {
+                Some(client) => client.clone(),
+                None => {
+                    return Err(GooseTaskError::InvalidClient {
+                        name: name.to_string(),
+                    })
+                }
+            },
+            None => self.client.clone(),
+        };
+
+        // Fail rather than ignore features that aren't supported with a Unix domain socket.
+        #[cfg(unix)]
+        if let Some(detail) = self.unix_socket_unsupported() {
+            return Err(GooseTaskError::UnixSocket {
+                detail: detail.to_string(),
+            });
+        }
+
+        // Decide if this request is sent to the variant.
+        let variant = self.is_variant();
+
         // If the RequestBuilder is already defined in the GooseRequest use it.
-        let request_builder = if request.request_builder.is_some() {
+        let mut request_builder = if request.request_builder.is_some() {
             request.request_builder.take().unwrap()
-        // Otherwise get a new RequestBuilder.
+        // Otherwise get a new RequestBuilder, prepending the variant host if configured.
         } else {
-            self.get_request_builder(&request.method, request.path)?
+            let url = match self.variant_base_url.as_ref() {
+                Some(variant_base_url) if variant => join_url(variant_base_url, request.path)?,
+                _ => self.build_url(request.path)?,
+            };
+            self.build_request_builder(&client, &request.method, &url)?
         };
 
-        // Determine the name for this request.
-        let request_name = self.get_request_name(&request);
+        // Add the variant header, if configured.
+        if variant {
+            if let Some((name, value)) = util::parse_header(&self.config.variant_header) {
+                request_builder = request_builder.header(name, value);
+            }
+        }
+
+        // Add any headers, query parameters and timeout configured with the
+        // GooseRequestBuilder.
+        for (name, value) in &request.headers {
+            request_builder = request_builder.header(*name, *value);
+        }
+        if !request.query.is_empty() {
+            request_builder = request_builder.query(&request.query);
+        }
+        if let Some(timeout) = request.timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        // Request part of the response, uncompressed so the range refers to the bytes of
+        // the response itself.
+        if let Some(range) = request.range {
+            request_builder = request_builder
+                .header(header::RANGE, range.to_string())
+                .header(header::ACCEPT_ENCODING, "identity");
+        }
+
+        // Determine the name for this request, labeling requests sent to the variant so
+        // their metrics are reported separately.
+        let request_name = if variant {
+            format!("{} [variant]", self.get_request_name(&request))
+        } else {
+            self.get_request_name(&request).to_string()
+        };
 
         // If throttle-requests is enabled...
         if self.is_throttled && self.throttle.is_some() {
@@ -1497,7 +2563,28 @@ impl GooseUser {
         let started = Instant::now();
 
         // Create a Reqwest Request object from the RequestBuilder.
-        let built_request = request_builder.build()?;
+        let mut built_request = request_builder.build()?;
+
+        // Set the headers of this user's device profile, if any.
+        self.set_device_headers(&mut built_request);
+
+        // Set the header of this user's cohort, if any.
+        self.set_cohort_header(&mut built_request);
+
+        // Set the configured Accept-Encoding header, if any.
+        self.set_accept_encoding(&mut built_request);
+
+        // Set the default headers and query parameters of this user's task set, if any.
+        self.set_task_set_defaults(&mut built_request);
+
+        // Mark the request as load test traffic, if configured with `--run-id`.
+        self.set_run_id_header(&mut built_request);
+
+        // Bypass caches with a unique value, if configured with `--cache-buster`.
+        self.set_cache_buster(&mut built_request, self.get_request_name(&request));
+
+        // Authorize the request in advance if a Digest challenge was already received.
+        self.set_digest_authorization(&mut built_request);
 
         // Get a string version of request path for logging.
         let path = match Url::parse(&built_request.url().to_string()) {
@@ -1540,14 +2627,88 @@ impl GooseUser {
         // Record information about the request.
         let mut request_metric = GooseRequestMetric::new(
             raw_request,
-            request_name,
+            &request_name,
             self.started.elapsed().as_millis(),
             self.weighted_users_index,
         );
 
-        // Make the actual request.
-        let response = self.client.execute(built_request).await;
+        // Record the device profile as a metrics dimension.
+        if let Some(device_profile) = self.device_profile {
+            request_metric
+                .dimensions
+                .insert("device".to_string(), device_profile.to_string());
+        }
+
+        // Record the cohort as a metrics dimension.
+        if let Some(cohort) = self.cohort.as_ref() {
+            request_metric
+                .dimensions
+                .insert("cohort".to_string(), cohort.to_string());
+        }
+
+        // Make the actual request, emulating a slow network connection if configured.
+        self.delay_request(&built_request).await;
+        // If NTLM credentials are set, keep a copy of the request to retry if challenged.
+        #[cfg(feature = "ntlm")]
+        let retry_request = self.ntlm.as_ref().and_then(|_| built_request.try_clone());
+        // If Digest credentials are set, keep a copy of the request to retry if challenged.
+        let digest_request = self.digest.as_ref().and_then(|_| built_request.try_clone());
+        // Requests to a Unix domain socket are sent with their own client. Those that fail
+        // without a response are recorded as failed before returning an error, as there's
+        // no reqwest::Error to return in the GooseResponse.
+        #[cfg(unix)]
+        let response = match self.unix_client.as_ref() {
+            Some(unix_client) => match unix_client.execute(built_request).await {
+                Ok(response) => Ok(response),
+                Err(unix_error) => {
+                    return Err(self
+                        .unix_request_failed(request_metric, &request_name, started, unix_error)
+                        .await)
+                }
+            },
+            None => client.execute(built_request).await,
+        };
+        #[cfg(not(unix))]
+        let response = client.execute(built_request).await;
+        #[cfg(feature = "ntlm")]
+        let response = self
+            .ntlm_authenticate(&client, retry_request, response)
+            .await;
+        let response = self
+            .digest_authenticate(&client, digest_request, response)
+            .await;
+        let response = self.delay_response(response).await;
         request_metric.set_response_time(started.elapsed().as_millis());
+        request_metric.set_response_time_budget(request.expect_response_time);
+
+        // If enabled, read the response body to record its size, after the response time.
+        let response = self.measure_body(response, &mut request_metric).await;
+
+        // If expected, read the response body to verify its checksum and length.
+        let response = integrity::verify_body(
+            response,
+            request.expect_body_sha1,
+            request.expect_body_length,
+            &mut request_metric,
+        )
+        .await;
+
+        // If a range was requested, read the response body to verify it's the requested
+        // part.
+        let response =
+            range::verify_partial_content(response, request.range, &mut request_metric).await;
+
+        // If a JSON Schema is registered for this request, read the response body to
+        // validate it.
+        #[cfg(feature = "json-schema")]
+        let response = crate::schema::verify_body(
+            response,
+            self.json_schemas
+                .as_ref()
+                .and_then(|json_schemas| json_schemas.get(self.get_request_name(&request))),
+            &mut request_metric,
+        )
+        .await;
 
         // Determine if the request suceeded or failed.
         match &response {
@@ -1572,6 +2733,45 @@ impl GooseUser {
                     request_metric.error = format!("{}: {}", status_code, request_name);
                 }
 
+                // If verifying session affinity, record which backend served the response.
+                if !self.config.affinity_header.is_empty() {
+                    let backend = r
+                        .headers()
+                        .get(self.config.affinity_header.as_str())
+                        .and_then(|value| value.to_str().ok())
+                        .map(|value| value.to_string());
+                    self.record_backend(&mut request_metric, backend);
+                }
+
+                // Record the values of response headers tracked as metrics dimensions.
+                for header in self
+                    .config
+                    .header_dimensions
+                    .split(',')
+                    .map(|header| header.trim().to_lowercase())
+                    .filter(|header| !header.is_empty())
+                {
+                    let value = r
+                        .headers()
+                        .get(header.as_str())
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or("(none)")
+                        .to_string();
+                    request_metric.dimensions.insert(header, value);
+                }
+
+                // If enabled, record whether an intermediary cache served the response.
+                if self.config.cache_status {
+                    request_metric.cache_hit = Some(cache::served_by_cache(r.headers()));
+                }
+
+                // If the throttle is adaptive, report the target server's rate limit.
+                if let Some(rate_limit) = self.rate_limit.as_ref() {
+                    if let Some(limit) = throttle::rate_limit_from_headers(r.headers()) {
+                        let _ = rate_limit.send(limit);
+                    }
+                }
+
                 // Load test user was redirected.
                 if self.config.sticky_follow && request_metric.raw.url != request_metric.final_url {
                     let base_url = self.base_url.to_string();
@@ -1595,7 +2795,12 @@ impl GooseUser {
                 warn!("{:?}: {}", &path, e);
                 request_metric.success = false;
                 request_metric.set_status_code(None);
-                request_metric.error = clean_reqwest_error(e, request_name);
+                request_metric.error = clean_reqwest_error(e, &request_name);
+                // Requests that couldn't be built or redirected never reached the transport.
+                if !e.is_builder() && !e.is_redirect() {
+                    request_metric.transport_error =
+                        Some(GooseTransportError::from_reqwest_error(e));
+                }
             }
         };
 
@@ -1607,6 +2812,9 @@ impl GooseUser {
             .coordinated_omission_mitigation(&request_metric)
             .await?;
 
+        // If backing off after errors, track consecutive failed requests.
+        self.record_backoff(request_metric.success);
+
         // Send a copy of the raw request object to the parent process if
         // we're tracking metrics.
         if !self.config.no_metrics {
@@ -1706,10 +2914,16 @@ impl GooseUser {
     /// loop through all GooseTasks by the current GooseUser. Through this mechanism, Goose is
     /// able to detect stalls on the upstream server being load tested, backfilling requests based
     /// on what statistically should have happened. Can be disabled with `--co-mitigation disabled`.
-    async fn coordinated_omission_mitigation(
-        &self,
+    pub(crate) async fn coordinated_omission_mitigation(
+        &mut self,
         request_metric: &GooseRequestMetric,
     ) -> Result<u64, GooseTaskError> {
+        // An expected cadence replaces the cadence calculated by Coordinated Omission
+        // Mitigation.
+        if self.config.co_cadence > 0 {
+            return self.expected_cadence_mitigation(request_metric).await;
+        }
+
         if let Some(co_mitigation) = self.config.co_mitigation.as_ref() {
             // Return immediately if coordinated omission mitigation is disabled.
             if co_mitigation == &GooseCoordinatedOmissionMitigation::Disabled {
@@ -1759,8 +2973,57 @@ impl GooseUser {
         }
     }
 
-    fn send_request_metric_to_parent(&self, request_metric: GooseRequestMetric) -> GooseTaskResult {
-        // If requests-file is enabled, send a copy of the raw request to the logger thread.
+    /// If an expected cadence is configured with `--co-cadence`, each request is expected to
+    /// start that many milliseconds after the previous request made by the current GooseUser,
+    /// not counting time spent waiting between GooseTasks. When requests start late, for
+    /// example because a slow upstream server or the throttle held up the GooseUser, the
+    /// requests that should have been made in the meantime are backfilled the same as with
+    /// Coordinated Omission Mitigation.
+    async fn expected_cadence_mitigation(
+        &mut self,
+        request_metric: &GooseRequestMetric,
+    ) -> Result<u64, GooseTaskError> {
+        let cadence = self.config.co_cadence as u64;
+
+        let previous_elapsed = self
+            .request_cadence
+            .previous_request_elapsed
+            .replace(request_metric.elapsed);
+        let slept = self
+            .slept
+            .saturating_sub(self.request_cadence.previous_request_slept);
+        self.request_cadence.previous_request_slept = self.slept;
+
+        if let Some(previous_elapsed) = previous_elapsed {
+            // How much time passed since the previous request started, until this request
+            // completed, accounting for time waiting between GooseTasks.
+            let elapsed = request_metric
+                .elapsed
+                .saturating_sub(previous_elapsed)
+                .saturating_sub(slept)
+                + request_metric.response_time;
+
+            // This request started late, backfill the requests that weren't made.
+            if elapsed > cadence * 2 {
+                debug!(
+                    "user {}: expected cadence: elapsed({}) > cadence({})",
+                    self.weighted_users_index, elapsed, cadence
+                );
+                let mut coordinated_omission_request_metric = request_metric.clone();
+                coordinated_omission_request_metric.coordinated_omission_elapsed = elapsed;
+                coordinated_omission_request_metric.user_cadence = cadence;
+                self.send_request_metric_to_parent(coordinated_omission_request_metric)?;
+            }
+        }
+
+        Ok(cadence)
+    }
+
+    pub(crate) fn send_request_metric_to_parent(
+        &self,
+        request_metric: GooseRequestMetric,
+    ) -> GooseTaskResult {
+        // If the request log is enabled, send a copy of the raw request to the logger thread.
         if !self.config.request_log.is_empty() {
             if let Some(logger) = self.logger.as_ref() {
                 logger.send(Some(GooseLog::Request(request_metric.clone())))?;
@@ -1771,7 +3034,7 @@ impl GooseUser {
         // [`test_start`](../struct.GooseAttack.html#method.test_start),
         // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
         if let Some(parent) = self.channel_to_parent.clone() {
-            parent.send(GooseMetric::Request(request_metric))?;
+            parent.send(GooseMetric::Request(Box::new(request_metric)))?;
         }
 
         Ok(())
@@ -2002,21 +3265,67 @@ impl GooseUser {
             // [`test_start`](../struct.GooseAttack.html#method.test_start),
             // [`test_stop`](../struct.GooseAttack.html#method.test_stop), and during testing.
             if let Some(logger) = self.logger.clone() {
-                if self.config.no_debug_body {
-                    logger.send(Some(GooseLog::Debug(GooseDebug::new(
-                        tag, request, headers, None,
-                    ))))?;
-                } else {
-                    logger.send(Some(GooseLog::Debug(GooseDebug::new(
-                        tag, request, headers, body,
-                    ))))?;
-                }
+                let body = self.debug_body(request, body);
+                logger.send(Some(GooseLog::Debug(GooseDebug::new(
+                    self, tag, request, headers, body,
+                ))))?;
             }
         }
 
         Ok(())
     }
 
+    /// Limits how many bytes of response bodies this user writes to the debug log, or
+    /// excludes them entirely with a limit of `0`. Longer bodies are truncated. Set to
+    /// `None` to write whole bodies. Defaults to the limit configured with the
+    /// `--debug-log-body-limit BYTES` run-time option.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// task!(setup_debug).set_on_start();
+    ///
+    /// async fn setup_debug(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Only debug log the first kilobyte of response bodies.
+    ///     user.set_debug_body_limit(Some(1024));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_debug_body_limit(&mut self, limit: Option<usize>) {
+        self.debug_body_limit = limit;
+    }
+
+    // Returns the part of the body to write to the debug log, following `--no-debug-body`,
+    // `--debug-log-body-status` and the debug body limit.
+    fn debug_body<'a>(
+        &self,
+        request: Option<&GooseRequestMetric>,
+        body: Option<&'a str>,
+    ) -> Option<&'a str> {
+        let body = body?;
+        if self.config.no_debug_body
+            || (!self.debug_body_status.is_empty()
+                && !request
+                    .is_some_and(|request| self.debug_body_status.contains(&request.status_code)))
+        {
+            return None;
+        }
+        match self.debug_body_limit {
+            Some(0) => None,
+            Some(limit) if body.len() > limit => {
+                // Don't split a multi-byte character.
+                let mut end = limit;
+                while !body.is_char_boundary(end) {
+                    end -= 1;
+                }
+                Some(&body[..end])
+            }
+            _ => Some(body),
+        }
+    }
+
     /// Manually build a
     /// [`reqwest::Client`](https://docs.rs/reqwest/*/reqwest/struct.Client.html).
     ///
@@ -2029,6 +3338,9 @@ impl GooseUser {
     /// [store cookies](https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.cookie_store),
     /// which is generally necessary if you aim to simulate logged in users.
     ///
+    /// Custom clients can't connect to a Unix domain socket, so when load testing one this
+    /// returns [`GooseTaskError::UnixSocket`].
+    ///
     /// # Default configuration:
     ///
     /// ```rust
@@ -2160,11 +3472,79 @@ impl GooseUser {
         &mut self,
         builder: ClientBuilder,
     ) -> Result<(), GooseTaskError> {
+        // Requests to a Unix domain socket are always made with the client that connects to
+        // the socket.
+        #[cfg(unix)]
+        if self.unix_client.is_some() {
+            return Err(GooseTaskError::UnixSocket {
+                detail: "custom clients can not be used with a Unix domain socket".to_string(),
+            });
+        }
         self.client = builder.build()?;
 
         Ok(())
     }
 
+    /// Adds an additional named client to this [`GooseUser`], with its own configuration,
+    /// cookies and session. Requests are made with the named client by selecting it with
+    /// [`GooseRequestBuilder::client`], all other requests continue to use the default
+    /// client.
+    ///
+    /// This is useful for user journeys mixing different types of traffic, for example a
+    /// browser session with cookies and API calls authenticated with a bearer token. Adding
+    /// a client with a name that's already in use replaces the previous client. As with
+    /// [`GooseUser::set_client_builder`], this returns [`GooseTaskError::UnixSocket`] when
+    /// load testing a Unix domain socket.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use reqwest::{header, Client};
+    ///
+    /// task!(setup_api_client).set_on_start();
+    ///
+    /// async fn setup_api_client(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Build a client without cookies that authenticates with a bearer token.
+    ///     let mut headers = header::HeaderMap::new();
+    ///     headers.insert(
+    ///         header::AUTHORIZATION,
+    ///         header::HeaderValue::from_static("Bearer secret-token"),
+    ///     );
+    ///     let builder = Client::builder()
+    ///         .user_agent("example-loadtest")
+    ///         .default_headers(headers);
+    ///
+    ///     // Add the client to this GooseUser as "api".
+    ///     user.set_named_client_builder("api", builder).await?;
+    ///
+    ///     // Make a request with the "api" client.
+    ///     let goose_request = GooseRequest::builder()
+    ///         .path("api/orders")
+    ///         .client("api")
+    ///         .build();
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_named_client_builder(
+        &mut self,
+        name: &str,
+        builder: ClientBuilder,
+    ) -> Result<(), GooseTaskError> {
+        // Requests to a Unix domain socket are always made with the client that connects to
+        // the socket.
+        #[cfg(unix)]
+        if self.unix_client.is_some() {
+            return Err(GooseTaskError::UnixSocket {
+                detail: "custom clients can not be used with a Unix domain socket".to_string(),
+            });
+        }
+        self.clients.insert(name.to_string(), builder.build()?);
+
+        Ok(())
+    }
+
     /// Some websites use multiple domains to serve traffic, redirecting depending on
     /// the user's roll. For this reason, Goose needs to respect a redirect of the
     /// `base_url` and subsequent paths should be built from the redirect domain.
@@ -2185,154 +3565,664 @@ impl GooseUser {
     /// to `http://other.example.com`, subsequent relative requests would still be made
     /// against `foo.example.com`.
     ///
-    /// This functionality is used internally by Goose to follow redirects of the
-    /// `base_url` when `--sticky-follow` is specified at run time, or
-    /// [`set_default`](../struct.GooseAttack.html#method.set_default)
-    /// `(`[`GooseDefault::StickyFollow`](../config/enum.GooseDefault.html#variant.StickyFollow)
-    /// `, true)` is enabled. It is also
-    /// available to be manually invoked from a load test such as in the following
-    /// example.
+    /// This functionality is used internally by Goose to follow redirects of the
+    /// `base_url` when `--sticky-follow` is specified at run time, or
+    /// [`set_default`](../struct.GooseAttack.html#method.set_default)
+    /// `(`[`GooseDefault::StickyFollow`](../config/enum.GooseDefault.html#variant.StickyFollow)
+    /// `, true)` is enabled. It is also
+    /// available to be manually invoked from a load test such as in the following
+    /// example.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let _goose_metrics = GooseAttack::initialize()?
+    ///         .register_taskset(taskset!("LoadtestTasks")
+    ///             .set_host("http://foo.example.com/")
+    ///             .set_wait_time(Duration::from_secs(0), Duration::from_secs(3))?
+    ///             .register_task(task!(task_foo).set_weight(10)?)
+    ///             .register_task(task!(task_bar))
+    ///         )
+    ///         // Set a default run time so this test runs to completion.
+    ///         .set_default(GooseDefault::RunTime, 1)?
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn task_foo(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn task_bar(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Before this task runs, all requests are being made against
+    ///     // http://foo.example.com, after this task runs all subsequent
+    ///     // requests are made against http://bar.example.com/.
+    ///     user.set_base_url("http://bar.example.com/");
+    ///     let _goose = user.get("").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_base_url(&mut self, host: &str) -> Result<(), GooseTaskError> {
+        self.base_url = Url::parse(host)?;
+        Ok(())
+    }
+
+    /// Aborts the entire load test, for example when test data runs out or the target
+    /// server fails in a way it can't recover from. The load test stops the same as with
+    /// ctrl-c, displaying metrics and writing any configured report, and the reason is
+    /// recorded in [`GooseMetrics`](../metrics/struct.GooseMetrics.html)`.aborted`. If
+    /// more than one user aborts the load test, the first reason is kept.
+    ///
+    /// The task keeps running after calling `abort_test`, so it typically returns right
+    /// away. Only tasks run by the [`GooseUser`]s of a running load test can abort it, not
+    /// [`test_start`](../struct.GooseAttack.html#method.test_start) or
+    /// [`test_stop`](../struct.GooseAttack.html#method.test_stop) tasks.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// task!(next_account);
+    ///
+    /// async fn next_account(user: &mut GooseUser) -> GooseTaskResult {
+    ///     match user.get_session_data_mut::<Vec<String>>().and_then(|a| a.pop()) {
+    ///         Some(account) => {
+    ///             let _goose = user.get(&format!("/account/{}", account)).await?;
+    ///         }
+    ///         None => user.abort_test("account data exhausted"),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn abort_test(&self, reason: &str) {
+        match self.abort.as_ref() {
+            Some(abort) => {
+                let mut abort_reason = abort.reason.lock().unwrap();
+                if abort_reason.is_none() {
+                    error!(
+                        "user {} aborted load test: {}",
+                        self.weighted_users_index + 1,
+                        reason
+                    );
+                    *abort_reason = Some(reason.to_string());
+                }
+                abort.canceled.store(true, Ordering::SeqCst);
+            }
+            None => warn!("only a running load test can be aborted: {}", reason),
+        }
+    }
+
+    /// Stops launching more users, holding the load test at the users already running
+    /// until it stops. This is useful for finding the capacity of the target server: users
+    /// keep being launched at the hatch rate until a task sees that a condition is met,
+    /// such as response times exceeding a threshold.
+    ///
+    /// The load test keeps running for the configured run time, and metrics are reset as
+    /// if all users had been launched. Users added by a
+    /// [Controller](../controller/index.html) afterward are launched as usual. Only tasks
+    /// run by the [`GooseUser`]s of a running load test can stop hatching.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// task!(loadtest_index);
+    ///
+    /// async fn loadtest_index(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let goose = user.get("/").await?;
+    ///
+    ///     // Hold the current load once a response takes longer than a second.
+    ///     if goose.request.response_time > 1_000 {
+    ///         user.stop_hatching();
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stop_hatching(&self) {
+        match self.hatching_stopped.as_ref() {
+            Some(hatching_stopped) => {
+                if !hatching_stopped.swap(true, Ordering::SeqCst) {
+                    info!(
+                        "user {} stopped launching more users",
+                        self.weighted_users_index + 1
+                    );
+                }
+            }
+            None => warn!("only a running load test can stop launching users"),
+        }
+    }
+
+    /// Records a named annotation marking the current moment of the load test, such as
+    /// `deployed v2.3` or `cache flushed`. Annotations are timestamped, included in
+    /// [`GooseMetrics`](../metrics/struct.GooseMetrics.html)`.annotations`, and marked on
+    /// the charts of the html report.
+    ///
+    /// Only tasks run by the [`GooseUser`]s of a running load test can record annotations.
+    /// Annotations can also be recorded with the `annotate` command of the
+    /// [Controller](../controller/index.html).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// task!(flush_cache);
+    ///
+    /// async fn flush_cache(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.post("/cache/flush", "").await?;
+    ///     user.annotate("cache flushed");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn annotate(&self, name: &str) {
+        match self.channel_to_parent.as_ref() {
+            Some(parent) => {
+                // Best effort metrics.
+                let _ = parent.send(GooseMetric::Annotation(GooseAnnotation::new(name)));
+            }
+            None => warn!("only a running load test can be annotated: {}", name),
+        }
+    }
+}
+
+/// Defines the HTTP requests that Goose makes.
+///
+/// Can be manually created and configured with [`GooseRequest::builder`], but it's typically
+/// more convenient to use the [`GooseUser::get`], [`GooseUser::get_named`], [`GooseUser::post`],
+/// [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::head`] and
+/// [`GooseUser::delete`] helpers.
+///
+/// For complete instructions review [`GooseRequestBuilder`].
+#[derive(Debug)]
+pub struct GooseRequest<'a> {
+    // Defaults to `""`.
+    path: &'a str,
+    // Defaults to [`GooseMethod::Get`].
+    method: GooseMethod,
+    // Defaults to [`None`].
+    name: Option<&'a str>,
+    // Defaults to [`None`].
+    expect_status_code: Option<u16>,
+    // Defaults to [`None`].
+    expect_response_time: Option<u64>,
+    // Defaults to [`None`].
+    expect_body_sha1: Option<&'a str>,
+    // Defaults to [`None`].
+    expect_body_length: Option<(usize, usize)>,
+    // Defaults to [`None`].
+    range: Option<GooseRange>,
+    // Defaults to [`None`].
+    client: Option<&'a str>,
+    // Defaults to no headers.
+    headers: Vec<(&'a str, &'a str)>,
+    // Defaults to no query parameters.
+    query: Vec<(&'a str, &'a str)>,
+    // Defaults to [`None`].
+    timeout: Option<Duration>,
+    // Defaults to [`None`].
+    request_builder: Option<RequestBuilder>,
+}
+impl<'a> GooseRequest<'a> {
+    /// Convenience function to bring [`GooseRequestBuilder`] into scope.
+    pub fn builder() -> GooseRequestBuilder<'a> {
+        GooseRequestBuilder::new()
+    }
+}
+
+/// Used to build a [`GooseRequest`] object, necessary to make a request with Goose.
+///
+/// It's only necessary to build manually if the [`GooseUser::get`], [`GooseUser::get_named`],
+/// [`GooseUser::get_with_params`], [`GooseUser::post`], [`GooseUser::post_form`],
+/// [`GooseUser::post_json`], [`GooseUser::head`] and [`GooseUser::delete`] helpers don't
+/// provide you with enough flexibility.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+///
+/// let mut a_task = task!(task_function);
+///
+/// // A simple task that loads a relative path.
+/// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+///     // Manually create a GooseRequestBuilder object.
+///     let goose_request = GooseRequest::builder()
+///         // Set a relative path to request.
+///         .path("about")
+///         // Name the request in the metircs.
+///         .name("About page")
+///         // Build the GooseRequest object.
+///         .build();
+///
+///     // Make the configured request.
+///     let _goose = user.request(goose_request).await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct GooseRequestBuilder<'a> {
+    path: &'a str,
+    method: GooseMethod,
+    name: Option<&'a str>,
+    expect_status_code: Option<u16>,
+    expect_response_time: Option<u64>,
+    expect_body_sha1: Option<&'a str>,
+    expect_body_length: Option<(usize, usize)>,
+    range: Option<GooseRange>,
+    client: Option<&'a str>,
+    headers: Vec<(&'a str, &'a str)>,
+    query: Vec<(&'a str, &'a str)>,
+    timeout: Option<Duration>,
+    request_builder: Option<RequestBuilder>,
+}
+impl<'a> GooseRequestBuilder<'a> {
+    // Internal method to build a [`GooseRequest`] from a [`GooseRequestBuilder`].
+    fn new() -> Self {
+        Self {
+            path: "",
+            method: GooseMethod::Get,
+            name: None,
+            expect_status_code: None,
+            expect_response_time: None,
+            expect_body_sha1: None,
+            expect_body_length: None,
+            range: None,
+            client: None,
+            headers: Vec::new(),
+            query: Vec::new(),
+            timeout: None,
+            request_builder: None,
+        }
+    }
+
+    /// Set the path to request.
+    ///
+    /// Typically is a relative path allowing Goose to append a configurable base_url.
+    ///
+    /// Defaults to `""` (the main index).
+    ///
+    /// # Example
+    /// This can be implemented in a simpler way using the [`GooseUser::get`] helper function.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// // A simple task that loads a relative path.
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("a/relative/path")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn path(mut self, path: impl Into<&'a str>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Set the method of the request.
+    ///
+    /// Must be set to a [`GooseMethod`].
+    ///
+    /// Defaults to [`GooseMethod::Get`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// // Make a DELETE request.
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("path/to/delete")
+    ///         // Set the method to DELETE.
+    ///         .method(GooseMethod::Delete)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured DELETE request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn method(mut self, method: GooseMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set a name for the request, affecting how it shows up in metrics.
+    ///
+    /// Must be set to a [`GooseMethod`].
+    ///
+    /// Defaults to [`GooseMethod::Get`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// // Make a named request.
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("path/to/request")
+    ///         // Name the request in the metrics.
+    ///         .name("custom name")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn name(mut self, name: impl Into<&'a str>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Manually configure the expected HTTP response status code.
+    ///
+    /// Defaults to [`reqwest::StatusCode::is_success`].
+    ///
+    /// # Example
+    /// Intentionally request a 404 page, and do not trigger an error.
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// // Make a named request.
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("no/such/path")
+    ///         // Tell Goose to expect a 404 HTTP response status code.
+    ///         .expect_status_code(404)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn expect_status_code(mut self, status_code: u16) -> Self {
+        self.expect_status_code = Some(status_code);
+        self
+    }
+
+    /// Set a response time budget for the request.
+    ///
+    /// Responses that take longer than the budget are counted as slow in the
+    /// [`GooseRequestMetricAggregate::slow_count`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.slow_count)
+    /// of the request, and displayed in the response time budgets table of the metrics.
+    /// Slow requests are not failures: a slow response can still be successful.
+    ///
+    /// Defaults to [`None`], no budget.
+    ///
+    /// # Example
+    /// Count responses from the search page taking longer than 200 milliseconds as slow.
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("search")
+    ///         // Set a 200 millisecond response time budget.
+    ///         .expect_response_time(Duration::from_millis(200))
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn expect_response_time(mut self, budget: Duration) -> Self {
+        self.expect_response_time = Some(budget.as_millis() as u64);
+        self
+    }
+
+    /// Set the expected SHA-1 checksum of the response body, as 40 hexadecimal digits.
+    ///
+    /// The body of each successful (2xx) response is read and verified before the
+    /// response is returned. Responses with a different body fail, and are counted in
+    /// [`GooseRequestMetricAggregate::integrity_failures`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.integrity_failures).
+    /// This is useful for detecting corrupted downloads under load.
+    ///
+    /// Defaults to [`None`], the response body isn't verified.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("downloads/release.tar.gz")
+    ///         // Verify the downloaded file.
+    ///         .expect_body_sha1("16c180a80e3c19ee1a8a996a9982eb0b8f687ec1")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn expect_body_sha1(mut self, checksum: &'a str) -> Self {
+        self.expect_body_sha1 = Some(checksum);
+        self
+    }
+
+    /// Set the expected range of response body lengths in bytes, inclusive.
+    ///
+    /// The body of each successful (2xx) response is read and verified before the
+    /// response is returned. Responses with a shorter or longer body fail, and are
+    /// counted in
+    /// [`GooseRequestMetricAggregate::integrity_failures`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.integrity_failures).
+    ///
+    /// Defaults to [`None`], the response body isn't verified.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("images/banner.jpg")
+    ///         // Fail truncated images.
+    ///         .expect_body_length(50_000, 60_000)
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn expect_body_length(mut self, min: usize, max: usize) -> Self {
+        self.expect_body_length = Some((min, max));
+        self
+    }
+
+    /// Request part of the response with a `Range` header, verifying the response is
+    /// `206 Partial Content` with the requested range.
+    ///
+    /// The response is requested uncompressed, with an `Accept-Encoding: identity`
+    /// header. The body of each successful (2xx) response is read and verified before
+    /// the response is returned. Responses that ignore the range, describe a different
+    /// range in their `Content-Range` header, or have a body of a different length fail,
+    /// and are counted in
+    /// [`GooseRequestMetricAggregate::integrity_failures`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.integrity_failures).
+    ///
+    /// Defaults to [`None`], the whole response is requested.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use goose::range::GooseRange;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("videos/intro.mp4")
+    ///         // Name the request in the metrics.
+    ///         .name("video start")
+    ///         // Only request the first megabyte.
+    ///         .range(GooseRange::Between(0, 1_048_575))
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn range(mut self, range: GooseRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Make the request with a named client added with
+    /// [`GooseUser::set_named_client_builder`], instead of the default client.
+    ///
+    /// Making a request with a client name that was never added fails with
+    /// [`GooseTaskError::InvalidClient`].
+    ///
+    /// Defaults to [`None`], the default client.
     ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
-    /// use std::time::Duration;
     ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), GooseError> {
-    ///     let _goose_metrics = GooseAttack::initialize()?
-    ///         .register_taskset(taskset!("LoadtestTasks")
-    ///             .set_host("http://foo.example.com/")
-    ///             .set_wait_time(Duration::from_secs(0), Duration::from_secs(3))?
-    ///             .register_task(task!(task_foo).set_weight(10)?)
-    ///             .register_task(task!(task_bar))
-    ///         )
-    ///         // Set a default run time so this test runs to completion.
-    ///         .set_default(GooseDefault::RunTime, 1)?
-    ///         .execute()
-    ///         .await?;
+    /// let mut a_task = task!(task_function);
     ///
-    ///     Ok(())
-    /// }
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("api/orders")
+    ///         // Make the request with the "api" client.
+    ///         .client("api")
+    ///         // Build the GooseRequest object.
+    ///         .build();
     ///
-    /// async fn task_foo(user: &mut GooseUser) -> GooseTaskResult {
-    ///     let _goose = user.get("").await?;
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
     ///
     ///     Ok(())
     /// }
+    /// ```
+    pub fn client(mut self, name: &'a str) -> Self {
+        self.client = Some(name);
+        self
+    }
+
+    /// Add a header to the request.
     ///
-    /// async fn task_bar(user: &mut GooseUser) -> GooseTaskResult {
-    ///     // Before this task runs, all requests are being made against
-    ///     // http://foo.example.com, after this task runs all subsequent
-    ///     // requests are made against http://bar.example.com/.
-    ///     user.set_base_url("http://bar.example.com/");
-    ///     let _goose = user.get("").await?;
+    /// Can be called multiple times to add multiple headers. Headers added here replace
+    /// any header of the same name set by the [`GooseUser`]'s client, including the
+    /// User-Agent header.
+    ///
+    /// Defaults to no additional headers.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(task_function);
+    ///
+    /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose_request = GooseRequest::builder()
+    ///         // Set a relative path to request.
+    ///         .path("api/orders")
+    ///         // Request a JSON response.
+    ///         .header("Accept", "application/json")
+    ///         // Build the GooseRequest object.
+    ///         .build();
+    ///
+    ///     // Make the configured request.
+    ///     let _goose = user.request(goose_request).await?;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn set_base_url(&mut self, host: &str) -> Result<(), GooseTaskError> {
-        self.base_url = Url::parse(host)?;
-        Ok(())
-    }
-}
-
-/// Defines the HTTP requests that Goose makes.
-///
-/// Can be manually created and configured with [`GooseRequest::builder`], but it's typically
-/// more convenient to use the [`GooseUser::get`], [`GooseUser::get_named`], [`GooseUser::post`],
-/// [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::head`] and
-/// [`GooseUser::delete`] helpers.
-///
-/// For complete instructions review [`GooseRequestBuilder`].
-#[derive(Debug)]
-pub struct GooseRequest<'a> {
-    // Defaults to `""`.
-    path: &'a str,
-    // Defaults to [`GooseMethod::Get`].
-    method: GooseMethod,
-    // Defaults to [`None`].
-    name: Option<&'a str>,
-    // Defaults to [`None`].
-    expect_status_code: Option<u16>,
-    // Defaults to [`None`].
-    request_builder: Option<RequestBuilder>,
-}
-impl<'a> GooseRequest<'a> {
-    /// Convenience function to bring [`GooseRequestBuilder`] into scope.
-    pub fn builder() -> GooseRequestBuilder<'a> {
-        GooseRequestBuilder::new()
-    }
-}
-
-/// Used to build a [`GooseRequest`] object, necessary to make a request with Goose.
-///
-/// It's only necessary to build manually if the [`GooseUser::get`], [`GooseUser::get_named`],
-/// [`GooseUser::post`], [`GooseUser::post_form`], [`GooseUser::post_json`], [`GooseUser::head`]
-/// and [`GooseUser::delete`] helpers don't provide you with enough flexibility.
-///
-/// # Example
-/// ```rust
-/// use goose::prelude::*;
-///
-/// let mut a_task = task!(task_function);
-///
-/// // A simple task that loads a relative path.
-/// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
-///     // Manually create a GooseRequestBuilder object.
-///     let goose_request = GooseRequest::builder()
-///         // Set a relative path to request.
-///         .path("about")
-///         // Name the request in the metircs.
-///         .name("About page")
-///         // Build the GooseRequest object.
-///         .build();
-///
-///     // Make the configured request.
-///     let _goose = user.request(goose_request).await?;
-///
-///     Ok(())
-/// }
-/// ```
-pub struct GooseRequestBuilder<'a> {
-    path: &'a str,
-    method: GooseMethod,
-    name: Option<&'a str>,
-    expect_status_code: Option<u16>,
-    request_builder: Option<RequestBuilder>,
-}
-impl<'a> GooseRequestBuilder<'a> {
-    // Internal method to build a [`GooseRequest`] from a [`GooseRequestBuilder`].
-    fn new() -> Self {
-        Self {
-            path: "",
-            method: GooseMethod::Get,
-            name: None,
-            expect_status_code: None,
-            request_builder: None,
-        }
+    pub fn header(mut self, name: &'a str, value: &'a str) -> Self {
+        self.headers.push((name, value));
+        self
     }
 
-    /// Set the path to request.
+    /// Send an idempotency key with the request, in an `Idempotency-Key` header, so a
+    /// retried write isn't applied twice.
     ///
-    /// Typically is a relative path allowing Goose to append a configurable base_url.
+    /// A key that stays the same for the rest of the user's current pass through its
+    /// tasks is returned by [`GooseUser::idempotency_key`]. For more details refer to the
+    /// [`write`](../write/index.html) module.
     ///
-    /// Defaults to `""` (the main index).
+    /// Defaults to no idempotency key.
     ///
     /// # Example
-    /// This can be implemented in a simpler way using the [`GooseUser::get`] helper function.
     /// ```rust
     /// use goose::prelude::*;
     ///
     /// let mut a_task = task!(task_function);
     ///
-    /// // A simple task that loads a relative path.
     /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
-    ///     // Manually create a GooseRequestBuilder object.
+    ///     let key = user.idempotency_key();
     ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("a/relative/path")
-    ///         // Build the GooseRequest object.
+    ///         .method(GooseMethod::Post)
+    ///         .path("api/orders")
+    ///         // Retries of this order send the same key.
+    ///         .idempotency_key(&key)
     ///         .build();
     ///
     ///     // Make the configured request.
@@ -2341,16 +4231,19 @@ impl<'a> GooseRequestBuilder<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn path(mut self, path: impl Into<&'a str>) -> Self {
-        self.path = path.into();
+    pub fn idempotency_key(mut self, key: &'a str) -> Self {
+        self.headers.push(("Idempotency-Key", key));
         self
     }
 
-    /// Set the method of the request.
+    /// Make the request conditional on the resource still having an ETag, with an
+    /// `If-Match` header. Servers answer with `412 Precondition Failed` if the resource
+    /// changed since the ETag was read.
     ///
-    /// Must be set to a [`GooseMethod`].
+    /// ETags can be remembered with [`GooseUser::remember_etag`]. For more details refer
+    /// to the [`write`](../write/index.html) module.
     ///
-    /// Defaults to [`GooseMethod::Get`].
+    /// Defaults to an unconditional request.
     ///
     /// # Example
     /// ```rust
@@ -2358,33 +4251,35 @@ impl<'a> GooseRequestBuilder<'a> {
     ///
     /// let mut a_task = task!(task_function);
     ///
-    /// // Make a DELETE request.
     /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
-    ///     // Manually create a GooseRequestBuilder object.
+    ///     let goose = user.get("api/cart").await?;
+    ///     let etag = user.remember_etag("cart", &goose).unwrap_or_default();
     ///     let goose_request = GooseRequest::builder()
-    ///         // Set a relative path to request.
-    ///         .path("path/to/delete")
-    ///         // Set the method to DELETE.
     ///         .method(GooseMethod::Delete)
-    ///         // Build the GooseRequest object.
+    ///         .path("api/cart")
+    ///         // Only empty the cart if nobody else changed it.
+    ///         .if_match(&etag)
     ///         .build();
     ///
-    ///     // Make the configured DELETE request.
+    ///     // Make the configured request.
     ///     let _goose = user.request(goose_request).await?;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn method(mut self, method: GooseMethod) -> Self {
-        self.method = method;
+    pub fn if_match(mut self, etag: &'a str) -> Self {
+        self.headers.push(("If-Match", etag));
         self
     }
 
-    /// Set a name for the request, affecting how it shows up in metrics.
+    /// Add a query parameter to the request.
     ///
-    /// Must be set to a [`GooseMethod`].
+    /// Can be called multiple times to add multiple parameters. Names and values are
+    /// URL-encoded and appended to any query already included in the path. Unless the
+    /// request is named, it's named after the path without these parameters, so requests
+    /// with different parameter values are grouped together in metrics.
     ///
-    /// Defaults to [`GooseMethod::Get`].
+    /// Defaults to no additional query parameters.
     ///
     /// # Example
     /// ```rust
@@ -2392,14 +4287,13 @@ impl<'a> GooseRequestBuilder<'a> {
     ///
     /// let mut a_task = task!(task_function);
     ///
-    /// // Make a named request.
     /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
     ///     // Manually create a GooseRequestBuilder object.
     ///     let goose_request = GooseRequest::builder()
     ///         // Set a relative path to request.
-    ///         .path("path/to/request")
-    ///         // Name the request in the metrics.
-    ///         .name("custom name")
+    ///         .path("search")
+    ///         // Requests "search?q=rust+%26+goose".
+    ///         .query("q", "rust & goose")
     ///         // Build the GooseRequest object.
     ///         .build();
     ///
@@ -2409,30 +4303,32 @@ impl<'a> GooseRequestBuilder<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn name(mut self, name: impl Into<&'a str>) -> Self {
-        self.name = Some(name.into());
+    pub fn query(mut self, name: &'a str, value: &'a str) -> Self {
+        self.query.push((name, value));
         self
     }
 
-    /// Manually configure the expected HTTP response status code.
+    /// Set a timeout for the request.
     ///
-    /// Defaults to [`reqwest::StatusCode::is_success`].
+    /// The timeout applies from when the request starts connecting until the response
+    /// body has been read. Requests that time out fail with a timeout error.
+    ///
+    /// Defaults to [`None`], the timeout of the [`GooseUser`]'s client, if any.
     ///
     /// # Example
-    /// Intentionally request a 404 page, and do not trigger an error.
     /// ```rust
     /// use goose::prelude::*;
+    /// use std::time::Duration;
     ///
     /// let mut a_task = task!(task_function);
     ///
-    /// // Make a named request.
     /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
     ///     // Manually create a GooseRequestBuilder object.
     ///     let goose_request = GooseRequest::builder()
     ///         // Set a relative path to request.
-    ///         .path("no/such/path")
-    ///         // Tell Goose to expect a 404 HTTP response status code.
-    ///         .expect_status_code(404)
+    ///         .path("search")
+    ///         // Fail the request if it takes longer than 500 milliseconds.
+    ///         .timeout(Duration::from_millis(500))
     ///         // Build the GooseRequest object.
     ///         .build();
     ///
@@ -2442,27 +4338,25 @@ impl<'a> GooseRequestBuilder<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn expect_status_code(mut self, status_code: u16) -> Self {
-        self.expect_status_code = Some(status_code);
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
         self
     }
 
     /// Manually create the [`reqwest::RequestBuilder`] used to make a request.
     ///
     /// # Example
-    /// Manually create a `RequestBuilder` in order to set a timeout.
+    /// Manually create a `RequestBuilder` in order to set basic authentication.
     /// ```rust
     /// use goose::prelude::*;
     ///
     /// let mut a_task = task!(task_function);
     ///
     /// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
-    ///     use std::time::Duration;
-    ///
     ///     // Manually interact with the Reqwest RequestBuilder object.
-    ///     let request_builder = user.get_request_builder(&GooseMethod::Get, "no/such/path")?
-    ///         // Configure the request to timeout if it takes longer than 500 milliseconds.
-    ///         .timeout(Duration::from_millis(500));
+    ///     let request_builder = user.get_request_builder(&GooseMethod::Get, "admin")?
+    ///         // Configure the request to authenticate as the admin user.
+    ///         .basic_auth("admin", Some("password"));
     ///
     ///     // Manually build a GooseRequest in order to set our custom RequestBuilder.
     ///     let goose_request = GooseRequest::builder()
@@ -2497,6 +4391,14 @@ impl<'a> GooseRequestBuilder<'a> {
             method,
             name,
             expect_status_code,
+            expect_response_time,
+            expect_body_sha1,
+            expect_body_length,
+            range,
+            client,
+            headers,
+            query,
+            timeout,
             request_builder,
         } = self;
         GooseRequest {
@@ -2504,11 +4406,32 @@ impl<'a> GooseRequestBuilder<'a> {
             method,
             name,
             expect_status_code,
+            expect_response_time,
+            expect_body_sha1,
+            expect_body_length,
+            range,
+            client,
+            headers,
+            query,
+            timeout,
             request_builder,
         }
     }
 }
 
+// Join a path to a base URL. Paths that include a host are used as-is.
+fn join_url(base_url: &Url, path: &str) -> Result<String, GooseTaskError> {
+    // If URL includes a host, simply use it.
+    if let Ok(parsed_path) = Url::parse(path) {
+        if let Some(_host) = parsed_path.host() {
+            return Ok(path.to_string());
+        }
+    }
+
+    // Otherwise use the `base_url`.
+    Ok(base_url.join(path)?.to_string())
+}
+
 /// Remove path from Reqwest error to avoid having a lot of distincts error
 /// when path parameters are used.
 fn clean_reqwest_error(e: &reqwest::Error, request_name: &str) -> String {
@@ -2533,6 +4456,38 @@ fn clean_reqwest_error(e: &reqwest::Error, request_name: &str) -> String {
     }
 }
 
+/// Build the default client configuration shared by all users, optionally presenting
+/// a client certificate.
+pub(crate) fn client_builder(
+    configuration: &GooseConfiguration,
+    client_cert: Option<&GooseClientCert>,
+) -> reqwest::ClientBuilder {
+    let builder = Client::builder()
+        .user_agent(APP_USER_AGENT)
+        .cookie_store(true)
+        // Enable gzip unless `--no-gzip` flag is enabled, or Goose decompresses
+        // responses itself to track body sizes.
+        .gzip(!configuration.no_gzip && !configuration.body_sizes);
+
+    // Count TLS handshakes, and optionally disable TLS session resumption.
+    #[cfg(feature = "rustls-tls")]
+    let builder =
+        builder.use_preconfigured_tls(crate::tls::client_config(configuration, client_cert));
+
+    // Rustls presents the client certificate from the preconfigured TLS configuration.
+    #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+    let builder = match client_cert {
+        Some(client_cert) => builder.identity(client_cert.identity.clone()),
+        None => builder,
+    };
+
+    #[cfg(not(any(feature = "native-tls", feature = "rustls-tls")))]
+    let _ = client_cert;
+
+    // Connect to tunneled hosts through their SSH tunnel.
+    crate::ssh::resolve_tunnels(builder)
+}
+
 /// A helper to determine which host should be prepended to relative load test
 /// paths in this TaskSet.
 ///
@@ -2609,6 +4564,9 @@ pub struct GooseTask {
     pub on_start: bool,
     /// A flag indicating that this task runs when the user stops.
     pub on_stop: bool,
+    /// An index into [`GooseTaskSet`](./struct.GooseTaskSet.html)`.groups`, if this task
+    /// was registered as part of a [`GooseTaskGroup`](./struct.GooseTaskGroup.html).
+    pub group: Option<usize>,
     /// A required function that is executed each time this task runs.
     pub function: GooseTaskFunction,
 }
@@ -2622,6 +4580,7 @@ impl GooseTask {
             sequence: 0,
             on_start: false,
             on_stop: false,
+            group: None,
             function,
         }
     }
@@ -2840,6 +4799,7 @@ impl Hash for GooseTask {
         self.sequence.hash(state);
         self.on_start.hash(state);
         self.on_stop.hash(state);
+        self.group.hash(state);
     }
 }
 
@@ -2963,6 +4923,42 @@ mod tests {
             task_set.task_wait,
             Some((Duration::from_secs(3), Duration::from_secs(9)))
         );
+
+        // Hatch rate defaults to the global hatch rate.
+        assert_eq!(task_set.hatch_rate, None);
+
+        // Hatch rate can be set, including to a decimal.
+        task_set = task_set.set_hatch_rate("0.5").unwrap();
+        assert_eq!(task_set.hatch_rate, Some("0.5".to_string()));
+
+        // Hatch rate must be a number greater than 0.
+        assert!(task_set.clone().set_hatch_rate("0").is_err());
+        assert!(task_set.clone().set_hatch_rate("foo").is_err());
+        assert_eq!(task_set.hatch_rate, Some("0.5".to_string()));
+
+        // Users are assigned by weight by default.
+        assert_eq!(task_set.users, None);
+
+        // A fixed number of users can be set.
+        task_set = task_set.set_users(20).unwrap();
+        assert_eq!(task_set.users, Some(20));
+
+        // A fixed number of users must be at least 1.
+        assert!(task_set.clone().set_users(0).is_err());
+        assert_eq!(task_set.users, Some(20));
+
+        // Task sets are enabled by default, and can be disabled.
+        assert!(!task_set.disabled);
+        task_set = task_set.set_disabled();
+        assert!(task_set.disabled);
+
+        // Task sets don't emulate a network profile by default.
+        assert_eq!(task_set.network_profile, None);
+        task_set = task_set.set_network_profile(GooseNetworkProfile::slow_3g());
+        assert_eq!(
+            task_set.network_profile,
+            Some(GooseNetworkProfile::new(Duration::from_secs(2), 400, 400))
+        );
     }
 
     #[test]
@@ -3045,6 +5041,87 @@ mod tests {
         assert_eq!(task.sequence, 8);
     }
 
+    #[test]
+    fn goose_debug() {
+        const HOST: &str = "http://example.com/";
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let base_url = get_base_url(Some(HOST.to_string()), None, None).unwrap();
+        let mut user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+        user.weighted_users_index = 3;
+        user.task_name = Some("load front page".to_string());
+
+        let raw = GooseRawRequest::new(GooseMethod::Get, HOST, vec![], "");
+        let request = GooseRequestMetric::new(raw, "front page", 0, 3);
+        let mut headers = header::HeaderMap::new();
+        headers.insert("server", header::HeaderValue::from_static("goose"));
+        headers.append("set-cookie", header::HeaderValue::from_static("a=1"));
+        headers.append("set-cookie", header::HeaderValue::from_static("b=2"));
+
+        let debug = GooseDebug::new(&user, "tag", Some(&request), Some(&headers), Some("body"));
+        assert_eq!(debug.schema_version, GOOSE_DEBUG_SCHEMA_VERSION);
+        assert!(debug.timestamp > 0);
+        assert_eq!(debug.user, 3);
+        assert_eq!(debug.task_name.as_deref(), Some("load front page"));
+        assert_eq!(debug.request_name.as_deref(), Some("front page"));
+        // Repeated headers are all included.
+        assert_eq!(
+            debug.headers.as_ref().unwrap(),
+            &vec![
+                ("server".to_string(), "goose".to_string()),
+                ("set-cookie".to_string(), "a=1".to_string()),
+                ("set-cookie".to_string(), "b=2".to_string()),
+            ]
+        );
+
+        // The JSON format is typed, and can be parsed back.
+        let value = serde_json::to_value(&debug).unwrap();
+        assert_eq!(value["schema_version"], GOOSE_DEBUG_SCHEMA_VERSION);
+        assert_eq!(value["headers"][0][0], "server");
+        let parsed: GooseDebug = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.body.as_deref(), Some("body"));
+
+        // Everything but the tag is optional.
+        let debug = GooseDebug::new(&user, "tag", None, None, None);
+        assert!(debug.request_name.is_none());
+        assert!(debug.headers.is_none());
+    }
+
+    #[test]
+    fn goose_debug_body() {
+        const HOST: &str = "http://example.com/";
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.debug_log_body_limit = Some(4);
+        configuration.debug_log_body_status = "500,503".to_string();
+        let base_url = get_base_url(Some(HOST.to_string()), None, None).unwrap();
+        let mut user = GooseUser::new(0, base_url, &configuration, 0).unwrap();
+
+        let raw = GooseRawRequest::new(GooseMethod::Get, HOST, vec![], "");
+        let mut request = GooseRequestMetric::new(raw, "front page", 0, 3);
+        request.status_code = 503;
+
+        // Bodies are truncated to the limit, without splitting characters.
+        assert_eq!(
+            user.debug_body(Some(&request), Some("body text")),
+            Some("body")
+        );
+        assert_eq!(user.debug_body(Some(&request), Some("bøø")), Some("bø"));
+
+        // Bodies are only included for the configured status codes.
+        assert_eq!(user.debug_body(None, Some("body")), None);
+        request.status_code = 200;
+        assert_eq!(user.debug_body(Some(&request), Some("body")), None);
+
+        // Each user can change its limit, and a limit of 0 excludes bodies.
+        request.status_code = 500;
+        user.set_debug_body_limit(None);
+        assert_eq!(
+            user.debug_body(Some(&request), Some("body text")),
+            Some("body text")
+        );
+        user.set_debug_body_limit(Some(0));
+        assert_eq!(user.debug_body(Some(&request), Some("body")), None);
+    }
+
     #[tokio::test]
     async fn goose_user() {
         const HOST: &str = "http://example.com/";
@@ -3180,6 +5257,54 @@ mod tests {
         comment.assert_hits(1);
     }
 
+    #[tokio::test]
+    async fn named_clients() {
+        let server = MockServer::start();
+
+        let mut user = setup_user(&server).unwrap();
+
+        // Set up a mock http server endpoint that requires a bearer token.
+        const API_PATH: &str = "/api";
+        let api = server.mock(|when, then| {
+            when.method(GET)
+                .path(API_PATH)
+                .header("authorization", "Bearer token");
+            then.status(200);
+        });
+
+        // Add a named client that sends the bearer token.
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_static("Bearer token"),
+        );
+        user.set_named_client_builder("api", Client::builder().default_headers(headers))
+            .await
+            .unwrap();
+
+        // The default client doesn't send the bearer token.
+        let goose = user.get(API_PATH).await.unwrap();
+        assert_eq!(goose.request.status_code, 404);
+        assert_eq!(api.hits(), 0);
+
+        // The named client sends the bearer token.
+        let goose_request = GooseRequest::builder().path(API_PATH).client("api").build();
+        let goose = user.request(goose_request).await.unwrap();
+        assert_eq!(goose.request.status_code, 200);
+        api.assert_hits(1);
+
+        // Selecting a client that was never added is an error.
+        let goose_request = GooseRequest::builder()
+            .path(API_PATH)
+            .client("browser")
+            .build();
+        assert!(matches!(
+            user.request(goose_request).await,
+            Err(GooseTaskError::InvalidClient { .. })
+        ));
+        api.assert_hits(1);
+    }
+
     #[test]
     fn test_set_session_data() {
         #[derive(Debug, PartialEq, Clone)]
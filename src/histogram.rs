@@ -0,0 +1,123 @@
+//! Optional HdrHistogram backed response time percentiles.
+//!
+//! By default response times are rounded before they're counted, to the nearest 10ms
+//! from 100ms, the nearest 100ms from 500ms and the nearest second from one second, so
+//! slow percentiles of long load tests are only approximate. When Goose is compiled with
+//! the `hdr-histogram` feature each request also records its response times in an
+//! [HdrHistogram](https://docs.rs/hdrhistogram), which keeps three significant digits of
+//! every response time in a fixed amount of memory, and request percentiles such as the
+//! 99.9th and 99.99th are calculated from it.
+
+use hdrhistogram::Histogram;
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+/// How many significant digits of each response time are kept.
+const SIGNIFICANT_DIGITS: u8 = 3;
+
+/// Response times recorded in an HdrHistogram.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct GooseHistogram(Histogram<u64>);
+impl Default for GooseHistogram {
+    fn default() -> Self {
+        // The histogram resizes itself to fit the slowest response time.
+        GooseHistogram(Histogram::new(SIGNIFICANT_DIGITS).expect("valid significant digits"))
+    }
+}
+impl Eq for GooseHistogram {}
+impl GooseHistogram {
+    /// Records a response time in milliseconds.
+    pub(crate) fn record(&mut self, response_time: u64) {
+        // Only fails if the histogram can't grow, then the slowest value is recorded.
+        if self.0.record(response_time).is_err() {
+            self.0.saturating_record(response_time);
+        }
+    }
+
+    /// Adds all response times recorded in another histogram.
+    pub(crate) fn merge(&mut self, other: &GooseHistogram) {
+        if let Err(e) = self.0.add(&other.0) {
+            warn!("failed to merge response times: {}", e);
+        }
+    }
+
+    /// Discards all recorded response times.
+    pub(crate) fn clear(&mut self) {
+        self.0.reset();
+    }
+
+    /// Whether any response time has been recorded.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The response time in milliseconds at a percentile, such as `0.999`.
+    pub(crate) fn percentile(&self, percent: f32) -> usize {
+        self.0.value_at_quantile(percent as f64) as usize
+    }
+}
+impl Serialize for GooseHistogram {
+    // Serialize the count of each distinct recorded value, so histograms can be sent from
+    // Workers to the Manager.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let counts: Vec<(u64, u64)> = self
+            .0
+            .iter_recorded()
+            .map(|value| (value.value_iterated_to(), value.count_at_value()))
+            .collect();
+        counts.serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for GooseHistogram {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let counts = Vec::<(u64, u64)>::deserialize(deserializer)?;
+        let mut histogram = GooseHistogram::default();
+        for (value, count) in counts {
+            histogram
+                .0
+                .record_n(value, count)
+                .map_err(serde::de::Error::custom)?;
+        }
+        Ok(histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles() {
+        let mut histogram = GooseHistogram::default();
+        assert!(histogram.is_empty());
+        for response_time in 1..=10_000 {
+            histogram.record(response_time);
+        }
+        // Three significant digits are kept, so slow percentiles aren't rounded to the
+        // nearest second.
+        for (percent, response_time) in [(0.5, 5_000), (0.99, 9_900), (0.999, 9_990)] {
+            let percentile = histogram.percentile(percent);
+            assert!(percentile >= response_time && percentile <= response_time + 10);
+        }
+
+        let mut merged = GooseHistogram::default();
+        merged.merge(&histogram);
+        merged.merge(&histogram);
+        assert_eq!(merged.percentile(0.5), histogram.percentile(0.5));
+
+        // Histograms survive serialization, for Gaggle mode.
+        let serialized = serde_json::to_string(&histogram).unwrap();
+        let deserialized: GooseHistogram = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, histogram);
+
+        histogram.clear();
+        assert!(histogram.is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+//! Optional streaming of requests to InfluxDB.
+//!
+//! The requests log keeps every request in a local file, which has to be imported
+//! somewhere before it can be queried. With the `--influxdb-url URL` run-time option (or
+//! `GooseDefault::InfluxdbUrl`) Goose instead streams every request to InfluxDB while the
+//! load test runs, in the line protocol. The URL is the full write endpoint, for example
+//! `http://localhost:8086/write?db=goose` for the InfluxDB v1 API, or
+//! `http://localhost:8086/api/v2/write?org=acme&bucket=goose` for the v2 API together with
+//! `--influxdb-token TOKEN`.
+//!
+//! Requests are written in batches from a dedicated task, so a slow InfluxDB never slows
+//! down the load test. A batch is written once it holds `--influxdb-batch-size` requests
+//! (default 5,000), or every `--influxdb-flush-interval` seconds (default 1), whichever
+//! comes first. Any remaining requests are written when the load test stops.
+//!
+//! Each request is written as a `goose_request` point, tagged with the `method` and `name`
+//! of the request, its `status_code` and the `user` that made it, as well as the `run_id`
+//! if set, with the `response_time` in milliseconds, whether it was a `success` and any
+//! `error` as fields. A request later marked as failed is written again with the same tags
+//! and timestamp, overwriting the original point.
+
+use std::time::Duration;
+
+use crate::metrics::GooseRequestMetric;
+use crate::GooseConfiguration;
+
+/// Sends lines to the InfluxDB task, or `None` to write any remaining lines and exit.
+pub(crate) type GooseInfluxDbTx = flume::Sender<Option<String>>;
+
+/// Spawns a task writing lines to InfluxDB in batches, returning the channel used to send
+/// it lines and its join handle, which resolves to how many lines failed to be written.
+pub(crate) fn spawn_influxdb(
+    configuration: &GooseConfiguration,
+) -> (GooseInfluxDbTx, tokio::task::JoinHandle<usize>) {
+    // Request timestamps are written in milliseconds.
+    let separator = if configuration.influxdb_url.contains('?') {
+        '&'
+    } else {
+        '?'
+    };
+    let url = if configuration.influxdb_url.contains("precision=") {
+        configuration.influxdb_url.to_string()
+    } else {
+        format!("{}{}precision=ms", configuration.influxdb_url, separator)
+    };
+    info!(
+        "streaming requests to InfluxDB at: {}",
+        configuration.influxdb_url
+    );
+
+    let (influxdb_tx, influxdb_rx) = flume::unbounded();
+    let handle = tokio::spawn(influxdb_main(
+        url,
+        configuration.influxdb_token.to_string(),
+        // Batch size and flush interval are always set when streaming to InfluxDB.
+        configuration.influxdb_batch_size.unwrap_or(5_000),
+        Duration::from_secs(configuration.influxdb_flush_interval.unwrap_or(1) as u64),
+        influxdb_rx,
+    ));
+
+    (influxdb_tx, handle)
+}
+
+/// Collects lines into batches, writing each batch once it is full or the flush interval
+/// expires, until told to exit.
+async fn influxdb_main(
+    url: String,
+    token: String,
+    batch_size: usize,
+    flush_interval: Duration,
+    influxdb_rx: flume::Receiver<Option<String>>,
+) -> usize {
+    let client = reqwest::Client::new();
+    let mut batch: Vec<String> = Vec::with_capacity(batch_size);
+    let mut flush_timer = tokio::time::Instant::now();
+    let mut failed_lines = 0;
+
+    loop {
+        let exit =
+            match tokio::time::timeout_at(flush_timer + flush_interval, influxdb_rx.recv_async())
+                .await
+            {
+                Ok(Ok(Some(line))) => {
+                    batch.push(line);
+                    false
+                }
+                // Exit when told to, or if the parent went away.
+                Ok(Ok(None)) | Ok(Err(_)) => true,
+                // The flush interval expired.
+                Err(_) => false,
+            };
+
+        if exit || batch.len() >= batch_size || flush_timer.elapsed() >= flush_interval {
+            if !batch.is_empty() {
+                if let Err(e) = write_batch(&client, &url, &token, &batch).await {
+                    warn!(
+                        "failed to write {} requests to InfluxDB: {}",
+                        batch.len(),
+                        e
+                    );
+                    failed_lines += batch.len();
+                }
+                batch.clear();
+            }
+            flush_timer = tokio::time::Instant::now();
+        }
+
+        if exit {
+            break;
+        }
+    }
+
+    failed_lines
+}
+
+/// Writes a batch of lines to InfluxDB.
+async fn write_batch(
+    client: &reqwest::Client,
+    url: &str,
+    token: &str,
+    batch: &[String],
+) -> Result<(), reqwest::Error> {
+    let mut request = client.post(url).body(batch.join("\n"));
+    // The v2 API authenticates with a token.
+    if !token.is_empty() {
+        request = request.header("Authorization", format!("Token {}", token));
+    }
+    request.send().await?.error_for_status()?;
+    Ok(())
+}
+
+/// Renders a request as a line of the InfluxDB line protocol, timestamped with when the
+/// request started in milliseconds since the Unix epoch.
+pub(crate) fn render(request_metric: &GooseRequestMetric, run_id: &str, timestamp: i64) -> String {
+    // The user is a tag, so points of different users made in the same millisecond don't
+    // overwrite each other.
+    let mut tags = format!(
+        "method={},name={},status_code={},user={}",
+        request_metric.raw.method_name(),
+        escape_tag(&request_metric.name),
+        request_metric.status_code,
+        request_metric.user
+    );
+    if !run_id.is_empty() {
+        tags.push_str(&format!(",run_id={}", escape_tag(run_id)));
+    }
+
+    let mut fields = format!(
+        "response_time={}i,success={}",
+        request_metric.response_time, request_metric.success
+    );
+    if !request_metric.error.is_empty() {
+        fields.push_str(&format!(
+            ",error=\"{}\"",
+            request_metric
+                .error
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+        ));
+    }
+
+    format!("goose_request,{} {} {}", tags, fields, timestamp)
+}
+
+/// Escapes a tag value as required by the line protocol.
+fn escape_tag(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goose::GooseMethod;
+    use crate::metrics::GooseRawRequest;
+
+    #[test]
+    fn render_request() {
+        let mut request_metric = GooseRequestMetric::new(
+            GooseRawRequest::new(GooseMethod::Get, "http://127.0.0.1/", vec![], ""),
+            "search page?q=a,b",
+            0,
+            1,
+        );
+        request_metric.status_code = 200;
+        request_metric.response_time = 12;
+        request_metric.success = true;
+        assert_eq!(
+            render(&request_metric, "nightly", 1_636_000_000_000),
+            "goose_request,method=GET,name=search\\ page?q\\=a\\,b,status_code=200,user=1,run_id=nightly response_time=12i,success=true 1636000000000"
+        );
+
+        request_metric.status_code = 500;
+        request_metric.success = false;
+        request_metric.error = "500 \"Internal Server Error\"".to_string();
+        assert_eq!(
+            render(&request_metric, "", 1_636_000_000_000),
+            "goose_request,method=GET,name=search\\ page?q\\=a\\,b,status_code=500,user=1 response_time=12i,success=false,error=\"500 \\\"Internal Server Error\\\"\" 1636000000000"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Optional verification of response bodies.
+//!
+//! When load testing downloads or CDN endpoints, a response can return the expected status
+//! code with a truncated or corrupted body. A request can declare the SHA-1 checksum of
+//! the expected body with [`GooseRequestBuilder::expect_body_sha1`], or a range of
+//! expected body lengths with [`GooseRequestBuilder::expect_body_length`]. Goose then
+//! reads the body of each successful (2xx) response before returning it, and counts
+//! responses with an unexpected body as failed requests and as integrity failures.
+//! Response times don't include reading the body.
+//!
+//! [`GooseRequestBuilder::expect_body_sha1`]: ../goose/struct.GooseRequestBuilder.html#method.expect_body_sha1
+//! [`GooseRequestBuilder::expect_body_length`]: ../goose/struct.GooseRequestBuilder.html#method.expect_body_length
+
+use reqwest::Response;
+use sha1::{Digest, Sha1};
+
+use crate::compression::response_builder;
+use crate::metrics::GooseRequestMetric;
+
+// If expected, read the response body and verify its checksum and length. A new response
+// is returned with the same status, headers and body.
+pub(crate) async fn verify_body(
+    response: Result<Response, reqwest::Error>,
+    expect_sha1: Option<&str>,
+    expect_length: Option<(usize, usize)>,
+    request_metric: &mut GooseRequestMetric,
+) -> Result<Response, reqwest::Error> {
+    if expect_sha1.is_none() && expect_length.is_none() {
+        return response;
+    }
+    let response = response?;
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+
+    let builder = response_builder(&response);
+    let body = response.bytes().await?;
+
+    let mut error = None;
+    if let Some((min, max)) = expect_length {
+        if body.len() < min || body.len() > max {
+            debug!(
+                "{}: body length {} not within {}-{}",
+                request_metric.name,
+                body.len(),
+                min,
+                max
+            );
+            error = Some("body length mismatch");
+        }
+    }
+    if let Some(expect_sha1) = expect_sha1 {
+        let sha1 = format!("{:x}", Sha1::digest(&body));
+        if !sha1.eq_ignore_ascii_case(expect_sha1.trim()) {
+            debug!(
+                "{}: body checksum {} expected {}",
+                request_metric.name, sha1, expect_sha1
+            );
+            error = Some("body checksum mismatch");
+        }
+    }
+
+    request_metric.integrity = Some(error.is_none());
+    if let Some(error) = error {
+        request_metric.success = false;
+        request_metric.error = format!("{}: {}", error, request_metric.name);
+    }
+
+    // The builder was created from a valid response, so this can't fail.
+    Ok(Response::from(builder.body(body).unwrap()))
+}
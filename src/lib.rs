@@ -40,34 +40,78 @@
 #[macro_use]
 extern crate log;
 
+mod backoff;
+mod baseline;
+mod cache;
+mod cohort;
+mod compression;
 pub mod config;
 pub mod controller;
+pub mod device;
+mod digest;
+pub mod export;
+#[cfg(feature = "faker")]
+pub mod faker;
 pub mod goose;
+#[cfg(feature = "hdr-histogram")]
+mod histogram;
+mod identity;
+mod influxdb;
+mod integrity;
 pub mod logger;
 #[cfg(feature = "gaggle")]
 mod manager;
+mod md;
 pub mod metrics;
+pub mod network;
+#[cfg(feature = "ntlm")]
+mod ntlm;
+mod plan;
 pub mod prelude;
+mod prometheus;
+pub mod random;
+pub mod range;
+mod record;
 mod report;
+mod rotate;
+#[cfg(feature = "json-schema")]
+mod schema;
+pub mod socket;
+mod ssh;
+mod statsd;
+mod syslog;
+pub mod template;
+mod test_plan;
 mod throttle;
+#[cfg(feature = "rustls-tls")]
+mod tls;
+#[cfg(unix)]
+mod unix;
+pub mod upload;
 mod user;
 pub mod util;
+pub mod validate;
+pub mod wait;
+mod warmup;
 #[cfg(feature = "gaggle")]
 mod worker;
+pub mod write;
+pub mod xml;
 
 use chrono::prelude::*;
+use futures::Stream;
 use gumdrop::Options;
 use lazy_static::lazy_static;
 #[cfg(feature = "gaggle")]
 use nng::Socket;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
 use std::sync::{
     atomic::{AtomicBool, AtomicUsize, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 use std::time::{self, Duration};
 use std::{fmt, io};
@@ -75,9 +119,18 @@ use tokio::fs::File;
 
 use crate::config::{GooseConfiguration, GooseDefaults};
 use crate::controller::{GooseControllerProtocol, GooseControllerRequest};
-use crate::goose::{GaggleUser, GooseTask, GooseTaskSet, GooseUser, GooseUserCommand};
-use crate::logger::{GooseLoggerJoinHandle, GooseLoggerTx};
-use crate::metrics::{GooseMetric, GooseMetrics};
+use crate::export::GooseMetricsExporter;
+use crate::goose::{GaggleUser, GooseAbort, GooseTask, GooseTaskSet, GooseUser, GooseUserCommand};
+use crate::logger::{
+    GooseLogSink, GooseLogSinks, GooseLogType, GooseLoggerJoinHandle, GooseLoggerTx,
+    GooseSharedLogSink,
+};
+use crate::metrics::{
+    GooseMetric, GooseMetricEvent, GooseMetrics, GooseMetricsCheckpoint, GooseNumberFormat,
+    GooseSetupTeardown, GooseSetupTeardownMetric,
+};
+use crate::test_plan::GooseTestPlan;
+use crate::throttle::GooseRateLimit;
 #[cfg(feature = "gaggle")]
 use crate::worker::{register_shutdown_pipe_handler, GaggleMetrics};
 
@@ -158,6 +211,14 @@ pub enum GooseError {
         /// An optional explanation of the error.
         detail: String,
     },
+    /// A [`GooseTaskSet`](./goose/struct.GooseTaskSet.html) is configured in a way that
+    /// can't run as intended.
+    InvalidTaskSet {
+        /// The name of the invalid task set.
+        name: String,
+        /// An optional explanation of the error.
+        detail: String,
+    },
     /// Invalid weight specified.
     InvalidWeight {
         // The specified weight.
@@ -182,6 +243,7 @@ impl GooseError {
             GooseError::InvalidHost { .. } => "failed to parse hostname",
             GooseError::InvalidOption { .. } => "invalid option or value specified",
             GooseError::InvalidWaitTime { .. } => "invalid wait_time specified",
+            GooseError::InvalidTaskSet { .. } => "invalid task set defined",
             GooseError::InvalidWeight { .. } => "invalid weight specified",
             GooseError::NoTaskSets { .. } => "no task sets defined",
         }
@@ -304,6 +366,10 @@ struct GooseAttackRunState {
     /// A counter tracking which [`GooseUser`](./goose/struct.GooseUser.html) is being
     /// spawned.
     spawn_user_counter: usize,
+    /// Tracks when the previous [`GooseUser`](./goose/struct.GooseUser.html) was launched
+    /// and how many milliseconds until the next should be spawned, for each
+    /// [`GooseTaskSet`](./goose/struct.GooseTaskSet.html) with its own hatch rate.
+    task_set_spawn_timers: HashMap<usize, (std::time::Instant, usize)>,
     /// This variable accounts for time spent doing things which is then subtracted from
     /// the time sleeping to avoid an unintentional drift in events that are supposed to
     /// happen regularly.
@@ -324,6 +390,11 @@ struct GooseAttackRunState {
     throttle_threads_tx: Option<flume::Sender<bool>>,
     /// Optional sender for throttle thread, if enabled.
     parent_to_throttle_tx: Option<flume::Sender<bool>>,
+    /// Optional sender for all [`GooseUser`](./goose/struct.GooseUser.html)s to report
+    /// rate limits to the throttle thread, if adaptive.
+    throttle_rate_limit_tx: Option<flume::Sender<GooseRateLimit>>,
+    /// Optional handle for the throttle thread returning the rate it allowed, if adaptive.
+    throttle_handle: Option<tokio::task::JoinHandle<f32>>,
     /// Optional channel allowing controller thread to make requests, if not disabled.
     controller_channel_rx: Option<flume::Receiver<GooseControllerRequest>>,
     /// Optional unbuffered writer for html-formatted report file, if enabled.
@@ -335,14 +406,53 @@ struct GooseAttackRunState {
     idle_status_displayed: bool,
     /// Collection of all [`GooseUser`](./goose/struct.GooseUser.html) threads so they
     /// can be stopped later.
-    users: Vec<tokio::task::JoinHandle<()>>,
+    users: Vec<tokio::task::JoinHandle<GooseUser>>,
     /// All unbounded senders to allow communication with
     /// [`GooseUser`](./goose/struct.GooseUser.html) threads.
     user_channels: Vec<flume::Sender<GooseUserCommand>>,
+    /// The index of the [`GooseTaskSet`](./goose/struct.GooseTaskSet.html) each
+    /// [`GooseUser`](./goose/struct.GooseUser.html) thread is running, in the same order
+    /// as `user_channels`.
+    user_task_sets: Vec<usize>,
+    /// When each [`GooseUser`](./goose/struct.GooseUser.html) is planned to launch, in
+    /// milliseconds since the load test started, and the index of the
+    /// [`GooseTaskSet`](./goose/struct.GooseTaskSet.html) it runs. Empty when following
+    /// a test plan.
+    planned_launches: Vec<(usize, usize)>,
     /// Timer tracking when to display running metrics, if enabled.
     running_metrics_timer: std::time::Instant,
     /// Boolean flag indicating if running metrics should be displayed.
     display_running_metrics: bool,
+    /// Timer tracking when to stream a snapshot of all metrics, if enabled.
+    metrics_stream_timer: std::time::Instant,
+    /// The live metrics served to Prometheus, if enabled.
+    prometheus_metrics: Option<prometheus::GoosePrometheusMetrics>,
+    /// Optional join handle for the thread serving live metrics to Prometheus.
+    prometheus_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Timer tracking when to refresh the live metrics served to Prometheus, if enabled.
+    prometheus_timer: std::time::Instant,
+    /// Optional client sending request metrics to StatsD.
+    statsd: Option<statsd::GooseStatsd>,
+    /// Optional channel sending requests to the thread streaming them to InfluxDB.
+    influxdb_tx: Option<influxdb::GooseInfluxDbTx>,
+    /// Optional join handle for the thread streaming requests to InfluxDB.
+    influxdb_handle: Option<tokio::task::JoinHandle<usize>>,
+    /// Registered metrics exporters, running while the load test runs.
+    metrics_exporters: Vec<export::GooseMetricsExporterHandle>,
+    /// Timer tracking when to export a snapshot of all metrics, if enabled.
+    metrics_export_timer: std::time::Instant,
+    /// Optional channel sending response bodies to the background validators.
+    validation_tx: Option<validate::GooseValidationTx>,
+    /// Join handles for the background validators.
+    validation_handles: Vec<tokio::task::JoinHandle<()>>,
+    /// Timer tracking when to write an interim report, if enabled.
+    report_timer: std::time::Instant,
+    /// Timer tracking when to save a metrics checkpoint, if enabled.
+    checkpoint_timer: std::time::Instant,
+    /// Timer tracking when a Worker last pushed metrics or a heartbeat to the Manager.
+    heartbeat_timer: std::time::Instant,
+    /// Metrics checkpoint to resume from once all users are spawned, if enabled.
+    resume_checkpoint: Option<GooseMetricsCheckpoint>,
     /// Boolean flag indicating if all [`GooseUser`](./goose/struct.GooseUser.html)s
     /// have been spawned.
     all_users_spawned: bool,
@@ -351,9 +461,24 @@ struct GooseAttackRunState {
     /// Thread-safe boolean flag indicating if the [`GooseAttack`](./struct.GooseAttack.html)
     /// has been canceled.
     canceled: Arc<AtomicBool>,
+    /// Why a [`GooseUser`](./goose/struct.GooseUser.html) aborted the
+    /// [`GooseAttack`](./struct.GooseAttack.html), if it did.
+    abort_reason: Arc<Mutex<Option<String>>>,
+    /// Thread-safe boolean flag indicating if a [`GooseUser`](./goose/struct.GooseUser.html)
+    /// stopped launching more users.
+    hatching_stopped: Arc<AtomicBool>,
     /// Optional socket used to coordinate a distributed Gaggle.
     socket: Option<Socket>,
 }
+impl GooseAttackRunState {
+    // Whether a Controller request is waiting to be handled.
+    fn controller_request_pending(&self) -> bool {
+        match self.controller_channel_rx.as_ref() {
+            Some(controller_channel_rx) => !controller_channel_rx.is_empty(),
+            None => false,
+        }
+    }
+}
 
 /// Global internal state for the load test.
 pub struct GooseAttack {
@@ -373,6 +498,8 @@ pub struct GooseAttack {
     configuration: GooseConfiguration,
     /// How long (in seconds) the load test should run.
     run_time: usize,
+    /// An optional test plan ramping users up and down through multiple steps.
+    test_plan: Option<GooseTestPlan>,
     /// The load test operates in only one of the following modes: StandAlone, Manager, or Worker.
     attack_mode: AttackMode,
     /// Which phase the load test is currently operating in.
@@ -382,8 +509,24 @@ pub struct GooseAttack {
     scheduler: GooseScheduler,
     /// When the load test started.
     started: Option<time::Instant>,
+    /// When the GooseUsers were created, from when each times what it does.
+    users_created: Option<DateTime<Local>>,
     /// All metrics merged together.
     metrics: GooseMetrics,
+    /// Optional unbounded sender streaming metric events to the embedding application.
+    metrics_stream_tx: Option<flume::Sender<GooseMetricEvent>>,
+    /// Exporters registered with `register_metrics_exporter`.
+    metrics_exporters: Vec<Box<dyn GooseMetricsExporter>>,
+    /// Log sinks set with `set_log_sink`.
+    log_sinks: GooseLogSinks,
+    /// Users of a previous load test whose clients and session data are reused, in the
+    /// order they were launched.
+    reused_users: VecDeque<GooseUser>,
+    /// Users that exited when the load test stopped, in the order they were launched.
+    finished_users: Vec<GooseUser>,
+    /// JSON Schemas registered with `register_json_schema`, keyed by request name.
+    #[cfg(feature = "json-schema")]
+    json_schemas: schema::GooseJsonSchemas,
 }
 /// Goose's internal global state.
 impl GooseAttack {
@@ -405,11 +548,20 @@ impl GooseAttack {
             defaults: GooseDefaults::default(),
             configuration: GooseConfiguration::parse_args_default_or_exit(),
             run_time: 0,
+            test_plan: None,
             attack_mode: AttackMode::Undefined,
             attack_phase: AttackPhase::Idle,
             scheduler: GooseScheduler::RoundRobin,
             started: None,
+            users_created: None,
             metrics: GooseMetrics::default(),
+            metrics_stream_tx: None,
+            metrics_exporters: Vec::new(),
+            log_sinks: HashMap::new(),
+            reused_users: VecDeque::new(),
+            finished_users: Vec::new(),
+            #[cfg(feature = "json-schema")]
+            json_schemas: Arc::new(HashMap::new()),
         })
     }
 
@@ -439,11 +591,20 @@ impl GooseAttack {
             defaults: GooseDefaults::default(),
             configuration,
             run_time: 0,
+            test_plan: None,
             attack_mode: AttackMode::Undefined,
             attack_phase: AttackPhase::Idle,
             scheduler: GooseScheduler::RoundRobin,
             started: None,
+            users_created: None,
             metrics: GooseMetrics::default(),
+            metrics_stream_tx: None,
+            metrics_exporters: Vec::new(),
+            log_sinks: HashMap::new(),
+            reused_users: VecDeque::new(),
+            finished_users: Vec::new(),
+            #[cfg(feature = "json-schema")]
+            json_schemas: Arc::new(HashMap::new()),
         })
     }
 
@@ -616,6 +777,89 @@ impl GooseAttack {
         self
     }
 
+    /// Optionally register a [JSON Schema](https://json-schema.org/) that all successful
+    /// (2xx) responses to requests with this name must match.
+    ///
+    /// The body of each of these responses is read and validated before the response is
+    /// returned, without being included in the response time. Responses that aren't
+    /// JSON or don't match the schema fail, and are counted in
+    /// [`GooseRequestMetricAggregate::schema_violations`](./metrics/struct.GooseRequestMetricAggregate.html#structfield.schema_violations).
+    /// The name is the name of the request in the metrics, usually its path. This allows
+    /// load tests to share the schemas used by contract tests.
+    ///
+    /// Requires the `json-schema` compile-time feature, returning
+    /// [`GooseError::FeatureNotEnabled`] otherwise. Returns
+    /// [`GooseError::InvalidOption`] if the schema isn't valid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let users_schema = json!({
+    ///         "type": "object",
+    ///         "required": ["users"],
+    ///         "properties": { "users": { "type": "array" } }
+    ///     });
+    ///
+    ///     let goose_attack = GooseAttack::initialize()?
+    ///         .register_taskset(taskset!("ApiTasks")
+    ///             .register_task(task!(users_task))
+    ///         )
+    ///         .register_json_schema("/api/users", &users_schema);
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn users_task(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("/api/users").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "json-schema")]
+    pub fn register_json_schema(
+        mut self,
+        name: &str,
+        schema: &serde_json::Value,
+    ) -> Result<Self, GooseError> {
+        let json_schema = schema::compile(name, schema)?;
+        match Arc::get_mut(&mut self.json_schemas) {
+            Some(json_schemas) => {
+                json_schemas.insert(name.to_string(), json_schema);
+                Ok(self)
+            }
+            // Users only share the schemas once the load test starts.
+            None => Err(GooseError::InvalidOption {
+                option: "`GooseAttack::register_json_schema`".to_string(),
+                value: name.to_string(),
+                detail: "JSON Schemas must be registered before the load test starts.".to_string(),
+            }),
+        }
+    }
+
+    /// Optionally register a [JSON Schema](https://json-schema.org/) that all successful
+    /// (2xx) responses to requests with this name must match.
+    ///
+    /// Requires the `json-schema` compile-time feature, returning
+    /// [`GooseError::FeatureNotEnabled`] otherwise.
+    #[cfg(not(feature = "json-schema"))]
+    pub fn register_json_schema(
+        self,
+        name: &str,
+        _schema: &serde_json::Value,
+    ) -> Result<Self, GooseError> {
+        Err(GooseError::FeatureNotEnabled {
+            feature: "json-schema".to_string(),
+            detail: format!(
+                "Load test must be recompiled with `--features json-schema` to validate responses to {} against a JSON Schema.",
+                name
+            ),
+        })
+    }
+
     /// Use configured GooseScheduler to build out a properly weighted list of
     /// [`GooseTaskSet`](./goose/struct.GooseTaskSet.html)s to be assigned to
     /// [`GooseUser`](./goose/struct.GooseUser.html)s
@@ -624,7 +868,8 @@ impl GooseAttack {
 
         let mut u: usize = 0;
         let mut v: usize;
-        for task_set in &self.task_sets {
+        // Task sets with a fixed number of users are not allocated by weight.
+        for task_set in self.task_sets.iter().filter(|t| t.users.is_none()) {
             if u == 0 {
                 u = task_set.weight;
             } else {
@@ -641,6 +886,11 @@ impl GooseAttack {
         let mut available_task_sets = Vec::with_capacity(self.task_sets.len());
         let mut total_task_sets = 0;
         for (index, task_set) in self.task_sets.iter().enumerate() {
+            if task_set.users.is_some() {
+                trace!("{}: {} has a fixed number of users", index, task_set.name);
+                available_task_sets.push(Vec::new());
+                continue;
+            }
             // divide by greatest common divisor so vector is as short as possible
             let weight = task_set.weight / u;
             trace!(
@@ -694,9 +944,10 @@ impl GooseAttack {
                 }
             }
             GooseScheduler::Random => {
-                // Allocate task sets randomly.
+                // Allocate task sets randomly, in the same order every run if seeded.
+                let mut rng = random::scheduler_rng(self.configuration.seed);
                 loop {
-                    let task_set = available_task_sets.choose_mut(&mut rand::thread_rng());
+                    let task_set = available_task_sets.choose_mut(&mut rng);
                     match task_set {
                         Some(set) => {
                             if let Some(s) = set.pop() {
@@ -714,75 +965,160 @@ impl GooseAttack {
         weighted_task_sets
     }
 
+    /// Allocate a vector of task set indexes, one for each user that will be launched.
+    fn allocate_users(&mut self) -> Result<Vec<usize>, GooseError> {
+        trace!("allocate_users");
+
+        // Users are required here so unwrap() is safe.
+        let total_users = self.configuration.users.unwrap();
+
+        // Task sets with a fixed number of users are allocated before task sets allocated
+        // by weight.
+        let fixed_users: usize = self.task_sets.iter().filter_map(|t| t.users).sum();
+        if fixed_users > total_users {
+            return Err(GooseError::InvalidOption {
+                option: "--users".to_string(),
+                value: total_users.to_string(),
+                detail: format!(
+                    "--users must be at least {}, the number of users fixed with GooseTaskSet.set_users().",
+                    fixed_users
+                ),
+            });
+        }
+        let weighted_task_sets = self.allocate_task_sets();
+        if weighted_task_sets.is_empty() && fixed_users != total_users {
+            return Err(GooseError::InvalidOption {
+                option: "--users".to_string(),
+                value: total_users.to_string(),
+                detail: format!(
+                    "--users must be {} when all task sets have a fixed number of users.",
+                    fixed_users
+                ),
+            });
+        }
+
+        // Build a list of users for each task set with a fixed number of users, and one list
+        // of all users allocated by weight.
+        let mut user_groups: Vec<Vec<usize>> = self
+            .task_sets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, task_set)| task_set.users.map(|users| vec![index; users]))
+            .collect();
+        user_groups.push(
+            weighted_task_sets
+                .iter()
+                .cycle()
+                .take(total_users - fixed_users)
+                .copied()
+                .collect(),
+        );
+
+        // Spread the users of each group evenly through the allocated users, always
+        // allocating from the group that has the smallest share of its users allocated.
+        let mut allocated = vec![0; user_groups.len()];
+        let mut users = Vec::with_capacity(total_users);
+        while users.len() < total_users {
+            let mut next: Option<usize> = None;
+            for (group, group_users) in user_groups.iter().enumerate() {
+                if allocated[group] >= group_users.len() {
+                    continue;
+                }
+                next = match next {
+                    Some(n)
+                        if allocated[n] * group_users.len()
+                            <= allocated[group] * user_groups[n].len() =>
+                    {
+                        Some(n)
+                    }
+                    _ => Some(group),
+                };
+            }
+            // The loop only runs while users remain to be allocated, so unwrap() is safe.
+            let group = next.unwrap();
+            users.push(user_groups[group][allocated[group]]);
+            allocated[group] += 1;
+        }
+
+        if fixed_users > 0 {
+            match self.scheduler {
+                GooseScheduler::RoundRobin => (),
+                // Allocate users serially in the order task sets are defined.
+                GooseScheduler::Serial => users.sort_unstable(),
+                // Allocate users randomly.
+                GooseScheduler::Random => {
+                    users.shuffle(&mut random::scheduler_rng(self.configuration.seed))
+                }
+            }
+        }
+
+        Ok(users)
+    }
+
     /// Allocate a vector of weighted [`GooseUser`](./goose/struct.GooseUser.html)s.
     fn weight_task_set_users(&mut self) -> Result<Vec<GooseUser>, GooseError> {
         trace!("weight_task_set_users");
 
-        let weighted_task_sets = self.allocate_task_sets();
+        let allocated_users = self.allocate_users()?;
 
         // Allocate a state for each user that will be hatched.
         info!("initializing user states...");
-        let mut weighted_users = Vec::new();
-        let mut user_count = 0;
-        loop {
-            for task_sets_index in &weighted_task_sets {
-                debug!(
-                    "creating user state: {} ({})",
-                    weighted_users.len(),
-                    task_sets_index
-                );
-                let base_url = goose::get_base_url(
-                    self.get_configuration_host(),
-                    self.task_sets[*task_sets_index].host.clone(),
-                    self.defaults.host.clone(),
-                )?;
-                weighted_users.push(GooseUser::new(
-                    self.task_sets[*task_sets_index].task_sets_index,
-                    base_url,
-                    &self.configuration,
-                    self.metrics.hash,
-                )?);
-                user_count += 1;
-                // Users are required here so unwrap() is safe.
-                if user_count >= self.configuration.users.unwrap() {
-                    debug!("created {} weighted_users", user_count);
-                    return Ok(weighted_users);
-                }
-            }
+        self.create_task_set_users(allocated_users)
+    }
+
+    /// Create a [`GooseUser`](./goose/struct.GooseUser.html) for each of the allocated
+    /// task set indexes.
+    fn create_task_set_users(
+        &mut self,
+        allocated_users: Vec<usize>,
+    ) -> Result<Vec<GooseUser>, GooseError> {
+        let mut weighted_users = Vec::with_capacity(allocated_users.len());
+        for task_sets_index in allocated_users {
+            debug!(
+                "creating user state: {} ({})",
+                weighted_users.len(),
+                task_sets_index
+            );
+            let base_url = goose::get_base_url(
+                self.get_configuration_host(),
+                self.task_sets[task_sets_index].host.clone(),
+                self.defaults.host.clone(),
+            )?;
+            weighted_users.push(GooseUser::new(
+                self.task_sets[task_sets_index].task_sets_index,
+                base_url,
+                &self.configuration,
+                self.metrics.hash,
+            )?);
         }
+        debug!("created {} weighted_users", weighted_users.len());
+        Ok(weighted_users)
     }
 
     /// Allocate a vector of weighted [`GaggleUser`](./goose/struct.GaggleUser.html).
     fn prepare_worker_task_set_users(&mut self) -> Result<Vec<GaggleUser>, GooseError> {
         trace!("prepare_worker_task_set_users");
 
-        let weighted_task_sets = self.allocate_task_sets();
+        let allocated_users = self.allocate_users()?;
 
         // Determine the users sent to each Worker.
         info!("preparing users for Workers...");
-        let mut weighted_users = Vec::new();
-        let mut user_count = 0;
-        loop {
-            for task_sets_index in &weighted_task_sets {
-                let base_url = goose::get_base_url(
-                    self.get_configuration_host(),
-                    self.task_sets[*task_sets_index].host.clone(),
-                    self.defaults.host.clone(),
-                )?;
-                weighted_users.push(GaggleUser::new(
-                    self.task_sets[*task_sets_index].task_sets_index,
-                    base_url,
-                    &self.configuration,
-                    self.metrics.hash,
-                ));
-                user_count += 1;
-                // Users are required here so unwrap() is safe.
-                if user_count >= self.configuration.users.unwrap() {
-                    debug!("prepared {} weighted_gaggle_users", user_count);
-                    return Ok(weighted_users);
-                }
-            }
+        let mut weighted_users = Vec::with_capacity(allocated_users.len());
+        for task_sets_index in allocated_users {
+            let base_url = goose::get_base_url(
+                self.get_configuration_host(),
+                self.task_sets[task_sets_index].host.clone(),
+                self.defaults.host.clone(),
+            )?;
+            weighted_users.push(GaggleUser::new(
+                self.task_sets[task_sets_index].task_sets_index,
+                base_url,
+                &self.configuration,
+                self.metrics.hash,
+            ));
         }
+        debug!("prepared {} weighted_gaggle_users", weighted_users.len());
+        Ok(weighted_users)
     }
 
     // Change from one attack_phase to another.
@@ -806,11 +1142,46 @@ impl GooseAttack {
         self.attack_phase = phase;
     }
 
+    // Calculate a unique hash for the load test, used to confirm Workers in a Gaggle and
+    // resumed metrics checkpoints are running the same load test.
+    fn load_test_hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.task_sets.hash(&mut s);
+        s.finish()
+    }
+
     fn set_run_time(&mut self) -> Result<(), GooseError> {
-        self.run_time = util::parse_timespan(&self.configuration.run_time);
+        // A test plan runs for as long as its steps take.
+        if !self.configuration.test_plan.is_empty() {
+            let test_plan = GooseTestPlan::parse(&self.configuration.test_plan)?;
+            self.run_time = test_plan.run_time();
+            self.test_plan = Some(test_plan);
+        } else if let Some(test_plan) = self.ramp_hold_ramp() {
+            self.run_time = test_plan.run_time();
+            self.test_plan = Some(test_plan);
+        } else {
+            self.run_time = util::parse_timespan(&self.configuration.run_time);
+        }
         Ok(())
     }
 
+    // If `--ramp-up`, `--hold` or `--ramp-down` is set, returns the test plan they
+    // describe.
+    fn ramp_hold_ramp(&self) -> Option<GooseTestPlan> {
+        let ramp_up = util::parse_timespan(&self.configuration.ramp_up);
+        let hold = util::parse_timespan(&self.configuration.hold);
+        let ramp_down = util::parse_timespan(&self.configuration.ramp_down);
+        if ramp_up + hold + ramp_down == 0 {
+            return None;
+        }
+        Some(GooseTestPlan::ramp_hold_ramp(
+            self.configuration.users.unwrap_or(0),
+            ramp_up,
+            hold,
+            ramp_down,
+        ))
+    }
+
     // If enabled, returns the path of the report_file, otherwise returns None.
     fn get_report_file_path(&mut self) -> Option<String> {
         // Return if enabled.
@@ -822,6 +1193,167 @@ impl GooseAttack {
         }
     }
 
+    /// Returns a stream of [`GooseMetricEvent`](./metrics/enum.GooseMetricEvent.html)s,
+    /// so an application embedding Goose can follow the load test while it runs, for
+    /// example to update a custom dashboard.
+    ///
+    /// The stream yields an event for each completed request and task, and a snapshot of
+    /// all metrics aggregated so far about once per second. It ends when the load test
+    /// finishes. Events are buffered without limit, so the stream should be consumed
+    /// concurrently with [`GooseAttack::execute`](./struct.GooseAttack.html#method.execute).
+    /// Only one stream is active at a time: calling this function again ends any stream
+    /// previously returned. No events are streamed when metrics are disabled, or from the
+    /// Manager of a Gaggle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use goose::metrics::GooseMetricEvent;
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let mut goose_attack = GooseAttack::initialize()?
+    ///         .register_taskset(taskset!("ExampleTasks")
+    ///             .register_task(task!(example_task))
+    ///             // Goose must run against a host, point to localhost so test starts.
+    ///             .set_host("http://localhost")
+    ///         )
+    ///         // Exit after one second so test doesn't run forever.
+    ///         .set_default(GooseDefault::RunTime, 1)?;
+    ///
+    ///     // Count requests while the load test runs.
+    ///     let mut metrics_stream = goose_attack.metrics_stream();
+    ///     let counter = tokio::spawn(async move {
+    ///         let mut requests = 0;
+    ///         while let Some(event) = metrics_stream.next().await {
+    ///             if let GooseMetricEvent::Request(_) = event {
+    ///                 requests += 1;
+    ///             }
+    ///         }
+    ///         requests
+    ///     });
+    ///
+    ///     let _goose_metrics = goose_attack.execute().await?;
+    ///     let _requests = counter.await.unwrap();
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn example_task(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("/foo").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn metrics_stream(&mut self) -> impl Stream<Item = GooseMetricEvent> + Unpin {
+        let (metrics_stream_tx, metrics_stream_rx) = flume::unbounded();
+        self.metrics_stream_tx = Some(metrics_stream_tx);
+        metrics_stream_rx.into_stream()
+    }
+
+    /// Register an exporter that receives a snapshot of all metrics about once per second
+    /// while the load test runs, and the final metrics once it stops, so live metrics can
+    /// be sent to telemetry systems Goose doesn't natively support. Any number of
+    /// exporters can be registered. For more, see the
+    /// [`export`](./export/index.html) module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::export::GooseMetricsExporter;
+    /// use goose::metrics::GooseMetrics;
+    /// use goose::prelude::*;
+    ///
+    /// struct DurationExporter;
+    /// #[async_trait::async_trait]
+    /// impl GooseMetricsExporter for DurationExporter {
+    ///     async fn export(&mut self, metrics: &GooseMetrics) -> Result<(), String> {
+    ///         println!("load test has run for {} seconds", metrics.duration);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let _goose_attack = GooseAttack::initialize()?
+    ///         .register_metrics_exporter(DurationExporter);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn register_metrics_exporter<T: GooseMetricsExporter + 'static>(
+        mut self,
+        exporter: T,
+    ) -> Self {
+        self.metrics_exporters.push(Box::new(exporter));
+        self
+    }
+
+    /// Write a log to a custom [`GooseLogSink`](./logger/trait.GooseLogSink.html), so the
+    /// [`GooseDebug`](./goose/struct.GooseDebug.html) messages or requests made by the load
+    /// test can be captured in-process instead of parsing a log file. The sink replaces any
+    /// file, stdout or syslog the log was configured to be written to, and enables the log
+    /// if it wasn't. The same sink is used each time the load test is started. For more,
+    /// see the [`logger`](./logger/index.html) module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::logger::{GooseLog, GooseLogSink, GooseLogType};
+    /// use goose::prelude::*;
+    /// use std::io;
+    ///
+    /// struct PrintDebug;
+    /// #[async_trait::async_trait]
+    /// impl GooseLogSink for PrintDebug {
+    ///     async fn write(&mut self, message: &GooseLog, _formatted: &[u8]) -> io::Result<()> {
+    ///         if let GooseLog::Debug(debug) = message {
+    ///             println!("{}", debug.tag);
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let _goose_attack = GooseAttack::initialize()?
+    ///         .set_log_sink(GooseLogType::Debug, PrintDebug);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_log_sink<T: GooseLogSink + 'static>(mut self, log: GooseLogType, sink: T) -> Self {
+        // Users only send messages to enabled logs.
+        let log_name = match log {
+            GooseLogType::Debug => &mut self.configuration.debug_log,
+            GooseLogType::Error => &mut self.configuration.error_log,
+            GooseLogType::Request => &mut self.configuration.request_log,
+            GooseLogType::Task => &mut self.configuration.task_log,
+        };
+        if log_name.is_empty() {
+            *log_name = "custom".to_string();
+        }
+        self.log_sinks.insert(log, GooseSharedLogSink::new(sink));
+        self
+    }
+
+    /// Reuse the clients and session data of users returned by
+    /// [`GooseAttack::execute_and_keep_users`](./struct.GooseAttack.html#method.execute_and_keep_users)
+    /// from a previous load test.
+    ///
+    /// Each [`GooseUser`](./goose/struct.GooseUser.html) launched by this load test takes
+    /// over the client, named clients and session data of the user launched in the same
+    /// order by the previous load test, so open connections, cookies and logged in
+    /// sessions carry over. If this load test launches more users than are provided, the
+    /// additional users start with new clients and no session data. Metrics are not
+    /// carried over, each load test reports its own.
+    ///
+    /// See [`GooseAttack::execute_and_keep_users`](./struct.GooseAttack.html#method.execute_and_keep_users)
+    /// for an example.
+    pub fn reuse_users(mut self, users: Vec<GooseUser>) -> Self {
+        self.reused_users = users.into();
+        self
+    }
+
     /// Execute the [`GooseAttack`](./struct.GooseAttack.html) load test.
     ///
     /// # Example
@@ -857,7 +1389,150 @@ impl GooseAttack {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn execute(mut self) -> Result<GooseMetrics, GooseError> {
+    pub async fn execute(self) -> Result<GooseMetrics, GooseError> {
+        let goose_attack = self.run().await;
+        // The users are dropped, so their SSH tunnels are no longer needed.
+        ssh::close_tunnels();
+        Ok(goose_attack?.metrics)
+    }
+
+    /// Execute the [`GooseAttack`](./struct.GooseAttack.html) load test, also returning
+    /// the [`GooseUser`](./goose/struct.GooseUser.html)s in the order they were launched.
+    ///
+    /// The returned users can be passed to
+    /// [`GooseAttack::reuse_users`](./struct.GooseAttack.html#method.reuse_users) to run
+    /// another load test with the same clients and session data. When running in Gaggle
+    /// mode users run on the Workers, and no users are returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), GooseError> {
+    ///     let (write_metrics, users) = GooseAttack::initialize()?
+    ///         .register_taskset(taskset!("WriteTasks")
+    ///             .register_task(task!(write_task))
+    ///             .set_host("http://localhost")
+    ///         )
+    ///         .set_default(GooseDefault::RunTime, 1)?
+    ///         .execute_and_keep_users()
+    ///         .await?;
+    ///
+    ///     let read_metrics = GooseAttack::initialize()?
+    ///         .register_taskset(taskset!("ReadTasks")
+    ///             .register_task(task!(read_task))
+    ///             .set_host("http://localhost")
+    ///         )
+    ///         .set_default(GooseDefault::RunTime, 1)?
+    ///         .reuse_users(users)
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn write_task(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.post("/foo", "bar").await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// async fn read_task(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get("/foo").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_and_keep_users(
+        self,
+    ) -> Result<(GooseMetrics, Vec<GooseUser>), GooseError> {
+        let goose_attack = self.run().await?;
+        Ok((goose_attack.metrics, goose_attack.finished_users))
+    }
+
+    // Validate the registered task sets, returning an error describing the first task set
+    // that can't run as intended.
+    fn validate_task_sets(&self) -> Result<(), GooseError> {
+        let mut names = HashSet::new();
+        for task_set in &self.task_sets {
+            let invalid = |detail: String| GooseError::InvalidTaskSet {
+                name: task_set.name.to_string(),
+                detail,
+            };
+
+            // Task set names identify task sets in metrics, logs and Controller commands.
+            if !names.insert(task_set.name.as_str()) {
+                return Err(invalid(format!(
+                    "Task set name \"{}\" is used by more than one task set.",
+                    task_set.name
+                )));
+            }
+
+            // Users running a task set without tasks would do nothing.
+            if task_set.tasks.is_empty()
+                && task_set.setup_task.is_none()
+                && task_set.teardown_task.is_none()
+            {
+                return Err(invalid(format!(
+                    "Task set \"{}\" has no tasks.",
+                    task_set.name
+                )));
+            }
+
+            // Weights are normally validated by `set_weight()`, but can be set directly.
+            if task_set.weight == 0 {
+                return Err(GooseError::InvalidWeight {
+                    weight: task_set.weight,
+                    detail: format!(
+                        "Task set \"{}\" weight must be set to at least 1.",
+                        task_set.name
+                    ),
+                });
+            }
+
+            let mut task_names = HashSet::new();
+            for task in &task_set.tasks {
+                // Tasks don't need to be named, but named tasks must be distinguishable.
+                if !task.name.is_empty() && !task_names.insert(task.name.as_str()) {
+                    return Err(invalid(format!(
+                        "Task name \"{}\" is used by more than one task in task set \"{}\".",
+                        task.name, task_set.name
+                    )));
+                }
+                if task.weight == 0 {
+                    return Err(GooseError::InvalidWeight {
+                        weight: task.weight,
+                        detail: format!(
+                            "Task \"{}\" in task set \"{}\" weight must be set to at least 1.",
+                            task.name, task_set.name
+                        ),
+                    });
+                }
+            }
+
+            // Setup and teardown tasks run once outside of any user, so can't also be
+            // sequenced or run when users start or stop.
+            for (kind, task) in [
+                ("setup", &task_set.setup_task),
+                ("teardown", &task_set.teardown_task),
+            ] {
+                if let Some(task) = task {
+                    if task.sequence > 0 || task.on_start || task.on_stop {
+                        return Err(invalid(format!(
+                            "The {} task of task set \"{}\" can not be sequenced, or set to run on start or on stop.",
+                            kind, task_set.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Run the load test, returning the GooseAttack once it has finished.
+    async fn run(mut self) -> Result<GooseAttack, GooseError> {
         // If version flag is set, display package name and version and exit.
         if self.configuration.version {
             println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
@@ -871,6 +1546,9 @@ impl GooseAttack {
             });
         }
 
+        // Fail fast if any task set can't run as intended.
+        self.validate_task_sets()?;
+
         // Display task sets and tasks, then exit.
         if self.configuration.list {
             println!("Available tasks:");
@@ -892,6 +1570,12 @@ impl GooseAttack {
         // Configure the validated run time.
         self.set_run_time()?;
 
+        // Record a browser session through a proxy instead of running a load test.
+        if !self.configuration.record.is_empty() {
+            record::record_main(&self.configuration, self.run_time).await?;
+            return Ok(self);
+        }
+
         // With a validated GooseConfiguration, enter a run mode.
         self.attack_mode = if self.configuration.manager {
             AttackMode::Manager
@@ -914,10 +1598,16 @@ impl GooseAttack {
             self.prepare_load_test()?;
         }
 
+        // Log labels describing the load test, so the Goose log can be traced back to it.
+        for label in &self.configuration.label {
+            info!("label: {}", label);
+        }
+        if !self.configuration.run_id.is_empty() {
+            info!("run id: {}", self.configuration.run_id);
+        }
+
         // Calculate a unique hash for the current load test.
-        let mut s = DefaultHasher::new();
-        self.task_sets.hash(&mut s);
-        self.metrics.hash = s.finish();
+        self.metrics.hash = self.load_test_hash();
         debug!("hash: {}", self.metrics.hash);
 
         // Start goose in manager mode.
@@ -954,7 +1644,7 @@ impl GooseAttack {
             self = self.start_attack(None).await?;
         }
 
-        Ok(self.metrics)
+        Ok(self)
     }
 
     // Returns OK(()) if there's a valid host, GooseError with details if not.
@@ -997,13 +1687,19 @@ impl GooseAttack {
             self.validate_host()?;
         }
 
-        // Apply weights to tasks in each task set.
+        // Apply weights to tasks in each task set, in the same order every run if seeded.
+        let mut rng = random::scheduler_rng(self.configuration.seed);
         for task_set in &mut self.task_sets {
-            let (weighted_on_start_tasks, weighted_tasks, weighted_on_stop_tasks) =
-                allocate_tasks(task_set, &self.scheduler);
+            let (
+                weighted_on_start_tasks,
+                weighted_tasks,
+                weighted_on_stop_tasks,
+                weighted_group_ends,
+            ) = allocate_tasks(task_set, &self.scheduler, &mut rng);
             task_set.weighted_on_start_tasks = weighted_on_start_tasks;
             task_set.weighted_tasks = weighted_tasks;
             task_set.weighted_on_stop_tasks = weighted_on_stop_tasks;
+            task_set.weighted_group_ends = weighted_group_ends;
             debug!(
                 "weighted {} on_start: {:?} tasks: {:?} on_stop: {:?}",
                 task_set.name,
@@ -1018,14 +1714,23 @@ impl GooseAttack {
             if !self.configuration.no_metrics {
                 self.metrics.display_metrics = true;
             }
+            self.metrics.output = self.configuration.output.unwrap_or_default();
+            self.metrics.number_format = GooseNumberFormat::new(&self.configuration);
 
             if self.attack_mode == AttackMode::StandAlone {
                 // Allocate a state for each of the users we are about to start.
-                self.weighted_users = self.weight_task_set_users()?;
+                self.users_created = Some(Local::now());
+                // A test plan launches users as it runs.
+                if self.test_plan.is_none() {
+                    self.weighted_users = self.weight_task_set_users()?;
+                }
             } else if self.attack_mode == AttackMode::Manager {
                 // Build a list of users to be allocated on Workers.
                 self.weighted_gaggle_users = self.prepare_worker_task_set_users()?;
             }
+
+            // Optionally write the allocation plan before any user is launched.
+            self.write_plan_file()?;
         }
 
         Ok(())
@@ -1050,10 +1755,15 @@ impl GooseAttack {
         Option<flume::Sender<bool>>,
         // A channel used by parent to tell throttle the load test is complete.
         Option<flume::Sender<bool>>,
+        // A channel used by [`GooseUser`](./goose/struct.GooseUser.html)s to report rate
+        // limits to the throttle, if adaptive.
+        Option<flume::Sender<GooseRateLimit>>,
+        // A handle returning the rate allowed by the throttle, if adaptive.
+        Option<tokio::task::JoinHandle<f32>>,
     ) {
         // If the throttle isn't enabled, return immediately.
         if self.configuration.throttle_requests == 0 {
-            return (None, None);
+            return (None, None, None, None);
         }
 
         // Create a bounded channel allowing single-sender multi-receiver to throttle
@@ -1068,12 +1778,25 @@ impl GooseAttack {
         // oneshot channel as we don't want to block waiting for a message.
         let (parent_to_throttle_tx, throttle_rx) = flume::bounded(1);
 
-        // Launch a new thread for throttling, no need to rejoin it.
-        let _ = Some(tokio::spawn(throttle::throttle_main(
-            self.configuration.throttle_requests,
-            throttle_receiver,
-            throttle_rx,
-        )));
+        // An adaptive throttle is rejoined to learn the rate it allowed.
+        let (rate_limit_tx, throttle_handle) = if self.configuration.adaptive_throttle {
+            let (rate_limit_tx, rate_limit_rx) = flume::unbounded();
+            let throttle_handle = tokio::spawn(throttle::adaptive_throttle_main(
+                self.configuration.throttle_requests,
+                throttle_receiver,
+                throttle_rx,
+                rate_limit_rx,
+            ));
+            (Some(rate_limit_tx), Some(throttle_handle))
+        } else {
+            // Launch a new thread for throttling, no need to rejoin it.
+            let _ = Some(tokio::spawn(throttle::throttle_main(
+                self.configuration.throttle_requests,
+                throttle_receiver,
+                throttle_rx,
+            )));
+            (None, None)
+        };
 
         let sender = all_threads_throttle.clone();
         // We start from 1 instead of 0 to intentionally fill all but one slot in the
@@ -1086,7 +1809,12 @@ impl GooseAttack {
             let _ = sender.send_async(true).await;
         }
 
-        (Some(all_threads_throttle), Some(parent_to_throttle_tx))
+        (
+            Some(all_threads_throttle),
+            Some(parent_to_throttle_tx),
+            rate_limit_tx,
+            throttle_handle,
+        )
     }
 
     // Helper to optionally spawn a telnet and/or WebSocket Controller thread. The Controller
@@ -1181,7 +1909,7 @@ impl GooseAttack {
     }
 
     // Invoke `test_start` tasks if existing.
-    async fn run_test_start(&self) -> Result<(), GooseError> {
+    async fn run_test_start(&mut self) -> Result<(), GooseError> {
         // Initialize per-user states.
         if self.attack_mode != AttackMode::Worker {
             // First run global test_start_task, if defined.
@@ -1201,16 +1929,23 @@ impl GooseAttack {
                 // No test_start_task defined, nothing to do.
                 None => (),
             }
+
+            // Then run the setup task of each task set, if defined.
+            self.run_setup_teardown(GooseSetupTeardown::Setup).await?;
         }
 
         Ok(())
     }
 
     // Invoke `test_stop` tasks if existing.
-    async fn run_test_stop(&self) -> Result<(), GooseError> {
+    async fn run_test_stop(&mut self) -> Result<(), GooseError> {
         // Initialize per-user states.
         if self.attack_mode != AttackMode::Worker {
-            // First run global test_stop_task, if defined.
+            // First run the teardown task of each task set, if defined.
+            self.run_setup_teardown(GooseSetupTeardown::Teardown)
+                .await?;
+
+            // Then run global test_stop_task, if defined.
             match &self.test_stop_task {
                 Some(t) => {
                     info!("running test_stop_task");
@@ -1232,6 +1967,49 @@ impl GooseAttack {
         Ok(())
     }
 
+    // Run the setup or teardown task of each task set that defines one, recording how
+    // long each takes and whether it succeeded.
+    async fn run_setup_teardown(&mut self, kind: GooseSetupTeardown) -> Result<(), GooseError> {
+        for task_set in &self.task_sets {
+            let task = match kind {
+                GooseSetupTeardown::Setup => task_set.setup_task.as_ref(),
+                GooseSetupTeardown::Teardown => task_set.teardown_task.as_ref(),
+            };
+            if let Some(t) = task {
+                info!("running {} task of {}", kind, task_set.name);
+                // Create a one-time-use User against the task set's host.
+                let base_url = goose::get_base_url(
+                    self.get_configuration_host(),
+                    task_set.host.clone(),
+                    self.defaults.host.clone(),
+                )?;
+                let mut user = GooseUser::single(base_url, &self.configuration)?;
+                user.default_headers = task_set.default_headers.clone();
+                user.default_query = task_set.default_query.clone();
+                let started = time::Instant::now();
+                let result = (t.function)(&mut user).await;
+                let run_time = started.elapsed().as_millis() as u64;
+                let error = match result {
+                    Ok(_) => None,
+                    Err(e) => {
+                        warn!("{} task of {} failed: {}", kind, task_set.name, e);
+                        Some(e.to_string())
+                    }
+                };
+                self.metrics.setup_teardown.push(GooseSetupTeardownMetric {
+                    taskset_index: task_set.task_sets_index,
+                    taskset_name: task_set.name.to_string(),
+                    kind,
+                    run_time,
+                    success: error.is_none(),
+                    error,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     // Create a GooseAttackRunState object and do all initialization required
     // to start a [`GooseAttack`](./struct.GooseAttack.html).
     async fn initialize_attack(
@@ -1240,6 +2018,9 @@ impl GooseAttack {
     ) -> Result<GooseAttackRunState, GooseError> {
         trace!("initialize_attack");
 
+        // If resuming, load the metrics checkpoint before starting anything else.
+        let resume_checkpoint = self.load_checkpoint().await?;
+
         // Create a single channel used to send metrics from GooseUser threads
         // to parent thread.
         let (all_threads_metrics_tx, metrics_rx): (
@@ -1250,6 +2031,25 @@ impl GooseAttack {
         // Optionally spawn a telnet and/or Websocket Controller thread.
         let controller_channel_rx = self.setup_controllers().await;
 
+        // Optionally spawn a thread serving live metrics to Prometheus.
+        let (prometheus_metrics, prometheus_handle) = if self.configuration.prometheus_port > 0 {
+            let (prometheus_metrics, prometheus_handle) =
+                prometheus::spawn_prometheus(self.configuration.prometheus_port).await?;
+            (Some(prometheus_metrics), Some(prometheus_handle))
+        } else {
+            (None, None)
+        };
+
+        // Optionally connect to StatsD to send request metrics.
+        let statsd = if self.configuration.statsd_host.is_empty() {
+            None
+        } else {
+            Some(statsd::GooseStatsd::connect(
+                &self.configuration.statsd_host,
+                &self.configuration.run_id,
+            )?)
+        };
+
         // Grab now() once from the standard library, used by multiple timers in
         // the run state.
         let std_now = std::time::Instant::now();
@@ -1258,6 +2058,7 @@ impl GooseAttack {
             spawn_user_timer: std_now,
             spawn_user_in_ms: 0,
             spawn_user_counter: 0,
+            task_set_spawn_timers: HashMap::new(),
             drift_timer: tokio::time::Instant::now(),
             all_threads_metrics_tx,
             metrics_rx,
@@ -1265,17 +2066,38 @@ impl GooseAttack {
             all_threads_logger_tx: None,
             throttle_threads_tx: None,
             parent_to_throttle_tx: None,
+            throttle_rate_limit_tx: None,
+            throttle_handle: None,
             controller_channel_rx,
             report_file: None,
             metrics_header_displayed: false,
             idle_status_displayed: false,
             users: Vec::new(),
             user_channels: Vec::new(),
+            user_task_sets: Vec::new(),
+            planned_launches: Vec::new(),
             running_metrics_timer: std_now,
             display_running_metrics: false,
+            metrics_stream_timer: std_now,
+            prometheus_metrics,
+            prometheus_handle,
+            prometheus_timer: std_now,
+            statsd,
+            influxdb_tx: None,
+            influxdb_handle: None,
+            metrics_exporters: Vec::new(),
+            metrics_export_timer: std_now,
+            validation_tx: None,
+            validation_handles: Vec::new(),
+            report_timer: std_now,
+            checkpoint_timer: std_now,
+            heartbeat_timer: std_now,
+            resume_checkpoint,
             all_users_spawned: false,
             shutdown_after_stop: !self.configuration.no_autostart,
             canceled: Arc::new(AtomicBool::new(false)),
+            abort_reason: Arc::new(Mutex::new(None)),
+            hatching_stopped: Arc::new(AtomicBool::new(false)),
             socket,
         };
 
@@ -1294,125 +2116,79 @@ impl GooseAttack {
         &mut self,
         goose_attack_run_state: &mut GooseAttackRunState,
     ) -> Result<(), GooseError> {
-        // If `startup_time` has been configured, calculate the hatch_rate.
-        let hatch_rate = if self.configuration.startup_time != "0" {
-            if let Some(users) = self.configuration.users {
-                // Divide the number of users by the total time to start up to calculate the
-                // hatch rate.
-                users as f32 / util::parse_timespan(&self.configuration.startup_time) as f32
-            } else {
-                // Users have to be configured.
-                unreachable!();
-            }
-        // Otherwise either `hatch_rate` was configured or Goose will default to launching
-        // one GooseUser per second.
-        } else {
-            util::get_hatch_rate(self.configuration.hatch_rate.clone())
-        };
+        self.hold_running_users(goose_attack_run_state);
+        let hatch_rate = self.hatch_rate();
 
-        // Determine if it's time to spawn a GooseUser.
+        // Determine if it's time to spawn a GooseUser from a GooseTaskSet without its own
+        // hatch rate.
+        let mut spawned_user = false;
         if goose_attack_run_state.spawn_user_in_ms == 0
             || util::ms_timer_expired(
                 goose_attack_run_state.spawn_user_timer,
                 goose_attack_run_state.spawn_user_in_ms,
             )
         {
-            if let Some(mut thread_user) = self.weighted_users.pop() {
+            if let Some(index) = self.next_weighted_user(None) {
                 // Reset the spawn timer.
                 goose_attack_run_state.spawn_user_timer = std::time::Instant::now();
+                goose_attack_run_state.spawn_user_in_ms = self.spawn_user_in_ms(hatch_rate);
 
-                // To determine how long before we spawn the next GooseUser, start with 1,000.0
-                // milliseconds and divide by the hatch_rate.
-                goose_attack_run_state.spawn_user_in_ms = (1_000.0 / hatch_rate) as usize;
-
-                // If running on a Worker, multiple by the number of workers as each is spawning
-                // GooseUsers at this rate.
-                if self.attack_mode == AttackMode::Worker {
-                    goose_attack_run_state.spawn_user_in_ms *=
-                        self.configuration.expect_workers.unwrap() as usize;
-                };
-                goose_attack_run_state.spawn_user_counter += 1;
-
-                // Remember which task group this user is using.
-                thread_user.weighted_users_index = self.metrics.users;
-
-                // Create a per-thread channel allowing parent thread to control child threads.
-                let (parent_sender, thread_receiver): (
-                    flume::Sender<GooseUserCommand>,
-                    flume::Receiver<GooseUserCommand>,
-                ) = flume::unbounded();
-                goose_attack_run_state.user_channels.push(parent_sender);
-
-                // Clone the logger_tx if enabled, otherwise is None.
-                thread_user.logger = goose_attack_run_state.all_threads_logger_tx.clone();
-
-                // Copy the GooseUser-throttle receiver channel, used by all threads.
-                thread_user.throttle = if self.configuration.throttle_requests > 0 {
-                    Some(goose_attack_run_state.throttle_threads_tx.clone().unwrap())
-                } else {
-                    None
-                };
-
-                // Copy the GooseUser-to-parent sender channel, used by all threads.
-                thread_user.channel_to_parent =
-                    Some(goose_attack_run_state.all_threads_metrics_tx.clone());
-
-                // Copy the appropriate task_set into the thread.
-                let thread_task_set = self.task_sets[thread_user.task_sets_index].clone();
-
-                // We number threads from 1 as they're human-visible (in the logs),
-                // whereas metrics.users starts at 0.
-                let thread_number = self.metrics.users + 1;
-
-                let is_worker = self.attack_mode == AttackMode::Worker;
+                let thread_user = self.weighted_users.remove(index);
+                self.launch_user(goose_attack_run_state, thread_user);
+                spawned_user = true;
+            }
+        }
 
-                // If running on Worker, use Worker configuration in GooseUser.
-                if is_worker {
-                    thread_user.config = self.configuration.clone();
-                }
+        // GooseTaskSets with their own hatch rate spawn GooseUsers on their own schedule.
+        for task_sets_index in 0..self.task_sets.len() {
+            let task_set_hatch_rate = match self.task_sets[task_sets_index].hatch_rate.as_ref() {
+                Some(h) => util::get_hatch_rate(Some(h.to_string())),
+                None => continue,
+            };
+            let (spawn_user_timer, spawn_user_in_ms) = *goose_attack_run_state
+                .task_set_spawn_timers
+                .entry(task_sets_index)
+                .or_insert((std::time::Instant::now(), 0));
+            if spawn_user_in_ms == 0 || util::ms_timer_expired(spawn_user_timer, spawn_user_in_ms) {
+                if let Some(index) = self.next_weighted_user(Some(task_sets_index)) {
+                    // Reset the spawn timer for this GooseTaskSet.
+                    goose_attack_run_state.task_set_spawn_timers.insert(
+                        task_sets_index,
+                        (
+                            std::time::Instant::now(),
+                            self.spawn_user_in_ms(task_set_hatch_rate),
+                        ),
+                    );
 
-                // Launch a new user.
-                let user = tokio::spawn(user::user_main(
-                    thread_number,
-                    thread_task_set,
-                    thread_user,
-                    thread_receiver,
-                    is_worker,
-                ));
-
-                goose_attack_run_state.users.push(user);
-                self.metrics.users += 1;
-
-                if let Some(running_metrics) = self.configuration.running_metrics {
-                    if self.attack_mode != AttackMode::Worker
-                        && util::timer_expired(
-                            goose_attack_run_state.running_metrics_timer,
-                            running_metrics,
-                        )
-                    {
-                        goose_attack_run_state.running_metrics_timer = time::Instant::now();
-                        self.metrics.print_running();
-                    }
+                    let thread_user = self.weighted_users.remove(index);
+                    self.launch_user(goose_attack_run_state, thread_user);
+                    spawned_user = true;
                 }
             }
-        } else {
+        }
+
+        if !spawned_user && !self.weighted_users.is_empty() {
             // If displaying running metrics, be sure we wake up often enough to
             // display them at the configured rate.
             let running_metrics = self.configuration.running_metrics.unwrap_or(0);
 
-            // Otherwise, sleep until the next time something needs to happen.
-            let sleep_duration = if running_metrics > 0
-                && running_metrics * 1_000 < goose_attack_run_state.spawn_user_in_ms
-            {
-                let sleep_delay = self.configuration.running_metrics.unwrap() * 1_000;
-                goose_attack_run_state.spawn_user_in_ms -= sleep_delay;
-                Duration::from_millis(sleep_delay as u64)
+            // Otherwise, sleep until the next time a GooseUser needs to be spawned.
+            let spawn_user_in_ms = self.next_spawn_user_in_ms(goose_attack_run_state);
+            let sleep_duration =
+                if running_metrics > 0 && running_metrics * 1_000 < spawn_user_in_ms {
+                    Duration::from_millis(running_metrics as u64 * 1_000)
+                } else {
+                    Duration::from_millis(spawn_user_in_ms as u64)
+                };
+            // Workers wake at least once a second to push a heartbeat to the Manager.
+            let sleep_duration = if self.attack_mode == AttackMode::Worker {
+                sleep_duration.min(Duration::from_secs(1))
             } else {
-                Duration::from_millis(goose_attack_run_state.spawn_user_in_ms as u64)
+                sleep_duration
             };
             debug!("sleeping {:?}...", sleep_duration);
-            goose_attack_run_state.drift_timer =
-                util::sleep_minus_drift(sleep_duration, goose_attack_run_state.drift_timer).await;
+            tokio::time::sleep(sleep_duration).await;
+            goose_attack_run_state.drift_timer = tokio::time::Instant::now();
         }
 
         // If enough users have been spawned, move onto the next attack phase.
@@ -1426,11 +2202,14 @@ impl GooseAttack {
                     get_worker_id(),
                     self.metrics.users
                 );
+                self.reset_metrics(goose_attack_run_state).await?;
+            } else if self.test_plan.is_some() {
+                info!("following test plan...");
             } else {
                 info!("launched {} users...", self.metrics.users);
+                self.reset_metrics(goose_attack_run_state).await?;
             }
 
-            self.reset_metrics(goose_attack_run_state).await?;
             self.set_attack_phase(goose_attack_run_state, AttackPhase::Running);
             // Also record a formattable timestamp, for human readable reports.
             self.metrics.started = Some(Local::now());
@@ -1439,6 +2218,218 @@ impl GooseAttack {
         Ok(())
     }
 
+    // If a GooseUser stopped launching more users, hold the load test at the users already
+    // running.
+    fn hold_running_users(&mut self, goose_attack_run_state: &GooseAttackRunState) {
+        if goose_attack_run_state
+            .hatching_stopped
+            .load(Ordering::SeqCst)
+            && !self.weighted_users.is_empty()
+        {
+            let users = goose_attack_run_state.user_channels.len();
+            info!("stopped launching users, holding at {} users...", users);
+            self.weighted_users.clear();
+            self.configuration.users = Some(users);
+        }
+    }
+
+    // Returns how many GooseUsers to spawn per second from GooseTaskSets without their
+    // own hatch rate.
+    fn hatch_rate(&self) -> f32 {
+        // If `startup_time` has been configured, calculate the hatch_rate.
+        if self.configuration.startup_time != "0" {
+            if let Some(users) = self.configuration.users {
+                // Divide the number of users by the total time to start up to calculate the
+                // hatch rate.
+                users as f32 / util::parse_timespan(&self.configuration.startup_time) as f32
+            } else {
+                // Users have to be configured.
+                unreachable!();
+            }
+        // Otherwise either `hatch_rate` was configured or Goose will default to launching
+        // one GooseUser per second.
+        } else {
+            util::get_hatch_rate(self.configuration.hatch_rate.clone())
+        }
+    }
+
+    // Returns when each weighted GooseUser is planned to launch, following the hatch rate
+    // of its GooseTaskSet or otherwise the global hatch rate, in milliseconds since the
+    // load test started. GooseUsers are launched from the end of `weighted_users`.
+    fn planned_launches(&self) -> Vec<(usize, usize)> {
+        // A test plan launches users on its own schedule.
+        if self.test_plan.is_some() {
+            return Vec::new();
+        }
+
+        let mut launched: HashMap<Option<usize>, usize> = HashMap::new();
+        self.weighted_users
+            .iter()
+            .rev()
+            .map(|user| {
+                let task_set_hatch_rate = self.task_sets[user.task_sets_index]
+                    .hatch_rate
+                    .as_ref()
+                    .map(|h| util::get_hatch_rate(Some(h.to_string())));
+                let (key, hatch_rate) = match task_set_hatch_rate {
+                    Some(hatch_rate) => (Some(user.task_sets_index), hatch_rate),
+                    None => (None, self.hatch_rate()),
+                };
+                let counter = launched.entry(key).or_insert(0);
+                let launch_ms = *counter * self.spawn_user_in_ms(hatch_rate);
+                *counter += 1;
+                (launch_ms, user.task_sets_index)
+            })
+            .collect()
+    }
+
+    // Returns the index of the next weighted GooseUser to spawn, either from the specified
+    // GooseTaskSet, or otherwise from any GooseTaskSet without its own hatch rate.
+    fn next_weighted_user(&self, task_sets_index: Option<usize>) -> Option<usize> {
+        self.weighted_users
+            .iter()
+            .rposition(|user| match task_sets_index {
+                Some(index) => user.task_sets_index == index,
+                None => self.task_sets[user.task_sets_index].hatch_rate.is_none(),
+            })
+    }
+
+    // Convert a hatch rate into how many milliseconds to wait between spawning GooseUsers.
+    fn spawn_user_in_ms(&self, hatch_rate: f32) -> usize {
+        // Start with 1,000.0 milliseconds and divide by the hatch_rate.
+        let spawn_user_in_ms = (1_000.0 / hatch_rate) as usize;
+
+        // If running on a Worker, multiple by the number of workers as each is spawning
+        // GooseUsers at this rate.
+        if self.attack_mode == AttackMode::Worker {
+            spawn_user_in_ms * self.configuration.expect_workers.unwrap() as usize
+        } else {
+            spawn_user_in_ms
+        }
+    }
+
+    // Returns how many milliseconds until the next GooseUser should be spawned, considering
+    // only GooseTaskSets that still have GooseUsers to spawn.
+    fn next_spawn_user_in_ms(&self, goose_attack_run_state: &GooseAttackRunState) -> usize {
+        let remaining = |timer: std::time::Instant, in_ms: usize| {
+            in_ms.saturating_sub(timer.elapsed().as_millis() as usize)
+        };
+
+        let mut next_spawn_user_in_ms = usize::MAX;
+        if self.next_weighted_user(None).is_some() {
+            next_spawn_user_in_ms = remaining(
+                goose_attack_run_state.spawn_user_timer,
+                goose_attack_run_state.spawn_user_in_ms,
+            );
+        }
+        for (task_sets_index, (timer, in_ms)) in &goose_attack_run_state.task_set_spawn_timers {
+            if self.next_weighted_user(Some(*task_sets_index)).is_some() {
+                next_spawn_user_in_ms = next_spawn_user_in_ms.min(remaining(*timer, *in_ms));
+            }
+        }
+        next_spawn_user_in_ms
+    }
+
+    // Launch a GooseUser thread.
+    fn launch_user(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+        mut thread_user: GooseUser,
+    ) {
+        goose_attack_run_state.spawn_user_counter += 1;
+
+        // Remember which task group this user is using.
+        thread_user.weighted_users_index = self.metrics.users;
+
+        // Seed the user's random number generator now that its index is known.
+        thread_user.rng =
+            random::user_rng(self.configuration.seed, thread_user.weighted_users_index);
+
+        // Create a per-thread channel allowing parent thread to control child threads.
+        let (parent_sender, thread_receiver): (
+            flume::Sender<GooseUserCommand>,
+            flume::Receiver<GooseUserCommand>,
+        ) = flume::unbounded();
+        goose_attack_run_state.user_channels.push(parent_sender);
+        goose_attack_run_state
+            .user_task_sets
+            .push(thread_user.task_sets_index);
+
+        // Clone the logger_tx if enabled, otherwise is None.
+        thread_user.logger = goose_attack_run_state.all_threads_logger_tx.clone();
+
+        // Copy the GooseUser-throttle receiver channel, used by all threads.
+        thread_user.throttle = if self.configuration.throttle_requests > 0 {
+            Some(goose_attack_run_state.throttle_threads_tx.clone().unwrap())
+        } else {
+            None
+        };
+        thread_user.rate_limit = goose_attack_run_state.throttle_rate_limit_tx.clone();
+
+        // Allow the user to abort the load test.
+        thread_user.abort = Some(GooseAbort {
+            canceled: goose_attack_run_state.canceled.clone(),
+            reason: goose_attack_run_state.abort_reason.clone(),
+        });
+        // Allow the user to stop launching more users.
+        thread_user.hatching_stopped = Some(goose_attack_run_state.hatching_stopped.clone());
+
+        // Copy the GooseUser-to-parent sender channel, used by all threads.
+        thread_user.channel_to_parent = Some(goose_attack_run_state.all_threads_metrics_tx.clone());
+
+        // Copy the channel to the background validators, used by all threads.
+        thread_user.validation = goose_attack_run_state.validation_tx.clone();
+
+        // Share the registered JSON Schemas with all threads.
+        #[cfg(feature = "json-schema")]
+        if !self.json_schemas.is_empty() {
+            thread_user.json_schemas = Some(self.json_schemas.clone());
+        }
+
+        // Copy the appropriate task_set into the thread.
+        let thread_task_set = self.task_sets[thread_user.task_sets_index].clone();
+
+        // We number threads from 1 as they're human-visible (in the logs),
+        // whereas metrics.users starts at 0.
+        let thread_number = self.metrics.users + 1;
+
+        let is_worker = self.attack_mode == AttackMode::Worker;
+
+        // If running on Worker, use Worker configuration in GooseUser.
+        if is_worker {
+            thread_user.config = self.configuration.clone();
+        }
+
+        // Reuse the clients and session data of a user from a previous load test, if any.
+        if let Some(reused_user) = self.reused_users.pop_front() {
+            thread_user.reuse(reused_user);
+        }
+
+        // Launch a new user.
+        let user = tokio::spawn(user::user_main(
+            thread_number,
+            thread_task_set,
+            thread_user,
+            thread_receiver,
+            is_worker,
+        ));
+
+        goose_attack_run_state.users.push(user);
+        self.metrics.users += 1;
+
+        if let Some(running_metrics) = self.configuration.running_metrics {
+            if self.attack_mode != AttackMode::Worker
+                && util::timer_expired(
+                    goose_attack_run_state.running_metrics_timer,
+                    running_metrics,
+                )
+            {
+                goose_attack_run_state.running_metrics_timer = time::Instant::now();
+                self.metrics.print_running();
+            }
+        }
+    }
+
     // Let the [`GooseAttack`](./struct.GooseAttack.html) run until the timer expires
     // (or the test is canceled), and then trigger a shut down.
     async fn monitor_attack(
@@ -1450,18 +2441,76 @@ impl GooseAttack {
             self.set_attack_phase(goose_attack_run_state, AttackPhase::Stopping);
             self.metrics.stopping = Some(Local::now());
         } else {
-            // Subtract the time spent doing other things, running the main parent loop twice
-            // per second.
-            goose_attack_run_state.drift_timer = util::sleep_minus_drift(
-                time::Duration::from_millis(500),
-                goose_attack_run_state.drift_timer,
-            )
-            .await;
+            self.hold_running_users(goose_attack_run_state);
+            self.record_phases();
+            let mut sleep_duration = time::Duration::from_millis(500);
+            if let Some(users) = self
+                .test_plan
+                .as_ref()
+                .filter(|_| {
+                    !goose_attack_run_state
+                        .hatching_stopped
+                        .load(Ordering::SeqCst)
+                })
+                .map(|test_plan| test_plan.users_at(self.started.unwrap().elapsed().as_millis()))
+            {
+                // Change how many users are running to follow the test plan, launching
+                // new users right away as the test plan sets the pace.
+                if users != goose_attack_run_state.user_channels.len()
+                    && self.change_running_users(goose_attack_run_state, users)
+                {
+                    while let Some(thread_user) = self.weighted_users.pop() {
+                        self.launch_user(goose_attack_run_state, thread_user);
+                    }
+                }
+                sleep_duration = time::Duration::from_millis(100);
+            // Launch any users added by a Controller while running, at the hatch rate.
+            } else if !self.weighted_users.is_empty() {
+                if goose_attack_run_state.spawn_user_in_ms == 0
+                    || util::ms_timer_expired(
+                        goose_attack_run_state.spawn_user_timer,
+                        goose_attack_run_state.spawn_user_in_ms,
+                    )
+                {
+                    goose_attack_run_state.spawn_user_timer = std::time::Instant::now();
+                    goose_attack_run_state.spawn_user_in_ms =
+                        self.spawn_user_in_ms(self.hatch_rate());
+                    if let Some(thread_user) = self.weighted_users.pop() {
+                        self.launch_user(goose_attack_run_state, thread_user);
+                    }
+                }
+                let spawn_user_in_ms = goose_attack_run_state.spawn_user_in_ms.saturating_sub(
+                    goose_attack_run_state
+                        .spawn_user_timer
+                        .elapsed()
+                        .as_millis() as usize,
+                );
+                sleep_duration =
+                    sleep_duration.min(time::Duration::from_millis(spawn_user_in_ms as u64));
+            }
+
+            // Subtract the time spent doing other things, running the main parent loop at
+            // least twice per second.
+            goose_attack_run_state.drift_timer =
+                util::sleep_minus_drift(sleep_duration, goose_attack_run_state.drift_timer).await;
         }
 
         Ok(())
     }
 
+    // Whether the parent loop has something more urgent to do than receiving metrics: a
+    // Controller request, ctrl-c, or the end of the configured run time.
+    pub(crate) fn control_pending(&self, goose_attack_run_state: &GooseAttackRunState) -> bool {
+        goose_attack_run_state.canceled.load(Ordering::SeqCst)
+            || goose_attack_run_state.controller_request_pending()
+            || match self.started {
+                Some(started) if self.attack_phase == AttackPhase::Running => {
+                    util::timer_expired(started, self.run_time)
+                }
+                _ => false,
+            }
+    }
+
     async fn stop_running_users(
         &mut self,
         goose_attack_run_state: &mut GooseAttackRunState,
@@ -1483,7 +2532,17 @@ impl GooseAttack {
         } else {
             info!("stopping after {} seconds...", self.metrics.duration);
         }
-        for (index, send_to_user) in goose_attack_run_state.user_channels.iter().enumerate() {
+        // If a stop rate is configured, stop users gradually, the most recently launched
+        // first, unless the load test was canceled.
+        let stop_user_in_ms =
+            self.configuration.stop_rate.as_ref().map(|stop_rate| {
+                self.spawn_user_in_ms(util::get_hatch_rate(Some(stop_rate.clone())))
+            });
+        if let Some(stop_rate) = self.configuration.stop_rate.as_ref() {
+            info!("stopping {} users per second...", stop_rate);
+        }
+        let user_channels = goose_attack_run_state.user_channels.clone();
+        for (index, send_to_user) in user_channels.iter().enumerate().rev() {
             match send_to_user.send(GooseUserCommand::Exit) {
                 Ok(_) => {
                     debug!("telling user {} to exit", index);
@@ -1492,6 +2551,18 @@ impl GooseAttack {
                     info!("failed to tell user {} to exit: {}", index, e);
                 }
             }
+            // Let the user finish its current task and any on_stop tasks before stopping
+            // the next user, receiving metrics in the meantime.
+            if let Some(stop_user_in_ms) = stop_user_in_ms {
+                if index > 0 && !goose_attack_run_state.canceled.load(Ordering::SeqCst) {
+                    self.sync_metrics(goose_attack_run_state, false).await?;
+                    goose_attack_run_state.drift_timer = util::sleep_minus_drift(
+                        Duration::from_millis(stop_user_in_ms as u64),
+                        goose_attack_run_state.drift_timer,
+                    )
+                    .await;
+                }
+            }
         }
         if self.attack_mode == AttackMode::Worker {
             info!("[{}] waiting for users to exit", get_worker_id());
@@ -1504,12 +2575,29 @@ impl GooseAttack {
             let _ = throttle_tx.send(false);
         }
 
+        // If the throttle is adaptive, record the rate it allowed.
+        if let Some(throttle_handle) = goose_attack_run_state.throttle_handle.take() {
+            if let Ok(throttle_rate) = throttle_handle.await {
+                self.metrics.throttle_rate = Some(throttle_rate);
+            }
+        }
+
         // Take the users vector out of the GooseAttackRunState object so it can be
         // consumed by futures::future::join_all().
         let users = std::mem::take(&mut goose_attack_run_state.users);
-        futures::future::join_all(users).await;
+        self.finished_users = futures::future::join_all(users)
+            .await
+            .into_iter()
+            .filter_map(|user| user.ok())
+            .collect();
         debug!("all users exited");
 
+        // Validate any remaining response bodies, before collecting the final metrics.
+        if let Some(validation_tx) = goose_attack_run_state.validation_tx.take() {
+            let validation_handles = std::mem::take(&mut goose_attack_run_state.validation_handles);
+            validate::stop_validators(validation_tx, validation_handles).await;
+        }
+
         // If the logger thread is enabled, tell it to flush and exit.
         if goose_attack_run_state.logger_handle.is_some() {
             if let Err(e) = goose_attack_run_state
@@ -1523,7 +2611,9 @@ impl GooseAttack {
             // Take logger out of the GooseAttackRunState object so it can be
             // consumed by tokio::join!().
             let logger = std::mem::take(&mut goose_attack_run_state.logger_handle);
-            let _ = tokio::join!(logger.unwrap());
+            if let (Ok(Ok(log_failures)),) = tokio::join!(logger.unwrap()) {
+                self.metrics.log_failures = log_failures;
+            }
         }
 
         // If we're printing metrics, collect the final metrics received from users.
@@ -1533,6 +2623,19 @@ impl GooseAttack {
             let _received_message = self.receive_metrics(goose_attack_run_state, true).await?;
         }
 
+        // If streaming requests to InfluxDB, tell the thread to write any remaining requests
+        // and exit.
+        if let Some(influxdb_tx) = goose_attack_run_state.influxdb_tx.take() {
+            let _ = influxdb_tx.send(None);
+        }
+        if let Some(influxdb_handle) = goose_attack_run_state.influxdb_handle.take() {
+            if let Ok(failed_requests) = influxdb_handle.await {
+                if failed_requests > 0 {
+                    warn!("failed to write {} requests to InfluxDB", failed_requests);
+                }
+            }
+        }
+
         #[cfg(feature = "gaggle")]
         {
             // As worker, push metrics up to manager.
@@ -1543,6 +2646,7 @@ impl GooseAttack {
                         GaggleMetrics::Requests(self.metrics.requests.clone()),
                         GaggleMetrics::Errors(self.metrics.errors.clone()),
                         GaggleMetrics::Tasks(self.metrics.tasks.clone()),
+                        GaggleMetrics::Stopped(self.metrics.aborted.clone()),
                     ],
                     true,
                 );
@@ -1570,11 +2674,10 @@ impl GooseAttack {
         &mut self,
         goose_attack_run_state: &mut GooseAttackRunState,
     ) -> Result<(), GooseError> {
-        // Run any configured test_start() functions.
-        self.run_test_start().await.unwrap();
-
         // Prepare to collect metrics, if enabled.
         self.metrics = GooseMetrics::default();
+        self.metrics.output = self.configuration.output.unwrap_or_default();
+        self.metrics.number_format = GooseNumberFormat::new(&self.configuration);
         if !self.configuration.no_metrics {
             self.metrics.initialize_task_metrics(
                 &self.task_sets,
@@ -1584,33 +2687,94 @@ impl GooseAttack {
             self.metrics.display_metrics = true;
             // Only display status codes if enabled.
             self.metrics.display_status_codes = self.configuration.status_codes;
+            // Optionally compare metrics with an earlier load test.
+            self.metrics.baseline = self.load_baseline().await?;
+        }
+
+        // Optionally measure the network round-trip time to each host, before any load.
+        if self.configuration.rtt_samples > 0 {
+            let mut hosts: Vec<String> = self.metrics.hosts.iter().cloned().collect();
+            hosts.sort();
+            self.metrics.network_baselines =
+                baseline::measure_hosts(&hosts, self.configuration.rtt_samples).await;
         }
 
+        // Run any configured test_start() functions, recording how long task set setup
+        // tasks take.
+        self.run_test_start().await.unwrap();
+        #[cfg(feature = "rustls-tls")]
+        tls::reset_handshakes();
+
         // Reset the run state.
         let std_now = std::time::Instant::now();
         goose_attack_run_state.spawn_user_timer = std_now;
         goose_attack_run_state.spawn_user_in_ms = 0;
         goose_attack_run_state.spawn_user_counter = 0;
+        goose_attack_run_state.task_set_spawn_timers = HashMap::new();
         goose_attack_run_state.drift_timer = tokio::time::Instant::now();
         goose_attack_run_state.metrics_header_displayed = false;
         goose_attack_run_state.idle_status_displayed = false;
         goose_attack_run_state.users = Vec::new();
         goose_attack_run_state.user_channels = Vec::new();
+        goose_attack_run_state.user_task_sets = Vec::new();
+        goose_attack_run_state.planned_launches = self.planned_launches();
         goose_attack_run_state.running_metrics_timer = std_now;
         goose_attack_run_state.display_running_metrics = false;
+        goose_attack_run_state.report_timer = std_now;
+        goose_attack_run_state.checkpoint_timer = std_now;
+        goose_attack_run_state.heartbeat_timer = std_now;
         goose_attack_run_state.shutdown_after_stop = !self.configuration.no_autostart;
         goose_attack_run_state.all_users_spawned = false;
+        goose_attack_run_state
+            .hatching_stopped
+            .store(false, Ordering::SeqCst);
 
         // If enabled, spawn a logger thread.
-        let (logger_handle, all_threads_logger_tx) =
-            self.configuration.setup_loggers(&self.defaults).await?;
+        let (logger_handle, all_threads_logger_tx) = self
+            .configuration
+            .setup_loggers(
+                &self.defaults,
+                &self.log_sinks,
+                &goose_attack_run_state.canceled,
+            )
+            .await?;
         goose_attack_run_state.logger_handle = logger_handle;
         goose_attack_run_state.all_threads_logger_tx = all_threads_logger_tx;
 
         // If enabled, spawn a throttle thread.
-        let (throttle_threads_tx, parent_to_throttle_tx) = self.setup_throttle().await;
+        let (throttle_threads_tx, parent_to_throttle_tx, throttle_rate_limit_tx, throttle_handle) =
+            self.setup_throttle().await;
         goose_attack_run_state.throttle_threads_tx = throttle_threads_tx;
         goose_attack_run_state.parent_to_throttle_tx = parent_to_throttle_tx;
+        goose_attack_run_state.throttle_rate_limit_tx = throttle_rate_limit_tx;
+        goose_attack_run_state.throttle_handle = throttle_handle;
+
+        // If enabled, spawn a thread streaming requests to InfluxDB.
+        if !self.configuration.influxdb_url.is_empty() {
+            let (influxdb_tx, influxdb_handle) = influxdb::spawn_influxdb(&self.configuration);
+            goose_attack_run_state.influxdb_tx = Some(influxdb_tx);
+            goose_attack_run_state.influxdb_handle = Some(influxdb_handle);
+        }
+
+        // If enabled, spawn a task for each registered metrics exporter. Workers don't
+        // export metrics, as the Manager aggregates them.
+        if !self.configuration.no_metrics && self.attack_mode != AttackMode::Worker {
+            let metrics_exporters = std::mem::take(&mut self.metrics_exporters);
+            goose_attack_run_state.metrics_exporters = export::spawn_exporters(metrics_exporters);
+            goose_attack_run_state.metrics_export_timer = std::time::Instant::now();
+        }
+
+        // Spawn the background validators, unless this is a Manager without users.
+        if self.attack_mode != AttackMode::Manager {
+            let validation_workers = match self.configuration.validation_workers {
+                0 => num_cpus::get(),
+                validation_workers => validation_workers,
+            };
+            let (validation_tx, validation_handles) =
+                validate::spawn_validators(validation_workers);
+            goose_attack_run_state.validation_tx = Some(validation_tx);
+            goose_attack_run_state.validation_handles = validation_handles;
+        }
 
         // If enabled, create an report file and confirm access.
         goose_attack_run_state.report_file = match self.prepare_report_file().await {
@@ -1636,10 +2800,7 @@ impl GooseAttack {
 
         // The GooseAttackRunState is used while spawning and running the
         // GooseUser threads that generate the load test.
-        let mut goose_attack_run_state = self
-            .initialize_attack(socket)
-            .await
-            .expect("failed to initialize GooseAttackRunState");
+        let mut goose_attack_run_state = self.initialize_attack(socket).await?;
 
         // The Goose parent process GooseAttack loop runs until Goose shuts down. Goose enters
         // the loop in AttackPhase::Idle, and exits in AttackPhase::Shutdown.
@@ -1696,6 +2857,11 @@ impl GooseAttack {
                     self.sync_metrics(&mut goose_attack_run_state, true).await?;
                     // The load test is fully stopped at this point.
                     self.metrics.stopped = Some(Local::now());
+                    // Export the final metrics, if enabled.
+                    let metrics_exporters =
+                        std::mem::take(&mut goose_attack_run_state.metrics_exporters);
+                    self.metrics_exporters =
+                        export::stop_exporters(metrics_exporters, &self.metrics).await;
                     // Write an html report, if enabled.
                     self.write_html_report(&mut goose_attack_run_state).await?;
                     // Shutdown Goose or go into an idle waiting state.
@@ -1703,9 +2869,7 @@ impl GooseAttack {
                         self.set_attack_phase(&mut goose_attack_run_state, AttackPhase::Shutdown);
                     } else {
                         // Print metrics, if enabled.
-                        if !self.configuration.no_metrics {
-                            println!("{}", self.metrics);
-                        }
+                        self.metrics.print();
                         self.set_attack_phase(&mut goose_attack_run_state, AttackPhase::Idle);
                     }
                 }
@@ -1716,9 +2880,17 @@ impl GooseAttack {
             self.sync_metrics(&mut goose_attack_run_state, false)
                 .await?;
 
-            // Check if a Controller has made a request.
-            self.handle_controller_requests(&mut goose_attack_run_state)
-                .await?;
+            // Check if a Controller has made a request, handling queued requests before
+            // receiving more metrics. At most 16 are handled per pass, so a flood of
+            // Controller requests can't starve metrics or the end of the load test.
+            let controller_requests_per_pass = 16;
+            for _ in 0..controller_requests_per_pass {
+                self.handle_controller_requests(&mut goose_attack_run_state)
+                    .await?;
+                if !goose_attack_run_state.controller_request_pending() {
+                    break;
+                }
+            }
 
             // Gracefully exit loop if ctrl-c is caught.
             if self.attack_phase != AttackPhase::Shutdown
@@ -1727,6 +2899,11 @@ impl GooseAttack {
                 // Shutdown after stopping as the load test was canceled.
                 goose_attack_run_state.shutdown_after_stop = true;
 
+                // Record why the load test was aborted, if a GooseUser aborted it.
+                if let Some(reason) = goose_attack_run_state.abort_reason.lock().unwrap().clone() {
+                    self.metrics.aborted = Some(reason);
+                }
+
                 // No metrics to display when sitting idle, so disable.
                 if self.attack_phase == AttackPhase::Idle {
                     self.metrics.display_metrics = false;
@@ -1738,6 +2915,11 @@ impl GooseAttack {
             }
         }
 
+        // Stop serving live metrics to Prometheus.
+        if let Some(prometheus_handle) = goose_attack_run_state.prometheus_handle.take() {
+            prometheus_handle.abort();
+        }
+
         Ok(self)
     }
 }
@@ -1749,15 +2931,89 @@ impl GooseAttack {
 /// starts. Normal `tasks` are then run for the duration of the
 /// [`GooseAttack`](./struct.GooseAttack.html). The `on_stop_tasks` finally are only run once when
 /// the [`GooseAttack`](./struct.GooseAttack.html) stops.
+///
+/// The tasks of each [`GooseTaskGroup`](./goose/struct.GooseTaskGroup.html) are scheduled
+/// as blocks, each a pass through the group, following the normal `tasks` as often as the
+/// weight of the group. A fourth vector flags the last task of each pass with the index of
+/// its group.
 fn allocate_tasks(
     task_set: &GooseTaskSet,
     scheduler: &GooseScheduler,
-) -> (WeightedGooseTasks, WeightedGooseTasks, WeightedGooseTasks) {
+    rng: &mut StdRng,
+) -> (
+    WeightedGooseTasks,
+    WeightedGooseTasks,
+    WeightedGooseTasks,
+    Vec<Option<usize>>,
+) {
     debug!(
         "allocating GooseTasks on GooseUsers with {:?} scheduler",
         scheduler
     );
 
+    // Tasks that are not part of a group, and all on_start and on_stop tasks.
+    let ungrouped_tasks: Vec<GooseTask> = task_set
+        .tasks
+        .iter()
+        .filter(|task| task.group.is_none() || task.on_start || task.on_stop)
+        .cloned()
+        .collect();
+    let (on_start_tasks, mut tasks, on_stop_tasks) =
+        allocate_task_list(task_set, &ungrouped_tasks, scheduler, rng);
+    let mut group_ends = vec![None; tasks.len()];
+
+    if !task_set.groups.is_empty() {
+        // Each pass through a group runs its tasks ordered by their own weights and sequences.
+        let mut passes = Vec::with_capacity(task_set.groups.len());
+        for group_index in 0..task_set.groups.len() {
+            let group_tasks: Vec<GooseTask> = task_set
+                .tasks
+                .iter()
+                .filter(|task| task.group == Some(group_index) && !task.on_start && !task.on_stop)
+                .cloned()
+                .collect();
+            let (_, pass, _) = allocate_task_list(task_set, &group_tasks, scheduler, rng);
+            passes.push(pass);
+        }
+
+        // Schedule the passes as often as the weight of each group, divided by the greatest
+        // common divisor so the vector is as short as possible.
+        let u = task_set.groups.iter().fold(0, |u, group| match u {
+            0 => group.weight,
+            _ => util::gcd(u, group.weight),
+        });
+        let weighted_groups: Vec<Vec<usize>> = task_set
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(group_index, group)| vec![group_index; group.weight / u])
+            .collect();
+        let total_groups = weighted_groups.iter().map(Vec::len).sum();
+        for group_index in
+            schedule_unsequenced_tasks(&weighted_groups, total_groups, scheduler, rng)
+        {
+            let pass = &passes[group_index];
+            if pass.is_empty() {
+                continue;
+            }
+            tasks.extend(pass.iter().cloned());
+            group_ends.extend(vec![None; pass.len() - 1]);
+            group_ends.push(Some(group_index));
+        }
+    }
+
+    (on_start_tasks, tasks, on_stop_tasks, group_ends)
+}
+
+/// Allocate the given tasks of the [`GooseTaskSet`](./goose/struct.GooseTaskSet.html) with the
+/// configured GooseScheduler, returning ordered `on_start_tasks`, `tasks`, and
+/// `on_stop_tasks`.
+fn allocate_task_list(
+    task_set: &GooseTaskSet,
+    task_list: &[GooseTask],
+    scheduler: &GooseScheduler,
+    rng: &mut StdRng,
+) -> (WeightedGooseTasks, WeightedGooseTasks, WeightedGooseTasks) {
     // A BTreeMap of Vectors allows us to group and sort tasks per sequence value.
     let mut sequenced_tasks: SequencedGooseTasks = BTreeMap::new();
     let mut sequenced_on_start_tasks: SequencedGooseTasks = BTreeMap::new();
@@ -1769,7 +3025,7 @@ fn allocate_tasks(
     let mut v: usize;
 
     // Find the greatest common divisor of all tasks in the task_set.
-    for task in &task_set.tasks {
+    for task in task_list {
         if task.sequence > 0 {
             if task.on_start {
                 if let Some(sequence) = sequenced_on_start_tasks.get_mut(&task.sequence) {
@@ -1838,26 +3094,30 @@ fn allocate_tasks(
 
     // Schedule sequenced tasks.
     let scheduled_sequenced_on_start_tasks =
-        schedule_sequenced_tasks(&weighted_sequenced_on_start_tasks, scheduler);
-    let scheduled_sequenced_tasks = schedule_sequenced_tasks(&weighted_sequenced_tasks, scheduler);
+        schedule_sequenced_tasks(&weighted_sequenced_on_start_tasks, scheduler, rng);
+    let scheduled_sequenced_tasks =
+        schedule_sequenced_tasks(&weighted_sequenced_tasks, scheduler, rng);
     let scheduled_sequenced_on_stop_tasks =
-        schedule_sequenced_tasks(&weighted_sequenced_on_stop_tasks, scheduler);
+        schedule_sequenced_tasks(&weighted_sequenced_on_stop_tasks, scheduler, rng);
 
     // Schedule unsequenced tasks.
     let scheduled_unsequenced_on_start_tasks = schedule_unsequenced_tasks(
         &weighted_unsequenced_on_start_tasks,
         total_unsequenced_on_start_tasks,
         scheduler,
+        rng,
     );
     let scheduled_unsequenced_tasks = schedule_unsequenced_tasks(
         &weighted_unsequenced_tasks,
         total_unsequenced_tasks,
         scheduler,
+        rng,
     );
     let scheduled_unsequenced_on_stop_tasks = schedule_unsequenced_tasks(
         &weighted_unsequenced_on_stop_tasks,
         total_unsequenced_on_stop_tasks,
         scheduler,
+        rng,
     );
 
     // Finally build a Vector of tuples: (task id, task name)
@@ -1934,11 +3194,12 @@ fn weight_sequenced_tasks(
 fn schedule_sequenced_tasks(
     available_sequenced_tasks: &BTreeMap<usize, Vec<Vec<usize>>>,
     scheduler: &GooseScheduler,
+    rng: &mut StdRng,
 ) -> Vec<usize> {
     let mut weighted_tasks: Vec<usize> = Vec::new();
 
     for (_sequence, tasks) in available_sequenced_tasks.iter() {
-        let scheduled_tasks = schedule_unsequenced_tasks(tasks, tasks[0].len(), scheduler);
+        let scheduled_tasks = schedule_unsequenced_tasks(tasks, tasks[0].len(), scheduler, rng);
         weighted_tasks.extend(scheduled_tasks);
     }
 
@@ -1950,6 +3211,7 @@ fn schedule_unsequenced_tasks(
     available_unsequenced_tasks: &[Vec<usize>],
     total_tasks: usize,
     scheduler: &GooseScheduler,
+    rng: &mut StdRng,
 ) -> Vec<usize> {
     // Now build the weighted list with the appropriate scheduler.
     let mut weighted_tasks = Vec::new();
@@ -1986,7 +3248,7 @@ fn schedule_unsequenced_tasks(
 
                 let mut tasks_clone = tasks.clone();
                 if scheduler == &GooseScheduler::Random {
-                    tasks_clone.shuffle(&mut thread_rng());
+                    tasks_clone.shuffle(rng);
                 }
                 weighted_tasks.append(&mut tasks_clone);
             }
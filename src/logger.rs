@@ -8,10 +8,14 @@
 //! (the standard debug output of a Rust structure), using the following run time optios:
 //!  - `--debug-format`, `--request-format`, `--task-format`
 //!
-//! All of these loggers use a single shared logger thread, with
+//! The request log can also be written in the Apache `combined` log format.
+//!
+//! All of these loggers share a single logger thread, with
 //! [`GooseUser`](../goose/struct.GooseUser.html)s sending log messages through the same shared
-//! channel. The logger determines which log file to write the message to based on the message
-//! data type. The logger thread uses Tokio's asynchronous
+//! channel. The logger routes each message to a separate writer task for each enabled log
+//! based on the message data type, so a slow log (such as a remote syslog server) doesn't
+//! hold up the others, and formatting many messages is spread across threads. Each writer
+//! uses Tokio's asynchronous
 //! [`BufWriter`](https://docs.rs/tokio/*/tokio/io/struct.BufWriter.html). The logger thread only
 //! starts if at least one logger is enabled.
 //!
@@ -30,6 +34,14 @@
 //! Each [`GooseRequestMetric`] object generated by all [`GooseUser`](../goose/struct.GooseUser.html)
 //! threads during a load test is written to this log file.
 //!
+//! With `--request-format combined` each request is instead written as a line in the
+//! Apache combined log format, so log analyzers such as GoAccess or AWStats can read the
+//! log directly. The remote host is always `127.0.0.1`, the remote user is the number of
+//! the [`GooseUser`](../goose/struct.GooseUser.html) that made the request, and the
+//! response size is only known with `--body-sizes`. Updates to earlier requests and
+//! requests back-filled by Coordinated Omission Mitigation aren't written, and the run ID
+//! isn't included.
+//!
 //! ## Task File logger
 //! The Goose tasks logger is enabled with the `--task-log` command-line option, or the
 //! [`GooseDefault::TaskLog`](../config/enum.GooseDefault.html#variant.TaskLog) default
@@ -103,14 +115,14 @@
 //! [`log_debug`](../goose/struct.GooseUser.html#method.log_debug)
 //! results in a debug log message similar to:
 //! ```json
-//! {"body":null,"header":null,"request":null,"tag":"POSTing [(\"field_1\", \"foo\"), (\"field_2\", \"bar\"), (\"op\", \"Save\")] on /path/to/form"}
+//! {"schema_version":2,"timestamp":1626686518100,"elapsed":0,"user":0,"task_name":null,"request_name":null,"tag":"POSTing [(\"field_1\", \"foo\"), (\"field_2\", \"bar\"), (\"op\", \"Save\")] on /path/to/form","request":null,"headers":null,"body":null}
 //! ```
 //!
 //! The second call to
 //! [`log_debug`](../goose/struct.GooseUser.html#method.log_debug)
 //! results in a debug log message similar to:
 //! ```json
-//! {"body":null,"header":null,"request":{"elapsed":1,"final_url":"http://local.dev/path/to/form","method":"POST","name":"(Anon) post to form","redirected":false,"response_time":22,"status_code":404,"success":false,"update":false,"url":"http://local.dev/path/to/form","user":0},"tag":"POSTing [(\"field_1\", \"foo\"), (\"field_2\", \"bar\"), (\"op\", \"Save\")] on /path/to/form"}
+//! {"schema_version":2,"timestamp":1626686518123,"elapsed":23,"user":0,"task_name":null,"request_name":"(Anon) post to form","tag":"POSTing [(\"field_1\", \"foo\"), (\"field_2\", \"bar\"), (\"op\", \"Save\")] on /path/to/form","request":{"elapsed":1,"final_url":"http://local.dev/path/to/form","method":"POST","name":"(Anon) post to form","redirected":false,"response_time":22,"status_code":404,"success":false,"update":false,"url":"http://local.dev/path/to/form","user":0},"headers":null,"body":null}
 //! ```
 //!
 //! For a more complex debug logging example, refer to the
@@ -130,28 +142,81 @@
 //! [`GooseDefault::NoDebugBody`](../config/enum.GooseDefault.html#variant.NoDebugBody) default
 //! configuration option. The debug logger will still record any custom messages, details
 //! about the request (when available), and all server response headers (when available).
+//!
+//! ## Redaction
+//! Before any message is written, the values of the `Authorization`, `Cookie` and
+//! `Set-Cookie` headers are replaced with `[REDACTED]`. Other headers can be masked instead
+//! with `--redact-headers NAMES`, or none with `--no-redact-headers`.
+//!
+//! ## Rotation
+//! Logs written to files can be rotated by size with `--log-rotate-size SIZE`, or by time
+//! with `--log-rotate-interval TIME`, keeping `--log-rotate-keep VALUE` optionally
+//! gzip-compressed rotated files. Each writer rotates its own log as it writes messages.
+//!
+//! ## Syslog
+//! Instead of writing to a file, any of the logs can be sent to syslog by setting its name to
+//! `syslog:local`, `syslog:udp://HOST[:PORT]` or `syslog:tcp://HOST[:PORT]`. For example,
+//! `--request-log syslog:udp://logs.example.com:514` sends each [`GooseRequestMetric`] to a
+//! remote syslog server over UDP.
+//!
+//! ## Stdout
+//! Any of the logs can be written to stdout by setting its name to `-`, for example
+//! `--request-log -` to pipe requests to another program.
+//!
+//! ## Custom Sinks
+//! Applications embedding Goose can capture the messages of any log in-process, rather
+//! than parsing a log file, by implementing the [`GooseLogSink`] trait and setting it with
+//! [`GooseAttack::set_log_sink`](../struct.GooseAttack.html#method.set_log_sink). The sink
+//! receives each [`GooseLog`] message, together with the message formatted in the log's
+//! configured format, and replaces any file, stdout or syslog the log was configured to
+//! be written to.
 
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, FixedOffset, Local};
 use regex::RegexSet;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::{self, Write};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::fs::File;
 use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::Mutex;
 
 use crate::config::{GooseConfigure, GooseValue};
 use crate::goose::GooseDebug;
 use crate::metrics::{GooseErrorMetric, GooseRequestMetric, GooseTaskMetric};
+use crate::rotate::GooseLogRotation;
+use crate::syslog::{self, GooseSyslog};
+use crate::util;
 use crate::{GooseConfiguration, GooseDefaults, GooseError};
 
-/// Optional unbounded receiver for logger thread, if debug logger is enabled.
+/// The capacity of the buffer each log writer formats messages into.
+const LOG_BUFFER_CAPACITY: usize = 64 * 1024;
+
+/// How many messages can be queued for each log writer. Messages logged while a writer
+/// is this far behind are handled with the `--log-failure` policy, rather than queued in
+/// memory without limit.
+const LOG_WRITER_CAPACITY: usize = 10_000;
+
+/// The headers masked in logs by default, as they usually hold credentials.
+const DEFAULT_REDACT_HEADERS: &str = "authorization,cookie,set-cookie";
+
+/// The value logged in place of the value of a masked header.
+const REDACTED_HEADER: &str = "[REDACTED]";
+
+/// Optional unbounded receiver for logger thread, if debug logger is enabled. The logger
+/// thread returns how many times writing to each log failed, keyed by the log's path.
 pub(crate) type GooseLoggerJoinHandle =
-    Option<tokio::task::JoinHandle<std::result::Result<(), GooseError>>>;
+    Option<tokio::task::JoinHandle<std::result::Result<BTreeMap<String, usize>, GooseError>>>;
 /// Optional unbounded sender from all GooseUsers to logger thread, if enabled.
 pub(crate) type GooseLoggerTx = Option<flume::Sender<Option<GooseLog>>>;
 
 /// If enabled, the logger thread can accept any of the following types of messages, and will
 /// write them to the correct log file.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum GooseLog {
     Debug(GooseDebug),
     Error(GooseErrorMetric),
@@ -159,6 +224,198 @@ pub enum GooseLog {
     Task(GooseTaskMetric),
 }
 
+/// Identifies one of the logs written by the logger thread.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum GooseLogType {
+    /// The debug log, of [`GooseDebug`] messages.
+    Debug,
+    /// The error log, of [`GooseErrorMetric`]s.
+    Error,
+    /// The request log, of [`GooseRequestMetric`]s.
+    Request,
+    /// The task log, of [`GooseTaskMetric`]s.
+    Task,
+}
+
+/// Where the logger thread writes the messages of a log.
+///
+/// Goose writes logs to files, stdout or syslog depending on the configured log name.
+/// Load tests can instead capture the messages of a log in-process by implementing this
+/// trait, and setting it with
+/// [`GooseAttack::set_log_sink`](../struct.GooseAttack.html#method.set_log_sink).
+///
+/// # Example
+/// ```rust
+/// use goose::logger::{GooseLog, GooseLogSink};
+/// use std::io;
+///
+/// /// Counts the requests that failed.
+/// #[derive(Default)]
+/// struct FailedRequests {
+///     failed: usize,
+/// }
+/// #[async_trait::async_trait]
+/// impl GooseLogSink for FailedRequests {
+///     async fn write(&mut self, message: &GooseLog, _formatted: &[u8]) -> io::Result<()> {
+///         if let GooseLog::Request(request) = message {
+///             if !request.success {
+///                 self.failed += 1;
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait GooseLogSink: Send {
+    /// Writes a message to the log. The message is also provided formatted in the log's
+    /// configured format, without a trailing newline.
+    async fn write(&mut self, message: &GooseLog, formatted: &[u8]) -> io::Result<()>;
+
+    /// Writes the header starting a log written in the `csv` format. By default the header
+    /// isn't written.
+    async fn write_header(&mut self, _header: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Flushes any buffered messages, called when the load test stops.
+    async fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The log sinks set with `GooseAttack::set_log_sink`, shared with each load test run.
+pub(crate) type GooseLogSinks = HashMap<GooseLogType, GooseSharedLogSink>;
+
+/// A log sink set with `GooseAttack::set_log_sink`, which outlives the logger thread.
+#[derive(Clone)]
+pub(crate) struct GooseSharedLogSink(Arc<Mutex<Box<dyn GooseLogSink>>>);
+impl GooseSharedLogSink {
+    pub(crate) fn new<T: GooseLogSink + 'static>(sink: T) -> Self {
+        GooseSharedLogSink(Arc::new(Mutex::new(Box::new(sink))))
+    }
+}
+#[async_trait]
+impl GooseLogSink for GooseSharedLogSink {
+    async fn write(&mut self, message: &GooseLog, formatted: &[u8]) -> io::Result<()> {
+        self.0.lock().await.write(message, formatted).await
+    }
+
+    async fn write_header(&mut self, header: &str) -> io::Result<()> {
+        self.0.lock().await.write_header(header).await
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        self.0.lock().await.flush().await
+    }
+}
+
+/// Writes a log to stdout, configured with a log name of `-`.
+struct GooseStdoutLogSink(BufWriter<tokio::io::Stdout>);
+#[async_trait]
+impl GooseLogSink for GooseStdoutLogSink {
+    async fn write(&mut self, _message: &GooseLog, formatted: &[u8]) -> io::Result<()> {
+        self.0.write_all(formatted).await?;
+        self.0.write_all(b"\n").await
+    }
+
+    async fn write_header(&mut self, header: &str) -> io::Result<()> {
+        self.0.write_all(header.as_bytes()).await?;
+        self.0.write_all(b"\n").await
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        self.0.flush().await
+    }
+}
+
+/// Writes a log to a buffered file, optionally rotating it.
+struct GooseFileLogSink {
+    file: BufWriter<File>,
+    /// The path of the file, reopened each time it's rotated.
+    path: String,
+    /// Which log is written, ie `request_log`.
+    log_name: &'static str,
+    /// The capacity of the buffer the file is written through.
+    buffer_capacity: usize,
+    /// The CSV header starting each file, if the log is a CSV.
+    header: Option<String>,
+    /// When to rotate the file, if enabled.
+    rotation: Option<GooseLogRotation>,
+    /// How many bytes were written to the current file.
+    written: u64,
+    /// When the current file was opened.
+    opened: std::time::Instant,
+}
+impl GooseFileLogSink {
+    /// Writes a line to the file.
+    async fn write_line(&mut self, line: &[u8]) -> io::Result<()> {
+        self.file.write_all(line).await?;
+        self.file.write_all(b"\n").await?;
+        // Include the newline written after each line.
+        self.written += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    /// Closes the file and rotates it, then opens a new file with the same name, starting
+    /// with the CSV header if the log is a CSV.
+    async fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush().await?;
+        if let Some(rotation) = self.rotation.as_ref() {
+            rotation.rotate(&self.path).await?;
+        }
+        self.file = BufWriter::with_capacity(self.buffer_capacity, File::create(&self.path).await?);
+        self.written = 0;
+        self.opened = std::time::Instant::now();
+        info!("rotated {}: {}", self.log_name, self.path);
+        if let Some(header) = self.header.clone() {
+            self.write_line(header.as_bytes()).await?;
+        }
+        Ok(())
+    }
+}
+#[async_trait]
+impl GooseLogSink for GooseFileLogSink {
+    async fn write(&mut self, _message: &GooseLog, formatted: &[u8]) -> io::Result<()> {
+        // Rotate before writing, so a message that can't be written after failing to
+        // rotate is handled like any other write failure.
+        if self
+            .rotation
+            .as_ref()
+            .is_some_and(|rotation| rotation.is_due(self.written, self.opened))
+        {
+            self.rotate().await?;
+        }
+        self.write_line(formatted).await
+    }
+
+    async fn write_header(&mut self, header: &str) -> io::Result<()> {
+        self.header = Some(header.to_string());
+        self.write_line(header.as_bytes()).await
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        self.file.flush().await
+    }
+}
+
+/// The logs opened when the load test starts, written to by the logger thread.
+struct GooseLogs {
+    debug_log: Option<Box<dyn GooseLogSink>>,
+    error_log: Option<Box<dyn GooseLogSink>>,
+    request_log: Option<Box<dyn GooseLogSink>>,
+    task_log: Option<Box<dyn GooseLogSink>>,
+}
+
+/// The logger thread's end of the channel to a log's writer task.
+struct GooseLogWriter {
+    log_name: &'static str,
+    log_file_path: String,
+    tx: flume::Sender<GooseLog>,
+    /// How many messages were logged while the writer's channel was full.
+    overflows: usize,
+}
+
 /// Defines the formats logs can be written to file.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum GooseLogFormat {
@@ -166,6 +423,7 @@ pub enum GooseLogFormat {
     Json,
     Raw,
     Pretty,
+    Combined,
 }
 /// Allow setting log formats from the command line by impleenting [`FromStr`].
 impl FromStr for GooseLogFormat {
@@ -179,6 +437,7 @@ impl FromStr for GooseLogFormat {
             r"(?i)^(json|jsn)$",
             r"(?i)^raw$",
             r"(?i)^pretty$",
+            r"(?i)^(combined|apache)$",
         ])
         .expect("failed to compile log_format RegexSet");
         let matches = log_format.matches(s);
@@ -190,20 +449,158 @@ impl FromStr for GooseLogFormat {
             Ok(GooseLogFormat::Raw)
         } else if matches.matched(3) {
             Ok(GooseLogFormat::Pretty)
+        } else if matches.matched(4) {
+            Ok(GooseLogFormat::Combined)
         } else {
             Err(GooseError::InvalidOption {
                 option: format!("GooseLogFormat::{:?}", s),
                 value: s.to_string(),
-                detail: "Invalid log_format, expected: csv, json, or raw".to_string(),
+                detail: "Invalid log_format, expected: csv, json, raw, pretty, or combined"
+                    .to_string(),
+            })
+        }
+    }
+}
+
+/// Defines what the logger thread does when writing to a log fails, for example because the
+/// disk is full, or because the log can't be written as quickly as messages are logged.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum GooseLogFailure {
+    /// Drop messages that can't be written, counting them (default).
+    Drop,
+    /// Write messages that can't be written to the log to stderr instead.
+    Stderr,
+    /// Stop the load test.
+    Abort,
+}
+/// Allow setting the log failure policy from the command line by implementing [`FromStr`].
+impl FromStr for GooseLogFailure {
+    type Err = GooseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Use a [`RegexSet`] to match string representations of `GooseLogFailure`,
+        // returning the appropriate enum value. Also match a wide range of abbreviations and synonyms.
+        let log_failure = RegexSet::new([
+            r"(?i)^(drop|ignore)$",
+            r"(?i)^(stderr|err)$",
+            r"(?i)^(abort|stop)$",
+        ])
+        .expect("failed to compile log_failure RegexSet");
+        let matches = log_failure.matches(s);
+        if matches.matched(0) {
+            Ok(GooseLogFailure::Drop)
+        } else if matches.matched(1) {
+            Ok(GooseLogFailure::Stderr)
+        } else if matches.matched(2) {
+            Ok(GooseLogFailure::Abort)
+        } else {
+            Err(GooseError::InvalidOption {
+                option: format!("GooseLogFailure::{:?}", s),
+                value: s.to_string(),
+                detail: "Invalid log_failure, expected: drop, stderr, or abort".to_string(),
             })
         }
     }
 }
 
+/// Parses a comma-separated list of status codes such as `500,503`, configured with
+/// `--debug-log-body-status`. Returns `None` if any status code isn't valid.
+pub(crate) fn parse_status_codes(status_codes: &str) -> Option<Vec<u16>> {
+    status_codes
+        .split(',')
+        .map(str::trim)
+        .filter(|status_code| !status_code.is_empty())
+        .map(|status_code| {
+            status_code
+                .parse::<u16>()
+                .ok()
+                .filter(|status_code| (100..1000).contains(status_code))
+        })
+        .collect()
+}
+
+/// Parses a comma-separated list of header names such as `authorization,x-api-key`,
+/// configured with `--redact-headers`, returning the names in lowercase. Returns `None` if
+/// any header name isn't valid.
+pub(crate) fn parse_header_names(header_names: &str) -> Option<Vec<String>> {
+    header_names
+        .split(',')
+        .map(str::trim)
+        .filter(|header_name| !header_name.is_empty())
+        .map(|header_name| {
+            reqwest::header::HeaderName::from_bytes(header_name.as_bytes())
+                .ok()
+                .map(|header_name| header_name.as_str().to_string())
+        })
+        .collect()
+}
+
+/// Returns the names of the headers masked in logs: the default headers and any configured
+/// with `--redact-headers`, or none if disabled with `--no-redact-headers`.
+fn redacted_header_names(redact_headers: &str, no_redact_headers: bool) -> Vec<String> {
+    if no_redact_headers {
+        return Vec::new();
+    }
+    let mut redacted = parse_header_names(DEFAULT_REDACT_HEADERS).unwrap_or_default();
+    for header_name in parse_header_names(redact_headers).unwrap_or_default() {
+        if !redacted.contains(&header_name) {
+            redacted.push(header_name);
+        }
+    }
+    redacted
+}
+
+/// Masks the value of any redacted header in a message before it's logged.
+fn redact_headers(message: &mut GooseLog, redacted: &[String]) {
+    if redacted.is_empty() {
+        return;
+    }
+    let raw = match message {
+        GooseLog::Debug(debug) => {
+            // Response headers are recorded as name and value pairs.
+            for (name, value) in debug.headers.iter_mut().flatten() {
+                if redacted.contains(&name.to_lowercase()) {
+                    *value = REDACTED_HEADER.to_string();
+                }
+            }
+            match debug.request.as_mut() {
+                Some(request) => &mut request.raw,
+                None => return,
+            }
+        }
+        GooseLog::Error(error) => &mut error.raw,
+        GooseLog::Request(request) => &mut request.raw,
+        GooseLog::Task(_) => return,
+    };
+    // Request headers are recorded as the Debug output of their name and value, for
+    // example `("authorization", "Bearer secret")`.
+    for header in raw.headers.iter_mut() {
+        let name = header
+            .strip_prefix("(\"")
+            .and_then(|header| header.split_once("\", "))
+            .map(|(name, _)| name.to_lowercase());
+        if let Some(name) = name.filter(|name| redacted.contains(name)) {
+            *header = format!("{:?}", (name, REDACTED_HEADER));
+        }
+    }
+}
+
 // @TODO this should be automatically derived from the structure.
 fn debug_csv_header() -> String {
     // No quotes needed in header.
-    format!("{},{},{},{}", "tag", "request", "header", "body")
+    format!(
+        "{},{},{},{},{},{},{},{},{},{}",
+        "schema_version",
+        "timestamp",
+        "elapsed",
+        "user",
+        "task_name",
+        "request_name",
+        "tag",
+        "request",
+        "headers",
+        "body",
+    )
 }
 
 // @TODO this should be automatically derived from the structure.
@@ -227,7 +624,7 @@ fn error_csv_header() -> String {
 fn requests_csv_header() -> String {
     // No quotes needed in header.
     format!(
-        "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
         "elapsed",
         "raw",
         "name",
@@ -241,6 +638,17 @@ fn requests_csv_header() -> String {
         "error",
         "coordinated_omission_elapsed",
         "user_cadence",
+        "response_time_budget",
+        "slow",
+        "backend",
+        "affinity_violation",
+        "dimensions",
+        "body_size",
+        "decompressed_body_size",
+        "integrity",
+        "schema_valid",
+        "cache_hit",
+        "transport_error",
     )
 }
 
@@ -261,26 +669,27 @@ fn tasks_csv_header() -> String {
 
 /// Two traits that must be implemented by all loggers provided through this thread.
 pub(crate) trait GooseLogger<T> {
-    /// Converts a rust structure to a formatted string.
-    /// @TODO: rework with .to_string()
-    fn format_message(&self, message: T) -> String;
+    /// Writes a rust structure to a buffer as a formatted log message.
+    fn format_message(&self, message: &T, buffer: &mut Vec<u8>);
     /// Helper that makes a best-effort to convert a supported rust structure to a CSV row.
     fn prepare_csv(&self, message: &T) -> String;
 }
 /// Traits for GooseDebug logs.
 impl GooseLogger<GooseDebug> for GooseConfiguration {
-    /// Converts a GooseDebug structure to a formatted string.
-    fn format_message(&self, message: GooseDebug) -> String {
+    /// Writes a GooseDebug structure to the buffer as a formatted log message.
+    fn format_message(&self, message: &GooseDebug, buffer: &mut Vec<u8>) {
         if let Some(debug_format) = self.debug_format.as_ref() {
             match debug_format {
-                // Use serde_json to create JSON.
-                GooseLogFormat::Json => json!(message).to_string(),
+                // Use serde_json to serialize JSON directly into the buffer.
+                GooseLogFormat::Json => self.write_json(message, buffer),
                 // Raw format is Debug output for GooseRawRequest structure.
-                GooseLogFormat::Raw => format!("{:?}", message),
+                GooseLogFormat::Raw => self.write_raw(format_args!("{:?}", message), buffer),
                 // Pretty format is Debug Pretty output for GooseRawRequest structure.
-                GooseLogFormat::Pretty => format!("{:#?}", message),
-                // Not yet implemented.
-                GooseLogFormat::Csv => self.prepare_csv(&message),
+                GooseLogFormat::Pretty => self.write_raw(format_args!("{:#?}", message), buffer),
+                // CSV format is a row of the structure's fields.
+                GooseLogFormat::Csv => self.write_csv(&self.prepare_csv(message), buffer),
+                // Only the request log can be written in combined format, validated at startup.
+                GooseLogFormat::Combined => unreachable!(),
             }
         } else {
             // A log format is required.
@@ -290,28 +699,45 @@ impl GooseLogger<GooseDebug> for GooseConfiguration {
 
     /// Converts a GooseDebug structure to a CSV row.
     fn prepare_csv(&self, debug: &GooseDebug) -> String {
-        // Put quotes around all fields, as they are all strings.
-        // @TODO: properly handle Option<>; also, escape inner quotes etc.
+        // Quote all strings, the request and headers are serialized as JSON. Missing
+        // values are empty.
         format!(
-            "\"{}\",\"{:?}\",\"{:?}\",\"{:?}\"",
-            debug.tag, debug.request, debug.header, debug.body
+            "{},{},{},{},{},{},{},{},{},{}",
+            debug.schema_version,
+            debug.timestamp,
+            debug.elapsed,
+            debug.user,
+            csv_field(debug.task_name.as_deref().unwrap_or_default()),
+            csv_field(debug.request_name.as_deref().unwrap_or_default()),
+            csv_field(&debug.tag),
+            debug
+                .request
+                .as_ref()
+                .map_or_else(|| csv_field(""), csv_json_field),
+            debug
+                .headers
+                .as_ref()
+                .map_or_else(|| csv_field(""), csv_json_field),
+            csv_field(debug.body.as_deref().unwrap_or_default()),
         )
     }
 }
 /// Traits for GooseErrorMetric logs.
 impl GooseLogger<GooseErrorMetric> for GooseConfiguration {
-    /// Converts a GooseErrorMetric structure to a formatted string.
-    fn format_message(&self, message: GooseErrorMetric) -> String {
+    /// Writes a GooseErrorMetric structure to the buffer as a formatted log message.
+    fn format_message(&self, message: &GooseErrorMetric, buffer: &mut Vec<u8>) {
         if let Some(error_format) = self.error_format.as_ref() {
             match error_format {
-                // Use serde_json to create JSON.
-                GooseLogFormat::Json => json!(message).to_string(),
+                // Use serde_json to serialize JSON directly into the buffer.
+                GooseLogFormat::Json => self.write_json(message, buffer),
                 // Raw format is Debug output for GooseErrorMetric structure.
-                GooseLogFormat::Raw => format!("{:?}", message),
+                GooseLogFormat::Raw => self.write_raw(format_args!("{:?}", message), buffer),
                 // Pretty format is Debug Pretty output for GooseErrorMetric structure.
-                GooseLogFormat::Pretty => format!("{:#?}", message),
-                // Not yet implemented.
-                GooseLogFormat::Csv => self.prepare_csv(&message),
+                GooseLogFormat::Pretty => self.write_raw(format_args!("{:#?}", message), buffer),
+                // CSV format is a row of the structure's fields.
+                GooseLogFormat::Csv => self.write_csv(&self.prepare_csv(message), buffer),
+                // Only the request log can be written in combined format, validated at startup.
+                GooseLogFormat::Combined => unreachable!(),
             }
         } else {
             // A log format is required.
@@ -322,34 +748,45 @@ impl GooseLogger<GooseErrorMetric> for GooseConfiguration {
     /// Converts a GooseErrorMetric structure to a CSV row.
     fn prepare_csv(&self, request: &GooseErrorMetric) -> String {
         format!(
-            // Put quotes around name, url, final_url and error as they are strings.
-            "{},\"{:?}\",\"{}\",\"{}\",{},{},{},{},\"{}\"",
+            // Quote all strings, the raw request is serialized as JSON.
+            "{},{},{},{},{},{},{},{},{}",
             request.elapsed,
-            request.raw,
-            request.name,
-            request.final_url,
+            csv_json_field(&request.raw),
+            csv_field(&request.name),
+            csv_field(&request.final_url),
             request.redirected,
             request.response_time,
             request.status_code,
             request.user,
-            request.error,
+            csv_field(&request.error),
         )
     }
 }
 /// Traits for GooseRequestMetric logs.
 impl GooseLogger<GooseRequestMetric> for GooseConfiguration {
-    /// Converts a GooseRequestMetric structure to a formatted string.
-    fn format_message(&self, message: GooseRequestMetric) -> String {
+    /// Writes a GooseRequestMetric structure to the buffer as a formatted log message.
+    fn format_message(&self, message: &GooseRequestMetric, buffer: &mut Vec<u8>) {
         if let Some(request_format) = self.request_format.as_ref() {
             match request_format {
-                // Use serde_json to create JSON.
-                GooseLogFormat::Json => json!(message).to_string(),
+                // Use serde_json to serialize JSON directly into the buffer.
+                GooseLogFormat::Json => self.write_json(message, buffer),
                 // Raw format is Debug output for GooseRequestMetric structure.
-                GooseLogFormat::Raw => format!("{:?}", message),
+                GooseLogFormat::Raw => self.write_raw(format_args!("{:?}", message), buffer),
                 // Pretty format is Debug Pretty output for GooseRequestMetric structure.
-                GooseLogFormat::Pretty => format!("{:#?}", message),
-                // Not yet implemented.
-                GooseLogFormat::Csv => self.prepare_csv(&message),
+                GooseLogFormat::Pretty => self.write_raw(format_args!("{:#?}", message), buffer),
+                // CSV format is a row of the structure's fields.
+                GooseLogFormat::Csv => self.write_csv(&self.prepare_csv(message), buffer),
+                // Each request is logged once, when it was made.
+                GooseLogFormat::Combined => {
+                    if !message.update && message.coordinated_omission_elapsed == 0 {
+                        // The request started the response time before it was logged.
+                        let started =
+                            Local::now() - Duration::milliseconds(message.response_time as i64);
+                        buffer.extend_from_slice(
+                            prepare_combined(message, started.into()).as_bytes(),
+                        );
+                    }
+                }
             }
         } else {
             // A log format is required.
@@ -360,38 +797,109 @@ impl GooseLogger<GooseRequestMetric> for GooseConfiguration {
     /// Converts a GooseRequestMetric structure to a CSV row.
     fn prepare_csv(&self, request: &GooseRequestMetric) -> String {
         format!(
-            // Put quotes around name, url and final_url as they are strings.
-            "{},\"{:?}\",\"{}\",\"{}\",{},{},{},{},{},{},{},{},{}",
+            // Quote all strings, the raw request and dimensions are serialized as JSON, and
+            // missing optional values are empty.
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
             request.elapsed,
-            request.raw,
-            request.name,
-            request.final_url,
+            csv_json_field(&request.raw),
+            csv_field(&request.name),
+            csv_field(&request.final_url),
             request.redirected,
             request.response_time,
             request.status_code,
             request.success,
             request.update,
             request.user,
-            request.error,
+            csv_field(&request.error),
             request.coordinated_omission_elapsed,
             request.user_cadence,
+            csv_option(&request.response_time_budget),
+            request.slow,
+            csv_field(request.backend.as_deref().unwrap_or("")),
+            request.affinity_violation,
+            csv_json_field(&request.dimensions),
+            csv_option(&request.body_size),
+            csv_option(&request.decompressed_body_size),
+            csv_option(&request.integrity),
+            csv_option(&request.schema_valid),
+            csv_option(&request.cache_hit),
+            csv_field(&csv_option(&request.transport_error)),
         )
     }
 }
+/// Quotes a CSV field, escaping quotes in the value by doubling them.
+fn csv_field(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Formats an optional value as a CSV field, empty if there's no value.
+fn csv_option<T: fmt::Display>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map_or_else(String::new, |value| value.to_string())
+}
+
+/// Serializes a structure as JSON, quoted as a CSV field.
+fn csv_json_field<T: Serialize>(value: &T) -> String {
+    // Serializing the log structures to JSON can't fail.
+    csv_field(&serde_json::to_string(value).unwrap_or_default())
+}
+
+/// Converts a GooseRequestMetric structure to a line in the Apache combined log format.
+fn prepare_combined(request: &GooseRequestMetric, started: DateTime<FixedOffset>) -> String {
+    // Only the path and query of the URL are logged.
+    let path = match url::Url::parse(&request.raw.url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => request.raw.url.to_string(),
+    };
+    // Headers are recorded as the Debug output of their name and value, for example
+    // `("referer", "https://example.com/")`, so values are already quoted and escaped.
+    let header = |name: &str| {
+        request
+            .raw
+            .headers
+            .iter()
+            .find_map(|header| {
+                header
+                    .strip_prefix(&format!("(\"{}\", ", name))
+                    .and_then(|value| value.strip_suffix(')'))
+            })
+            .unwrap_or("\"-\"")
+            .to_string()
+    };
+    format!(
+        "127.0.0.1 - {} [{}] \"{} {} HTTP/1.1\" {} {} {} {}",
+        request.user,
+        started.format("%d/%b/%Y:%H:%M:%S %z"),
+        request.raw.method_name(),
+        path,
+        request.status_code,
+        request
+            .body_size
+            .map_or_else(|| "-".to_string(), |body_size| body_size.to_string()),
+        header("referer"),
+        header("user-agent"),
+    )
+}
 /// Traits for GooseTaskMetric logs.
 impl GooseLogger<GooseTaskMetric> for GooseConfiguration {
-    /// Converts a GooseTaskMetric structure to a formatted string.
-    fn format_message(&self, message: GooseTaskMetric) -> String {
+    /// Writes a GooseTaskMetric structure to the buffer as a formatted log message.
+    fn format_message(&self, message: &GooseTaskMetric, buffer: &mut Vec<u8>) {
         if let Some(task_format) = self.task_format.as_ref() {
             match task_format {
-                // Use serde_json to create JSON.
-                GooseLogFormat::Json => json!(message).to_string(),
+                // Use serde_json to serialize JSON directly into the buffer.
+                GooseLogFormat::Json => self.write_json(message, buffer),
                 // Raw format is Debug output for GooseTaskMetric structure.
-                GooseLogFormat::Raw => format!("{:?}", message),
+                GooseLogFormat::Raw => self.write_raw(format_args!("{:?}", message), buffer),
                 // Pretty format is Debug Pretty output for GooseTaskMetric structure.
-                GooseLogFormat::Pretty => format!("{:#?}", message),
-                // Not yet implemented.
-                GooseLogFormat::Csv => self.prepare_csv(&message),
+                GooseLogFormat::Pretty => self.write_raw(format_args!("{:#?}", message), buffer),
+                // CSV format is a row of the structure's fields.
+                GooseLogFormat::Csv => self.write_csv(&self.prepare_csv(message), buffer),
+                // Only the request log can be written in combined format, validated at startup.
+                GooseLogFormat::Combined => unreachable!(),
             }
         } else {
             // A log format is required.
@@ -402,12 +910,12 @@ impl GooseLogger<GooseTaskMetric> for GooseConfiguration {
     /// Converts a GooseTaskMetric structure to a CSV row.
     fn prepare_csv(&self, request: &GooseTaskMetric) -> String {
         format!(
-            // Put quotes around name as it is a string.
-            "{},{},{},\"{}\",{},{},{}",
+            // Quote the name as it is a string.
+            "{},{},{},{},{},{},{}",
             request.elapsed,
             request.taskset_index,
             request.task_index,
-            request.name,
+            csv_field(&request.name),
             request.run_time,
             request.success,
             request.user,
@@ -415,6 +923,47 @@ impl GooseLogger<GooseTaskMetric> for GooseConfiguration {
     }
 }
 
+/// Helpers to mark log messages with the run ID configured with `--run-id`.
+impl GooseConfiguration {
+    /// Writes a JSON log message to the buffer, with a `run_id` field.
+    fn write_json<T: Serialize>(&self, message: &T, buffer: &mut Vec<u8>) {
+        // Writing the log structures to memory can't fail.
+        let _ = serde_json::to_writer(&mut *buffer, message);
+        // Add the run ID as the last field of the JSON object.
+        if !self.run_id.is_empty() && buffer.last() == Some(&b'}') {
+            buffer.pop();
+            buffer.extend_from_slice(b",\"run_id\":");
+            let _ = serde_json::to_writer(&mut *buffer, &self.run_id);
+            buffer.push(b'}');
+        }
+    }
+
+    /// Writes a raw or pretty log message to the buffer, prefixed with the run ID.
+    fn write_raw(&self, message: fmt::Arguments, buffer: &mut Vec<u8>) {
+        if !self.run_id.is_empty() {
+            let _ = write!(buffer, "[{}] ", self.run_id);
+        }
+        let _ = buffer.write_fmt(message);
+    }
+
+    /// Writes a CSV log row to the buffer, with a `run_id` column.
+    fn write_csv(&self, row: &str, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(row.as_bytes());
+        if !self.run_id.is_empty() {
+            let _ = write!(buffer, ",{}", csv_field(&self.run_id));
+        }
+    }
+
+    /// Adds a `run_id` column to a CSV log header.
+    fn stamp_csv_header(&self, header: String) -> String {
+        if self.run_id.is_empty() {
+            header
+        } else {
+            format!("{},run_id", header)
+        }
+    }
+}
+
 /// Helpers to launch and control configured loggers.
 impl GooseConfiguration {
     /// Makes sure the GooseConfiguration has any/all configured log files (loading from defaults
@@ -460,6 +1009,58 @@ impl GooseConfiguration {
             },
         ]);
 
+        // Set `debug_log_body_limit`.
+        self.debug_log_body_limit = self.get_value(vec![
+            // Use --debug-log-body-limit if set.
+            GooseValue {
+                value: self.debug_log_body_limit,
+                filter: self.debug_log_body_limit.is_none(),
+                message: "",
+            },
+            // Otherwise use GooseDefault if set and not on Manager.
+            GooseValue {
+                value: defaults.debug_log_body_limit,
+                filter: defaults.debug_log_body_limit.is_none() || self.manager,
+                message: "",
+            },
+        ]);
+
+        // Set `debug_log_body_status`.
+        self.debug_log_body_status = self
+            .get_value(vec![
+                // Use --debug-log-body-status if set.
+                GooseValue {
+                    value: Some(self.debug_log_body_status.to_string()),
+                    filter: self.debug_log_body_status.is_empty(),
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.debug_log_body_status.clone(),
+                    filter: defaults.debug_log_body_status.is_none() || self.manager,
+                    message: "",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
+        // Set `redact_headers`.
+        self.redact_headers = self
+            .get_value(vec![
+                // Use --redact-headers if set.
+                GooseValue {
+                    value: Some(self.redact_headers.to_string()),
+                    filter: self.redact_headers.is_empty(),
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.redact_headers.clone(),
+                    filter: defaults.redact_headers.is_none() || self.manager,
+                    message: "",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
         // Configure error_log path if enabled.
         self.error_log = self
             .get_value(vec![
@@ -597,12 +1198,100 @@ impl GooseConfiguration {
                 message: "",
             },
         ]);
+
+        // Set `log_failure`.
+        self.log_failure = self.get_value(vec![
+            // Use --log-failure if set.
+            GooseValue {
+                value: self.log_failure.clone(),
+                filter: self.log_failure.is_none(),
+                message: "",
+            },
+            // Otherwise use GooseDefault if set and not on Manager.
+            GooseValue {
+                value: defaults.log_failure.clone(),
+                filter: defaults.log_failure.is_none() || self.manager,
+                message: "",
+            },
+        ]);
+
+        // Configure `log_rotate_size`.
+        self.log_rotate_size = self
+            .get_value(vec![
+                // Use --log-rotate-size if set.
+                GooseValue {
+                    value: Some(self.log_rotate_size.to_string()),
+                    filter: self.log_rotate_size.is_empty(),
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.log_rotate_size.clone(),
+                    filter: defaults.log_rotate_size.is_none() || self.manager,
+                    message: "",
+                },
+            ])
+            .unwrap_or_else(|| "".to_string());
+
+        // Configure `log_rotate_interval`.
+        self.log_rotate_interval = self
+            .get_value(vec![
+                // Use --log-rotate-interval if set.
+                GooseValue {
+                    value: Some(util::parse_timespan(&self.log_rotate_interval)),
+                    filter: util::parse_timespan(&self.log_rotate_interval) == 0,
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.log_rotate_interval,
+                    filter: defaults.log_rotate_interval.is_none() || self.manager,
+                    message: "",
+                },
+            ])
+            .map_or_else(|| "0".to_string(), |v| v.to_string());
+
+        // Set `log_rotate_keep`.
+        self.log_rotate_keep = self.get_value(vec![
+            // Use --log-rotate-keep if set.
+            GooseValue {
+                value: self.log_rotate_keep,
+                filter: self.log_rotate_keep.is_none(),
+                message: "",
+            },
+            // Otherwise use GooseDefault if set and not on Manager.
+            GooseValue {
+                value: defaults.log_rotate_keep,
+                filter: defaults.log_rotate_keep.is_none() || self.manager,
+                message: "",
+            },
+        ]);
+
+        // Configure `log_rotate_compress`.
+        self.log_rotate_compress = self
+            .get_value(vec![
+                // Use --log-rotate-compress if set.
+                GooseValue {
+                    value: Some(self.log_rotate_compress),
+                    filter: !self.log_rotate_compress,
+                    message: "",
+                },
+                // Otherwise use GooseDefault if set and not on Manager.
+                GooseValue {
+                    value: defaults.log_rotate_compress,
+                    filter: defaults.log_rotate_compress.is_none() || self.manager,
+                    message: "",
+                },
+            ])
+            .unwrap_or(false);
     }
 
     /// Spawns the logger thread if one or more loggers are enabled.
     pub(crate) async fn setup_loggers(
         &mut self,
         defaults: &GooseDefaults,
+        log_sinks: &GooseLogSinks,
+        canceled: &Arc<AtomicBool>,
     ) -> Result<(GooseLoggerJoinHandle, GooseLoggerTx), GooseError> {
         // If running in Manager mode, no logger thread is started.
         if self.manager {
@@ -621,6 +1310,43 @@ impl GooseConfiguration {
             return Ok((None, None));
         }
 
+        // Open the logs before launching the logger thread, so a log that can't be opened
+        // stops the load test before it starts.
+        let logs = GooseLogs {
+            debug_log: self
+                .open_log(
+                    "--debug-log",
+                    "debug_log",
+                    &self.debug_log,
+                    log_sinks.get(&GooseLogType::Debug),
+                )
+                .await?,
+            error_log: self
+                .open_log(
+                    "--error-log",
+                    "error_log",
+                    &self.error_log,
+                    log_sinks.get(&GooseLogType::Error),
+                )
+                .await?,
+            request_log: self
+                .open_log(
+                    "--request-log",
+                    "request_log",
+                    &self.request_log,
+                    log_sinks.get(&GooseLogType::Request),
+                )
+                .await?,
+            task_log: self
+                .open_log(
+                    "--task-log",
+                    "task_log",
+                    &self.task_log,
+                    log_sinks.get(&GooseLogType::Task),
+                )
+                .await?,
+        };
+
         // Create an unbounded channel allowing GooseUser threads to log errors.
         let (all_threads_logger_tx, logger_rx): (
             flume::Sender<Option<GooseLog>>,
@@ -628,186 +1354,578 @@ impl GooseConfiguration {
         ) = flume::unbounded();
         // Launch a new thread for logging.
         let configuration = self.clone();
-        let logger_handle = tokio::spawn(async move { configuration.logger_main(logger_rx).await });
+        let canceled = canceled.clone();
+        let logger_handle =
+            tokio::spawn(async move { configuration.logger_main(logs, logger_rx, canceled).await });
         Ok((Some(logger_handle), Some(all_threads_logger_tx)))
     }
 
-    /// A helper used to open any/all log files, deleting any file that already exists.
+    /// The capacity of the buffer each log file is written through.
+    fn buffer_capacity(&self, log_name: &str) -> usize {
+        match log_name {
+            // Allocate a larger 8M buffer if logging response body.
+            "debug_log" if !self.no_debug_body => 8 * 1024 * 1024,
+            // Allocate a larger 8M buffer if logging request body.
+            "request_log" if self.request_body => 8 * 1024 * 1024,
+            // Otherwise allocate a smaller 64K buffer.
+            _ => 64 * 1024,
+        }
+    }
+
+    /// A helper used to open any/all logs. A log with a sink set with
+    /// `GooseAttack::set_log_sink` is written to that sink, a log named `-` is written to
+    /// stdout, and logs named `syslog:TARGET` are sent to syslog. Any other log is written
+    /// to a file, deleting any file that already exists.
     async fn open_log_file(
         &self,
+        log_name: &'static str,
         log_file_path: &str,
-        log_file_type: &str,
-        buffer_capacity: usize,
-    ) -> std::option::Option<tokio::io::BufWriter<tokio::fs::File>> {
-        if log_file_path.is_empty() {
-            None
+        log_sink: Option<&GooseSharedLogSink>,
+    ) -> Result<Option<Box<dyn GooseLogSink>>, String> {
+        let log_file_type = log_name.replace('_', " ");
+        if let Some(log_sink) = log_sink {
+            info!("writing {} to custom log sink", log_file_type);
+            Ok(Some(Box::new(log_sink.clone())))
+        } else if log_file_path.is_empty() {
+            Ok(None)
+        } else if log_file_path == "-" {
+            info!("writing {} to stdout", log_file_type);
+            Ok(Some(Box::new(GooseStdoutLogSink(BufWriter::new(
+                tokio::io::stdout(),
+            )))))
+        } else if let Some(target) = syslog::syslog_target(log_file_path) {
+            // Syslog messages are tagged with the log, ie `request`.
+            let syslog_message_id = log_name.trim_end_matches("_log");
+            let connected = match target {
+                Ok(target) => GooseSyslog::connect(&target, syslog_message_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e),
+            };
+            match connected {
+                Ok(syslog) => {
+                    info!("sending {} to: {}", log_file_type, log_file_path);
+                    Ok(Some(Box::new(syslog)))
+                }
+                Err(e) => Err(format!("Failed to connect {}: {}", log_file_type, e)),
+            }
         } else {
             match File::create(log_file_path).await {
                 Ok(f) => {
                     info!("writing {} to: {}", log_file_type, log_file_path);
-                    Some(BufWriter::with_capacity(buffer_capacity, f))
-                }
-                Err(e) => {
-                    error!(
-                        "failed to create {} ({}): {}",
-                        log_file_type, log_file_path, e
-                    );
-                    None
+                    let buffer_capacity = self.buffer_capacity(log_name);
+                    Ok(Some(Box::new(GooseFileLogSink {
+                        file: BufWriter::with_capacity(buffer_capacity, f),
+                        path: log_file_path.to_string(),
+                        log_name,
+                        buffer_capacity,
+                        header: None,
+                        // Log files are optionally rotated by size or time.
+                        rotation: GooseLogRotation::new(self),
+                        written: 0,
+                        opened: std::time::Instant::now(),
+                    })))
                 }
+                Err(e) => Err(format!("Failed to create {}: {}", log_file_type, e)),
             }
         }
     }
 
-    /// Helper to write a line to the log file.
-    async fn write_to_log_file(
+    /// Opens a log, starting with the CSV header if the log is a CSV. Returns an error if
+    /// the log can't be opened, unless configured to continue without the log with
+    /// `--continue-without-logs`.
+    async fn open_log(
         &self,
-        log_file: &mut tokio::io::BufWriter<tokio::fs::File>,
-        formatted_message: String,
-    ) -> Result<(), ()> {
-        match log_file
-            .write(format!("{}\n", formatted_message).as_ref())
-            .await
-        {
-            Ok(_) => (),
-            Err(e) => {
-                warn!("failed to write to {}: {}", &self.debug_log, e);
+        option: &str,
+        log_name: &'static str,
+        log_file_path: &str,
+        log_sink: Option<&GooseSharedLogSink>,
+    ) -> Result<Option<Box<dyn GooseLogSink>>, GooseError> {
+        match self.open_log_file(log_name, log_file_path, log_sink).await {
+            Ok(Some(mut log)) => {
+                if let Some(header) = self.csv_header(log_name) {
+                    // The header is buffered, so failing to write it is handled by the
+                    // writer task.
+                    let _ = log.write_header(&header).await;
+                }
+                Ok(Some(log))
+            }
+            Ok(None) => Ok(None),
+            Err(detail) if self.continue_without_logs => {
+                warn!("{} ({}), continuing without it", detail, log_file_path);
+                Ok(None)
             }
+            Err(detail) => Err(GooseError::InvalidOption {
+                option: option.to_string(),
+                value: log_file_path.to_string(),
+                detail,
+            }),
         }
+    }
 
-        Ok(())
+    /// Applies the `--log-failure` policy the first time writing to a log fails, rather
+    /// than warning about every message that can't be written.
+    fn handle_log_failure(
+        &self,
+        log_name: &str,
+        log_file_path: &str,
+        error: &dyn std::fmt::Display,
+        canceled: &AtomicBool,
+    ) {
+        // Messages that can't be written are dropped unless another policy is set.
+        match self.log_failure {
+            Some(GooseLogFailure::Stderr) => {
+                warn!(
+                    "failed to write to {} {}: {}, writing it to stderr",
+                    log_name, log_file_path, error
+                );
+            }
+            Some(GooseLogFailure::Abort) => {
+                error!(
+                    "failed to write to {} {}: {}, stopping load test",
+                    log_name, log_file_path, error
+                );
+                canceled.store(true, Ordering::SeqCst);
+            }
+            _ => {
+                warn!(
+                    "failed to write to {} {}: {}, dropping messages that can't be written",
+                    log_name, log_file_path, error
+                );
+            }
+        }
     }
 
-    /// Logger thread, opens a log file (if configured) and waits for messages from
-    /// [`GooseUser`](../goose/struct.GooseUser.html) threads.
-    pub(crate) async fn logger_main(
+    /// Logger thread, routes messages received from
+    /// [`GooseUser`](../goose/struct.GooseUser.html) threads to a writer task for each
+    /// opened log.
+    async fn logger_main(
         self: GooseConfiguration,
+        logs: GooseLogs,
         receiver: flume::Receiver<Option<GooseLog>>,
-    ) -> Result<(), GooseError> {
-        // If the debug_log is enabled, allocate a buffer and open the file.
-        let mut debug_log = self
-            .open_log_file(
-                &self.debug_log,
-                "debug file",
-                if self.no_debug_body {
-                    // Allocate a smaller 64K buffer if not logging response body.
-                    64 * 1024
-                } else {
-                    // Allocate a larger 8M buffer if logging response body.
-                    8 * 1024 * 1024
-                },
-            )
-            .await;
-        // If the debug_log is a CSV, write the header.
-        if self.debug_format == Some(GooseLogFormat::Csv) {
-            if let Some(log_file) = debug_log.as_mut() {
-                // @TODO: error handling when writing to log fails.
-                let _ = self.write_to_log_file(log_file, debug_csv_header()).await;
+        canceled: Arc<AtomicBool>,
+    ) -> Result<BTreeMap<String, usize>, GooseError> {
+        let mut writers = Vec::new();
+        let mut debug_writer = self.spawn_log_writer(
+            "debug_log",
+            &self.debug_log,
+            logs.debug_log,
+            &canceled,
+            &mut writers,
+        );
+        let mut error_writer = self.spawn_log_writer(
+            "error_log",
+            &self.error_log,
+            logs.error_log,
+            &canceled,
+            &mut writers,
+        );
+        let mut request_writer = self.spawn_log_writer(
+            "request_log",
+            &self.request_log,
+            logs.request_log,
+            &canceled,
+            &mut writers,
+        );
+        let mut task_writer = self.spawn_log_writer(
+            "task_log",
+            &self.task_log,
+            logs.task_log,
+            &canceled,
+            &mut writers,
+        );
+
+        // Headers masked in every log.
+        let redacted = redacted_header_names(&self.redact_headers, self.no_redact_headers);
+        // Messages that overflow a writer are formatted here with `--log-failure stderr`.
+        let mut buffer = Vec::new();
+
+        // Loop waiting for and routing logs from GooseUser threads.
+        while let Ok(received_message) = receiver.recv_async().await {
+            if let Some(mut message) = received_message {
+                redact_headers(&mut message, &redacted);
+                if let Some(writer) = match message {
+                    GooseLog::Debug(_) => debug_writer.as_mut(),
+                    GooseLog::Error(_) => error_writer.as_mut(),
+                    GooseLog::Request(_) => request_writer.as_mut(),
+                    GooseLog::Task(_) => task_writer.as_mut(),
+                } {
+                    self.send_to_writer(writer, message, &mut buffer, &canceled);
+                }
+            } else {
+                // Empty message means it's time to exit.
+                break;
             }
         }
 
-        // If the error_log is enabled, allocate a buffer and open the file.
-        let mut error_log = self
-            .open_log_file(&self.error_log, "error log", 64 * 1024)
-            .await;
-        // If the request_log is a CSV, write the header.
-        if self.error_format == Some(GooseLogFormat::Csv) {
-            if let Some(log_file) = error_log.as_mut() {
-                // @TODO: error handling when writing to log fails.
-                let _ = self.write_to_log_file(log_file, error_csv_header()).await;
+        // Closing the channels tells the writers to flush their logs and exit. Messages that
+        // overflowed a writer count as failures to write to its log.
+        let overflows: Vec<(String, usize)> =
+            vec![debug_writer, error_writer, request_writer, task_writer]
+                .into_iter()
+                .flatten()
+                .map(|writer| (writer.log_file_path, writer.overflows))
+                .collect();
+        let mut log_failures = BTreeMap::new();
+        for (log_file_path, failures) in futures::future::join_all(writers)
+            .await
+            .into_iter()
+            .filter_map(|writer| writer.ok())
+            .chain(overflows)
+        {
+            if failures > 0 {
+                *log_failures.entry(log_file_path).or_insert(0) += failures;
             }
         }
 
-        // If the request_log is enabled, allocate a buffer and open the file.
-        let mut request_log = self
-            .open_log_file(
-                &self.request_log,
-                "request log",
-                if self.request_body {
-                    // Allocate a larger 8M buffer if logging request body.
-                    8 * 1024 * 1024
-                } else {
-                    // Allocate a smaller 64K buffer if not logging request body.
-                    64 * 1024
-                },
-            )
-            .await;
-        // If the request_log is a CSV, write the header.
-        if self.request_format == Some(GooseLogFormat::Csv) {
-            if let Some(log_file) = request_log.as_mut() {
-                // @TODO: error handling when writing to log fails.
-                let _ = self
-                    .write_to_log_file(log_file, requests_csv_header())
-                    .await;
+        Ok(log_failures)
+    }
+
+    /// Queues a message for a log's writer without waiting. If the writer has fallen
+    /// [`LOG_WRITER_CAPACITY`] messages behind, the message is handled with the
+    /// `--log-failure` policy instead, so a slow log can't grow memory without limit.
+    fn send_to_writer(
+        &self,
+        writer: &mut GooseLogWriter,
+        message: GooseLog,
+        buffer: &mut Vec<u8>,
+        canceled: &AtomicBool,
+    ) {
+        // The writer only exits after its sender is dropped.
+        if let Err(flume::TrySendError::Full(message)) = writer.tx.try_send(message) {
+            if writer.overflows == 0 {
+                self.handle_log_failure(
+                    writer.log_name,
+                    &writer.log_file_path,
+                    &format!("more than {} messages waiting", LOG_WRITER_CAPACITY),
+                    canceled,
+                );
+            }
+            writer.overflows += 1;
+            if self.log_failure == Some(GooseLogFailure::Stderr) {
+                buffer.clear();
+                self.format_log(&message, buffer);
+                if !buffer.is_empty() {
+                    eprintln!("{}", String::from_utf8_lossy(buffer));
+                }
             }
         }
+    }
 
-        // If the task_log is enabled, allocate a buffer and open the file.
-        let mut task_log = self
-            .open_log_file(&self.task_log, "task log", 64 * 1024)
-            .await;
-        // If the task_log is a CSV, write the header.
-        if self.task_format == Some(GooseLogFormat::Csv) {
-            if let Some(log_file) = task_log.as_mut() {
-                // @TODO: error handling when writing to log fails.
-                let _ = self.write_to_log_file(log_file, tasks_csv_header()).await;
-            }
+    /// Spawns a writer task for a log if it was opened, returning a bounded channel to send
+    /// it messages.
+    fn spawn_log_writer(
+        &self,
+        log_name: &'static str,
+        log_file_path: &str,
+        log: Option<Box<dyn GooseLogSink>>,
+        canceled: &Arc<AtomicBool>,
+        writers: &mut Vec<tokio::task::JoinHandle<(String, usize)>>,
+    ) -> Option<GooseLogWriter> {
+        let log = log?;
+        let (writer_tx, writer_rx) = flume::bounded(LOG_WRITER_CAPACITY);
+        let configuration = self.clone();
+        let writer_log_file_path = log_file_path.to_string();
+        let canceled = canceled.clone();
+        writers.push(tokio::spawn(async move {
+            configuration
+                .log_writer_main(log_name, writer_log_file_path, log, writer_rx, canceled)
+                .await
+        }));
+        Some(GooseLogWriter {
+            log_name,
+            log_file_path: log_file_path.to_string(),
+            tx: writer_tx,
+            overflows: 0,
+        })
+    }
+
+    /// Formats any message for its log.
+    fn format_log(&self, message: &GooseLog, buffer: &mut Vec<u8>) {
+        match message {
+            GooseLog::Debug(debug_message) => self.format_message(debug_message, buffer),
+            GooseLog::Error(error_message) => self.format_message(error_message, buffer),
+            GooseLog::Request(request_message) => self.format_message(request_message, buffer),
+            GooseLog::Task(task_message) => self.format_message(task_message, buffer),
         }
+    }
 
-        // Loop waiting for and writing error logs from GooseUser threads.
-        while let Ok(received_message) = receiver.recv_async().await {
-            if let Some(message) = received_message {
-                let formatted_message;
-                if let Some(log_file) = match message {
-                    GooseLog::Debug(debug_message) => {
-                        formatted_message = self.format_message(debug_message).to_string();
-                        debug_log.as_mut()
-                    }
-                    GooseLog::Error(error_message) => {
-                        formatted_message = self.format_message(error_message).to_string();
-                        error_log.as_mut()
-                    }
-                    GooseLog::Request(request_message) => {
-                        formatted_message = self.format_message(request_message).to_string();
-                        request_log.as_mut()
-                    }
-                    GooseLog::Task(task_message) => {
-                        formatted_message = self.format_message(task_message).to_string();
-                        task_log.as_mut()
-                    }
-                } {
-                    // @TODO: error handling when writing to log fails.
-                    let _ = self.write_to_log_file(log_file, formatted_message).await;
+    /// The stamped CSV header starting each file of a log, if the log is a CSV.
+    fn csv_header(&self, log_name: &str) -> Option<String> {
+        let (format, header): (&Option<GooseLogFormat>, fn() -> String) = match log_name {
+            "debug_log" => (&self.debug_format, debug_csv_header),
+            "error_log" => (&self.error_format, error_csv_header),
+            "request_log" => (&self.request_format, requests_csv_header),
+            _ => (&self.task_format, tasks_csv_header),
+        };
+        if format == &Some(GooseLogFormat::Csv) {
+            Some(self.stamp_csv_header(header()))
+        } else {
+            None
+        }
+    }
+
+    /// Writer task for a single log, formatting and writing the messages routed to it so
+    /// a slow log doesn't hold up the others, and formatting is spread across threads.
+    /// Returns the log's path and how many times writing to it failed.
+    async fn log_writer_main(
+        self: GooseConfiguration,
+        log_name: &str,
+        log_file_path: String,
+        mut log: Box<dyn GooseLogSink>,
+        receiver: flume::Receiver<GooseLog>,
+        canceled: Arc<AtomicBool>,
+    ) -> (String, usize) {
+        let mut failures: usize = 0;
+        // With `--log-failure stderr`, messages are written to stderr after the first failure.
+        let mut to_stderr = false;
+        // Each message is formatted into the same buffer, so once it has grown to fit the
+        // messages being logged, formatting them doesn't allocate.
+        let mut buffer = Vec::with_capacity(LOG_BUFFER_CAPACITY);
+        while let Ok(message) = receiver.recv_async().await {
+            buffer.clear();
+            self.format_log(&message, &mut buffer);
+            // Nothing is written for messages left out of the log.
+            if buffer.is_empty() {
+                continue;
+            }
+            if to_stderr {
+                eprintln!("{}", String::from_utf8_lossy(&buffer));
+            } else if let Err(e) = log.write(&message, &buffer).await {
+                if failures == 0 {
+                    self.handle_log_failure(log_name, &log_file_path, &e, &canceled);
+                }
+                failures += 1;
+                if self.log_failure == Some(GooseLogFailure::Stderr) {
+                    to_stderr = true;
+                    eprintln!("{}", String::from_utf8_lossy(&buffer));
                 }
-            } else {
-                // Empty message means it's time to exit.
-                break;
             }
+            // Release memory grown to fit an unusually large message, such as a debug log
+            // message with a large response body.
+            buffer.shrink_to(LOG_BUFFER_CAPACITY);
         }
 
-        // Flush debug logs to disk if enabled.
-        if let Some(debug_log_file) = debug_log.as_mut() {
-            info!("flushing debug_log: {}", &self.debug_log);
-            let _ = debug_log_file.flush().await;
-        };
-
-        // Flush requests log to disk if enabled.
-        if let Some(requests_log_file) = request_log.as_mut() {
-            info!("flushing request_log: {}", &self.request_log);
-            let _ = requests_log_file.flush().await;
+        // Flush the log to disk.
+        info!("flushing {}: {}", log_name, log_file_path);
+        if let Err(e) = log.flush().await {
+            if failures == 0 {
+                self.handle_log_failure(log_name, &log_file_path, &e, &canceled);
+            }
+            failures += 1;
         }
 
-        // Flush tasks log to disk if enabled.
-        if let Some(tasks_log_file) = task_log.as_mut() {
-            info!("flushing task_log: {}", &self.task_log);
-            let _ = tasks_log_file.flush().await;
+        (log_file_path, failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goose::GooseMethod;
+    use crate::metrics::{GooseRawRequest, GooseTransportError};
+
+    use gumdrop::Options;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    #[test]
+    fn status_codes() {
+        assert_eq!(parse_status_codes(""), Some(vec![]));
+        assert_eq!(parse_status_codes("500"), Some(vec![500]));
+        assert_eq!(parse_status_codes("500, 503,"), Some(vec![500, 503]));
+        assert_eq!(parse_status_codes("5xx"), None);
+        assert_eq!(parse_status_codes("500,42"), None);
+    }
+
+    #[test]
+    fn header_names() {
+        assert_eq!(parse_header_names(""), Some(vec![]));
+        assert_eq!(
+            parse_header_names("Authorization, x-api-key,"),
+            Some(vec!["authorization".to_string(), "x-api-key".to_string()])
+        );
+        assert_eq!(parse_header_names("bad header"), None);
+    }
+
+    #[test]
+    fn redacted_headers() {
+        let defaults = vec![
+            "authorization".to_string(),
+            "cookie".to_string(),
+            "set-cookie".to_string(),
+        ];
+        assert_eq!(redacted_header_names("", false), defaults);
+        // Configured headers are masked in addition to the defaults.
+        let mut redacted = defaults.clone();
+        redacted.push("x-api-key".to_string());
+        assert_eq!(redacted_header_names("X-Api-Key,cookie", false), redacted);
+        assert!(redacted_header_names("x-api-key", true).is_empty());
+    }
+
+    #[test]
+    fn redact_request_headers() {
+        let headers = vec![
+            format!("{:?}", ("authorization", "Bearer secret")),
+            format!("{:?}", ("user-agent", "goose")),
+        ];
+        let raw = GooseRawRequest::new(GooseMethod::Get, "/", headers, "");
+        let mut message = GooseLog::Request(GooseRequestMetric::new(raw, "/", 0, 0));
+        let redacted = parse_header_names("Authorization").unwrap();
+        redact_headers(&mut message, &redacted);
+        match message {
+            GooseLog::Request(request) => assert_eq!(
+                request.raw.headers,
+                vec![
+                    "(\"authorization\", \"[REDACTED]\")".to_string(),
+                    "(\"user-agent\", \"goose\")".to_string(),
+                ]
+            ),
+            _ => unreachable!(),
         }
+    }
 
-        // Flush error logs to disk if enabled.
-        if let Some(error_log_file) = error_log.as_mut() {
-            info!("flushing error_log: {}", &self.error_log);
-            let _ = error_log_file.flush().await;
+    #[test]
+    fn writer_overflow() {
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let (tx, rx) = flume::bounded(1);
+        let mut writer = GooseLogWriter {
+            log_name: "task_log",
+            log_file_path: "tasks.log".to_string(),
+            tx,
+            overflows: 0,
         };
+        let message = || GooseLog::Task(GooseTaskMetric::new(1500, 0, 2, "front".to_string(), 3));
+        let canceled = AtomicBool::new(false);
+        let mut buffer = Vec::new();
 
-        Ok(())
+        configuration.send_to_writer(&mut writer, message(), &mut buffer, &canceled);
+        assert_eq!(writer.overflows, 0);
+
+        // Messages are dropped by default while the writer's channel is full.
+        configuration.send_to_writer(&mut writer, message(), &mut buffer, &canceled);
+        assert_eq!(writer.overflows, 1);
+        assert_eq!(rx.len(), 1);
+        assert!(!canceled.load(Ordering::SeqCst));
+
+        // With `--log-failure abort` the load test is stopped.
+        configuration.log_failure = Some(GooseLogFailure::Abort);
+        let mut writer = GooseLogWriter {
+            overflows: 0,
+            ..writer
+        };
+        configuration.send_to_writer(&mut writer, message(), &mut buffer, &canceled);
+        assert_eq!(writer.overflows, 1);
+        assert!(canceled.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn format_task_message() {
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let message = GooseTaskMetric::new(1500, 0, 2, "load \"front\" page".to_string(), 3);
+        let mut buffer = Vec::new();
+
+        // JSON is serialized directly into the buffer, and parses as the same message.
+        configuration.task_format = Some(GooseLogFormat::Json);
+        configuration.format_message(&message, &mut buffer);
+        let value: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        assert_eq!(value, serde_json::to_value(&message).unwrap());
+
+        // The run ID is added as another field.
+        configuration.run_id = "nightly".to_string();
+        buffer.clear();
+        configuration.format_message(&message, &mut buffer);
+        let value: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        assert_eq!(value["run_id"], "nightly");
+        assert_eq!(value["name"], "load \"front\" page");
+
+        // Other formats are also written to the buffer.
+        configuration.task_format = Some(GooseLogFormat::Raw);
+        buffer.clear();
+        configuration.format_message(&message, &mut buffer);
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            format!("[nightly] {:?}", message)
+        );
+        configuration.task_format = Some(GooseLogFormat::Csv);
+        buffer.clear();
+        configuration.format_message(&message, &mut buffer);
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "1500,0,2,\"load \"\"front\"\" page\",0,true,3,\"nightly\""
+        );
+    }
+
+    #[test]
+    fn format_request_csv() {
+        let raw = GooseRawRequest::new(
+            GooseMethod::Post,
+            "http://example.com/login",
+            vec![],
+            "name=\"goose\"",
+        );
+        let mut request = GooseRequestMetric::new(raw, "log in, \"quickly\"", 1500, 3);
+        request.error = "500: server error, \"retry\"".to_string();
+
+        // Quotes in strings are escaped, and the raw request is serialized as JSON.
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        assert_eq!(
+            configuration.prepare_csv(&request),
+            concat!(
+                "1500,",
+                "\"{\"\"method\"\":\"\"Post\"\",\"\"url\"\":\"\"http://example.com/login\"\",",
+                "\"\"headers\"\":[],\"\"body\"\":\"\"name=\\\"\"goose\\\"\"\"\"}\",",
+                "\"log in, \"\"quickly\"\"\",\"\",false,0,0,true,false,3,",
+                "\"500: server error, \"\"retry\"\"\",0,0,,false,\"\",false,\"{}\",,,,,,\"\""
+            )
+        );
+
+        // Optional values are included when recorded, and dimensions serialized as JSON.
+        request.response_time_budget = Some(250);
+        request.slow = true;
+        request.backend = Some("web-1".to_string());
+        request
+            .dimensions
+            .insert("device".to_string(), "mobile".to_string());
+        request.body_size = Some(512);
+        request.decompressed_body_size = Some(2048);
+        request.integrity = Some(true);
+        request.schema_valid = Some(false);
+        request.cache_hit = Some(true);
+        request.transport_error = Some(GooseTransportError::Timeout);
+        assert!(configuration.prepare_csv(&request).ends_with(concat!(
+            ",0,0,250,true,\"web-1\",false,\"{\"\"device\"\":\"\"mobile\"\"}\",",
+            "512,2048,true,false,true,\"timeout\""
+        )));
+    }
+
+    #[test]
+    fn format_request_combined() {
+        let raw = GooseRawRequest::new(
+            GooseMethod::Get,
+            "http://example.com/search?q=goose",
+            vec!["(\"referer\", \"http://example.com/\")".to_string()],
+            "",
+        );
+        let mut request = GooseRequestMetric::new(raw, "search", 1500, 3);
+        request.status_code = 200;
+        let started = DateTime::parse_from_rfc3339("2021-11-02T10:15:32+01:00").unwrap();
+        assert_eq!(
+            prepare_combined(&request, started),
+            "127.0.0.1 - 3 [02/Nov/2021:10:15:32 +0100] \"GET /search?q=goose HTTP/1.1\" 200 - \"http://example.com/\" \"-\""
+        );
+        request.body_size = Some(1024);
+        assert!(prepare_combined(&request, started).contains(" 200 1024 "));
+
+        // Updates to earlier requests aren't logged again.
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.request_format = Some(GooseLogFormat::Combined);
+        let mut buffer = Vec::new();
+        configuration.format_message(&request, &mut buffer);
+        assert!(!buffer.is_empty());
+        request.update = true;
+        buffer.clear();
+        configuration.format_message(&request, &mut buffer);
+        assert!(buffer.is_empty());
     }
 }
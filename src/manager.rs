@@ -1,10 +1,12 @@
 use lazy_static::lazy_static;
+#[cfg(feature = "gaggle-tls")]
+use nng::options::{transport::tls::CertKeyFile, Options};
 use nng::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::io::BufWriter;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time;
 
 use crate::metrics::{
@@ -12,10 +14,11 @@ use crate::metrics::{
     GooseRequestMetrics, GooseTaskMetricAggregate, GooseTaskMetrics,
 };
 use crate::util;
-use crate::worker::GaggleMetrics;
+use crate::worker::{GaggleMetrics, GaggleWorkerInit};
 use crate::{GooseAttack, GooseConfiguration, GooseUserCommand};
 
-/// How long the manager will wait for all workers to stop after the load test ends.
+/// How long the manager will wait for all workers to stop and push their final metrics
+/// after the load test ends.
 const GRACEFUL_SHUTDOWN_TIMEOUT: usize = 30;
 
 /// All elements required to initialize a user in a worker process.
@@ -33,11 +36,34 @@ pub struct GooseUserInitializer {
     pub worker_id: usize,
 }
 
+/// A Worker connected to the Manager.
+struct GaggleWorker {
+    /// Numerical identifier for worker, in the order it connected.
+    id: usize,
+    /// When the Worker last pushed metrics or a heartbeat.
+    heartbeat: time::Instant,
+    /// The users the Worker is running.
+    users: Vec<GooseUserInitializer>,
+    /// Users moved to the Worker from lost Workers, not yet sent to it.
+    redistributed_users: Vec<GooseUserInitializer>,
+    /// Whether the Worker was lost, because its connection closed or it stopped sending
+    /// heartbeats.
+    lost: bool,
+    /// Whether the Worker stopped and pushed its final metrics.
+    finished: bool,
+}
+
 // Mutable singleton globally tracking how many workers are currently being managed.
 lazy_static! {
     static ref ACTIVE_WORKERS: AtomicUsize = AtomicUsize::new(0);
 }
 
+// Mutable singleton globally tracking worker pipes that closed, until the manager checks
+// for lost workers.
+lazy_static! {
+    static ref CLOSED_PIPES: Mutex<Vec<Pipe>> = Mutex::new(Vec::new());
+}
+
 fn distribute_users(goose_attack: &GooseAttack) -> (usize, usize) {
     // Users and expect_workers is required to get here, so unwrap() is safe.
     let users_per_worker = goose_attack.configuration.users.unwrap()
@@ -55,7 +81,7 @@ fn distribute_users(goose_attack: &GooseAttack) -> (usize, usize) {
     (users_per_worker, users_remainder)
 }
 
-fn pipe_closed(_pipe: Pipe, event: PipeEvent) {
+fn pipe_closed(pipe: Pipe, event: PipeEvent) {
     match event {
         PipeEvent::AddPost => {
             debug!("worker pipe added");
@@ -64,11 +90,61 @@ fn pipe_closed(_pipe: Pipe, event: PipeEvent) {
         PipeEvent::RemovePost => {
             let active_workers = ACTIVE_WORKERS.fetch_sub(1, Ordering::SeqCst);
             info!("worker {} exited", active_workers);
+            CLOSED_PIPES.lock().unwrap().push(pipe);
         }
         _ => {}
     }
 }
 
+/// Find Workers lost since last checked, because their connection closed or they haven't
+/// pushed metrics or a heartbeat for `worker_timeout` seconds. Workers that already pushed
+/// their final metrics aren't lost.
+fn find_lost_workers(
+    workers: &mut HashMap<Pipe, GaggleWorker>,
+    worker_timeout: usize,
+) -> Vec<Pipe> {
+    let closed_pipes: Vec<Pipe> = CLOSED_PIPES.lock().unwrap().drain(..).collect();
+    let mut lost_workers = Vec::new();
+    for (pipe, worker) in workers.iter_mut() {
+        if worker.lost || worker.finished {
+            continue;
+        }
+        if closed_pipes.contains(pipe) || util::timer_expired(worker.heartbeat, worker_timeout) {
+            worker.lost = true;
+            lost_workers.push(*pipe);
+        }
+    }
+    lost_workers
+}
+
+/// Move the users of a lost Worker to the remaining Workers, in turn. Returns false if
+/// there are no remaining Workers.
+fn redistribute_users(
+    workers: &mut HashMap<Pipe, GaggleWorker>,
+    users: Vec<GooseUserInitializer>,
+) -> bool {
+    let mut remaining_workers: Vec<&mut GaggleWorker> = workers
+        .values_mut()
+        .filter(|worker| !worker.lost && !worker.finished)
+        .collect();
+    if remaining_workers.is_empty() {
+        return false;
+    }
+    remaining_workers.sort_by_key(|worker| worker.id);
+    info!(
+        "redistributing {} users to {} remaining workers",
+        users.len(),
+        remaining_workers.len()
+    );
+    let remaining = remaining_workers.len();
+    for (index, user) in users.into_iter().enumerate() {
+        remaining_workers[index % remaining]
+            .redistributed_users
+            .push(user);
+    }
+    true
+}
+
 /// Merge per-user task metrics from user thread into global parent metrics
 fn merge_tasks_from_worker(
     parent_task: &GooseTaskMetricAggregate,
@@ -101,29 +177,56 @@ fn merge_requests_from_worker(
 ) -> GooseRequestMetricAggregate {
     // Make a mutable copy where we can merge things
     let mut merged_request = parent_request.clone();
-    // Iterate over user response times, and merge into global response time
-    merged_request.raw_data.times = metrics::merge_times(
-        merged_request.raw_data.times,
-        user_request.raw_data.times.clone(),
-    );
-    // Increment total response time counter.
-    merged_request.raw_data.total_time += &user_request.raw_data.total_time;
-    // Increment count of how many response counters we've seen.
-    merged_request.raw_data.counter += &user_request.raw_data.counter;
-    // If user had new fastest response time, update global fastest response time.
-    merged_request.raw_data.minimum_time = metrics::update_min_time(
-        merged_request.raw_data.minimum_time,
-        user_request.raw_data.minimum_time,
-    );
-    // If user had new slowest response time, update global slowest response time.
-    merged_request.raw_data.maximum_time = metrics::update_max_time(
-        merged_request.raw_data.maximum_time,
-        user_request.raw_data.maximum_time,
-    );
+    // Merge user response times into global response times.
+    merged_request.raw_data.merge(&user_request.raw_data);
     // Increment total success counter.
     merged_request.success_count += &user_request.success_count;
     // Increment total fail counter.
     merged_request.fail_count += &user_request.fail_count;
+    // Increment total slow counter.
+    merged_request.slow_count += &user_request.slow_count;
+    if user_request.response_time_budget.is_some() {
+        merged_request.response_time_budget = user_request.response_time_budget;
+    }
+    // Increment per-backend counters and the total affinity violation counter.
+    for (backend, count) in &user_request.backends {
+        *merged_request
+            .backends
+            .entry(backend.to_string())
+            .or_insert(0) += count;
+    }
+    merged_request.affinity_violations += &user_request.affinity_violations;
+    // Increment response body size counters.
+    merged_request.body_count += &user_request.body_count;
+    merged_request.body_bytes += &user_request.body_bytes;
+    merged_request.decompressed_body_bytes += &user_request.decompressed_body_bytes;
+    // Increment response body integrity counters.
+    merged_request.integrity_checks += &user_request.integrity_checks;
+    merged_request.integrity_failures += &user_request.integrity_failures;
+    // Increment JSON Schema validation counters.
+    merged_request.schema_checks += &user_request.schema_checks;
+    merged_request.schema_violations += &user_request.schema_violations;
+    // Increment write conflict counters.
+    merged_request.conflicts += &user_request.conflicts;
+    merged_request.precondition_failures += &user_request.precondition_failures;
+    // Merge response times grouped by response header values.
+    for (dimension, user_data) in &user_request.dimensions {
+        let merged_data = merged_request
+            .dimensions
+            .entry(dimension.to_string())
+            .or_insert_with(|| metrics::GooseRequestMetricTimingData::new(None));
+        merge_timing_data(merged_data, user_data);
+    }
+    // Merge response times of responses served by a cache and by the origin.
+    merge_timing_data(&mut merged_request.cache_data, &user_request.cache_data);
+    merge_timing_data(&mut merged_request.origin_data, &user_request.origin_data);
+    // Increment per-kind transport error counters.
+    for (transport_error, count) in &user_request.transport_errors {
+        *merged_request
+            .transport_errors
+            .entry(*transport_error)
+            .or_insert(0) += count;
+    }
     // Only accrue overhead of merging status_code_counts if we're going to display the results
     if status_codes {
         for (status_code, count) in &user_request.status_code_counts {
@@ -146,6 +249,14 @@ fn merge_requests_from_worker(
     merged_request
 }
 
+/// Merge per-user response times into global response times
+fn merge_timing_data(
+    merged_data: &mut metrics::GooseRequestMetricTimingData,
+    user_data: &metrics::GooseRequestMetricTimingData,
+) {
+    merged_data.merge(user_data);
+}
+
 /// Merge per-Worker errors into global Manager metrics
 fn merge_errors_from_worker(
     manager_error: &GooseErrorMetricAggregate,
@@ -191,9 +302,14 @@ fn send_message_to_worker(server: &Socket, message: Message) -> bool {
 fn merge_request_metrics(goose_attack: &mut GooseAttack, requests: GooseRequestMetrics) {
     if !requests.is_empty() {
         debug!("requests metrics received: {:?}", requests.len());
-        for (request_key, request) in requests {
+        for (request_key, mut request) in requests {
             trace!("request_key: {}", request_key);
-            let merged_request;
+            // Group requests together once too many distinct requests are tracked.
+            let (request_key, name) = goose_attack
+                .metrics
+                .request_key(&request.method_name(), &request.path);
+            request.path = name;
+            let mut merged_request;
             if let Some(parent_request) = goose_attack.metrics.requests.get(&request_key) {
                 merged_request = merge_requests_from_worker(
                     parent_request,
@@ -204,6 +320,9 @@ fn merge_request_metrics(goose_attack: &mut GooseAttack, requests: GooseRequestM
                 // First time seeing this request, simply insert it.
                 merged_request = request.clone();
             }
+            if goose_attack.configuration.no_histograms {
+                merged_request.discard_times();
+            }
             goose_attack
                 .metrics
                 .requests
@@ -216,10 +335,13 @@ fn merge_request_metrics(goose_attack: &mut GooseAttack, requests: GooseRequestM
 fn merge_task_metrics(goose_attack: &mut GooseAttack, tasks: GooseTaskMetrics) {
     for task_set in tasks {
         for task in task_set {
-            let merged_task = merge_tasks_from_worker(
+            let mut merged_task = merge_tasks_from_worker(
                 &goose_attack.metrics.tasks[task.taskset_index][task.task_index],
                 &task,
             );
+            if goose_attack.configuration.no_histograms {
+                merged_task.discard_times();
+            }
             goose_attack.metrics.tasks[task.taskset_index][task.task_index] = merged_task;
         }
     }
@@ -246,12 +368,54 @@ fn merge_error_metrics(goose_attack: &mut GooseAttack, errors: GooseErrorMetrics
     }
 }
 
+/// Listens for Workers over TLS, presenting the certificate and private key in
+/// `cert_key_file`.
+#[cfg(feature = "gaggle-tls")]
+fn listen_with_tls(server: &Socket, address: &str, cert_key_file: &str) {
+    let listener = ListenerBuilder::new(server, address)
+        .map_err(|error| eprintln!("{:?} (address = {})", error, address))
+        .expect("failed to create listener");
+    listener
+        .set_opt::<CertKeyFile>(cert_key_file.to_string())
+        .map_err(|error| eprintln!("{:?} (manager_tls_cert = {})", error, cert_key_file))
+        .expect("failed to load TLS certificate and key");
+    listener
+        .start()
+        .map_err(|(_, error)| eprintln!("{:?} (address = {})", error, address))
+        .expect("failed to bind to socket");
+}
+
+/// Configuration validation prevents TLS from being enabled without the `gaggle-tls`
+/// feature.
+#[cfg(not(feature = "gaggle-tls"))]
+fn listen_with_tls(_server: &Socket, _address: &str, _cert_key_file: &str) {
+    unreachable!("TLS requires the gaggle-tls feature");
+}
+
+/// Compares the pre-shared key a Worker authenticated with to the Manager's key in
+/// constant time, so the key can't be guessed by timing failed attempts.
+fn gaggle_key_matches(expected: &str, received: &str) -> bool {
+    expected.len() == received.len()
+        && expected
+            .bytes()
+            .zip(received.bytes())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
 /// Main manager loop.
 pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
-    // Creates a TCP address.
+    // Creates a TCP address, secured with TLS if a certificate is configured.
+    let scheme = if goose_attack.configuration.manager_tls_cert.is_empty() {
+        "tcp"
+    } else {
+        "tls+tcp"
+    };
     let address = format!(
-        "tcp://{}:{}",
-        goose_attack.configuration.manager_bind_host, goose_attack.configuration.manager_bind_port
+        "{}://{}:{}",
+        scheme,
+        goose_attack.configuration.manager_bind_host,
+        goose_attack.configuration.manager_bind_port
     );
     debug!("preparing to listen for workers at: {}", &address);
 
@@ -267,10 +431,18 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
         .expect("failed to set up pipe handler");
 
     // Listen for connections.
-    server
-        .listen(&address)
-        .map_err(|error| eprintln!("{:?} (address = {})", error, address))
-        .expect("failed to bind to socket");
+    if goose_attack.configuration.manager_tls_cert.is_empty() {
+        server
+            .listen(&address)
+            .map_err(|error| eprintln!("{:?} (address = {})", error, address))
+            .expect("failed to bind to socket");
+    } else {
+        listen_with_tls(
+            &server,
+            &address,
+            &goose_attack.configuration.manager_tls_cert,
+        );
+    }
 
     // Expect workers is reqiured so unwrap() is safe.
     info!(
@@ -285,8 +457,8 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
     // A mutable bucket of users to be assigned to workers.
     let mut available_users = goose_attack.weighted_gaggle_users.clone();
 
-    // Track how many workers we've seen.
-    let mut workers: HashSet<Pipe> = HashSet::new();
+    // Track the workers we've seen.
+    let mut workers: HashMap<Pipe, GaggleWorker> = HashMap::new();
 
     // Track start time, we'll reset this when the test actually starts.
     let mut started = time::Instant::now();
@@ -322,26 +494,64 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
 
     // Worker control loop.
     loop {
-        // While running load test, check if any workers go away.
-        if !load_test_finished {
-            // If ACTIVE_WORKERS is less than the total workers seen, a worker went away.
-            if ACTIVE_WORKERS.load(Ordering::SeqCst) < workers.len() {
-                // If worked goes away during load test, exit gracefully.
-                if load_test_running {
-                    info!(
-                        "worker went away, stopping gracefully after {} seconds...",
-                        started.elapsed().as_secs()
-                    );
-                    load_test_finished = true;
-                    exit_timer = time::Instant::now();
-                }
-                // If a worker goes away during start up, exit immediately.
-                else {
-                    warn!("worker went away, stopping immediately...");
-                    break;
+        // While running load test, check if any workers go away, also while stopping.
+        if load_test_running {
+            // While stopping, workers don't push heartbeats while flushing their logs, so
+            // they're only lost if their connection closes until the graceful shutdown
+            // timer expires. Worker timeout is always set on the Manager, so unwrap() is
+            // safe.
+            let worker_timeout = if load_test_finished {
+                0
+            } else {
+                goose_attack.configuration.worker_timeout.unwrap()
+            };
+            for pipe in find_lost_workers(&mut workers, worker_timeout) {
+                // The metrics the lost worker didn't push yet are missing.
+                goose_attack.metrics.lost_workers += 1;
+                let worker = workers.get_mut(&pipe).unwrap();
+                let mut users = std::mem::take(&mut worker.users);
+                users.append(&mut worker.redistributed_users);
+                warn!(
+                    "worker {} running {} users lost after {} seconds, metrics will be partial",
+                    worker.id,
+                    users.len(),
+                    started.elapsed().as_secs()
+                );
+                // Optionally keep running, with the remaining workers running the lost
+                // users.
+                if load_test_finished
+                    || (goose_attack.configuration.redistribute_users
+                        && redistribute_users(&mut workers, users))
+                {
+                    continue;
                 }
+                // Otherwise exit gracefully.
+                info!(
+                    "stopping gracefully after {} seconds...",
+                    started.elapsed().as_secs()
+                );
+                goose_attack.metrics.duration =
+                    goose_attack.started.unwrap().elapsed().as_secs() as usize;
+                load_test_finished = true;
+                exit_timer = time::Instant::now();
+            }
+
+            // All workers stopped and pushed their final metrics, or were lost.
+            if load_test_finished
+                && workers
+                    .values()
+                    .all(|worker| worker.finished || worker.lost)
+            {
+                info!("all workers have stopped");
+                break;
             }
         }
+        // If ACTIVE_WORKERS is less than the total workers seen, a worker went away
+        // during start up, exit immediately.
+        else if ACTIVE_WORKERS.load(Ordering::SeqCst) < workers.len() {
+            warn!("worker went away, stopping immediately...");
+            break;
+        }
         if load_test_running {
             if !load_test_finished {
                 // Test ran to completion or was canceled with ctrl-c.
@@ -356,9 +566,18 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                 }
             }
 
-            // Aborting graceful shutdown, workers took too long to shut down.
+            // Aborting graceful shutdown, workers took too long to shut down. Their final
+            // metrics are missing, so they're lost.
             if load_test_finished && util::timer_expired(exit_timer, GRACEFUL_SHUTDOWN_TIMEOUT) {
                 warn!("graceful shutdown timer expired, exiting...");
+                for worker in workers
+                    .values_mut()
+                    .filter(|worker| !worker.finished && !worker.lost)
+                {
+                    warn!("worker {} didn't stop, metrics will be partial", worker.id);
+                    worker.lost = true;
+                    goose_attack.metrics.lost_workers += 1;
+                }
                 break;
             }
 
@@ -383,12 +602,8 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                 // Message received, grab the pipe to determine which worker it is.
                 let pipe = msg.pipe().expect("fatal error getting worker pipe");
 
-                // Workers always send a vector of GooseMetric objects.
-                let mut gaggle_metrics: Vec<GaggleMetrics> =
-                    serde_cbor::from_reader(msg.as_slice()).unwrap();
-
                 // Check if we're seeing this worker for the first time.
-                if !workers.contains(&pipe) {
+                if !workers.contains_key(&pipe) {
                     // Check if we are expecting another worker. Expect workers is required
                     // so unwrap() is safe.
                     if workers.len() >= goose_attack.configuration.expect_workers.unwrap() as usize
@@ -408,39 +623,61 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                     }
                     // We need another worker, accept the connection.
                     else {
-                        // New worker has to send us a single
-                        // GaggleMetrics::WorkerInit object or it's invalid.
-                        if gaggle_metrics.len() != 1 {
-                            warn!("invalid message from Worker, exiting load test");
-                            // Invalid message, tell worker to GooseUserCommand::Exit.
+                        // New worker has to send us a GaggleWorkerInit object, or it's
+                        // invalid.
+                        let worker_init: GaggleWorkerInit =
+                            match serde_cbor::from_reader(msg.as_slice()) {
+                                Ok(worker_init) => worker_init,
+                                Err(e) => {
+                                    warn!(
+                                        "invalid message from Worker ({}), telling it to exit",
+                                        e
+                                    );
+                                    // Invalid message, tell worker to GooseUserCommand::Exit.
+                                    if !tell_worker_to_exit(&server) {
+                                        // All workers have exited, shut down the
+                                        // load test.
+                                        break;
+                                    }
+                                    continue;
+                                }
+                            };
+
+                        // Workers must authenticate with the Manager's pre-shared key, if set.
+                        if !goose_attack.configuration.gaggle_key.is_empty()
+                            && !gaggle_key_matches(
+                                &goose_attack.configuration.gaggle_key,
+                                &worker_init.key,
+                            )
+                        {
+                            warn!("worker failed to authenticate with the gaggle key, telling it to exit");
                             if !tell_worker_to_exit(&server) {
                                 // All workers have exited, shut down the
                                 // load test.
                                 break;
                             }
+                            continue;
                         }
 
-                        let goose_metric = gaggle_metrics.pop().unwrap();
-                        if let GaggleMetrics::WorkerInit(load_test_hash) = goose_metric {
-                            if load_test_hash != goose_attack.metrics.hash {
-                                if goose_attack.configuration.no_hash_check {
-                                    warn!("worker is running a different load test, ignoring");
-                                } else {
-                                    panic!("worker is running a different load test, set --no-hash-check to ignore");
-                                }
-                            }
-                        } else {
-                            // Unexpected object received, tell the worker
-                            // to GooseUserCommand::Exit.
-                            warn!("invalid object from Worker, exiting load test");
-                            if !tell_worker_to_exit(&server) {
-                                // All workers have exited, shut down the
-                                // load test.
-                                break;
+                        if worker_init.hash != goose_attack.metrics.hash {
+                            if goose_attack.configuration.no_hash_check {
+                                warn!("worker is running a different load test, ignoring");
+                            } else {
+                                panic!("worker is running a different load test, set --no-hash-check to ignore");
                             }
                         }
 
-                        workers.insert(pipe);
+                        workers.insert(
+                            pipe,
+                            GaggleWorker {
+                                id: workers.len() + 1,
+                                heartbeat: time::Instant::now(),
+                                users: Vec::new(),
+                                redistributed_users: Vec::new(),
+                                lost: false,
+                                finished: false,
+                            },
+                        );
                         // Expect workers is required so unwrap() is safe.
                         info!(
                             "worker {} of {} connected",
@@ -485,6 +722,8 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                             .expect("failed to serialize user initializers");
 
                         info!("sending {} users to worker {}", users.len(), workers.len());
+                        // Remember the users, to redistribute them if the worker is lost.
+                        workers.get_mut(&pipe).unwrap().users = users;
                         if !send_message_to_worker(
                             &server,
                             message
@@ -514,8 +753,28 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                 }
                 // Received message from known Worker.
                 else {
+                    // Known workers always send a vector of GaggleMetrics objects.
+                    let gaggle_metrics: Vec<GaggleMetrics> =
+                        match serde_cbor::from_reader(msg.as_slice()) {
+                            Ok(gaggle_metrics) => gaggle_metrics,
+                            Err(e) => {
+                                warn!("invalid message from Worker ({}), telling it to exit", e);
+                                // Invalid message, tell worker to GooseUserCommand::Exit.
+                                if !tell_worker_to_exit(&server) {
+                                    // All workers have exited, shut down the
+                                    // load test.
+                                    break;
+                                }
+                                continue;
+                            }
+                        };
+
                     let mut message = Message::new();
 
+                    // Any message from a Worker shows it's still running.
+                    let worker = workers.get_mut(&pipe).unwrap();
+                    worker.heartbeat = time::Instant::now();
+
                     // When starting a Gaggle, some Workers may start before others and
                     // will send regular heartbeats to the Manager to confirm the load
                     // test is still waiting to start.
@@ -545,17 +804,46 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
                             GaggleMetrics::Errors(errors) => {
                                 merge_error_metrics(&mut goose_attack, errors)
                             }
+                            // The Worker stopped, if the load test is still running it
+                            // stopped on its own so the other Workers are stopped too.
+                            GaggleMetrics::Stopped(aborted) => {
+                                worker.finished = true;
+                                if !load_test_finished {
+                                    info!(
+                                        "worker {} stopped, stopping gracefully after {} seconds...",
+                                        worker.id,
+                                        started.elapsed().as_secs()
+                                    );
+                                    if aborted.is_some() {
+                                        goose_attack.metrics.aborted = aborted;
+                                    }
+                                    goose_attack.metrics.duration =
+                                        goose_attack.started.unwrap().elapsed().as_secs() as usize;
+                                    load_test_finished = true;
+                                    exit_timer = time::Instant::now();
+                                }
+                            }
                             // Ignore Worker heartbeats.
                             GaggleMetrics::WorkerInit(_) => (),
                         }
                     }
 
-                    if load_test_finished {
+                    // Lost workers were replaced, so are told to exit too.
+                    if load_test_finished || worker.lost {
                         debug!("telling worker to exit");
                         serde_cbor::to_writer(&mut message, &GooseUserCommand::Exit)
                             .map_err(|error| eprintln!("{:?}", error))
                             .expect("failed to serialize user command");
                     }
+                    // Send the worker any users moved to it from lost workers.
+                    else if !worker.redistributed_users.is_empty() {
+                        let mut users = std::mem::take(&mut worker.redistributed_users);
+                        info!("sending {} users to worker {}", users.len(), worker.id);
+                        serde_cbor::to_writer(&mut message, &users)
+                            .map_err(|error| eprintln!("{:?}", error))
+                            .expect("failed to serialize user initializers");
+                        worker.users.append(&mut users);
+                    }
                     // Notify the worker that the load test is still running.
                     else {
                         serde_cbor::to_writer(&mut message, &GooseUserCommand::Run)
@@ -585,6 +873,13 @@ pub(crate) async fn manager_main(mut goose_attack: GooseAttack) -> GooseAttack {
             }
         }
     }
+    if goose_attack.metrics.lost_workers > 0 {
+        warn!(
+            "{} workers were lost, metrics are partial",
+            goose_attack.metrics.lost_workers
+        );
+    }
+
     // Run any configured test_stop() functions.
     goose_attack.run_test_stop().await.unwrap();
 
@@ -622,4 +917,12 @@ mod tests {
         assert_eq!(users_per_process, 4);
         assert_eq!(users_remainder, 16);
     }
+
+    #[test]
+    fn test_gaggle_key_matches() {
+        assert!(gaggle_key_matches("secret", "secret"));
+        assert!(!gaggle_key_matches("secret", "secreT"));
+        assert!(!gaggle_key_matches("secret", "secret2"));
+        assert!(!gaggle_key_matches("secret", ""));
+    }
 }
@@ -17,18 +17,43 @@ use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
 use std::str::FromStr;
 use std::{f32, fmt};
 use tokio::io::AsyncWriteExt;
 
 use crate::config::GooseDefaults;
+use crate::export;
 use crate::goose::{get_base_url, GooseMethod, GooseTaskSet};
+#[cfg(feature = "hdr-histogram")]
+use crate::histogram::GooseHistogram;
+use crate::influxdb;
 use crate::logger::GooseLog;
+use crate::prometheus;
 use crate::report;
+use crate::socket::GooseSocketMethod;
+#[cfg(feature = "rustls-tls")]
+use crate::tls;
 use crate::util;
 #[cfg(feature = "gaggle")]
 use crate::worker::{self, GaggleMetrics};
-use crate::{AttackMode, GooseAttack, GooseAttackRunState, GooseConfiguration, GooseError};
+use crate::{
+    AttackMode, AttackPhase, GooseAttack, GooseAttackRunState, GooseConfiguration, GooseError,
+};
+
+/// The most distinct values of each `--header-dimensions` response header tracked per
+/// request, limiting memory use when a header has unexpectedly many values.
+const MAX_DIMENSION_VALUES: usize = 20;
+
+/// The most distinct requests tracked, limiting memory use when a load test makes requests
+/// with unexpectedly many names, such as dynamic paths that weren't given a name.
+const MAX_REQUEST_NAMES: usize = 1_000;
+
+/// The upper bound in milliseconds of each response time bucket of the response time
+/// heatmap, from fast to slow on a roughly logarithmic scale.
+const RESPONSE_TIME_HEATMAP_BUCKETS: [u64; 13] = [
+    1, 2, 5, 10, 20, 50, 100, 200, 500, 1_000, 2_000, 5_000, 10_000,
+];
 
 /// Used to send metrics from [`GooseUser`](../goose/struct.GooseUser.html) threads
 /// to the parent Goose process.
@@ -45,8 +70,34 @@ use crate::{AttackMode, GooseAttack, GooseAttackRunState, GooseConfiguration, Go
 /// can spend all their time generating and validating load.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GooseMetric {
-    Request(GooseRequestMetric),
+    Request(Box<GooseRequestMetric>),
+    Task(GooseTaskMetric),
+    /// A pass through a [`GooseTaskGroup`](../goose/struct.GooseTaskGroup.html), where
+    /// `task_index` is the index of the group.
+    Group(GooseTaskMetric),
+    /// A named annotation marking the running load test, sent with
+    /// [`GooseUser::annotate`](../goose/struct.GooseUser.html#method.annotate).
+    Annotation(GooseAnnotation),
+}
+
+/// Metric events streamed to an application embedding Goose.
+///
+/// Returned by the stream created with
+/// [`GooseAttack::metrics_stream`](../struct.GooseAttack.html#method.metrics_stream),
+/// so custom dashboards can follow a load test while it runs.
+#[derive(Debug, Clone)]
+pub enum GooseMetricEvent {
+    /// A request completed.
+    Request(Box<GooseRequestMetric>),
+    /// A task completed.
     Task(GooseTaskMetric),
+    /// A pass through a group of tasks completed, where `task_index` is the index of the
+    /// group.
+    Group(GooseTaskMetric),
+    /// A named annotation was recorded.
+    Annotation(GooseAnnotation),
+    /// A snapshot of all metrics aggregated so far, sent about once per second.
+    Interval(Box<GooseMetrics>),
 }
 
 /// THIS IS AN EXPERIMENTAL FEATURE, DISABLED BY DEFAULT. Optionally mitigate the loss of data
@@ -116,6 +167,58 @@ impl FromStr for GooseCoordinatedOmissionMitigation {
     }
 }
 
+/// What Goose prints to stdout while and after running a load test.
+///
+/// Running metrics flood logs when a load test runs unattended, for example in CI. Less
+/// verbose modes only print the final summary, only print the final metrics as JSON, or
+/// print nothing at all, leaving the exit code to report whether the load test ran.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum GooseOutput {
+    /// Print running metrics and status messages while the load test runs, and the final
+    /// metrics when it completes (default).
+    #[default]
+    Verbose,
+    /// Only print the final metrics when the load test completes.
+    Summary,
+    /// Only print the final metrics when the load test completes, as a single line of JSON.
+    Json,
+    /// Don't print anything.
+    Quiet,
+}
+/// Allow `--output` from the command line using text variations on supported
+/// `GooseOutput`s by implementing [`FromStr`].
+impl FromStr for GooseOutput {
+    type Err = GooseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Use a [`RegexSet`] to match string representations of `GooseOutput`,
+        // returning the appropriate enum value. Also match a wide range of abbreviations and synonyms.
+        let output = RegexSet::new([
+            r"(?i)^(verbose|full|all|normal)$",
+            r"(?i)^(summary|only-summary|only_summary)$",
+            r"(?i)^(json)$",
+            r"(?i)^(quiet|silent|none)$",
+        ])
+        .expect("failed to compile output RegexSet");
+        let matches = output.matches(s);
+        if matches.matched(0) {
+            Ok(GooseOutput::Verbose)
+        } else if matches.matched(1) {
+            Ok(GooseOutput::Summary)
+        } else if matches.matched(2) {
+            Ok(GooseOutput::Json)
+        } else if matches.matched(3) {
+            Ok(GooseOutput::Quiet)
+        } else {
+            Err(GooseError::InvalidOption {
+                option: format!("GooseOutput::{:?}", s),
+                value: s.to_string(),
+                detail: "Invalid output, expected: verbose, summary, json, or quiet".to_string(),
+            })
+        }
+    }
+}
+
 /// All requests made during a load test.
 ///
 /// Goose optionally tracks metrics about requests made during a load test. The
@@ -265,6 +368,9 @@ pub struct GooseRawRequest {
     pub headers: Vec<String>,
     /// The body of the request made, if `--request-body` is enabled.
     pub body: String,
+    /// The protocol of a raw socket request, which has no HTTP method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<GooseSocketMethod>,
 }
 impl GooseRawRequest {
     pub(crate) fn new(
@@ -278,8 +384,23 @@ impl GooseRawRequest {
             url: url.to_string(),
             headers,
             body: body.to_string(),
+            socket: None,
         }
     }
+
+    /// The method of the request as displayed in metrics, such as `GET`, or the protocol
+    /// of a raw socket request, such as `TCP`.
+    pub fn method_name(&self) -> String {
+        method_name(&self.method, self.socket)
+    }
+}
+
+// The HTTP method of a request, or the protocol of a raw socket request.
+fn method_name(method: &GooseMethod, socket: Option<GooseSocketMethod>) -> String {
+    match socket {
+        Some(socket) => socket.to_string(),
+        None => method.to_string(),
+    }
 }
 
 /// For tracking and counting requests made during a load test.
@@ -320,6 +441,44 @@ pub struct GooseRequestMetric {
     /// [`GooseTask`](../goose/struct.GooseTask.html)s by this
     /// [`GooseUser`](../goose/struct.GooseUser.html) thread.
     pub user_cadence: u64,
+    /// The optional response time budget in milliseconds, configured with
+    /// [`GooseRequestBuilder::expect_response_time`](../goose/struct.GooseRequestBuilder.html#method.expect_response_time).
+    pub response_time_budget: Option<u64>,
+    /// Whether or not the response took longer than the response time budget.
+    pub slow: bool,
+    /// The backend that served the response, read from the `--affinity-header` response
+    /// header if enabled.
+    pub backend: Option<String>,
+    /// Whether or not a different backend served this user's previous response.
+    pub affinity_violation: bool,
+    /// The values of the response headers configured with `--header-dimensions`, keyed
+    /// by lowercase header name. Headers missing from the response have a value of
+    /// `(none)`. The user's device profile, if assigned with `--device-profiles`, is
+    /// keyed as `device`.
+    pub dimensions: BTreeMap<String, String>,
+    /// The size in bytes of the response body as received, which may be compressed, if
+    /// tracking body sizes with `--body-sizes`.
+    pub body_size: Option<usize>,
+    /// The size in bytes of the response body after decompression, if tracking body sizes
+    /// with `--body-sizes`. Bodies that aren't compressed with gzip or deflate are the
+    /// same size as `body_size`.
+    pub decompressed_body_size: Option<usize>,
+    /// Whether or not the response body matched the checksum and length expected with
+    /// [`GooseRequestBuilder::expect_body_sha1`](../goose/struct.GooseRequestBuilder.html#method.expect_body_sha1)
+    /// and [`GooseRequestBuilder::expect_body_length`](../goose/struct.GooseRequestBuilder.html#method.expect_body_length),
+    /// if verified.
+    pub integrity: Option<bool>,
+    /// Whether or not the response body matched the JSON Schema registered for this
+    /// request name with
+    /// [`GooseAttack::register_json_schema`](../struct.GooseAttack.html#method.register_json_schema),
+    /// if validated.
+    pub schema_valid: Option<bool>,
+    /// Whether or not the response was served by an intermediary cache rather than the
+    /// origin, if tracked with `--cache-status`.
+    pub cache_hit: Option<bool>,
+    /// The kind of transport-level failure that prevented the request from getting a
+    /// response, if any.
+    pub transport_error: Option<GooseTransportError>,
 }
 impl GooseRequestMetric {
     pub(crate) fn new(raw: GooseRawRequest, name: &str, elapsed: u128, user: usize) -> Self {
@@ -337,6 +496,17 @@ impl GooseRequestMetric {
             error: "".to_string(),
             coordinated_omission_elapsed: 0,
             user_cadence: 0,
+            response_time_budget: None,
+            slow: false,
+            backend: None,
+            affinity_violation: false,
+            dimensions: BTreeMap::new(),
+            body_size: None,
+            decompressed_body_size: None,
+            integrity: None,
+            schema_valid: None,
+            cache_hit: None,
+            transport_error: None,
         }
     }
 
@@ -353,6 +523,15 @@ impl GooseRequestMetric {
         self.response_time = response_time as u64;
     }
 
+    // Record the response time budget, and whether the response exceeded it.
+    pub(crate) fn set_response_time_budget(&mut self, response_time_budget: Option<u64>) {
+        self.response_time_budget = response_time_budget;
+        self.slow = match response_time_budget {
+            Some(budget) => self.response_time > budget,
+            None => false,
+        };
+    }
+
     // Record the returned `status_code`.
     pub(crate) fn set_status_code(&mut self, status_code: Option<StatusCode>) {
         self.status_code = match status_code {
@@ -362,6 +541,110 @@ impl GooseRequestMetric {
     }
 }
 
+/// The kind of transport-level failure that prevented a request from getting a response.
+///
+/// Each kind calls for a different investigation, so failures are counted by kind in
+/// [`GooseRequestMetricAggregate::transport_errors`] rather than only as failed requests.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum GooseTransportError {
+    /// The host name couldn't be resolved.
+    Dns,
+    /// The server refused the connection.
+    ConnectionRefused,
+    /// The connection was reset or closed before the response was received.
+    ConnectionReset,
+    /// The request timed out.
+    Timeout,
+    /// The TLS handshake failed, for example because the certificate wasn't trusted.
+    Tls,
+    /// Any other failure to make the request or receive the response.
+    Other,
+}
+impl GooseTransportError {
+    /// Determines the kind of transport-level failure from a failed request.
+    pub(crate) fn from_reqwest_error(error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            return GooseTransportError::Timeout;
+        }
+        // The message of the request error includes the URL, which can contain any of the
+        // words looked for in messages, so only its sources are classified.
+        match error.source() {
+            Some(source) => GooseTransportError::from_error(source),
+            None => GooseTransportError::Other,
+        }
+    }
+
+    /// Determines the kind of transport-level failure from an error and its sources.
+    pub(crate) fn from_error(error: &(dyn Error + 'static)) -> Self {
+        // The kind of an underlying io error, or of an HTTP client error, is most reliable.
+        let mut source = Some(error);
+        while let Some(error) = source {
+            if let Some(io_error) = error.downcast_ref::<std::io::Error>() {
+                match io_error.kind() {
+                    std::io::ErrorKind::ConnectionRefused => {
+                        return GooseTransportError::ConnectionRefused
+                    }
+                    std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof => {
+                        return GooseTransportError::ConnectionReset
+                    }
+                    std::io::ErrorKind::TimedOut => return GooseTransportError::Timeout,
+                    _ => (),
+                }
+            } else if let Some(hyper_error) = error.downcast_ref::<hyper::Error>() {
+                if hyper_error.is_timeout() {
+                    return GooseTransportError::Timeout;
+                } else if hyper_error.is_incomplete_message() || hyper_error.is_closed() {
+                    return GooseTransportError::ConnectionReset;
+                }
+            }
+            source = error.source();
+        }
+
+        // Errors from the resolver and TLS library aren't otherwise distinguishable, so
+        // are identified by their message.
+        let mut source = Some(error);
+        while let Some(error) = source {
+            let message = error.to_string().to_lowercase();
+            if message.contains("dns error")
+                || message.contains("failed to lookup address")
+                || message.contains("name or service not known")
+            {
+                return GooseTransportError::Dns;
+            } else if message.contains("tls")
+                || message.contains("ssl")
+                || message.contains("certificate")
+                || message.contains("handshake")
+            {
+                return GooseTransportError::Tls;
+            } else if message.contains("connection refused") {
+                return GooseTransportError::ConnectionRefused;
+            } else if message.contains("connection reset") || message.contains("connection closed")
+            {
+                return GooseTransportError::ConnectionReset;
+            } else if message.contains("timed out") {
+                return GooseTransportError::Timeout;
+            }
+            source = error.source();
+        }
+        GooseTransportError::Other
+    }
+}
+impl fmt::Display for GooseTransportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GooseTransportError::Dns => write!(f, "dns"),
+            GooseTransportError::ConnectionRefused => write!(f, "connection refused"),
+            GooseTransportError::ConnectionReset => write!(f, "connection reset"),
+            GooseTransportError::Timeout => write!(f, "timeout"),
+            GooseTransportError::Tls => write!(f, "tls"),
+            GooseTransportError::Other => write!(f, "other"),
+        }
+    }
+}
+
 /// Metrics collected about a method-path pair, (for example `GET /index`).
 ///
 /// [`GooseRequestMetric`]s are sent by [`GooseUser`](../goose/struct.GooseUser.html)
@@ -377,6 +660,9 @@ pub struct GooseRequestMetricAggregate {
     ///
     /// For example: [`GooseMethod::Get`].
     pub method: GooseMethod,
+    /// The protocol of raw socket requests, which have no HTTP method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<GooseSocketMethod>,
     /// The raw data seen from actual requests.
     pub raw_data: GooseRequestMetricTimingData,
     /// Combines the raw data with statistically generated Coordinated Omission Metrics.
@@ -391,6 +677,75 @@ pub struct GooseRequestMetricAggregate {
     ///
     /// A count of how many requests resulted in a non-2xx status code.
     pub fail_count: usize,
+    /// The response time budget in milliseconds, if one was configured with
+    /// [`GooseRequestBuilder::expect_response_time`](../goose/struct.GooseRequestBuilder.html#method.expect_response_time).
+    pub response_time_budget: Option<u64>,
+    /// Total number of times this path-method request took longer than its response time
+    /// budget.
+    ///
+    /// Slow requests are counted separately from failures, and can also be successful.
+    pub slow_count: usize,
+    /// Per-backend counters, tracking how often each backend served this request when
+    /// verifying session affinity with `--affinity-header`.
+    pub backends: HashMap<String, usize>,
+    /// Total number of times this path-method request was served by a different backend
+    /// than the user's previous request.
+    pub affinity_violations: usize,
+    /// Response times grouped by the values of the response headers configured with
+    /// `--header-dimensions`, keyed by `header: value` (for example `x-cache: HIT`), and
+    /// by device profile if configured with `--device-profiles` (for example
+    /// `device: mobile`).
+    pub dimensions: BTreeMap<String, GooseRequestMetricTimingData>,
+    /// Total number of response bodies measured with `--body-sizes`.
+    pub body_count: usize,
+    /// Total size in bytes of the response bodies measured with `--body-sizes`, as
+    /// received.
+    pub body_bytes: usize,
+    /// Total size in bytes of the response bodies measured with `--body-sizes`, after
+    /// decompression.
+    pub decompressed_body_bytes: usize,
+    /// Total number of response bodies verified against an expected checksum or length.
+    pub integrity_checks: usize,
+    /// Total number of verified response bodies that didn't match the expected checksum
+    /// or length.
+    ///
+    /// Integrity failures are also counted as failed requests.
+    pub integrity_failures: usize,
+    /// Total number of response bodies validated against a registered JSON Schema.
+    pub schema_checks: usize,
+    /// Total number of validated response bodies that weren't JSON or didn't match the
+    /// registered JSON Schema.
+    ///
+    /// Schema violations are also counted as failed requests.
+    pub schema_violations: usize,
+    /// Total number of `409 Conflict` responses, returned when a write conflicts with the
+    /// current state of the resource.
+    ///
+    /// Conflicts are counted separately from other failures, as write APIs return them to
+    /// correctly behaving clients, but they're also counted as failed requests unless the
+    /// status code was expected.
+    pub conflicts: usize,
+    /// Total number of `412 Precondition Failed` responses, returned when a conditional
+    /// write such as one with an `If-Match` header is made with a stale ETag.
+    ///
+    /// Like conflicts, these are also counted as failed requests unless the status code
+    /// was expected.
+    pub precondition_failures: usize,
+    /// Response times of the responses served by an intermediary cache, if tracked with
+    /// `--cache-status`.
+    pub cache_data: GooseRequestMetricTimingData,
+    /// Response times of the responses served by the origin, if tracked with
+    /// `--cache-status`.
+    pub origin_data: GooseRequestMetricTimingData,
+    /// Per-kind counters of requests that failed without a response because of a
+    /// transport-level failure, such as a DNS failure or a refused connection.
+    ///
+    /// These failures are also counted as failed requests.
+    pub transport_errors: BTreeMap<GooseTransportError, usize>,
+    /// How many of these requests were in flight during each second of the load test.
+    ///
+    /// Not tracked with `--no-time-series` or in Gaggle mode.
+    pub concurrency: GooseConcurrency,
     /// Load test hash.
     ///
     /// The hash is primarily used when running a distributed Gaggle, allowing the Manager to confirm
@@ -404,15 +759,62 @@ impl GooseRequestMetricAggregate {
         GooseRequestMetricAggregate {
             path: path.to_string(),
             method,
-            raw_data: GooseRequestMetricTimingData::new(None),
+            socket: None,
+            raw_data: GooseRequestMetricTimingData::with_histogram(),
             coordinated_omission_data: None,
             status_code_counts: HashMap::new(),
             success_count: 0,
             fail_count: 0,
+            response_time_budget: None,
+            slow_count: 0,
+            backends: HashMap::new(),
+            affinity_violations: 0,
+            dimensions: BTreeMap::new(),
+            body_count: 0,
+            body_bytes: 0,
+            decompressed_body_bytes: 0,
+            integrity_checks: 0,
+            integrity_failures: 0,
+            schema_checks: 0,
+            schema_violations: 0,
+            conflicts: 0,
+            precondition_failures: 0,
+            cache_data: GooseRequestMetricTimingData::new(None),
+            origin_data: GooseRequestMetricTimingData::new(None),
+            transport_errors: BTreeMap::new(),
+            concurrency: GooseConcurrency::default(),
             load_test_hash,
         }
     }
 
+    /// The method of the requests as displayed in metrics, such as `GET`, or the protocol
+    /// of raw socket requests, such as `TCP`.
+    pub fn method_name(&self) -> String {
+        method_name(&self.method, self.socket)
+    }
+
+    /// Record a response time grouped by the value of a response header. Once too many
+    /// distinct values have been seen for a header, further values are grouped together
+    /// as `(other)`.
+    pub(crate) fn record_dimension(&mut self, header: &str, value: &str, time_elapsed: u64) {
+        let mut key = format!("{}: {}", header, value);
+        if !self.dimensions.contains_key(&key) {
+            let prefix = format!("{}: ", header);
+            let values = self
+                .dimensions
+                .keys()
+                .filter(|dimension| dimension.starts_with(&prefix))
+                .count();
+            if values >= MAX_DIMENSION_VALUES {
+                key = format!("{}(other)", prefix);
+            }
+        }
+        self.dimensions
+            .entry(key)
+            .or_insert_with(|| GooseRequestMetricTimingData::new(None))
+            .record_time(time_elapsed);
+    }
+
     pub(crate) fn record_time(&mut self, time_elapsed: u64, coordinated_omission_mitigation: bool) {
         // Only add time_elapsed to raw_data if the time wasn't generated by Coordinated
         // Omission Mitigation.
@@ -429,11 +831,36 @@ impl GooseRequestMetricAggregate {
             // If this time_elapsed was generated by Coordinated Omission Mitigation, it doesn't
             // exist in the raw_data, so add it.
             let mut coordinated_omission_data = self.raw_data.clone();
+            #[cfg(feature = "hdr-histogram")]
+            {
+                coordinated_omission_data.histogram = None;
+            }
             coordinated_omission_data.record_time(time_elapsed);
             self.coordinated_omission_data = Some(coordinated_omission_data);
         }
     }
 
+    /// Discard the response times used to calculate medians and percentiles, keeping only
+    /// the minimum, maximum, average and count. Used when `--no-histograms` is enabled.
+    pub(crate) fn discard_times(&mut self) {
+        self.raw_data.discard_times();
+        if let Some(coordinated_omission_data) = self.coordinated_omission_data.as_mut() {
+            coordinated_omission_data.discard_times();
+        }
+        for dimension in self.dimensions.values_mut() {
+            dimension.discard_times();
+        }
+        self.cache_data.discard_times();
+        self.origin_data.discard_times();
+    }
+
+    /// The response time in milliseconds at a percentile of this request, such as `0.999`,
+    /// or `None` if individual response times were discarded with `--no-histograms`.
+    /// Accurate to three significant digits when compiled with the `hdr-histogram` feature.
+    pub fn response_time_percentile(&self, percent: f32) -> Option<usize> {
+        self.raw_data.percentile(percent)
+    }
+
     /// Increment counter for status code, creating new counter if first time seeing status code.
     pub(crate) fn set_status_code(&mut self, status_code: u16) {
         let counter = match self.status_code_counts.get(&status_code) {
@@ -492,6 +919,13 @@ pub struct GooseRequestMetricTimingData {
     ///
     /// A count of how many requests have been tracked for this method-path pair.
     pub counter: usize,
+    /// Response times without rounding, used to calculate percentiles when compiled with
+    /// the `hdr-histogram` feature.
+    ///
+    /// Only kept for the response times of each request, as every histogram is about 16 KB.
+    #[cfg(feature = "hdr-histogram")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) histogram: Option<GooseHistogram>,
 }
 impl GooseRequestMetricTimingData {
     /// Create a new GooseRequestMetricAggregate object.
@@ -508,7 +942,83 @@ impl GooseRequestMetricTimingData {
                 maximum_time: 0,
                 total_time: 0,
                 counter: 0,
+                #[cfg(feature = "hdr-histogram")]
+                histogram: None,
+            }
+        }
+    }
+
+    /// Create a new empty GooseRequestMetricTimingData object that also keeps response
+    /// times without rounding when compiled with the `hdr-histogram` feature.
+    pub(crate) fn with_histogram() -> Self {
+        #[allow(unused_mut)]
+        let mut data = GooseRequestMetricTimingData::new(None);
+        #[cfg(feature = "hdr-histogram")]
+        {
+            data.histogram = Some(GooseHistogram::default());
+        }
+        data
+    }
+
+    /// The response time in milliseconds at a percentile, such as `0.99`, or `None` if
+    /// individual response times were discarded with `--no-histograms`.
+    ///
+    /// When compiled with the `hdr-histogram` feature the percentiles of each request are
+    /// calculated from response times kept with three significant digits, otherwise from
+    /// rounded response times.
+    pub fn percentile(&self, percent: f32) -> Option<usize> {
+        #[cfg(feature = "hdr-histogram")]
+        if let Some(histogram) = self.histogram.as_ref() {
+            if histogram.is_empty() {
+                return if self.counter > 0 { None } else { Some(0) };
             }
+            let response_time = histogram.percentile(percent);
+            return Some(response_time.clamp(self.minimum_time, self.maximum_time));
+        }
+        response_time_percentile(
+            &self.times,
+            self.counter,
+            self.minimum_time,
+            self.maximum_time,
+            percent,
+        )
+    }
+
+    /// The response time at a percentile formatted for display, or `-` if individual
+    /// response times were discarded.
+    pub(crate) fn format_percentile(
+        &self,
+        percent: f32,
+        number_format: &GooseNumberFormat,
+    ) -> String {
+        match self.percentile(percent) {
+            Some(response_time) => number_format.time(response_time),
+            None => "-".to_string(),
+        }
+    }
+
+    /// Merge response times tracked elsewhere, such as by a Worker or another request.
+    pub(crate) fn merge(&mut self, other: &GooseRequestMetricTimingData) {
+        self.times = merge_times(std::mem::take(&mut self.times), other.times.clone());
+        self.total_time += other.total_time;
+        self.counter += other.counter;
+        self.minimum_time = update_min_time(self.minimum_time, other.minimum_time);
+        self.maximum_time = update_max_time(self.maximum_time, other.maximum_time);
+        #[cfg(feature = "hdr-histogram")]
+        if let (Some(histogram), Some(other_histogram)) =
+            (self.histogram.as_mut(), other.histogram.as_ref())
+        {
+            histogram.merge(other_histogram);
+        }
+    }
+
+    /// Discard the individual response times, keeping only the minimum, maximum, average
+    /// and count.
+    pub(crate) fn discard_times(&mut self) {
+        self.times.clear();
+        #[cfg(feature = "hdr-histogram")]
+        if let Some(histogram) = self.histogram.as_mut() {
+            histogram.clear();
         }
     }
 
@@ -533,6 +1043,11 @@ impl GooseRequestMetricTimingData {
         // Each time we store a new time, increment counter by one.
         self.counter += 1;
 
+        #[cfg(feature = "hdr-histogram")]
+        if let Some(histogram) = self.histogram.as_mut() {
+            histogram.record(time_elapsed);
+        }
+
         // Round the time so we can combine similar times together and
         // minimize required memory to store and push upstream to the parent.
         // No rounding for 1-100ms times.
@@ -645,6 +1160,10 @@ pub struct GooseTaskMetricAggregate {
     pub success_count: usize,
     /// Total number of times task has failed.
     pub fail_count: usize,
+    /// How many instances of this task were running during each second of the load test.
+    ///
+    /// Not tracked with `--no-time-series` or in Gaggle mode.
+    pub concurrency: GooseConcurrency,
 }
 impl GooseTaskMetricAggregate {
     /// Create a new GooseTaskMetricAggregate.
@@ -666,6 +1185,7 @@ impl GooseTaskMetricAggregate {
             counter: 0,
             success_count: 0,
             fail_count: 0,
+            concurrency: GooseConcurrency::default(),
         }
     }
 
@@ -718,6 +1238,472 @@ impl GooseTaskMetricAggregate {
         self.times.insert(rounded_time, counter);
         debug!("incremented {} counter: {}", rounded_time, counter);
     }
+
+    /// Discard the task times used to calculate the median, keeping only the minimum,
+    /// maximum, average and count. Used when `--no-histograms` is enabled.
+    pub(crate) fn discard_times(&mut self) {
+        self.times.clear();
+    }
+}
+
+/// How many users were planned to be running, and how many actually ran, during each
+/// second of the load test, either for all users or for the users of one task set.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GooseRampProfile {
+    /// An index into [`GooseAttack`](../struct.GooseAttack.html)`.task_sets`, indicating
+    /// which task set this is, or `None` for all users.
+    pub taskset_index: Option<usize>,
+    /// The task set name, or `All users`.
+    pub name: String,
+    /// How many users were planned to be running during each second.
+    pub planned_users: Vec<usize>,
+    /// How many users were running during each second.
+    pub active_users: Vec<usize>,
+}
+impl GooseRampProfile {
+    /// The name displayed in metrics and reports, prefixed with the task set number.
+    fn display_name(&self) -> String {
+        match self.taskset_index {
+            Some(taskset_index) => format!("{}: {}", taskset_index + 1, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Summarizes the ramp: the most users planned, the second they were planned to be
+    /// running, the most users actually running, and the second that many users were
+    /// actually running, if ever.
+    fn summary(&self) -> (usize, usize, usize, Option<usize>) {
+        let planned = self.planned_users.iter().copied().max().unwrap_or(0);
+        let planned_at = self
+            .planned_users
+            .iter()
+            .position(|users| *users == planned)
+            .unwrap_or(0);
+        let active = self.active_users.iter().copied().max().unwrap_or(0);
+        let reached_at = self.active_users.iter().position(|users| *users >= planned);
+        (planned, planned_at, active, reached_at)
+    }
+}
+
+/// Counts of responses by response time and by when they were received, a two
+/// dimensional histogram charted as a heatmap.
+///
+/// Percentiles summarize response times over the whole load test, hiding multi-modal
+/// behavior such as periodic pauses on the server. The heatmap keeps how response times
+/// were distributed during each second of the load test, so these patterns are visible.
+#[derive(Clone, Debug, Serialize)]
+pub struct GooseResponseTimeHeatmap {
+    /// The upper bound in milliseconds of each response time bucket. Responses slower than
+    /// the last bound are counted in an additional final bucket.
+    pub buckets: Vec<u64>,
+    /// For each second since the first user was launched, how many responses fell in
+    /// each response time bucket.
+    pub counts: Vec<Vec<usize>>,
+}
+impl Default for GooseResponseTimeHeatmap {
+    fn default() -> Self {
+        GooseResponseTimeHeatmap {
+            buckets: RESPONSE_TIME_HEATMAP_BUCKETS.to_vec(),
+            counts: Vec::new(),
+        }
+    }
+}
+impl GooseResponseTimeHeatmap {
+    /// Counts a response time in the second of the load test it was received.
+    pub(crate) fn record(&mut self, second: usize, response_time: u64) {
+        if self.counts.len() <= second {
+            self.counts
+                .resize(second + 1, vec![0; self.buckets.len() + 1]);
+        }
+        let bucket = self
+            .buckets
+            .iter()
+            .position(|bound| response_time <= *bound)
+            .unwrap_or(self.buckets.len());
+        self.counts[second][bucket] += 1;
+    }
+}
+
+/// How many requests completed and failed during each second of the load test, and how
+/// long they took.
+///
+/// Aggregate metrics describe the load test as a whole. The time series shows how
+/// throughput, errors and response times changed as users ramped up and as the load test
+/// progressed, so the point where the server started to degrade is visible.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GooseRequestTimeSeries {
+    /// Requests completed during each second since the first user was launched.
+    pub seconds: Vec<GooseRequestSecond>,
+}
+impl GooseRequestTimeSeries {
+    /// Records a request completed in the second of the load test it was received.
+    pub(crate) fn record(
+        &mut self,
+        second: usize,
+        response_time: u64,
+        success: bool,
+        discard_times: bool,
+    ) {
+        self.resize(second);
+        self.seconds[second].record(response_time, success, discard_times);
+    }
+
+    /// Records a request later updated as successful or failed, in the second of the load
+    /// test the update was received.
+    pub(crate) fn record_update(&mut self, second: usize, success: bool) {
+        self.resize(second);
+        self.seconds[second].record_update(success);
+    }
+
+    /// Adds seconds without requests, up to and including this second.
+    fn resize(&mut self, second: usize) {
+        if self.seconds.len() <= second {
+            self.seconds
+                .resize(second + 1, GooseRequestSecond::default());
+        }
+    }
+}
+
+/// The requests completed during one second of the load test.
+#[derive(Clone, Debug)]
+pub struct GooseRequestSecond {
+    /// How many of the requests failed.
+    pub errors: usize,
+    /// How long the requests took. Individual times are discarded with `--no-histograms`.
+    pub times: GooseRequestMetricTimingData,
+}
+impl Default for GooseRequestSecond {
+    fn default() -> Self {
+        GooseRequestSecond {
+            errors: 0,
+            times: GooseRequestMetricTimingData::new(None),
+        }
+    }
+}
+impl GooseRequestSecond {
+    /// Records a completed request.
+    pub(crate) fn record(&mut self, response_time: u64, success: bool, discard_times: bool) {
+        self.times.record_time(response_time);
+        if discard_times {
+            self.times.discard_times();
+        }
+        if !success {
+            self.errors += 1;
+        }
+    }
+
+    /// Records a request later updated as successful or failed.
+    pub(crate) fn record_update(&mut self, success: bool) {
+        if success {
+            self.errors = self.errors.saturating_sub(1);
+        } else {
+            self.errors += 1;
+        }
+    }
+
+    /// How many requests completed.
+    pub fn requests(&self) -> usize {
+        self.times.counter
+    }
+
+    /// The average response time in milliseconds.
+    pub fn mean(&self) -> f32 {
+        if self.times.counter == 0 {
+            0.0
+        } else {
+            self.times.total_time as f32 / self.times.counter as f32
+        }
+    }
+
+    /// The response time in milliseconds at a percentile, such as `0.95`, or `None` if
+    /// individual times were discarded.
+    pub fn percentile(&self, percent: f32) -> Option<usize> {
+        self.times.percentile(percent)
+    }
+}
+impl Serialize for GooseRequestSecond {
+    // Serialize summaries of the response times rather than every time.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("GooseRequestSecond", 6)?;
+        s.serialize_field("requests", &self.requests())?;
+        s.serialize_field("errors", &self.errors)?;
+        s.serialize_field("mean", &self.mean())?;
+        s.serialize_field("median", &self.percentile(0.5))?;
+        s.serialize_field("percentile_95", &self.percentile(0.95))?;
+        s.serialize_field("maximum", &self.times.maximum_time)?;
+        s.end()
+    }
+}
+
+/// Concurrency of a request or task over time.
+///
+/// Verifies that the offered concurrency matched the design of the load test. Instead of
+/// sampling, the time each request or task spent in flight is added to each second it
+/// overlapped, so each second's total divided by 1,000 is how many were in flight on
+/// average during that second.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GooseConcurrency {
+    /// For each second since the load test started, the combined milliseconds spent in
+    /// flight during that second.
+    pub busy: Vec<u64>,
+}
+impl GooseConcurrency {
+    /// Adds the time spent in flight by a request or task that finished `finished`
+    /// milliseconds after the load test started, having taken `duration` milliseconds.
+    pub(crate) fn record(&mut self, finished: u64, duration: u64) {
+        let mut started = finished.saturating_sub(duration);
+        let last_second = (finished / 1_000) as usize;
+        if self.busy.len() <= last_second {
+            self.busy.resize(last_second + 1, 0);
+        }
+        while started < finished {
+            let second = started / 1_000;
+            let until = finished.min((second + 1) * 1_000);
+            self.busy[second as usize] += until - started;
+            started = until;
+        }
+    }
+
+    /// How many were in flight on average, from the first second any were in flight until
+    /// the last.
+    pub fn average(&self) -> f32 {
+        match self.busy.iter().position(|busy| *busy > 0) {
+            Some(first) => {
+                self.busy.iter().sum::<u64>() as f32 / ((self.busy.len() - first) * 1_000) as f32
+            }
+            None => 0.0,
+        }
+    }
+
+    /// How many were in flight on average during the busiest second of the load test.
+    pub fn maximum(&self) -> f32 {
+        self.busy.iter().copied().max().unwrap_or(0) as f32 / 1_000.0
+    }
+}
+
+/// Which of a task set's one-time tasks was run.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum GooseSetupTeardown {
+    /// The task set's [`setup`](../goose/struct.GooseTaskSet.html#method.set_setup_task)
+    /// task, run before any user is launched.
+    Setup,
+    /// The task set's
+    /// [`teardown`](../goose/struct.GooseTaskSet.html#method.set_teardown_task) task, run
+    /// after all users have exited.
+    Teardown,
+}
+impl fmt::Display for GooseSetupTeardown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GooseSetupTeardown::Setup => write!(f, "setup"),
+            GooseSetupTeardown::Teardown => write!(f, "teardown"),
+        }
+    }
+}
+
+/// How long a task set's setup or teardown task took, and whether it succeeded.
+///
+/// Setup and teardown tasks run once outside of the load, so they are reported separately
+/// from the per-task metrics in [`GooseMetrics::setup_teardown`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GooseSetupTeardownMetric {
+    /// An index into [`GooseAttack`](../struct.GooseAttack.html)`.task_sets`,
+    /// indicating which task set this is.
+    pub taskset_index: usize,
+    /// The task set name.
+    pub taskset_name: String,
+    /// Whether this is the setup or the teardown task.
+    pub kind: GooseSetupTeardown,
+    /// How many milliseconds the task took.
+    pub run_time: u64,
+    /// Whether or not the task was successful.
+    pub success: bool,
+    /// The error returned by the task, if it failed.
+    pub error: Option<String>,
+}
+
+/// A named annotation marking a moment of the running load test, such as `deployed v2.3`
+/// or `cache flushed`.
+///
+/// Annotations are recorded with
+/// [`GooseUser::annotate`](../goose/struct.GooseUser.html#method.annotate) or the
+/// `annotate` [Controller](../controller/index.html) command, and are marked on the
+/// charts of the html report.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GooseAnnotation {
+    /// The name of the annotation.
+    pub name: String,
+    /// When the annotation was recorded, as a unix timestamp in milliseconds.
+    pub timestamp: i64,
+    /// How many seconds after the load test started the annotation was recorded.
+    pub elapsed: usize,
+}
+impl GooseAnnotation {
+    /// Create a new annotation recorded now. The elapsed time is set by the parent
+    /// process once it receives the annotation.
+    pub(crate) fn new(name: &str) -> Self {
+        GooseAnnotation {
+            name: name.to_string(),
+            timestamp: Local::now().timestamp_millis(),
+            elapsed: 0,
+        }
+    }
+}
+
+/// A phase of a load test following a test plan, such as the `ramp-up`, `hold` and
+/// `ramp-down` phases configured with the `--ramp-up`, `--hold` and `--ramp-down` run-time
+/// options, or a step of a `--test-plan`.
+///
+/// Requests completed during each phase are aggregated separately, so metrics at very
+/// different load levels aren't blended together.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoosePhase {
+    /// The name of the phase.
+    pub name: String,
+    /// How many users are planned to be running at the end of the phase.
+    pub users: usize,
+    /// When the phase started, as a unix timestamp in milliseconds.
+    pub timestamp: i64,
+    /// How many seconds after the load test started the phase started.
+    pub elapsed: usize,
+    /// The requests completed during the phase.
+    pub requests: GooseRequestSecond,
+}
+
+/// The network round-trip time to a host, measured by timing TCP connects before the load
+/// test starts.
+///
+/// Response times include the network distance to the host, so the round-trip time can be
+/// subtracted when comparing load tests run from different locations. Measured with the
+/// `--rtt-samples VALUE` run-time option, and included in
+/// [`GooseMetrics::network_baselines`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GooseNetworkBaseline {
+    /// The host, as the load test is run against it.
+    pub host: String,
+    /// The address connected to, or `None` if the host couldn't be resolved.
+    pub address: Option<String>,
+    /// How many milliseconds each successful connect took, in the order they were made.
+    pub round_trip_times: Vec<f32>,
+    /// How many connects failed or timed out.
+    pub failures: usize,
+}
+impl GooseNetworkBaseline {
+    /// The fastest round-trip time in milliseconds, if any connect succeeded.
+    pub fn minimum(&self) -> Option<f32> {
+        self.round_trip_times.iter().copied().reduce(f32::min)
+    }
+
+    /// The median round-trip time in milliseconds, if any connect succeeded.
+    pub fn median(&self) -> Option<f32> {
+        let mut round_trip_times = self.round_trip_times.clone();
+        round_trip_times.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let middle = round_trip_times.len() / 2;
+        match round_trip_times.len() {
+            0 => None,
+            length if length % 2 == 0 => {
+                Some((round_trip_times[middle - 1] + round_trip_times[middle]) / 2.0)
+            }
+            _ => Some(round_trip_times[middle]),
+        }
+    }
+
+    /// The slowest round-trip time in milliseconds, if any connect succeeded.
+    pub fn maximum(&self) -> Option<f32> {
+        self.round_trip_times.iter().copied().reduce(f32::max)
+    }
+}
+
+/// Metrics from an earlier load test, loaded with the `--baseline-file NAME` run-time
+/// option so running metrics can be compared with them.
+///
+/// Read from a file written with `--output json`, or from a metrics checkpoint saved with
+/// `--checkpoint-file NAME`. Other fields in the file are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct GooseMetricsBaseline {
+    /// How many seconds the earlier load test ran.
+    pub duration: usize,
+    /// Request metrics collected by the earlier load test.
+    pub requests: GooseRequestMetrics,
+}
+
+/// How a request compares with the same request in the baseline, displayed in the
+/// baseline comparison table and html report.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GooseBaselineComparison {
+    /// Average response time, in milliseconds.
+    pub(crate) average: f32,
+    /// Percent change of the average response time, or `None` if the baseline is 0.
+    pub(crate) average_change: Option<f32>,
+    /// Requests per second.
+    pub(crate) requests_per_second: f32,
+    /// Percent change of the requests per second, or `None` if the baseline is 0.
+    pub(crate) requests_per_second_change: Option<f32>,
+    /// Percent of requests that failed.
+    pub(crate) fail_percent: f32,
+    /// Change of the percent of requests that failed, in percentage points.
+    pub(crate) fail_percent_change: f32,
+}
+impl GooseBaselineComparison {
+    /// Compare a request with the same request in the baseline, each with the number of
+    /// seconds its load test ran.
+    pub(crate) fn new(
+        request: &GooseRequestMetricAggregate,
+        duration: usize,
+        baseline: &GooseRequestMetricAggregate,
+        baseline_duration: usize,
+    ) -> Self {
+        let average = |request: &GooseRequestMetricAggregate| {
+            if request.raw_data.counter == 0 {
+                0.0
+            } else {
+                request.raw_data.total_time as f32 / request.raw_data.counter as f32
+            }
+        };
+        let fail_percent = |request: &GooseRequestMetricAggregate| {
+            let total_count = request.success_count + request.fail_count;
+            if request.fail_count == 0 {
+                0.0
+            } else {
+                request.fail_count as f32 / total_count as f32 * 100.0
+            }
+        };
+        let requests_per_second = |request: &GooseRequestMetricAggregate, duration: usize| {
+            per_second_calculations(
+                duration,
+                request.success_count + request.fail_count,
+                request.fail_count,
+            )
+            .0
+        };
+        let percent_change = |value: f32, baseline: f32| {
+            if baseline > 0.0 {
+                Some((value - baseline) / baseline * 100.0)
+            } else {
+                None
+            }
+        };
+
+        let (current_average, baseline_average) = (average(request), average(baseline));
+        let (current_requests_per_second, baseline_requests_per_second) = (
+            requests_per_second(request, duration),
+            requests_per_second(baseline, baseline_duration),
+        );
+        GooseBaselineComparison {
+            average: current_average,
+            average_change: percent_change(current_average, baseline_average),
+            requests_per_second: current_requests_per_second,
+            requests_per_second_change: percent_change(
+                current_requests_per_second,
+                baseline_requests_per_second,
+            ),
+            fail_percent: fail_percent(request),
+            fail_percent_change: fail_percent(request) - fail_percent(baseline),
+        }
+    }
 }
 
 /// All metrics optionally collected during a Goose load test.
@@ -844,6 +1830,34 @@ pub struct GooseMetrics {
     /// This value may be smaller than what was configured at start time if the test
     /// didn't run long enough for all configured users to start.
     pub users: usize,
+    /// How many users were running during each second of the load test, starting when
+    /// the first user was launched, so response times can be compared with the load at
+    /// the time.
+    ///
+    /// Not tracked in Gaggle mode.
+    pub active_users: Vec<usize>,
+    /// How many users were planned to be running and how many actually ran during each
+    /// second of the load test, in the same seconds as `active_users`. The first profile
+    /// is of all users, following the hatch rate or test plan, followed by a profile of
+    /// each task set. Fewer active than planned users show where the load test fell behind
+    /// its ramp, for example because the load generator ran out of resources.
+    ///
+    /// Not tracked in Gaggle mode, or with `--no-time-series`. Task sets aren't profiled
+    /// when following a test plan.
+    pub ramp_profiles: Vec<GooseRampProfile>,
+    /// How response times were distributed during each second of the load test, starting
+    /// when the first user was launched, so changes in response times over time are
+    /// visible. Requests made before metrics are reset are included.
+    ///
+    /// Not tracked in Gaggle mode, or with `--no-time-series`.
+    pub response_time_heatmap: GooseResponseTimeHeatmap,
+    /// How many requests completed and failed during each second of the load test,
+    /// starting when the first user was launched, and how long they took, so ramp-up
+    /// behavior and degradation over time are visible. Requests made before metrics are
+    /// reset are included.
+    ///
+    /// Not tracked in Gaggle mode, or with `--no-time-series`.
+    pub request_time_series: GooseRequestTimeSeries,
     /// Tracks details about each request made during the load test.
     ///
     /// Can be disabled with the `--no-metrics` run-time option, or with
@@ -856,6 +1870,12 @@ pub struct GooseMetrics {
     /// [GooseDefault::NoTaskMetrics](../config/enum.GooseDefault.html#variant.NoTaskMetrics) or
     /// [GooseDefault::NoMetrics](../config/enum.GooseDefault.html#variant.NoMetrics).
     pub tasks: GooseTaskMetrics,
+    /// Tracks details about each pass through a
+    /// [`GooseTaskGroup`](../goose/struct.GooseTaskGroup.html), where `task_index` and
+    /// `task_name` identify the group.
+    ///
+    /// Disabled along with task metrics, and not tracked in Gaggle mode.
+    pub groups: GooseTaskMetrics,
     /// Tracks and counts each time an error is detected during the load test.
     ///
     /// Can be disabled with either the `--no-error-summary` or `--no-metrics` run-time options,
@@ -865,14 +1885,80 @@ pub struct GooseMetrics {
     pub errors: GooseErrorMetrics,
     /// Tracks all hosts that the load test is run against.
     pub hosts: HashSet<String>,
-    /// Flag indicating whether or not these are the final metrics, used to determine
-    /// which metrics should be displayed. Defaults to false.
-    pub(crate) final_metrics: bool,
-    /// Flag indicating whether or not to display status_codes. Defaults to false.
-    pub(crate) display_status_codes: bool,
+    /// Metadata describing the load test, such as the build or environment being load
+    /// tested, configured with the `--label KEY=VALUE` run-time option or with
+    /// [GooseDefault::Labels](../config/enum.GooseDefault.html#variant.Labels).
+    pub labels: BTreeMap<String, String>,
+    /// The run ID marking the load test's requests, logs and reports, configured with
+    /// the `--run-id` run-time option or with
+    /// [GooseDefault::RunId](../config/enum.GooseDefault.html#variant.RunId).
+    pub run_id: String,
+    /// Total number of TLS handshakes that did not resume a previous session.
+    ///
+    /// Only tracked when Goose is compiled with the `rustls-tls` feature.
+    pub tls_full_handshakes: usize,
+    /// Total number of TLS handshakes that attempted to resume a previous session.
+    ///
+    /// Only tracked when Goose is compiled with the `rustls-tls` feature. Session
+    /// resumption can be disabled with the `--no-tls-resumption` run-time option, or with
+    /// [GooseDefault::NoTlsResumption](../config/enum.GooseDefault.html#variant.NoTlsResumption).
+    pub tls_resumed_handshakes: usize,
+    /// How many times writing to each log failed, keyed by the log's path, for example
+    /// because the disk filled up. What happens to messages that can't be written is
+    /// configured with the `--log-failure` run-time option or with
+    /// [GooseDefault::LogFailure](../config/enum.GooseDefault.html#variant.LogFailure).
+    pub log_failures: BTreeMap<String, usize>,
+    /// How long each task set's setup and teardown tasks took, in the order they ran.
+    pub setup_teardown: Vec<GooseSetupTeardownMetric>,
+    /// The network round-trip time to each host, measured before the load test started
+    /// with the `--rtt-samples VALUE` run-time option or with
+    /// [GooseDefault::RttSamples](../config/enum.GooseDefault.html#variant.RttSamples).
+    ///
+    /// Not measured in Gaggle mode.
+    pub network_baselines: Vec<GooseNetworkBaseline>,
+    /// Metrics from an earlier load test that running metrics are compared with, loaded
+    /// with the `--baseline-file NAME` run-time option.
+    ///
+    /// Not supported in Gaggle mode.
+    pub baseline: Option<GooseMetricsBaseline>,
+    /// How many requests per second were made on average when the throttle adapted to the
+    /// rate-limit headers of the target server, enabled with the `--adaptive-throttle`
+    /// run-time option or with
+    /// [GooseDefault::AdaptiveThrottle](../config/enum.GooseDefault.html#variant.AdaptiveThrottle).
+    pub throttle_rate: Option<f32>,
+    /// Why the load test was aborted, if a task aborted it with
+    /// [`GooseUser::abort_test`](../goose/struct.GooseUser.html#method.abort_test).
+    pub aborted: Option<String>,
+    /// Named annotations recorded while the load test ran, in the order they were
+    /// recorded.
+    ///
+    /// Not supported in Gaggle mode.
+    pub annotations: Vec<GooseAnnotation>,
+    /// The phases of the test plan the load test followed, in the order they started.
+    /// A phase ends when the next phase starts, or when the load test stops.
+    ///
+    /// Only recorded when following a test plan, which isn't supported in Gaggle mode.
+    pub phases: Vec<GoosePhase>,
+    /// How many Workers of a Gaggle were lost while the load test ran, because their
+    /// connection closed or they stopped sending heartbeats. Metrics a lost Worker
+    /// collected after it last pushed metrics to the Manager are missing, so if any
+    /// Workers were lost the metrics are partial.
+    ///
+    /// Only counted in Gaggle mode.
+    pub lost_workers: usize,
+    /// Flag indicating whether or not these are the final metrics, used to determine
+    /// which metrics should be displayed. Defaults to false.
+    pub(crate) final_metrics: bool,
+    /// Flag indicating whether or not to display status_codes. Defaults to false.
+    pub(crate) display_status_codes: bool,
     /// Flag indicating whether or not to display metrics. This defaults to false on
     /// Workers, otherwise true.
     pub(crate) display_metrics: bool,
+    /// What is printed to stdout, configured with the `--output` run-time option.
+    pub(crate) output: GooseOutput,
+    /// How numbers are displayed, configured with the `--number-locale` and
+    /// `--scale-units` run-time options.
+    pub(crate) number_format: GooseNumberFormat,
 }
 impl GooseMetrics {
     /// Initialize the task_metrics vector, and determine which hosts are being
@@ -884,6 +1970,15 @@ impl GooseMetrics {
         defaults: &GooseDefaults,
     ) -> Result<(), GooseError> {
         self.tasks = Vec::new();
+        self.groups = Vec::new();
+        // Labels were validated at startup.
+        self.labels = config
+            .label
+            .iter()
+            .filter_map(|label| util::parse_label(label))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        self.run_id = config.run_id.to_string();
         for task_set in task_sets {
             // Don't initialize task metrics if metrics or task_metrics are disabled.
             if !config.no_metrics {
@@ -898,6 +1993,22 @@ impl GooseMetrics {
                         ));
                     }
                     self.tasks.push(task_vector);
+
+                    self.groups.push(
+                        task_set
+                            .groups
+                            .iter()
+                            .enumerate()
+                            .map(|(group_index, group)| {
+                                GooseTaskMetricAggregate::new(
+                                    task_set.task_sets_index,
+                                    &task_set.name,
+                                    group_index,
+                                    &group.name,
+                                )
+                            })
+                            .collect(),
+                    );
                 }
 
                 // The host is not needed on the Worker, metrics are only printed on
@@ -927,8 +2038,29 @@ impl GooseMetrics {
         Ok(())
     }
 
+    /// Returns the key and name a request is tracked under in `GooseMetrics.requests`. Once
+    /// too many distinct requests are tracked, further requests are grouped together by
+    /// method as `(other)`, logging a warning the first time.
+    pub(crate) fn request_key(&self, method: &impl fmt::Display, name: &str) -> (String, String) {
+        let key = format!("{} {}", method, name);
+        if self.requests.contains_key(&key) || self.requests.len() < MAX_REQUEST_NAMES {
+            return (key, name.to_string());
+        }
+        let other_key = format!("{} (other)", method);
+        if !self.requests.contains_key(&other_key) {
+            warn!(
+                "more than {} distinct requests, grouping further {} requests as \"(other)\": name requests with dynamic paths to track them",
+                MAX_REQUEST_NAMES, method
+            );
+        }
+        (other_key, "(other)".to_string())
+    }
+
     /// Consumes and display all enabled metrics from a completed load test.
     ///
+    /// With `--output json` the metrics are printed as a single line of JSON, and with
+    /// `--output quiet` nothing is printed.
+    ///
     /// # Example
     /// ```rust
     /// use goose::prelude::*;
@@ -958,8 +2090,17 @@ impl GooseMetrics {
     /// ```
     pub fn print(&self) {
         if self.display_metrics {
-            info!("printing final metrics after {} seconds...", self.duration);
-            print!("{}", self);
+            match self.output {
+                GooseOutput::Verbose | GooseOutput::Summary => {
+                    info!("printing final metrics after {} seconds...", self.duration);
+                    print!("{}", self);
+                }
+                GooseOutput::Json => match serde_json::to_string(self) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => error!("failed to serialize final metrics: {}", e),
+                },
+                GooseOutput::Quiet => (),
+            }
         }
     }
 
@@ -970,7 +2111,7 @@ impl GooseMetrics {
     /// is invoked at regular intervals if the `--running-metrics` run-time
     /// option is enabled.
     pub(crate) fn print_running(&self) {
-        if self.display_metrics {
+        if self.display_metrics && self.output == GooseOutput::Verbose {
             info!(
                 "printing running metrics after {} seconds...",
                 self.duration
@@ -981,6 +2122,32 @@ impl GooseMetrics {
         }
     }
 
+    /// Compares each request with the same request in the baseline, if a baseline was
+    /// loaded. Requests that only ran in one of the load tests aren't compared.
+    pub(crate) fn baseline_comparisons(&self) -> Vec<(&String, GooseBaselineComparison)> {
+        let baseline = match self.baseline.as_ref() {
+            Some(baseline) => baseline,
+            None => return Vec::new(),
+        };
+        self.requests
+            .iter()
+            .sorted()
+            .filter_map(|(request_key, request)| {
+                baseline.requests.get(request_key).map(|baseline_request| {
+                    (
+                        request_key,
+                        GooseBaselineComparison::new(
+                            request,
+                            self.duration,
+                            baseline_request,
+                            baseline.duration,
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// Optionally prepares a table of requests and fails.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
@@ -1016,40 +2183,34 @@ impl GooseMetrics {
             };
             let (reqs, fails) =
                 per_second_calculations(self.duration, total_count, request.fail_count);
-            let reqs_precision = determine_precision(reqs);
-            let fails_precision = determine_precision(fails);
             // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
             if fail_percent as usize == 100 || fail_percent as usize == 0 {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.reqs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     util::truncate_string(request_key, 24),
-                    total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(total_count),
                     format!(
                         "{} ({}%)",
-                        request.fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(request.fail_count),
                         fail_percent as usize
                     ),
-                    reqs,
-                    fails,
-                    reqs_p = reqs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(reqs),
+                    self.number_format.decimal(fails),
                 )?;
             } else {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.reqs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     util::truncate_string(request_key, 24),
-                    total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(total_count),
                     format!(
                         "{} ({:.1}%)",
-                        request.fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(request.fail_count),
                         fail_percent
                     ),
-                    reqs,
-                    fails,
-                    reqs_p = reqs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(reqs),
+                    self.number_format.decimal(fails),
                 )?;
             }
             aggregate_total_count += total_count;
@@ -1067,40 +2228,34 @@ impl GooseMetrics {
             )?;
             let (reqs, fails) =
                 per_second_calculations(self.duration, aggregate_total_count, aggregate_fail_count);
-            let reqs_precision = determine_precision(reqs);
-            let fails_precision = determine_precision(fails);
             // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
             if aggregate_fail_percent as usize == 100 || aggregate_fail_percent as usize == 0 {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.reqs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     "Aggregated",
-                    aggregate_total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(aggregate_total_count),
                     format!(
                         "{} ({}%)",
-                        aggregate_fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(aggregate_fail_count),
                         aggregate_fail_percent as usize
                     ),
-                    reqs,
-                    fails,
-                    reqs_p = reqs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(reqs),
+                    self.number_format.decimal(fails),
                 )?;
             } else {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.reqs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     "Aggregated",
-                    aggregate_total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(aggregate_total_count),
                     format!(
                         "{} ({:.1}%)",
-                        aggregate_fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(aggregate_fail_count),
                         aggregate_fail_percent
                     ),
-                    reqs,
-                    fails,
-                    reqs_p = reqs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(reqs),
+                    self.number_format.decimal(fails),
                 )?;
             }
         }
@@ -1147,8 +2302,6 @@ impl GooseMetrics {
                 };
                 let (runs, fails) =
                     per_second_calculations(self.duration, total_count, task.fail_count);
-                let runs_precision = determine_precision(runs);
-                let fails_precision = determine_precision(fails);
 
                 // First time through display name of task set.
                 if !displayed_task_set {
@@ -1166,40 +2319,36 @@ impl GooseMetrics {
                 if fail_percent as usize == 100 || fail_percent as usize == 0 {
                     writeln!(
                         fmt,
-                        " {:<24} | {:>13} | {:>14} | {:>8.runs_p$} | {:>7.fails_p$}",
+                        " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                         util::truncate_string(
                             &format!("  {}: {}", task.task_index + 1, task.task_name),
                             24
                         ),
-                        total_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(total_count),
                         format!(
                             "{} ({}%)",
-                            task.fail_count.to_formatted_string(&Locale::en),
+                            self.number_format.number(task.fail_count),
                             fail_percent as usize
                         ),
-                        runs,
-                        fails,
-                        runs_p = runs_precision,
-                        fails_p = fails_precision,
+                        self.number_format.decimal(runs),
+                        self.number_format.decimal(fails),
                     )?;
                 } else {
                     writeln!(
                         fmt,
-                        " {:<24} | {:>13} | {:>14} | {:>8.runs_p$} | {:>7.fails_p$}",
+                        " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                         util::truncate_string(
                             &format!("  {}: {}", task.task_index + 1, task.task_name),
                             24
                         ),
-                        total_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(total_count),
                         format!(
                             "{} ({:.1}%)",
-                            task.fail_count.to_formatted_string(&Locale::en),
+                            self.number_format.number(task.fail_count),
                             fail_percent
                         ),
-                        runs,
-                        fails,
-                        runs_p = runs_precision,
-                        fails_p = fails_precision,
+                        self.number_format.decimal(runs),
+                        self.number_format.decimal(fails),
                     )?;
                 }
                 aggregate_total_count += total_count;
@@ -1218,41 +2367,35 @@ impl GooseMetrics {
             )?;
             let (runs, fails) =
                 per_second_calculations(self.duration, aggregate_total_count, aggregate_fail_count);
-            let runs_precision = determine_precision(runs);
-            let fails_precision = determine_precision(fails);
 
             // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
             if aggregate_fail_percent as usize == 100 || aggregate_fail_percent as usize == 0 {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.runs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     "Aggregated",
-                    aggregate_total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(aggregate_total_count),
                     format!(
                         "{} ({}%)",
-                        aggregate_fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(aggregate_fail_count),
                         aggregate_fail_percent as usize
                     ),
-                    runs,
-                    fails,
-                    runs_p = runs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(runs),
+                    self.number_format.decimal(fails),
                 )?;
             } else {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>13} | {:>14} | {:>8.runs_p$} | {:>7.fails_p$}",
+                    " {:<24} | {:>13} | {:>14} | {:>8} | {:>7}",
                     "Aggregated",
-                    aggregate_total_count.to_formatted_string(&Locale::en),
+                    self.number_format.number(aggregate_total_count),
                     format!(
                         "{} ({:.1}%)",
-                        aggregate_fail_count.to_formatted_string(&Locale::en),
+                        self.number_format.number(aggregate_fail_count),
                         aggregate_fail_percent
                     ),
-                    runs,
-                    fails,
-                    runs_p = runs_precision,
-                    fails_p = fails_precision,
+                    self.number_format.decimal(runs),
+                    self.number_format.decimal(fails),
                 )?;
             }
         }
@@ -1282,7 +2425,11 @@ impl GooseMetrics {
         writeln!(
             fmt,
             " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
-            "Name", "Avg (ms)", "Min", "Max", "Median"
+            "Name",
+            self.number_format.time_label("Avg"),
+            "Min",
+            "Max",
+            "Median"
         )?;
         writeln!(
             fmt,
@@ -1325,25 +2472,24 @@ impl GooseMetrics {
                     0 => 0.00,
                     _ => task.total_time as f32 / task.counter as f32,
                 };
-                let average_precision = determine_precision(average);
 
                 writeln!(
                     fmt,
-                    " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
+                    " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                     util::truncate_string(
                         &format!("  {}: {}", task.task_index + 1, task.task_name),
                         24
                     ),
-                    average,
-                    format_number(task.min_time),
-                    format_number(task.max_time),
-                    format_number(util::median(
+                    self.number_format.average_time(average),
+                    self.number_format.time(task.min_time),
+                    self.number_format.time(task.max_time),
+                    format_median(
                         &task.times,
                         task.counter,
                         task.min_time,
-                        task.max_time
-                    )),
-                    avg_precision = average_precision,
+                        task.max_time,
+                        &self.number_format
+                    ),
                 )?;
             }
         }
@@ -1352,7 +2498,6 @@ impl GooseMetrics {
                 0 => 0.00,
                 _ => aggregate_total_task_time as f32 / aggregate_task_time_counter as f32,
             };
-            let average_precision = determine_precision(average);
 
             writeln!(
                 fmt,
@@ -1360,18 +2505,18 @@ impl GooseMetrics {
             )?;
             writeln!(
                 fmt,
-                " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
+                " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                 "Aggregated",
-                average,
-                format_number(aggregate_min_task_time),
-                format_number(aggregate_max_task_time),
-                format_number(util::median(
+                self.number_format.average_time(average),
+                self.number_format.time(aggregate_min_task_time),
+                self.number_format.time(aggregate_max_task_time),
+                format_median(
                     &aggregate_task_times,
                     aggregate_task_time_counter,
                     aggregate_min_task_time,
-                    aggregate_max_task_time
-                )),
-                avg_precision = average_precision,
+                    aggregate_max_task_time,
+                    &self.number_format
+                ),
             )?;
         }
 
@@ -1400,7 +2545,11 @@ impl GooseMetrics {
         writeln!(
             fmt,
             " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
-            "Name", "Avg (ms)", "Min", "Max", "Median"
+            "Name",
+            self.number_format.time_label("Avg"),
+            "Min",
+            "Max",
+            "Median"
         )?;
         writeln!(
             fmt,
@@ -1418,7 +2567,6 @@ impl GooseMetrics {
                 0 => 0.0,
                 _ => request.raw_data.total_time as f32 / request.raw_data.counter as f32,
             };
-            let raw_average_precision = determine_precision(raw_average);
 
             // Merge in all times from this request into an aggregate.
             aggregate_raw_times = merge_times(aggregate_raw_times, request.raw_data.times.clone());
@@ -1435,18 +2583,18 @@ impl GooseMetrics {
 
             writeln!(
                 fmt,
-                " {:<24} | {:>11.raw_avg_precision$} | {:>10} | {:>11} | {:>10}",
+                " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                 util::truncate_string(request_key, 24),
-                raw_average,
-                format_number(request.raw_data.minimum_time),
-                format_number(request.raw_data.maximum_time),
-                format_number(util::median(
+                self.number_format.average_time(raw_average),
+                self.number_format.time(request.raw_data.minimum_time),
+                self.number_format.time(request.raw_data.maximum_time),
+                format_median(
                     &request.raw_data.times,
                     request.raw_data.counter,
                     request.raw_data.minimum_time,
                     request.raw_data.maximum_time,
-                )),
-                raw_avg_precision = raw_average_precision,
+                    &self.number_format,
+                ),
             )?;
         }
 
@@ -1454,7 +2602,6 @@ impl GooseMetrics {
             0 => 0.0,
             _ => aggregate_raw_total_time as f32 / aggregate_raw_counter as f32,
         };
-        let raw_average_precision = determine_precision(raw_average);
 
         // Display aggregated data if there was more than one request.
         if self.requests.len() > 1 {
@@ -1464,18 +2611,18 @@ impl GooseMetrics {
             )?;
             writeln!(
                 fmt,
-                " {:<24} | {:>11.avg_precision$} | {:>10} | {:>11} | {:>10}",
+                " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                 "Aggregated",
-                raw_average,
-                format_number(aggregate_raw_min_time),
-                format_number(aggregate_raw_max_time),
-                format_number(util::median(
+                self.number_format.average_time(raw_average),
+                self.number_format.time(aggregate_raw_min_time),
+                self.number_format.time(aggregate_raw_max_time),
+                format_median(
                     &aggregate_raw_times,
                     aggregate_raw_counter,
                     aggregate_raw_min_time,
-                    aggregate_raw_max_time
-                )),
-                avg_precision = raw_average_precision,
+                    aggregate_raw_max_time,
+                    &self.number_format
+                ),
             )?;
         }
 
@@ -1502,7 +2649,11 @@ impl GooseMetrics {
         writeln!(
             fmt,
             " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
-            "Name", "Avg (ms)", "Std Dev", "Max", "Median"
+            "Name",
+            self.number_format.time_label("Avg"),
+            "Std Dev",
+            "Max",
+            "Median"
         )?;
         writeln!(
             fmt,
@@ -1542,26 +2693,23 @@ impl GooseMetrics {
                 co_minimum = 0;
                 co_maximum = 0;
             }
-            let co_average_precision = determine_precision(co_average);
-            let standard_deviation_precision = determine_precision(standard_deviation);
 
             // Coordinated Omission Mitigation was enabled for this request, display the extra data:
             if let Some(co_data) = request.coordinated_omission_data.as_ref() {
                 writeln!(
                     fmt,
-                    " {:<24} | {:>11.co_avg_precision$} | {:>10.sd_precision$} | {:>11} | {:>10}",
+                    " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                     util::truncate_string(request_key, 24),
-                    co_average,
-                    standard_deviation,
-                    format_number(co_maximum),
-                    format_number(util::median(
+                    self.number_format.average_time(co_average),
+                    self.number_format.average_time(standard_deviation),
+                    self.number_format.time(co_maximum),
+                    format_median(
                         &co_data.times,
                         co_data.counter,
                         co_minimum,
                         co_maximum,
-                    )),
-                    co_avg_precision = co_average_precision,
-                    sd_precision = standard_deviation_precision,
+                        &self.number_format,
+                    ),
                 )?;
             } else {
                 writeln!(
@@ -1582,9 +2730,7 @@ impl GooseMetrics {
                 0 => 0.0,
                 _ => aggregate_co_total_time as f32 / aggregate_co_counter as f32,
             };
-            let co_average_precision = determine_precision(co_average);
             let standard_deviation = util::standard_deviation(raw_average, co_average);
-            let standard_deviation_precision = determine_precision(standard_deviation);
 
             writeln!(
                 fmt,
@@ -1593,19 +2739,18 @@ impl GooseMetrics {
 
             writeln!(
                 fmt,
-                " {:<24} | {:>11.avg_precision$} | {:>10.sd_precision$} | {:>11} | {:>10}",
+                " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
                 "Aggregated",
-                co_average,
-                standard_deviation,
-                format_number(aggregate_co_max_time),
-                format_number(util::median(
+                self.number_format.average_time(co_average),
+                self.number_format.average_time(standard_deviation),
+                self.number_format.time(aggregate_co_max_time),
+                format_median(
                     &aggregate_co_times,
                     aggregate_co_counter,
                     aggregate_co_min_time,
-                    aggregate_co_max_time
-                )),
-                avg_precision = co_average_precision,
-                sd_precision = standard_deviation_precision,
+                    aggregate_co_max_time,
+                    &self.number_format
+                ),
             )?;
         }
 
@@ -1622,18 +2767,53 @@ impl GooseMetrics {
             return Ok(());
         }
 
-        let mut raw_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut raw_aggregate_total_response_time: usize = 0;
-        let mut raw_aggregate_response_time_counter: usize = 0;
-        let mut raw_aggregate_min_response_time: usize = 0;
-        let mut raw_aggregate_max_response_time: usize = 0;
+        // Percentiles can't be calculated if response times were discarded.
+        if !self.requests.is_empty()
+            && self
+                .requests
+                .values()
+                .all(|request| request.raw_data.times.is_empty())
+        {
+            return Ok(());
+        }
+
+        // The percentiles displayed for each request.
+        const DISPLAYED_PERCENTILES: [f32; 6] = [0.5, 0.75, 0.98, 0.99, 0.999, 0.9999];
+        let write_row = |fmt: &mut fmt::Formatter<'_>,
+                         name: &str,
+                         data: &GooseRequestMetricTimingData|
+         -> fmt::Result {
+            let percentiles: Vec<String> = DISPLAYED_PERCENTILES
+                .iter()
+                .map(|percent| data.format_percentile(*percent, &self.number_format))
+                .collect();
+            writeln!(
+                fmt,
+                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
+                util::truncate_string(name, 24),
+                percentiles[0],
+                percentiles[1],
+                percentiles[2],
+                percentiles[3],
+                percentiles[4],
+                percentiles[5],
+            )
+        };
+
+        let mut raw_aggregate_data = GooseRequestMetricTimingData::with_histogram();
+
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
         writeln!(
             fmt,
-            " Slowest page load within specified percentile of requests (in ms):"
+            " Slowest page load within specified percentile of requests{}:",
+            if self.number_format.scale_units {
+                ""
+            } else {
+                " (in ms)"
+            }
         )?;
         writeln!(
             fmt,
@@ -1655,128 +2835,17 @@ impl GooseMetrics {
                 co_data = true;
             }
 
-            // Iterate over user response times, and merge into global response times.
-            raw_aggregate_response_times =
-                merge_times(raw_aggregate_response_times, request.raw_data.times.clone());
-
-            // Increment total response time counter.
-            raw_aggregate_total_response_time += &request.raw_data.total_time;
-
-            // Increment counter tracking individual response times seen.
-            raw_aggregate_response_time_counter += &request.raw_data.counter;
-
-            // If user had new fastest response time, update global fastest response time.
-            raw_aggregate_min_response_time = update_min_time(
-                raw_aggregate_min_response_time,
-                request.raw_data.minimum_time,
-            );
+            // Merge per-request response times into global response times.
+            raw_aggregate_data.merge(&request.raw_data);
 
-            // If user had new slowest response time, update global slowest response time.
-            raw_aggregate_max_response_time = update_max_time(
-                raw_aggregate_max_response_time,
-                request.raw_data.maximum_time,
-            );
-            // Sort response times so we can calculate a mean.
-            writeln!(
-                fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                util::truncate_string(request_key, 24),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                    0.9999
-                ),
-            )?;
+            write_row(fmt, request_key, &request.raw_data)?;
         }
         if self.requests.len() > 1 {
             writeln!(
                 fmt,
                 " -------------------------+--------+--------+--------+--------+--------+-------"
             )?;
-            writeln!(
-                fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                "Aggregated",
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
-                    &raw_aggregate_response_times,
-                    raw_aggregate_response_time_counter,
-                    raw_aggregate_min_response_time,
-                    raw_aggregate_max_response_time,
-                    0.9999
-                ),
-            )?;
+            write_row(fmt, "Aggregated", &raw_aggregate_data)?;
         }
 
         // If there's no Coordinated Omission Mitigation data to display, exit.
@@ -1784,11 +2853,7 @@ impl GooseMetrics {
             return Ok(());
         }
 
-        let mut co_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut co_aggregate_total_response_time: usize = 0;
-        let mut co_aggregate_response_time_counter: usize = 0;
-        let mut co_aggregate_min_response_time: usize = 0;
-        let mut co_aggregate_max_response_time: usize = 0;
+        let mut co_aggregate_data = GooseRequestMetricTimingData::new(None);
 
         writeln!(
             fmt,
@@ -1810,78 +2875,10 @@ impl GooseMetrics {
         )?;
         for (request_key, request) in self.requests.iter().sorted() {
             if let Some(coordinated_omission_data) = request.coordinated_omission_data.as_ref() {
-                // Iterate over user response times, and merge into global response times.
-                co_aggregate_response_times = merge_times(
-                    co_aggregate_response_times,
-                    coordinated_omission_data.times.clone(),
-                );
-
-                // Increment total response time counter.
-                co_aggregate_total_response_time += &coordinated_omission_data.total_time;
-
-                // Increment counter tracking individual response times seen.
-                co_aggregate_response_time_counter += &coordinated_omission_data.counter;
-
-                // If user had new fastest response time, update global fastest response time.
-                co_aggregate_min_response_time = update_min_time(
-                    co_aggregate_min_response_time,
-                    coordinated_omission_data.minimum_time,
-                );
-
-                // If user had new slowest response time, update global slowest response time.
-                co_aggregate_max_response_time = update_max_time(
-                    co_aggregate_max_response_time,
-                    coordinated_omission_data.maximum_time,
-                );
+                // Merge per-request response times into global response times.
+                co_aggregate_data.merge(coordinated_omission_data);
 
-                // Sort response times so we can calculate a mean.
-                writeln!(
-                    fmt,
-                    " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                    util::truncate_string(request_key, 24),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.5
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.75
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.98
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.99
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.999
-                    ),
-                    calculate_response_time_percentile(
-                        &coordinated_omission_data.times,
-                        coordinated_omission_data.counter,
-                        coordinated_omission_data.minimum_time,
-                        coordinated_omission_data.maximum_time,
-                        0.9999
-                    ),
-                )?;
+                write_row(fmt, request_key, coordinated_omission_data)?;
             } else {
                 writeln!(
                     fmt,
@@ -1901,53 +2898,7 @@ impl GooseMetrics {
                 fmt,
                 " -------------------------+--------+--------+--------+--------+--------+-------"
             )?;
-            writeln!(
-                fmt,
-                " {:<24} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6} | {:>6}",
-                "Aggregated",
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.5
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.75
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.98
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.99
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.999
-                ),
-                calculate_response_time_percentile(
-                    &co_aggregate_response_times,
-                    co_aggregate_response_time_counter,
-                    co_aggregate_min_response_time,
-                    co_aggregate_max_response_time,
-                    0.9999
-                ),
-            )?;
+            write_row(fmt, "Aggregated", &co_aggregate_data)?;
         }
 
         Ok(())
@@ -1977,6 +2928,7 @@ impl GooseMetrics {
             let codes = prepare_status_codes(
                 &request.status_code_counts,
                 &mut Some(&mut aggregated_status_code_counts),
+                &self.number_format,
             );
 
             writeln!(
@@ -1990,104 +2942,1217 @@ impl GooseMetrics {
             fmt,
             " -------------------------+----------------------------------------------------"
         )?;
-        let codes = prepare_status_codes(&aggregated_status_code_counts, &mut None);
+        let codes = prepare_status_codes(
+            &aggregated_status_code_counts,
+            &mut None,
+            &self.number_format,
+        );
         writeln!(fmt, " {:<24} | {:>51} ", "Aggregated", codes)?;
 
         Ok(())
     }
 
-    /// Optionally prepares a table of errors.
+    /// Optionally prepares a table of requests with a response time budget, counting how
+    /// many responses were slower than the budget.
     ///
     /// This function is invoked by `GooseMetrics::print()` and
     /// `GooseMetrics::print_running()`.
-    pub(crate) fn fmt_errors(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Only include errors when displaying the final metrics report, and if there are
-        // errors to display.
-        if !self.final_metrics || self.errors.is_empty() {
+    pub(crate) fn fmt_response_time_budgets(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| request.response_time_budget.is_some())
+        {
             return Ok(());
         }
 
-        // Write the errors into a vector which can then be sorted by occurrences.
-        let mut errors: Vec<(usize, String)> = Vec::new();
-        for error in self.errors.values() {
-            errors.push((
-                error.occurrences,
-                format!("{} {}: {}", error.method, error.name, error.error),
-            ));
-        }
-
         writeln!(
             fmt,
-            "\n === ERRORS ===\n ------------------------------------------------------------------------------"
+            "\n === RESPONSE TIME BUDGETS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>13} | {:>18}",
+            "Name",
+            self.number_format.time_label("Budget"),
+            "# reqs",
+            "# slow"
         )?;
-        writeln!(fmt, " {:<11} | Error", "Count")?;
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            let budget = match request.response_time_budget {
+                Some(budget) => budget,
+                None => continue,
+            };
+            let total_count = request.success_count + request.fail_count;
+            let slow_percent = if request.slow_count > 0 {
+                request.slow_count as f32 / total_count as f32 * 100.0
+            } else {
+                0.0
+            };
+            // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
+            let slow = if slow_percent as usize == 100 || slow_percent as usize == 0 {
+                format!(
+                    "{} ({}%)",
+                    self.number_format.number(request.slow_count),
+                    slow_percent as usize
+                )
+            } else {
+                format!(
+                    "{} ({:.1}%)",
+                    self.number_format.number(request.slow_count),
+                    slow_percent
+                )
+            };
+            writeln!(
+                fmt,
+                " {:<24} | {:>13} | {:>13} | {:>18}",
+                util::truncate_string(request_key, 24),
+                self.number_format.time(budget as usize),
+                self.number_format.number(total_count),
+                slow,
+            )?;
+        }
 
-        // Reverse sort errors to display the error occuring the most first.
-        for (occurrences, error) in errors.iter().sorted().rev() {
-            writeln!(fmt, " {:<12}  {}", format_number(*occurrences), error)?;
+        Ok(())
+    }
+
+    /// Optionally prepares a table of requests with verified response bodies, counting how
+    /// many bodies didn't match the expected checksum or length.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_integrity(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| request.integrity_checks > 0)
+        {
+            return Ok(());
         }
 
+        writeln!(
+            fmt,
+            "\n === RESPONSE INTEGRITY ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>13} | {:>18}",
+            "Name", "# reqs", "# verified", "# failures"
+        )?;
         writeln!(
             fmt,
             " ------------------------------------------------------------------------------"
         )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.integrity_checks == 0 {
+                continue;
+            }
+            let failure_percent =
+                request.integrity_failures as f32 / request.integrity_checks as f32 * 100.0;
+            // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
+            let failures = if failure_percent as usize == 100 || failure_percent as usize == 0 {
+                format!(
+                    "{} ({}%)",
+                    self.number_format.number(request.integrity_failures),
+                    failure_percent as usize
+                )
+            } else {
+                format!(
+                    "{} ({:.1}%)",
+                    self.number_format.number(request.integrity_failures),
+                    failure_percent
+                )
+            };
+            writeln!(
+                fmt,
+                " {:<24} | {:>13} | {:>13} | {:>18}",
+                util::truncate_string(request_key, 24),
+                self.number_format
+                    .number(request.success_count + request.fail_count),
+                self.number_format.number(request.integrity_checks),
+                failures,
+            )?;
+        }
 
         Ok(())
     }
 
-    // Determine the seconds, minutes and hours between two chrono:DateTimes.
-    fn get_seconds_minutes_hours(
-        &self,
-        start: &chrono::DateTime<chrono::Local>,
-        end: &chrono::DateTime<chrono::Local>,
-    ) -> (i64, i64, i64) {
-        let duration = end.timestamp() - start.timestamp();
-        let seconds = duration % 60;
-        let minutes = (duration / 60) % 60;
-        let hours = duration / 60 / 60;
-        (seconds, minutes, hours)
-    }
-
-    /// Optionally prepares an overview table.
+    /// Optionally prepares a table of requests that returned `409 Conflict` or
+    /// `412 Precondition Failed` responses, as write APIs do when writes conflict.
     ///
-    /// This function is invoked by [`GooseMetrics::print()`].
-    pub(crate) fn fmt_overview(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Only display overview in the final metrics.
-        if !self.final_metrics || self.starting.is_none() {
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_write_conflicts(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| request.conflicts + request.precondition_failures > 0)
+        {
             return Ok(());
         }
 
-        // Calculations necessary for overview table.
-        let starting = self.starting.unwrap();
-        let starting_time = starting.format("%Y-%m-%d %H:%M:%S").to_string();
-        let started = if self.started.is_some() {
-            self.started.unwrap()
-        } else {
-            self.stopping.unwrap()
-        };
-        let (starting_seconds, starting_minutes, starting_hours) =
-            self.get_seconds_minutes_hours(&starting, &started);
-        let start_time = started.format("%Y-%m-%d %H:%M:%S").to_string();
-        let stopping = self.stopping.unwrap();
-        let (running_seconds, running_minutes, running_hours) =
-            self.get_seconds_minutes_hours(&started, &stopping);
-        let stopping_time = stopping.format("%Y-%m-%d %H:%M:%S").to_string();
-        let stopped = self.stopped.unwrap();
-        let stopped_time = stopped.format("%Y-%m-%d %H:%M:%S").to_string();
-        let (stopping_seconds, stopping_minutes, stopping_hours) =
-            self.get_seconds_minutes_hours(&stopping, &stopped);
-
         writeln!(
             fmt,
-            " ------------------------------------------------------------------------------"
+            "\n === WRITE CONFLICTS ===\n ------------------------------------------------------------------------------"
         )?;
-        writeln!(fmt, " Users: {}", self.users)?;
-        match self.hosts.len() {
-            0 => {
+        writeln!(
+            fmt,
+            " {:<24} | {:>10} | {:>14} | {:>18}",
+            "Name", "# reqs", "# 409 conflict", "# 412 precondition"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.conflicts + request.precondition_failures == 0 {
+                continue;
+            }
+            writeln!(
+                fmt,
+                " {:<24} | {:>10} | {:>14} | {:>18}",
+                util::truncate_string(request_key, 24),
+                self.number_format
+                    .number(request.success_count + request.fail_count),
+                self.number_format.number(request.conflicts),
+                self.number_format.number(request.precondition_failures),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of requests with responses validated against a JSON
+    /// Schema, counting how many bodies weren't JSON or didn't match the schema.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_schema(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| request.schema_checks > 0)
+        {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === RESPONSE SCHEMA ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>13} | {:>18}",
+            "Name", "# reqs", "# validated", "# violations"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.schema_checks == 0 {
+                continue;
+            }
+            let violation_percent =
+                request.schema_violations as f32 / request.schema_checks as f32 * 100.0;
+            // Compress 100.0 and 0.0 to 100 and 0 respectively to save width.
+            let violations = if violation_percent as usize == 100 || violation_percent as usize == 0
+            {
+                format!(
+                    "{} ({}%)",
+                    self.number_format.number(request.schema_violations),
+                    violation_percent as usize
+                )
+            } else {
+                format!(
+                    "{} ({:.1}%)",
+                    self.number_format.number(request.schema_violations),
+                    violation_percent
+                )
+            };
+            writeln!(
+                fmt,
+                " {:<24} | {:>13} | {:>13} | {:>18}",
+                util::truncate_string(request_key, 24),
+                self.number_format
+                    .number(request.success_count + request.fail_count),
+                self.number_format.number(request.schema_checks),
+                violations,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table comparing requests with the same requests in the
+    /// baseline loaded with `--baseline-file`.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_baseline_comparison(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        let comparisons = self.baseline_comparisons();
+        if comparisons.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === BASELINE COMPARISON ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>17} | {:>14} | {:>13}",
+            "Name",
+            self.number_format.time_label("Avg"),
+            "req/s",
+            "fail %"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (request_key, comparison) in comparisons {
+            writeln!(
+                fmt,
+                " {:<24} | {:>17} | {:>14} | {:>13}",
+                util::truncate_string(request_key, 24),
+                format!(
+                    "{} ({})",
+                    self.number_format.average_time(comparison.average),
+                    baseline_change(comparison.average_change)
+                ),
+                format!(
+                    "{} ({})",
+                    self.number_format.decimal(comparison.requests_per_second),
+                    baseline_change(comparison.requests_per_second_change)
+                ),
+                format!(
+                    "{:.1}% ({:+.1})",
+                    comparison.fail_percent, comparison.fail_percent_change
+                ),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of session affinity, showing how many backends served
+    /// each request and how often users were moved to a different backend.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_session_affinity(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| !request.backends.is_empty())
+        {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === SESSION AFFINITY ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>13} | {:>18}",
+            "Name", "# reqs", "# backends", "# violations"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut backends: HashMap<&str, usize> = HashMap::new();
+        let mut violations = 0;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.backends.is_empty() {
+                continue;
+            }
+            for (backend, count) in &request.backends {
+                *backends.entry(backend).or_insert(0) += count;
+            }
+            violations += request.affinity_violations;
+            writeln!(
+                fmt,
+                " {:<24} | {:>13} | {:>13} | {:>18}",
+                util::truncate_string(request_key, 24),
+                self.number_format
+                    .number(request.backends.values().sum::<usize>()),
+                self.number_format.number(request.backends.len()),
+                self.number_format.number(request.affinity_violations),
+            )?;
+        }
+        writeln!(
+            fmt,
+            " -------------------------+---------------+---------------+-------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>13} | {:>18}",
+            "Aggregated",
+            self.number_format.number(backends.values().sum::<usize>()),
+            self.number_format.number(backends.len()),
+            self.number_format.number(violations),
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of response times grouped by the values of the
+    /// response headers configured with `--header-dimensions`, and by device profile.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_dimensions(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| !request.dimensions.is_empty())
+        {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === RESPONSE TIME DIMENSIONS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
+            "Name",
+            "# reqs",
+            self.number_format.time_label("Avg"),
+            "Min",
+            "Max"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.dimensions.is_empty() {
+                continue;
+            }
+            writeln!(fmt, " {:<24} |", util::truncate_string(request_key, 24))?;
+            for (dimension, data) in &request.dimensions {
+                let average = match data.counter {
+                    0 => 0.0,
+                    _ => data.total_time as f32 / data.counter as f32,
+                };
+                writeln!(
+                    fmt,
+                    "   {:<22} | {:>11} | {:>10} | {:>11} | {:>10}",
+                    util::truncate_string(dimension, 22),
+                    self.number_format.number(data.counter),
+                    self.number_format.average_time(average),
+                    self.number_format.time(data.minimum_time),
+                    self.number_format.time(data.maximum_time),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of responses served by an intermediary cache and by the
+    /// origin, comparing their response times, if tracked with `--cache-status`.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_cache_status(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || !self
+                .requests
+                .values()
+                .any(|request| request.cache_data.counter + request.origin_data.counter > 0)
+        {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === CACHE STATUS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>10} | {:>11} | {:>10}",
+            "Name",
+            "# reqs",
+            self.number_format.time_label("Avg"),
+            "50%",
+            "95%"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (request_key, request) in self.requests.iter().sorted() {
+            let total = request.cache_data.counter + request.origin_data.counter;
+            if total == 0 {
+                continue;
+            }
+            writeln!(fmt, " {:<24} |", util::truncate_string(request_key, 24))?;
+            for (label, data) in [
+                ("cache", &request.cache_data),
+                ("origin", &request.origin_data),
+            ] {
+                let percent = data.counter as f32 / total as f32 * 100.0;
+                let average = match data.counter {
+                    0 => 0.0,
+                    _ => data.total_time as f32 / data.counter as f32,
+                };
+                writeln!(
+                    fmt,
+                    "   {:<22} | {:>11} | {:>10} | {:>11} | {:>10}",
+                    format!("{} ({:.1}%)", label, percent),
+                    self.number_format.number(data.counter),
+                    self.number_format.average_time(average),
+                    calculate_response_time_percentile(
+                        &data.times,
+                        data.counter,
+                        data.minimum_time,
+                        data.maximum_time,
+                        0.5,
+                        &self.number_format,
+                    ),
+                    calculate_response_time_percentile(
+                        &data.times,
+                        data.counter,
+                        data.minimum_time,
+                        data.maximum_time,
+                        0.95,
+                        &self.number_format,
+                    ),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of response body sizes, comparing the size of bodies as
+    /// received with their decompressed size, if tracked with `--body-sizes`.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_body_sizes(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics || !self.requests.values().any(|request| request.body_count > 0) {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === RESPONSE BODY SIZES ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>8} | {:>12} | {:>12} | {:>8}",
+            "Name", "# bodies", "Avg received", "Avg body", "Saved"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut aggregate_count = 0;
+        let mut aggregate_bytes = 0;
+        let mut aggregate_decompressed_bytes = 0;
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.body_count == 0 {
+                continue;
+            }
+            aggregate_count += request.body_count;
+            aggregate_bytes += request.body_bytes;
+            aggregate_decompressed_bytes += request.decompressed_body_bytes;
+            self.fmt_body_size_row(
+                fmt,
+                request_key,
+                request.body_count,
+                request.body_bytes,
+                request.decompressed_body_bytes,
+            )?;
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        self.fmt_body_size_row(
+            fmt,
+            "Aggregated",
+            aggregate_count,
+            aggregate_bytes,
+            aggregate_decompressed_bytes,
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of how many tasks were running and requests were in
+    /// flight, on average and during the busiest second.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_concurrency(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        let tracked_tasks = self
+            .tasks
+            .iter()
+            .flatten()
+            .any(|task| !task.concurrency.busy.is_empty());
+        let tracked_requests = self
+            .requests
+            .values()
+            .any(|request| !request.concurrency.busy.is_empty());
+        if !self.display_metrics || (!tracked_tasks && !tracked_requests) {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === CONCURRENCY ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>15} | {:>15}",
+            "Name", "Avg in flight", "Max in flight"
+        )?;
+        if tracked_tasks {
+            writeln!(
+                fmt,
+                " ------------------------------------------------------------------------------"
+            )?;
+            for task_set in &self.tasks {
+                let mut displayed_task_set = false;
+                for task in task_set {
+                    if task.concurrency.busy.is_empty() {
+                        continue;
+                    }
+                    // First time through display name of task set.
+                    if !displayed_task_set {
+                        writeln!(
+                            fmt,
+                            " {:<24} |",
+                            util::truncate_string(
+                                &format!("{}: {}", task.taskset_index + 1, task.taskset_name),
+                                60
+                            ),
+                        )?;
+                        displayed_task_set = true;
+                    }
+                    self.fmt_concurrency_row(
+                        fmt,
+                        &format!("  {}: {}", task.task_index + 1, task.task_name),
+                        &task.concurrency,
+                    )?;
+                }
+            }
+        }
+        if tracked_requests {
+            writeln!(
+                fmt,
+                " ------------------------------------------------------------------------------"
+            )?;
+            for (request_key, request) in self.requests.iter().sorted() {
+                if request.concurrency.busy.is_empty() {
+                    continue;
+                }
+                self.fmt_concurrency_row(fmt, request_key, &request.concurrency)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table comparing how many users were planned to run with how
+    /// many actually ran, for the whole load test and for each task set.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_ramp_profiles(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.ramp_profiles.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === USER RAMP ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>9} | {:>10} | {:>9} | {:>12}",
+            "Name", "Planned", "Planned at", "Active", "Reached at"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for ramp_profile in &self.ramp_profiles {
+            let (planned, planned_at, active, reached_at) = ramp_profile.summary();
+            writeln!(
+                fmt,
+                " {:<24} | {:>9} | {:>10} | {:>9} | {:>12}",
+                util::truncate_string(&ramp_profile.display_name(), 24),
+                self.number_format.number(planned),
+                format!("{}s", planned_at),
+                self.number_format.number(active),
+                match reached_at {
+                    Some(second) => format!("{}s", second),
+                    None => "never".to_string(),
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// How many seconds a phase of the test plan ran: until the next phase started, or
+    /// for the last phase until the load test stopped, or until now if it's still running.
+    pub(crate) fn phase_duration(&self, index: usize) -> f32 {
+        let ended = match self.phases.get(index + 1) {
+            Some(next_phase) => next_phase.timestamp,
+            None => self
+                .stopped
+                .or(self.stopping)
+                .unwrap_or_else(Local::now)
+                .timestamp_millis(),
+        };
+        (ended - self.phases[index].timestamp).max(0) as f32 / 1_000.0
+    }
+
+    /// How many requests completed per second during a phase of the test plan.
+    pub(crate) fn phase_requests_per_second(&self, index: usize) -> f32 {
+        let duration = self.phase_duration(index);
+        if duration > 0.0 {
+            self.phases[index].requests.requests() as f32 / duration
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn fmt_phases(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.phases.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === PHASES ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<14} | {:>5} | {:>6} | {:>8} | {:>6} | {:>7} | {:>9}",
+            "Name",
+            "Users",
+            "Start",
+            "Requests",
+            "Fails",
+            "Req/s",
+            self.number_format.time_label("Avg"),
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (index, phase) in self.phases.iter().enumerate() {
+            writeln!(
+                fmt,
+                " {:<14} | {:>5} | {:>6} | {:>8} | {:>6} | {:>7} | {:>9}",
+                util::truncate_string(&phase.name, 14),
+                self.number_format.number(phase.users),
+                format!("{}s", phase.elapsed),
+                self.number_format.number(phase.requests.requests()),
+                self.number_format.number(phase.requests.errors),
+                self.number_format
+                    .fixed(self.phase_requests_per_second(index), 2),
+                self.number_format.average_time(phase.requests.mean()),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Display one row of the concurrency table.
+    fn fmt_concurrency_row(
+        &self,
+        fmt: &mut fmt::Formatter<'_>,
+        name: &str,
+        concurrency: &GooseConcurrency,
+    ) -> fmt::Result {
+        writeln!(
+            fmt,
+            " {:<24} | {:>15} | {:>15}",
+            util::truncate_string(name, 24),
+            self.number_format.fixed(concurrency.average(), 2),
+            self.number_format.fixed(concurrency.maximum(), 2),
+        )
+    }
+
+    // Display one row of the response body sizes table.
+    fn fmt_body_size_row(
+        &self,
+        fmt: &mut fmt::Formatter<'_>,
+        name: &str,
+        count: usize,
+        bytes: usize,
+        decompressed_bytes: usize,
+    ) -> fmt::Result {
+        let saved = if decompressed_bytes > 0 {
+            (1.0 - bytes as f32 / decompressed_bytes as f32) * 100.0
+        } else {
+            0.0
+        };
+        writeln!(
+            fmt,
+            " {:<24} | {:>8} | {:>12} | {:>12} | {:>7.2}%",
+            util::truncate_string(name, 24),
+            self.number_format.number(count),
+            self.number_format.bytes(bytes / count),
+            self.number_format.bytes(decompressed_bytes / count),
+            saved,
+        )
+    }
+
+    /// Optionally prepares a table of TLS handshakes.
+    ///
+    /// This function is invoked by `GooseMetrics::print()`.
+    pub(crate) fn fmt_tls_handshakes(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include TLS handshakes when displaying the final metrics report, and if
+        // any handshakes were counted.
+        let total = self.tls_full_handshakes + self.tls_resumed_handshakes;
+        if !self.final_metrics || total == 0 {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === TLS HANDSHAKES ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>13} | {:>8}",
+            "Handshake", "# handshakes", "%"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for (name, count) in &[
+            ("Full", self.tls_full_handshakes),
+            ("Resumed", self.tls_resumed_handshakes),
+        ] {
+            writeln!(
+                fmt,
+                " {:<24} | {:>13} | {:>7.2}%",
+                name,
+                self.number_format.number(*count),
+                (*count as f32 / total as f32) * 100.0,
+            )?;
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of passes through groups of tasks.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_groups(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.groups.iter().all(Vec::is_empty) || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === PER GROUP METRICS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>12} | {:>10} | {:>9}",
+            "Name",
+            "# passes",
+            "# fails",
+            self.number_format.time_label("Avg"),
+            "Max"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for task_set in &self.groups {
+            let mut displayed_task_set = false;
+            for group in task_set {
+                // First time through display name of task set.
+                if !displayed_task_set {
+                    writeln!(
+                        fmt,
+                        " {:24 } |",
+                        util::truncate_string(
+                            &format!("{}: {}", group.taskset_index + 1, &group.taskset_name),
+                            60
+                        ),
+                    )?;
+                    displayed_task_set = true;
+                }
+
+                let average = match group.counter {
+                    0 => 0.00,
+                    _ => group.total_time as f32 / group.counter as f32,
+                };
+                writeln!(
+                    fmt,
+                    " {:<24} | {:>11} | {:>12} | {:>10} | {:>9}",
+                    util::truncate_string(
+                        &format!("  {}: {}", group.task_index + 1, group.task_name),
+                        24
+                    ),
+                    self.number_format.number(group.counter),
+                    self.number_format.number(group.fail_count),
+                    self.number_format.average_time(average),
+                    self.number_format.time(group.max_time),
+                )?;
+            }
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of task set setup and teardown tasks.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_setup_teardown(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.setup_teardown.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === SETUP AND TEARDOWN ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:<10} | {:>13} | Result",
+            "Name",
+            "Task",
+            self.number_format.time_label("Time")
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        for metric in &self.setup_teardown {
+            writeln!(
+                fmt,
+                " {:<24} | {:<10} | {:>13} | {}",
+                util::truncate_string(
+                    &format!("{}: {}", metric.taskset_index + 1, metric.taskset_name),
+                    24
+                ),
+                metric.kind.to_string(),
+                self.number_format.time(metric.run_time as usize),
+                if metric.success { "ok" } else { "failed" },
+            )?;
+            if let Some(error) = metric.error.as_ref() {
+                writeln!(fmt, "   error: {}", error)?;
+            }
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of the network round-trip time to each host.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_network_baselines(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if self.network_baselines.is_empty() || !self.display_metrics {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === NETWORK BASELINE ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>8} | {:>10} | {:>11} | {:>10}",
+            "Host",
+            "Connects",
+            self.number_format.time_label("Min"),
+            self.number_format.time_label("Median"),
+            self.number_format.time_label("Max"),
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let round_trip_time = |time: Option<f32>| match time {
+            Some(time) => self.number_format.fixed_time(time, 2),
+            None => "-".to_string(),
+        };
+        for baseline in &self.network_baselines {
+            writeln!(
+                fmt,
+                " {:<24} | {:>8} | {:>10} | {:>11} | {:>10}",
+                util::truncate_string(&baseline.host, 24),
+                // How many connects succeeded, of how many were made.
+                format!(
+                    "{}/{}",
+                    self.number_format.number(baseline.round_trip_times.len()),
+                    self.number_format
+                        .number(baseline.round_trip_times.len() + baseline.failures)
+                ),
+                round_trip_time(baseline.minimum()),
+                round_trip_time(baseline.median()),
+                round_trip_time(baseline.maximum()),
+            )?;
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of requests that failed without a response, by kind of
+    /// transport-level failure.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_transport_errors(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there's nothing to display, exit immediately.
+        if !self.display_metrics
+            || self
+                .requests
+                .values()
+                .all(|request| request.transport_errors.is_empty())
+        {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === TRANSPORT ERRORS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(
+            fmt,
+            " {:<24} | {:>11} | {:>10}",
+            "Name", "# fails", "% fails"
+        )?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        let mut aggregate_fail_count = 0;
+        let mut aggregate_transport_errors: BTreeMap<GooseTransportError, usize> = BTreeMap::new();
+        for (request_key, request) in self.requests.iter().sorted() {
+            if request.transport_errors.is_empty() {
+                continue;
+            }
+            aggregate_fail_count += request.fail_count;
+            writeln!(fmt, " {:<24} |", util::truncate_string(request_key, 24))?;
+            for (transport_error, count) in &request.transport_errors {
+                *aggregate_transport_errors
+                    .entry(*transport_error)
+                    .or_insert(0) += count;
+                writeln!(
+                    fmt,
+                    "   {:<22} | {:>11} | {:>9.1}%",
+                    transport_error.to_string(),
+                    self.number_format.number(*count),
+                    *count as f32 / request.fail_count.max(1) as f32 * 100.0,
+                )?;
+            }
+        }
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " {:<24} |", "Aggregated")?;
+        for (transport_error, count) in &aggregate_transport_errors {
+            writeln!(
+                fmt,
+                "   {:<22} | {:>11} | {:>9.1}%",
+                transport_error.to_string(),
+                self.number_format.number(*count),
+                *count as f32 / aggregate_fail_count.max(1) as f32 * 100.0,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of errors.
+    ///
+    /// This function is invoked by `GooseMetrics::print()` and
+    /// `GooseMetrics::print_running()`.
+    pub(crate) fn fmt_errors(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include errors when displaying the final metrics report, and if there are
+        // errors to display.
+        if !self.final_metrics || self.errors.is_empty() {
+            return Ok(());
+        }
+
+        // Write the errors into a vector which can then be sorted by occurrences.
+        let mut errors: Vec<(usize, String)> = Vec::new();
+        for error in self.errors.values() {
+            errors.push((
+                error.occurrences,
+                format!("{} {}: {}", error.method_name(), error.name, error.error),
+            ));
+        }
+
+        writeln!(
+            fmt,
+            "\n === ERRORS ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " {:<11} | Error", "Count")?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        // Reverse sort errors to display the error occuring the most first.
+        for (occurrences, error) in errors.iter().sorted().rev() {
+            writeln!(
+                fmt,
+                " {:<12}  {}",
+                self.number_format.number(*occurrences),
+                error
+            )?;
+        }
+
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    /// Optionally prepares a table of logs that couldn't be written to.
+    ///
+    /// This function is invoked by `GooseMetrics::print()`.
+    pub(crate) fn fmt_log_failures(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only include log failures when displaying the final metrics report, and if
+        // writing to a log failed.
+        if !self.final_metrics || self.log_failures.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(
+            fmt,
+            "\n === LOG WRITE FAILURES ===\n ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " {:<11} | Log", "Count")?;
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        for (log, failures) in &self.log_failures {
+            writeln!(
+                fmt,
+                " {:<12}  {}",
+                self.number_format.number(*failures),
+                log
+            )?;
+        }
+
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+
+        Ok(())
+    }
+
+    // Determine the seconds, minutes and hours between two chrono:DateTimes.
+    fn get_seconds_minutes_hours(
+        &self,
+        start: &chrono::DateTime<chrono::Local>,
+        end: &chrono::DateTime<chrono::Local>,
+    ) -> (i64, i64, i64) {
+        let duration = end.timestamp() - start.timestamp();
+        let seconds = duration % 60;
+        let minutes = (duration / 60) % 60;
+        let hours = duration / 60 / 60;
+        (seconds, minutes, hours)
+    }
+
+    /// Optionally prepares an overview table.
+    ///
+    /// This function is invoked by [`GooseMetrics::print()`].
+    pub(crate) fn fmt_overview(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only display overview in the final metrics.
+        if !self.final_metrics || self.starting.is_none() {
+            return Ok(());
+        }
+
+        // Calculations necessary for overview table.
+        let starting = self.starting.unwrap();
+        let starting_time = starting.format("%Y-%m-%d %H:%M:%S").to_string();
+        let started = if self.started.is_some() {
+            self.started.unwrap()
+        } else {
+            self.stopping.unwrap()
+        };
+        let (starting_seconds, starting_minutes, starting_hours) =
+            self.get_seconds_minutes_hours(&starting, &started);
+        let start_time = started.format("%Y-%m-%d %H:%M:%S").to_string();
+        let stopping = self.stopping.unwrap();
+        let (running_seconds, running_minutes, running_hours) =
+            self.get_seconds_minutes_hours(&started, &stopping);
+        let stopping_time = stopping.format("%Y-%m-%d %H:%M:%S").to_string();
+        let stopped = self.stopped.unwrap();
+        let stopped_time = stopped.format("%Y-%m-%d %H:%M:%S").to_string();
+        let (stopping_seconds, stopping_minutes, stopping_hours) =
+            self.get_seconds_minutes_hours(&stopping, &stopped);
+
+        writeln!(
+            fmt,
+            " ------------------------------------------------------------------------------"
+        )?;
+        writeln!(fmt, " Users: {}", self.users)?;
+        match self.hosts.len() {
+            0 => {
                 // A host is required to run a load test.
                 unreachable!();
             }
@@ -2103,6 +4168,32 @@ impl GooseMetrics {
                 }
             }
         }
+        if !self.labels.is_empty() {
+            writeln!(fmt, " Labels: ")?;
+            for (key, value) in &self.labels {
+                writeln!(fmt, " - {}={}", key, value)?;
+            }
+        }
+        if !self.run_id.is_empty() {
+            writeln!(fmt, " Run ID: {}", self.run_id)?;
+        }
+        if let Some(aborted) = self.aborted.as_ref() {
+            writeln!(fmt, " Aborted: {}", aborted)?;
+        }
+        if self.lost_workers > 0 {
+            writeln!(
+                fmt,
+                " Partial: {} workers lost, their metrics are incomplete",
+                self.lost_workers
+            )?;
+        }
+        if let Some(throttle_rate) = self.throttle_rate {
+            writeln!(
+                fmt,
+                " Adaptive throttle: {} requests/s",
+                self.number_format.fixed(throttle_rate, 2)
+            )?;
+        }
         writeln!(
             fmt,
             " Starting: {} - {} (duration: {:02}:{:02}:{:02})",
@@ -2144,7 +4235,7 @@ impl Serialize for GooseMetrics {
     where
         S: Serializer,
     {
-        let mut s = serializer.serialize_struct("GooseMetrics", 10)?;
+        let mut s = serializer.serialize_struct("GooseMetrics", 27)?;
         s.serialize_field("hash", &self.hash)?;
         // Convert started field to a unix timestamp.
         let timestamp;
@@ -2156,9 +4247,26 @@ impl Serialize for GooseMetrics {
         s.serialize_field("started", &timestamp)?;
         s.serialize_field("duration", &self.duration)?;
         s.serialize_field("users", &self.users)?;
+        s.serialize_field("active_users", &self.active_users)?;
+        s.serialize_field("ramp_profiles", &self.ramp_profiles)?;
+        s.serialize_field("response_time_heatmap", &self.response_time_heatmap)?;
+        s.serialize_field("request_time_series", &self.request_time_series)?;
+        s.serialize_field("labels", &self.labels)?;
+        s.serialize_field("run_id", &self.run_id)?;
         s.serialize_field("requests", &self.requests)?;
         s.serialize_field("tasks", &self.tasks)?;
+        s.serialize_field("groups", &self.groups)?;
         s.serialize_field("errors", &self.errors)?;
+        s.serialize_field("tls_full_handshakes", &self.tls_full_handshakes)?;
+        s.serialize_field("tls_resumed_handshakes", &self.tls_resumed_handshakes)?;
+        s.serialize_field("log_failures", &self.log_failures)?;
+        s.serialize_field("setup_teardown", &self.setup_teardown)?;
+        s.serialize_field("network_baselines", &self.network_baselines)?;
+        s.serialize_field("throttle_rate", &self.throttle_rate)?;
+        s.serialize_field("aborted", &self.aborted)?;
+        s.serialize_field("annotations", &self.annotations)?;
+        s.serialize_field("phases", &self.phases)?;
+        s.serialize_field("lost_workers", &self.lost_workers)?;
         s.serialize_field("final_metrics", &self.final_metrics)?;
         s.serialize_field("display_status_codes", &self.display_status_codes)?;
         s.serialize_field("display_metrics", &self.display_metrics)?;
@@ -2174,11 +4282,29 @@ impl fmt::Display for GooseMetrics {
         // flags are set.
         self.fmt_tasks(fmt)?;
         self.fmt_task_times(fmt)?;
+        self.fmt_groups(fmt)?;
+        self.fmt_setup_teardown(fmt)?;
+        self.fmt_network_baselines(fmt)?;
         self.fmt_requests(fmt)?;
         self.fmt_response_times(fmt)?;
         self.fmt_percentiles(fmt)?;
         self.fmt_status_codes(fmt)?;
+        self.fmt_response_time_budgets(fmt)?;
+        self.fmt_integrity(fmt)?;
+        self.fmt_schema(fmt)?;
+        self.fmt_write_conflicts(fmt)?;
+        self.fmt_baseline_comparison(fmt)?;
+        self.fmt_session_affinity(fmt)?;
+        self.fmt_dimensions(fmt)?;
+        self.fmt_cache_status(fmt)?;
+        self.fmt_body_sizes(fmt)?;
+        self.fmt_concurrency(fmt)?;
+        self.fmt_ramp_profiles(fmt)?;
+        self.fmt_phases(fmt)?;
+        self.fmt_tls_handshakes(fmt)?;
+        self.fmt_transport_errors(fmt)?;
         self.fmt_errors(fmt)?;
+        self.fmt_log_failures(fmt)?;
         self.fmt_overview(fmt)
     }
 }
@@ -2243,6 +4369,9 @@ pub struct GooseErrorMetric {
 pub struct GooseErrorMetricAggregate {
     /// The method that resulted in an error.
     pub method: GooseMethod,
+    /// The protocol of the raw socket request that resulted in an error, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<GooseSocketMethod>,
     /// The optional name of the request.
     pub name: String,
     /// The error string.
@@ -2254,11 +4383,36 @@ impl GooseErrorMetricAggregate {
     pub(crate) fn new(method: GooseMethod, name: String, error: String) -> Self {
         GooseErrorMetricAggregate {
             method,
+            socket: None,
             name,
             error,
             occurrences: 0,
         }
     }
+
+    /// The method that resulted in an error as displayed in metrics, such as `GET`, or the
+    /// protocol of a raw socket request, such as `TCP`.
+    pub fn method_name(&self) -> String {
+        method_name(&self.method, self.socket)
+    }
+}
+
+/// Aggregated metrics periodically saved to disk while a load test is running, so a
+/// restarted load test can resume with `--resume` instead of starting over.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct GooseMetricsCheckpoint {
+    /// A hash of the load test that saved the checkpoint.
+    pub(crate) hash: u64,
+    /// How many seconds the load test had been running when the checkpoint was saved.
+    pub(crate) duration: usize,
+    /// Request metrics collected so far.
+    pub(crate) requests: GooseRequestMetrics,
+    /// Task metrics collected so far.
+    pub(crate) tasks: GooseTaskMetrics,
+    /// Error metrics collected so far.
+    pub(crate) errors: GooseErrorMetrics,
+    /// Hosts the load test has run against so far.
+    pub(crate) hosts: HashSet<String>,
 }
 
 impl GooseAttack {
@@ -2288,17 +4442,44 @@ impl GooseAttack {
             // Load messages from user threads until the receiver queue is empty.
             let received_message = self.receive_metrics(goose_attack_run_state, flush).await?;
 
-            // As worker, push metrics up to manager.
-            if self.attack_mode == AttackMode::Worker && received_message {
+            // Track how many users are running while starting and running the load test,
+            // unless disabled with `--no-time-series`.
+            if !self.configuration.no_time_series
+                && self.attack_mode == AttackMode::StandAlone
+                && [AttackPhase::Starting, AttackPhase::Running].contains(&self.attack_phase)
+            {
+                self.record_active_users(goose_attack_run_state);
+            }
+
+            // Load TLS handshake counters shared by all user threads.
+            #[cfg(feature = "rustls-tls")]
+            {
+                let (full, resumed) = tls::handshakes();
+                self.metrics.tls_full_handshakes = full;
+                self.metrics.tls_resumed_handshakes = resumed;
+            }
+
+            // As worker, push metrics up to manager, or at least once a second push a
+            // heartbeat so the manager knows the worker is still running.
+            if self.attack_mode == AttackMode::Worker
+                && (received_message
+                    || util::timer_expired(goose_attack_run_state.heartbeat_timer, 1))
+            {
                 #[cfg(feature = "gaggle")]
                 {
-                    // Push metrics to manager process.
-                    if !worker::push_metrics_to_manager(
-                        &goose_attack_run_state.socket.clone().unwrap(),
+                    goose_attack_run_state.heartbeat_timer = std::time::Instant::now();
+                    let gaggle_metrics = if received_message {
                         vec![
                             GaggleMetrics::Requests(self.metrics.requests.clone()),
                             GaggleMetrics::Tasks(self.metrics.tasks.clone()),
-                        ],
+                        ]
+                    } else {
+                        vec![GaggleMetrics::WorkerInit(self.metrics.hash)]
+                    };
+                    // Push metrics to manager process.
+                    if !worker::push_metrics_to_manager(
+                        &goose_attack_run_state.socket.clone().unwrap(),
+                        gaggle_metrics,
                         true,
                     ) {
                         // GooseUserCommand::Exit received, cancel.
@@ -2306,17 +4487,56 @@ impl GooseAttack {
                             .canceled
                             .store(true, std::sync::atomic::Ordering::SeqCst);
                     }
-                    // The manager has all our metrics, reset locally.
-                    self.metrics.requests = HashMap::new();
-                    self.metrics.initialize_task_metrics(
-                        &self.task_sets,
-                        &self.configuration,
-                        &self.defaults,
-                    )?;
+                    // Launch any users the manager moved here from lost workers.
+                    self.weighted_users
+                        .extend(worker::take_redistributed_users(self.metrics.hash));
+                    if received_message {
+                        // The manager has all our metrics, reset locally.
+                        self.metrics.requests = HashMap::new();
+                        self.metrics.initialize_task_metrics(
+                            &self.task_sets,
+                            &self.configuration,
+                            &self.defaults,
+                        )?;
+                    }
                 }
             }
         }
 
+        // If enabled, stream a snapshot of all metrics about once per second.
+        if self.metrics_stream_tx.is_some()
+            && !self.configuration.no_metrics
+            && util::timer_expired(goose_attack_run_state.metrics_stream_timer, 1)
+        {
+            goose_attack_run_state.metrics_stream_timer = std::time::Instant::now();
+            self.update_duration();
+            if let Some(metrics_stream_tx) = self.metrics_stream_tx.as_ref() {
+                // Ignore errors, the application may no longer be consuming the stream.
+                let _ = metrics_stream_tx
+                    .send(GooseMetricEvent::Interval(Box::new(self.metrics.clone())));
+            }
+        }
+
+        // If enabled, export a snapshot of all metrics about once per second.
+        if !goose_attack_run_state.metrics_exporters.is_empty()
+            && util::timer_expired(goose_attack_run_state.metrics_export_timer, 1)
+        {
+            goose_attack_run_state.metrics_export_timer = std::time::Instant::now();
+            self.update_duration();
+            export::export_interval(&goose_attack_run_state.metrics_exporters, &self.metrics);
+        }
+
+        // If enabled, refresh the live metrics served to Prometheus about once per second.
+        if let Some(prometheus_metrics) = goose_attack_run_state.prometheus_metrics.as_ref() {
+            if !self.configuration.no_metrics
+                && (flush || util::timer_expired(goose_attack_run_state.prometheus_timer, 1))
+            {
+                goose_attack_run_state.prometheus_timer = std::time::Instant::now();
+                self.update_duration();
+                *prometheus_metrics.lock().unwrap() = prometheus::render(&self.metrics);
+            }
+        }
+
         // If enabled, display running metrics after sync
         if goose_attack_run_state.display_running_metrics {
             goose_attack_run_state.display_running_metrics = false;
@@ -2324,9 +4544,88 @@ impl GooseAttack {
             self.metrics.print_running();
         }
 
+        // If enabled, write an interim report after sync.
+        if !self.configuration.no_metrics
+            && [AttackPhase::Starting, AttackPhase::Running].contains(&self.attack_phase)
+        {
+            self.write_interim_report(goose_attack_run_state).await;
+        }
+
+        // If enabled, save a metrics checkpoint after sync.
+        if !self.configuration.no_metrics && self.attack_phase == AttackPhase::Running {
+            self.write_checkpoint(goose_attack_run_state).await;
+        }
+
         Ok(())
     }
 
+    // Record how many users are running, once for each second since the first user was
+    // launched. Any seconds missed while busy are filled in with the current count.
+    fn record_active_users(&mut self, goose_attack_run_state: &GooseAttackRunState) {
+        let starting = match self.metrics.starting {
+            Some(starting) => starting,
+            None => return,
+        };
+        let elapsed = (Local::now() - starting).num_seconds().max(0) as usize;
+        if self.metrics.active_users.len() <= elapsed {
+            let active_users = goose_attack_run_state
+                .users
+                .iter()
+                .filter(|user| !user.is_finished())
+                .count();
+            let seconds = self.metrics.active_users.len()..=elapsed;
+            self.metrics.active_users.resize(elapsed + 1, active_users);
+
+            // Profile all users, and unless following a test plan, the users of each task
+            // set.
+            if self.metrics.ramp_profiles.is_empty() {
+                self.metrics.ramp_profiles.push(GooseRampProfile {
+                    taskset_index: None,
+                    name: "All users".to_string(),
+                    ..Default::default()
+                });
+                if self.test_plan.is_none() {
+                    for task_set in &self.task_sets {
+                        self.metrics.ramp_profiles.push(GooseRampProfile {
+                            taskset_index: Some(task_set.task_sets_index),
+                            name: task_set.name.clone(),
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+
+            // Record how many users were planned to be running and were running during the
+            // same seconds.
+            for ramp_profile in &mut self.metrics.ramp_profiles {
+                let active_users = match ramp_profile.taskset_index {
+                    // A user channel is disconnected once its user exits.
+                    Some(taskset_index) => goose_attack_run_state
+                        .user_channels
+                        .iter()
+                        .zip(&goose_attack_run_state.user_task_sets)
+                        .filter(|(user_channel, task_sets_index)| {
+                            **task_sets_index == taskset_index && !user_channel.is_disconnected()
+                        })
+                        .count(),
+                    None => active_users,
+                };
+                ramp_profile.active_users.resize(elapsed + 1, active_users);
+                for second in seconds.clone() {
+                    let planned_users = match self.test_plan.as_ref() {
+                        Some(test_plan) => test_plan.users_at(second as u128 * 1_000),
+                        None => planned_users_at(
+                            &goose_attack_run_state.planned_launches,
+                            second,
+                            ramp_profile.taskset_index,
+                        ),
+                    };
+                    ramp_profile.planned_users.push(planned_users);
+                }
+            }
+        }
+    }
+
     // When the [`GooseAttack`](./struct.GooseAttack.html) goes from the `Starting`
     // phase to the `Running` phase, optionally flush metrics.
     pub(crate) async fn reset_metrics(
@@ -2347,7 +4646,7 @@ impl GooseAttack {
                 // Reset running_metrics_timer.
                 goose_attack_run_state.running_metrics_timer = std::time::Instant::now();
 
-                if self.metrics.display_metrics {
+                if self.metrics.display_metrics && self.metrics.output == GooseOutput::Verbose {
                     // Users is required here so unwrap() is safe.
                     if self.metrics.users < users {
                         println!(
@@ -2366,17 +4665,28 @@ impl GooseAttack {
                     &self.configuration,
                     &self.defaults,
                 )?;
-            } else if self.metrics.users < users {
-                println!(
-                    "{} of {} users hatched, timer expired.\n",
-                    self.metrics.users, users
-                );
-            } else {
-                println!("All {} users hatched.\n", self.metrics.users);
+                #[cfg(feature = "rustls-tls")]
+                tls::reset_handshakes();
+                self.metrics.tls_full_handshakes = 0;
+                self.metrics.tls_resumed_handshakes = 0;
+            } else if self.metrics.output == GooseOutput::Verbose {
+                if self.metrics.users < users {
+                    println!(
+                        "{} of {} users hatched, timer expired.\n",
+                        self.metrics.users, users
+                    );
+                } else {
+                    println!("All {} users hatched.\n", self.metrics.users);
+                }
             }
 
             // Restart the timer now that all threads are launched.
             self.started = Some(std::time::Instant::now());
+
+            // If resuming, continue from the metrics and run time in the checkpoint.
+            if let Some(checkpoint) = goose_attack_run_state.resume_checkpoint.take() {
+                self.resume_from_checkpoint(checkpoint);
+            }
         }
 
         Ok(())
@@ -2386,14 +4696,17 @@ impl GooseAttack {
     // `GooseMetrics.requests` `HashMap`, merging if already existing, or creating new.
     // Also writes it to the request_file if enabled.
     async fn record_request_metric(&mut self, request_metric: &GooseRequestMetric) {
-        let key = format!("{} {}", request_metric.raw.method, request_metric.name);
+        let (key, name) = self
+            .metrics
+            .request_key(&request_metric.raw.method_name(), &request_metric.name);
         let mut merge_request = match self.metrics.requests.get(&key) {
             Some(m) => m.clone(),
-            None => GooseRequestMetricAggregate::new(
-                &request_metric.name,
-                request_metric.raw.method.clone(),
-                0,
-            ),
+            None => {
+                let mut request =
+                    GooseRequestMetricAggregate::new(&name, request_metric.raw.method.clone(), 0);
+                request.socket = request_metric.raw.socket;
+                request
+            }
         };
 
         // Handle a metrics update.
@@ -2405,6 +4718,14 @@ impl GooseAttack {
                 merge_request.success_count -= 1;
                 merge_request.fail_count += 1;
             }
+            if let Some(second) = self.time_series_second() {
+                self.metrics
+                    .request_time_series
+                    .record_update(second, request_metric.success);
+            }
+            if let Some(phase) = self.metrics.phases.last_mut() {
+                phase.requests.record_update(request_metric.success);
+            }
         }
         // Store a new metric.
         else {
@@ -2415,20 +4736,183 @@ impl GooseAttack {
             if self.configuration.status_codes {
                 merge_request.set_status_code(request_metric.status_code);
             }
+            if self.configuration.no_histograms {
+                merge_request.discard_times();
+            }
             if request_metric.success {
                 merge_request.success_count += 1;
             } else {
                 merge_request.fail_count += 1;
             }
+            if request_metric.response_time_budget.is_some() {
+                merge_request.response_time_budget = request_metric.response_time_budget;
+            }
+            // Statistically generated Coordinated Omission Mitigation requests are not
+            // counted as slow, only requests that were actually made.
+            if request_metric.slow && request_metric.coordinated_omission_elapsed == 0 {
+                merge_request.slow_count += 1;
+            }
+            if let Some(backend) = request_metric.backend.as_ref() {
+                if request_metric.coordinated_omission_elapsed == 0 {
+                    *merge_request
+                        .backends
+                        .entry(backend.to_string())
+                        .or_insert(0) += 1;
+                    if request_metric.affinity_violation {
+                        merge_request.affinity_violations += 1;
+                    }
+                }
+            }
+            if request_metric.coordinated_omission_elapsed == 0 {
+                for (header, value) in &request_metric.dimensions {
+                    merge_request.record_dimension(header, value, request_metric.response_time);
+                }
+                if let Some(body_size) = request_metric.body_size {
+                    merge_request.body_count += 1;
+                    merge_request.body_bytes += body_size;
+                    merge_request.decompressed_body_bytes +=
+                        request_metric.decompressed_body_size.unwrap_or(body_size);
+                }
+                if let Some(intact) = request_metric.integrity {
+                    merge_request.integrity_checks += 1;
+                    if !intact {
+                        merge_request.integrity_failures += 1;
+                    }
+                }
+                if let Some(valid) = request_metric.schema_valid {
+                    merge_request.schema_checks += 1;
+                    if !valid {
+                        merge_request.schema_violations += 1;
+                    }
+                }
+                match request_metric.status_code {
+                    409 => merge_request.conflicts += 1,
+                    412 => merge_request.precondition_failures += 1,
+                    _ => {}
+                }
+                // Track response times and concurrency over time, unless disabled with
+                // `--no-time-series`.
+                if let Some(second) = self.time_series_second() {
+                    self.metrics
+                        .response_time_heatmap
+                        .record(second, request_metric.response_time);
+                    // Requests generated by Coordinated Omission Mitigation weren't made, so
+                    // aren't counted in the throughput of each second.
+                    if request_metric.coordinated_omission_elapsed == 0 {
+                        self.metrics.request_time_series.record(
+                            second,
+                            request_metric.response_time,
+                            request_metric.success,
+                            self.configuration.no_histograms,
+                        );
+                    }
+                    if let Some(finished) = self
+                        .finished_since_start(request_metric.elapsed, request_metric.response_time)
+                    {
+                        merge_request
+                            .concurrency
+                            .record(finished, request_metric.response_time);
+                    }
+                }
+                // Requests are also aggregated by the phase of the test plan they completed
+                // in.
+                if let Some(phase) = self.metrics.phases.last_mut() {
+                    phase.requests.record(
+                        request_metric.response_time,
+                        request_metric.success,
+                        self.configuration.no_histograms,
+                    );
+                }
+                if let Some(transport_error) = request_metric.transport_error {
+                    *merge_request
+                        .transport_errors
+                        .entry(transport_error)
+                        .or_insert(0) += 1;
+                }
+                match request_metric.cache_hit {
+                    Some(true) => merge_request
+                        .cache_data
+                        .record_time(request_metric.response_time),
+                    Some(false) => merge_request
+                        .origin_data
+                        .record_time(request_metric.response_time),
+                    None => {}
+                }
+            }
         }
 
         self.metrics.requests.insert(key, merge_request);
     }
 
+    // The second of the load test a metric is received in, if tracking metrics over time,
+    // unless disabled with `--no-time-series`.
+    fn time_series_second(&self) -> Option<usize> {
+        if self.configuration.no_time_series || self.attack_mode != AttackMode::StandAlone {
+            return None;
+        }
+        let starting = self.metrics.starting?;
+        Some((Local::now() - starting).num_seconds().max(0) as usize)
+    }
+
+    // How many milliseconds after the load test started a request or task finished, from
+    // when its GooseUser recorded it began and how long it took. Each GooseUser records
+    // times from when it was created, so these are offset by how long before the load
+    // test started the GooseUsers were created.
+    fn finished_since_start(&self, elapsed: u64, duration: u64) -> Option<u64> {
+        let offset = (self.metrics.starting? - self.users_created?)
+            .num_milliseconds()
+            .max(0) as u64;
+        Some((elapsed + duration).saturating_sub(offset))
+    }
+
+    /// Records each phase of the test plan the load test has reached, timed from when the
+    /// load test started.
+    pub(crate) fn record_phases(&mut self) {
+        let (test_plan, started) = match (self.test_plan.as_ref(), self.started) {
+            (Some(test_plan), Some(started)) => (test_plan, started),
+            _ => return,
+        };
+        let step = test_plan.step_at(started.elapsed().as_millis());
+        while self.metrics.phases.len() <= step {
+            let index = self.metrics.phases.len();
+            let now = Local::now();
+            let phase = GoosePhase {
+                name: test_plan.names[index].to_string(),
+                users: test_plan.steps[index].0,
+                timestamp: now.timestamp_millis(),
+                elapsed: self
+                    .metrics
+                    .starting
+                    .map_or(0, |starting| (now - starting).num_seconds().max(0) as usize),
+                requests: GooseRequestSecond::default(),
+            };
+            info!("entering {} phase...", phase.name);
+            self.metrics.phases.push(phase);
+        }
+    }
+
+    /// Records a named annotation, timed from when the load test started. Returns false
+    /// if the load test hasn't started.
+    pub(crate) fn record_annotation(&mut self, mut annotation: GooseAnnotation) -> bool {
+        let starting = match self.metrics.starting {
+            Some(starting) => starting,
+            None => return false,
+        };
+        annotation.elapsed =
+            ((annotation.timestamp - starting.timestamp_millis()) / 1000).max(0) as usize;
+        info!(
+            "annotation after {}s: {}",
+            annotation.elapsed, annotation.name
+        );
+        self.metrics.annotations.push(annotation);
+        true
+    }
+
     // Receive metrics from [`GooseUser`](./goose/struct.GooseUser.html) threads. If flush
     // is true all metrics will be received regardless of how long it takes. If flush is
     // false, metrics will only be received for up to 400 ms before exiting to continue on
-    // the next call to this function.
+    // the next call to this function, or until a Controller request, ctrl-c or the end of
+    // the load test needs handling, so these aren't delayed by a flood of metrics.
     pub(crate) async fn receive_metrics(
         &mut self,
         goose_attack_run_state: &mut GooseAttackRunState,
@@ -2440,11 +4924,52 @@ impl GooseAttack {
         // Main loop wakes up every 500ms, so don't spend more than 400ms receiving metrics.
         let receive_timeout = 400;
         let receive_started = std::time::Instant::now();
+        // How often to check if the parent loop has something more urgent to do.
+        let control_interval = 64;
+        let mut until_control_check = control_interval;
 
         while message.is_ok() {
             received_message = true;
-            match message.unwrap() {
+            until_control_check -= 1;
+            let metric = message.unwrap();
+            // Optionally stream the metric to the embedding application.
+            if let Some(metrics_stream_tx) = self.metrics_stream_tx.as_ref() {
+                let event = match &metric {
+                    GooseMetric::Request(request_metric) => {
+                        GooseMetricEvent::Request(request_metric.clone())
+                    }
+                    GooseMetric::Task(task_metric) => GooseMetricEvent::Task(task_metric.clone()),
+                    GooseMetric::Group(group_metric) => {
+                        GooseMetricEvent::Group(group_metric.clone())
+                    }
+                    GooseMetric::Annotation(annotation) => {
+                        GooseMetricEvent::Annotation(annotation.clone())
+                    }
+                };
+                // Ignore errors, the application may no longer be consuming the stream.
+                let _ = metrics_stream_tx.send(event);
+            }
+            match metric {
                 GooseMetric::Request(request_metric) => {
+                    // If enabled, send the request metrics to StatsD.
+                    if let Some(statsd) = goose_attack_run_state.statsd.as_ref() {
+                        statsd.send_request(&request_metric);
+                    }
+
+                    // If enabled, stream the request to InfluxDB, timestamped with when it
+                    // started.
+                    if let Some(influxdb_tx) = goose_attack_run_state.influxdb_tx.as_ref() {
+                        let timestamp = self.users_created.map_or_else(
+                            || Local::now().timestamp_millis(),
+                            |users_created| users_created.timestamp_millis(),
+                        ) + request_metric.elapsed as i64;
+                        let _ = influxdb_tx.send(Some(influxdb::render(
+                            &request_metric,
+                            &self.configuration.run_id,
+                            timestamp,
+                        )));
+                    }
+
                     // If there was an error, store it.
                     if !request_metric.error.is_empty() {
                         self.record_error(&request_metric, goose_attack_run_state);
@@ -2483,15 +5008,48 @@ impl GooseAttack {
                     }
                 }
                 GooseMetric::Task(raw_task) => {
+                    // Track concurrency over time, unless disabled with `--no-time-series`.
+                    let finished = if !self.configuration.no_time_series
+                        && self.attack_mode == AttackMode::StandAlone
+                    {
+                        self.finished_since_start(raw_task.elapsed, raw_task.run_time)
+                    } else {
+                        None
+                    };
+                    // Store a new metric.
+                    let task = &mut self.metrics.tasks[raw_task.taskset_index][raw_task.task_index];
+                    task.set_time(raw_task.run_time, raw_task.success);
+                    if self.configuration.no_histograms {
+                        task.discard_times();
+                    }
+                    if let Some(finished) = finished {
+                        task.concurrency.record(finished, raw_task.run_time);
+                    }
+                }
+                GooseMetric::Group(raw_group) => {
                     // Store a new metric.
-                    self.metrics.tasks[raw_task.taskset_index][raw_task.task_index]
-                        .set_time(raw_task.run_time, raw_task.success);
+                    let group =
+                        &mut self.metrics.groups[raw_group.taskset_index][raw_group.task_index];
+                    group.set_time(raw_group.run_time, raw_group.success);
+                    if self.configuration.no_histograms {
+                        group.discard_times();
+                    }
+                }
+                GooseMetric::Annotation(annotation) => {
+                    self.record_annotation(annotation);
                 }
             }
-            // Unless flushing all metrics, break out of receive loop after timeout.
-            if !flush && util::ms_timer_expired(receive_started, receive_timeout) {
+            // Unless flushing all metrics, break out of receive loop after timeout, or to
+            // let the parent loop handle a control message.
+            if !flush
+                && (util::ms_timer_expired(receive_started, receive_timeout)
+                    || (until_control_check == 0 && self.control_pending(goose_attack_run_state)))
+            {
                 break;
             }
+            if until_control_check == 0 {
+                until_control_check = control_interval;
+            }
             message = goose_attack_run_state.metrics_rx.try_recv();
         }
 
@@ -2532,18 +5090,24 @@ impl GooseAttack {
         // Create a string to uniquely identify errors for tracking metrics.
         let error_string = format!(
             "{}.{}.{}",
-            raw_request.error, raw_request.raw.method, raw_request.name
+            raw_request.error,
+            raw_request.raw.method_name(),
+            raw_request.name
         );
 
         let mut error_metrics = match self.metrics.errors.get(&error_string) {
             // We've seen this error before.
             Some(m) => m.clone(),
             // First time we've seen this error.
-            None => GooseErrorMetricAggregate::new(
-                raw_request.raw.method.clone(),
-                raw_request.name.to_string(),
-                raw_request.error.to_string(),
-            ),
+            None => {
+                let mut error_metrics = GooseErrorMetricAggregate::new(
+                    raw_request.raw.method.clone(),
+                    raw_request.name.to_string(),
+                    raw_request.error.to_string(),
+                );
+                error_metrics.socket = raw_request.raw.socket;
+                error_metrics
+            }
         };
         error_metrics.occurrences += 1;
         self.metrics.errors.insert(error_string, error_metrics);
@@ -2558,51 +5122,45 @@ impl GooseAttack {
         }
     }
 
-    // Write an HTML-formatted report, if enabled.
-    pub(crate) async fn write_html_report(
-        &mut self,
-        goose_attack_run_state: &mut GooseAttackRunState,
-    ) -> Result<(), GooseError> {
-        // Only write the report if enabled.
-        if let Some(report_file) = goose_attack_run_state.report_file.as_mut() {
-            // Prepare report summary variables.
-            let users = self.metrics.users.to_string();
+    // Build an HTML-formatted report. Interim reports are built while the load test is
+    // still running, so any phases that haven't finished yet end now.
+    pub(crate) fn build_html_report(&self) -> String {
+        // Prepare report summary variables.
+        let users = self.metrics.users.to_string();
 
-            let starting = self.metrics.starting.unwrap();
-            let started = if self.metrics.started.is_some() {
-                self.metrics.started.unwrap()
-            } else {
-                self.metrics.stopping.unwrap()
-            };
-            let (starting_seconds, starting_minutes, starting_hours) =
-                self.metrics.get_seconds_minutes_hours(&starting, &started);
-            let stopping = self.metrics.stopping.unwrap();
-            let (running_seconds, running_minutes, running_hours) =
-                self.metrics.get_seconds_minutes_hours(&started, &stopping);
-            let stopped = self.metrics.stopped.unwrap();
-            let (stopping_seconds, stopping_minutes, stopping_hours) =
-                self.metrics.get_seconds_minutes_hours(&stopping, &stopped);
-
-            let mut report_range = format!(
-                "<p>Starting: <span>{} - {} (Duration: {:02}:{:02}:{:02})</span></p>",
-                starting.format("%Y-%m-%d %H:%M:%S").to_string(),
-                started.format("%Y-%m-%d %H:%M:%S").to_string(),
-                starting_hours,
-                starting_minutes,
-                starting_seconds,
-            );
+        let now = Local::now();
+        let starting = self.metrics.starting.unwrap_or(now);
+        let stopping = self.metrics.stopping.unwrap_or(now);
+        let started = self.metrics.started.unwrap_or(stopping);
+        let (starting_seconds, starting_minutes, starting_hours) =
+            self.metrics.get_seconds_minutes_hours(&starting, &started);
+        let (running_seconds, running_minutes, running_hours) =
+            self.metrics.get_seconds_minutes_hours(&started, &stopping);
+        let stopped = self.metrics.stopped.unwrap_or(now);
+        let (stopping_seconds, stopping_minutes, stopping_hours) =
+            self.metrics.get_seconds_minutes_hours(&stopping, &stopped);
+
+        let mut report_range = format!(
+            "<p>Starting: <span>{} - {} (Duration: {:02}:{:02}:{:02})</span></p>",
+            starting.format("%Y-%m-%d %H:%M:%S").to_string(),
+            started.format("%Y-%m-%d %H:%M:%S").to_string(),
+            starting_hours,
+            starting_minutes,
+            starting_seconds,
+        );
 
-            if self.metrics.started.is_some() {
-                report_range.push_str(&format!(
-                    "<p>Running: <span>{} - {} (Duration: {:02}:{:02}:{:02})</span></p>",
-                    started.format("%Y-%m-%d %H:%M:%S").to_string(),
-                    stopping.format("%Y-%m-%d %H:%M:%S").to_string(),
-                    running_hours,
-                    running_minutes,
-                    running_seconds,
-                ));
-            }
+        if self.metrics.started.is_some() {
+            report_range.push_str(&format!(
+                "<p>Running: <span>{} - {} (Duration: {:02}:{:02}:{:02})</span></p>",
+                started.format("%Y-%m-%d %H:%M:%S").to_string(),
+                stopping.format("%Y-%m-%d %H:%M:%S").to_string(),
+                running_hours,
+                running_minutes,
+                running_seconds,
+            ));
+        }
 
+        if self.metrics.stopping.is_some() {
             report_range.push_str(&format!(
                 "<p>Stopping: <span>{} - {} (Duration: {:02}:{:02}:{:02})</span></p>",
                 stopping.format("%Y-%m-%d %H:%M:%S").to_string(),
@@ -2611,414 +5169,651 @@ impl GooseAttack {
                 stopping_minutes,
                 stopping_seconds,
             ));
+        }
 
-            // Build a comma separated list of hosts.
-            let hosts = &self.metrics.hosts.clone().into_iter().join(", ");
-
-            // Prepare requests and responses variables.
-            let mut raw_request_metrics = Vec::new();
-            let mut co_request_metrics = Vec::new();
-            let mut raw_response_metrics = Vec::new();
-            let mut co_response_metrics = Vec::new();
-            let mut raw_aggregate_total_count = 0;
-            let mut co_aggregate_total_count = 0;
-            let mut raw_aggregate_fail_count = 0;
-            let mut raw_aggregate_response_time_counter: usize = 0;
-            let mut raw_aggregate_response_time_minimum: usize = 0;
-            let mut raw_aggregate_response_time_maximum: usize = 0;
-            let mut raw_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-            let mut co_aggregate_response_time_counter: usize = 0;
-            let mut co_aggregate_response_time_maximum: usize = 0;
-            let mut co_aggregate_response_times: BTreeMap<usize, usize> = BTreeMap::new();
-            let mut co_data = false;
-            for (request_key, request) in self.metrics.requests.iter().sorted() {
-                // Determine whether or not to include Coordinated Omission data.
-                if !co_data && request.coordinated_omission_data.is_some() {
-                    co_data = true;
-                }
-                let method = format!("{}", request.method);
-                // The request_key is "{method} {name}", so by stripping the "{method} "
-                // prefix we get the name.
-                let name = request_key
-                    .strip_prefix(&format!("{} ", request.method))
-                    .unwrap()
-                    .to_string();
-                let total_request_count = request.success_count + request.fail_count;
-                let (requests_per_second, failures_per_second) = per_second_calculations(
-                    self.metrics.duration,
-                    total_request_count,
-                    request.fail_count,
-                );
-                // Prepare per-request metrics.
-                raw_request_metrics.push(report::RequestMetric {
-                    method: method.to_string(),
-                    name: name.to_string(),
-                    number_of_requests: total_request_count,
-                    number_of_failures: request.fail_count,
-                    response_time_average: format!(
-                        "{:.2}",
-                        request.raw_data.total_time as f32 / request.raw_data.counter as f32
-                    ),
-                    response_time_minimum: request.raw_data.minimum_time,
-                    response_time_maximum: request.raw_data.maximum_time,
-                    requests_per_second: format!("{:.2}", requests_per_second),
-                    failures_per_second: format!("{:.2}", failures_per_second),
-                });
+        // Build a comma separated list of hosts.
+        let hosts = &self.metrics.hosts.clone().into_iter().join(", ");
 
-                // Prepare per-response metrics.
-                raw_response_metrics.push(report::get_response_metric(
-                    &method,
-                    &name,
-                    &request.raw_data.times,
-                    request.raw_data.counter,
-                    request.raw_data.minimum_time,
-                    request.raw_data.maximum_time,
-                ));
+        // Build a comma separated list of labels, if any.
+        let labels = if self.metrics.labels.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                "<p>Labels: <span>{}</span></p>",
+                self.metrics
+                    .labels
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .join(", ")
+            )
+        };
 
-                // Collect aggregated request and response metrics.
-                raw_aggregate_total_count += total_request_count;
-                raw_aggregate_fail_count += request.fail_count;
-                raw_aggregate_response_time_counter += request.raw_data.total_time;
-                raw_aggregate_response_time_minimum = update_min_time(
-                    raw_aggregate_response_time_minimum,
-                    request.raw_data.minimum_time,
-                );
-                raw_aggregate_response_time_maximum = update_max_time(
-                    raw_aggregate_response_time_maximum,
-                    request.raw_data.maximum_time,
-                );
-                raw_aggregate_response_times =
-                    merge_times(raw_aggregate_response_times, request.raw_data.times.clone());
-            }
+        // Include the run ID, if any.
+        let run_id = if self.metrics.run_id.is_empty() {
+            "".to_string()
+        } else {
+            format!("<p>Run ID: <span>{}</span></p>", self.metrics.run_id)
+        };
 
-            // Prepare aggregate per-request metrics.
-            let (raw_aggregate_requests_per_second, raw_aggregate_failures_per_second) =
-                per_second_calculations(
-                    self.metrics.duration,
-                    raw_aggregate_total_count,
-                    raw_aggregate_fail_count,
-                );
+        // Include why the load test was aborted, if it was.
+        let aborted_template = match self.metrics.aborted.as_ref() {
+            Some(aborted) => format!("<p>Aborted: <span>{}</span></p>", aborted),
+            None => "".to_string(),
+        };
+
+        // Numbers are grouped and scaled as configured for the metrics tables.
+        let number_format = self.metrics.number_format;
+
+        // Prepare requests and responses variables.
+        let mut raw_request_metrics = Vec::new();
+        let mut co_request_metrics = Vec::new();
+        let mut raw_response_metrics = Vec::new();
+        let mut co_response_metrics = Vec::new();
+        let mut response_time_percentiles = Vec::new();
+        let mut raw_aggregate_total_count = 0;
+        let mut co_aggregate_total_count = 0;
+        let mut raw_aggregate_fail_count = 0;
+        let mut raw_aggregate_response_time_counter: usize = 0;
+        let mut raw_aggregate_response_time_minimum: usize = 0;
+        let mut raw_aggregate_response_time_maximum: usize = 0;
+        let mut raw_aggregate_data = GooseRequestMetricTimingData::with_histogram();
+        let mut co_aggregate_response_time_counter: usize = 0;
+        let mut co_aggregate_response_time_maximum: usize = 0;
+        let mut co_aggregate_data = GooseRequestMetricTimingData::new(None);
+        let mut co_data = false;
+        for (request_key, request) in self.metrics.requests.iter().sorted() {
+            // Determine whether or not to include Coordinated Omission data.
+            if !co_data && request.coordinated_omission_data.is_some() {
+                co_data = true;
+            }
+            let method = request.method_name();
+            // The request_key is "{method} {name}", so by stripping the "{method} "
+            // prefix we get the name.
+            let name = request_key
+                .strip_prefix(&format!("{} ", method))
+                .unwrap()
+                .to_string();
+            let total_request_count = request.success_count + request.fail_count;
+            let (requests_per_second, failures_per_second) = per_second_calculations(
+                self.metrics.duration,
+                total_request_count,
+                request.fail_count,
+            );
+            // Prepare per-request metrics.
             raw_request_metrics.push(report::RequestMetric {
+                method: method.to_string(),
+                name: name.to_string(),
+                number_of_requests: number_format.number(total_request_count),
+                number_of_failures: number_format.number(request.fail_count),
+                response_time_average: number_format.fixed_time(
+                    request.raw_data.total_time as f32 / request.raw_data.counter as f32,
+                    2,
+                ),
+                response_time_minimum: number_format.time(request.raw_data.minimum_time),
+                response_time_maximum: number_format.time(request.raw_data.maximum_time),
+                requests_per_second: number_format.fixed(requests_per_second, 2),
+                failures_per_second: number_format.fixed(failures_per_second, 2),
+            });
+
+            // Prepare per-response metrics.
+            raw_response_metrics.push(report::get_response_metric(
+                &method,
+                &name,
+                &request.raw_data,
+                &number_format,
+            ));
+
+            // Prepare per-request response time percentiles to chart.
+            if let Some(percentiles) =
+                report::get_response_time_percentiles(request_key, &request.raw_data)
+            {
+                response_time_percentiles.push(percentiles);
+            }
+
+            // Collect aggregated request and response metrics.
+            raw_aggregate_total_count += total_request_count;
+            raw_aggregate_fail_count += request.fail_count;
+            raw_aggregate_response_time_counter += request.raw_data.total_time;
+            raw_aggregate_response_time_minimum = update_min_time(
+                raw_aggregate_response_time_minimum,
+                request.raw_data.minimum_time,
+            );
+            raw_aggregate_response_time_maximum = update_max_time(
+                raw_aggregate_response_time_maximum,
+                request.raw_data.maximum_time,
+            );
+            raw_aggregate_data.merge(&request.raw_data);
+        }
+
+        // Prepare aggregate per-request metrics.
+        let (raw_aggregate_requests_per_second, raw_aggregate_failures_per_second) =
+            per_second_calculations(
+                self.metrics.duration,
+                raw_aggregate_total_count,
+                raw_aggregate_fail_count,
+            );
+        raw_request_metrics.push(report::RequestMetric {
+            method: "".to_string(),
+            name: "Aggregated".to_string(),
+            number_of_requests: number_format.number(raw_aggregate_total_count),
+            number_of_failures: number_format.number(raw_aggregate_fail_count),
+            response_time_average: number_format.fixed_time(
+                raw_aggregate_response_time_counter as f32 / raw_aggregate_total_count as f32,
+                2,
+            ),
+            response_time_minimum: number_format.time(raw_aggregate_response_time_minimum),
+            response_time_maximum: number_format.time(raw_aggregate_response_time_maximum),
+            requests_per_second: number_format.fixed(raw_aggregate_requests_per_second, 2),
+            failures_per_second: number_format.fixed(raw_aggregate_failures_per_second, 2),
+        });
+
+        // Prepare aggregate per-response metrics.
+        raw_response_metrics.push(report::get_response_metric(
+            "",
+            "Aggregated",
+            &raw_aggregate_data,
+            &number_format,
+        ));
+
+        // Only chart aggregated percentiles if there was more than one request.
+        if self.metrics.requests.len() > 1 {
+            if let Some(percentiles) =
+                report::get_response_time_percentiles("Aggregated", &raw_aggregate_data)
+            {
+                response_time_percentiles.push(percentiles);
+            }
+        }
+        let response_time_percentiles_template = if response_time_percentiles.is_empty() {
+            "".to_string()
+        } else {
+            report::response_time_percentiles_template(&response_time_percentiles)
+        };
+
+        // Compile the request metrics template.
+        let mut raw_requests_rows = Vec::new();
+        for metric in raw_request_metrics {
+            raw_requests_rows.push(report::raw_request_metrics_row(metric));
+        }
+
+        // Compile the response metrics template.
+        let mut raw_responses_rows = Vec::new();
+        for metric in raw_response_metrics {
+            raw_responses_rows.push(report::response_metrics_row(metric));
+        }
+
+        let co_requests_template: String;
+        let co_responses_template: String;
+        if co_data {
+            for (request_key, request) in self.metrics.requests.iter().sorted() {
+                if let Some(coordinated_omission_data) = request.coordinated_omission_data.as_ref()
+                {
+                    let method = request.method_name();
+                    // The request_key is "{method} {name}", so by stripping the "{method} "
+                    // prefix we get the name.
+                    let name = request_key
+                        .strip_prefix(&format!("{} ", method))
+                        .unwrap()
+                        .to_string();
+                    let raw_average =
+                        request.raw_data.total_time as f32 / request.raw_data.counter as f32;
+                    let co_average = coordinated_omission_data.total_time as f32
+                        / coordinated_omission_data.counter as f32;
+                    // Prepare per-request metrics.
+                    co_request_metrics.push(report::CORequestMetric {
+                        method: method.to_string(),
+                        name: name.to_string(),
+                        response_time_average: number_format.fixed_time(co_average, 2),
+                        response_time_standard_deviation: number_format
+                            .fixed_time(util::standard_deviation(raw_average, co_average), 2),
+                        response_time_maximum: number_format
+                            .time(coordinated_omission_data.maximum_time),
+                    });
+
+                    // Prepare per-response metrics.
+                    co_response_metrics.push(report::get_response_metric(
+                        &method,
+                        &name,
+                        coordinated_omission_data,
+                        &number_format,
+                    ));
+
+                    // Collect aggregated request and response metrics.
+                    co_aggregate_response_time_counter += coordinated_omission_data.total_time;
+                    co_aggregate_response_time_maximum = update_max_time(
+                        co_aggregate_response_time_maximum,
+                        coordinated_omission_data.maximum_time,
+                    );
+                    co_aggregate_data.merge(coordinated_omission_data);
+                }
+                let total_request_count = request.success_count + request.fail_count;
+                co_aggregate_total_count += total_request_count;
+            }
+            let co_average =
+                co_aggregate_response_time_counter as f32 / co_aggregate_total_count as f32;
+            let raw_average =
+                raw_aggregate_response_time_counter as f32 / raw_aggregate_total_count as f32;
+            co_request_metrics.push(report::CORequestMetric {
                 method: "".to_string(),
                 name: "Aggregated".to_string(),
-                number_of_requests: raw_aggregate_total_count,
-                number_of_failures: raw_aggregate_fail_count,
-                response_time_average: format!(
-                    "{:.2}",
-                    raw_aggregate_response_time_counter as f32 / raw_aggregate_total_count as f32
+                response_time_average: number_format.fixed_time(
+                    co_aggregate_response_time_counter as f32 / co_aggregate_total_count as f32,
+                    2,
                 ),
-                response_time_minimum: raw_aggregate_response_time_minimum,
-                response_time_maximum: raw_aggregate_response_time_maximum,
-                requests_per_second: format!("{:.2}", raw_aggregate_requests_per_second),
-                failures_per_second: format!("{:.2}", raw_aggregate_failures_per_second),
+                response_time_standard_deviation: number_format
+                    .fixed_time(util::standard_deviation(raw_average, co_average), 2),
+                response_time_maximum: number_format.time(co_aggregate_response_time_maximum),
             });
 
             // Prepare aggregate per-response metrics.
-            raw_response_metrics.push(report::get_response_metric(
+            co_response_metrics.push(report::get_response_metric(
                 "",
                 "Aggregated",
-                &raw_aggregate_response_times,
-                raw_aggregate_total_count,
-                raw_aggregate_response_time_minimum,
-                raw_aggregate_response_time_maximum,
+                &co_aggregate_data,
+                &number_format,
             ));
 
-            // Compile the request metrics template.
-            let mut raw_requests_rows = Vec::new();
-            for metric in raw_request_metrics {
-                raw_requests_rows.push(report::raw_request_metrics_row(metric));
+            // Compile the co_request metrics rows.
+            let mut co_request_rows = Vec::new();
+            for metric in co_request_metrics {
+                co_request_rows.push(report::coordinated_omission_request_metrics_row(metric));
             }
 
-            // Compile the response metrics template.
-            let mut raw_responses_rows = Vec::new();
-            for metric in raw_response_metrics {
-                raw_responses_rows.push(report::response_metrics_row(metric));
-            }
+            // Compile the status_code metrics template.
+            co_requests_template =
+                report::coordinated_omission_request_metrics_template(&co_request_rows.join("\n"));
 
-            let co_requests_template: String;
-            let co_responses_template: String;
-            if co_data {
-                for (request_key, request) in self.metrics.requests.iter().sorted() {
-                    if let Some(coordinated_omission_data) =
-                        request.coordinated_omission_data.as_ref()
-                    {
-                        let method = format!("{}", request.method);
-                        // The request_key is "{method} {name}", so by stripping the "{method} "
-                        // prefix we get the name.
-                        let name = request_key
-                            .strip_prefix(&format!("{} ", request.method))
-                            .unwrap()
-                            .to_string();
-                        let raw_average =
-                            request.raw_data.total_time as f32 / request.raw_data.counter as f32;
-                        let co_average = coordinated_omission_data.total_time as f32
-                            / coordinated_omission_data.counter as f32;
-                        // Prepare per-request metrics.
-                        co_request_metrics.push(report::CORequestMetric {
-                            method: method.to_string(),
-                            name: name.to_string(),
-                            response_time_average: format!("{:.2}", co_average),
-                            response_time_standard_deviation: format!(
-                                "{:.2}",
-                                util::standard_deviation(raw_average, co_average)
-                            ),
-                            response_time_maximum: coordinated_omission_data.maximum_time,
-                        });
+            // Compile the co_request metrics rows.
+            let mut co_response_rows = Vec::new();
+            for metric in co_response_metrics {
+                co_response_rows.push(report::coordinated_omission_response_metrics_row(metric));
+            }
 
-                        // Prepare per-response metrics.
-                        co_response_metrics.push(report::get_response_metric(
-                            &method,
-                            &name,
-                            &coordinated_omission_data.times,
-                            coordinated_omission_data.counter,
-                            coordinated_omission_data.minimum_time,
-                            coordinated_omission_data.maximum_time,
-                        ));
+            // Compile the status_code metrics template.
+            co_responses_template = report::coordinated_omission_response_metrics_template(
+                &co_response_rows.join("\n"),
+            );
+        } else {
+            // If --status-codes is not enabled, return an empty template.
+            co_requests_template = "".to_string();
+            co_responses_template = "".to_string();
+        }
 
-                        // Collect aggregated request and response metrics.
-                        co_aggregate_response_time_counter += coordinated_omission_data.total_time;
-                        co_aggregate_response_time_maximum = update_max_time(
-                            co_aggregate_response_time_maximum,
-                            coordinated_omission_data.maximum_time,
-                        );
-                        co_aggregate_response_times = merge_times(
-                            co_aggregate_response_times,
-                            coordinated_omission_data.times.clone(),
-                        );
+        // Only build the tasks template if --no-task-metrics isn't enabled.
+        let tasks_template: String;
+        if !self.configuration.no_task_metrics {
+            let mut task_metrics = Vec::new();
+            let mut aggregate_total_count = 0;
+            let mut aggregate_fail_count = 0;
+            let mut aggregate_task_time_counter: usize = 0;
+            let mut aggregate_task_time_minimum: usize = 0;
+            let mut aggregate_task_time_maximum: usize = 0;
+            let mut aggregate_task_times: BTreeMap<usize, usize> = BTreeMap::new();
+            for (task_set_counter, task_set) in self.metrics.tasks.iter().enumerate() {
+                for (task_counter, task) in task_set.iter().enumerate() {
+                    if task_counter == 0 {
+                        // Only the taskset_name is used for task sets.
+                        task_metrics.push(report::TaskMetric {
+                            is_task_set: true,
+                            task: "".to_string(),
+                            name: task.taskset_name.to_string(),
+                            number_of_requests: "".to_string(),
+                            number_of_failures: "".to_string(),
+                            response_time_average: "".to_string(),
+                            response_time_minimum: "".to_string(),
+                            response_time_maximum: "".to_string(),
+                            requests_per_second: "".to_string(),
+                            failures_per_second: "".to_string(),
+                        });
                     }
-                    let total_request_count = request.success_count + request.fail_count;
-                    co_aggregate_total_count += total_request_count;
-                }
-                let co_average =
-                    co_aggregate_response_time_counter as f32 / co_aggregate_total_count as f32;
-                let raw_average =
-                    raw_aggregate_response_time_counter as f32 / raw_aggregate_total_count as f32;
-                co_request_metrics.push(report::CORequestMetric {
-                    method: "".to_string(),
-                    name: "Aggregated".to_string(),
-                    response_time_average: format!(
-                        "{:.2}",
-                        co_aggregate_response_time_counter as f32 / co_aggregate_total_count as f32
-                    ),
-                    response_time_standard_deviation: format!(
-                        "{:.2}",
-                        util::standard_deviation(raw_average, co_average),
-                    ),
-                    response_time_maximum: co_aggregate_response_time_maximum,
-                });
-
-                // Prepare aggregate per-response metrics.
-                co_response_metrics.push(report::get_response_metric(
-                    "",
-                    "Aggregated",
-                    &co_aggregate_response_times,
-                    co_aggregate_total_count,
-                    raw_aggregate_response_time_minimum,
-                    co_aggregate_response_time_maximum,
-                ));
+                    let total_run_count = task.success_count + task.fail_count;
+                    let (requests_per_second, failures_per_second) = per_second_calculations(
+                        self.metrics.duration,
+                        total_run_count,
+                        task.fail_count,
+                    );
+                    let average = match task.counter {
+                        0 => 0.00,
+                        _ => task.total_time as f32 / task.counter as f32,
+                    };
+                    task_metrics.push(report::TaskMetric {
+                        is_task_set: false,
+                        task: format!("{}.{}", task_set_counter, task_counter),
+                        name: task.task_name.to_string(),
+                        number_of_requests: number_format.number(total_run_count),
+                        number_of_failures: number_format.number(task.fail_count),
+                        response_time_average: number_format.fixed_time(average, 2),
+                        response_time_minimum: number_format.time(task.min_time),
+                        response_time_maximum: number_format.time(task.max_time),
+                        requests_per_second: number_format.fixed(requests_per_second, 2),
+                        failures_per_second: number_format.fixed(failures_per_second, 2),
+                    });
 
-                // Compile the co_request metrics rows.
-                let mut co_request_rows = Vec::new();
-                for metric in co_request_metrics {
-                    co_request_rows.push(report::coordinated_omission_request_metrics_row(metric));
+                    aggregate_total_count += total_run_count;
+                    aggregate_fail_count += task.fail_count;
+                    aggregate_task_times = merge_times(aggregate_task_times, task.times.clone());
+                    aggregate_task_time_counter += &task.counter;
+                    aggregate_task_time_minimum =
+                        update_min_time(aggregate_task_time_minimum, task.min_time);
+                    aggregate_task_time_maximum =
+                        update_max_time(aggregate_task_time_maximum, task.max_time);
                 }
+            }
 
-                // Compile the status_code metrics template.
-                co_requests_template = report::coordinated_omission_request_metrics_template(
-                    &co_request_rows.join("\n"),
+            let (aggregate_requests_per_second, aggregate_failures_per_second) =
+                per_second_calculations(
+                    self.metrics.duration,
+                    aggregate_total_count,
+                    aggregate_fail_count,
                 );
+            task_metrics.push(report::TaskMetric {
+                is_task_set: false,
+                task: "".to_string(),
+                name: "Aggregated".to_string(),
+                number_of_requests: number_format.number(aggregate_total_count),
+                number_of_failures: number_format.number(aggregate_fail_count),
+                response_time_average: number_format.fixed_time(
+                    raw_aggregate_response_time_counter as f32 / aggregate_total_count as f32,
+                    2,
+                ),
+                response_time_minimum: number_format.time(aggregate_task_time_minimum),
+                response_time_maximum: number_format.time(aggregate_task_time_maximum),
+                requests_per_second: number_format.fixed(aggregate_requests_per_second, 2),
+                failures_per_second: number_format.fixed(aggregate_failures_per_second, 2),
+            });
+            let mut tasks_rows = Vec::new();
+            // Compile the task metrics template.
+            for metric in task_metrics {
+                tasks_rows.push(report::task_metrics_row(metric));
+            }
 
-                // Compile the co_request metrics rows.
-                let mut co_response_rows = Vec::new();
-                for metric in co_response_metrics {
-                    co_response_rows
-                        .push(report::coordinated_omission_response_metrics_row(metric));
-                }
+            tasks_template = report::task_metrics_template(&tasks_rows.join("\n"));
+        } else {
+            tasks_template = "".to_string();
+        }
 
-                // Compile the status_code metrics template.
-                co_responses_template = report::coordinated_omission_response_metrics_template(
-                    &co_response_rows.join("\n"),
-                );
-            } else {
-                // If --status-codes is not enabled, return an empty template.
-                co_requests_template = "".to_string();
-                co_responses_template = "".to_string();
-            }
-
-            // Only build the tasks template if --no-task-metrics isn't enabled.
-            let tasks_template: String;
-            if !self.configuration.no_task_metrics {
-                let mut task_metrics = Vec::new();
-                let mut aggregate_total_count = 0;
-                let mut aggregate_fail_count = 0;
-                let mut aggregate_task_time_counter: usize = 0;
-                let mut aggregate_task_time_minimum: usize = 0;
-                let mut aggregate_task_time_maximum: usize = 0;
-                let mut aggregate_task_times: BTreeMap<usize, usize> = BTreeMap::new();
-                for (task_set_counter, task_set) in self.metrics.tasks.iter().enumerate() {
-                    for (task_counter, task) in task_set.iter().enumerate() {
-                        if task_counter == 0 {
-                            // Only the taskset_name is used for task sets.
-                            task_metrics.push(report::TaskMetric {
-                                is_task_set: true,
-                                task: "".to_string(),
-                                name: task.taskset_name.to_string(),
-                                number_of_requests: 0,
-                                number_of_failures: 0,
-                                response_time_average: "".to_string(),
-                                response_time_minimum: 0,
-                                response_time_maximum: 0,
-                                requests_per_second: "".to_string(),
-                                failures_per_second: "".to_string(),
-                            });
-                        }
-                        let total_run_count = task.success_count + task.fail_count;
-                        let (requests_per_second, failures_per_second) = per_second_calculations(
-                            self.metrics.duration,
-                            total_run_count,
-                            task.fail_count,
-                        );
-                        let average = match task.counter {
-                            0 => 0.00,
-                            _ => task.total_time as f32 / task.counter as f32,
-                        };
-                        task_metrics.push(report::TaskMetric {
-                            is_task_set: false,
-                            task: format!("{}.{}", task_set_counter, task_counter),
-                            name: task.task_name.to_string(),
-                            number_of_requests: total_run_count,
-                            number_of_failures: task.fail_count,
-                            response_time_average: format!("{:.2}", average),
-                            response_time_minimum: task.min_time,
-                            response_time_maximum: task.max_time,
-                            requests_per_second: format!("{:.2}", requests_per_second),
-                            failures_per_second: format!("{:.2}", failures_per_second),
-                        });
+        // Only build the tasks template if --no-task-metrics isn't enabled.
+        let errors_template: String;
+        if !self.metrics.errors.is_empty() {
+            let mut error_rows = Vec::new();
+            for error in self.metrics.errors.values() {
+                error_rows.push(report::error_row(error));
+            }
+            errors_template = report::errors_template(&error_rows.join("\n"));
+        } else {
+            errors_template = "".to_string();
+        }
 
-                        aggregate_total_count += total_run_count;
-                        aggregate_fail_count += task.fail_count;
-                        aggregate_task_times =
-                            merge_times(aggregate_task_times, task.times.clone());
-                        aggregate_task_time_counter += &task.counter;
-                        aggregate_task_time_minimum =
-                            update_min_time(aggregate_task_time_minimum, task.min_time);
-                        aggregate_task_time_maximum =
-                            update_max_time(aggregate_task_time_maximum, task.max_time);
-                    }
-                }
+        // Only build the status_code template if --status-codes is enabled.
+        let status_code_template: String;
+        if self.configuration.status_codes {
+            let mut status_code_metrics = Vec::new();
+            let mut aggregated_status_code_counts: HashMap<u16, usize> = HashMap::new();
+            for (request_key, request) in self.metrics.requests.iter().sorted() {
+                let method = request.method_name();
+                // The request_key is "{method} {name}", so by stripping the "{method} "
+                // prefix we get the name.
+                let name = request_key
+                    .strip_prefix(&format!("{} ", method))
+                    .unwrap()
+                    .to_string();
 
-                let (aggregate_requests_per_second, aggregate_failures_per_second) =
-                    per_second_calculations(
-                        self.metrics.duration,
-                        aggregate_total_count,
-                        aggregate_fail_count,
-                    );
-                task_metrics.push(report::TaskMetric {
-                    is_task_set: false,
-                    task: "".to_string(),
-                    name: "Aggregated".to_string(),
-                    number_of_requests: aggregate_total_count,
-                    number_of_failures: aggregate_fail_count,
-                    response_time_average: format!(
-                        "{:.2}",
-                        raw_aggregate_response_time_counter as f32 / aggregate_total_count as f32
-                    ),
-                    response_time_minimum: aggregate_task_time_minimum,
-                    response_time_maximum: aggregate_task_time_maximum,
-                    requests_per_second: format!("{:.2}", aggregate_requests_per_second),
-                    failures_per_second: format!("{:.2}", aggregate_failures_per_second),
+                // Build a list of status codes, and update the aggregate record.
+                let codes = prepare_status_codes(
+                    &request.status_code_counts,
+                    &mut Some(&mut aggregated_status_code_counts),
+                    &number_format,
+                );
+
+                // Add a row of data for the status code table.
+                status_code_metrics.push(report::StatusCodeMetric {
+                    method,
+                    name,
+                    status_codes: codes,
                 });
-                let mut tasks_rows = Vec::new();
-                // Compile the task metrics template.
-                for metric in task_metrics {
-                    tasks_rows.push(report::task_metrics_row(metric));
-                }
+            }
 
-                tasks_template = report::task_metrics_template(&tasks_rows.join("\n"));
-            } else {
-                tasks_template = "".to_string();
+            // Build a list of aggregate status codes.
+            let aggregated_codes =
+                prepare_status_codes(&aggregated_status_code_counts, &mut None, &number_format);
+
+            // Add a final row of aggregate data for the status code table.
+            status_code_metrics.push(report::StatusCodeMetric {
+                method: "".to_string(),
+                name: "Aggregated".to_string(),
+                status_codes: aggregated_codes,
+            });
+
+            // Compile the status_code metrics rows.
+            let mut status_code_rows = Vec::new();
+            for metric in status_code_metrics {
+                status_code_rows.push(report::status_code_metrics_row(metric));
+            }
+
+            // Compile the status_code metrics template.
+            status_code_template =
+                report::status_code_metrics_template(&status_code_rows.join("\n"));
+        } else {
+            // If --status-codes is not enabled, return an empty template.
+            status_code_template = "".to_string();
+        }
+
+        // Only build the dimensions template if --header-dimensions or --device-profiles
+        // is enabled.
+        let active_users_template = if self.metrics.active_users.is_empty() {
+            "".to_string()
+        } else {
+            report::active_users_template(
+                &self.metrics.active_users,
+                self.metrics
+                    .ramp_profiles
+                    .first()
+                    .map_or(&[], |ramp_profile| &ramp_profile.planned_users),
+                &self.metrics.annotations,
+            )
+        };
+
+        // Only build the user ramp template if planned users were tracked.
+        let ramp_profiles_template = if self.metrics.ramp_profiles.is_empty() {
+            "".to_string()
+        } else {
+            let mut ramp_profile_rows = Vec::new();
+            for ramp_profile in &self.metrics.ramp_profiles {
+                let (planned, planned_at, active, reached_at) = ramp_profile.summary();
+                ramp_profile_rows.push(report::ramp_profile_row(report::RampProfileMetric {
+                    name: ramp_profile.display_name(),
+                    planned_users: self.metrics.number_format.number(planned),
+                    planned_at: format!("{}s", planned_at),
+                    active_users: self.metrics.number_format.number(active),
+                    reached_at: match reached_at {
+                        Some(second) => format!("{}s", second),
+                        None => "never".to_string(),
+                    },
+                }));
+            }
+            report::ramp_profiles_template(&ramp_profile_rows.join("\n"))
+        };
+
+        // Only build the phases template if a test plan was followed.
+        let phases_template = if self.metrics.phases.is_empty() {
+            "".to_string()
+        } else {
+            let mut phase_rows = Vec::new();
+            for (index, phase) in self.metrics.phases.iter().enumerate() {
+                phase_rows.push(report::phase_row(report::PhaseMetric {
+                    name: phase.name.to_string(),
+                    users: self.metrics.number_format.number(phase.users),
+                    started: format!("{}s", phase.elapsed),
+                    duration: format!("{}s", self.metrics.phase_duration(index).round()),
+                    requests: self.metrics.number_format.number(phase.requests.requests()),
+                    fails: self.metrics.number_format.number(phase.requests.errors),
+                    requests_per_second: self
+                        .metrics
+                        .number_format
+                        .fixed(self.metrics.phase_requests_per_second(index), 2),
+                    response_time_average: self
+                        .metrics
+                        .number_format
+                        .fixed_time(phase.requests.mean(), 2),
+                    response_time_95: match phase.requests.percentile(0.95) {
+                        Some(time) => self.metrics.number_format.fixed_time(time as f32, 0),
+                        None => "-".to_string(),
+                    },
+                }));
+            }
+            report::phases_template(&phase_rows.join("\n"))
+        };
+
+        // Only build the network baseline template if the round-trip time was measured.
+        let network_baselines_template = if self.metrics.network_baselines.is_empty() {
+            "".to_string()
+        } else {
+            let round_trip_time = |time: Option<f32>| match time {
+                Some(time) => number_format.fixed_time(time, 2),
+                None => "-".to_string(),
+            };
+            let mut network_baseline_rows = Vec::new();
+            for baseline in &self.metrics.network_baselines {
+                network_baseline_rows.push(report::network_baseline_row(
+                    report::NetworkBaselineMetric {
+                        host: baseline.host.to_string(),
+                        address: baseline.address.clone().unwrap_or_else(|| "-".to_string()),
+                        samples: number_format
+                            .number(baseline.round_trip_times.len() + baseline.failures),
+                        failures: number_format.number(baseline.failures),
+                        round_trip_time_minimum: round_trip_time(baseline.minimum()),
+                        round_trip_time_median: round_trip_time(baseline.median()),
+                        round_trip_time_maximum: round_trip_time(baseline.maximum()),
+                    },
+                ));
             }
+            report::network_baselines_template(&network_baseline_rows.join("\n"))
+        };
 
-            // Only build the tasks template if --no-task-metrics isn't enabled.
-            let errors_template: String;
-            if !self.metrics.errors.is_empty() {
-                let mut error_rows = Vec::new();
-                for error in self.metrics.errors.values() {
-                    error_rows.push(report::error_row(error));
-                }
-                errors_template = report::errors_template(&error_rows.join("\n"));
-            } else {
-                errors_template = "".to_string();
+        // Only build the baseline comparison template if a baseline was loaded.
+        let baseline_comparisons = self.metrics.baseline_comparisons();
+        let baseline_comparison_template = if baseline_comparisons.is_empty() {
+            "".to_string()
+        } else {
+            let mut baseline_comparison_rows = Vec::new();
+            for (request_key, comparison) in baseline_comparisons {
+                baseline_comparison_rows.push(report::baseline_comparison_row(
+                    report::BaselineComparisonMetric {
+                        name: request_key.to_string(),
+                        response_time_average: number_format.fixed_time(comparison.average, 2),
+                        response_time_average_change: baseline_change(comparison.average_change),
+                        requests_per_second: number_format.fixed(comparison.requests_per_second, 2),
+                        requests_per_second_change: baseline_change(
+                            comparison.requests_per_second_change,
+                        ),
+                        fail_percent: format!("{:.1}%", comparison.fail_percent),
+                        fail_percent_change: format!("{:+.1}", comparison.fail_percent_change),
+                    },
+                ));
             }
+            report::baseline_comparison_template(&baseline_comparison_rows.join("\n"))
+        };
 
-            // Only build the status_code template if --status-codes is enabled.
-            let status_code_template: String;
-            if self.configuration.status_codes {
-                let mut status_code_metrics = Vec::new();
-                let mut aggregated_status_code_counts: HashMap<u16, usize> = HashMap::new();
-                for (request_key, request) in self.metrics.requests.iter().sorted() {
-                    let method = format!("{}", request.method);
-                    // The request_key is "{method} {name}", so by stripping the "{method} "
-                    // prefix we get the name.
-                    let name = request_key
-                        .strip_prefix(&format!("{} ", request.method))
-                        .unwrap()
-                        .to_string();
+        let response_time_heatmap_template = if self.metrics.response_time_heatmap.counts.is_empty()
+        {
+            "".to_string()
+        } else {
+            report::response_time_heatmap_template(&self.metrics.response_time_heatmap)
+        };
 
-                    // Build a list of status codes, and update the aggregate record.
-                    let codes = prepare_status_codes(
-                        &request.status_code_counts,
-                        &mut Some(&mut aggregated_status_code_counts),
-                    );
+        let request_time_series_template = if self.metrics.request_time_series.seconds.is_empty() {
+            "".to_string()
+        } else {
+            report::request_time_series_template(
+                &self.metrics.request_time_series,
+                &self.metrics.annotations,
+            )
+        };
 
-                    // Add a row of data for the status code table.
-                    status_code_metrics.push(report::StatusCodeMetric {
-                        method,
-                        name,
-                        status_codes: codes,
-                    });
+        let dimensions_template = if !self.configuration.header_dimensions.is_empty()
+            || !self.configuration.device_profiles.is_empty()
+            || !self.configuration.cohorts.is_empty()
+        {
+            let mut dimension_rows = Vec::new();
+            for (request_key, request) in self.metrics.requests.iter().sorted() {
+                let method = request.method_name();
+                // The request_key is "{method} {name}", so by stripping the "{method} "
+                // prefix we get the name.
+                let name = request_key
+                    .strip_prefix(&format!("{} ", method))
+                    .unwrap()
+                    .to_string();
+
+                for (dimension, data) in &request.dimensions {
+                    let average = match data.counter {
+                        0 => 0.0,
+                        _ => data.total_time as f32 / data.counter as f32,
+                    };
+                    dimension_rows.push(report::dimension_metrics_row(report::DimensionMetric {
+                        method: method.to_string(),
+                        name: name.to_string(),
+                        dimension: dimension.to_string(),
+                        number_of_requests: number_format.number(data.counter),
+                        response_time_average: number_format.fixed_time(average, 2),
+                        response_time_minimum: number_format.time(data.minimum_time),
+                        response_time_maximum: number_format.time(data.maximum_time),
+                    }));
                 }
+            }
 
-                // Build a list of aggregate status codes.
-                let aggregated_codes =
-                    prepare_status_codes(&aggregated_status_code_counts, &mut None);
+            // Compile the dimension metrics template.
+            report::dimension_metrics_template(&dimension_rows.join("\n"))
+        } else {
+            // If no dimensions are enabled, return an empty template.
+            "".to_string()
+        };
 
-                // Add a final row of aggregate data for the status code table.
-                status_code_metrics.push(report::StatusCodeMetric {
-                    method: "".to_string(),
-                    name: "Aggregated".to_string(),
-                    status_codes: aggregated_codes,
-                });
+        // Compile the report template.
+        let report = report::build_report(
+            &users,
+            &report_range,
+            hosts,
+            &labels,
+            &run_id,
+            report::GooseReportTemplates {
+                raw_requests_template: &raw_requests_rows.join("\n"),
+                raw_responses_template: &raw_responses_rows.join("\n"),
+                co_requests_template: &co_requests_template,
+                co_responses_template: &co_responses_template,
+                tasks_template: &tasks_template,
+                status_codes_template: &status_code_template,
+                dimensions_template: &dimensions_template,
+                errors_template: &errors_template,
+                active_users_template: &active_users_template,
+                ramp_profiles_template: &ramp_profiles_template,
+                phases_template: &phases_template,
+                network_baselines_template: &network_baselines_template,
+                baseline_comparison_template: &baseline_comparison_template,
+                aborted_template: &aborted_template,
+                response_time_percentiles_template: &response_time_percentiles_template,
+                response_time_heatmap_template: &response_time_heatmap_template,
+                request_time_series_template: &request_time_series_template,
+            },
+        );
 
-                // Compile the status_code metrics rows.
-                let mut status_code_rows = Vec::new();
-                for metric in status_code_metrics {
-                    status_code_rows.push(report::status_code_metrics_row(metric));
-                }
+        // Scaled times are displayed with their own unit, so drop it from the headers.
+        if number_format.scale_units {
+            report.replace(" (ms)</th>", "</th>")
+        } else {
+            report
+        }
+    }
 
-                // Compile the status_code metrics template.
-                status_code_template =
-                    report::status_code_metrics_template(&status_code_rows.join("\n"));
-            } else {
-                // If --status-codes is not enabled, return an empty template.
-                status_code_template = "".to_string();
-            }
-
-            // Compile the report template.
-            let report = report::build_report(
-                &users,
-                &report_range,
-                hosts,
-                report::GooseReportTemplates {
-                    raw_requests_template: &raw_requests_rows.join("\n"),
-                    raw_responses_template: &raw_responses_rows.join("\n"),
-                    co_requests_template: &co_requests_template,
-                    co_responses_template: &co_responses_template,
-                    tasks_template: &tasks_template,
-                    status_codes_template: &status_code_template,
-                    errors_template: &errors_template,
-                },
-            );
+    // Write an HTML-formatted report, if enabled.
+    pub(crate) async fn write_html_report(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+    ) -> Result<(), GooseError> {
+        // Only write the report if enabled.
+        if let Some(report_file) = goose_attack_run_state.report_file.as_mut() {
+            let report = self.build_html_report();
+
+            // Discard any interim report previously written to the same file.
+            report_file.set_len(0).await?;
 
             // Write the report to file.
             if let Err(e) = report_file.write(report.as_ref()).await {
@@ -3039,6 +5834,212 @@ impl GooseAttack {
 
         Ok(())
     }
+
+    // Write an interim HTML-formatted report while the load test is running, if enabled
+    // and the report interval has passed. Failing to write an interim report is logged,
+    // but doesn't stop the load test.
+    pub(crate) async fn write_interim_report(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+    ) {
+        let report_interval = match self.configuration.report_interval {
+            Some(report_interval) => report_interval,
+            None => return,
+        };
+        if self.attack_mode == AttackMode::Worker
+            || !util::timer_expired(goose_attack_run_state.report_timer, report_interval * 60)
+        {
+            return;
+        }
+        goose_attack_run_state.report_timer = std::time::Instant::now();
+
+        // Interim reports are written to the report file, or to a timestamped copy of it.
+        let report_file_path = match self.get_report_file_path() {
+            Some(report_file_path) => report_file_path,
+            None => return,
+        };
+        let report_file_path = if self.configuration.report_timestamp {
+            timestamped_path(&report_file_path, &Local::now())
+        } else {
+            report_file_path
+        };
+
+        self.update_duration();
+        let report = self.build_html_report();
+        match tokio::fs::write(&report_file_path, report).await {
+            Ok(_) => info!("wrote interim html report file to: {}", report_file_path),
+            Err(e) => warn!(
+                "failed to write interim html report file to {}: {}",
+                report_file_path, e
+            ),
+        }
+    }
+
+    // Save a metrics checkpoint while the load test is running, if enabled and the
+    // checkpoint interval has passed. The checkpoint is written to a temporary file and
+    // then renamed, so a crash while writing never leaves a truncated checkpoint behind.
+    // Failing to save a checkpoint is logged, but doesn't stop the load test.
+    pub(crate) async fn write_checkpoint(
+        &mut self,
+        goose_attack_run_state: &mut GooseAttackRunState,
+    ) {
+        let checkpoint_interval = match self.configuration.checkpoint_interval {
+            Some(checkpoint_interval) => checkpoint_interval,
+            None => return,
+        };
+        if self.configuration.checkpoint_file.is_empty()
+            || self.attack_mode == AttackMode::Worker
+            || !util::timer_expired(goose_attack_run_state.checkpoint_timer, checkpoint_interval)
+        {
+            return;
+        }
+        goose_attack_run_state.checkpoint_timer = std::time::Instant::now();
+
+        self.update_duration();
+        let checkpoint = GooseMetricsCheckpoint {
+            hash: self.load_test_hash(),
+            duration: self.metrics.duration,
+            requests: self.metrics.requests.clone(),
+            tasks: self.metrics.tasks.clone(),
+            errors: self.metrics.errors.clone(),
+            hosts: self.metrics.hosts.clone(),
+        };
+        let checkpoint_file = &self.configuration.checkpoint_file;
+        let temporary_file = format!("{}.tmp", checkpoint_file);
+        let result = match serde_json::to_string(&checkpoint) {
+            Ok(json) => match tokio::fs::write(&temporary_file, json).await {
+                Ok(_) => tokio::fs::rename(&temporary_file, checkpoint_file).await,
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e.into()),
+        };
+        match result {
+            Ok(_) => debug!("saved metrics checkpoint to: {}", checkpoint_file),
+            Err(e) => warn!(
+                "failed to save metrics checkpoint to {}: {}",
+                checkpoint_file, e
+            ),
+        }
+    }
+
+    // Load the metrics checkpoint to resume from, if configured. The checkpoint must
+    // have been saved by the same load test, unless `--no-hash-check` is enabled.
+    pub(crate) async fn load_checkpoint(
+        &self,
+    ) -> Result<Option<GooseMetricsCheckpoint>, GooseError> {
+        let resume = &self.configuration.resume;
+        if resume.is_empty() {
+            return Ok(None);
+        }
+
+        let invalid_checkpoint = |detail: String| GooseError::InvalidOption {
+            option: "--resume".to_string(),
+            value: resume.to_string(),
+            detail,
+        };
+        let json = tokio::fs::read_to_string(resume)
+            .await
+            .map_err(|e| invalid_checkpoint(format!("Failed to read checkpoint: {}", e)))?;
+        let checkpoint: GooseMetricsCheckpoint = serde_json::from_str(&json)
+            .map_err(|e| invalid_checkpoint(format!("Failed to parse checkpoint: {}", e)))?;
+
+        if checkpoint.hash != self.load_test_hash() {
+            if self.configuration.no_hash_check {
+                warn!("checkpoint {} was saved by a different load test", resume);
+            } else {
+                return Err(invalid_checkpoint(
+                    "Checkpoint was saved by a different load test (disable with --no-hash-check)."
+                        .to_string(),
+                ));
+            }
+        }
+        info!(
+            "resuming from checkpoint {} after {} seconds",
+            resume, checkpoint.duration
+        );
+
+        Ok(Some(checkpoint))
+    }
+
+    // Load the metrics of an earlier load test to compare running metrics with, if
+    // configured.
+    pub(crate) async fn load_baseline(&self) -> Result<Option<GooseMetricsBaseline>, GooseError> {
+        let baseline_file = &self.configuration.baseline_file;
+        if baseline_file.is_empty() {
+            return Ok(None);
+        }
+
+        let invalid_baseline = |detail: String| GooseError::InvalidOption {
+            option: "--baseline-file".to_string(),
+            value: baseline_file.to_string(),
+            detail,
+        };
+        let json = tokio::fs::read_to_string(baseline_file)
+            .await
+            .map_err(|e| invalid_baseline(format!("Failed to read baseline: {}", e)))?;
+        let baseline: GooseMetricsBaseline = serde_json::from_str(&json)
+            .map_err(|e| invalid_baseline(format!("Failed to parse baseline: {}", e)))?;
+        info!(
+            "comparing metrics with baseline {} of {} requests",
+            baseline_file,
+            baseline.requests.len()
+        );
+
+        Ok(Some(baseline))
+    }
+
+    // Replace the metrics collected so far with the metrics in a checkpoint, and move
+    // the start time back so the duration and remaining run time continue from where
+    // the checkpoint was saved.
+    fn resume_from_checkpoint(&mut self, checkpoint: GooseMetricsCheckpoint) {
+        self.metrics.requests = checkpoint.requests;
+        self.metrics.tasks = checkpoint.tasks;
+        self.metrics.errors = checkpoint.errors;
+        self.metrics.hosts.extend(checkpoint.hosts);
+        self.started = std::time::Instant::now()
+            .checked_sub(std::time::Duration::from_secs(checkpoint.duration as u64))
+            .or(self.started);
+    }
+}
+
+/// Insert a timestamp into a file path before its extension, for example
+/// `report.html` becomes `report-20211102-153000.html`.
+pub(crate) fn timestamped_path<Tz: TimeZone>(path: &str, timestamp: &DateTime<Tz>) -> String
+where
+    Tz::Offset: fmt::Display,
+{
+    let timestamp = timestamp.format("%Y%m%d-%H%M%S");
+    let path = std::path::Path::new(path);
+    let file_name = match (path.file_stem(), path.extension()) {
+        (Some(stem), Some(extension)) => format!(
+            "{}-{}.{}",
+            stem.to_string_lossy(),
+            timestamp,
+            extension.to_string_lossy()
+        ),
+        (Some(stem), None) => format!("{}-{}", stem.to_string_lossy(), timestamp),
+        _ => timestamp.to_string(),
+    };
+    path.with_file_name(file_name).to_string_lossy().to_string()
+}
+
+/// How many users are planned to be running the given second after the load test started,
+/// optionally only counting users of one task set.
+fn planned_users_at(
+    planned_launches: &[(usize, usize)],
+    second: usize,
+    task_sets_index: Option<usize>,
+) -> usize {
+    planned_launches
+        .iter()
+        .filter(|(launch_ms, index)| {
+            *launch_ms <= second * 1_000
+                && match task_sets_index {
+                    Some(task_sets_index) => task_sets_index == *index,
+                    None => true,
+                }
+        })
+        .count()
 }
 
 /// Helper to calculate requests and fails per seconds.
@@ -3055,6 +6056,15 @@ pub(crate) fn per_second_calculations(duration: usize, total: usize, fail: usize
     (requests_per_second, fails_per_second)
 }
 
+/// Format the percent change of a metric compared with the baseline, or `-` if the
+/// baseline is 0.
+pub(crate) fn baseline_change(change: Option<f32>) -> String {
+    match change {
+        Some(change) => format!("{:+.1}%", change),
+        None => "-".to_string(),
+    }
+}
+
 fn determine_precision(value: f32) -> usize {
     if value < 1000.0 {
         2
@@ -3063,9 +6073,118 @@ fn determine_precision(value: f32) -> usize {
     }
 }
 
-/// Format large number in locale appropriate style.
-pub(crate) fn format_number(number: usize) -> String {
-    (number).to_formatted_string(&Locale::en)
+/// How numbers are displayed in metrics tables and the html report, configured with the
+/// `--number-locale` and `--scale-units` run-time options.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GooseNumberFormat {
+    /// Digits are grouped and decimals separated as in this locale, ie `1,234.5` in
+    /// `en` or `1.234,5` in `de`.
+    pub(crate) locale: Locale,
+    /// Whether times are displayed in milliseconds or seconds with their unit, and byte
+    /// counts with SI prefixes, instead of as raw milliseconds and bytes.
+    pub(crate) scale_units: bool,
+}
+impl Default for GooseNumberFormat {
+    fn default() -> Self {
+        GooseNumberFormat {
+            locale: Locale::en,
+            scale_units: false,
+        }
+    }
+}
+impl GooseNumberFormat {
+    /// Build the number format configured with `--number-locale` and `--scale-units`.
+    pub(crate) fn new(config: &GooseConfiguration) -> Self {
+        GooseNumberFormat {
+            // The locale was validated at startup.
+            locale: Locale::from_name(&config.number_locale).unwrap_or(Locale::en),
+            scale_units: config.scale_units,
+        }
+    }
+
+    /// Format a count, grouping its digits.
+    pub(crate) fn number(&self, number: usize) -> String {
+        number.to_formatted_string(&self.locale)
+    }
+
+    /// Format a decimal number with the given precision, grouping the digits of its
+    /// integer part.
+    pub(crate) fn fixed(&self, value: f32, precision: usize) -> String {
+        let formatted = format!("{:.*}", precision, value);
+        let (integer, fraction) = match formatted.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (formatted.as_str(), None),
+        };
+        // Values that aren't a plain integer, such as NaN, are displayed as is.
+        let integer = match integer.parse::<u64>() {
+            Ok(integer) => integer.to_formatted_string(&self.locale),
+            Err(_) => return formatted,
+        };
+        match fraction {
+            Some(fraction) => format!("{}{}{}", integer, self.locale.decimal(), fraction),
+            None => integer,
+        }
+    }
+
+    /// Format a decimal number, with less precision the larger it is.
+    pub(crate) fn decimal(&self, value: f32) -> String {
+        self.fixed(value, determine_precision(value))
+    }
+
+    /// Format a time in milliseconds.
+    pub(crate) fn time(&self, milliseconds: usize) -> String {
+        if !self.scale_units {
+            self.number(milliseconds)
+        } else if milliseconds < 1_000 {
+            format!("{} ms", milliseconds)
+        } else {
+            format!("{} s", self.fixed(milliseconds as f32 / 1_000.0, 2))
+        }
+    }
+
+    /// Format an average time in milliseconds, with less precision the larger it is.
+    pub(crate) fn average_time(&self, milliseconds: f32) -> String {
+        self.fixed_time(milliseconds, determine_precision(milliseconds))
+    }
+
+    /// Format an average time in milliseconds with the given precision.
+    pub(crate) fn fixed_time(&self, milliseconds: f32, precision: usize) -> String {
+        if !self.scale_units {
+            self.fixed(milliseconds, precision)
+        } else if milliseconds < 1_000.0 {
+            format!("{} ms", self.fixed(milliseconds, precision))
+        } else {
+            format!("{} s", self.fixed(milliseconds / 1_000.0, 2))
+        }
+    }
+
+    /// Format a count of bytes.
+    pub(crate) fn bytes(&self, bytes: usize) -> String {
+        if !self.scale_units {
+            return self.number(bytes);
+        }
+        let mut value = bytes as f32;
+        for prefix in &["", "k", "M", "G", "T"] {
+            if value < 1_000.0 {
+                return if prefix.is_empty() {
+                    format!("{} B", bytes)
+                } else {
+                    format!("{} {}B", self.fixed(value, 2), prefix)
+                };
+            }
+            value /= 1_000.0;
+        }
+        format!("{} PB", self.fixed(value, 2))
+    }
+
+    /// Label a column of times, including the unit unless each time has its own.
+    pub(crate) fn time_label(&self, label: &str) -> String {
+        if self.scale_units {
+            label.to_string()
+        } else {
+            format!("{} (ms)", label)
+        }
+    }
 }
 
 /// A helper function that merges together times.
@@ -3105,6 +6224,21 @@ pub(crate) fn update_max_time(mut global_max: usize, max: usize) -> usize {
     global_max
 }
 
+/// Get the median time, or "-" if the times were discarded with `--no-histograms`.
+fn format_median(
+    times: &BTreeMap<usize, usize>,
+    total: usize,
+    min: usize,
+    max: usize,
+    number_format: &GooseNumberFormat,
+) -> String {
+    if times.is_empty() && total > 0 {
+        "-".to_string()
+    } else {
+        number_format.time(util::median(times, total, min, max))
+    }
+}
+
 /// Get the response time that a certain number of percent of the requests finished within.
 pub(crate) fn calculate_response_time_percentile(
     response_times: &BTreeMap<usize, usize>,
@@ -3112,7 +6246,28 @@ pub(crate) fn calculate_response_time_percentile(
     min: usize,
     max: usize,
     percent: f32,
+    number_format: &GooseNumberFormat,
 ) -> String {
+    match response_time_percentile(response_times, total_requests, min, max, percent) {
+        Some(response_time) => number_format.time(response_time),
+        // Response times were discarded, the percentile is unknown.
+        None => "-".to_string(),
+    }
+}
+
+/// Get the response time in milliseconds that a certain number of percent of the requests
+/// finished within, or `None` if response times were discarded.
+pub(crate) fn response_time_percentile(
+    response_times: &BTreeMap<usize, usize>,
+    total_requests: usize,
+    min: usize,
+    max: usize,
+    percent: f32,
+) -> Option<usize> {
+    if response_times.is_empty() && total_requests > 0 {
+        return None;
+    }
+
     let percentile_request = (total_requests as f32 * percent).round() as usize;
     debug!(
         "percentile: {}, request {} of total {}",
@@ -3125,35 +6280,32 @@ pub(crate) fn calculate_response_time_percentile(
         total_count += counter;
         if total_count >= percentile_request {
             if *value < min {
-                return format_number(min);
+                return Some(min);
             } else if *value > max {
-                return format_number(max);
+                return Some(max);
             } else {
-                return format_number(*value);
+                return Some(*value);
             }
         }
     }
-    format_number(0)
+    Some(0)
 }
 
 /// Helper to count and aggregate seen status codes.
 pub(crate) fn prepare_status_codes(
     status_code_counts: &HashMap<u16, usize>,
     aggregate_counts: &mut Option<&mut HashMap<u16, usize>>,
+    number_format: &GooseNumberFormat,
 ) -> String {
     let mut codes: String = "".to_string();
     for (status_code, count) in status_code_counts {
         if codes.is_empty() {
-            codes = format!(
-                "{} [{}]",
-                count.to_formatted_string(&Locale::en),
-                status_code
-            );
+            codes = format!("{} [{}]", number_format.number(*count), status_code);
         } else {
             codes = format!(
                 "{}, {} [{}]",
                 codes.clone(),
-                count.to_formatted_string(&Locale::en),
+                number_format.number(*count),
                 status_code
             );
         }
@@ -3175,6 +6327,237 @@ pub(crate) fn prepare_status_codes(
 mod test {
     use super::*;
 
+    #[test]
+    fn output_from_str() {
+        assert_eq!(
+            GooseOutput::from_str("verbose").unwrap(),
+            GooseOutput::Verbose
+        );
+        assert_eq!(
+            GooseOutput::from_str("Summary").unwrap(),
+            GooseOutput::Summary
+        );
+        assert_eq!(
+            GooseOutput::from_str("only-summary").unwrap(),
+            GooseOutput::Summary
+        );
+        assert_eq!(GooseOutput::from_str("JSON").unwrap(), GooseOutput::Json);
+        assert_eq!(GooseOutput::from_str("quiet").unwrap(), GooseOutput::Quiet);
+        assert!(GooseOutput::from_str("loud").is_err());
+    }
+
+    #[test]
+    fn number_format() {
+        // By default digits are grouped as in English, and times are in milliseconds.
+        let number_format = GooseNumberFormat::default();
+        assert_eq!(number_format.number(1_234_567), "1,234,567");
+        assert_eq!(number_format.fixed(1_234.5, 2), "1,234.50");
+        assert_eq!(number_format.time(12_345), "12,345");
+        assert_eq!(number_format.average_time(12.345), "12.35");
+        assert_eq!(number_format.bytes(2_048), "2,048");
+        assert_eq!(number_format.time_label("Avg"), "Avg (ms)");
+
+        // Digits can be grouped and decimals separated as in another locale.
+        let number_format = GooseNumberFormat {
+            locale: Locale::de,
+            scale_units: false,
+        };
+        assert_eq!(number_format.number(1_234_567), "1.234.567");
+        assert_eq!(number_format.fixed(1_234.5, 2), "1.234,50");
+
+        // Times and bytes can be scaled, each including its own unit.
+        let number_format = GooseNumberFormat {
+            locale: Locale::en,
+            scale_units: true,
+        };
+        assert_eq!(number_format.time(999), "999 ms");
+        assert_eq!(number_format.time(12_345), "12.35 s");
+        assert_eq!(number_format.average_time(12.345), "12.35 ms");
+        assert_eq!(number_format.average_time(1_500.0), "1.50 s");
+        assert_eq!(number_format.bytes(999), "999 B");
+        assert_eq!(number_format.bytes(2_048), "2.05 kB");
+        assert_eq!(number_format.bytes(3_500_000_000), "3.50 GB");
+        assert_eq!(number_format.time_label("Avg"), "Avg");
+    }
+
+    #[test]
+    fn concurrency() {
+        let mut concurrency = GooseConcurrency::default();
+        assert_eq!(concurrency.average(), 0.0);
+        assert_eq!(concurrency.maximum(), 0.0);
+
+        // Two requests in flight throughout the first second.
+        concurrency.record(1_000, 1_000);
+        concurrency.record(1_000, 1_000);
+        assert_eq!(concurrency.busy, vec![2_000, 0]);
+
+        // A request spanning the second and third seconds is split between them.
+        concurrency.record(2_500, 1_000);
+        assert_eq!(concurrency.busy, vec![2_000, 500, 500]);
+        assert_eq!(concurrency.maximum(), 2.0);
+        assert_eq!(concurrency.average(), 1.0);
+
+        // Seconds before the first was in flight aren't averaged.
+        let mut concurrency = GooseConcurrency::default();
+        concurrency.record(3_000, 1_000);
+        assert_eq!(concurrency.busy, vec![0, 0, 1_000, 0]);
+        assert_eq!(concurrency.average(), 0.5);
+    }
+
+    #[test]
+    fn response_time_heatmap() {
+        let mut heatmap = GooseResponseTimeHeatmap::default();
+        heatmap.record(0, 0);
+        heatmap.record(0, 1);
+        heatmap.record(0, 2);
+        heatmap.record(2, 150);
+        heatmap.record(2, 60_000);
+
+        // A row for each second, including seconds without responses.
+        assert_eq!(heatmap.counts.len(), 3);
+        assert!(heatmap
+            .counts
+            .iter()
+            .all(|counts| counts.len() == RESPONSE_TIME_HEATMAP_BUCKETS.len() + 1));
+        // Response times are counted in the first bucket they fit in.
+        assert_eq!(heatmap.counts[0][0], 2);
+        assert_eq!(heatmap.counts[0][1], 1);
+        assert_eq!(heatmap.counts[1].iter().sum::<usize>(), 0);
+        assert_eq!(heatmap.counts[2][7], 1);
+        // Responses slower than the last bound are counted in the final bucket.
+        assert_eq!(heatmap.counts[2][RESPONSE_TIME_HEATMAP_BUCKETS.len()], 1);
+    }
+
+    #[test]
+    fn request_response_time_percentile() {
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        assert_eq!(request.response_time_percentile(0.99), Some(0));
+        for response_time in 1..=10_000 {
+            request.record_time(response_time, false);
+        }
+        // Slow response times are only kept with three significant digits when compiled
+        // with the `hdr-histogram` feature, otherwise they're rounded to the nearest second.
+        #[cfg(feature = "hdr-histogram")]
+        {
+            assert_eq!(request.response_time_percentile(0.5), Some(5_003));
+            assert_eq!(request.response_time_percentile(0.999), Some(9_991));
+        }
+        #[cfg(not(feature = "hdr-histogram"))]
+        {
+            assert_eq!(request.response_time_percentile(0.5), Some(5_000));
+            assert_eq!(request.response_time_percentile(0.999), Some(10_000));
+        }
+        assert_eq!(request.response_time_percentile(1.0), Some(10_000));
+
+        // Only the response times of the request are kept in a histogram.
+        #[cfg(feature = "hdr-histogram")]
+        {
+            request.record_dimension("x-backend", "a", 10);
+            request.record_time(10_001, true);
+            assert!(request.raw_data.histogram.is_some());
+            assert!(request.dimensions.values().all(|d| d.histogram.is_none()));
+            assert!(request
+                .coordinated_omission_data
+                .as_ref()
+                .unwrap()
+                .histogram
+                .is_none());
+        }
+
+        // Percentiles are unknown once response times are discarded.
+        request.discard_times();
+        assert_eq!(request.response_time_percentile(0.5), None);
+    }
+
+    #[test]
+    fn request_time_series() {
+        let mut time_series = GooseRequestTimeSeries::default();
+        time_series.record(0, 10, true, false);
+        time_series.record(0, 30, false, false);
+        time_series.record(2, 150, true, false);
+        time_series.record(2, 50, true, true);
+
+        // A second for each second, including seconds without requests.
+        assert_eq!(time_series.seconds.len(), 3);
+        assert_eq!(time_series.seconds[0].requests(), 2);
+        assert_eq!(time_series.seconds[0].errors, 1);
+        assert_eq!(time_series.seconds[0].mean(), 20.0);
+        assert_eq!(time_series.seconds[0].percentile(0.5), Some(10));
+        assert_eq!(time_series.seconds[0].percentile(0.95), Some(30));
+        assert_eq!(time_series.seconds[1].requests(), 0);
+        assert_eq!(time_series.seconds[1].mean(), 0.0);
+        // Without individual times percentiles are unknown, but the rest is tracked.
+        assert_eq!(time_series.seconds[2].requests(), 2);
+        assert_eq!(time_series.seconds[2].mean(), 100.0);
+        assert_eq!(time_series.seconds[2].percentile(0.95), None);
+
+        // Requests updated as failed or successful change the errors in the second the
+        // update was received.
+        time_series.record_update(1, false);
+        assert_eq!(time_series.seconds[1].errors, 1);
+        time_series.record_update(0, true);
+        assert_eq!(time_series.seconds[0].errors, 0);
+        time_series.record_update(0, true);
+        assert_eq!(time_series.seconds[0].errors, 0);
+    }
+
+    #[test]
+    fn transport_error() {
+        use std::io;
+
+        // Errors are classified by kind where possible.
+        let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+        assert_eq!(
+            GooseTransportError::from_error(&refused),
+            GooseTransportError::ConnectionRefused
+        );
+        let reset = io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe");
+        assert_eq!(
+            GooseTransportError::from_error(&reset),
+            GooseTransportError::ConnectionReset
+        );
+        let timeout = io::Error::new(io::ErrorKind::TimedOut, "socket request timed out");
+        assert_eq!(
+            GooseTransportError::from_error(&timeout),
+            GooseTransportError::Timeout
+        );
+
+        // Otherwise by their message.
+        let dns = io::Error::new(
+            io::ErrorKind::NotFound,
+            "dns error: failed to lookup address information: Name or service not known",
+        );
+        assert_eq!(
+            GooseTransportError::from_error(&dns),
+            GooseTransportError::Dns
+        );
+        let tls = io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid peer certificate: UnknownIssuer",
+        );
+        assert_eq!(
+            GooseTransportError::from_error(&tls),
+            GooseTransportError::Tls
+        );
+        let other = io::Error::new(io::ErrorKind::InvalidData, "something went wrong");
+        assert_eq!(
+            GooseTransportError::from_error(&other),
+            GooseTransportError::Other
+        );
+    }
+
+    #[tokio::test]
+    async fn transport_error_url() {
+        // Words in the URL of a failed request don't affect how it's classified.
+        let error = reqwest::get("http://127.0.0.1:1/tls-certificate-timed-out")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            GooseTransportError::from_reqwest_error(&error),
+            GooseTransportError::ConnectionRefused
+        );
+    }
+
     #[test]
     fn max_response_time() {
         let mut max_response_time = 99;
@@ -3216,21 +6599,84 @@ mod test {
         response_times.insert(2, 1);
         response_times.insert(3, 1);
         // 3 * .5 = 1.5, rounds to 2.
-        assert!(calculate_response_time_percentile(&response_times, 3, 1, 3, 0.5) == "2");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                3,
+                1,
+                3,
+                0.5,
+                &GooseNumberFormat::default()
+            ) == "2"
+        );
         response_times.insert(3, 2);
         // 4 * .5 = 2
-        assert!(calculate_response_time_percentile(&response_times, 4, 1, 3, 0.5) == "2");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                1,
+                3,
+                0.5,
+                &GooseNumberFormat::default()
+            ) == "2"
+        );
         // 4 * .25 = 1
-        assert!(calculate_response_time_percentile(&response_times, 4, 1, 3, 0.25) == "1");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                1,
+                3,
+                0.25,
+                &GooseNumberFormat::default()
+            ) == "1"
+        );
         // 4 * .75 = 3
-        assert!(calculate_response_time_percentile(&response_times, 4, 1, 3, 0.75) == "3");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                1,
+                3,
+                0.75,
+                &GooseNumberFormat::default()
+            ) == "3"
+        );
         // 4 * 1 = 4 (and the 4th response time is also 3)
-        assert!(calculate_response_time_percentile(&response_times, 4, 1, 3, 1.0) == "3");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                1,
+                3,
+                1.0,
+                &GooseNumberFormat::default()
+            ) == "3"
+        );
 
         // 4 * .5 = 2, but uses specified minimum of 2
-        assert!(calculate_response_time_percentile(&response_times, 4, 2, 3, 0.25) == "2");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                2,
+                3,
+                0.25,
+                &GooseNumberFormat::default()
+            ) == "2"
+        );
         // 4 * .75 = 3, but uses specified maximum of 2
-        assert!(calculate_response_time_percentile(&response_times, 4, 1, 2, 0.75) == "2");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                4,
+                1,
+                2,
+                0.75,
+                &GooseNumberFormat::default()
+            ) == "2"
+        );
 
         response_times.insert(10, 25);
         response_times.insert(20, 25);
@@ -3238,9 +6684,36 @@ mod test {
         response_times.insert(50, 25);
         response_times.insert(100, 10);
         response_times.insert(200, 1);
-        assert!(calculate_response_time_percentile(&response_times, 115, 1, 200, 0.9) == "50");
-        assert!(calculate_response_time_percentile(&response_times, 115, 1, 200, 0.99) == "100");
-        assert!(calculate_response_time_percentile(&response_times, 115, 1, 200, 0.999) == "200");
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                115,
+                1,
+                200,
+                0.9,
+                &GooseNumberFormat::default()
+            ) == "50"
+        );
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                115,
+                1,
+                200,
+                0.99,
+                &GooseNumberFormat::default()
+            ) == "100"
+        );
+        assert!(
+            calculate_response_time_percentile(
+                &response_times,
+                115,
+                1,
+                200,
+                0.999,
+                &GooseNumberFormat::default()
+            ) == "200"
+        );
     }
 
     #[test]
@@ -3300,6 +6773,18 @@ mod test {
         assert_eq!(request_metric.status_code, 200);
         assert!(request_metric.success);
         assert!(!request_metric.update);
+
+        // Requests aren't slow without a response time budget.
+        assert!(!request_metric.slow);
+        request_metric.set_response_time_budget(Some(200));
+        assert_eq!(request_metric.response_time_budget, Some(200));
+        assert!(!request_metric.slow);
+        // Slow requests are still successful.
+        request_metric.set_response_time_budget(Some(100));
+        assert!(request_metric.slow);
+        assert!(request_metric.success);
+        request_metric.set_response_time_budget(None);
+        assert!(!request_metric.slow);
     }
 
     #[test]
@@ -3507,4 +6992,182 @@ mod test {
         assert_eq!(request.raw_data.total_time, 987657045);
         assert_eq!(request.raw_data.counter, 8);
     }
+
+    #[test]
+    fn record_dimensions() {
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        request.record_dimension("x-cache", "HIT", 10);
+        request.record_dimension("x-cache", "HIT", 20);
+        request.record_dimension("x-cache", "MISS", 300);
+        assert_eq!(request.dimensions.len(), 2);
+        assert_eq!(request.dimensions["x-cache: HIT"].counter, 2);
+        assert_eq!(request.dimensions["x-cache: HIT"].minimum_time, 10);
+        assert_eq!(request.dimensions["x-cache: HIT"].maximum_time, 20);
+        assert_eq!(request.dimensions["x-cache: MISS"].counter, 1);
+
+        // Once too many distinct values are seen, further values are grouped together.
+        for value in 0..MAX_DIMENSION_VALUES + 5 {
+            request.record_dimension("x-request-id", &value.to_string(), 1);
+        }
+        assert_eq!(request.dimensions.len(), 2 + MAX_DIMENSION_VALUES + 1);
+        assert_eq!(request.dimensions["x-request-id: (other)"].counter, 5);
+        // Values already seen are still tracked individually.
+        request.record_dimension("x-request-id", "0", 1);
+        assert_eq!(request.dimensions["x-request-id: 0"].counter, 2);
+    }
+
+    #[test]
+    fn request_key() {
+        let mut metrics = GooseMetrics::default();
+        assert_eq!(
+            metrics.request_key(&GooseMethod::Get, "/"),
+            ("GET /".to_string(), "/".to_string())
+        );
+
+        // Once too many distinct requests are tracked, further requests are grouped together
+        // by method.
+        for path in 0..MAX_REQUEST_NAMES {
+            let path = format!("/{}", path);
+            let (key, name) = metrics.request_key(&GooseMethod::Get, &path);
+            assert_eq!(name, path);
+            metrics.requests.insert(
+                key,
+                GooseRequestMetricAggregate::new(&name, GooseMethod::Get, 0),
+            );
+        }
+        assert_eq!(
+            metrics.request_key(&GooseMethod::Get, "/new"),
+            ("GET (other)".to_string(), "(other)".to_string())
+        );
+        assert_eq!(
+            metrics.request_key(&GooseMethod::Post, "/new"),
+            ("POST (other)".to_string(), "(other)".to_string())
+        );
+        // Requests already tracked are still tracked individually.
+        assert_eq!(
+            metrics.request_key(&GooseMethod::Get, "/0"),
+            ("GET /0".to_string(), "/0".to_string())
+        );
+    }
+
+    #[test]
+    fn checkpoint_round_trip() {
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        request.record_time(12, false);
+        request.success_count = 3;
+        let mut requests = GooseRequestMetrics::new();
+        requests.insert("GET /".to_string(), request);
+        let mut hosts = HashSet::new();
+        hosts.insert("http://127.0.0.1".to_string());
+
+        let checkpoint = GooseMetricsCheckpoint {
+            hash: 42,
+            duration: 3600,
+            requests,
+            tasks: Vec::new(),
+            errors: GooseErrorMetrics::new(),
+            hosts,
+        };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let resumed: GooseMetricsCheckpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.hash, 42);
+        assert_eq!(resumed.duration, 3600);
+        assert_eq!(resumed.hosts, checkpoint.hosts);
+        let resumed_request = resumed.requests.get("GET /").unwrap();
+        assert_eq!(resumed_request.success_count, 3);
+        assert_eq!(
+            resumed_request.raw_data.times,
+            checkpoint.requests.get("GET /").unwrap().raw_data.times
+        );
+    }
+
+    #[test]
+    fn interim_report_path() {
+        let timestamp = DateTime::parse_from_rfc3339("2021-11-02T15:30:00+00:00").unwrap();
+        assert_eq!(
+            timestamped_path("report.html", &timestamp),
+            "report-20211102-153000.html"
+        );
+        assert_eq!(
+            timestamped_path("reports/goose.report.html", &timestamp),
+            "reports/goose.report-20211102-153000.html"
+        );
+        assert_eq!(
+            timestamped_path("report", &timestamp),
+            "report-20211102-153000"
+        );
+    }
+
+    #[test]
+    fn ramp_profile() {
+        // Two users of task set 0 launched a second apart, and one of task set 1.
+        let planned_launches = vec![(0, 0), (0, 1), (1_000, 0)];
+        assert_eq!(planned_users_at(&planned_launches, 0, None), 2);
+        assert_eq!(planned_users_at(&planned_launches, 1, None), 3);
+        assert_eq!(planned_users_at(&planned_launches, 1, Some(0)), 2);
+        assert_eq!(planned_users_at(&planned_launches, 1, Some(1)), 1);
+
+        // The planned users were reached a second late.
+        let mut ramp_profile = GooseRampProfile {
+            taskset_index: Some(0),
+            name: "LoadtestTasks".to_string(),
+            planned_users: vec![2, 3, 3],
+            active_users: vec![1, 2, 3],
+        };
+        assert_eq!(ramp_profile.display_name(), "1: LoadtestTasks");
+        assert_eq!(ramp_profile.summary(), (3, 1, 3, Some(2)));
+        // The planned users were never reached.
+        ramp_profile.active_users = vec![1, 2, 2];
+        assert_eq!(ramp_profile.summary(), (3, 1, 2, None));
+    }
+
+    #[test]
+    fn network_baseline() {
+        let mut baseline = GooseNetworkBaseline {
+            host: "http://127.0.0.1/".to_string(),
+            address: Some("127.0.0.1:80".to_string()),
+            round_trip_times: vec![4.0, 1.5, 2.5],
+            failures: 1,
+        };
+        assert_eq!(baseline.minimum(), Some(1.5));
+        assert_eq!(baseline.median(), Some(2.5));
+        assert_eq!(baseline.maximum(), Some(4.0));
+        baseline.round_trip_times.push(3.5);
+        assert_eq!(baseline.median(), Some(3.0));
+
+        // Nothing to report if every connect failed.
+        baseline.round_trip_times.clear();
+        assert_eq!(baseline.minimum(), None);
+        assert_eq!(baseline.median(), None);
+        assert_eq!(baseline.maximum(), None);
+    }
+
+    #[test]
+    fn baseline_comparison() {
+        // The baseline made 10 requests in 10 seconds, averaging 10 ms, with 1 failure.
+        let mut baseline = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        baseline.record_time(5, false);
+        baseline.record_time(15, false);
+        baseline.success_count = 9;
+        baseline.fail_count = 1;
+
+        // This load test made 20 requests in 10 seconds, averaging 15 ms, without failures.
+        let mut request = GooseRequestMetricAggregate::new("/", GooseMethod::Get, 0);
+        request.record_time(15, false);
+        request.success_count = 20;
+
+        let comparison = GooseBaselineComparison::new(&request, 10, &baseline, 10);
+        assert_eq!(comparison.average, 15.0);
+        assert_eq!(comparison.average_change, Some(50.0));
+        assert_eq!(comparison.requests_per_second, 2.0);
+        assert_eq!(comparison.requests_per_second_change, Some(100.0));
+        assert_eq!(comparison.fail_percent, 0.0);
+        assert_eq!(comparison.fail_percent_change, -10.0);
+        assert_eq!(baseline_change(comparison.average_change), "+50.0%");
+
+        // Nothing to compare with if the baseline didn't run.
+        let comparison = GooseBaselineComparison::new(&request, 10, &baseline, 0);
+        assert_eq!(comparison.requests_per_second_change, None);
+        assert_eq!(baseline_change(comparison.requests_per_second_change), "-");
+    }
 }
@@ -0,0 +1,164 @@
+//! Simulated client network conditions.
+//!
+//! By default every [`GooseUser`] makes requests as quickly as the load generator's own
+//! network allows. A [`GooseNetworkProfile`] assigned to a [`GooseTaskSet`] instead makes
+//! all users running that task set emulate a slower connection, for example a mobile
+//! phone on a 3G network. Each request is delayed by the profile's latency, and request
+//! and response bodies are transferred no faster than the profile's upload and download
+//! bandwidth.
+//!
+//! Because slow users hold on to each request for longer, the same number of users
+//! generates fewer requests per second and more concurrent in-flight requests, as happens
+//! with real slow clients. The delays are included in the response times of these users.
+//!
+//! Users are assigned to task sets by weight (or with
+//! [`GooseTaskSet::set_users`](../goose/struct.GooseTaskSet.html#method.set_users)), so the
+//! portion of users emulating a slow connection is controlled by registering the same
+//! tasks in a second task set with a network profile.
+//!
+//! # Example
+//! ```rust
+//! use goose::network::GooseNetworkProfile;
+//! use goose::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), GooseError> {
+//!     // 1 of every 4 users emulates a slow mobile connection.
+//!     let desktop = taskset!("Desktop")
+//!         .set_weight(3)?
+//!         .register_task(task!(loadtest_index));
+//!     let mobile = taskset!("Mobile")
+//!         .set_network_profile(GooseNetworkProfile::slow_3g())
+//!         .register_task(task!(loadtest_index));
+//!
+//!     Ok(())
+//! }
+//!
+//! async fn loadtest_index(user: &mut GooseUser) -> GooseTaskResult {
+//!     let _goose = user.get("").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`GooseTaskSet`]: ../goose/struct.GooseTaskSet.html
+
+use reqwest::{Request, Response, ResponseBuilderExt};
+use std::time::Duration;
+
+use crate::goose::GooseUser;
+
+/// Network conditions emulated by all users running a
+/// [`GooseTaskSet`](../goose/struct.GooseTaskSet.html).
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct GooseNetworkProfile {
+    /// Latency added to each request.
+    pub latency: Duration,
+    /// Maximum download bandwidth in kilobits per second, or 0 for no limit.
+    pub download_kbps: usize,
+    /// Maximum upload bandwidth in kilobits per second, or 0 for no limit.
+    pub upload_kbps: usize,
+}
+impl GooseNetworkProfile {
+    /// Create a custom network profile. Bandwidth is in kilobits per second, with 0
+    /// meaning no limit.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::network::GooseNetworkProfile;
+    /// use std::time::Duration;
+    ///
+    /// // A congested DSL connection.
+    /// let profile = GooseNetworkProfile::new(Duration::from_millis(80), 2_000, 500);
+    /// ```
+    pub fn new(latency: Duration, download_kbps: usize, upload_kbps: usize) -> Self {
+        GooseNetworkProfile {
+            latency,
+            download_kbps,
+            upload_kbps,
+        }
+    }
+
+    /// A slow 3G mobile connection, matching the Chrome DevTools preset: 2 seconds of
+    /// latency and 400 kbps of bandwidth in each direction.
+    pub fn slow_3g() -> Self {
+        GooseNetworkProfile::new(Duration::from_millis(2_000), 400, 400)
+    }
+
+    /// A fast 3G mobile connection, matching the Chrome DevTools preset: 563 milliseconds
+    /// of latency, 1,440 kbps of download bandwidth and 675 kbps of upload bandwidth.
+    pub fn fast_3g() -> Self {
+        GooseNetworkProfile::new(Duration::from_millis(563), 1_440, 675)
+    }
+}
+
+// How long it takes to transfer the provided number of bytes at the provided bandwidth.
+fn transfer_time(bytes: usize, kbps: usize) -> Duration {
+    if kbps == 0 {
+        Duration::from_millis(0)
+    } else {
+        // One kilobit per second transfers one bit per millisecond.
+        Duration::from_millis((bytes as u64 * 8) / kbps as u64)
+    }
+}
+
+impl GooseUser {
+    // Wait for the latency and the upload of the request body, if this user emulates a
+    // slow network connection.
+    pub(crate) async fn delay_request(&self, request: &Request) {
+        if let Some(network_profile) = self.network_profile.as_ref() {
+            let body_length = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map_or(0, |bytes| bytes.len());
+            let delay =
+                network_profile.latency + transfer_time(body_length, network_profile.upload_kbps);
+            if delay > Duration::from_millis(0) {
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    // Wait for the download of the response body, if this user emulates a slow network
+    // connection with limited download bandwidth. The body is read into memory, and a new
+    // response is returned with the same status, headers and body.
+    pub(crate) async fn delay_response(
+        &self,
+        response: Result<Response, reqwest::Error>,
+    ) -> Result<Response, reqwest::Error> {
+        let download_kbps = match self.network_profile.as_ref() {
+            Some(network_profile) if network_profile.download_kbps > 0 => {
+                network_profile.download_kbps
+            }
+            _ => return response,
+        };
+        let response = response?;
+
+        let mut builder = http::Response::builder()
+            .status(response.status())
+            .version(response.version())
+            .url(response.url().clone());
+        if let Some(headers) = builder.headers_mut() {
+            *headers = response.headers().clone();
+        }
+        let body = response.bytes().await?;
+        tokio::time::sleep(transfer_time(body.len(), download_kbps)).await;
+
+        // The builder was created from a valid response, so this can't fail.
+        Ok(Response::from(builder.body(body).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn network_transfer_time() {
+        // No limit.
+        assert_eq!(transfer_time(1_000_000, 0), Duration::from_millis(0));
+        // 50,000 bytes is 400 kilobits, taking a second at 400 kbps.
+        assert_eq!(transfer_time(50_000, 400), Duration::from_millis(1_000));
+        assert_eq!(transfer_time(50_000, 1_600), Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,397 @@
+//! Optional NTLM and Negotiate authentication.
+//!
+//! Enabled with the `ntlm` compile-time feature. Services hosted on IIS often require
+//! NTLM or Negotiate (SPNEGO) authentication, which reqwest doesn't support. Once
+//! credentials are set with [`GooseUser::set_ntlm_credentials`], each request that is
+//! challenged with a `401 Unauthorized` response offering `NTLM` or `Negotiate`
+//! authentication is retried, completing the NTLMv2 handshake on the same connection.
+//! Negotiate authentication is completed with NTLM tokens, Kerberos isn't supported.
+//!
+//! NTLM authenticates connections rather than requests, so further requests reusing an
+//! authenticated connection aren't challenged again. The response time of a challenged
+//! request includes the whole handshake, and only the final response is returned and
+//! included in the metrics. Requests with a streaming body can't be retried, and return
+//! the challenge.
+
+use rand::Rng;
+use reqwest::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use reqwest::{Client, Request, Response, StatusCode};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::goose::GooseUser;
+use crate::md::{hmac_md5, md4};
+
+/// The signature that starts every NTLM message.
+const SIGNATURE: &[u8] = b"NTLMSSP\0";
+
+/// Negotiate flags requested by the client: Unicode strings, the target name and
+/// information, NTLM with extended session security, and 56 and 128 bit encryption.
+const NEGOTIATE_FLAGS: u32 = 0xa088_8205;
+
+/// The target information attribute holding the server time.
+const MSV_AV_TIMESTAMP: u16 = 7;
+
+/// The number of 100 nanosecond intervals between 1601-01-01 and 1970-01-01.
+const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+
+/// Credentials used to authenticate with NTLM.
+#[derive(Clone, Debug)]
+pub(crate) struct NtlmCredentials {
+    domain: String,
+    username: String,
+    password: String,
+}
+
+// Encode a string as UTF-16LE, as used by NTLM messages.
+fn unicode(value: &str) -> Vec<u8> {
+    value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()
+}
+
+// Read a little-endian u16 at an offset.
+fn read_u16(message: &[u8], offset: usize) -> Option<u16> {
+    let bytes = message.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+// Read a little-endian u32 at an offset.
+fn read_u32(message: &[u8], offset: usize) -> Option<u32> {
+    let bytes = message.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// The parts of an NTLM challenge message needed to respond.
+#[derive(Debug, PartialEq)]
+struct Challenge {
+    flags: u32,
+    server_challenge: [u8; 8],
+    target_info: Vec<u8>,
+}
+
+// Build the negotiate message that starts the handshake.
+fn negotiate_message() -> Vec<u8> {
+    let mut message = SIGNATURE.to_vec();
+    message.extend_from_slice(&1u32.to_le_bytes());
+    message.extend_from_slice(&NEGOTIATE_FLAGS.to_le_bytes());
+    // Empty domain and workstation fields.
+    message.extend_from_slice(&[0; 16]);
+    message
+}
+
+// Parse the challenge message sent by the server.
+fn parse_challenge(message: &[u8]) -> Option<Challenge> {
+    if !message.starts_with(SIGNATURE) || read_u32(message, 8)? != 2 {
+        return None;
+    }
+    let flags = read_u32(message, 20)?;
+    let mut server_challenge = [0; 8];
+    server_challenge.copy_from_slice(message.get(24..32)?);
+    let target_info = match (read_u16(message, 40), read_u32(message, 44)) {
+        (Some(length), Some(offset)) if length > 0 => message
+            .get(offset as usize..offset as usize + length as usize)?
+            .to_vec(),
+        _ => Vec::new(),
+    };
+    Some(Challenge {
+        flags,
+        server_challenge,
+        target_info,
+    })
+}
+
+// Find the server time in the target information, if included.
+fn timestamp(target_info: &[u8]) -> Option<[u8; 8]> {
+    let mut offset = 0;
+    while let (Some(id), Some(length)) = (
+        read_u16(target_info, offset),
+        read_u16(target_info, offset + 2),
+    ) {
+        let value = target_info.get(offset + 4..offset + 4 + length as usize)?;
+        if id == MSV_AV_TIMESTAMP && length == 8 {
+            let mut time = [0; 8];
+            time.copy_from_slice(value);
+            return Some(time);
+        } else if id == 0 {
+            break;
+        }
+        offset += 4 + length as usize;
+    }
+    None
+}
+
+// The current time, as the number of 100 nanosecond intervals since 1601-01-01.
+fn now() -> [u8; 8] {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64 / 100)
+        .unwrap_or(0);
+    (FILETIME_UNIX_EPOCH + elapsed).to_le_bytes()
+}
+
+impl NtlmCredentials {
+    // The NTLMv2 response key, derived from the password, username and domain.
+    fn response_key(&self) -> [u8; 16] {
+        let password_hash = md4(&unicode(&self.password));
+        let identity = format!("{}{}", self.username.to_uppercase(), self.domain);
+        hmac_md5(&password_hash, &unicode(&identity))
+    }
+
+    // Calculate the LMv2 and NTLMv2 responses to a challenge.
+    fn responses(
+        &self,
+        challenge: &Challenge,
+        client_challenge: [u8; 8],
+        time: [u8; 8],
+    ) -> (Vec<u8>, Vec<u8>) {
+        let key = self.response_key();
+
+        let mut lm_response = challenge.server_challenge.to_vec();
+        lm_response.extend_from_slice(&client_challenge);
+        let mut lm_response = hmac_md5(&key, &lm_response).to_vec();
+        lm_response.extend_from_slice(&client_challenge);
+
+        let mut blob = vec![1, 1, 0, 0, 0, 0, 0, 0];
+        blob.extend_from_slice(&time);
+        blob.extend_from_slice(&client_challenge);
+        blob.extend_from_slice(&[0; 4]);
+        blob.extend_from_slice(&challenge.target_info);
+        blob.extend_from_slice(&[0; 4]);
+        let mut nt_proof = challenge.server_challenge.to_vec();
+        nt_proof.extend_from_slice(&blob);
+        let mut nt_response = hmac_md5(&key, &nt_proof).to_vec();
+        nt_response.extend_from_slice(&blob);
+
+        (lm_response, nt_response)
+    }
+
+    // Build the authenticate message responding to a challenge.
+    fn authenticate_message(&self, challenge: &Challenge, client_challenge: [u8; 8]) -> Vec<u8> {
+        // Use the server time if provided, in which case the LMv2 response is omitted.
+        let (time, server_time) = match timestamp(&challenge.target_info) {
+            Some(time) => (time, true),
+            None => (now(), false),
+        };
+        let (lm_response, nt_response) = self.responses(challenge, client_challenge, time);
+        let lm_response = if server_time {
+            vec![0; 24]
+        } else {
+            lm_response
+        };
+
+        let fields = [
+            lm_response,
+            nt_response,
+            unicode(&self.domain),
+            unicode(&self.username),
+            // No workstation name or session key.
+            Vec::new(),
+            Vec::new(),
+        ];
+        let mut message = SIGNATURE.to_vec();
+        message.extend_from_slice(&3u32.to_le_bytes());
+        let mut payload = Vec::new();
+        let mut offset = 64;
+        for field in &fields {
+            message.extend_from_slice(&(field.len() as u16).to_le_bytes());
+            message.extend_from_slice(&(field.len() as u16).to_le_bytes());
+            message.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += field.len();
+            payload.extend_from_slice(field);
+        }
+        message.extend_from_slice(&(challenge.flags & NEGOTIATE_FLAGS).to_le_bytes());
+        message.extend_from_slice(&payload);
+        message
+    }
+}
+
+// Find the NTLM or Negotiate challenge in a response, returning the scheme and token.
+fn challenge_header(response: &Response) -> Option<(String, Option<Vec<u8>>)> {
+    response
+        .headers()
+        .get_all(WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| {
+            let mut parts = value.trim().splitn(2, ' ');
+            let scheme = parts.next()?;
+            if !scheme.eq_ignore_ascii_case("NTLM") && !scheme.eq_ignore_ascii_case("Negotiate") {
+                return None;
+            }
+            let token = parts
+                .next()
+                .and_then(|token| base64::decode(token.trim()).ok());
+            Some((scheme.to_string(), token))
+        })
+        // Prefer NTLM when both schemes are offered.
+        .min_by_key(|(scheme, _)| !scheme.eq_ignore_ascii_case("NTLM"))
+}
+
+// Send a copy of a request with an authorization header.
+async fn send(
+    client: &Client,
+    request: &Request,
+    scheme: &str,
+    message: &[u8],
+) -> Option<Result<Response, reqwest::Error>> {
+    let mut request = request.try_clone()?;
+    let value = format!("{} {}", scheme, base64::encode(message));
+    request
+        .headers_mut()
+        .insert(AUTHORIZATION, value.parse().ok()?);
+    Some(client.execute(request).await)
+}
+
+impl GooseUser {
+    /// Authenticate requests challenged with NTLM or Negotiate authentication.
+    ///
+    /// Requires the `ntlm` compile-time feature. Each request that receives a
+    /// `401 Unauthorized` response offering `NTLM` or `Negotiate` authentication is
+    /// retried, completing the NTLMv2 handshake with these credentials. Kerberos isn't
+    /// supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(login).set_on_start();
+    ///
+    /// /// Authenticate all requests made by this user as EXAMPLE\loadtest.
+    /// async fn login(user: &mut GooseUser) -> GooseTaskResult {
+    ///     user.set_ntlm_credentials("EXAMPLE", "loadtest", "secret");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_ntlm_credentials(&mut self, domain: &str, username: &str, password: &str) {
+        self.ntlm = Some(NtlmCredentials {
+            domain: domain.to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
+        });
+    }
+
+    // If challenged, complete the NTLM handshake and return the authenticated response.
+    // Otherwise, or if the request can't be retried, return the original response.
+    pub(crate) async fn ntlm_authenticate(
+        &self,
+        client: &Client,
+        request: Option<Request>,
+        response: Result<Response, reqwest::Error>,
+    ) -> Result<Response, reqwest::Error> {
+        let (credentials, request) = match (self.ntlm.as_ref(), request) {
+            (Some(credentials), Some(request)) => (credentials, request),
+            _ => return response,
+        };
+        let response = response?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let scheme = match challenge_header(&response) {
+            Some((scheme, _)) => scheme,
+            None => return Ok(response),
+        };
+
+        // Read the body so the connection can be reused for the handshake.
+        let _ = response.bytes().await;
+        let challenged = match send(client, &request, &scheme, &negotiate_message()).await {
+            Some(challenged) => challenged?,
+            None => return client.execute(request).await,
+        };
+        let challenge = match challenge_header(&challenged)
+            .and_then(|(_, token)| token)
+            .and_then(|token| parse_challenge(&token))
+        {
+            Some(challenge) => challenge,
+            None => {
+                debug!("invalid NTLM challenge from {}", request.url());
+                return Ok(challenged);
+            }
+        };
+
+        let _ = challenged.bytes().await;
+        let message = credentials.authenticate_message(&challenge, rand::thread_rng().gen());
+        match send(client, &request, &scheme, &message).await {
+            Some(authenticated) => authenticated,
+            None => client.execute(request).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::md::hex;
+
+    // Credentials and challenge from the NTLMv2 example in MS-NLMP section 4.2.4.
+    fn example() -> (NtlmCredentials, Challenge) {
+        let credentials = NtlmCredentials {
+            domain: "Domain".to_string(),
+            username: "User".to_string(),
+            password: "Password".to_string(),
+        };
+        let mut target_info = vec![2, 0, 12, 0];
+        target_info.extend_from_slice(&unicode("Domain"));
+        target_info.extend_from_slice(&[1, 0, 12, 0]);
+        target_info.extend_from_slice(&unicode("Server"));
+        target_info.extend_from_slice(&[0, 0, 0, 0]);
+        let challenge = Challenge {
+            flags: 0xe28a_8233,
+            server_challenge: [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+            target_info,
+        };
+        (credentials, challenge)
+    }
+
+    #[test]
+    fn ntlmv2_responses() {
+        let (credentials, challenge) = example();
+        assert_eq!(
+            hex(&credentials.response_key()),
+            "0c868a403bfd7a93a3001ef22ef02e3f"
+        );
+
+        let (lm_response, nt_response) = credentials.responses(&challenge, [0xaa; 8], [0; 8]);
+        assert_eq!(
+            hex(&lm_response),
+            "86c35097ac9cec102554764a57cccc19aaaaaaaaaaaaaaaa"
+        );
+        assert_eq!(hex(&nt_response[..16]), "68cd0ab851e51c96aabc927bebef6a1c");
+        assert_eq!(nt_response.len(), 16 + 28 + challenge.target_info.len() + 4);
+    }
+
+    #[test]
+    fn messages() {
+        let (credentials, challenge) = example();
+
+        // The negotiate message has no payload.
+        let negotiate = negotiate_message();
+        assert_eq!(negotiate.len(), 32);
+        assert!(negotiate.starts_with(SIGNATURE));
+
+        // A challenge message is parsed back into its parts.
+        let mut message = SIGNATURE.to_vec();
+        message.extend_from_slice(&2u32.to_le_bytes());
+        message.extend_from_slice(&[0, 0, 0, 0, 56, 0, 0, 0]);
+        message.extend_from_slice(&challenge.flags.to_le_bytes());
+        message.extend_from_slice(&challenge.server_challenge);
+        message.extend_from_slice(&[0; 8]);
+        let length = challenge.target_info.len() as u16;
+        message.extend_from_slice(&length.to_le_bytes());
+        message.extend_from_slice(&length.to_le_bytes());
+        message.extend_from_slice(&56u32.to_le_bytes());
+        message.extend_from_slice(&[0; 8]);
+        message.extend_from_slice(&challenge.target_info);
+        assert_eq!(parse_challenge(&message), Some(challenge));
+        assert_eq!(parse_challenge(&negotiate), None);
+
+        // The authenticate message includes the domain and username.
+        let (_, challenge) = example();
+        let authenticate = credentials.authenticate_message(&challenge, [0xaa; 8]);
+        assert_eq!(read_u32(&authenticate, 8), Some(3));
+        let domain_length = read_u16(&authenticate, 28).unwrap() as usize;
+        let domain_offset = read_u32(&authenticate, 32).unwrap() as usize;
+        assert_eq!(
+            &authenticate[domain_offset..domain_offset + domain_length],
+            unicode("Domain").as_slice()
+        );
+    }
+}
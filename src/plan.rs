@@ -0,0 +1,214 @@
+//! Optional machine-readable plan of how users and tasks are allocated.
+//!
+//! Before starting a load test, Goose allocates users to task sets by weight (or by the
+//! fixed number of users set with `GooseTaskSet::set_users`), orders the tasks each user
+//! runs by weight, sequence and scheduler, and determines when each user is launched from
+//! the hatch rate or startup time. With the `--plan-file NAME` run-time option (or
+//! `GooseDefault::PlanFile`) this allocation is written to a JSON file before any user is
+//! launched, so orchestration tooling and reviewers can confirm that the traffic model
+//! matches what was intended.
+//!
+//! The plan includes:
+//!  - `users`: the total number of users
+//!  - `hatch_rate`: the global number of users launched per second
+//!  - `task_sets`: each task set, how many users run it, and the weighted order in which
+//!    its `on_start`, regular and `on_stop` tasks are run by each of its users
+//!  - `hatch_schedule`: the order in which users are launched, which task set each runs,
+//!    and how many milliseconds after the load test starts each is launched
+//!
+//! The hatch schedule is a plan: users are launched as close to it as possible, but on a
+//! busy load generator they can be launched slightly later.
+
+use serde::Serialize;
+
+use crate::goose::{self, GooseTaskSet};
+use crate::util;
+use crate::{AttackMode, GooseAttack, GooseError, WeightedGooseTasks};
+
+/// A task run by users, in the order defined by its weight and sequence.
+#[derive(Debug, Serialize)]
+struct GoosePlanTask {
+    /// The index of the task within its task set.
+    index: usize,
+    /// The name of the task, which can be empty.
+    name: String,
+}
+
+/// The users and weighted tasks allocated to a task set.
+#[derive(Debug, Serialize)]
+struct GoosePlanTaskSet {
+    /// The index of the task set.
+    index: usize,
+    /// The name of the task set.
+    name: String,
+    /// The weight of the task set.
+    weight: usize,
+    /// The number of users fixed with `GooseTaskSet::set_users`, if any.
+    fixed_users: Option<usize>,
+    /// The total number of users that run this task set.
+    users: usize,
+    /// The host this task set is load testing.
+    host: String,
+    /// The task set's own hatch rate, if any.
+    hatch_rate: Option<String>,
+    /// The `on_start` tasks run once by each user, in order.
+    on_start_tasks: Vec<GoosePlanTask>,
+    /// The tasks run repeatedly by each user, in order.
+    tasks: Vec<GoosePlanTask>,
+    /// The `on_stop` tasks run once by each user, in order.
+    on_stop_tasks: Vec<GoosePlanTask>,
+}
+
+/// When a user is launched.
+#[derive(Debug, Serialize)]
+struct GoosePlanUser {
+    /// The order in which the user is launched, starting with 0.
+    user: usize,
+    /// The index of the task set run by the user.
+    task_set: usize,
+    /// How many milliseconds after the load test starts the user is launched.
+    start_ms: usize,
+}
+
+/// The full allocation of users and tasks.
+#[derive(Debug, Serialize)]
+struct GoosePlan {
+    /// The total number of users.
+    users: usize,
+    /// The global number of users launched per second.
+    hatch_rate: f32,
+    /// The users and weighted tasks allocated to each task set.
+    task_sets: Vec<GoosePlanTaskSet>,
+    /// When each user is launched.
+    hatch_schedule: Vec<GoosePlanUser>,
+}
+
+// Convert a weighted list of tasks into the tasks of the plan.
+fn plan_tasks(weighted_tasks: &WeightedGooseTasks) -> Vec<GoosePlanTask> {
+    weighted_tasks
+        .iter()
+        .map(|(index, name)| GoosePlanTask {
+            index: *index,
+            name: name.to_string(),
+        })
+        .collect()
+}
+
+impl GooseAttack {
+    // Build the plan from the users allocated to each task set, listed in the order
+    // `spawn_attack()` launches them.
+    fn build_plan(&self, allocated_users: &[usize]) -> Result<GoosePlan, GooseError> {
+        let hatch_rate = self.hatch_rate();
+
+        let mut task_sets = Vec::with_capacity(self.task_sets.len());
+        for task_set in &self.task_sets {
+            task_sets.push(self.plan_task_set(task_set, allocated_users)?);
+        }
+
+        // Users are launched from the end of the list of allocated users, task sets with
+        // their own hatch rate on their own schedule.
+        let mut hatch_schedule = Vec::with_capacity(allocated_users.len());
+        let mut launched = vec![0; self.task_sets.len()];
+        let mut launched_globally = 0;
+        for task_sets_index in allocated_users.iter().rev() {
+            let start_ms = match self.task_sets[*task_sets_index].hatch_rate.as_ref() {
+                Some(task_set_hatch_rate) => {
+                    let start_ms = launched[*task_sets_index]
+                        * self.spawn_user_in_ms(util::get_hatch_rate(Some(
+                            task_set_hatch_rate.to_string(),
+                        )));
+                    launched[*task_sets_index] += 1;
+                    start_ms
+                }
+                None => {
+                    let start_ms = launched_globally * self.spawn_user_in_ms(hatch_rate);
+                    launched_globally += 1;
+                    start_ms
+                }
+            };
+            hatch_schedule.push(GoosePlanUser {
+                user: 0,
+                task_set: *task_sets_index,
+                start_ms,
+            });
+        }
+        // Sorting is stable, so users launched at the same time stay in launch order.
+        hatch_schedule.sort_by_key(|user| user.start_ms);
+        for (user, planned_user) in hatch_schedule.iter_mut().enumerate() {
+            planned_user.user = user;
+        }
+
+        Ok(GoosePlan {
+            users: allocated_users.len(),
+            hatch_rate,
+            task_sets,
+            hatch_schedule,
+        })
+    }
+
+    // Build the plan of a single task set.
+    fn plan_task_set(
+        &self,
+        task_set: &GooseTaskSet,
+        allocated_users: &[usize],
+    ) -> Result<GoosePlanTaskSet, GooseError> {
+        Ok(GoosePlanTaskSet {
+            index: task_set.task_sets_index,
+            name: task_set.name.to_string(),
+            weight: task_set.weight,
+            fixed_users: task_set.users,
+            users: allocated_users
+                .iter()
+                .filter(|index| **index == task_set.task_sets_index)
+                .count(),
+            host: goose::get_base_url(
+                self.get_configuration_host(),
+                task_set.host.clone(),
+                self.defaults.host.clone(),
+            )?
+            .to_string(),
+            hatch_rate: task_set.hatch_rate.clone(),
+            on_start_tasks: plan_tasks(&task_set.weighted_on_start_tasks),
+            tasks: plan_tasks(&task_set.weighted_tasks),
+            on_stop_tasks: plan_tasks(&task_set.weighted_on_stop_tasks),
+        })
+    }
+
+    // Write the allocation plan to the plan file, if enabled.
+    pub(crate) fn write_plan_file(&self) -> Result<(), GooseError> {
+        if self.configuration.plan_file.is_empty() {
+            return Ok(());
+        }
+
+        // Determine which task set each allocated user runs.
+        let allocated_users: Vec<usize> = match self.attack_mode {
+            AttackMode::Manager => self
+                .weighted_gaggle_users
+                .iter()
+                .map(|user| user.task_sets_index)
+                .collect(),
+            _ => self
+                .weighted_users
+                .iter()
+                .map(|user| user.task_sets_index)
+                .collect(),
+        };
+
+        let plan = self.build_plan(&allocated_users)?;
+        // Serializing the plan can't fail, it only contains strings and numbers.
+        let json = serde_json::to_string_pretty(&plan).expect("unexpected serde failure");
+        if let Err(e) = std::fs::write(&self.configuration.plan_file, json) {
+            return Err(GooseError::InvalidOption {
+                option: "--plan-file".to_string(),
+                value: self.configuration.plan_file.to_string(),
+                detail: format!("Failed to write plan file: {}", e),
+            });
+        }
+        info!(
+            "wrote allocation plan file to: {}",
+            self.configuration.plan_file
+        );
+
+        Ok(())
+    }
+}
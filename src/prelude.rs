@@ -7,10 +7,12 @@
 //! use goose::prelude::*;
 //! ```
 
-pub use crate::config::{GooseDefault, GooseDefaultType};
+pub use crate::config::{GooseDefault, GooseDefaultType, GooseSettings};
 pub use crate::goose::{
     GooseMethod, GooseRequest, GooseTask, GooseTaskError, GooseTaskFunction, GooseTaskResult,
     GooseTaskSet, GooseUser,
 };
-pub use crate::metrics::{GooseCoordinatedOmissionMitigation, GooseMetrics};
+pub use crate::metrics::{GooseCoordinatedOmissionMitigation, GooseMetrics, GooseOutput};
+pub use crate::socket::{GooseSocketMethod, GooseSocketRequest};
+pub use crate::template::{GooseTemplate, GooseTemplateError, GooseTemplateEscape};
 pub use crate::{task, taskset, GooseAttack, GooseError, GooseScheduler};
@@ -0,0 +1,261 @@
+//! Optional endpoint serving live metrics to Prometheus.
+//!
+//! A load test that runs for a long time is easier to follow on the same dashboards as the
+//! servers being load tested. With the `--prometheus-port PORT` run-time option (or
+//! `GooseDefault::PrometheusPort`) Goose serves the metrics of the running load test on
+//! `http://0.0.0.0:PORT/metrics`, in the Prometheus text format, so Prometheus can scrape
+//! them for Grafana dashboards. The served metrics are refreshed about once per second, and
+//! like all other metrics are reset once all users have launched unless `--no-reset-metrics`
+//! is set.
+//!
+//! The following metrics are served:
+//!  - `goose_users`: how many users have been launched
+//!  - `goose_duration_seconds`: how long the load test has been running
+//!  - `goose_requests_total` and `goose_request_failures_total`: how many requests were
+//!    made and how many failed, labeled with the `method` and `name` of the request
+//!  - `goose_response_time_milliseconds`: a histogram of response times, labeled with the
+//!    `method` and `name` of the request
+//!  - `goose_errors_total`: how many times each error occurred, labeled with the `method`
+//!    and `name` of the request and the `error`
+//!  - `goose_task_runs_total` and `goose_task_failures_total`: how many times each task ran
+//!    and how many times it failed, labeled with the `taskset` and the `task`
+
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::metrics::GooseMetrics;
+use crate::GooseError;
+
+/// The upper bounds of the response time histogram buckets, in milliseconds.
+const RESPONSE_TIME_BUCKETS: &[usize] = &[10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+/// The most recently rendered metrics, shared by the parent process with the endpoint.
+pub(crate) type GoosePrometheusMetrics = Arc<Mutex<String>>;
+
+/// Binds to the configured port, and spawns a thread serving the most recently rendered
+/// metrics until aborted.
+pub(crate) async fn spawn_prometheus(
+    port: u16,
+) -> Result<(GoosePrometheusMetrics, tokio::task::JoinHandle<()>), GooseError> {
+    let address = format!("0.0.0.0:{}", port);
+    let listener = TcpListener::bind(&address).await?;
+    info!("serving Prometheus metrics on: http://{}/metrics", address);
+
+    let metrics = GoosePrometheusMetrics::default();
+    let handle = tokio::spawn(prometheus_main(listener, metrics.clone()));
+
+    Ok((metrics, handle))
+}
+
+/// Accepts connections from Prometheus, responding to each independently.
+async fn prometheus_main(listener: TcpListener, metrics: GoosePrometheusMetrics) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(respond(stream, metrics.clone()));
+            }
+            Err(e) => info!("failed to accept Prometheus connection: {}", e),
+        }
+    }
+}
+
+/// Responds to a single request with the most recently rendered metrics, then closes
+/// the connection.
+async fn respond(stream: TcpStream, metrics: GoosePrometheusMetrics) {
+    let mut stream = BufReader::new(stream);
+    let mut line = String::new();
+    if stream.read_line(&mut line).await.unwrap_or(0) == 0 {
+        return;
+    }
+    let path = line.split_whitespace().nth(1).unwrap_or("").to_string();
+
+    // Read and ignore the request headers.
+    loop {
+        let mut header = String::new();
+        if stream.read_line(&mut header).await.unwrap_or(0) == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let response = if path.split('?').next() == Some("/metrics") {
+        let body = metrics.lock().unwrap().clone();
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/plain; version=0.0.4\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n".to_string()
+    };
+    let _ = stream.get_mut().write_all(response.as_bytes()).await;
+}
+
+/// Renders the metrics in the Prometheus text format.
+pub(crate) fn render(metrics: &GooseMetrics) -> String {
+    let mut rendered = String::new();
+
+    write_header(&mut rendered, "goose_users", "gauge", "Users launched.");
+    let _ = writeln!(rendered, "goose_users {}", metrics.users);
+    write_header(
+        &mut rendered,
+        "goose_duration_seconds",
+        "gauge",
+        "Seconds the load test has been running.",
+    );
+    let _ = writeln!(rendered, "goose_duration_seconds {}", metrics.duration);
+
+    // Sort requests so they are always served in the same order.
+    let mut requests: Vec<_> = metrics.requests.values().collect();
+    requests.sort_by(|a, b| (&a.path, a.method_name()).cmp(&(&b.path, b.method_name())));
+    let labels: Vec<String> = requests
+        .iter()
+        .map(|request| {
+            format!(
+                "method=\"{}\",name=\"{}\"",
+                request.method_name(),
+                escape(&request.path)
+            )
+        })
+        .collect();
+
+    write_header(
+        &mut rendered,
+        "goose_requests_total",
+        "counter",
+        "Requests made.",
+    );
+    for (request, labels) in requests.iter().zip(&labels) {
+        let _ = writeln!(
+            rendered,
+            "goose_requests_total{{{}}} {}",
+            labels,
+            request.success_count + request.fail_count
+        );
+    }
+    write_header(
+        &mut rendered,
+        "goose_request_failures_total",
+        "counter",
+        "Requests that failed.",
+    );
+    for (request, labels) in requests.iter().zip(&labels) {
+        let _ = writeln!(
+            rendered,
+            "goose_request_failures_total{{{}}} {}",
+            labels, request.fail_count
+        );
+    }
+    write_header(
+        &mut rendered,
+        "goose_response_time_milliseconds",
+        "histogram",
+        "Response times in milliseconds.",
+    );
+    for (request, labels) in requests.iter().zip(&labels) {
+        let times = &request.raw_data;
+        // Response times aren't kept with `--no-histograms`, only their sum and count.
+        if !times.times.is_empty() {
+            for bucket in RESPONSE_TIME_BUCKETS {
+                let count: usize = times.times.range(..=*bucket).map(|(_, count)| count).sum();
+                let _ = writeln!(
+                    rendered,
+                    "goose_response_time_milliseconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bucket, count
+                );
+            }
+        }
+        let _ = writeln!(
+            rendered,
+            "goose_response_time_milliseconds_bucket{{{},le=\"+Inf\"}} {}",
+            labels, times.counter
+        );
+        let _ = writeln!(
+            rendered,
+            "goose_response_time_milliseconds_sum{{{}}} {}",
+            labels, times.total_time
+        );
+        let _ = writeln!(
+            rendered,
+            "goose_response_time_milliseconds_count{{{}}} {}",
+            labels, times.counter
+        );
+    }
+
+    write_header(
+        &mut rendered,
+        "goose_errors_total",
+        "counter",
+        "Times each error occurred.",
+    );
+    for error in metrics.errors.values() {
+        let _ = writeln!(
+            rendered,
+            "goose_errors_total{{method=\"{}\",name=\"{}\",error=\"{}\"}} {}",
+            error.method_name(),
+            escape(&error.name),
+            escape(&error.error),
+            error.occurrences
+        );
+    }
+
+    write_header(
+        &mut rendered,
+        "goose_task_runs_total",
+        "counter",
+        "Times each task ran.",
+    );
+    for task in metrics.tasks.iter().flatten() {
+        let _ = writeln!(
+            rendered,
+            "goose_task_runs_total{{taskset=\"{}\",task=\"{}\"}} {}",
+            escape(&task.taskset_name),
+            escape(&task.task_name),
+            task.success_count + task.fail_count
+        );
+    }
+    write_header(
+        &mut rendered,
+        "goose_task_failures_total",
+        "counter",
+        "Times each task failed.",
+    );
+    for task in metrics.tasks.iter().flatten() {
+        let _ = writeln!(
+            rendered,
+            "goose_task_failures_total{{taskset=\"{}\",task=\"{}\"}} {}",
+            escape(&task.taskset_name),
+            escape(&task.task_name),
+            task.fail_count
+        );
+    }
+
+    rendered
+}
+
+/// Writes the help and type comments describing a metric.
+fn write_header(rendered: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(rendered, "# HELP {} {}", name, help);
+    let _ = writeln!(rendered, "# TYPE {} {}", name, kind);
+}
+
+/// Escapes a label value as required by the Prometheus text format.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_label() {
+        assert_eq!(escape("/"), "/");
+        assert_eq!(escape("say \"hi\"\n"), "say \\\"hi\\\"\\n");
+        assert_eq!(escape("C:\\"), "C:\\\\");
+    }
+}
@@ -0,0 +1,290 @@
+//! Reproducible randomness for load tests.
+//!
+//! Each [`GooseUser`] has its own random number generator, which tasks can use through
+//! the helpers defined here to make weighted choices and to generate random data, without
+//! importing and wiring up the [`rand`](https://docs.rs/rand) crate themselves.
+//!
+//! By default each random number generator is seeded from system entropy, so every load
+//! test run is different. When the `--seed` run-time option (or
+//! [`GooseDefault::Seed`](../config/enum.GooseDefault.html#variant.Seed)) is set, each
+//! user's random number generator is instead seeded from that value and the user's index,
+//! so every run of the load test makes the same random choices in the same order on each
+//! user. The seed also fixes the random pauses between tasks configured with
+//! [`GooseTaskSet::set_wait_time`](../goose/struct.GooseTaskSet.html#method.set_wait_time),
+//! and the order in which the
+//! [`GooseScheduler::Random`](../enum.GooseScheduler.html#variant.Random) scheduler
+//! allocates task sets to users and tasks to task sets.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//!
+//! let mut a_task = task!(search);
+//!
+//! /// Search for a random term, preferring popular terms.
+//! async fn search(user: &mut GooseUser) -> GooseTaskResult {
+//!     let term = *user
+//!         .random_weighted_choice(&[("goose", 10), ("duck", 3), ("swan", 1)])
+//!         .unwrap();
+//!     let page = user.random_number(1..10);
+//!
+//!     let _goose = user.get(&format!("search?q={}&page={}", term, page)).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use rand::distributions::uniform::{SampleRange, SampleUniform};
+use rand::distributions::{Alphanumeric, Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::goose::GooseUser;
+
+/// Build the random number generator for a [`GooseUser`]. If a seed is configured, the
+/// generator is derived from the seed and the user's index so each user makes different
+/// but reproducible choices, otherwise it is seeded from system entropy.
+pub(crate) fn user_rng(seed: Option<usize>, weighted_users_index: usize) -> StdRng {
+    match seed {
+        // Spread user indexes across the seed space so neighboring seeds don't share
+        // user streams.
+        Some(seed) => StdRng::seed_from_u64(
+            (seed as u64)
+                .wrapping_add((weighted_users_index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)),
+        ),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Build the random number generator used by the
+/// [`GooseScheduler::Random`](../enum.GooseScheduler.html#variant.Random) scheduler. If a
+/// seed is configured, task sets and tasks are allocated in the same order every run,
+/// otherwise it is seeded from system entropy.
+pub(crate) fn scheduler_rng(seed: Option<usize>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed as u64),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Generate a random alphanumeric string of the provided length.
+pub(crate) fn alphanumeric<R: Rng + ?Sized>(rng: &mut R, length: usize) -> String {
+    rng.sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+/// Generate a random version 4 UUID, formatted as a lowercase hyphenated string.
+pub(crate) fn uuid<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let mut bytes: [u8; 16] = rng.gen();
+    // Set the version (4) and variant (RFC 4122) bits.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+impl GooseUser {
+    /// Returns this user's random number generator, for generating random values not
+    /// covered by the other helpers. The generator implements
+    /// [`rand::Rng`](https://docs.rs/rand/*/rand/trait.Rng.html).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use rand::Rng;
+    ///
+    /// let mut a_task = task!(coin_toss);
+    ///
+    /// /// Load the heads page half of the time.
+    /// async fn coin_toss(user: &mut GooseUser) -> GooseTaskResult {
+    ///     if user.rng().gen_bool(0.5) {
+    ///         let _goose = user.get("heads").await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Chooses one of the provided choices at random, in proportion to its weight. Returns
+    /// [`None`] if there are no choices, or if all weights are 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(load_category);
+    ///
+    /// /// Load "books" 3 times as often as "music".
+    /// async fn load_category(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let category = *user
+    ///         .random_weighted_choice(&[("books", 3), ("music", 1)])
+    ///         .unwrap();
+    ///     let _goose = user.get(category).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn random_weighted_choice<'a, T>(&mut self, choices: &'a [(T, usize)]) -> Option<&'a T> {
+        let weights = WeightedIndex::new(choices.iter().map(|(_, weight)| *weight)).ok()?;
+        Some(&choices[weights.sample(&mut self.rng)].0)
+    }
+
+    /// Returns a random number in the provided range, which can be exclusive (`1..10`)
+    /// or inclusive (`1..=10`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(load_product);
+    ///
+    /// /// Load a random product.
+    /// async fn load_product(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let product = user.random_number(1..=1000);
+    ///     let _goose = user.get(&format!("product/{}", product)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn random_number<T: SampleUniform, R: SampleRange<T>>(&mut self, range: R) -> T {
+        self.rng.gen_range(range)
+    }
+
+    /// Returns a random alphanumeric string of the provided length.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(register);
+    ///
+    /// /// Register a new account with a random username.
+    /// async fn register(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let username = user.random_string(12);
+    ///     let _goose = user.post("register", format!("username={}", username)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn random_string(&mut self, length: usize) -> String {
+        alphanumeric(&mut self.rng, length)
+    }
+
+    /// Returns a random version 4 UUID, formatted as a lowercase hyphenated string.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(load_order);
+    ///
+    /// /// Load an order with a random id.
+    /// async fn load_order(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let order_id = user.random_uuid();
+    ///     let _goose = user.get(&format!("order/{}", order_id)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn random_uuid(&mut self) -> String {
+        uuid(&mut self.rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::config::GooseConfiguration;
+    use gumdrop::Options;
+    use rand::seq::SliceRandom;
+
+    fn setup_user(seed: Option<usize>, weighted_users_index: usize) -> GooseUser {
+        let configuration = GooseConfiguration::parse_args_default::<&str>(&[]).unwrap();
+        let base_url = url::Url::parse("http://127.0.0.1:5000").unwrap();
+        let mut user = GooseUser::single(base_url, &configuration).unwrap();
+        user.rng = user_rng(seed, weighted_users_index);
+        user
+    }
+
+    #[test]
+    fn seeded_users_are_reproducible() {
+        let mut user = setup_user(Some(42), 0);
+        let mut same_user = setup_user(Some(42), 0);
+        let mut other_user = setup_user(Some(42), 1);
+
+        let values: Vec<u32> = (0..10).map(|_| user.random_number(0..u32::MAX)).collect();
+        let same_values: Vec<u32> = (0..10)
+            .map(|_| same_user.random_number(0..u32::MAX))
+            .collect();
+        let other_values: Vec<u32> = (0..10)
+            .map(|_| other_user.random_number(0..u32::MAX))
+            .collect();
+
+        // The same seed and user index generate the same values.
+        assert_eq!(values, same_values);
+        // Different users generate different values.
+        assert_ne!(values, other_values);
+    }
+
+    #[test]
+    fn seeded_scheduler_is_reproducible() {
+        let allocated: Vec<usize> = (0..20).collect();
+        let mut order = allocated.clone();
+        let mut same_order = allocated.clone();
+        order.shuffle(&mut scheduler_rng(Some(42)));
+        same_order.shuffle(&mut scheduler_rng(Some(42)));
+
+        // The same seed allocates in the same random order.
+        assert_eq!(order, same_order);
+        assert_ne!(order, allocated);
+    }
+
+    #[test]
+    fn random_weighted_choice() {
+        let mut user = setup_user(Some(1), 0);
+
+        // Choices with a weight of 0 are never made.
+        let choices = [("never", 0), ("always", 1)];
+        for _ in 0..100 {
+            assert_eq!(user.random_weighted_choice(&choices), Some(&"always"));
+        }
+
+        // There's nothing to choose from.
+        let empty: [(&str, usize); 0] = [];
+        assert_eq!(user.random_weighted_choice(&empty), None);
+        assert_eq!(user.random_weighted_choice(&[("never", 0)]), None);
+    }
+
+    #[test]
+    fn random_data() {
+        let mut user = setup_user(None, 0);
+
+        let number = user.random_number(5..=6);
+        assert!(number == 5 || number == 6);
+
+        let string = user.random_string(16);
+        assert_eq!(string.len(), 16);
+        assert!(string.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        let uuid = user.random_uuid();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(uuid.matches('-').count(), 4);
+        // Version 4.
+        assert_eq!(&uuid[14..15], "4");
+        // RFC 4122 variant.
+        assert!(["8", "9", "a", "b"].contains(&&uuid[19..20]));
+    }
+}
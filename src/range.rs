@@ -0,0 +1,277 @@
+//! Range requests and verification of partial content responses.
+//!
+//! Video and download services serve large files in parts, with `Range` requests that
+//! are answered with `206 Partial Content`. A request can ask for part of a response
+//! with [`GooseRequestBuilder::range`], or with the [`GooseUser::get_range`] helper. Goose
+//! then sends the `Range` header, asks for the response uncompressed so byte offsets
+//! refer to the file itself, and verifies each successful (2xx) response before returning
+//! it:
+//!  - the response must be `206 Partial Content`, a server that ignores the range and
+//!    returns the whole file fails,
+//!  - the `Content-Range` header must describe the range that was requested, limited to
+//!    the size of the file if it's known,
+//!  - the body must be exactly as long as the range.
+//!
+//! Responses that fail verification are counted as failed requests and as integrity
+//! failures, the same as responses with an unexpected body checksum or length. Response
+//! times don't include reading the body.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use goose::range::GooseRange;
+//!
+//! let mut a_task = task!(seek_video);
+//!
+//! /// Load the first megabyte of a video, then seek to its last 64 KiB.
+//! async fn seek_video(user: &mut GooseUser) -> GooseTaskResult {
+//!     let _goose = user
+//!         .get_range("videos/intro.mp4", GooseRange::Between(0, 1_048_575))
+//!         .await?;
+//!     let _goose = user
+//!         .get_range("videos/intro.mp4", GooseRange::Last(65_536))
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`GooseRequestBuilder::range`]: ../goose/struct.GooseRequestBuilder.html#method.range
+
+use reqwest::header::CONTENT_RANGE;
+use reqwest::{Response, StatusCode};
+use std::fmt;
+
+use crate::compression::response_builder;
+use crate::goose::{GooseRequest, GooseResponse, GooseTaskError, GooseUser};
+use crate::metrics::GooseRequestMetric;
+
+/// A range of bytes requested with a `Range` header. Offsets start at 0, and the end of
+/// a range is inclusive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GooseRange {
+    /// All bytes from an offset to the end, ie `bytes=1000-`.
+    From(u64),
+    /// The bytes from the first offset to the last offset, inclusive, ie `bytes=0-999`.
+    Between(u64, u64),
+    /// The last bytes, ie `bytes=-500`.
+    Last(u64),
+}
+impl GooseRange {
+    /// The first and last offset of the bytes that should be returned from a file of
+    /// `size` bytes, or `None` if the range isn't satisfiable.
+    fn expected(&self, size: u64) -> Option<(u64, u64)> {
+        match *self {
+            GooseRange::From(first) if first < size => Some((first, size - 1)),
+            GooseRange::Between(first, last) if first <= last && first < size => {
+                Some((first, last.min(size - 1)))
+            }
+            GooseRange::Last(length) if length > 0 && size > 0 => {
+                Some((size - length.min(size), size - 1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a range returned from a file of unknown size could satisfy this range.
+    fn satisfied_by(&self, first: u64, last: u64) -> bool {
+        match *self {
+            GooseRange::From(from) => first == from,
+            GooseRange::Between(from, to) => first == from && last <= to,
+            GooseRange::Last(length) => last - first < length,
+        }
+    }
+}
+/// Formats the range as the value of a `Range` header.
+impl fmt::Display for GooseRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GooseRange::From(first) => write!(f, "bytes={}-", first),
+            GooseRange::Between(first, last) => write!(f, "bytes={}-{}", first, last),
+            GooseRange::Last(length) => write!(f, "bytes=-{}", length),
+        }
+    }
+}
+
+/// Parses a `Content-Range` header such as `bytes 0-999/8000`, returning the first and
+/// last offset, and the size of the file if it's known. Returns `None` if the header
+/// isn't valid.
+fn parse_content_range(content_range: &str) -> Option<(u64, u64, Option<u64>)> {
+    let (range, size) = content_range
+        .trim()
+        .strip_prefix("bytes ")?
+        .split_once('/')?;
+    let (first, last) = range.split_once('-')?;
+    let (first, last) = (first.trim().parse().ok()?, last.trim().parse().ok()?);
+    let size = match size.trim() {
+        "*" => None,
+        size => Some(size.parse().ok()?),
+    };
+    if first > last || size.is_some_and(|size| last >= size) {
+        return None;
+    }
+    Some((first, last, size))
+}
+
+// If a range was requested, read the body of a successful response and verify it's the
+// requested part. A new response is returned with the same status, headers and body.
+pub(crate) async fn verify_partial_content(
+    response: Result<Response, reqwest::Error>,
+    range: Option<GooseRange>,
+    request_metric: &mut GooseRequestMetric,
+) -> Result<Response, reqwest::Error> {
+    let range = match range {
+        Some(range) => range,
+        None => return response,
+    };
+    let response = response?;
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    let content_range = response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let builder = response_builder(&response);
+    let body = response.bytes().await?;
+
+    let error = if status != StatusCode::PARTIAL_CONTENT {
+        debug!(
+            "{}: status {} for range {}",
+            request_metric.name, status, range
+        );
+        Some("range ignored")
+    } else {
+        match content_range.as_deref().and_then(parse_content_range) {
+            None => {
+                debug!(
+                    "{}: invalid content range {:?}",
+                    request_metric.name, content_range
+                );
+                Some("content range invalid")
+            }
+            Some((first, last, size)) => {
+                let satisfied = match size {
+                    Some(size) => range.expected(size) == Some((first, last)),
+                    None => range.satisfied_by(first, last),
+                };
+                if !satisfied {
+                    debug!(
+                        "{}: content range {}-{} for range {}",
+                        request_metric.name, first, last, range
+                    );
+                    Some("content range mismatch")
+                } else if body.len() as u64 != last - first + 1 {
+                    debug!(
+                        "{}: body length {} for content range {}-{}",
+                        request_metric.name,
+                        body.len(),
+                        first,
+                        last
+                    );
+                    Some("partial content length mismatch")
+                } else {
+                    None
+                }
+            }
+        }
+    };
+
+    // Combined with any checksum or length verified for the same response.
+    request_metric.integrity = Some(request_metric.integrity.unwrap_or(true) && error.is_none());
+    if let Some(error) = error {
+        request_metric.success = false;
+        request_metric.error = format!("{}: {}", error, request_metric.name);
+    }
+
+    // The builder was created from a valid response, so this can't fail.
+    Ok(Response::from(builder.body(body).unwrap()))
+}
+
+impl GooseUser {
+    /// A helper to make a `GET` request for part of a path with a `Range` header, and
+    /// collect relevant metrics. Automatically prepends the correct host.
+    ///
+    /// The response is verified to be `206 Partial Content` with the requested range,
+    /// otherwise the request fails. For more details refer to the
+    /// [`range`](../range/index.html) module.
+    ///
+    /// Calls to `get_range()` return a [`GooseResponse`](../goose/struct.GooseResponse.html)
+    /// object. To name the request or verify more of the response, build the request with
+    /// [`GooseRequestBuilder::range`](../goose/struct.GooseRequestBuilder.html#method.range)
+    /// instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use goose::range::GooseRange;
+    ///
+    /// let mut a_task = task!(get_function);
+    ///
+    /// /// A very simple task that loads the first kilobyte of a file.
+    /// async fn get_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.get_range("downloads/release.tar.gz", GooseRange::Between(0, 1023)).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_range(
+        &mut self,
+        path: &str,
+        range: GooseRange,
+    ) -> Result<GooseResponse, GooseTaskError> {
+        let goose_request = GooseRequest::builder().path(path).range(range).build();
+
+        self.request(goose_request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_header() {
+        assert_eq!(GooseRange::From(1000).to_string(), "bytes=1000-");
+        assert_eq!(GooseRange::Between(0, 999).to_string(), "bytes=0-999");
+        assert_eq!(GooseRange::Last(500).to_string(), "bytes=-500");
+    }
+
+    #[test]
+    fn expected_range() {
+        assert_eq!(GooseRange::From(1000).expected(8000), Some((1000, 7999)));
+        assert_eq!(GooseRange::From(8000).expected(8000), None);
+        assert_eq!(GooseRange::Between(0, 999).expected(8000), Some((0, 999)));
+        // The last offset is limited to the size of the file.
+        assert_eq!(GooseRange::Between(0, 9999).expected(8000), Some((0, 7999)));
+        assert_eq!(GooseRange::Between(10, 5).expected(8000), None);
+        assert_eq!(GooseRange::Last(500).expected(8000), Some((7500, 7999)));
+        assert_eq!(GooseRange::Last(9000).expected(8000), Some((0, 7999)));
+        assert_eq!(GooseRange::Last(0).expected(8000), None);
+    }
+
+    #[test]
+    fn content_range() {
+        assert_eq!(
+            parse_content_range("bytes 0-999/8000"),
+            Some((0, 999, Some(8000)))
+        );
+        assert_eq!(
+            parse_content_range("bytes 7500-7999/*"),
+            Some((7500, 7999, None))
+        );
+        assert_eq!(parse_content_range("bytes */8000"), None);
+        assert_eq!(parse_content_range("bytes 999-0/8000"), None);
+        assert_eq!(parse_content_range("bytes 0-8000/8000"), None);
+        assert_eq!(parse_content_range("0-999/8000"), None);
+
+        // Without the size of the file, the range is compared with the request.
+        assert!(GooseRange::Between(0, 999).satisfied_by(0, 499));
+        assert!(!GooseRange::Between(0, 999).satisfied_by(1, 999));
+        assert!(GooseRange::Last(500).satisfied_by(7500, 7999));
+        assert!(!GooseRange::Last(500).satisfied_by(7000, 7999));
+    }
+}
@@ -0,0 +1,753 @@
+//! Optional recording proxy, generating a load test from a browser session.
+//!
+//! A realistic load test makes the same requests as a real user's browser, including the
+//! images, scripts and stylesheets each page loads. With the `--record NAME` run-time
+//! option (or `GooseDefault::Record`) Goose doesn't run a load test, but instead runs an
+//! HTTP proxy on `127.0.0.1`, port 5118 by default (configured with `--record-port` or
+//! `GooseDefault::RecordPort`). Configure a browser to use it as its HTTP proxy and browse
+//! the site to be load tested: each request is forwarded to the site and recorded. When
+//! recording stops, either with ctrl-c or when the `--run-time` expires, the recorded
+//! session is written to `NAME` as the source of a Goose load test.
+//!
+//! The generated load test has one task set, `RecordedSession`, with a task for each page
+//! loaded while recording, run in the order the pages were loaded. A page starts with each
+//! request for an HTML document, and its task also makes the requests made by the browser
+//! until the next page. Requests to the host of the first request are made relative to the
+//! load test's host, which defaults to that host, while requests to other hosts are made
+//! to their full URL.
+//!
+//! Pages loaded more than once, with the same method and normalized path, are merged into a
+//! single task to keep the generated load test manageable. A path is normalized by ignoring
+//! its query string, and treating path segments that look like identifiers (numbers, UUIDs
+//! and long hexadecimal strings) as the same. The merged task replays the requests made the
+//! first time the page was loaded, is run in the order the page was first loaded, and is
+//! weighted by the number of times the page was loaded. The merged pages are reported when
+//! recording stops, and listed in the generated load test.
+//!
+//! Only plain HTTP requests can be recorded: HTTPS requests are tunneled to the site
+//! unchanged, as the proxy can't see inside them, and a warning is logged for each tunneled
+//! connection so the gaps in the generated load test are visible. Requests made with methods
+//! Goose doesn't support, such as `OPTIONS`, are forwarded but not recorded. Request bodies
+//! larger than 16 MiB are rejected rather than buffered.
+
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::redirect::Policy;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use url::{Position, Url};
+
+use crate::goose::GooseMethod;
+use crate::util;
+use crate::{GooseConfiguration, GooseError};
+
+/// Headers that only apply to a single connection, so are not forwarded by the proxy.
+const HOP_BY_HOP_HEADERS: &[&str] = &[
+    "connection",
+    "content-length",
+    "host",
+    "keep-alive",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// The largest request body the proxy buffers, as the browser's `Content-Length` can't be
+/// trusted to fit in memory.
+pub(crate) const MAX_REQUEST_BODY: usize = 16 * 1024 * 1024;
+
+/// A request made by the browser while recording.
+#[derive(Debug)]
+struct GooseRecordedRequest {
+    /// When the proxy received the request, used to order requests made in parallel.
+    received: time::Instant,
+    /// The request method.
+    method: GooseMethod,
+    /// The full URL requested.
+    url: Url,
+    /// Whether the browser requested an HTML document, starting a new page.
+    page: bool,
+    /// The `Content-Type` header of a request with a body.
+    content_type: Option<String>,
+    /// The request body, if any.
+    body: Option<String>,
+}
+
+/// A page loaded while recording, merged with later loads of the same page.
+struct GooseRecordedPage<'a> {
+    /// The requests made the first time the page was loaded, starting with the page itself.
+    requests: Vec<&'a GooseRecordedRequest>,
+    /// The normalized path identifying the page, used to merge later loads.
+    normalized: String,
+    /// The path of each load of the page.
+    loads: Vec<String>,
+}
+
+/// A request read from a browser connection.
+struct GooseProxyRequest {
+    /// The request method, such as `GET`.
+    method: String,
+    /// The request target, a full URL, or `HOST:PORT` for `CONNECT` requests.
+    target: String,
+    /// The request headers, in the order they were received.
+    headers: Vec<(String, String)>,
+}
+impl GooseProxyRequest {
+    /// Returns the value of the first header with this name.
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Runs the recording proxy until ctrl-c is pressed or the run time expires, then writes
+/// the recorded session to the `--record` file as the source of a load test.
+pub(crate) async fn record_main(
+    configuration: &GooseConfiguration,
+    run_time: usize,
+) -> Result<(), GooseError> {
+    let address = format!("127.0.0.1:{}", configuration.record_port);
+    let listener = TcpListener::bind(&address).await?;
+    info!("recording proxy listening on: {}", address);
+
+    // Requests are forwarded as they were made by the browser: redirects and compressed
+    // responses are passed back to the browser to handle.
+    let client = reqwest::Client::builder()
+        .redirect(Policy::none())
+        .no_gzip()
+        .no_proxy()
+        .build()?;
+
+    let (recorded_tx, recorded_rx) = flume::unbounded();
+    let canceled = Arc::new(AtomicBool::new(false));
+    util::setup_ctrlc_handler(&canceled);
+    let started = time::Instant::now();
+    loop {
+        if canceled.load(Ordering::SeqCst) || util::timer_expired(started, run_time) {
+            break;
+        }
+        // Wake up regularly to check if it's time to stop recording.
+        if let Ok(accepted) =
+            tokio::time::timeout(time::Duration::from_millis(100), listener.accept()).await
+        {
+            let (stream, _) = accepted?;
+            // Each connection is proxied independently, until the browser closes it.
+            tokio::spawn(proxy_connection(
+                stream,
+                client.clone(),
+                recorded_tx.clone(),
+            ));
+        }
+    }
+    drop(recorded_tx);
+
+    let mut requests: Vec<GooseRecordedRequest> = recorded_rx.drain().collect();
+    requests.sort_by_key(|request| request.received);
+    let host = requests
+        .first()
+        .map(|request| request.url.origin().ascii_serialization());
+    let pages = recorded_pages(&requests, host.as_deref());
+    print_merged_pages(&pages);
+    tokio::fs::write(
+        &configuration.record,
+        generate_load_test(&pages, host.as_deref()),
+    )
+    .await?;
+    info!(
+        "wrote load test of {} recorded requests to: {}",
+        requests.len(),
+        configuration.record
+    );
+
+    Ok(())
+}
+
+/// Proxies the requests made on a browser connection, recording them.
+async fn proxy_connection(
+    stream: TcpStream,
+    client: reqwest::Client,
+    recorded_tx: flume::Sender<GooseRecordedRequest>,
+) {
+    let mut stream = BufReader::new(stream);
+    // Requests are read one at a time, as browsers don't pipeline requests to a proxy.
+    while let Some(request) = read_request(&mut stream).await {
+        if request.method.eq_ignore_ascii_case("CONNECT") {
+            warn!(
+                "tunneling {} without recording it, as HTTPS requests can't be recorded",
+                request.target
+            );
+            tunnel(stream.into_inner(), &request.target).await;
+            return;
+        }
+        let close = request
+            .header("connection")
+            .or_else(|| request.header("proxy-connection"))
+            .map(|value| value.eq_ignore_ascii_case("close"))
+            .unwrap_or(false);
+        if !proxy_request(&mut stream, &client, &recorded_tx, request).await || close {
+            return;
+        }
+    }
+}
+
+/// Reads the request line and headers of the next request, or returns `None` when the
+/// connection is closed or the request can't be parsed.
+async fn read_request(stream: &mut BufReader<TcpStream>) -> Option<GooseProxyRequest> {
+    let mut line = String::new();
+    if stream.read_line(&mut line).await.ok()? == 0 {
+        return None;
+    }
+    let mut request_line = line.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if stream.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':')?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    Some(GooseProxyRequest {
+        method,
+        target,
+        headers,
+    })
+}
+
+/// Tunnels an HTTPS connection to its host without recording it.
+async fn tunnel(mut stream: TcpStream, target: &str) {
+    match TcpStream::connect(target).await {
+        Ok(mut upstream) => {
+            if stream
+                .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                .await
+                .is_ok()
+            {
+                let _ = tokio::io::copy_bidirectional(&mut stream, &mut upstream).await;
+            }
+        }
+        Err(e) => {
+            info!("failed to tunnel to {}: {}", target, e);
+            let _ = write_error(&mut stream, 502, "Bad Gateway").await;
+        }
+    }
+}
+
+/// Forwards a request to its host, writing the response back to the browser and recording
+/// the request. Returns `false` if the connection can't be used for further requests.
+async fn proxy_request(
+    stream: &mut BufReader<TcpStream>,
+    client: &reqwest::Client,
+    recorded_tx: &flume::Sender<GooseRecordedRequest>,
+    request: GooseProxyRequest,
+) -> bool {
+    let received = time::Instant::now();
+
+    // Browsers send the full URL to a proxy.
+    let (url, method) = match (
+        Url::parse(&request.target),
+        reqwest::Method::from_bytes(request.method.as_bytes()),
+    ) {
+        (Ok(url), Ok(method)) => (url, method),
+        _ => {
+            let _ = write_error(stream.get_mut(), 400, "Bad Request").await;
+            return false;
+        }
+    };
+
+    // Browsers don't stream request bodies to a proxy, so only a known length is supported.
+    if request.header("transfer-encoding").is_some() {
+        let _ = write_error(stream.get_mut(), 501, "Not Implemented").await;
+        return false;
+    }
+    let length = request
+        .header("content-length")
+        .and_then(|length| length.parse::<usize>().ok())
+        .unwrap_or(0);
+    if length > MAX_REQUEST_BODY {
+        info!(
+            "rejected request body of {} bytes to {}, larger than {} bytes",
+            length, url, MAX_REQUEST_BODY
+        );
+        let _ = write_error(stream.get_mut(), 413, "Payload Too Large").await;
+        return false;
+    }
+    let mut body = vec![0; length];
+    if stream.read_exact(&mut body).await.is_err() {
+        return false;
+    }
+
+    let mut request_builder = client.request(method, url.clone());
+    for (name, value) in &request.headers {
+        if HOP_BY_HOP_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            continue;
+        }
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            request_builder = request_builder.header(name, value);
+        }
+    }
+    if !body.is_empty() {
+        request_builder = request_builder.body(body.clone());
+    }
+
+    let written = match request_builder.send().await {
+        Ok(response) => write_response(stream.get_mut(), response).await,
+        Err(e) => {
+            info!("failed to proxy {}: {}", url, e);
+            write_error(stream.get_mut(), 502, "Bad Gateway").await
+        }
+    };
+
+    // Record requests made with methods Goose supports.
+    if let Some(method) = goose_method(&request.method) {
+        let _ = recorded_tx.send(GooseRecordedRequest {
+            received,
+            method,
+            page: request
+                .header("accept")
+                .map(|accept| accept.contains("text/html"))
+                .unwrap_or(false),
+            content_type: request
+                .header("content-type")
+                .filter(|_| !body.is_empty())
+                .map(|content_type| content_type.to_string()),
+            body: if body.is_empty() {
+                None
+            } else {
+                Some(String::from_utf8_lossy(&body).to_string())
+            },
+            url,
+        });
+    }
+
+    written.is_ok()
+}
+
+/// Writes a response from the host back to the browser.
+async fn write_response(
+    stream: &mut TcpStream,
+    response: reqwest::Response,
+) -> std::io::Result<()> {
+    let status = response.status();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or("")
+    );
+    for (name, value) in response.headers() {
+        if HOP_BY_HOP_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+        head.push_str(&format!(
+            "{}: {}\r\n",
+            name,
+            String::from_utf8_lossy(value.as_bytes())
+        ));
+    }
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(e) => {
+            info!("failed to read response body: {}", e);
+            return write_error(stream, 502, "Bad Gateway").await;
+        }
+    };
+    head.push_str(&format!("content-length: {}\r\n\r\n", body.len()));
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await
+}
+
+/// Writes an error response to the browser.
+async fn write_error(stream: &mut TcpStream, status: u16, reason: &str) -> std::io::Result<()> {
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 {} {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                status, reason
+            )
+            .as_bytes(),
+        )
+        .await
+}
+
+/// Returns the [`GooseMethod`] used to replay a request, if Goose supports its method.
+fn goose_method(method: &str) -> Option<GooseMethod> {
+    match method.to_ascii_uppercase().as_str() {
+        "DELETE" => Some(GooseMethod::Delete),
+        "GET" => Some(GooseMethod::Get),
+        "HEAD" => Some(GooseMethod::Head),
+        "PATCH" => Some(GooseMethod::Patch),
+        "POST" => Some(GooseMethod::Post),
+        "PUT" => Some(GooseMethod::Put),
+        _ => None,
+    }
+}
+
+/// Groups the recorded requests into pages, each starting with a request for an HTML
+/// document, merging pages with the same method and normalized path.
+fn recorded_pages<'a>(
+    requests: &'a [GooseRecordedRequest],
+    host: Option<&str>,
+) -> Vec<GooseRecordedPage<'a>> {
+    let mut pages: Vec<GooseRecordedPage> = Vec::new();
+    // The page the following requests are part of, or None if it was merged.
+    let mut current: Option<usize> = None;
+    for request in requests {
+        if !request.page {
+            match current {
+                Some(index) => pages[index].requests.push(request),
+                // Requests made before the first page start a page of their own.
+                None if pages.is_empty() => {
+                    pages.push(GooseRecordedPage {
+                        requests: vec![request],
+                        normalized: normalized_path(request, host),
+                        loads: vec![replay_path(&request.url, host)],
+                    });
+                    current = Some(0);
+                }
+                // The requests of a merged page were already recorded the first time.
+                None => (),
+            }
+            continue;
+        }
+
+        let normalized = normalized_path(request, host);
+        if let Some(page) = pages.iter_mut().find(|page| page.normalized == normalized) {
+            page.loads.push(replay_path(&request.url, host));
+            current = None;
+        } else {
+            pages.push(GooseRecordedPage {
+                requests: vec![request],
+                normalized,
+                loads: vec![replay_path(&request.url, host)],
+            });
+            current = Some(pages.len() - 1);
+        }
+    }
+    pages
+}
+
+/// Returns the method and normalized path of a request, used to merge pages loaded more
+/// than once: the query string is ignored, and path segments that look like identifiers
+/// are replaced with `{id}`.
+fn normalized_path(request: &GooseRecordedRequest, host: Option<&str>) -> String {
+    let mut url = request.url.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    let path = replay_path(&url, host);
+    let (prefix, path) = match path.find("://") {
+        // Requests to other hosts keep their origin.
+        Some(scheme) => match path[scheme + 3..].find('/') {
+            Some(slash) => path.split_at(scheme + 3 + slash),
+            None => (path.as_str(), ""),
+        },
+        None => ("", path.as_str()),
+    };
+    let segments: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            if is_identifier(segment) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    format!("{} {}{}", request.method, prefix, segments.join("/"))
+}
+
+/// Whether a path segment looks like an identifier: a number, a UUID, or a long hexadecimal
+/// string.
+fn is_identifier(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+    let hex = segment.chars().filter(|c| c.is_ascii_hexdigit()).count();
+    let dashes = segment.chars().filter(|c| *c == '-').count();
+    segment.chars().all(|c| c.is_ascii_digit())
+        || (segment.len() == 36 && hex == 32 && dashes == 4)
+        || (segment.len() >= 16 && hex == segment.len())
+}
+
+/// Displays the pages that were loaded more than once and merged into a single task.
+fn print_merged_pages(pages: &[GooseRecordedPage]) {
+    if pages.iter().all(|page| page.loads.len() == 1) {
+        return;
+    }
+    println!("\n === MERGED RECORDED PAGES ===\n ------------------------------------------------------------------------------");
+    println!(" {:<11} | Page", "Loads");
+    println!(" ------------------------------------------------------------------------------");
+    for page in pages.iter().filter(|page| page.loads.len() > 1) {
+        println!(" {:<12}  {}", page.loads.len(), page.normalized);
+    }
+    println!(" ------------------------------------------------------------------------------");
+}
+
+/// Generates the source of a Goose load test replaying the recorded pages.
+fn generate_load_test(pages: &[GooseRecordedPage], host: Option<&str>) -> String {
+    let mut source = String::from(
+        r#"//! A Goose load test generated from a session recorded with `--record`.
+//!
+//! Each task loads a page visited while recording, with the other requests the browser
+//! made for it, in the order they were recorded. Review the tasks before running the load
+//! test, for example to remove requests for third party assets, or to replace recorded form
+//! values with values that differ between users.
+"#,
+    );
+    if pages.iter().any(|page| page.loads.len() > 1) {
+        source.push_str(
+            r#"//!
+//! Pages loaded more than once were merged into a single task, weighted by the number of
+//! times they were loaded:
+"#,
+        );
+        for page in pages.iter().filter(|page| page.loads.len() > 1) {
+            source.push_str(&format!(
+                "//!  - {} ({} loads)\n",
+                page.normalized,
+                page.loads.len()
+            ));
+        }
+    }
+    source.push_str(
+        r#"
+use goose::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), GooseError> {
+    GooseAttack::initialize()?
+        .register_taskset(
+            taskset!("RecordedSession")"#,
+    );
+    for (index, page) in pages.iter().enumerate() {
+        // Merged pages are named by their normalized path, and weighted by their loads.
+        let (weight, name) = if page.loads.len() > 1 {
+            let (_, name) = page.normalized.split_once(' ').unwrap_or_default();
+            (
+                format!(".set_weight({})?", page.loads.len()),
+                name.to_string(),
+            )
+        } else {
+            (String::new(), page.loads[0].clone())
+        };
+        source.push_str(&format!(
+            "\n                .register_task(task!(page_{}).set_sequence({}){}.set_name({:?}))",
+            index + 1,
+            index + 1,
+            weight,
+            name,
+        ));
+    }
+    source.push_str("\n        )\n");
+    if let Some(host) = host {
+        source.push_str(&format!(
+            "        .set_default(GooseDefault::Host, {:?})?\n",
+            host
+        ));
+    }
+    source.push_str(
+        r#"        .execute()
+        .await?
+        .print();
+
+    Ok(())
+}
+"#,
+    );
+
+    for (index, page) in pages.iter().enumerate() {
+        if page.loads.len() > 1 {
+            source.push_str(&format!(
+                "\n/// {}, loaded as: {}",
+                page.normalized,
+                page.loads.join(", ")
+            ));
+        } else {
+            source.push_str(&format!(
+                "\n/// {} {}",
+                page.requests[0].method, page.loads[0]
+            ));
+        }
+        source.push_str(&format!(
+            "\nasync fn page_{}(user: &mut GooseUser) -> GooseTaskResult {{\n",
+            index + 1
+        ));
+        for request in &page.requests {
+            source.push_str(&replay_request(request, host));
+        }
+        source.push_str("\n    Ok(())\n}\n");
+    }
+
+    source
+}
+
+/// Returns the path used to replay a request: relative to the load test's host if the
+/// request was made to that host, otherwise the full URL.
+fn replay_path(url: &Url, host: Option<&str>) -> String {
+    if Some(url.origin().ascii_serialization().as_str()) == host {
+        url[Position::BeforePath..Position::AfterQuery].to_string()
+    } else {
+        url[..Position::AfterQuery].to_string()
+    }
+}
+
+/// Generates the code replaying a single request.
+fn replay_request(request: &GooseRecordedRequest, host: Option<&str>) -> String {
+    let path = replay_path(&request.url, host);
+    match (&request.method, &request.content_type, &request.body) {
+        (GooseMethod::Get, None, None) => {
+            format!("    let _goose = user.get({:?}).await?;\n", path)
+        }
+        (GooseMethod::Head, None, None) => {
+            format!("    let _goose = user.head({:?}).await?;\n", path)
+        }
+        (GooseMethod::Delete, None, None) => {
+            format!("    let _goose = user.delete({:?}).await?;\n", path)
+        }
+        (GooseMethod::Post, None, Some(body)) => {
+            format!(
+                "    let _goose = user.post({:?}, {:?}).await?;\n",
+                path, body
+            )
+        }
+        (method, content_type, body) => {
+            let method = format!("{:?}", method);
+            let mut source = format!(
+                "    let request_builder = user\n        .get_request_builder(&GooseMethod::{}, {:?})?",
+                method, path
+            );
+            if let Some(content_type) = content_type {
+                source.push_str(&format!(
+                    "\n        .header(\"content-type\", {:?})",
+                    content_type
+                ));
+            }
+            if let Some(body) = body {
+                source.push_str(&format!("\n        .body({:?})", body));
+            }
+            source.push_str(&format!(
+                ";\n    let goose_request = GooseRequest::builder()\n        .method(GooseMethod::{})\n        .path({:?})\n        .set_request_builder(request_builder)\n        .build();\n    let _goose = user.request(goose_request).await?;\n",
+                method, path
+            ));
+            source
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(method: GooseMethod, url: &str, page: bool) -> GooseRecordedRequest {
+        GooseRecordedRequest {
+            received: time::Instant::now(),
+            method,
+            url: Url::parse(url).unwrap(),
+            page,
+            content_type: None,
+            body: None,
+        }
+    }
+
+    #[test]
+    fn generate() {
+        let mut login = recorded(GooseMethod::Post, "http://example.com/login", true);
+        login.content_type = Some("application/x-www-form-urlencoded".to_string());
+        login.body = Some("name=goose".to_string());
+        let requests = vec![
+            recorded(GooseMethod::Get, "http://example.com/?page=1#top", true),
+            recorded(GooseMethod::Get, "http://example.com/style.css", false),
+            recorded(GooseMethod::Get, "http://cdn.example.com/logo.png", false),
+            login,
+        ];
+        let source = generate_load_test(
+            &recorded_pages(&requests, Some("http://example.com")),
+            Some("http://example.com"),
+        );
+
+        // A task for each page, in order.
+        assert!(source
+            .contains(r#".register_task(task!(page_1).set_sequence(1).set_name("/?page=1"))"#));
+        assert!(
+            source.contains(r#".register_task(task!(page_2).set_sequence(2).set_name("/login"))"#)
+        );
+        assert!(!source.contains("page_3"));
+        assert!(source.contains(r#".set_default(GooseDefault::Host, "http://example.com")?"#));
+
+        // Requests to the load test's host are relative, other hosts keep the full URL.
+        assert!(source.contains(r#"    let _goose = user.get("/style.css").await?;"#));
+        assert!(source
+            .contains(r#"    let _goose = user.get("http://cdn.example.com/logo.png").await?;"#));
+
+        // The content type of a request body is kept.
+        assert!(source.contains(r#".header("content-type", "application/x-www-form-urlencoded")"#));
+        assert!(source.contains(r#".body("name=goose")"#));
+    }
+
+    #[test]
+    fn merge() {
+        let requests = vec![
+            recorded(GooseMethod::Get, "http://example.com/", true),
+            recorded(GooseMethod::Get, "http://example.com/node/1", true),
+            recorded(GooseMethod::Get, "http://example.com/style.css", false),
+            recorded(GooseMethod::Get, "http://example.com/node/2?tab=3", true),
+            recorded(GooseMethod::Get, "http://example.com/style.css", false),
+            recorded(
+                GooseMethod::Get,
+                "http://example.com/user/c0ffee00-1234-5678-9abc-def012345678",
+                true,
+            ),
+            recorded(GooseMethod::Get, "http://example.com/node/3", true),
+            recorded(GooseMethod::Get, "http://example.com/", true),
+            recorded(GooseMethod::Post, "http://example.com/node/4", true),
+        ];
+        let pages = recorded_pages(&requests, Some("http://example.com"));
+
+        // Pages with the same method and normalized path are merged, in the order they
+        // were first loaded.
+        let merged: Vec<(&str, usize)> = pages
+            .iter()
+            .map(|page| (page.normalized.as_str(), page.loads.len()))
+            .collect();
+        assert_eq!(
+            merged,
+            vec![
+                ("GET /", 2),
+                ("GET /node/{id}", 3),
+                ("GET /user/{id}", 1),
+                ("POST /node/{id}", 1),
+            ]
+        );
+        // Only the requests of the first load of a merged page are replayed.
+        assert_eq!(pages[1].requests.len(), 2);
+        assert_eq!(pages[1].loads, vec!["/node/1", "/node/2?tab=3", "/node/3"]);
+
+        // Merged pages are weighted by their loads, and listed in the load test.
+        let source = generate_load_test(&pages, Some("http://example.com"));
+        assert!(source.contains(
+            r#".register_task(task!(page_2).set_sequence(2).set_weight(3)?.set_name("/node/{id}"))"#
+        ));
+        assert!(
+            source.contains(r#".register_task(task!(page_4).set_sequence(4).set_name("/node/4"))"#)
+        );
+        assert!(source.contains("//!  - GET /node/{id} (3 loads)\n"));
+        assert!(!source.contains("page_5"));
+    }
+}
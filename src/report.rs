@@ -2,7 +2,6 @@
 
 use crate::metrics;
 
-use std::collections::BTreeMap;
 use std::mem;
 
 use serde::Serialize;
@@ -16,19 +15,32 @@ pub struct GooseReportTemplates<'a> {
     pub co_responses_template: &'a str,
     pub tasks_template: &'a str,
     pub status_codes_template: &'a str,
+    pub dimensions_template: &'a str,
     pub errors_template: &'a str,
+    pub active_users_template: &'a str,
+    pub ramp_profiles_template: &'a str,
+    pub phases_template: &'a str,
+    pub network_baselines_template: &'a str,
+    pub baseline_comparison_template: &'a str,
+    pub aborted_template: &'a str,
+    pub response_time_percentiles_template: &'a str,
+    pub response_time_heatmap_template: &'a str,
+    pub request_time_series_template: &'a str,
 }
 
+/// The percentiles of response times displayed in the html report.
+pub const PERCENTILES: [f32; 8] = [0.5, 0.6, 0.7, 0.8, 0.9, 0.95, 0.99, 1.0];
+
 /// Defines the metrics reported about requests.
 #[derive(Debug, Clone, Serialize)]
 pub struct RequestMetric {
     pub method: String,
     pub name: String,
-    pub number_of_requests: usize,
-    pub number_of_failures: usize,
+    pub number_of_requests: String,
+    pub number_of_failures: String,
     pub response_time_average: String,
-    pub response_time_minimum: usize,
-    pub response_time_maximum: usize,
+    pub response_time_minimum: String,
+    pub response_time_maximum: String,
     pub requests_per_second: String,
     pub failures_per_second: String,
 }
@@ -40,7 +52,7 @@ pub struct CORequestMetric {
     pub name: String,
     pub response_time_average: String,
     pub response_time_standard_deviation: String,
-    pub response_time_maximum: usize,
+    pub response_time_maximum: String,
 }
 
 /// Defines the metrics reported about responses.
@@ -64,11 +76,11 @@ pub struct TaskMetric {
     pub is_task_set: bool,
     pub task: String,
     pub name: String,
-    pub number_of_requests: usize,
-    pub number_of_failures: usize,
+    pub number_of_requests: String,
+    pub number_of_failures: String,
     pub response_time_average: String,
-    pub response_time_minimum: usize,
-    pub response_time_maximum: usize,
+    pub response_time_minimum: String,
+    pub response_time_maximum: String,
     pub requests_per_second: String,
     pub failures_per_second: String,
 }
@@ -80,25 +92,80 @@ pub struct StatusCodeMetric {
     pub status_codes: String,
 }
 
+/// Defines the metrics reported about response header dimensions.
+pub struct DimensionMetric {
+    pub method: String,
+    pub name: String,
+    pub dimension: String,
+    pub number_of_requests: String,
+    pub response_time_average: String,
+    pub response_time_minimum: String,
+    pub response_time_maximum: String,
+}
+
+/// Defines how many users were planned to run and actually ran the load test or a task set.
+pub struct RampProfileMetric {
+    pub name: String,
+    pub planned_users: String,
+    pub planned_at: String,
+    pub active_users: String,
+    pub reached_at: String,
+}
+
+/// Defines the requests completed during one phase of a test plan.
+pub struct PhaseMetric {
+    pub name: String,
+    pub users: String,
+    pub started: String,
+    pub duration: String,
+    pub requests: String,
+    pub fails: String,
+    pub requests_per_second: String,
+    pub response_time_average: String,
+    pub response_time_95: String,
+}
+
+/// Defines the network round-trip time to a host, measured before the load test.
+pub struct NetworkBaselineMetric {
+    pub host: String,
+    pub address: String,
+    pub samples: String,
+    pub failures: String,
+    pub round_trip_time_minimum: String,
+    pub round_trip_time_median: String,
+    pub round_trip_time_maximum: String,
+}
+
+/// Defines how a request compares with the same request in the baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct BaselineComparisonMetric {
+    pub name: String,
+    pub response_time_average: String,
+    pub response_time_average_change: String,
+    pub requests_per_second: String,
+    pub requests_per_second_change: String,
+    pub fail_percent: String,
+    pub fail_percent_change: String,
+}
+
+/// Defines the response times at each of the `PERCENTILES` of a request, charted in the
+/// html report.
+pub struct ResponseTimePercentiles {
+    pub name: String,
+    pub response_times: Vec<usize>,
+}
+
 /// Helper to generate a single response metric.
 pub fn get_response_metric(
     method: &str,
     name: &str,
-    response_times: &BTreeMap<usize, usize>,
-    total_request_count: usize,
-    response_time_minimum: usize,
-    response_time_maximum: usize,
+    data: &metrics::GooseRequestMetricTimingData,
+    number_format: &metrics::GooseNumberFormat,
 ) -> ResponseMetric {
     // Calculate percentiles in a loop.
     let mut percentiles = Vec::new();
-    for percent in &[0.5, 0.6, 0.7, 0.8, 0.9, 0.95, 0.99, 1.0] {
-        percentiles.push(metrics::calculate_response_time_percentile(
-            response_times,
-            total_request_count,
-            response_time_minimum,
-            response_time_maximum,
-            *percent,
-        ));
+    for percent in &PERCENTILES {
+        percentiles.push(data.format_percentile(*percent, number_format));
     }
 
     // Now take the Strings out of the Vector and build a ResponseMetric object.
@@ -116,6 +183,23 @@ pub fn get_response_metric(
     }
 }
 
+/// Helper to generate the response times at each of the `PERCENTILES` of a request, if
+/// its response times weren't discarded.
+pub fn get_response_time_percentiles(
+    name: &str,
+    data: &metrics::GooseRequestMetricTimingData,
+) -> Option<ResponseTimePercentiles> {
+    let response_times = PERCENTILES
+        .iter()
+        .map(|percent| data.percentile(*percent))
+        .collect::<Option<Vec<usize>>>()?;
+
+    Some(ResponseTimePercentiles {
+        name: name.to_string(),
+        response_times,
+    })
+}
+
 /// Build an individual row of raw request metrics in the html report.
 pub fn raw_request_metrics_row(metric: RequestMetric) -> String {
     format!(
@@ -310,6 +394,54 @@ pub fn status_code_metrics_row(metric: StatusCodeMetric) -> String {
     )
 }
 
+/// If dimensions are enabled, add a dimension metrics table to the html report.
+pub fn dimension_metrics_template(dimension_rows: &str) -> String {
+    format!(
+        r#"<div class="dimensions">
+        <h2>Response Time Dimensions</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th>Method</th>
+                    <th>Name</th>
+                    <th>Header</th>
+                    <th># Requests</th>
+                    <th>Average (ms)</th>
+                    <th>Min (ms)</th>
+                    <th>Max (ms)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {dimension_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        dimension_rows = dimension_rows,
+    )
+}
+
+/// Build an individual row of dimension metrics in the html report.
+pub fn dimension_metrics_row(metric: DimensionMetric) -> String {
+    format!(
+        r#"<tr>
+        <td>{method}</td>
+        <td>{name}</td>
+        <td>{dimension}</td>
+        <td>{number_of_requests}</td>
+        <td>{response_time_average}</td>
+        <td>{response_time_minimum}</td>
+        <td>{response_time_maximum}</td>
+    </tr>"#,
+        method = metric.method,
+        name = metric.name,
+        dimension = metric.dimension,
+        number_of_requests = metric.number_of_requests,
+        response_time_average = metric.response_time_average,
+        response_time_minimum = metric.response_time_minimum,
+        response_time_maximum = metric.response_time_maximum,
+    )
+}
+
 /// If task metrics are enabled, add a task metrics table to the html report.
 pub fn task_metrics_template(task_rows: &str) -> String {
     format!(
@@ -360,8 +492,8 @@ pub fn task_metrics_row(metric: TaskMetric) -> String {
         </tr>"#,
             task = metric.task,
             name = metric.name,
-            number_of_requests = metrics::format_number(metric.number_of_requests),
-            number_of_failures = metrics::format_number(metric.number_of_failures),
+            number_of_requests = metric.number_of_requests,
+            number_of_failures = metric.number_of_failures,
             response_time_average = metric.response_time_average,
             response_time_minimum = metric.response_time_minimum,
             response_time_maximum = metric.response_time_maximum,
@@ -404,11 +536,570 @@ pub fn error_row(error: &metrics::GooseErrorMetricAggregate) -> String {
     )
 }
 
+/// If active users were tracked, add a chart of active users over time to the html report,
+/// along with the users planned to be running if also tracked.
+pub fn active_users_template(
+    active_users: &[usize],
+    planned_users: &[usize],
+    annotations: &[metrics::GooseAnnotation],
+) -> String {
+    // Scale the chart to the duration of the load test and the most active or planned users.
+    let seconds = active_users.len().max(2) - 1;
+    let maximum = active_users
+        .iter()
+        .chain(planned_users)
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let points = |users: &[usize]| -> String {
+        users
+            .iter()
+            .take(seconds + 1)
+            .enumerate()
+            .map(|(second, users)| {
+                format!(
+                    "{:.1},{:.1}",
+                    50.0 + second as f32 * 930.0 / seconds as f32,
+                    310.0 - *users as f32 * 290.0 / maximum as f32,
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    let planned = if planned_users.is_empty() {
+        "".to_string()
+    } else {
+        format!(
+            r##"<polyline points="{points}" fill="none" stroke="#f5a623" stroke-width="2" stroke-dasharray="8 4" />
+            <text x="60" y="25" fill="#00ca5a">active</text>
+            <text x="120" y="25" fill="#f5a623">planned</text>"##,
+            points = points(planned_users),
+        )
+    };
+
+    format!(
+        r##"<div class="charts-container">
+        <h2>Active Users</h2>
+        <svg class="chart" viewBox="0 0 1000 350" preserveAspectRatio="none">
+            <line x1="50" y1="310" x2="980" y2="310" stroke="#cad9ea" />
+            <line x1="50" y1="20" x2="50" y2="310" stroke="#cad9ea" />
+            <text x="40" y="25" fill="#fff" text-anchor="end">{maximum}</text>
+            <text x="40" y="310" fill="#fff" text-anchor="end">0</text>
+            <text x="50" y="330" fill="#fff" text-anchor="middle">0s</text>
+            <text x="980" y="330" fill="#fff" text-anchor="middle">{seconds}s</text>
+            {planned}
+            <polyline points="{points}" fill="none" stroke="#00ca5a" stroke-width="2" />
+            {annotations}
+        </svg>
+    </div>"##,
+        maximum = maximum,
+        seconds = seconds,
+        planned = planned,
+        points = points(active_users),
+        annotations = annotation_markers(annotations, seconds),
+    )
+}
+
+/// Mark each annotation on a chart of the load test over time with a labeled vertical
+/// line, where the chart spans the given number of seconds.
+fn annotation_markers(annotations: &[metrics::GooseAnnotation], seconds: usize) -> String {
+    annotations
+        .iter()
+        .enumerate()
+        .map(|(index, annotation)| {
+            let x = 50.0 + annotation.elapsed.min(seconds) as f32 * 930.0 / seconds as f32;
+            // Stagger the labels so annotations close together don't overlap.
+            format!(
+                r##"<line x1="{x:.1}" y1="20" x2="{x:.1}" y2="310" stroke="#bd10e0" stroke-dasharray="4 4" />
+            <text x="{label_x:.1}" y="{label_y}" fill="#bd10e0">{name}</text>"##,
+                x = x,
+                label_x = x + 4.0,
+                label_y = 45 + (index % 4) * 20,
+                name = annotation.name,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n            ")
+}
+
+/// If planned users were tracked, add a table comparing planned and active users to the
+/// html report.
+pub fn ramp_profiles_template(ramp_profile_rows: &str) -> String {
+    format!(
+        r#"<div class="ramp_profiles">
+        <h2>User Ramp</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Name</th>
+                    <th>Planned</th>
+                    <th>Planned at</th>
+                    <th>Active</th>
+                    <th>Reached at</th>
+                </tr>
+            </thead>
+            <tbody>
+                {ramp_profile_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        ramp_profile_rows = ramp_profile_rows,
+    )
+}
+
+/// Build an individual row of the user ramp table in the html report.
+pub fn ramp_profile_row(metric: RampProfileMetric) -> String {
+    format!(
+        r#"<tr>
+        <td colspan="2">{name}</td>
+        <td>{planned_users}</td>
+        <td>{planned_at}</td>
+        <td>{active_users}</td>
+        <td>{reached_at}</td>
+    </tr>"#,
+        name = metric.name,
+        planned_users = metric.planned_users,
+        planned_at = metric.planned_at,
+        active_users = metric.active_users,
+        reached_at = metric.reached_at,
+    )
+}
+
+/// If a test plan was followed, add a table of the requests completed during each of its
+/// phases to the html report.
+pub fn phases_template(phase_rows: &str) -> String {
+    format!(
+        r#"<div class="phases">
+        <h2>Phases</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th colspan="2">Name</th>
+                    <th>Users</th>
+                    <th>Started</th>
+                    <th>Duration</th>
+                    <th># Requests</th>
+                    <th># Fails</th>
+                    <th>RPS</th>
+                    <th>Average (ms)</th>
+                    <th>95%ile (ms)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {phase_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        phase_rows = phase_rows,
+    )
+}
+
+/// Build an individual row of the phases table in the html report.
+pub fn phase_row(metric: PhaseMetric) -> String {
+    format!(
+        r#"<tr>
+        <td colspan="2">{name}</td>
+        <td>{users}</td>
+        <td>{started}</td>
+        <td>{duration}</td>
+        <td>{requests}</td>
+        <td>{fails}</td>
+        <td>{requests_per_second}</td>
+        <td>{response_time_average}</td>
+        <td>{response_time_95}</td>
+    </tr>"#,
+        name = metric.name,
+        users = metric.users,
+        started = metric.started,
+        duration = metric.duration,
+        requests = metric.requests,
+        fails = metric.fails,
+        requests_per_second = metric.requests_per_second,
+        response_time_average = metric.response_time_average,
+        response_time_95 = metric.response_time_95,
+    )
+}
+
+/// If the network round-trip time was measured, add a table of it to the html report.
+pub fn network_baselines_template(network_baseline_rows: &str) -> String {
+    format!(
+        r#"<div class="network_baselines">
+        <h2>Network Baseline</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th>Host</th>
+                    <th>Address</th>
+                    <th>Samples</th>
+                    <th># Fails</th>
+                    <th>Min (ms)</th>
+                    <th>Median (ms)</th>
+                    <th>Max (ms)</th>
+                </tr>
+            </thead>
+            <tbody>
+                {network_baseline_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        network_baseline_rows = network_baseline_rows,
+    )
+}
+
+/// Build an individual row of the network baseline table in the html report.
+pub fn network_baseline_row(metric: NetworkBaselineMetric) -> String {
+    format!(
+        r#"<tr>
+        <td>{host}</td>
+        <td>{address}</td>
+        <td>{samples}</td>
+        <td>{failures}</td>
+        <td>{round_trip_time_minimum}</td>
+        <td>{round_trip_time_median}</td>
+        <td>{round_trip_time_maximum}</td>
+    </tr>"#,
+        host = metric.host,
+        address = metric.address,
+        samples = metric.samples,
+        failures = metric.failures,
+        round_trip_time_minimum = metric.round_trip_time_minimum,
+        round_trip_time_median = metric.round_trip_time_median,
+        round_trip_time_maximum = metric.round_trip_time_maximum,
+    )
+}
+
+/// If a baseline was loaded, add a table comparing requests with it to the html report.
+pub fn baseline_comparison_template(baseline_comparison_rows: &str) -> String {
+    format!(
+        r#"<div class="baseline_comparison">
+        <h2>Baseline Comparison</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th>Name</th>
+                    <th>Average (ms)</th>
+                    <th>Change</th>
+                    <th>RPS</th>
+                    <th>Change</th>
+                    <th>Fail %</th>
+                    <th>Change</th>
+                </tr>
+            </thead>
+            <tbody>
+                {baseline_comparison_rows}
+            </tbody>
+        </table>
+    </div>"#,
+        baseline_comparison_rows = baseline_comparison_rows,
+    )
+}
+
+/// Build an individual row of the baseline comparison table in the html report.
+pub fn baseline_comparison_row(metric: BaselineComparisonMetric) -> String {
+    format!(
+        r#"<tr>
+        <td>{name}</td>
+        <td>{response_time_average}</td>
+        <td>{response_time_average_change}</td>
+        <td>{requests_per_second}</td>
+        <td>{requests_per_second_change}</td>
+        <td>{fail_percent}</td>
+        <td>{fail_percent_change}</td>
+    </tr>"#,
+        name = metric.name,
+        response_time_average = metric.response_time_average,
+        response_time_average_change = metric.response_time_average_change,
+        requests_per_second = metric.requests_per_second,
+        requests_per_second_change = metric.requests_per_second_change,
+        fail_percent = metric.fail_percent,
+        fail_percent_change = metric.fail_percent_change,
+    )
+}
+
+/// Add a chart of the response time at each percentile of each request to the html report.
+pub fn response_time_percentiles_template(percentiles: &[ResponseTimePercentiles]) -> String {
+    // Colors cycle if there are more requests than colors.
+    const COLORS: [&str; 6] = [
+        "#00ca5a", "#f5a623", "#4a90e2", "#d0021b", "#bd10e0", "#f8e71c",
+    ];
+
+    // Scale the chart to the slowest response time.
+    let maximum = percentiles
+        .iter()
+        .flat_map(|request| request.response_times.iter())
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let step = 900.0 / (PERCENTILES.len() - 1) as f32;
+
+    let mut lines = Vec::new();
+    let mut legend = Vec::new();
+    for (index, request) in percentiles.iter().enumerate() {
+        let color = COLORS[index % COLORS.len()];
+        let points: Vec<String> = request
+            .response_times
+            .iter()
+            .enumerate()
+            .map(|(percentile, response_time)| {
+                format!(
+                    "{:.1},{:.1}",
+                    80.0 + percentile as f32 * step,
+                    310.0 - *response_time as f32 * 290.0 / maximum as f32,
+                )
+            })
+            .collect();
+        lines.push(format!(
+            r##"<polyline points="{}" fill="none" stroke="{}" stroke-width="2"><title>{}</title></polyline>"##,
+            points.join(" "),
+            color,
+            request.name,
+        ));
+        legend.push(format!(
+            r##"<span style="color: {}">&#9632; {}</span>"##,
+            color, request.name,
+        ));
+    }
+
+    // Label each percentile along the bottom of the chart.
+    let labels: Vec<String> = PERCENTILES
+        .iter()
+        .enumerate()
+        .map(|(percentile, percent)| {
+            format!(
+                r##"<text x="{:.1}" y="330" fill="#fff" text-anchor="middle">{}%</text>"##,
+                80.0 + percentile as f32 * step,
+                (percent * 100.0).round(),
+            )
+        })
+        .collect();
+
+    format!(
+        r##"<div class="charts-container">
+        <h2>Response Time Percentiles</h2>
+        <svg class="chart" viewBox="0 0 1000 350" preserveAspectRatio="none">
+            <line x1="80" y1="310" x2="980" y2="310" stroke="#cad9ea" />
+            <line x1="80" y1="20" x2="80" y2="310" stroke="#cad9ea" />
+            <text x="70" y="25" fill="#fff" text-anchor="end">{maximum}ms</text>
+            <text x="70" y="310" fill="#fff" text-anchor="end">0ms</text>
+            {labels}
+            {lines}
+        </svg>
+        <p class="legend">{legend}</p>
+    </div>"##,
+        maximum = maximum,
+        labels = labels.join("\n            "),
+        lines = lines.join("\n            "),
+        legend = legend.join(" "),
+    )
+}
+
+/// If response times were tracked over time, add a heatmap of response times over time to
+/// the html report.
+pub fn response_time_heatmap_template(heatmap: &metrics::GooseResponseTimeHeatmap) -> String {
+    // Merge seconds into at most 100 columns, so long load tests remain readable.
+    let seconds = heatmap.counts.len();
+    let seconds_per_column = seconds.div_ceil(100).max(1);
+    let buckets = heatmap.buckets.len() + 1;
+    let columns: Vec<Vec<usize>> = heatmap
+        .counts
+        .chunks(seconds_per_column)
+        .map(|chunk| {
+            (0..buckets)
+                .map(|bucket| chunk.iter().map(|counts| counts[bucket]).sum())
+                .collect()
+        })
+        .collect();
+    let maximum = columns.iter().flatten().copied().max().unwrap_or(0).max(1);
+
+    let width = 900.0 / columns.len().max(1) as f32;
+    let height = 290.0 / buckets as f32;
+    let mut cells = Vec::new();
+    for (column, counts) in columns.iter().enumerate() {
+        for (bucket, count) in counts.iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+            // Shade on a logarithmic scale, so rare slow responses remain visible.
+            let opacity = ((*count as f32).ln_1p() / (maximum as f32).ln_1p()).max(0.1);
+            cells.push(format!(
+                r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="#00ca5a" fill-opacity="{:.2}"><title>{}</title></rect>"##,
+                80.0 + column as f32 * width,
+                310.0 - (bucket + 1) as f32 * height,
+                width,
+                height,
+                opacity,
+                count,
+            ));
+        }
+    }
+
+    // Label each response time bucket by its upper bound.
+    let label = |bound: u64| {
+        if bound < 1_000 {
+            format!("{}ms", bound)
+        } else {
+            format!("{}s", bound / 1_000)
+        }
+    };
+    let mut labels: Vec<String> = heatmap
+        .buckets
+        .iter()
+        .map(|bound| format!("&le;{}", label(*bound)))
+        .collect();
+    labels.push(format!(
+        "&gt;{}",
+        label(heatmap.buckets.last().copied().unwrap_or(0))
+    ));
+    let labels: Vec<String> = labels
+        .iter()
+        .enumerate()
+        .map(|(bucket, text)| {
+            format!(
+                r##"<text x="70" y="{:.1}" fill="#fff" text-anchor="end" font-size="10">{}</text>"##,
+                310.0 - (bucket as f32 + 0.5) * height + 4.0,
+                text,
+            )
+        })
+        .collect();
+
+    format!(
+        r##"<div class="charts-container">
+        <h2>Response Time Heatmap</h2>
+        <svg class="chart" viewBox="0 0 1000 350" preserveAspectRatio="none">
+            {cells}
+            <line x1="80" y1="310" x2="980" y2="310" stroke="#cad9ea" />
+            <line x1="80" y1="20" x2="80" y2="310" stroke="#cad9ea" />
+            {labels}
+            <text x="80" y="330" fill="#fff" text-anchor="middle">0s</text>
+            <text x="980" y="330" fill="#fff" text-anchor="middle">{seconds}s</text>
+        </svg>
+    </div>"##,
+        cells = cells.join("\n            "),
+        labels = labels.join("\n            "),
+        seconds = seconds,
+    )
+}
+
+/// If requests were tracked over time, add charts of the requests and errors during each
+/// second and of how long they took to the html report.
+pub fn request_time_series_template(
+    time_series: &metrics::GooseRequestTimeSeries,
+    annotations: &[metrics::GooseAnnotation],
+) -> String {
+    // Scale the charts to the duration of the load test.
+    let seconds = time_series.seconds.len().max(2) - 1;
+    let points = |values: &[f32], maximum: f32| -> String {
+        values
+            .iter()
+            .take(seconds + 1)
+            .enumerate()
+            .map(|(second, value)| {
+                format!(
+                    "{:.1},{:.1}",
+                    50.0 + second as f32 * 930.0 / seconds as f32,
+                    310.0 - value * 290.0 / maximum,
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    let requests: Vec<f32> = time_series
+        .seconds
+        .iter()
+        .map(|second| second.requests() as f32)
+        .collect();
+    let errors: Vec<f32> = time_series
+        .seconds
+        .iter()
+        .map(|second| second.errors as f32)
+        .collect();
+    let maximum_requests = requests.iter().copied().fold(1.0, f32::max);
+
+    let mean: Vec<f32> = time_series
+        .seconds
+        .iter()
+        .map(|second| second.mean())
+        .collect();
+    // Without individual times, the 95th percentile is unknown and not charted.
+    let percentile_95: Option<Vec<f32>> = time_series
+        .seconds
+        .iter()
+        .map(|second| match second.requests() {
+            0 => Some(0.0),
+            _ => second.percentile(0.95).map(|time| time as f32),
+        })
+        .collect();
+    let maximum_time = mean
+        .iter()
+        .chain(percentile_95.iter().flatten())
+        .copied()
+        .fold(1.0, f32::max)
+        .ceil();
+
+    let percentile_95_line = match percentile_95 {
+        Some(percentile_95) => format!(
+            r##"<polyline points="{points}" fill="none" stroke="#f5a623" stroke-width="2" stroke-dasharray="8 4" />
+            <text x="110" y="25" fill="#f5a623">95%ile</text>"##,
+            points = points(&percentile_95, maximum_time),
+        ),
+        None => "".to_string(),
+    };
+
+    format!(
+        r##"<div class="charts-container">
+        <h2>Requests Per Second</h2>
+        <svg class="chart" viewBox="0 0 1000 350" preserveAspectRatio="none">
+            <line x1="50" y1="310" x2="980" y2="310" stroke="#cad9ea" />
+            <line x1="50" y1="20" x2="50" y2="310" stroke="#cad9ea" />
+            <text x="40" y="25" fill="#fff" text-anchor="end">{maximum_requests}</text>
+            <text x="40" y="310" fill="#fff" text-anchor="end">0</text>
+            <text x="50" y="330" fill="#fff" text-anchor="middle">0s</text>
+            <text x="980" y="330" fill="#fff" text-anchor="middle">{seconds}s</text>
+            <text x="60" y="25" fill="#00ca5a">requests</text>
+            <text x="140" y="25" fill="#d0021b">errors</text>
+            <polyline points="{requests}" fill="none" stroke="#00ca5a" stroke-width="2" />
+            <polyline points="{errors}" fill="none" stroke="#d0021b" stroke-width="2" />
+            {annotations}
+        </svg>
+    </div>
+
+    <div class="charts-container">
+        <h2>Response Times Over Time</h2>
+        <svg class="chart" viewBox="0 0 1000 350" preserveAspectRatio="none">
+            <line x1="50" y1="310" x2="980" y2="310" stroke="#cad9ea" />
+            <line x1="50" y1="20" x2="50" y2="310" stroke="#cad9ea" />
+            <text x="40" y="25" fill="#fff" text-anchor="end">{maximum_time}ms</text>
+            <text x="40" y="310" fill="#fff" text-anchor="end">0ms</text>
+            <text x="50" y="330" fill="#fff" text-anchor="middle">0s</text>
+            <text x="980" y="330" fill="#fff" text-anchor="middle">{seconds}s</text>
+            <text x="60" y="25" fill="#00ca5a">mean</text>
+            {percentile_95_line}
+            <polyline points="{mean}" fill="none" stroke="#00ca5a" stroke-width="2" />
+            {annotations}
+        </svg>
+    </div>"##,
+        maximum_requests = maximum_requests,
+        maximum_time = maximum_time,
+        seconds = seconds,
+        requests = points(&requests, maximum_requests),
+        errors = points(&errors, maximum_requests),
+        mean = points(&mean, maximum_time),
+        percentile_95_line = percentile_95_line,
+        annotations = annotation_markers(annotations, seconds),
+    )
+}
+
 /// Build the html report.
 pub fn build_report(
     users: &str,
     report_range: &str,
     hosts: &str,
+    labels: &str,
+    run_id: &str,
     templates: GooseReportTemplates,
 ) -> String {
     let pkg_name = env!("CARGO_PKG_NAME");
@@ -464,6 +1155,10 @@ pub fn build_report(
             margin-bottom: 30px;
         }}
 
+        .legend span {{
+            margin-right: 15px;
+        }}
+
         .download {{
             float: right;
         }}
@@ -480,10 +1175,21 @@ pub fn build_report(
         <div class="info">
             <p>Users: <span>{users}</span> </p>
             <p>Target Host: <span>{hosts}</span></p>
+            {labels}
+            {run_id}
+            {aborted_template}
             {report_range}
             <p><span><small><em>{pkg_name} v{pkg_version}</em></small></span></pr>
         </div>
 
+        {active_users_template}
+
+        {ramp_profiles_template}
+
+        {phases_template}
+
+        {network_baselines_template}
+
         <div class="requests">
             <h2>Request Metrics</h2>
             <table>
@@ -508,6 +1214,8 @@ pub fn build_report(
 
         {co_requests_template}
 
+        {baseline_comparison_template}
+
         <div class="responses">
             <h2>Response Time Metrics</h2>
             <table>
@@ -531,10 +1239,18 @@ pub fn build_report(
             </table>
         </div>
 
+        {response_time_percentiles_template}
+
         {co_responses_template}
 
+        {response_time_heatmap_template}
+
+        {request_time_series_template}
+
         {status_codes_template}
 
+        {dimensions_template}
+
         {tasks_template}
 
         {errors_template}
@@ -545,6 +1261,8 @@ pub fn build_report(
         users = users,
         report_range = report_range,
         hosts = hosts,
+        labels = labels,
+        run_id = run_id,
         pkg_name = pkg_name,
         pkg_version = pkg_version,
         raw_requests_template = templates.raw_requests_template,
@@ -553,6 +1271,16 @@ pub fn build_report(
         co_responses_template = templates.co_responses_template,
         tasks_template = templates.tasks_template,
         status_codes_template = templates.status_codes_template,
+        dimensions_template = templates.dimensions_template,
         errors_template = templates.errors_template,
+        active_users_template = templates.active_users_template,
+        ramp_profiles_template = templates.ramp_profiles_template,
+        phases_template = templates.phases_template,
+        network_baselines_template = templates.network_baselines_template,
+        baseline_comparison_template = templates.baseline_comparison_template,
+        aborted_template = templates.aborted_template,
+        response_time_percentiles_template = templates.response_time_percentiles_template,
+        response_time_heatmap_template = templates.response_time_heatmap_template,
+        request_time_series_template = templates.request_time_series_template,
     )
 }
@@ -0,0 +1,205 @@
+//! Optional rotation of log files.
+//!
+//! Long soak tests can write logs of many gigabytes. Any log written to a file can
+//! instead be rotated once it grows larger than `--log-rotate-size SIZE` (ie `500M`), or
+//! every `--log-rotate-interval TIME` (ie `1h`), or both. When a log is rotated, the
+//! current file is closed and renamed with a `.1` suffix, earlier rotated files are
+//! renumbered, and a new file is opened with the original name. Only the most recent
+//! `--log-rotate-keep VALUE` rotated files are kept (5 by default), older files are
+//! deleted.
+//!
+//! With `--log-rotate-compress`, rotated files are gzip-compressed and given a `.gz`
+//! suffix. Logs sent to syslog aren't rotated.
+//!
+//! For example, with `--request-log requests.log --log-rotate-size 500M --log-rotate-keep 2`
+//! the request log is written to `requests.log`, and the two most recent 500M segments
+//! are kept in `requests.log.1` and `requests.log.2`.
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::util;
+use crate::GooseConfiguration;
+
+/// How many rotated files of each log are kept by default.
+const DEFAULT_LOG_ROTATE_KEEP: usize = 5;
+
+/// When and how logs written to files are rotated.
+#[derive(Debug, Clone)]
+pub(crate) struct GooseLogRotation {
+    /// Rotate logs larger than this many bytes, if set.
+    size: Option<u64>,
+    /// Rotate logs this often, if set.
+    interval: Option<Duration>,
+    /// How many rotated files of each log are kept.
+    keep: usize,
+    /// Whether rotated files are gzip-compressed.
+    compress: bool,
+}
+impl GooseLogRotation {
+    /// Returns the rotation configured with the `--log-rotate-*` run-time options, or
+    /// `None` if logs aren't rotated.
+    pub(crate) fn new(configuration: &GooseConfiguration) -> Option<Self> {
+        let size = parse_size(&configuration.log_rotate_size);
+        let interval = match util::parse_timespan(&configuration.log_rotate_interval) {
+            0 => None,
+            seconds => Some(Duration::from_secs(seconds as u64)),
+        };
+        if size.is_none() && interval.is_none() {
+            return None;
+        }
+        Some(GooseLogRotation {
+            size,
+            interval,
+            keep: configuration
+                .log_rotate_keep
+                .unwrap_or(DEFAULT_LOG_ROTATE_KEEP),
+            compress: configuration.log_rotate_compress,
+        })
+    }
+
+    /// Whether a log that was opened at `opened` and has had `written` bytes written to
+    /// it is due to be rotated.
+    pub(crate) fn is_due(&self, written: u64, opened: Instant) -> bool {
+        self.size.is_some_and(|size| written >= size)
+            || self
+                .interval
+                .is_some_and(|interval| opened.elapsed() >= interval)
+    }
+
+    /// Renames the closed log to its first rotated file, compressing it if enabled,
+    /// after renumbering earlier rotated files and deleting any beyond those kept.
+    pub(crate) async fn rotate(&self, log_file_path: &str) -> io::Result<()> {
+        let rotation = self.clone();
+        let log_file_path = log_file_path.to_string();
+        // Renaming and compressing large files blocks, so it doesn't run on the async
+        // runtime.
+        tokio::task::spawn_blocking(move || rotation.rotate_files(&log_file_path))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    /// Rotates the files of a log, see [`GooseLogRotation::rotate`].
+    fn rotate_files(&self, log_file_path: &str) -> io::Result<()> {
+        // Delete the oldest rotated file, then shift the others up by one.
+        remove_if_exists(&self.rotated_path(log_file_path, self.keep))?;
+        for index in (1..self.keep).rev() {
+            let rotated_path = self.rotated_path(log_file_path, index);
+            if Path::new(&rotated_path).exists() {
+                fs::rename(&rotated_path, self.rotated_path(log_file_path, index + 1))?;
+            }
+        }
+
+        let first_rotated_path = self.rotated_path(log_file_path, 1);
+        if self.compress {
+            let mut log_file = fs::File::open(log_file_path)?;
+            let mut encoder = GzEncoder::new(
+                fs::File::create(&first_rotated_path)?,
+                Compression::default(),
+            );
+            io::copy(&mut log_file, &mut encoder)?;
+            encoder.finish()?;
+            fs::remove_file(log_file_path)
+        } else {
+            fs::rename(log_file_path, first_rotated_path)
+        }
+    }
+
+    /// The path of a rotated file of a log, ie `requests.log.1` or `requests.log.1.gz`.
+    fn rotated_path(&self, log_file_path: &str, index: usize) -> String {
+        if self.compress {
+            format!("{}.{}.gz", log_file_path, index)
+        } else {
+            format!("{}.{}", log_file_path, index)
+        }
+    }
+}
+
+/// Deletes a file, succeeding if it doesn't exist.
+fn remove_if_exists(path: &str) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Parses a size in bytes, optionally followed by a `K`, `M` or `G` multiple of 1,024 and
+/// an optional `B`, ie `500M` or `2GB`. Returns `None` if the size isn't valid or is 0.
+pub(crate) fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim().to_uppercase();
+    let size = size.strip_suffix('B').unwrap_or(&size);
+    let (number, multiple) = match size.chars().last()? {
+        'K' => (&size[..size.len() - 1], 1024),
+        'M' => (&size[..size.len() - 1], 1024 * 1024),
+        'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    match number.trim().parse::<u64>() {
+        Ok(number) if number > 0 => number.checked_mul(multiple),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn size() {
+        assert_eq!(parse_size("100"), Some(100));
+        assert_eq!(parse_size("10K"), Some(10 * 1024));
+        assert_eq!(parse_size("500M"), Some(500 * 1024 * 1024));
+        assert_eq!(parse_size("500mb"), Some(500 * 1024 * 1024));
+        assert_eq!(parse_size(" 2GB "), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size(""), None);
+        assert_eq!(parse_size("0"), None);
+        assert_eq!(parse_size("M"), None);
+        assert_eq!(parse_size("1T"), None);
+    }
+
+    #[test]
+    fn rotate_files() {
+        let directory = std::env::temp_dir().join(format!("goose-rotate-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let log_file_path = directory.join("requests.log");
+        let log_file_path = log_file_path.to_str().unwrap();
+        let read = |path: &str| fs::read_to_string(path).unwrap();
+
+        let mut rotation = GooseLogRotation {
+            size: Some(1024),
+            interval: None,
+            keep: 2,
+            compress: false,
+        };
+        assert!(!rotation.is_due(1023, Instant::now()));
+        assert!(rotation.is_due(1024, Instant::now()));
+
+        // Only the two most recent rotated files are kept.
+        for segment in ["first", "second", "third"] {
+            fs::write(log_file_path, segment).unwrap();
+            rotation.rotate_files(log_file_path).unwrap();
+        }
+        assert!(!Path::new(log_file_path).exists());
+        assert_eq!(read(&format!("{}.1", log_file_path)), "third");
+        assert_eq!(read(&format!("{}.2", log_file_path)), "second");
+        assert!(!Path::new(&format!("{}.3", log_file_path)).exists());
+
+        // Compressed rotated files are gzipped.
+        rotation.compress = true;
+        fs::write(log_file_path, "fourth").unwrap();
+        rotation.rotate_files(log_file_path).unwrap();
+        let mut decoder = flate2::read::GzDecoder::new(
+            fs::File::open(format!("{}.1.gz", log_file_path)).unwrap(),
+        );
+        let mut decompressed = String::new();
+        decoder.read_to_string(&mut decompressed).unwrap();
+        assert_eq!(decompressed, "fourth");
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
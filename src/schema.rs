@@ -0,0 +1,94 @@
+//! Optional validation of JSON responses against a JSON Schema.
+//!
+//! A JSON Schema describing the expected response can be registered for a request name
+//! with [`GooseAttack::register_json_schema`], sharing the schemas already used by
+//! contract tests. Goose then reads the body of each successful (2xx) response to a
+//! request with that name before returning it, and counts responses that aren't JSON
+//! or don't match the schema as failed requests and as schema violations. Response
+//! times don't include reading or validating the body.
+//!
+//! Requires the `json-schema` compile-time feature.
+//!
+//! [`GooseAttack::register_json_schema`]: ../struct.GooseAttack.html#method.register_json_schema
+
+use jsonschema::JSONSchema;
+use reqwest::Response;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::compression::response_builder;
+use crate::metrics::GooseRequestMetric;
+use crate::GooseError;
+
+/// JSON Schemas shared by all users, keyed by request name.
+pub(crate) type GooseJsonSchemas = Arc<HashMap<String, JSONSchema>>;
+
+// Compile the JSON Schema registered for a request name.
+pub(crate) fn compile(name: &str, schema: &serde_json::Value) -> Result<JSONSchema, GooseError> {
+    JSONSchema::compile(schema).map_err(|error| GooseError::InvalidOption {
+        option: "`GooseAttack::register_json_schema`".to_string(),
+        value: name.to_string(),
+        detail: format!("invalid JSON Schema: {}", error),
+    })
+}
+
+// If a JSON Schema is registered, read the response body and validate it. A new response
+// is returned with the same status, headers and body.
+pub(crate) async fn verify_body(
+    response: Result<Response, reqwest::Error>,
+    schema: Option<&JSONSchema>,
+    request_metric: &mut GooseRequestMetric,
+) -> Result<Response, reqwest::Error> {
+    let schema = match schema {
+        Some(schema) => schema,
+        None => return response,
+    };
+    let response = response?;
+    // Don't validate bodies that already failed integrity verification.
+    if !response.status().is_success() || !request_metric.success {
+        return Ok(response);
+    }
+
+    let builder = response_builder(&response);
+    let body = response.bytes().await?;
+
+    let valid = match serde_json::from_slice::<serde_json::Value>(&body) {
+        Ok(json) => match schema.validate(&json) {
+            Ok(_) => true,
+            Err(errors) => {
+                for error in errors {
+                    debug!(
+                        "{}: schema violation at {}: {}",
+                        request_metric.name, error.instance_path, error
+                    );
+                }
+                false
+            }
+        },
+        Err(e) => {
+            debug!("{}: body is not valid JSON: {}", request_metric.name, e);
+            false
+        }
+    };
+
+    request_metric.schema_valid = Some(valid);
+    if !valid {
+        request_metric.success = false;
+        request_metric.error = format!("schema violation: {}", request_metric.name);
+    }
+
+    // The builder was created from a valid response, so this can't fail.
+    Ok(Response::from(builder.body(body).unwrap()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn compile_schema() {
+        assert!(compile("/", &json!({"type": "object"})).is_ok());
+        assert!(compile("/", &json!({"type": "not-a-type"})).is_err());
+    }
+}
@@ -0,0 +1,548 @@
+//! Raw TCP and UDP requests, for load testing services that don't speak HTTP.
+//!
+//! Goose is primarily an HTTP load testing tool, but many services use simple
+//! request/response protocols over raw sockets, for example Redis-protocol caches or
+//! custom TCP daemons. A [`GooseSocketRequest`] connects to an address, writes a payload,
+//! and optionally reads a response and confirms it contains expected bytes.
+//!
+//! Socket requests are throttled, timed, logged and included in metrics the same as HTTP
+//! requests, showing up with a method of `TCP` or `UDP`.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//!
+//! let mut a_task = task!(ping_redis);
+//!
+//! /// Send a PING to a Redis server, expecting a PONG in response.
+//! async fn ping_redis(user: &mut GooseUser) -> GooseTaskResult {
+//!     let socket_request = GooseSocketRequest::builder()
+//!         .tcp("127.0.0.1:6379")
+//!         .send(b"PING\r\n")
+//!         .expect_response(b"+PONG")
+//!         .name("redis ping")
+//!         .build();
+//!
+//!     let _goose = user.socket_request(socket_request).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use std::{fmt, io};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+
+use crate::goose::{GooseMethod, GooseTaskError, GooseUser};
+use crate::metrics::{GooseRawRequest, GooseRequestMetric, GooseTransportError};
+
+/// The largest UDP datagram that can be received.
+const MAX_DATAGRAM_SIZE: usize = 65_535;
+
+/// The most bytes read from a TCP socket in response to a request.
+const MAX_TCP_RESPONSE_SIZE: usize = 1_048_576;
+
+/// How long a socket request can take, unless a different timeout is set.
+const DEFAULT_SOCKET_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The protocol of a raw socket request.
+///
+/// Socket requests show up in metrics and logs with a method of `TCP` or `UDP`. Their
+/// [`GooseRawRequest`] records the protocol in its `socket` field.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Ord, PartialOrd)]
+pub enum GooseSocketMethod {
+    /// A raw TCP socket request.
+    Tcp,
+    /// A raw UDP socket request.
+    Udp,
+}
+/// Display protocol in upper case.
+impl fmt::Display for GooseSocketMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GooseSocketMethod::Tcp => write!(f, "TCP"),
+            GooseSocketMethod::Udp => write!(f, "UDP"),
+        }
+    }
+}
+
+/// Defines a raw TCP or UDP request that Goose makes.
+///
+/// Can be manually created and configured with [`GooseSocketRequest::builder`], or
+/// more simply by using the [`GooseUser::tcp`] and [`GooseUser::udp`] helpers.
+///
+/// For complete instructions review [`GooseSocketRequestBuilder`].
+#[derive(Debug)]
+pub struct GooseSocketRequest<'a> {
+    // Defaults to [`GooseSocketMethod::Tcp`].
+    method: GooseSocketMethod,
+    // Defaults to `""`.
+    address: &'a str,
+    // Defaults to [`None`].
+    name: Option<&'a str>,
+    // Defaults to `b""`.
+    payload: &'a [u8],
+    // Defaults to `true`.
+    read_response: bool,
+    // Defaults to [`None`].
+    expect_response: Option<&'a [u8]>,
+    // Defaults to 60 seconds.
+    timeout: Duration,
+}
+impl<'a> GooseSocketRequest<'a> {
+    /// Convenience function to bring [`GooseSocketRequestBuilder`] into scope.
+    pub fn builder() -> GooseSocketRequestBuilder<'a> {
+        GooseSocketRequestBuilder::new()
+    }
+}
+
+/// Used to build a [`GooseSocketRequest`] object, necessary to make a raw socket request
+/// with Goose.
+///
+/// It's only necessary to build manually if the [`GooseUser::tcp`] and [`GooseUser::udp`]
+/// helpers don't provide you with enough flexibility.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use std::time::Duration;
+///
+/// let mut a_task = task!(task_function);
+///
+/// /// Send a datagram, giving up on the response after 500 milliseconds.
+/// async fn task_function(user: &mut GooseUser) -> GooseTaskResult {
+///     let socket_request = GooseSocketRequest::builder()
+///         // Send a UDP datagram to this address.
+///         .udp("127.0.0.1:9000")
+///         // The bytes to send.
+///         .send(b"status")
+///         // The response must contain these bytes to be a success.
+///         .expect_response(b"ok")
+///         // Fail if no response is received within 500 milliseconds.
+///         .timeout(Duration::from_millis(500))
+///         // Build the GooseSocketRequest object.
+///         .build();
+///
+///     let _goose = user.socket_request(socket_request).await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct GooseSocketRequestBuilder<'a> {
+    method: GooseSocketMethod,
+    address: &'a str,
+    name: Option<&'a str>,
+    payload: &'a [u8],
+    read_response: bool,
+    expect_response: Option<&'a [u8]>,
+    timeout: Duration,
+}
+impl<'a> GooseSocketRequestBuilder<'a> {
+    // Internal method to build a [`GooseSocketRequest`] from a [`GooseSocketRequestBuilder`].
+    fn new() -> Self {
+        Self {
+            method: GooseSocketMethod::Tcp,
+            address: "",
+            name: None,
+            payload: b"",
+            read_response: true,
+            expect_response: None,
+            timeout: DEFAULT_SOCKET_TIMEOUT,
+        }
+    }
+
+    /// Connect to the address with TCP, for example `127.0.0.1:6379`.
+    pub fn tcp(mut self, address: &'a str) -> Self {
+        self.method = GooseSocketMethod::Tcp;
+        self.address = address;
+        self
+    }
+
+    /// Send a datagram to the address with UDP, for example `127.0.0.1:9000`.
+    pub fn udp(mut self, address: &'a str) -> Self {
+        self.method = GooseSocketMethod::Udp;
+        self.address = address;
+        self
+    }
+
+    /// Set a name for the request, affecting how it shows up in metrics.
+    ///
+    /// Defaults to the name of the task if set, and otherwise to the address.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the bytes to write to the socket.
+    ///
+    /// Defaults to sending nothing, which can be used to time establishing a TCP connection.
+    pub fn send(mut self, payload: &'a [u8]) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    /// Require that the response contains the expected bytes for the request to be
+    /// a success.
+    ///
+    /// With TCP, Goose reads from the socket until the expected bytes are received, the
+    /// server closes the connection, the request times out, or 1 MiB has been read. With
+    /// UDP, Goose reads a single datagram.
+    pub fn expect_response(mut self, expected: &'a [u8]) -> Self {
+        self.expect_response = Some(expected);
+        self.read_response = true;
+        self
+    }
+
+    /// Don't wait for a response, the request is complete as soon as the payload is sent.
+    ///
+    /// By default Goose waits for a response, reading until at least some bytes are received
+    /// or the server closes the connection.
+    pub fn no_response(mut self) -> Self {
+        self.read_response = false;
+        self.expect_response = None;
+        self
+    }
+
+    /// Fail the request if it doesn't complete within the specified duration.
+    ///
+    /// Defaults to 60 seconds. A shorter timeout is recommended when waiting for a UDP
+    /// response, as a lost datagram is never retransmitted.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Build the [`GooseSocketRequest`] to be passed to [`GooseUser::socket_request`].
+    pub fn build(self) -> GooseSocketRequest<'a> {
+        let Self {
+            method,
+            address,
+            name,
+            payload,
+            read_response,
+            expect_response,
+            timeout,
+        } = self;
+        GooseSocketRequest {
+            method,
+            address,
+            name,
+            payload,
+            read_response,
+            expect_response,
+            timeout,
+        }
+    }
+}
+
+/// The response to a [`GooseSocketRequest`].
+#[derive(Debug)]
+pub struct GooseSocketResponse {
+    /// The request that this is a response to.
+    pub request: GooseRequestMetric,
+    /// The bytes received, which are empty if no response was read.
+    pub response: Result<Vec<u8>, io::Error>,
+}
+impl GooseSocketResponse {
+    pub fn new(request: GooseRequestMetric, response: Result<Vec<u8>, io::Error>) -> Self {
+        GooseSocketResponse { request, response }
+    }
+}
+
+impl GooseUser {
+    /// A helper to write bytes to a TCP socket and read the response.
+    ///
+    /// The request is named after the task if set, otherwise after the address, and fails
+    /// if it doesn't complete within 60 seconds. For more control use
+    /// [`GooseUser::socket_request`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut task = task!(tcp_function);
+    ///
+    /// /// A simple task that sends a Redis PING.
+    /// async fn tcp_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.tcp("127.0.0.1:6379", b"PING\r\n").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn tcp(
+        &mut self,
+        address: &str,
+        payload: &[u8],
+    ) -> Result<GooseSocketResponse, GooseTaskError> {
+        let socket_request = GooseSocketRequest::builder()
+            .tcp(address)
+            .send(payload)
+            .build();
+
+        self.socket_request(socket_request).await
+    }
+
+    /// A helper to send a UDP datagram and read the response.
+    ///
+    /// The request is named after the task if set, otherwise after the address, and fails
+    /// if no response is received within 60 seconds. For more control, including setting
+    /// a shorter timeout, use [`GooseUser::socket_request`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut task = task!(udp_function);
+    ///
+    /// /// A simple task that sends a datagram.
+    /// async fn udp_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.udp("127.0.0.1:9000", b"status").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn udp(
+        &mut self,
+        address: &str,
+        payload: &[u8],
+    ) -> Result<GooseSocketResponse, GooseTaskError> {
+        let socket_request = GooseSocketRequest::builder()
+            .udp(address)
+            .send(payload)
+            .build();
+
+        self.socket_request(socket_request).await
+    }
+
+    /// Makes a raw socket request for the provided [`GooseSocketRequest`] object, and if
+    /// metrics are enabled captures relevant metrics.
+    ///
+    /// Socket errors and unexpected responses are recorded as failed requests, and returned
+    /// in the [`GooseSocketResponse`]. As with [`GooseUser::request`], this only returns an
+    /// error if the load test ends while the request is throttled, or if metrics can't be
+    /// sent to the parent thread.
+    pub async fn socket_request(
+        &mut self,
+        request: GooseSocketRequest<'_>,
+    ) -> Result<GooseSocketResponse, GooseTaskError> {
+        // Name the request after the task if not explicitly named, otherwise the address.
+        let request_name = match request.name {
+            Some(rn) => rn.to_string(),
+            None => match &self.task_name {
+                Some(task_name) => task_name.to_string(),
+                None => request.address.to_string(),
+            },
+        };
+
+        // If throttle-requests is enabled...
+        if self.is_throttled && self.throttle.is_some() {
+            // ...wait until there's room to add a token to the throttle channel before proceeding.
+            debug!("GooseUser: waiting on throttle");
+            // Will result in GooseTaskError::RequestCanceled if this fails.
+            self.throttle.clone().unwrap().send_async(true).await?;
+        };
+
+        // Once past the throttle, the request is officially started.
+        let started = Instant::now();
+
+        // If enabled, include the payload in the request log and the debug log.
+        let body = if self.config.request_body {
+            String::from_utf8_lossy(request.payload).to_string()
+        } else {
+            "".to_string()
+        };
+
+        // Sockets are identified by a URL with the protocol as the scheme.
+        let url = format!(
+            "{}://{}",
+            request.method.to_string().to_lowercase(),
+            request.address
+        );
+        // Socket requests have no HTTP method, their protocol is recorded instead.
+        let mut raw_request = GooseRawRequest::new(GooseMethod::Get, &url, vec![], &body);
+        raw_request.socket = Some(request.method);
+
+        // Record information about the request.
+        let mut request_metric = GooseRequestMetric::new(
+            raw_request,
+            &request_name,
+            self.started.elapsed().as_millis(),
+            self.weighted_users_index,
+        );
+
+        // Make the actual request.
+        let exchange = async {
+            match request.method {
+                GooseSocketMethod::Tcp => tcp_exchange(&request).await,
+                GooseSocketMethod::Udp => udp_exchange(&request).await,
+            }
+        };
+        let response = match tokio::time::timeout(request.timeout, exchange).await {
+            Ok(r) => r,
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "socket request timed out",
+            )),
+        };
+        request_metric.set_response_time(started.elapsed().as_millis());
+        request_metric.set_final_url(&url);
+
+        // Determine if the request suceeded or failed.
+        match &response {
+            Ok(bytes) => {
+                if let Some(expected) = request.expect_response {
+                    if !contains(bytes, expected) {
+                        request_metric.success = false;
+                        request_metric.error = format!("unexpected response: {}", request_name);
+                    }
+                }
+            }
+            Err(e) => {
+                warn!("{:?}: {}", &url, e);
+                request_metric.success = false;
+                request_metric.error = format!("{} {}: {}", request.method, request_name, e);
+                request_metric.transport_error = Some(GooseTransportError::from_error(e));
+            }
+        }
+
+        // Socket requests are included in Coordinated Omission Mitigation the same as
+        // HTTP requests.
+        request_metric.user_cadence = self
+            .coordinated_omission_mitigation(&request_metric)
+            .await?;
+
+        // Send a copy of the raw request object to the parent process if
+        // we're tracking metrics.
+        if !self.config.no_metrics {
+            self.send_request_metric_to_parent(request_metric.clone())?;
+        }
+
+        Ok(GooseSocketResponse::new(request_metric, response))
+    }
+}
+
+// Connect to a TCP socket, write the payload, and optionally read the response.
+async fn tcp_exchange(request: &GooseSocketRequest<'_>) -> Result<Vec<u8>, io::Error> {
+    let mut stream = TcpStream::connect(request.address).await?;
+    stream.write_all(request.payload).await?;
+
+    let mut response = Vec::new();
+    if request.read_response {
+        let mut buffer = [0; 4096];
+        loop {
+            let bytes = stream.read(&mut buffer).await?;
+            // The server closed the connection.
+            if bytes == 0 {
+                break;
+            }
+            if response.len() + bytes > MAX_TCP_RESPONSE_SIZE {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("response larger than {} bytes", MAX_TCP_RESPONSE_SIZE),
+                ));
+            }
+            // Only search the new bytes, and the end of the earlier bytes in case the
+            // expected response was split across reads.
+            let searched = match request.expect_response {
+                Some(expected) => response
+                    .len()
+                    .saturating_sub(expected.len().saturating_sub(1)),
+                None => response.len(),
+            };
+            response.extend_from_slice(&buffer[..bytes]);
+            // Without an expected response, the first bytes received are enough.
+            match request.expect_response {
+                Some(expected) if !contains(&response[searched..], expected) => continue,
+                _ => break,
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+// Send the payload as a UDP datagram, and optionally read a single datagram in response.
+async fn udp_exchange(request: &GooseSocketRequest<'_>) -> Result<Vec<u8>, io::Error> {
+    let address = match tokio::net::lookup_host(request.address).await?.next() {
+        Some(a) => a,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "failed to resolve address",
+            ))
+        }
+    };
+    // Bind to an ephemeral port of the same address family as the target.
+    let local_address = if address.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(local_address).await?;
+    socket.connect(address).await?;
+    socket.send(request.payload).await?;
+
+    if request.read_response {
+        let mut buffer = vec![0; MAX_DATAGRAM_SIZE];
+        let bytes = socket.recv(&mut buffer).await?;
+        buffer.truncate(bytes);
+        Ok(buffer)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+// Returns true if the haystack contains the needle.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn socket_request_builder() {
+        let request = GooseSocketRequest::builder().build();
+        assert_eq!(request.method, GooseSocketMethod::Tcp);
+        assert_eq!(request.timeout, DEFAULT_SOCKET_TIMEOUT);
+        assert_eq!(request.address, "");
+        assert!(request.read_response);
+        assert!(request.expect_response.is_none());
+
+        let request = GooseSocketRequest::builder()
+            .udp("127.0.0.1:9000")
+            .send(b"status")
+            .expect_response(b"ok")
+            .timeout(Duration::from_millis(500))
+            .name("status")
+            .build();
+        assert_eq!(request.method, GooseSocketMethod::Udp);
+        assert_eq!(request.address, "127.0.0.1:9000");
+        assert_eq!(request.payload, b"status");
+        assert_eq!(request.expect_response, Some(&b"ok"[..]));
+        assert_eq!(request.timeout, Duration::from_millis(500));
+        assert_eq!(request.name, Some("status"));
+
+        // No longer reading a response clears the expected response.
+        let request = GooseSocketRequest::builder()
+            .tcp("127.0.0.1:6379")
+            .expect_response(b"+PONG")
+            .no_response()
+            .build();
+        assert_eq!(request.method, GooseSocketMethod::Tcp);
+        assert!(!request.read_response);
+        assert!(request.expect_response.is_none());
+    }
+
+    #[test]
+    fn response_contains() {
+        assert!(contains(b"+PONG\r\n", b"+PONG"));
+        assert!(contains(b"+PONG\r\n", b"\r\n"));
+        assert!(contains(b"+PONG\r\n", b""));
+        assert!(!contains(b"+PONG\r\n", b"-ERR"));
+        assert!(!contains(b"", b"+PONG"));
+    }
+}
@@ -0,0 +1,327 @@
+//! Optional SSH tunneling to hosts only reachable through a jump host.
+//!
+//! Staging and internal environments are often only reachable through a bastion. With the
+//! `--ssh-jump-host [USER@]HOST[:PORT]` run-time option (or `GooseDefault::SshJumpHost`)
+//! Goose tunnels requests through the jump host, without any external port-forwarding
+//! scripts. The `--ssh-identity FILE` run-time option (or `GooseDefault::SshIdentity`)
+//! authenticates with a private key, otherwise the SSH agent and SSH configuration of the
+//! current user are used. Password prompts aren't possible.
+//!
+//! Goose starts the `ssh` client installed on the system to forward a local port to each
+//! host and port users make requests to, and all
+//! [`GooseUser`](../goose/struct.GooseUser.html) threads share the same tunnel. Tunnels are
+//! opened as users are created, before the load test starts, so setting them up isn't
+//! included in the metrics. The hostname of each request is resolved to the local end of
+//! the tunnel, so TLS certificates are still verified, but the port in the base_url is
+//! replaced with the local port. Hosts configured as an IP address are replaced with the
+//! local address. Only the base_url of each user is tunneled, requests to other hosts
+//! aren't. Tunnels are closed when the load test finishes.
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use url::Url;
+
+use crate::{GooseConfiguration, GooseError};
+
+/// How long to wait for a tunnel to be ready.
+const TUNNEL_TIMEOUT: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    /// The tunnel started for each host and port.
+    static ref TUNNELS: Mutex<HashMap<(String, u16), GooseSshTunnel>> = Mutex::new(HashMap::new());
+}
+
+/// A local port forwarded through the jump host by an `ssh` process.
+struct GooseSshTunnel {
+    /// The local address of the tunnel.
+    address: SocketAddr,
+    /// The `ssh` process forwarding the local address.
+    process: Child,
+}
+
+/// Validates an SSH jump host formatted as `[USER@]HOST[:PORT]`, returning the arguments
+/// passed to `ssh` to connect to it.
+pub(crate) fn jump_host_args(jump_host: &str) -> Result<Vec<String>, String> {
+    let invalid = || "`configuration.ssh_jump_host` must be formatted as [USER@]HOST[:PORT].";
+
+    let (user, host_port) = match jump_host.rsplit_once('@') {
+        Some((user, host_port)) if !user.is_empty() => (Some(user), host_port),
+        Some(_) => return Err(invalid().to_string()),
+        None => (None, jump_host),
+    };
+    // An IPv6 address must be enclosed in brackets to include a port.
+    let (host, port) = if let Some(bracketed) = host_port.strip_prefix('[') {
+        match bracketed.split_once(']') {
+            Some((host, "")) => (host, None),
+            Some((host, port)) => match port.strip_prefix(':') {
+                Some(port) => (host, Some(port)),
+                None => return Err(invalid().to_string()),
+            },
+            None => return Err(invalid().to_string()),
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) if !host.contains(':') => (host, Some(port)),
+            _ => (host_port, None),
+        }
+    };
+    if host.is_empty() || host.contains(char::is_whitespace) || host.starts_with('-') {
+        return Err(invalid().to_string());
+    }
+
+    let mut args = Vec::new();
+    if let Some(port) = port {
+        match port.parse::<u16>() {
+            Ok(port) if port > 0 => args.extend(["-p".to_string(), port.to_string()]),
+            _ => return Err(invalid().to_string()),
+        }
+    }
+    args.push(match user {
+        Some(user) => format!("{}@{}", user, host),
+        None => host.to_string(),
+    });
+
+    Ok(args)
+}
+
+/// Returns the base_url with its host and port replaced by the local end of the tunnel
+/// to the host, starting the tunnel if this is the first time the host is used.
+pub(crate) fn tunnel_base_url(
+    base_url: &Url,
+    configuration: &GooseConfiguration,
+) -> Result<Url, GooseError> {
+    let invalid = |detail: String| GooseError::InvalidOption {
+        option: "`configuration.ssh_jump_host`".to_string(),
+        value: configuration.ssh_jump_host.to_string(),
+        detail,
+    };
+
+    let (host, port) = match (base_url.host_str(), base_url.port_or_known_default()) {
+        (Some(host), Some(port)) => (host.to_string(), port),
+        _ => {
+            return Err(invalid(format!(
+                "Unable to tunnel {} without a host and port.",
+                base_url
+            )))
+        }
+    };
+    let address = tunnel_address(configuration, &host, port)?;
+
+    let mut tunnel_url = base_url.clone();
+    // Hostnames are resolved to the tunnel, so only addresses need to be replaced.
+    if base_url.domain().is_none() {
+        let _ = tunnel_url.set_ip_host(address.ip());
+    }
+    tunnel_url
+        .set_port(Some(address.port()))
+        .map_err(|_| invalid(format!("Unable to tunnel {}.", base_url)))?;
+
+    Ok(tunnel_url)
+}
+
+/// Resolves each tunneled hostname to the local end of its tunnel.
+pub(crate) fn resolve_tunnels(mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    for ((host, _), tunnel) in TUNNELS.lock().unwrap().iter() {
+        if host.parse::<IpAddr>().is_err() && !host.starts_with('[') {
+            builder = builder.resolve(host, tunnel.address);
+        }
+    }
+    builder
+}
+
+/// Stops all tunnels.
+pub(crate) fn close_tunnels() {
+    for ((host, port), mut tunnel) in TUNNELS.lock().unwrap().drain() {
+        info!("closing SSH tunnel to {}:{}", host, port);
+        let _ = tunnel.process.kill();
+        let _ = tunnel.process.wait();
+    }
+}
+
+/// Returns the local address of the tunnel to the host and port, starting it if this is
+/// the first time they're used.
+fn tunnel_address(
+    configuration: &GooseConfiguration,
+    host: &str,
+    port: u16,
+) -> Result<SocketAddr, GooseError> {
+    let mut tunnels = TUNNELS.lock().unwrap();
+    if let Some(tunnel) = tunnels.get(&(host.to_string(), port)) {
+        return Ok(tunnel.address);
+    }
+
+    let tunnel = open_tunnel(configuration, host, port)?;
+    let address = tunnel.address;
+    tunnels.insert((host.to_string(), port), tunnel);
+
+    Ok(address)
+}
+
+/// Starts `ssh` forwarding a free local port to the host and port through the jump host,
+/// waiting until the tunnel accepts connections.
+fn open_tunnel(
+    configuration: &GooseConfiguration,
+    host: &str,
+    port: u16,
+) -> Result<GooseSshTunnel, GooseError> {
+    let invalid = |detail: String| GooseError::InvalidOption {
+        option: "`configuration.ssh_jump_host`".to_string(),
+        value: configuration.ssh_jump_host.to_string(),
+        detail,
+    };
+
+    // Find a free local port for `ssh` to listen on.
+    let address =
+        TcpListener::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0))?.local_addr()?;
+
+    let args = ssh_args(configuration, address, host, port).map_err(invalid)?;
+    let mut process = Command::new("ssh")
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| invalid(format!("Failed to start ssh: {}", e)))?;
+
+    let started = Instant::now();
+    loop {
+        // The tunnel failed, include why in the error.
+        if let Some(status) = process.try_wait()? {
+            let mut stderr = String::new();
+            if let Some(mut output) = process.stderr.take() {
+                let _ = output.read_to_string(&mut stderr);
+            }
+            return Err(invalid(format!(
+                "SSH tunnel to {}:{} exited with {}: {}",
+                host,
+                port,
+                status,
+                stderr.trim()
+            )));
+        }
+
+        if TcpStream::connect_timeout(&address, Duration::from_millis(100)).is_ok() {
+            break;
+        }
+
+        if started.elapsed() > TUNNEL_TIMEOUT {
+            let _ = process.kill();
+            let _ = process.wait();
+            return Err(invalid(format!(
+                "SSH tunnel to {}:{} wasn't ready after {} seconds.",
+                host,
+                port,
+                TUNNEL_TIMEOUT.as_secs()
+            )));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    // Log anything `ssh` reports while the tunnel is open, such as failing to connect to
+    // the host.
+    if let Some(output) = process.stderr.take() {
+        let tunnel = format!("{}:{}", host, port);
+        std::thread::Builder::new()
+            .name("goose-ssh-tunnel".to_string())
+            .spawn(move || {
+                for line in BufReader::new(output).lines().map_while(Result::ok) {
+                    warn!("SSH tunnel to {}: {}", tunnel, line);
+                }
+            })?;
+    }
+
+    info!(
+        "tunneling {} to {}:{} through {}",
+        address, host, port, configuration.ssh_jump_host
+    );
+
+    Ok(GooseSshTunnel { address, process })
+}
+
+/// Builds the arguments passed to `ssh` to forward the local address to the host and
+/// port through the jump host.
+fn ssh_args(
+    configuration: &GooseConfiguration,
+    address: SocketAddr,
+    host: &str,
+    port: u16,
+) -> Result<Vec<String>, String> {
+    let mut args: Vec<String> = vec![
+        // Only forward a port, don't run a command.
+        "-N".to_string(),
+        // Fail instead of prompting for a password or host key confirmation.
+        "-o".to_string(),
+        "BatchMode=yes".to_string(),
+        "-o".to_string(),
+        "ExitOnForwardFailure=yes".to_string(),
+        "-o".to_string(),
+        "LogLevel=ERROR".to_string(),
+        "-L".to_string(),
+        format!("{}:{}:{}", address, host, port),
+    ];
+    if !configuration.ssh_identity.is_empty() {
+        args.extend([
+            "-i".to_string(),
+            configuration.ssh_identity.to_string(),
+            "-o".to_string(),
+            "IdentitiesOnly=yes".to_string(),
+        ]);
+    }
+    args.extend(jump_host_args(&configuration.ssh_jump_host)?);
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gumdrop::Options;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    #[test]
+    fn jump_host() {
+        assert_eq!(jump_host_args("bastion").unwrap(), vec!["bastion"]);
+        assert_eq!(
+            jump_host_args("deploy@bastion.example.com:2222").unwrap(),
+            vec!["-p", "2222", "deploy@bastion.example.com"]
+        );
+        assert_eq!(
+            jump_host_args("deploy@[2001:db8::1]:2222").unwrap(),
+            vec!["-p", "2222", "deploy@2001:db8::1"]
+        );
+        assert_eq!(jump_host_args("2001:db8::1").unwrap(), vec!["2001:db8::1"]);
+
+        for invalid in [
+            "",
+            "@bastion",
+            "deploy@",
+            "bastion:",
+            "bastion:0",
+            "-oProxyCommand=x",
+        ] {
+            assert!(jump_host_args(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn tunnel_args() {
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.ssh_jump_host = "deploy@bastion:2222".to_string();
+        configuration.ssh_identity = "id_ed25519".to_string();
+        let address = "127.0.0.1:40000".parse().unwrap();
+        assert_eq!(
+            ssh_args(&configuration, address, "app.internal", 443)
+                .unwrap()
+                .join(" "),
+            "-N -o BatchMode=yes -o ExitOnForwardFailure=yes -o LogLevel=ERROR \
+             -L 127.0.0.1:40000:app.internal:443 -i id_ed25519 -o IdentitiesOnly=yes \
+             -p 2222 deploy@bastion"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Optional sink sending request metrics to StatsD.
+//!
+//! Comparing the load generated by Goose with server-side metrics is easier when both
+//! are on the same dashboards. With the `--statsd-host HOST:PORT` run-time option (or
+//! `GooseDefault::StatsdHost`) Goose sends the metrics of each request to StatsD over UDP
+//! as the request completes, tagged in the DogStatsD format so they can be broken down
+//! by request.
+//!
+//! The following metrics are sent, each tagged with the `method` and `name` of the request
+//! and its `status_code`, as well as the `run_id` if set:
+//!  - `goose.requests`: a counter incremented for each request
+//!  - `goose.request_failures`: a counter incremented for each failed request
+//!  - `goose.response_time`: a timer of how many milliseconds each request took
+//!
+//! Metrics are sent on a best-effort basis: as with any StatsD client, metrics that can't
+//! be sent are dropped rather than slowing down the load test.
+
+use std::net::UdpSocket;
+
+use crate::metrics::GooseRequestMetric;
+use crate::GooseError;
+
+/// Sends request metrics to a StatsD server.
+#[derive(Debug)]
+pub(crate) struct GooseStatsd {
+    /// A non-blocking socket connected to the StatsD server.
+    socket: UdpSocket,
+    /// Tags added to every metric, identifying the load test.
+    tags: String,
+}
+impl GooseStatsd {
+    /// Connects to the StatsD server at `HOST:PORT`.
+    pub(crate) fn connect(statsd_host: &str, run_id: &str) -> Result<Self, GooseError> {
+        let invalid = |detail: String| GooseError::InvalidOption {
+            option: "`configuration.statsd_host`".to_string(),
+            value: statsd_host.to_string(),
+            detail,
+        };
+
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .and_then(|socket| {
+                socket.connect(statsd_host)?;
+                socket.set_nonblocking(true)?;
+                Ok(socket)
+            })
+            .map_err(|e| {
+                invalid(format!(
+                    "`configuration.statsd_host` must be a reachable HOST:PORT: {}",
+                    e
+                ))
+            })?;
+        info!("sending request metrics to StatsD at: {}", statsd_host);
+
+        let tags = if run_id.is_empty() {
+            "".to_string()
+        } else {
+            format!(",run_id:{}", escape(run_id))
+        };
+
+        Ok(GooseStatsd { socket, tags })
+    }
+
+    /// Sends the metrics of a completed request. A request first marked successful and
+    /// later updated as failed is only counted again as a failure.
+    pub(crate) fn send_request(&self, request_metric: &GooseRequestMetric) {
+        let packet = render(request_metric, &self.tags);
+        if !packet.is_empty() {
+            if let Err(e) = self.socket.send(packet.as_bytes()) {
+                debug!("failed to send metrics to StatsD: {}", e);
+            }
+        }
+    }
+}
+
+/// Renders the metrics of a request in the DogStatsD format, one metric per line.
+fn render(request_metric: &GooseRequestMetric, tags: &str) -> String {
+    let tags = format!(
+        "|#method:{},name:{},status_code:{}{}",
+        request_metric.raw.method_name(),
+        escape(&request_metric.name),
+        request_metric.status_code,
+        tags
+    );
+
+    let mut metrics = Vec::new();
+    if !request_metric.update {
+        metrics.push(format!("goose.requests:1|c{}", tags));
+        metrics.push(format!(
+            "goose.response_time:{}|ms{}",
+            request_metric.response_time, tags
+        ));
+    }
+    if !request_metric.success {
+        metrics.push(format!("goose.request_failures:1|c{}", tags));
+    }
+    metrics.join("\n")
+}
+
+/// Replaces characters that separate metrics, values and tags in the DogStatsD format.
+fn escape(value: &str) -> String {
+    value.replace([',', '|', '#', '\n'], "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goose::GooseMethod;
+    use crate::metrics::GooseRawRequest;
+
+    #[test]
+    fn render_request() {
+        let mut request_metric = GooseRequestMetric::new(
+            GooseRawRequest::new(GooseMethod::Get, "http://127.0.0.1/", vec![], ""),
+            "/search,all",
+            0,
+            0,
+        );
+        request_metric.status_code = 200;
+        request_metric.response_time = 12;
+        request_metric.success = true;
+        assert_eq!(
+            render(&request_metric, ",run_id:nightly"),
+            "goose.requests:1|c|#method:GET,name:/search_all,status_code:200,run_id:nightly\n\
+             goose.response_time:12|ms|#method:GET,name:/search_all,status_code:200,run_id:nightly"
+        );
+
+        // A request updated as failed is only counted as a failure.
+        request_metric.status_code = 500;
+        request_metric.success = false;
+        request_metric.update = true;
+        assert_eq!(
+            render(&request_metric, ""),
+            "goose.request_failures:1|c|#method:GET,name:/search_all,status_code:500"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Optional syslog destination for logs.
+//!
+//! Any of the Goose logs can be sent to syslog instead of being written to a file, by
+//! configuring a log name starting with `syslog:` followed by one of:
+//!  - `local`: the local syslog daemon, through the `/dev/log` Unix domain socket
+//!  - `udp://HOST[:PORT]`: a remote syslog server over UDP
+//!  - `tcp://HOST[:PORT]`: a remote syslog server over TCP
+//!
+//! For example, to send the request log to a central syslog server:
+//!
+//! ```bash
+//! cargo run --example simple -- --request-log syslog:udp://logs.example.com:514
+//! ```
+//!
+//! The port defaults to 514 if not set. Messages are formatted following
+//! [RFC 5424](https://datatracker.ietf.org/doc/html/rfc5424), with the `local0` facility,
+//! the `info` severity, an app name of `goose`, and a message ID identifying the log
+//! (`debug`, `error`, `request` or `task`). Messages sent over TCP are framed with
+//! octet counting, so multi-line messages such as the `pretty` log format arrive intact.
+
+use async_trait::async_trait;
+use chrono::prelude::*;
+use std::io;
+use tokio::io::AsyncWriteExt;
+#[cfg(unix)]
+use tokio::net::UnixDatagram;
+use tokio::net::{TcpStream, UdpSocket};
+use url::Url;
+
+use crate::logger::{GooseLog, GooseLogSink};
+
+/// Log names starting with this prefix are sent to syslog.
+const SYSLOG_PREFIX: &str = "syslog:";
+
+/// Unix domain socket of the local syslog daemon.
+const LOCAL_SOCKET: &str = "/dev/log";
+
+/// Default port of remote syslog servers.
+const DEFAULT_PORT: u16 = 514;
+
+/// The `local0` facility with the `info` severity.
+const PRIORITY: u8 = 16 * 8 + 6;
+
+/// Where syslog messages are sent.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum GooseSyslogTarget {
+    /// The local syslog daemon.
+    Local,
+    /// A remote syslog server listening on UDP at the given address.
+    Udp(String),
+    /// A remote syslog server listening on TCP at the given address.
+    Tcp(String),
+}
+
+/// Returns the syslog target of a log name, or [`None`] if the log name isn't a syslog
+/// target and is instead a file path.
+pub(crate) fn syslog_target(log_name: &str) -> Option<Result<GooseSyslogTarget, String>> {
+    let target = log_name.strip_prefix(SYSLOG_PREFIX)?;
+    if target == "local" {
+        return Some(Ok(GooseSyslogTarget::Local));
+    }
+
+    let url = match Url::parse(target) {
+        Ok(url) => url,
+        Err(e) => return Some(Err(format!("invalid syslog target {}: {}", target, e))),
+    };
+    let host = match url.host_str() {
+        Some(host) => host,
+        None => return Some(Err(format!("syslog target {} has no host", target))),
+    };
+    let address = format!("{}:{}", host, url.port().unwrap_or(DEFAULT_PORT));
+    Some(match url.scheme() {
+        "udp" => Ok(GooseSyslogTarget::Udp(address)),
+        "tcp" => Ok(GooseSyslogTarget::Tcp(address)),
+        scheme => Err(format!(
+            "unsupported syslog scheme {}, expected: local, udp or tcp",
+            scheme
+        )),
+    })
+}
+
+/// An open connection to syslog.
+enum GooseSyslogConnection {
+    #[cfg(unix)]
+    Local(UnixDatagram),
+    Udp(UdpSocket),
+    Tcp(TcpStream),
+}
+
+/// Sends the messages of one log to syslog.
+pub(crate) struct GooseSyslog {
+    connection: GooseSyslogConnection,
+    /// The RFC 5424 message ID, identifying which log each message belongs to.
+    message_id: String,
+    /// The local hostname, or `-` if it can't be determined.
+    hostname: String,
+}
+impl GooseSyslog {
+    /// Connect to syslog, tagging all messages with the provided message ID.
+    pub(crate) async fn connect(target: &GooseSyslogTarget, message_id: &str) -> io::Result<Self> {
+        let connection = match target {
+            #[cfg(unix)]
+            GooseSyslogTarget::Local => {
+                let socket = UnixDatagram::unbound()?;
+                socket.connect(LOCAL_SOCKET)?;
+                GooseSyslogConnection::Local(socket)
+            }
+            #[cfg(not(unix))]
+            GooseSyslogTarget::Local => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "local syslog is only supported on Unix",
+                ))
+            }
+            GooseSyslogTarget::Udp(address) => {
+                let address = tokio::net::lookup_host(address)
+                    .await?
+                    .next()
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::NotFound, "syslog host not found")
+                    })?;
+                // Bind to an unspecified local address of the same IP version.
+                let socket = if address.is_ipv4() {
+                    UdpSocket::bind("0.0.0.0:0").await?
+                } else {
+                    UdpSocket::bind("[::]:0").await?
+                };
+                socket.connect(address).await?;
+                GooseSyslogConnection::Udp(socket)
+            }
+            GooseSyslogTarget::Tcp(address) => {
+                GooseSyslogConnection::Tcp(TcpStream::connect(address).await?)
+            }
+        };
+
+        Ok(GooseSyslog {
+            connection,
+            message_id: message_id.to_string(),
+            hostname: hostname(),
+        })
+    }
+
+    /// Send a single log message to syslog.
+    pub(crate) async fn send(&mut self, message: &str) -> io::Result<()> {
+        let message = format_message(&self.hostname, &self.message_id, &Utc::now(), message);
+        match &mut self.connection {
+            #[cfg(unix)]
+            GooseSyslogConnection::Local(socket) => socket.send(message.as_bytes()).await?,
+            GooseSyslogConnection::Udp(socket) => socket.send(message.as_bytes()).await?,
+            GooseSyslogConnection::Tcp(stream) => {
+                stream
+                    .write_all(format!("{} {}", message.len(), message).as_bytes())
+                    .await?;
+                0
+            }
+        };
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GooseLogSink for GooseSyslog {
+    async fn write(&mut self, _message: &GooseLog, formatted: &[u8]) -> io::Result<()> {
+        self.send(&String::from_utf8_lossy(formatted)).await
+    }
+
+    /// Flush any messages not yet sent.
+    async fn flush(&mut self) -> io::Result<()> {
+        if let GooseSyslogConnection::Tcp(stream) = &mut self.connection {
+            stream.flush().await?;
+        }
+        Ok(())
+    }
+}
+
+// Format a message following RFC 5424.
+fn format_message(
+    hostname: &str,
+    message_id: &str,
+    timestamp: &DateTime<Utc>,
+    message: &str,
+) -> String {
+    format!(
+        "<{}>1 {} {} goose {} {} - {}",
+        PRIORITY,
+        timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+        hostname,
+        std::process::id(),
+        message_id,
+        message
+    )
+}
+
+// Determine the local hostname without depending on platform-specific APIs.
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.is_empty() && !hostname.contains(' '))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_syslog_target() {
+        // File paths aren't syslog targets.
+        assert_eq!(syslog_target("requests.log"), None);
+
+        assert_eq!(
+            syslog_target("syslog:local"),
+            Some(Ok(GooseSyslogTarget::Local))
+        );
+        assert_eq!(
+            syslog_target("syslog:udp://logs.example.com"),
+            Some(Ok(GooseSyslogTarget::Udp(
+                "logs.example.com:514".to_string()
+            )))
+        );
+        assert_eq!(
+            syslog_target("syslog:tcp://127.0.0.1:6514"),
+            Some(Ok(GooseSyslogTarget::Tcp("127.0.0.1:6514".to_string())))
+        );
+
+        // Invalid targets.
+        assert!(matches!(
+            syslog_target("syslog:http://127.0.0.1"),
+            Some(Err(_))
+        ));
+        assert!(matches!(syslog_target("syslog:remote"), Some(Err(_))));
+    }
+
+    #[test]
+    fn format_syslog_message() {
+        let timestamp = DateTime::parse_from_rfc3339("2021-11-02T15:30:00.123+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            format_message("loadgen", "request", &timestamp, "{\"user\":0}"),
+            format!(
+                "<134>1 2021-11-02T15:30:00.123Z loadgen goose {} request - {{\"user\":0}}",
+                std::process::id()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn send_udp() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let target = GooseSyslogTarget::Udp(server.local_addr().unwrap().to_string());
+        let mut syslog = GooseSyslog::connect(&target, "debug").await.unwrap();
+        syslog.send("hello").await.unwrap();
+
+        let mut buffer = [0; 1024];
+        let length = server.recv(&mut buffer).await.unwrap();
+        let received = String::from_utf8_lossy(&buffer[..length]);
+        assert!(received.starts_with("<134>1 "));
+        assert!(received.ends_with(" debug - hello"));
+    }
+}
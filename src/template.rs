@@ -0,0 +1,469 @@
+//! Request bodies rendered from templates.
+//!
+//! A [`GooseTemplate`] is a request body, for example a JSON or XML payload, containing
+//! `{{placeholder}}`s that are filled in each time the template is rendered. This avoids
+//! building parameterized payloads with long `format!` calls.
+//!
+//! Placeholders are replaced with either:
+//!  - a generated value:
+//!     - `{{random_uuid}}`: a random version 4 UUID
+//!     - `{{random_string}}`: a random 16 character alphanumeric string
+//!     - `{{random_number}}`: a random number from 1 to 1,000,000
+//!     - when compiled with the `faker` feature, `{{faker.name}}`, `{{faker.first_name}}`,
+//!       `{{faker.last_name}}`, `{{faker.email}}`, `{{faker.street_address}}`,
+//!       `{{faker.city}}`, `{{faker.state}}`, `{{faker.postal_code}}`,
+//!       `{{faker.address}}`, `{{faker.sentence}}` and `{{faker.paragraph}}`
+//!  - a value from the context the template is rendered with, a
+//!    [`serde_json::Value`](https://docs.rs/serde_json/*/serde_json/enum.Value.html)
+//!    typically built from per-user session data and other records with the
+//!    [`serde_json::json!`](https://docs.rs/serde_json/*/serde_json/macro.json.html) macro.
+//!    Nested values are separated by dots, for example `{{user.email}}`, and array
+//!    elements are selected by index, for example `{{items.0.sku}}`.
+//!
+//! String values are inserted without quotes, other values are inserted as JSON. Inserted
+//! values are escaped for the payload format, so values containing characters such as `"`
+//! or `<` don't break the payload: templates starting with a JSON object or array are
+//! escaped as JSON, and templates starting with a tag are escaped as XML. Other templates,
+//! for example plain text, insert values without escaping. The escaping can be set
+//! explicitly with [`GooseTemplate::escape`]. Generated values use the [`GooseUser`]'s own
+//! random number generator, so they are reproducible when the load test is run with
+//! `--seed`.
+//!
+//! Templates that can't be parsed or rendered return a [`GooseTemplateError`], which is
+//! converted into a
+//! [`GooseTaskError::InvalidTemplate`](../goose/enum.GooseTaskError.html#variant.InvalidTemplate)
+//! when returned from a task with `?`.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use serde::Serialize;
+//! use serde_json::json;
+//!
+//! #[derive(Serialize)]
+//! struct Session {
+//!     email: String,
+//! }
+//!
+//! let mut a_task = task!(create_order);
+//!
+//! /// Create an order, using the email address stored in this user's session data.
+//! async fn create_order(user: &mut GooseUser) -> GooseTaskResult {
+//!     let template = GooseTemplate::new(
+//!         r#"{"order_id": "{{random_uuid}}", "email": "{{user.email}}"}"#,
+//!     )?;
+//!     let context = json!({ "user": user.get_session_data::<Session>() });
+//!     let body = user.render_template(&template, &context)?;
+//!
+//!     let _goose = user.post("order", body).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use rand::Rng;
+use serde_json::Value;
+use std::fmt;
+
+use crate::goose::GooseUser;
+
+/// A placeholder in a [`GooseTemplate`] couldn't be parsed or rendered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GooseTemplateError {
+    /// The placeholder that couldn't be parsed or rendered.
+    pub placeholder: String,
+    /// Why the placeholder is invalid.
+    pub detail: String,
+}
+impl fmt::Display for GooseTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid template ({}: {})",
+            self.placeholder, self.detail
+        )
+    }
+}
+impl std::error::Error for GooseTemplateError {}
+
+/// Parsed pieces of a template.
+#[derive(Clone, Debug, PartialEq)]
+enum GooseTemplateSegment {
+    /// Text copied into the rendered template unchanged.
+    Text(String),
+    /// The name of a placeholder, without surrounding braces or whitespace.
+    Placeholder(String),
+}
+
+/// How values inserted into a [`GooseTemplate`] are escaped.
+///
+/// By default the escaping is chosen from the content type of the template, see
+/// [`GooseTemplate::new`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GooseTemplateEscape {
+    /// Insert values without escaping.
+    None,
+    /// Escape strings for use inside JSON strings, other values are already JSON.
+    Json,
+    /// Escape all values for use in XML text and attributes.
+    Xml,
+}
+impl GooseTemplateEscape {
+    // The escaping for the content type of a template: JSON if it starts with an object or
+    // array, XML if it starts with a tag, otherwise none.
+    fn for_template(template: &str) -> Self {
+        let template = template.trim_start();
+        if template.starts_with('<') {
+            GooseTemplateEscape::Xml
+        } else if template.starts_with('[')
+            || (template.starts_with('{') && !template.starts_with("{{"))
+        {
+            GooseTemplateEscape::Json
+        } else {
+            GooseTemplateEscape::None
+        }
+    }
+
+    // Escape a string value.
+    fn string(&self, value: &str) -> String {
+        match self {
+            GooseTemplateEscape::None => value.to_string(),
+            GooseTemplateEscape::Json => {
+                // Serializing a string can't fail, strip the surrounding quotes.
+                let quoted = serde_json::to_string(value).unwrap();
+                quoted[1..quoted.len() - 1].to_string()
+            }
+            GooseTemplateEscape::Xml => escape_xml(value),
+        }
+    }
+
+    // Escape a value that was serialized as JSON.
+    fn json(&self, value: &str) -> String {
+        match self {
+            GooseTemplateEscape::None | GooseTemplateEscape::Json => value.to_string(),
+            GooseTemplateEscape::Xml => escape_xml(value),
+        }
+    }
+}
+
+// Escape the characters that are special in XML text and attributes.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A request body template containing `{{placeholder}}`s.
+///
+/// Parse a template once with [`GooseTemplate::new`], then render it as many times as
+/// needed with [`GooseUser::render_template`].
+#[derive(Clone, Debug)]
+pub struct GooseTemplate {
+    segments: Vec<GooseTemplateSegment>,
+    escape: GooseTemplateEscape,
+}
+impl GooseTemplate {
+    /// Set how inserted values are escaped, instead of the escaping for the content type
+    /// of the template.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// // The template is a JSON string, but doesn't start with a JSON object or array.
+    /// let template = GooseTemplate::new(r#""{{user.name}}""#)
+    ///     .unwrap()
+    ///     .escape(GooseTemplateEscape::Json);
+    /// ```
+    pub fn escape(mut self, escape: GooseTemplateEscape) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Parse a template. Returns a [`GooseTemplateError`] if a placeholder is empty or not
+    /// closed.
+    ///
+    /// Inserted values are escaped for the content type of the template: as JSON if it
+    /// starts with a JSON object or array, as XML if it starts with a tag, and otherwise
+    /// not at all. Set the escaping explicitly with [`GooseTemplate::escape`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let template = GooseTemplate::new(r#"{"id": "{{random_uuid}}"}"#).unwrap();
+    /// ```
+    pub fn new(template: &str) -> Result<Self, GooseTemplateError> {
+        let mut segments = Vec::new();
+        let mut remaining = template;
+        while let Some(start) = remaining.find("{{") {
+            if start > 0 {
+                segments.push(GooseTemplateSegment::Text(remaining[..start].to_string()));
+            }
+            let after_start = &remaining[start + 2..];
+            let end = match after_start.find("}}") {
+                Some(end) => end,
+                None => {
+                    return Err(GooseTemplateError {
+                        placeholder: remaining[start..].to_string(),
+                        detail: "placeholder is not closed with }}".to_string(),
+                    });
+                }
+            };
+            let placeholder = after_start[..end].trim();
+            if placeholder.is_empty() {
+                return Err(GooseTemplateError {
+                    placeholder: remaining[start..start + end + 4].to_string(),
+                    detail: "placeholder is empty".to_string(),
+                });
+            }
+            segments.push(GooseTemplateSegment::Placeholder(placeholder.to_string()));
+            remaining = &after_start[end + 2..];
+        }
+        if !remaining.is_empty() {
+            segments.push(GooseTemplateSegment::Text(remaining.to_string()));
+        }
+
+        Ok(GooseTemplate {
+            segments,
+            escape: GooseTemplateEscape::for_template(template),
+        })
+    }
+
+    /// Render the template, replacing each placeholder with a generated value or a value
+    /// from the context. Returns a [`GooseTemplateError`] if a placeholder isn't a
+    /// generated value and isn't found in the context.
+    ///
+    /// Most load tests render templates with [`GooseUser::render_template`] instead, which
+    /// uses the user's own random number generator.
+    pub fn render<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        context: &Value,
+    ) -> Result<String, GooseTemplateError> {
+        let mut rendered = String::new();
+        for segment in &self.segments {
+            match segment {
+                GooseTemplateSegment::Text(text) => rendered.push_str(text),
+                GooseTemplateSegment::Placeholder(placeholder) => {
+                    rendered.push_str(&render_placeholder(rng, placeholder, context, self.escape)?)
+                }
+            }
+        }
+        Ok(rendered)
+    }
+}
+
+// Render a single placeholder, preferring generated values over the context.
+fn render_placeholder<R: Rng + ?Sized>(
+    rng: &mut R,
+    placeholder: &str,
+    context: &Value,
+    escape: GooseTemplateEscape,
+) -> Result<String, GooseTemplateError> {
+    if let Some(generated) = generated_value(rng, placeholder) {
+        return Ok(escape.string(&generated));
+    }
+
+    let mut value = context;
+    for key in placeholder.split('.') {
+        let next = match value {
+            Value::Object(map) => map.get(key),
+            Value::Array(array) => key.parse::<usize>().ok().and_then(|i| array.get(i)),
+            _ => None,
+        };
+        value = match next {
+            Some(v) => v,
+            None => {
+                return Err(GooseTemplateError {
+                    placeholder: placeholder.to_string(),
+                    detail: format!("{} not found in template context", key),
+                });
+            }
+        };
+    }
+
+    Ok(match value {
+        Value::String(s) => escape.string(s),
+        other => escape.json(&other.to_string()),
+    })
+}
+
+// Generate a value for a placeholder, if it names a generated value.
+fn generated_value<R: Rng + ?Sized>(rng: &mut R, placeholder: &str) -> Option<String> {
+    let value = match placeholder {
+        "random_uuid" => crate::random::uuid(rng),
+        "random_string" => crate::random::alphanumeric(rng, 16),
+        "random_number" => rng.gen_range(1..=1_000_000_u32).to_string(),
+        #[cfg(feature = "faker")]
+        "faker.name" => crate::faker::name(rng),
+        #[cfg(feature = "faker")]
+        "faker.first_name" => crate::faker::first_name(rng),
+        #[cfg(feature = "faker")]
+        "faker.last_name" => crate::faker::last_name(rng),
+        #[cfg(feature = "faker")]
+        "faker.email" => crate::faker::email(rng),
+        #[cfg(feature = "faker")]
+        "faker.street_address" => crate::faker::street_address(rng),
+        #[cfg(feature = "faker")]
+        "faker.city" => crate::faker::city(rng),
+        #[cfg(feature = "faker")]
+        "faker.state" => crate::faker::state(rng),
+        #[cfg(feature = "faker")]
+        "faker.postal_code" => crate::faker::postal_code(rng),
+        #[cfg(feature = "faker")]
+        "faker.address" => crate::faker::address(rng),
+        #[cfg(feature = "faker")]
+        "faker.sentence" => crate::faker::sentence(rng),
+        #[cfg(feature = "faker")]
+        "faker.paragraph" => crate::faker::paragraph(rng),
+        _ => return None,
+    };
+    Some(value)
+}
+
+impl GooseUser {
+    /// Render a [`GooseTemplate`] with the provided context, using this user's random
+    /// number generator for generated values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let mut a_task = task!(search);
+    ///
+    /// /// Search for a product.
+    /// async fn search(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let template = GooseTemplate::new("<search><term>{{term}}</term></search>")?;
+    ///     let body = user.render_template(&template, &json!({ "term": "goose" }))?;
+    ///
+    ///     let _goose = user.post("search", body).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn render_template(
+        &mut self,
+        template: &GooseTemplate,
+        context: &Value,
+    ) -> Result<String, GooseTemplateError> {
+        template.render(self.rng(), context)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    #[test]
+    fn parse_template() {
+        let template = GooseTemplate::new("a {{ b }} c{{d}}").unwrap();
+        assert_eq!(
+            template.segments,
+            vec![
+                GooseTemplateSegment::Text("a ".to_string()),
+                GooseTemplateSegment::Placeholder("b".to_string()),
+                GooseTemplateSegment::Text(" c".to_string()),
+                GooseTemplateSegment::Placeholder("d".to_string()),
+            ]
+        );
+
+        // Placeholders must be closed and not empty.
+        assert!(GooseTemplate::new("a {{ b").is_err());
+        assert!(GooseTemplate::new("a {{ }} b").is_err());
+    }
+
+    #[test]
+    fn render_template() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let context = json!({
+            "user": { "email": "goose@example.com", "id": 7, "admin": false },
+            "items": [{ "sku": "A1" }, { "sku": "B2" }],
+        });
+
+        let template = GooseTemplate::new(
+            r#"{"email": "{{user.email}}", "id": {{user.id}}, "admin": {{user.admin}}, "sku": "{{items.1.sku}}"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            template.render(&mut rng, &context).unwrap(),
+            r#"{"email": "goose@example.com", "id": 7, "admin": false, "sku": "B2"}"#
+        );
+
+        // Generated values.
+        let template =
+            GooseTemplate::new("{{random_uuid}} {{random_string}} {{random_number}}").unwrap();
+        let rendered = template.render(&mut rng, &context).unwrap();
+        let values: Vec<&str> = rendered.split(' ').collect();
+        assert_eq!(values[0].len(), 36);
+        assert_eq!(values[1].len(), 16);
+        assert!(values[2].parse::<u32>().is_ok());
+
+        // Placeholders not found in the context are errors.
+        let template = GooseTemplate::new("{{user.name}}").unwrap();
+        assert!(template.render(&mut rng, &context).is_err());
+        let template = GooseTemplate::new("{{items.5.sku}}").unwrap();
+        assert!(template.render(&mut rng, &context).is_err());
+    }
+
+    #[test]
+    fn escape_template() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let context = json!({
+            "name": r#"Mother "Goose" \ <Geese> & Co"#,
+            "tags": ["a<b"],
+        });
+
+        // JSON templates escape strings, the rendered body is valid JSON.
+        let template = GooseTemplate::new(r#"{"name": "{{name}}", "tags": {{tags}}}"#).unwrap();
+        assert_eq!(template.escape, GooseTemplateEscape::Json);
+        let rendered = template.render(&mut rng, &context).unwrap();
+        assert_eq!(
+            rendered,
+            r#"{"name": "Mother \"Goose\" \\ <Geese> & Co", "tags": ["a<b"]}"#
+        );
+        let parsed: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(parsed["name"], context["name"]);
+
+        // XML templates escape all values.
+        let template = GooseTemplate::new(r#"<user name="{{name}}">{{tags}}</user>"#).unwrap();
+        assert_eq!(template.escape, GooseTemplateEscape::Xml);
+        assert_eq!(
+            template.render(&mut rng, &context).unwrap(),
+            r#"<user name="Mother &quot;Goose&quot; \ &lt;Geese&gt; &amp; Co">[&quot;a&lt;b&quot;]</user>"#
+        );
+
+        // Templates of other content types insert values as they are, unless the
+        // escaping is set.
+        let template = GooseTemplate::new("{{name}}").unwrap();
+        assert_eq!(
+            template.render(&mut rng, &context).unwrap(),
+            r#"Mother "Goose" \ <Geese> & Co"#
+        );
+        let template = GooseTemplate::new("[{{tags}}]")
+            .unwrap()
+            .escape(GooseTemplateEscape::None);
+        assert_eq!(template.render(&mut rng, &context).unwrap(), r#"[["a<b"]]"#);
+        let template = GooseTemplate::new(r#"  "{{name}}""#)
+            .unwrap()
+            .escape(GooseTemplateEscape::Json);
+        assert_eq!(
+            template.render(&mut rng, &context).unwrap(),
+            r#"  "Mother \"Goose\" \\ <Geese> & Co""#
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Optional test plan ramping users up and down through multiple steps.
+//!
+//! By default Goose launches all users at the hatch rate, runs them until the run time
+//! expires, and then stops them all at once. With the `--test-plan PLAN` run-time option
+//! (or `GooseDefault::TestPlan`) the number of running users instead follows a plan of
+//! steps separated by semicolons. Each step is written `USERS,TIME`, and changes the
+//! number of running users to `USERS` at an even pace over `TIME`. The load test ends
+//! when the last step completes, unless it takes no time: `10,1m;50,0` launches 10 users
+//! over a minute, then launches 40 more and runs them all until the load test is stopped.
+//!
+//! For example, `10,30s;50,2m;50,10m;0,30s` launches 10 users over 30 seconds, then
+//! launches 40 more over 2 minutes, keeps all 50 running for 10 minutes, and finally
+//! stops them all over 30 seconds.
+//!
+//! The common case of ramping users up, holding them, and ramping them down again doesn't
+//! require writing a test plan: `--users 50 --ramp-up 5m --hold 30m --ramp-down 5m`
+//! follows the same steps as `50,5m;50,30m;0,5m`. Without `--hold` or `--ramp-down`, the
+//! users keep running after ramping up until the load test is stopped.
+//!
+//! Each step is a phase of the load test, named `step 1`, `step 2` and so on, or
+//! `ramp-up`, `hold` and `ramp-down`. When each phase starts it's recorded in
+//! [`GooseMetrics`](../metrics/struct.GooseMetrics.html)`.phases`.
+
+use crate::util;
+use crate::GooseError;
+
+/// The steps of a test plan, each changing the number of running users over time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct GooseTestPlan {
+    /// How many users are running at the end of each step, and how many seconds the
+    /// step takes.
+    pub(crate) steps: Vec<(usize, usize)>,
+    /// The name of each step.
+    pub(crate) names: Vec<String>,
+}
+impl GooseTestPlan {
+    /// Parse a test plan such as `10,30s;50,2m;0,30s`.
+    pub(crate) fn parse(test_plan: &str) -> Result<Self, GooseError> {
+        let invalid = |detail: &str| GooseError::InvalidOption {
+            option: "`configuration.test_plan`".to_string(),
+            value: test_plan.to_string(),
+            detail: detail.to_string(),
+        };
+
+        let mut steps = Vec::new();
+        for step in test_plan.split(';').map(str::trim) {
+            // Allow a trailing semicolon.
+            if step.is_empty() && !steps.is_empty() {
+                continue;
+            }
+            let (users, time) = step.split_once(',').ok_or_else(|| {
+                invalid("each `configuration.test_plan` step must be USERS,TIME, ie 10,30s.")
+            })?;
+            let users = users.trim().parse::<usize>().map_err(|_| {
+                invalid("each `configuration.test_plan` step must start with a number of users.")
+            })?;
+            let time = time.trim();
+            let seconds = util::parse_timespan(time);
+            // An invalid time is parsed as 0 seconds, so only accept 0 if written as such.
+            if seconds == 0
+                && (time.is_empty()
+                    || !time
+                        .trim_start_matches('0')
+                        .trim_end_matches('s')
+                        .is_empty())
+            {
+                return Err(invalid(
+                    "each `configuration.test_plan` step must end with a time, ie 30s, 2m or 1h30m.",
+                ));
+            }
+            steps.push((users, seconds));
+        }
+
+        if steps.iter().all(|(users, _)| *users == 0) {
+            return Err(invalid(
+                "`configuration.test_plan` must run at least 1 user.",
+            ));
+        }
+
+        let names = (1..=steps.len())
+            .map(|step| format!("step {}", step))
+            .collect();
+        Ok(GooseTestPlan { steps, names })
+    }
+
+    /// Build a test plan ramping `users` up over `ramp_up` seconds, holding them for
+    /// `hold` seconds, and ramping them down over `ramp_down` seconds. If neither `hold`
+    /// nor `ramp_down` is set, the users keep running until the load test is stopped.
+    pub(crate) fn ramp_hold_ramp(
+        users: usize,
+        ramp_up: usize,
+        hold: usize,
+        ramp_down: usize,
+    ) -> Self {
+        let mut test_plan = GooseTestPlan::default();
+        let mut add_step = |name: &str, users: usize, seconds: usize| {
+            test_plan.steps.push((users, seconds));
+            test_plan.names.push(name.to_string());
+        };
+        add_step("ramp-up", users, ramp_up);
+        if hold > 0 || ramp_down == 0 {
+            add_step("hold", users, hold);
+        }
+        if ramp_down > 0 {
+            add_step("ramp-down", 0, ramp_down);
+        }
+        test_plan
+    }
+
+    /// The most users running at any point of the test plan.
+    pub(crate) fn max_users(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|(users, _)| *users)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// How many seconds the test plan takes to complete, or 0 if the last step takes no
+    /// time and the load test runs until stopped.
+    pub(crate) fn run_time(&self) -> usize {
+        match self.steps.last() {
+            Some((_, 0)) | None => 0,
+            _ => self.steps.iter().map(|(_, seconds)| seconds).sum(),
+        }
+    }
+
+    /// The index of the step running the given number of milliseconds after the load test
+    /// started. Once the test plan is complete, this is the last step.
+    pub(crate) fn step_at(&self, elapsed: u128) -> usize {
+        let mut step_ends = 0;
+        for (index, (_, seconds)) in self.steps.iter().enumerate() {
+            step_ends += *seconds as u128 * 1_000;
+            if elapsed < step_ends {
+                return index;
+            }
+        }
+        self.steps.len().saturating_sub(1)
+    }
+
+    /// How many users should be running the given number of milliseconds after the load
+    /// test started. Once the test plan is complete, the users of the last step keep
+    /// running.
+    pub(crate) fn users_at(&self, elapsed: u128) -> usize {
+        let mut previous_users = 0;
+        let mut step_started = 0;
+        for (users, seconds) in &self.steps {
+            let step_ends = step_started + *seconds as u128 * 1_000;
+            if elapsed < step_ends {
+                // Change the number of users at an even pace through the step.
+                let progress = (elapsed - step_started) as f64 / (step_ends - step_started) as f64;
+                let change = (*users as f64 - previous_users as f64) * progress;
+                return (previous_users as f64 + change).round() as usize;
+            }
+            previous_users = *users;
+            step_started = step_ends;
+        }
+        previous_users
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let test_plan = GooseTestPlan::parse("10,30s;50,2m;50,1m;0,30s").unwrap();
+        assert_eq!(
+            test_plan.steps,
+            vec![(10, 30), (50, 120), (50, 60), (0, 30)]
+        );
+        assert_eq!(test_plan.max_users(), 50);
+        assert_eq!(test_plan.run_time(), 240);
+
+        // Users ramp up, hold, and then ramp down.
+        assert_eq!(test_plan.users_at(0), 0);
+        assert_eq!(test_plan.users_at(15_000), 5);
+        assert_eq!(test_plan.users_at(30_000), 10);
+        assert_eq!(test_plan.users_at(90_000), 30);
+        assert_eq!(test_plan.users_at(180_000), 50);
+        assert_eq!(test_plan.users_at(225_000), 25);
+        assert_eq!(test_plan.users_at(240_000), 0);
+
+        // Each step is a phase of the load test.
+        assert_eq!(test_plan.names[1], "step 2");
+        assert_eq!(test_plan.step_at(0), 0);
+        assert_eq!(test_plan.step_at(30_000), 1);
+        assert_eq!(test_plan.step_at(225_000), 3);
+        assert_eq!(test_plan.step_at(300_000), 3);
+
+        // A step taking no time changes the number of users right away, and the users of
+        // the last step keep running.
+        let test_plan = GooseTestPlan::parse("20,0; 20,10;").unwrap();
+        assert_eq!(test_plan.steps, vec![(20, 0), (20, 10)]);
+        assert_eq!(test_plan.users_at(0), 20);
+        assert_eq!(test_plan.users_at(60_000), 20);
+        assert_eq!(test_plan.run_time(), 10);
+
+        // The load test runs until stopped if the last step takes no time.
+        let test_plan = GooseTestPlan::parse("10,1m;50,0").unwrap();
+        assert_eq!(test_plan.run_time(), 0);
+        assert_eq!(test_plan.users_at(30_000), 5);
+        assert_eq!(test_plan.users_at(60_000), 50);
+
+        // Users ramp up, hold, and ramp down, or without hold or ramp down keep running.
+        let test_plan = GooseTestPlan::ramp_hold_ramp(50, 300, 1800, 300);
+        assert_eq!(test_plan.steps, vec![(50, 300), (50, 1800), (0, 300)]);
+        assert_eq!(test_plan.names, vec!["ramp-up", "hold", "ramp-down"]);
+        assert_eq!(test_plan.run_time(), 2400);
+        let test_plan = GooseTestPlan::ramp_hold_ramp(50, 0, 0, 60);
+        assert_eq!(test_plan.steps, vec![(50, 0), (0, 60)]);
+        assert_eq!(test_plan.users_at(0), 50);
+        let test_plan = GooseTestPlan::ramp_hold_ramp(50, 60, 0, 0);
+        assert_eq!(test_plan.steps, vec![(50, 60), (50, 0)]);
+        assert_eq!(test_plan.run_time(), 0);
+
+        // Invalid test plans.
+        assert!(GooseTestPlan::parse("").is_err());
+        assert!(GooseTestPlan::parse("10").is_err());
+        assert!(GooseTestPlan::parse("ten,30s").is_err());
+        assert!(GooseTestPlan::parse("10,soon").is_err());
+        assert!(GooseTestPlan::parse("10,").is_err());
+        assert!(GooseTestPlan::parse("0,30s").is_err());
+    }
+}
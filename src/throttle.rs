@@ -1,7 +1,61 @@
+use reqwest::header::HeaderMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::time;
 
 use crate::util;
 
+/// Response headers reporting how many more requests the target server allows.
+const RATE_LIMIT_REMAINING_HEADERS: [&str; 2] = ["x-ratelimit-remaining", "ratelimit-remaining"];
+
+/// Response headers reporting when the target server's rate limit resets.
+const RATE_LIMIT_RESET_HEADERS: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+
+/// Reset values larger than this are a unix timestamp instead of a number of seconds.
+const RATE_LIMIT_RESET_TIMESTAMP: u64 = 1_000_000_000;
+
+/// The adaptive throttle stays this far under the rate allowed by the target server.
+const ADAPTIVE_THROTTLE_MARGIN: f32 = 0.9;
+
+/// A rate limit reported by the target server in response headers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GooseRateLimit {
+    /// How many more requests are allowed before the rate limit resets.
+    pub(crate) remaining: u64,
+    /// How long until the rate limit resets.
+    pub(crate) reset: Duration,
+}
+
+// Parse the first of the named headers that contains a number.
+fn header_value(headers: &HeaderMap, names: &[&str]) -> Option<f64> {
+    names
+        .iter()
+        .filter_map(|name| headers.get(*name))
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|value| value.trim().parse::<f64>().ok())
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+// Determine the rate limit reported by `X-RateLimit-*` or `RateLimit-*` response headers.
+pub(crate) fn rate_limit_from_headers(headers: &HeaderMap) -> Option<GooseRateLimit> {
+    let remaining = header_value(headers, &RATE_LIMIT_REMAINING_HEADERS)? as u64;
+    let reset = header_value(headers, &RATE_LIMIT_RESET_HEADERS)?;
+    // Some servers report when the rate limit resets as a unix timestamp.
+    let reset = if reset as u64 > RATE_LIMIT_RESET_TIMESTAMP {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        (reset - now).max(0.0)
+    } else {
+        reset
+    };
+    // Ignore a reset too far away to be a duration, rather than panicking.
+    Some(GooseRateLimit {
+        remaining,
+        reset: Duration::try_from_secs_f64(reset).ok()?,
+    })
+}
+
 /// This throttle thread limits the maximum number of requests that can be made across
 /// all GooseUser threads. When enabled, GooseUser threads must add a token to the
 /// bounded channel before making a request, and this thread limits how frequently
@@ -68,3 +122,152 @@ pub async fn throttle_main(
         }
     }
 }
+
+/// When `--adaptive-throttle` is enabled this throttle thread is used instead of
+/// [`throttle_main`], removing tokens at up to `throttle_requests` per second but
+/// slowing down to stay just under the most recent rate limit reported by the target
+/// server. If no requests are remaining, no tokens are removed until the rate limit
+/// resets. Returns the average number of requests allowed per second.
+pub async fn adaptive_throttle_main(
+    throttle_requests: usize,
+    throttle_receiver: flume::Receiver<bool>,
+    parent_receiver: flume::Receiver<bool>,
+    rate_limit_receiver: flume::Receiver<GooseRateLimit>,
+) -> f32 {
+    let ten_milliseconds = time::Duration::from_millis(10);
+    let maximum_rate = throttle_requests as f32;
+    let started = time::Instant::now();
+
+    info!(
+        "adaptive throttle allowing up to {} request(s) per second",
+        throttle_requests
+    );
+
+    // The most recent rate limit, and when it was reported.
+    let mut rate_limit: Option<(GooseRateLimit, time::Instant)> = None;
+    // Fractions of a token accumulate until a whole token can be removed.
+    let mut credit: f32 = 0.0;
+    let mut removed: usize = 0;
+    let mut last_tick = time::Instant::now();
+    let mut throttle_drift = time::Instant::now();
+
+    loop {
+        throttle_drift = util::sleep_minus_drift(ten_milliseconds, throttle_drift).await;
+
+        // A message will be received when the load test is over.
+        if parent_receiver.try_recv().is_ok() {
+            // Close throttle channel to prevent any further requests.
+            info!("load test complete, closing throttle channel");
+            drop(throttle_receiver);
+            break;
+        }
+
+        // Only the most recently reported rate limit is used.
+        let now = time::Instant::now();
+        if let Some(latest) = rate_limit_receiver.try_iter().last() {
+            rate_limit = Some((latest, now));
+        }
+
+        let rate = match rate_limit {
+            Some((limit, reported)) => {
+                let reset = limit.reset.saturating_sub(now - reported);
+                if reset.is_zero() {
+                    // The rate limit has reset, so allow the maximum until told otherwise.
+                    maximum_rate
+                } else {
+                    (limit.remaining as f32 / reset.as_secs_f32() * ADAPTIVE_THROTTLE_MARGIN)
+                        .min(maximum_rate)
+                }
+            }
+            None => maximum_rate,
+        };
+        credit += rate * (now - last_tick).as_secs_f32();
+        last_tick = now;
+
+        // Remove tokens from the channel, freeing spots for request to be made.
+        while credit >= 1.0 {
+            // If the channel is empty, we will get an error, so stop trying to remove tokens.
+            if throttle_receiver.try_recv().is_err() {
+                // Unused credit doesn't accumulate into a burst of requests later.
+                credit = credit.fract();
+                break;
+            }
+            credit -= 1.0;
+            removed += 1;
+        }
+    }
+
+    let seconds = started.elapsed().as_secs_f32();
+    if seconds > 0.0 {
+        removed as f32 / seconds
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Build response headers from name-value pairs.
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn rate_limit() {
+        // Both the remaining requests and when the rate limit resets are required.
+        assert_eq!(
+            rate_limit_from_headers(&headers(&[
+                ("x-ratelimit-remaining", "10"),
+                ("x-ratelimit-reset", "5")
+            ])),
+            Some(GooseRateLimit {
+                remaining: 10,
+                reset: Duration::from_secs(5),
+            })
+        );
+        assert_eq!(
+            rate_limit_from_headers(&headers(&[
+                ("ratelimit-remaining", "0"),
+                ("ratelimit-reset", "0.5")
+            ])),
+            Some(GooseRateLimit {
+                remaining: 0,
+                reset: Duration::from_millis(500),
+            })
+        );
+        assert!(rate_limit_from_headers(&headers(&[("x-ratelimit-remaining", "10")])).is_none());
+        assert!(rate_limit_from_headers(&headers(&[("ratelimit-reset", "5")])).is_none());
+        assert!(rate_limit_from_headers(&headers(&[
+            ("x-ratelimit-remaining", "many"),
+            ("x-ratelimit-reset", "5")
+        ]))
+        .is_none());
+
+        // A unix timestamp is converted to how long until the rate limit resets.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let reset = (now + 60).to_string();
+        let mut timestamp = headers(&[("x-ratelimit-remaining", "10")]);
+        timestamp.append("x-ratelimit-reset", reset.parse().unwrap());
+        let rate_limit = rate_limit_from_headers(&timestamp).unwrap();
+        assert!(rate_limit.reset > Duration::from_secs(58));
+        assert!(rate_limit.reset <= Duration::from_secs(60));
+
+        // Values that aren't a valid duration are ignored.
+        for reset in ["inf", "NaN", "1e20", "1e300"] {
+            assert!(rate_limit_from_headers(&headers(&[
+                ("x-ratelimit-remaining", "10"),
+                ("x-ratelimit-reset", reset)
+            ]))
+            .is_none());
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Optional TLS session resumption control and handshake counters.
+//!
+//! When Goose is compiled with the `rustls-tls` feature, each
+//! [`GooseUser`](../goose/struct.GooseUser.html) client is built with a
+//! [`rustls`](https://docs.rs/rustls/) configuration that counts every TLS handshake,
+//! tracking separately how many handshakes attempted to resume a previous session. The
+//! totals are displayed with the final metrics, and are useful when benchmarking TLS
+//! termination capacity.
+//!
+//! Session resumption can be disabled with the `--no-tls-resumption` run-time option, or
+//! with the [`GooseDefault::NoTlsResumption`](../config/enum.GooseDefault.html#variant.NoTlsResumption)
+//! default configuration option, forcing a full handshake each time a new connection is
+//! established.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio_rustls::rustls::client::{
+    ClientSessionMemoryCache, ClientSessionStore, Resumption, ServerName, Tls12ClientSessionValue,
+    Tls12Resumption, Tls13ClientSessionValue,
+};
+use tokio_rustls::rustls::{ClientConfig, NamedGroup, OwnedTrustAnchor, RootCertStore};
+
+use crate::identity::GooseClientCert;
+use crate::GooseConfiguration;
+
+/// How many sessions each [`GooseUser`](../goose/struct.GooseUser.html) remembers,
+/// matching the rustls default.
+const SESSION_CACHE_SIZE: usize = 256;
+
+/// Total number of TLS handshakes started by all GooseUser clients.
+static TLS_HANDSHAKES: AtomicUsize = AtomicUsize::new(0);
+/// Total number of TLS handshakes that attempted to resume a previous session.
+static TLS_RESUMED_HANDSHAKES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the default rustls in-memory session cache, counting handshakes.
+///
+/// Rustls looks for a TLS 1.3 ticket at the start of every handshake, falling back to
+/// looking for a TLS 1.2 session if no ticket is found. A handshake is counted as
+/// resumed if either lookup returns a stored session.
+struct GooseTlsSessionStore {
+    cache: ClientSessionMemoryCache,
+}
+impl GooseTlsSessionStore {
+    fn new() -> Self {
+        GooseTlsSessionStore {
+            cache: ClientSessionMemoryCache::new(SESSION_CACHE_SIZE),
+        }
+    }
+}
+impl ClientSessionStore for GooseTlsSessionStore {
+    fn set_kx_hint(&self, server_name: &ServerName, group: NamedGroup) {
+        self.cache.set_kx_hint(server_name, group);
+    }
+
+    fn kx_hint(&self, server_name: &ServerName) -> Option<NamedGroup> {
+        self.cache.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(&self, server_name: &ServerName, value: Tls12ClientSessionValue) {
+        self.cache.set_tls12_session(server_name, value);
+    }
+
+    fn tls12_session(&self, server_name: &ServerName) -> Option<Tls12ClientSessionValue> {
+        let session = self.cache.tls12_session(server_name);
+        if session.is_some() {
+            TLS_RESUMED_HANDSHAKES.fetch_add(1, Ordering::Relaxed);
+        }
+        session
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName) {
+        self.cache.remove_tls12_session(server_name);
+    }
+
+    fn insert_tls13_ticket(&self, server_name: &ServerName, value: Tls13ClientSessionValue) {
+        self.cache.insert_tls13_ticket(server_name, value);
+    }
+
+    fn take_tls13_ticket(&self, server_name: &ServerName) -> Option<Tls13ClientSessionValue> {
+        // This is always the first lookup performed when starting a new handshake.
+        TLS_HANDSHAKES.fetch_add(1, Ordering::Relaxed);
+        let ticket = self.cache.take_tls13_ticket(server_name);
+        if ticket.is_some() {
+            TLS_RESUMED_HANDSHAKES.fetch_add(1, Ordering::Relaxed);
+        }
+        ticket
+    }
+}
+
+/// Counts handshakes even when session resumption is disabled.
+struct GooseTlsNoSessionStore;
+impl ClientSessionStore for GooseTlsNoSessionStore {
+    fn set_kx_hint(&self, _: &ServerName, _: NamedGroup) {}
+
+    fn kx_hint(&self, _: &ServerName) -> Option<NamedGroup> {
+        None
+    }
+
+    fn set_tls12_session(&self, _: &ServerName, _: Tls12ClientSessionValue) {}
+
+    fn tls12_session(&self, _: &ServerName) -> Option<Tls12ClientSessionValue> {
+        None
+    }
+
+    fn remove_tls12_session(&self, _: &ServerName) {}
+
+    fn insert_tls13_ticket(&self, _: &ServerName, _: Tls13ClientSessionValue) {}
+
+    fn take_tls13_ticket(&self, _: &ServerName) -> Option<Tls13ClientSessionValue> {
+        TLS_HANDSHAKES.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+}
+
+/// Builds the rustls configuration used by each GooseUser client, presenting its client
+/// certificate if assigned one.
+pub(crate) fn client_config(
+    configuration: &GooseConfiguration,
+    client_cert: Option<&GooseClientCert>,
+) -> ClientConfig {
+    let mut root_cert_store = RootCertStore::empty();
+    root_cert_store.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|trust_anchor| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(
+            trust_anchor.subject,
+            trust_anchor.spki,
+            trust_anchor.name_constraints,
+        )
+    }));
+
+    let tls = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(root_cert_store);
+    let mut tls = match client_cert {
+        Some(client_cert) => tls
+            .with_client_auth_cert(client_cert.certs.clone(), client_cert.key.clone())
+            .expect("client certificate key was validated when loaded"),
+        None => tls.with_no_client_auth(),
+    };
+
+    // Offer the same protocols that reqwest offers by default.
+    tls.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    tls.resumption = if configuration.no_tls_resumption {
+        // Disabling the store is not enough, also disable TLS 1.2 session ids and tickets.
+        Resumption::store(Arc::new(GooseTlsNoSessionStore))
+            .tls12_resumption(Tls12Resumption::Disabled)
+    } else {
+        Resumption::store(Arc::new(GooseTlsSessionStore::new()))
+    };
+
+    tls
+}
+
+/// Returns how many full and resumed TLS handshakes have been counted.
+pub(crate) fn handshakes() -> (usize, usize) {
+    let total = TLS_HANDSHAKES.load(Ordering::Relaxed);
+    let resumed = TLS_RESUMED_HANDSHAKES.load(Ordering::Relaxed);
+    (total.saturating_sub(resumed), resumed)
+}
+
+/// Resets the TLS handshake counters, for example when metrics are reset.
+pub(crate) fn reset_handshakes() {
+    TLS_HANDSHAKES.store(0, Ordering::Relaxed);
+    TLS_RESUMED_HANDSHAKES.store(0, Ordering::Relaxed);
+}
@@ -0,0 +1,372 @@
+//! Optional support for load testing HTTP servers listening on a Unix domain socket.
+//!
+//! Sidecars and local daemons often expose an HTTP API on a Unix domain socket instead
+//! of a TCP port. Such a server can be load tested by configuring a host with the
+//! `unix` scheme and the path to the socket, for example:
+//!
+//! ```bash
+//! cargo run --example simple -- --host unix:///var/run/app.sock
+//! ```
+//!
+//! By default requests are made with a pseudo-hostname of `localhost`, sent in the `Host`
+//! header. An alternative pseudo-hostname can be included in the host, for example
+//! `unix://api.internal/var/run/app.sock`.
+//!
+//! The underlying HTTP client only speaks TCP, so each
+//! [`GooseUser`](../goose/struct.GooseUser.html) connects to the socket directly with its
+//! own HTTP/1.1 client. Like the default client it keeps connections alive, sends the
+//! Goose user agent, stores cookies and follows redirects. Features that depend on the
+//! default client can't be used with a Unix domain socket: the load test fails to start
+//! if configured with client certificates or an Accept-Encoding header, and a
+//! [`GooseTaskError::UnixSocket`](../goose/enum.GooseTaskError.html#variant.UnixSocket)
+//! is returned when setting a custom client, sending a streaming request body, or
+//! authenticating with NTLM or Digest credentials. A request that fails without a
+//! response also returns this error, after its failure is recorded in the metrics.
+
+use futures::future::BoxFuture;
+use hyper::body::Bytes;
+use hyper::client::connect::{Connected, Connection};
+use hyper::service::Service;
+use hyper::Uri;
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::{
+    HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, LOCATION, SET_COOKIE, USER_AGENT,
+};
+use reqwest::{Method, Request, Response, ResponseBuilderExt, StatusCode};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::{fmt, io};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::UnixStream;
+use url::Url;
+
+use crate::metrics::GooseTransportError;
+use crate::{GooseConfiguration, GooseError};
+
+/// The pseudo-hostname used when none is included in the host.
+const DEFAULT_PSEUDO_HOSTNAME: &str = "localhost";
+
+/// How many redirects are followed, the same as the default client.
+const MAX_REDIRECTS: usize = 10;
+
+/// Converts a `unix://` host into an `http://` base_url using a pseudo-hostname, returning
+/// the base_url and a client that connects to the Unix domain socket.
+pub(crate) fn unix_client(
+    host: &Url,
+    configuration: &GooseConfiguration,
+    user_agent: &'static str,
+) -> Result<(Url, GooseUnixClient), GooseError> {
+    // Fail rather than silently ignore options that depend on the default client.
+    for (option, value) in [
+        ("--client-certs", &configuration.client_certs),
+        ("--accept-encoding", &configuration.accept_encoding),
+    ] {
+        if !value.is_empty() {
+            return Err(GooseError::InvalidOption {
+                option: option.to_string(),
+                value: value.to_string(),
+                detail: format!("{} can not be used with a Unix domain socket.", option),
+            });
+        }
+    }
+
+    let path = PathBuf::from(host.path());
+    if !path.exists() {
+        return Err(GooseError::InvalidOption {
+            option: "--host".to_string(),
+            value: host.to_string(),
+            detail: format!("Unix domain socket {} does not exist.", path.display()),
+        });
+    }
+
+    let pseudo_hostname = match host.host_str() {
+        Some(h) if !h.is_empty() => h,
+        _ => DEFAULT_PSEUDO_HOSTNAME,
+    };
+    let base_url = Url::parse(&format!("http://{}/", pseudo_hostname)).map_err(|parse_error| {
+        GooseError::InvalidHost {
+            host: host.to_string(),
+            detail: "Invalid pseudo-hostname for Unix domain socket.".to_string(),
+            parse_error,
+        }
+    })?;
+
+    let connector = UnixConnector {
+        path: Arc::new(path),
+    };
+    let client = GooseUnixClient {
+        client: hyper::Client::builder().build(connector),
+        cookies: Arc::new(Jar::default()),
+        user_agent,
+    };
+
+    Ok((base_url, client))
+}
+
+/// An HTTP client that sends all requests to a Unix domain socket.
+#[derive(Clone)]
+pub(crate) struct GooseUnixClient {
+    client: hyper::Client<UnixConnector>,
+    cookies: Arc<Jar>,
+    user_agent: &'static str,
+}
+impl GooseUnixClient {
+    /// Send a request to the Unix domain socket, following redirects.
+    pub(crate) async fn execute(&self, request: Request) -> Result<Response, UnixRequestError> {
+        match request.timeout().copied() {
+            Some(timeout) => match tokio::time::timeout(timeout, self.follow(request)).await {
+                Ok(response) => response,
+                Err(_) => Err(UnixRequestError {
+                    kind: "error sending request",
+                    transport_error: Some(GooseTransportError::Timeout),
+                    detail: "request timed out".to_string(),
+                }),
+            },
+            None => self.follow(request).await,
+        }
+    }
+
+    // Send a request, following redirects to the same pseudo-hostname.
+    async fn follow(&self, request: Request) -> Result<Response, UnixRequestError> {
+        let mut method = request.method().clone();
+        let mut url = request.url().clone();
+        let mut headers = request.headers().clone();
+        let mut body = match request.body().map(|body| body.as_bytes()) {
+            Some(Some(bytes)) => Bytes::copy_from_slice(bytes),
+            Some(None) => {
+                return Err(UnixRequestError::builder(
+                    "streaming request bodies can't be sent to a Unix domain socket",
+                ))
+            }
+            None => Bytes::new(),
+        };
+
+        for _ in 0..=MAX_REDIRECTS {
+            let response = self.send(&method, &url, &headers, body.clone()).await?;
+            let status = response.status();
+            let location = match redirect_location(&response, &url) {
+                Some(location) => location,
+                None => return Ok(response),
+            };
+            // Like browsers, redirects other than 307 and 308 are followed with a GET.
+            if status != StatusCode::TEMPORARY_REDIRECT
+                && status != StatusCode::PERMANENT_REDIRECT
+                && method != Method::HEAD
+            {
+                method = Method::GET;
+                body = Bytes::new();
+                headers.remove(CONTENT_TYPE);
+                headers.remove(CONTENT_LENGTH);
+            }
+            url = location;
+        }
+
+        Err(UnixRequestError {
+            kind: "error following redirect",
+            transport_error: None,
+            detail: format!("too many redirects following {}", url),
+        })
+    }
+
+    // Send a single request, adding the user agent and any stored cookies.
+    async fn send(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &HeaderMap,
+        body: Bytes,
+    ) -> Result<Response, UnixRequestError> {
+        let mut builder = hyper::Request::builder()
+            .method(method.clone())
+            .uri(url.as_str());
+        if let Some(request_headers) = builder.headers_mut() {
+            *request_headers = headers.clone();
+            if !request_headers.contains_key(USER_AGENT) {
+                request_headers.insert(USER_AGENT, HeaderValue::from_static(self.user_agent));
+            }
+            if !request_headers.contains_key(COOKIE) {
+                if let Some(cookies) = self.cookies.cookies(url) {
+                    request_headers.insert(COOKIE, cookies);
+                }
+            }
+        }
+        let request = builder
+            .body(hyper::Body::from(body))
+            .map_err(|e| UnixRequestError::builder(e.to_string()))?;
+
+        let response = self
+            .client
+            .request(request)
+            .await
+            .map_err(|e| UnixRequestError {
+                kind: "error sending request",
+                transport_error: Some(GooseTransportError::from_error(&e)),
+                detail: e.to_string(),
+            })?;
+        self.cookies
+            .set_cookies(&mut response.headers().get_all(SET_COOKIE).iter(), url);
+
+        let (parts, body) = response.into_parts();
+        let mut builder = http::Response::builder()
+            .status(parts.status)
+            .version(parts.version)
+            .url(url.clone());
+        if let Some(response_headers) = builder.headers_mut() {
+            *response_headers = parts.headers;
+        }
+        // The builder was created from a valid response, so this can't fail.
+        Ok(Response::from(builder.body(body).unwrap()))
+    }
+}
+
+/// Why a request to a Unix domain socket failed without a response.
+#[derive(Debug)]
+pub(crate) struct UnixRequestError {
+    /// What failed, worded like the errors of the default client.
+    kind: &'static str,
+    /// The kind of transport-level failure, if the request failed in transit.
+    pub(crate) transport_error: Option<GooseTransportError>,
+    /// Why the request failed.
+    detail: String,
+}
+impl UnixRequestError {
+    // The request couldn't be built, so was never sent.
+    fn builder<D: Into<String>>(detail: D) -> Self {
+        UnixRequestError {
+            kind: "builder error",
+            transport_error: None,
+            detail: detail.into(),
+        }
+    }
+
+    /// Describe the failure of the named request, as recorded in the request metrics.
+    pub(crate) fn describe(&self, request_name: &str) -> String {
+        format!("{} {}: {}", self.kind, request_name, self.detail)
+    }
+}
+impl fmt::Display for UnixRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.kind, self.detail)
+    }
+}
+
+// Returns where a response redirects to, if it's a redirect to the same pseudo-hostname.
+fn redirect_location(response: &Response, url: &Url) -> Option<Url> {
+    match response.status() {
+        StatusCode::MOVED_PERMANENTLY
+        | StatusCode::FOUND
+        | StatusCode::SEE_OTHER
+        | StatusCode::TEMPORARY_REDIRECT
+        | StatusCode::PERMANENT_REDIRECT => (),
+        _ => return None,
+    }
+    let location = response.headers().get(LOCATION)?.to_str().ok()?;
+    let location = url.join(location).ok()?;
+    if location.origin() == url.origin() {
+        Some(location)
+    } else {
+        None
+    }
+}
+
+/// Connects the HTTP client to a Unix domain socket, whatever the requested URI.
+#[derive(Clone)]
+struct UnixConnector {
+    path: Arc<PathBuf>,
+}
+impl Service<Uri> for UnixConnector {
+    type Response = UnixConnection;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, Result<UnixConnection, io::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let path = self.path.clone();
+        Box::pin(async move {
+            UnixStream::connect(path.as_path())
+                .await
+                .map(UnixConnection)
+        })
+    }
+}
+
+/// A connection to a Unix domain socket.
+struct UnixConnection(UnixStream);
+impl Connection for UnixConnection {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+impl AsyncRead for UnixConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+impl AsyncWrite for UnixConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gumdrop::Options;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    #[tokio::test]
+    async fn unix_client() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+
+        // Sockets that don't exist are invalid.
+        let host = Url::parse("unix:///goose-socket-does-not-exist.sock").unwrap();
+        assert!(super::unix_client(&host, &configuration, "goose").is_err());
+
+        // Requests fail when the socket can't be connected to.
+        let path = std::env::temp_dir().join(format!("goose-unix-{}.sock", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+        let host = Url::parse(&format!("unix://api.internal{}", path.display())).unwrap();
+        let (base_url, client) = super::unix_client(&host, &configuration, "goose").unwrap();
+        assert_eq!(base_url.as_str(), "http://api.internal/");
+        let request = Request::new(Method::GET, base_url);
+        let error = client.execute(request).await.unwrap_err();
+        assert_eq!(
+            error.transport_error,
+            Some(GooseTransportError::ConnectionRefused)
+        );
+        assert!(error
+            .describe("GET /")
+            .starts_with("error sending request GET /: "));
+
+        // Options that depend on the default client are rejected.
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.accept_encoding = "identity".to_string();
+        assert!(super::unix_client(&host, &configuration, "goose").is_err());
+        let mut configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        configuration.client_certs = "certs".to_string();
+        assert!(super::unix_client(&host, &configuration, "goose").is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
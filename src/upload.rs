@@ -0,0 +1,216 @@
+//! Streaming request bodies for large uploads.
+//!
+//! A request body is normally held in memory, which isn't practical when load testing
+//! endpoints that accept uploads of several gigabytes. A [`GooseUpload`] is instead
+//! streamed in chunks as the request is sent, either read from a file or produced by a
+//! generator, so only one chunk is held in memory at a time. It can be passed anywhere a
+//! request body is accepted, for example to [`GooseUser::post`].
+//!
+//! The body is sent with chunked transfer encoding, in chunks of 64 KiB by default,
+//! which can be changed with [`GooseUpload::set_chunk_size`]. The upload rate can be
+//! limited with [`GooseUpload::set_rate`], emulating clients that upload more slowly than
+//! the load generator's network allows. The response time includes the whole upload.
+//!
+//! Streamed bodies aren't included in the request log or the debug log, and requests
+//! with a streamed body can't be retried, for example to complete NTLM or Digest
+//! authentication.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use goose::upload::GooseUpload;
+//!
+//! let mut a_task = task!(upload_image);
+//! let mut another_task = task!(upload_generated);
+//!
+//! /// Upload a disk image from a file, at no more than 10 MB per second.
+//! async fn upload_image(user: &mut GooseUser) -> GooseTaskResult {
+//!     let upload = GooseUpload::file("disk.img").set_rate(10_000_000);
+//!     let _goose = user.post("upload", upload).await?;
+//!
+//!     Ok(())
+//! }
+//!
+//! /// Upload 1 GiB of generated data in chunks of 1 MiB.
+//! async fn upload_generated(user: &mut GooseUser) -> GooseTaskResult {
+//!     let upload = GooseUpload::generator(1 << 30, |chunk| chunk.fill(b'x'))
+//!         .set_chunk_size(1 << 20);
+//!     let _goose = user.post("upload", upload).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`GooseUser::post`]: ../goose/struct.GooseUser.html#method.post
+
+use futures::stream;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use tokio::time::Instant;
+
+/// The default size of each chunk of a streamed body.
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A function that fills each chunk of a generated body.
+type GooseUploadGenerator = Box<dyn FnMut(&mut [u8]) + Send + Sync>;
+
+/// Where the chunks of a streamed body are read from.
+enum GooseUploadSource {
+    /// Read from a file, opened when the request is sent.
+    File(PathBuf),
+    /// Produced by a generator, up to the given size in bytes.
+    Generator(u64, GooseUploadGenerator),
+}
+
+/// A request body streamed from a file or a generator.
+///
+/// Convert it into a [`reqwest::Body`] by passing it to a request, for example with
+/// [`GooseUser::post`](../goose/struct.GooseUser.html#method.post).
+pub struct GooseUpload {
+    source: GooseUploadSource,
+    chunk_size: usize,
+    rate: Option<u64>,
+}
+impl GooseUpload {
+    /// Stream the body from a file.
+    ///
+    /// The file is opened when the request is sent. If it can't be read, the request
+    /// fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::upload::GooseUpload;
+    ///
+    /// let upload = GooseUpload::file("disk.img");
+    /// ```
+    pub fn file<P: Into<PathBuf>>(path: P) -> Self {
+        GooseUpload {
+            source: GooseUploadSource::File(path.into()),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            rate: None,
+        }
+    }
+
+    /// Stream a body of `size` bytes produced by a generator.
+    ///
+    /// The generator is called to fill each chunk, which is passed to it zeroed. The last
+    /// chunk can be shorter than the configured chunk size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::upload::GooseUpload;
+    ///
+    /// // Upload 100 MB of zeroes.
+    /// let upload = GooseUpload::generator(100_000_000, |_chunk| {});
+    /// ```
+    pub fn generator<F>(size: u64, generator: F) -> Self
+    where
+        F: FnMut(&mut [u8]) + Send + Sync + 'static,
+    {
+        GooseUpload {
+            source: GooseUploadSource::Generator(size, Box::new(generator)),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            rate: None,
+        }
+    }
+
+    /// Set the size in bytes of each chunk, defaulting to 64 KiB.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::upload::GooseUpload;
+    ///
+    /// let upload = GooseUpload::file("disk.img").set_chunk_size(1024 * 1024);
+    /// ```
+    pub fn set_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Limit the upload to `bytes_per_second`, defaulting to no limit.
+    ///
+    /// Chunks are delayed as needed to stay within the limit, so smaller chunks upload
+    /// at a steadier rate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::upload::GooseUpload;
+    ///
+    /// // Upload at no more than 1 MB per second.
+    /// let upload = GooseUpload::file("disk.img").set_rate(1_000_000);
+    /// ```
+    pub fn set_rate(mut self, bytes_per_second: u64) -> Self {
+        self.rate = Some(bytes_per_second).filter(|rate| *rate > 0);
+        self
+    }
+
+    // Read the next chunk, or None once the body is complete.
+    async fn next_chunk(&mut self, file: &mut Option<File>) -> Option<io::Result<Vec<u8>>> {
+        match &mut self.source {
+            GooseUploadSource::File(path) => {
+                if file.is_none() {
+                    match File::open(&path).await {
+                        Ok(opened) => *file = Some(opened),
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                let mut chunk = vec![0; self.chunk_size];
+                match file.as_mut()?.read(&mut chunk).await {
+                    Ok(0) => None,
+                    Ok(length) => {
+                        chunk.truncate(length);
+                        Some(Ok(chunk))
+                    }
+                    Err(e) => Some(Err(e)),
+                }
+            }
+            GooseUploadSource::Generator(remaining, generator) => {
+                if *remaining == 0 {
+                    return None;
+                }
+                let length = (*remaining).min(self.chunk_size as u64);
+                *remaining -= length;
+                let mut chunk = vec![0; length as usize];
+                generator(&mut chunk);
+                Some(Ok(chunk))
+            }
+        }
+    }
+}
+
+/// The state of a body being streamed.
+struct GooseUploadState {
+    upload: GooseUpload,
+    file: Option<File>,
+    started: Option<Instant>,
+    sent: u64,
+}
+
+impl From<GooseUpload> for reqwest::Body {
+    fn from(upload: GooseUpload) -> reqwest::Body {
+        let state = GooseUploadState {
+            upload,
+            file: None,
+            started: None,
+            sent: 0,
+        };
+        let chunks = stream::unfold(state, |mut state| async move {
+            let started = *state.started.get_or_insert_with(Instant::now);
+            // If limiting the rate, wait until the chunks already sent are due.
+            if let Some(rate) = state.upload.rate {
+                let due = started + Duration::from_secs_f64(state.sent as f64 / rate as f64);
+                tokio::time::sleep_until(due).await;
+            }
+            let GooseUploadState { upload, file, .. } = &mut state;
+            let chunk = upload.next_chunk(file).await?;
+            if let Ok(chunk) = &chunk {
+                state.sent += chunk.len() as u64;
+            }
+            Some((chunk, state))
+        });
+        reqwest::Body::wrap_stream(chunks)
+    }
+}
@@ -12,7 +12,7 @@ pub(crate) async fn user_main(
     mut thread_user: GooseUser,
     thread_receiver: flume::Receiver<GooseUserCommand>,
     worker: bool,
-) {
+) -> GooseUser {
     if worker {
         info!(
             "[{}] launching user {} from {}...",
@@ -27,6 +27,38 @@ pub(crate) async fn user_main(
         );
     }
 
+    // Emulate the task set's network conditions, if any.
+    thread_user.network_profile = thread_task_set.network_profile.clone();
+
+    // Back off after failed requests, if configured.
+    thread_user.error_backoff = thread_task_set.error_backoff;
+
+    // Add the task set's default headers and query parameters to each request.
+    thread_user.default_headers = thread_task_set.default_headers.clone();
+    thread_user.default_query = thread_task_set.default_query.clone();
+
+    // Assign a device profile, if configured.
+    thread_user.assign_device_profile();
+
+    // Assign a cohort, if configured.
+    thread_user.assign_cohort();
+
+    // Assign a client certificate, if configured.
+    thread_user.assign_client_cert();
+
+    // Users running a disabled task set idle until the task set is enabled.
+    let mut disabled = thread_task_set.disabled;
+    if wait_while_disabled(&thread_receiver, &mut disabled).await {
+        info!(
+            "exiting user {} from {} without starting...",
+            thread_number, thread_task_set.name
+        );
+        return thread_user;
+    }
+
+    // Optionally resolve the host and open connections before running any task.
+    thread_user.warm_up().await;
+
     // User is starting, first invoke the weighted on_start tasks.
     if !thread_task_set.weighted_on_start_tasks.is_empty() {
         // Tasks are already weighted and scheduled, execute each in order.
@@ -38,7 +70,7 @@ pub(crate) async fn user_main(
                 thread_number, thread_task_name, thread_task_set.name
             );
             // Invoke the task function.
-            let _todo = invoke_task_function(
+            let _success = invoke_task_function(
                 function,
                 &mut thread_user,
                 *thread_task_index,
@@ -53,34 +85,87 @@ pub(crate) async fn user_main(
         // When there is a delay between tasks, wake every second to check for messages.
         let one_second = Duration::from_secs(1);
 
+        // When the current pass through a group of tasks started, how long the load test had
+        // been running, and whether all of its tasks have succeeded so far.
+        let mut group_pass: Option<(time::Instant, u128, bool)> = None;
+
         'launch_tasks: loop {
             // Tracks the time it takes to loop through all GooseTasks when Coordinated Omission
             // Mitigation is enabled.
             thread_user.update_request_cadence(thread_number).await;
 
-            for (thread_task_index, thread_task_name) in &thread_task_set.weighted_tasks {
+            // Each pass through the tasks writes with a new idempotency key.
+            thread_user.idempotency_key = None;
+
+            for (position, (thread_task_index, thread_task_name)) in
+                thread_task_set.weighted_tasks.iter().enumerate()
+            {
+                // If the task set has been disabled, idle until it is enabled again. Time
+                // spent idle is tracked as sleep for Coordinated Omission Mitigation.
+                if disabled {
+                    let idle_timer = time::Instant::now();
+                    if wait_while_disabled(&thread_receiver, &mut disabled).await {
+                        break 'launch_tasks;
+                    }
+                    thread_user.slept += (time::Instant::now() - idle_timer).as_millis() as u64;
+                }
+
                 // Determine which task we're going to run next.
                 let function = &thread_task_set.tasks[*thread_task_index].function;
                 debug!(
                     "[user {}]: launching {} task from {}",
                     thread_number, thread_task_name, thread_task_set.name
                 );
+                // Start timing a pass through a group with its first task.
+                if group_pass.is_none() && thread_task_set.tasks[*thread_task_index].group.is_some()
+                {
+                    group_pass = Some((
+                        time::Instant::now(),
+                        thread_user.started.elapsed().as_millis(),
+                        true,
+                    ));
+                }
                 // Invoke the task function.
-                let _todo = invoke_task_function(
+                let success = invoke_task_function(
                     function,
                     &mut thread_user,
                     *thread_task_index,
                     thread_task_name,
                 )
                 .await;
+                if let Some((_, _, group_success)) = group_pass.as_mut() {
+                    *group_success &= success;
+                }
+                // The pass through the group is complete after its last task.
+                if let Some(Some(group_index)) = thread_task_set.weighted_group_ends.get(position) {
+                    if let Some((started, elapsed, success)) = group_pass.take() {
+                        send_group_metric(
+                            &thread_user,
+                            &thread_task_set,
+                            *group_index,
+                            started,
+                            elapsed,
+                            success,
+                        );
+                    }
+                }
 
-                if received_exit(&thread_receiver) {
+                if received_exit(&thread_receiver, &mut disabled) {
                     break 'launch_tasks;
                 }
 
-                // If the task_wait is defined, wait for a random time between tasks.
-                if let Some((min, max)) = thread_task_set.task_wait {
-                    let wait_time = rand::thread_rng().gen_range(min..max).as_millis();
+                // If a wait time provider is defined, wait as long as it returns, otherwise if
+                // the task_wait is defined, wait for a random time between tasks. Users
+                // backing off after failed requests wait longer.
+                let mut wait_time = thread_user.backoff_wait().as_millis();
+                if let Some(provider) = &thread_task_set.wait_time_provider {
+                    let task = &thread_task_set.tasks[*thread_task_index];
+                    let elapsed = thread_user.started.elapsed();
+                    wait_time += provider.wait_time(&thread_user, task, elapsed).as_millis();
+                } else if let Some((min, max)) = thread_task_set.task_wait {
+                    wait_time += thread_user.rng.gen_range(min..max).as_millis();
+                }
+                if wait_time > 0 {
                     // Counter to track how long we've slept, waking regularly to check for messages.
                     let mut slept: u128 = 0;
                     // Wake every second to check if the parent thread has told us to exit.
@@ -89,7 +174,7 @@ pub(crate) async fn user_main(
                     let sleep_timer = time::Instant::now();
 
                     while in_sleep_loop {
-                        if received_exit(&thread_receiver) {
+                        if received_exit(&thread_receiver, &mut disabled) {
                             break 'launch_tasks;
                         }
 
@@ -101,10 +186,11 @@ pub(crate) async fn user_main(
                             }
                             one_second
                         } else {
-                            slept += wait_time;
+                            let remaining = wait_time - slept;
+                            slept = wait_time;
                             // Break out of sleep loop after next sleep.
                             in_sleep_loop = false;
-                            Duration::from_millis((wait_time - slept) as u64)
+                            Duration::from_millis(remaining as u64)
                         };
 
                         debug!(
@@ -133,7 +219,7 @@ pub(crate) async fn user_main(
                 thread_number, thread_task_name, thread_task_set.name
             );
             // Invoke the task function.
-            let _todo = invoke_task_function(
+            let _success = invoke_task_function(
                 function,
                 &mut thread_user,
                 *thread_task_index,
@@ -157,10 +243,14 @@ pub(crate) async fn user_main(
             thread_number, thread_task_set.name
         );
     }
+
+    // Return the user, so its clients and session data can be reused by a later load test.
+    thread_user
 }
 
-// Determine if the parent has sent a GooseUserCommand::Exit message.
-fn received_exit(thread_receiver: &flume::Receiver<GooseUserCommand>) -> bool {
+// Determine if the parent has sent a GooseUserCommand::Exit message, tracking whether the
+// task set has been disabled or enabled.
+fn received_exit(thread_receiver: &flume::Receiver<GooseUserCommand>, disabled: &mut bool) -> bool {
     let mut message = thread_receiver.try_recv();
     while message.is_ok() {
         match message.unwrap() {
@@ -168,8 +258,13 @@ fn received_exit(thread_receiver: &flume::Receiver<GooseUserCommand>) -> bool {
             GooseUserCommand::Exit => {
                 return true;
             }
-            command => {
-                debug!("ignoring unexpected GooseUserCommand: {:?}", command);
+            // The task set has been disabled.
+            GooseUserCommand::Wait => {
+                *disabled = true;
+            }
+            // The task set has been enabled.
+            GooseUserCommand::Run => {
+                *disabled = false;
             }
         }
         message = thread_receiver.try_recv();
@@ -178,13 +273,30 @@ fn received_exit(thread_receiver: &flume::Receiver<GooseUserCommand>) -> bool {
     false
 }
 
-// Invoke the task function, collecting task metrics.
+// Wait while the task set is disabled. Returns true if the parent sent a
+// GooseUserCommand::Exit message while waiting.
+async fn wait_while_disabled(
+    thread_receiver: &flume::Receiver<GooseUserCommand>,
+    disabled: &mut bool,
+) -> bool {
+    while *disabled {
+        match thread_receiver.recv_async().await {
+            Ok(GooseUserCommand::Run) => *disabled = false,
+            Ok(GooseUserCommand::Wait) => (),
+            // Exit if told to, or if the parent has gone away.
+            Ok(GooseUserCommand::Exit) | Err(_) => return true,
+        }
+    }
+    false
+}
+
+// Invoke the task function, collecting task metrics. Returns whether the task succeeded.
 async fn invoke_task_function(
     function: &GooseTaskFunction,
     thread_user: &mut GooseUser,
     thread_task_index: usize,
     thread_task_name: &str,
-) -> Result<(), flume::SendError<Option<GooseLog>>> {
+) -> bool {
     let started = time::Instant::now();
     let mut raw_task = GooseTaskMetric::new(
         thread_user.started.elapsed().as_millis(),
@@ -204,13 +316,14 @@ async fn invoke_task_function(
 
     // Exit if all metrics or task metrics are disabled.
     if thread_user.config.no_metrics || thread_user.config.no_task_metrics {
-        return Ok(());
+        return success;
     }
 
     // If tasks-file is enabled, send a copy of the raw task metric to the logger thread.
     if !thread_user.config.task_log.is_empty() {
         if let Some(logger) = thread_user.logger.as_ref() {
-            logger.send(Some(GooseLog::Task(raw_task.clone())))?;
+            // Best effort logging.
+            let _ = logger.send(Some(GooseLog::Task(raw_task.clone())));
         }
     }
 
@@ -220,5 +333,34 @@ async fn invoke_task_function(
         let _ = parent.send(GooseMetric::Task(raw_task));
     }
 
-    Ok(())
+    success
+}
+
+// Send the metric timing a completed pass through a group of tasks to the parent.
+fn send_group_metric(
+    thread_user: &GooseUser,
+    thread_task_set: &GooseTaskSet,
+    group_index: usize,
+    started: time::Instant,
+    elapsed: u128,
+    success: bool,
+) {
+    // Exit if all metrics or task metrics are disabled.
+    if thread_user.config.no_metrics || thread_user.config.no_task_metrics {
+        return;
+    }
+
+    let mut raw_group = GooseTaskMetric::new(
+        elapsed,
+        thread_user.task_sets_index,
+        group_index,
+        thread_task_set.groups[group_index].name.to_string(),
+        thread_user.weighted_users_index,
+    );
+    raw_group.set_time(started.elapsed().as_millis(), success);
+
+    if let Some(parent) = thread_user.channel_to_parent.as_ref() {
+        // Best effort metrics.
+        let _ = parent.send(GooseMetric::Group(raw_group));
+    }
 }
@@ -1,11 +1,12 @@
 //! Utility functions used by Goose, and available when writing load tests.
 
+use lazy_static::lazy_static;
 use regex::Regex;
 use std::cmp::{max, min};
 use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, Once};
 use std::time;
 use url::Url;
 
@@ -397,31 +398,153 @@ pub fn is_valid_host(host: &str) -> Result<bool, GooseError> {
     Ok(true)
 }
 
+// Internal helper to split a "NAME: VALUE" header into its name and value.
+pub(crate) fn parse_header(header: &str) -> Option<(&str, &str)> {
+    let (name, value) = header.split_once(':')?;
+    let name = name.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some((name, value.trim()))
+    }
+}
+
+/// Where a cache buster adds a unique value to each request.
+#[derive(Debug, PartialEq)]
+pub(crate) enum CacheBuster<'a> {
+    /// A "NAME={}" query parameter.
+    Query(&'a str, &'a str),
+    /// A "NAME: {}" header.
+    Header(&'a str, &'a str),
+}
+
+// Internal helper to split a cache buster into a "NAME: {}" header if a colon comes before
+// any equals sign, otherwise a "NAME={}" query parameter.
+pub(crate) fn parse_cache_buster(pattern: &str) -> Option<CacheBuster<'_>> {
+    let header = match (pattern.find(':'), pattern.find('=')) {
+        (Some(colon), Some(equals)) => colon < equals,
+        (colon, _) => colon.is_some(),
+    };
+    if header {
+        parse_header(pattern).map(|(name, value)| CacheBuster::Header(name, value))
+    } else {
+        parse_label(pattern).map(|(name, value)| CacheBuster::Query(name, value))
+    }
+}
+
+// Internal helper to validate a cache buster, returning why it's invalid.
+pub(crate) fn validate_cache_buster(pattern: &str) -> Result<(), String> {
+    let (name, value) =
+        match parse_cache_buster(pattern) {
+            Some(CacheBuster::Header(name, value)) => {
+                if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+                    || reqwest::header::HeaderValue::from_str(value).is_err()
+                {
+                    return Err(
+                        "`configuration.cache_buster` must be a valid header name and value."
+                            .to_string(),
+                    );
+                }
+                (name, value)
+            }
+            Some(CacheBuster::Query(name, value)) => (name, value),
+            None => return Err(
+                "`configuration.cache_buster` must be formatted as \"NAME={}\" or \"NAME: {}\"."
+                    .to_string(),
+            ),
+        };
+    if name.contains("{}") || !value.contains("{}") {
+        return Err(
+            "`configuration.cache_buster` must include {} in its value, replaced with a unique value for each request."
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+// Internal helper to split a "KEY=VALUE" label into its key and value.
+pub(crate) fn parse_label(label: &str) -> Option<(&str, &str)> {
+    let (key, value) = label.split_once('=')?;
+    let key = key.trim();
+    if key.is_empty() {
+        None
+    } else {
+        Some((key, value.trim()))
+    }
+}
+
+lazy_static! {
+    // The cancel flag of the running load test, replaced each time a load test starts so
+    // ctrl-c also stops load tests run one after another in the same process.
+    static ref CTRLC_CANCELED: Mutex<Arc<AtomicBool>> =
+        Mutex::new(Arc::new(AtomicBool::new(false)));
+}
+
+// The ctrl-c handler can only be set once per process.
+static CTRLC_HANDLER: Once = Once::new();
+
 // Internal helper to configure the control-c handler. Shutdown cleanly on the first
 // ctrl-c. Exit abruptly on the second ctrl-c.
 pub(crate) fn setup_ctrlc_handler(canceled: &Arc<AtomicBool>) {
-    let caught_ctrlc = canceled.clone();
-    match ctrlc::set_handler(move || {
-        // We've caught a ctrl-c, determine if it's the first time or an additional time.
-        if caught_ctrlc.load(Ordering::SeqCst) {
-            warn!("caught another ctrl-c, exiting immediately...");
-            std::process::exit(1);
-        } else {
-            warn!("caught ctrl-c, stopping...");
-            caught_ctrlc.store(true, Ordering::SeqCst);
-        }
-    }) {
-        Ok(_) => (),
-        Err(e) => {
-            info!("failed to set ctrl-c handler: {}", e);
+    *CTRLC_CANCELED.lock().unwrap() = canceled.clone();
+    CTRLC_HANDLER.call_once(|| {
+        match ctrlc::set_handler(move || {
+            let caught_ctrlc = CTRLC_CANCELED.lock().unwrap().clone();
+            // We've caught a ctrl-c, determine if it's the first time or an additional time.
+            if caught_ctrlc.load(Ordering::SeqCst) {
+                warn!("caught another ctrl-c, exiting immediately...");
+                std::process::exit(1);
+            } else {
+                warn!("caught ctrl-c, stopping...");
+                caught_ctrlc.store(true, Ordering::SeqCst);
+            }
+        }) {
+            Ok(_) => (),
+            Err(e) => {
+                info!("failed to set ctrl-c handler: {}", e);
+            }
         }
-    }
+    });
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn cache_buster() {
+        assert_eq!(
+            parse_cache_buster("cb={}"),
+            Some(CacheBuster::Query("cb", "{}"))
+        );
+        assert_eq!(
+            parse_cache_buster("X-Cache-Buster: {}"),
+            Some(CacheBuster::Header("X-Cache-Buster", "{}"))
+        );
+        // Whichever separator comes first decides what is added.
+        assert_eq!(
+            parse_cache_buster("x-bust: v={}"),
+            Some(CacheBuster::Header("x-bust", "v={}"))
+        );
+        assert_eq!(
+            parse_cache_buster("t=12:{}"),
+            Some(CacheBuster::Query("t", "12:{}"))
+        );
+
+        assert!(validate_cache_buster("cb={}").is_ok());
+        assert!(validate_cache_buster("X-Cache-Buster: run-{}").is_ok());
+        for invalid in [
+            "cb",
+            "=x{}",
+            "cb=static",
+            "{}=1",
+            "bad header: {}",
+            "x-bust: \u{7}{}",
+        ] {
+            assert!(validate_cache_buster(invalid).is_err(), "{}", invalid);
+        }
+    }
+
     #[test]
     fn timespan() {
         assert_eq!(parse_timespan("0"), 0);
@@ -0,0 +1,261 @@
+//! Optional validation of response bodies in the background.
+//!
+//! Expensive validation, such as verifying checksums of large downloads or validating
+//! JSON responses against a schema, slows down the
+//! [`GooseUser`](../goose/struct.GooseUser.html) that performs it, so fewer requests are
+//! made than configured. With [`GooseUser::validate_in_background`] a response body is
+//! instead handed to a bounded pool of background validators, and the user immediately
+//! continues with its next request. Response times never include validation.
+//!
+//! The pool starts one validator per CPU core, which can be changed with the
+//! `--validation-workers VALUE` run-time option (or
+//! [`GooseDefault::ValidationWorkers`](../config/enum.GooseDefault.html#variant.ValidationWorkers)).
+//! If validators can't keep up, up to 1,000 response bodies wait to be validated, after
+//! which users wait for room before continuing.
+//!
+//! A validator is any function or closure that receives the body and returns an error
+//! describing why the body isn't valid. Validators for common checks are included in this
+//! module. A request whose body isn't valid is counted as a failed request, the same as
+//! with [`GooseUser::set_failure`](../goose/struct.GooseUser.html#method.set_failure).
+//! All bodies are validated before the final metrics are displayed.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use goose::validate;
+//!
+//! let mut a_task = task!(loadtest_download);
+//!
+//! /// Download a release, verifying its checksum in the background.
+//! async fn loadtest_download(user: &mut GooseUser) -> GooseTaskResult {
+//!     let goose = user.get("release.tar.gz").await?;
+//!     user.validate_in_background(
+//!         goose,
+//!         validate::sha1("2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"),
+//!     )
+//!     .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use sha1::{Digest, Sha1};
+
+use crate::goose::{GooseResponse, GooseTaskResult, GooseUser};
+use crate::metrics::{GooseMetric, GooseRequestMetric};
+
+/// How many response bodies wait to be validated before users wait for room.
+const VALIDATION_QUEUE_SIZE: usize = 1_000;
+
+/// A response body waiting to be validated.
+pub(crate) struct GooseValidation {
+    /// The request the body is a response to, updated if the body isn't valid.
+    request_metric: GooseRequestMetric,
+    /// Validates the body.
+    validate: Box<dyn FnOnce() -> Result<(), String> + Send>,
+    /// Channel sending the updated request to the parent, if tracking metrics.
+    channel_to_parent: Option<flume::Sender<GooseMetric>>,
+}
+
+/// Sends response bodies to the validators, or `None` to tell a validator to exit.
+pub(crate) type GooseValidationTx = flume::Sender<Option<GooseValidation>>;
+
+/// Spawns the validators, returning the channel used to send them response bodies and
+/// their join handles.
+pub(crate) fn spawn_validators(
+    validation_workers: usize,
+) -> (GooseValidationTx, Vec<tokio::task::JoinHandle<()>>) {
+    let (validation_tx, validation_rx) = flume::bounded(VALIDATION_QUEUE_SIZE);
+    let handles = (0..validation_workers)
+        .map(|_| {
+            let validation_rx = validation_rx.clone();
+            // Validation can be CPU intensive, so it doesn't run on the async runtime.
+            tokio::task::spawn_blocking(move || validation_main(validation_rx))
+        })
+        .collect();
+    (validation_tx, handles)
+}
+
+/// Tells each validator to exit once all queued response bodies are validated, and waits
+/// for them to exit.
+pub(crate) async fn stop_validators(
+    validation_tx: GooseValidationTx,
+    handles: Vec<tokio::task::JoinHandle<()>>,
+) {
+    for _ in &handles {
+        let _ = validation_tx.send_async(None).await;
+    }
+    futures::future::join_all(handles).await;
+}
+
+/// Validates response bodies until told to exit.
+fn validation_main(validation_rx: flume::Receiver<Option<GooseValidation>>) {
+    // Exit when told to, or if the parent went away.
+    while let Ok(Some(validation)) = validation_rx.recv() {
+        run_validation(validation);
+    }
+}
+
+/// Validates a response body, updating its request as failed if it isn't valid.
+fn run_validation(validation: GooseValidation) {
+    let GooseValidation {
+        mut request_metric,
+        validate,
+        channel_to_parent,
+    } = validation;
+    if let Err(detail) = validate() {
+        info!("validation failed: {}: {}", request_metric.name, detail);
+        // Only send an update if this was previously a success.
+        if request_metric.success {
+            request_metric.success = false;
+            request_metric.update = true;
+            request_metric.error = format!("validation failed: {}", detail);
+            if let Some(channel_to_parent) = channel_to_parent {
+                let _ = channel_to_parent.send(GooseMetric::Request(Box::new(request_metric)));
+            }
+        }
+    }
+}
+
+impl GooseUser {
+    /// Reads the body of a response, and validates it in the background.
+    ///
+    /// The body is validated by a pool of background validators, so validation doesn't
+    /// slow down this user. If the `validator` returns an error, the request is counted
+    /// as a failed request with the error. Nothing is validated if the request failed
+    /// without a response. See the [`validate`](../validate/index.html) module for
+    /// details and an example.
+    ///
+    /// An error is only returned if the body can't be read.
+    pub async fn validate_in_background<F>(
+        &self,
+        goose: GooseResponse,
+        validator: F,
+    ) -> GooseTaskResult
+    where
+        F: FnOnce(&[u8]) -> Result<(), String> + Send + 'static,
+    {
+        let response = match goose.response {
+            Ok(response) => response,
+            Err(_) => return Ok(()),
+        };
+        let body = response.bytes().await?;
+
+        let validation = GooseValidation {
+            request_metric: goose.request,
+            validate: Box::new(move || validator(&body)),
+            channel_to_parent: if self.config.no_metrics {
+                None
+            } else {
+                self.channel_to_parent.clone()
+            },
+        };
+
+        match self.validation.as_ref() {
+            // Wait for room in the queue if the validators are falling behind.
+            Some(validation_tx) => {
+                if let Err(flume::SendError(Some(validation))) =
+                    validation_tx.send_async(Some(validation)).await
+                {
+                    // The validators exited, validate the body here.
+                    run_validation(validation);
+                }
+            }
+            // Validators only run during the load test, validate the body here.
+            None => run_validation(validation),
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a validator verifying the SHA-1 checksum of the body, formatted as hex.
+pub fn sha1(expected: &str) -> impl FnOnce(&[u8]) -> Result<(), String> + Send + 'static {
+    let expected = expected.trim().to_lowercase();
+    move |body: &[u8]| {
+        let sha1 = format!("{:x}", Sha1::digest(body));
+        if sha1 == expected {
+            Ok(())
+        } else {
+            Err(format!("body checksum {} expected {}", sha1, expected))
+        }
+    }
+}
+
+/// Returns a validator verifying the length of the body is within a range of bytes,
+/// inclusive.
+pub fn length(min: usize, max: usize) -> impl FnOnce(&[u8]) -> Result<(), String> + Send + 'static {
+    move |body: &[u8]| {
+        if body.len() < min || body.len() > max {
+            Err(format!(
+                "body length {} not within {}-{}",
+                body.len(),
+                min,
+                max
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns a validator verifying the body is JSON, and passing the parsed JSON to the
+/// `validator`, for example to verify it matches a schema.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use goose::validate;
+///
+/// let mut a_task = task!(loadtest_api);
+///
+/// /// Load the users API, verifying it returns a list of users in the background.
+/// async fn loadtest_api(user: &mut GooseUser) -> GooseTaskResult {
+///     let goose = user.get("api/users").await?;
+///     user.validate_in_background(
+///         goose,
+///         validate::json(|json| match json["users"].as_array() {
+///             Some(_) => Ok(()),
+///             None => Err("users is not a list".to_string()),
+///         }),
+///     )
+///     .await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub fn json<F>(validator: F) -> impl FnOnce(&[u8]) -> Result<(), String> + Send + 'static
+where
+    F: FnOnce(&serde_json::Value) -> Result<(), String> + Send + 'static,
+{
+    move |body: &[u8]| match serde_json::from_slice(body) {
+        Ok(json) => validator(&json),
+        Err(e) => Err(format!("body is not valid JSON: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validators() {
+        let body = b"The quick brown fox jumps over the lazy dog";
+
+        assert!(sha1("2fd4e1c67a2d28fced849ee1bb76e7391b93eb12")(body).is_ok());
+        assert!(sha1(" 2FD4E1C67A2D28FCED849EE1BB76E7391B93EB12 ")(body).is_ok());
+        assert!(sha1("da39a3ee5e6b4b0d3255bfef95601890afd80709")(body).is_err());
+
+        assert!(length(43, 43)(body).is_ok());
+        assert!(length(1, 42)(body).is_err());
+        assert!(length(44, 100)(body).is_err());
+
+        assert!(json(|_| Ok(()))(body).is_err());
+        assert!(json(|json| match json["ok"].as_bool() {
+            Some(true) => Ok(()),
+            _ => Err("not ok".to_string()),
+        })(br#"{"ok": true}"#)
+        .is_ok());
+        assert!(json(|_| Err("invalid".to_string()))(b"[]").is_err());
+    }
+}
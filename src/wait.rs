@@ -0,0 +1,218 @@
+//! Pluggable wait times between tasks.
+//!
+//! By default a [`GooseUser`] runs its tasks back to back, or pauses for a random time
+//! between a minimum and maximum after each task if configured with
+//! [`GooseTaskSet::set_wait_time`](../goose/struct.GooseTaskSet.html#method.set_wait_time).
+//! Load tests that need more realistic think times implement the [`GooseWaitTime`] trait,
+//! and assign it to a task set with
+//! [`GooseTaskSet::set_wait_time_provider`](../goose/struct.GooseTaskSet.html#method.set_wait_time_provider).
+//! After each task, users running the task set then pause for as long as the provider
+//! returns, for example a think time that depends on the time of day, or on the task that
+//! just ran.
+//!
+//! Goose includes [`GooseReplayedWaitTime`], which replays a list of recorded gaps between
+//! requests, for example extracted from a production access log.
+//!
+//! The `--replay-speed SPEED` run-time option (or `GooseDefault::ReplaySpeed`) replays
+//! recorded timings faster or slower, so the same recorded day of traffic can be compressed
+//! or stretched: with `--replay-speed 2` recorded gaps are halved, and with
+//! `--replay-speed 0.5` they are doubled. Custom providers replaying recorded timings apply
+//! the same speed with [`replay_duration`].
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use goose::wait::GooseWaitTime;
+//! use std::time::Duration;
+//!
+//! /// Users think longer after loading the about page.
+//! struct ThinkTime;
+//! impl GooseWaitTime for ThinkTime {
+//!     fn wait_time(&self, _user: &GooseUser, task: &GooseTask, _elapsed: Duration) -> Duration {
+//!         match task.name.as_str() {
+//!             "about" => Duration::from_secs(5),
+//!             _ => Duration::from_millis(500),
+//!         }
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), GooseError> {
+//!     let task_set = taskset!("LoadTestTasks")
+//!         .set_wait_time_provider(ThinkTime)
+//!         .register_task(task!(loadtest_about).set_name("about"));
+//!
+//!     Ok(())
+//! }
+//!
+//! async fn loadtest_about(user: &mut GooseUser) -> GooseTaskResult {
+//!     let _goose = user.get("/about").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::goose::{GooseTask, GooseUser};
+
+/// Computes how long a [`GooseUser`] pauses after running a task.
+pub trait GooseWaitTime: Send + Sync {
+    /// Returns how long `user` pauses after running `task`. `elapsed` is how long the user
+    /// has been running.
+    fn wait_time(&self, user: &GooseUser, task: &GooseTask, elapsed: Duration) -> Duration;
+}
+
+/// Wait time providers aren't part of the load test hash, like task functions.
+impl Hash for dyn GooseWaitTime {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Scales a recorded duration by the `--replay-speed` run-time option, returning the
+/// recorded duration unchanged if it's not set.
+///
+/// # Example
+/// ```rust
+/// use goose::prelude::*;
+/// use goose::wait::{replay_duration, GooseWaitTime};
+/// use std::time::Duration;
+///
+/// /// Replays the recorded think time of each task.
+/// struct RecordedThinkTime;
+/// impl GooseWaitTime for RecordedThinkTime {
+///     fn wait_time(&self, user: &GooseUser, task: &GooseTask, _elapsed: Duration) -> Duration {
+///         match task.name.as_str() {
+///             "checkout" => replay_duration(user, Duration::from_millis(12_400)),
+///             _ => replay_duration(user, Duration::from_millis(3_100)),
+///         }
+///     }
+/// }
+/// ```
+pub fn replay_duration(user: &GooseUser, recorded: Duration) -> Duration {
+    match user
+        .config
+        .replay_speed
+        .as_ref()
+        .and_then(|speed| speed.parse::<f64>().ok())
+    {
+        // Durations too long to represent are capped at u32::MAX seconds.
+        Some(speed) => {
+            Duration::from_secs_f64((recorded.as_secs_f64() / speed).min(u32::MAX as f64))
+        }
+        None => recorded,
+    }
+}
+
+/// Replays recorded gaps between requests as wait times, in order, starting again from the
+/// first gap after the last. The gaps are shared by all users running the task set, so
+/// together they pause for the recorded gaps in the order they were recorded. The gaps are
+/// scaled by the `--replay-speed` run-time option.
+pub struct GooseReplayedWaitTime {
+    /// The recorded gaps between requests.
+    gaps: Vec<Duration>,
+    /// The index of the next gap to replay.
+    next: AtomicUsize,
+}
+impl GooseReplayedWaitTime {
+    /// Replay the gaps between requests, for example extracted from a production access
+    /// log.
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use goose::wait::GooseReplayedWaitTime;
+    /// use std::time::Duration;
+    ///
+    /// let gaps = vec![Duration::from_millis(1200), Duration::from_millis(300)];
+    /// let task_set = taskset!("LoadTestTasks")
+    ///     .set_wait_time_provider(GooseReplayedWaitTime::new(gaps));
+    /// ```
+    pub fn new(gaps: Vec<Duration>) -> Self {
+        GooseReplayedWaitTime {
+            gaps,
+            next: AtomicUsize::new(0),
+        }
+    }
+}
+impl GooseWaitTime for GooseReplayedWaitTime {
+    fn wait_time(&self, user: &GooseUser, _task: &GooseTask, _elapsed: Duration) -> Duration {
+        if self.gaps.is_empty() {
+            return Duration::from_secs(0);
+        }
+        let next = self.next.fetch_add(1, Ordering::Relaxed);
+        replay_duration(user, self.gaps[next % self.gaps.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::GooseConfiguration;
+    use crate::goose::GooseTaskResult;
+    use gumdrop::Options;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    async fn noop(_user: &mut GooseUser) -> GooseTaskResult {
+        Ok(())
+    }
+
+    #[test]
+    fn replayed() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let user =
+            GooseUser::single("http://localhost:8080".parse().unwrap(), &configuration).unwrap();
+        let task = crate::task!(noop);
+        let elapsed = Duration::from_secs(0);
+
+        // Gaps are replayed in order, starting again after the last.
+        let replayed =
+            GooseReplayedWaitTime::new(vec![Duration::from_millis(100), Duration::from_millis(5)]);
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_millis(5)
+        );
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_millis(100)
+        );
+
+        // Without gaps there's no wait.
+        let replayed = GooseReplayedWaitTime::new(Vec::new());
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_secs(0)
+        );
+    }
+
+    #[test]
+    fn replay_speed() {
+        let configuration =
+            GooseConfiguration::parse_args_default(&["--replay-speed", "2"]).unwrap();
+        let mut user =
+            GooseUser::single("http://localhost:8080".parse().unwrap(), &configuration).unwrap();
+        let task = crate::task!(noop);
+        let elapsed = Duration::from_secs(0);
+
+        // Recorded gaps are replayed twice as fast.
+        let replayed = GooseReplayedWaitTime::new(vec![Duration::from_secs(3)]);
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_millis(1500)
+        );
+
+        // Or half as fast.
+        user.config.replay_speed = Some("0.5".to_string());
+        assert_eq!(
+            replayed.wait_time(&user, &task, elapsed),
+            Duration::from_secs(6)
+        );
+    }
+}
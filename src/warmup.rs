@@ -0,0 +1,89 @@
+//! Optional host pre-resolution and connection warm-up before users run any task.
+//!
+//! When a load test starts, every [`GooseUser`] resolves the host and opens new connections
+//! at about the same time, so connection setup can dominate the response times of the
+//! first requests. Enabling the `--resolve-hosts` run-time option (or
+//! `GooseDefault::ResolveHosts`) resolves each host once, asynchronously, and each user's
+//! client then connects to the resolved addresses without further lookups. The
+//! `--warm-up VALUE` run-time option (or `GooseDefault::WarmUp`) makes each user open
+//! VALUE connections by sending concurrent `HEAD` requests to its base_url, which aren't
+//! included in the metrics.
+//!
+//! Both happen as each user is launched, before its `on_start` tasks. A client replaced
+//! with [`GooseUser::set_client_builder`] doesn't use the resolved addresses or the opened
+//! connections.
+
+use futures::future::join_all;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+
+use crate::goose::{client_builder, GooseUser};
+
+lazy_static! {
+    /// The addresses each host and port resolved to, shared by all users.
+    static ref RESOLVED: Mutex<HashMap<(String, u16), Vec<SocketAddr>>> =
+        Mutex::new(HashMap::new());
+}
+
+// Resolve a host and port, only looking it up the first time.
+async fn resolve(host: &str, port: u16) -> Option<Vec<SocketAddr>> {
+    let key = (host.to_string(), port);
+    if let Some(addresses) = RESOLVED.lock().unwrap().get(&key) {
+        return Some(addresses.clone());
+    }
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port)).await.ok()?.collect();
+    if addresses.is_empty() {
+        return None;
+    }
+    RESOLVED.lock().unwrap().insert(key, addresses.clone());
+    Some(addresses)
+}
+
+impl GooseUser {
+    // Resolve the base_url host and open connections to it, if configured.
+    pub(crate) async fn warm_up(&mut self) {
+        // A Unix domain socket or SSH tunnel is already resolved to its local address.
+        if self.config.resolve_hosts && !self.unix_socket && self.config.ssh_jump_host.is_empty() {
+            self.resolve_base_url().await;
+        }
+
+        if self.config.warm_up > 0 {
+            let requests =
+                (0..self.config.warm_up).map(|_| self.client.head(self.base_url.clone()).send());
+            let failed = join_all(requests)
+                .await
+                .iter()
+                .filter(|response| response.is_err())
+                .count();
+            if failed > 0 {
+                warn!(
+                    "{} of {} warm-up requests to {} failed",
+                    failed, self.config.warm_up, self.base_url
+                );
+            }
+        }
+    }
+
+    // Rebuild the client to connect to the resolved addresses of the base_url host.
+    async fn resolve_base_url(&mut self) {
+        // Hosts configured as an IP address don't need resolving.
+        let host = match self.base_url.domain() {
+            Some(host) => host.to_string(),
+            None => return,
+        };
+        let port = self.base_url.port_or_known_default().unwrap_or(80);
+
+        match resolve(&host, port).await {
+            Some(addresses) => match client_builder(&self.config, self.client_cert.as_ref())
+                .resolve_to_addrs(&host, &addresses)
+                .build()
+            {
+                Ok(client) => self.client = client,
+                Err(e) => warn!("failed to build client for {}: {}", host, e),
+            },
+            None => warn!("failed to resolve {}", host),
+        }
+    }
+}
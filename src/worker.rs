@@ -1,8 +1,12 @@
 use gumdrop::Options;
+use lazy_static::lazy_static;
+#[cfg(feature = "gaggle-tls")]
+use nng::options::{transport::tls::CaFile, Options as _};
 use nng::*;
 use serde::{Deserialize, Serialize};
 use std::io::BufWriter;
 use std::sync::atomic::Ordering;
+use std::sync::Mutex;
 use std::time;
 use url::Url;
 
@@ -24,6 +28,25 @@ pub enum GaggleMetrics {
     Tasks(GooseTaskMetrics),
     /// Goose error metrics.
     Errors(GooseErrorMetrics),
+    /// The Worker stopped and flushed its logs, these are its final metrics. Includes why
+    /// the load test was aborted, if it was.
+    Stopped(Option<String>),
+}
+
+/// The first message a Worker sends to the Manager, to join the Gaggle.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct GaggleWorkerInit {
+    /// Load test hash, used to ensure all Workers are running the same load test.
+    pub(crate) hash: u64,
+    /// Pre-shared key the Worker authenticates with, only sent over TLS. Empty if the
+    /// Worker wasn't started with `--gaggle-key`.
+    pub(crate) key: String,
+}
+
+// Mutable singleton holding users the Manager moved to this Worker from lost Workers,
+// until they're launched.
+lazy_static! {
+    static ref REDISTRIBUTED_USERS: Mutex<Vec<GooseUserInitializer>> = Mutex::new(Vec::new());
 }
 
 // If pipe closes unexpectedly, panic.
@@ -49,11 +72,32 @@ pub fn register_shutdown_pipe_handler(manager: &Socket) {
         .expect("failed to set up new pipe handler");
 }
 
+/// Connects to the Manager over TLS, verifying its certificate with the CA certificates
+/// in `ca_file`.
+#[cfg(feature = "gaggle-tls")]
+fn dial_with_tls(manager: &Socket, address: &str, ca_file: &str) -> Result<()> {
+    let dialer = DialerBuilder::new(manager, address)?;
+    dialer.set_opt::<CaFile>(ca_file.to_string())?;
+    dialer.start(false).map(|_| ()).map_err(|(_, error)| error)
+}
+
+/// Configuration validation prevents TLS from being enabled without the `gaggle-tls`
+/// feature.
+#[cfg(not(feature = "gaggle-tls"))]
+fn dial_with_tls(_manager: &Socket, _address: &str, _ca_file: &str) -> Result<()> {
+    unreachable!("TLS requires the gaggle-tls feature");
+}
+
 pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
-    // Creates a TCP address.
+    // Creates a TCP address, secured with TLS if a CA is configured.
+    let scheme = if goose_attack.configuration.manager_tls_ca.is_empty() {
+        "tcp"
+    } else {
+        "tls+tcp"
+    };
     let address = format!(
-        "tcp://{}:{}",
-        goose_attack.configuration.manager_host, goose_attack.configuration.manager_port
+        "{}://{}:{}",
+        scheme, goose_attack.configuration.manager_host, goose_attack.configuration.manager_port
     );
     info!("worker connecting to manager at {}", &address);
 
@@ -72,7 +116,16 @@ pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
     // Connect to manager.
     let mut retries = 0;
     loop {
-        match manager.dial(&address) {
+        let dialed = if goose_attack.configuration.manager_tls_ca.is_empty() {
+            manager.dial(&address)
+        } else {
+            dial_with_tls(
+                &manager,
+                &address,
+                &goose_attack.configuration.manager_tls_ca,
+            )
+        };
+        match dialed {
             Ok(_) => break,
             Err(e) => {
                 if retries >= 5 {
@@ -90,12 +143,24 @@ pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
         }
     }
 
-    // Send manager the hash of the load test we are ready to run.
-    push_metrics_to_manager(
-        &manager,
-        vec![GaggleMetrics::WorkerInit(goose_attack.metrics.hash)],
-        false,
-    );
+    // Send manager the hash of the load test we are ready to run, and the pre-shared key
+    // to authenticate with if configured.
+    let worker_init = GaggleWorkerInit {
+        hash: goose_attack.metrics.hash,
+        key: goose_attack.configuration.gaggle_key.to_string(),
+    };
+    let mut message = BufWriter::new(Message::new());
+    serde_cbor::to_writer(&mut message, &worker_init)
+        .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
+        .expect("failed to serialize GaggleWorkerInit");
+    manager
+        .try_send(
+            message
+                .into_inner()
+                .expect("failed to extract nng message from buffer"),
+        )
+        .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
+        .expect("communication failure");
 
     let mut config: GooseConfiguration = GooseConfiguration::parse_args_default(&EMPTY_ARGS)
         .expect("failed to generate default configuration");
@@ -120,7 +185,7 @@ pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
             };
             match command {
                 GooseUserCommand::Exit => {
-                    panic!("unexpected GooseUserCommand::Exit from manager during startup");
+                    panic!("manager told worker to exit during startup, confirm --expect-workers and --gaggle-key are correct");
                 }
                 other => {
                     panic!("unknown command from manager: {:?}", other);
@@ -175,6 +240,14 @@ pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
             .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
             .expect("error receiving manager message");
 
+        // Users moved here from lost Workers also tell the Worker to start the load test.
+        if let Ok(initializers) =
+            serde_cbor::from_reader::<Vec<GooseUserInitializer>, _>(msg.as_slice())
+        {
+            REDISTRIBUTED_USERS.lock().unwrap().extend(initializers);
+            break;
+        }
+
         let command: GooseUserCommand = serde_cbor::from_reader(msg.as_slice())
             .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
             .expect("invalid message received");
@@ -240,9 +313,17 @@ pub(crate) async fn worker_main(goose_attack: GooseAttack) -> GooseAttack {
     // The debug_format option is configured on the Worker.
     worker_goose_attack.configuration.debug_format =
         goose_attack.configuration.debug_format.clone();
+    // The redact_headers and no_redact_headers options are configured on the Worker.
+    worker_goose_attack.configuration.redact_headers =
+        goose_attack.configuration.redact_headers.to_string();
+    worker_goose_attack.configuration.no_redact_headers =
+        goose_attack.configuration.no_redact_headers;
     // The throttle_requests option is set on the Worker.
     worker_goose_attack.configuration.throttle_requests =
         goose_attack.configuration.throttle_requests;
+    // The adaptive_throttle option is set on the Worker.
+    worker_goose_attack.configuration.adaptive_throttle =
+        goose_attack.configuration.adaptive_throttle;
     worker_goose_attack.attack_mode = AttackMode::Worker;
     worker_goose_attack.defaults = goose_attack.defaults.clone();
 
@@ -282,6 +363,20 @@ pub fn push_metrics_to_manager(
             .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
             .expect("error receiving manager message");
 
+        // The Manager may reply with users moved here from lost Workers, which are
+        // launched while the load test keeps running.
+        if let Ok(initializers) =
+            serde_cbor::from_reader::<Vec<GooseUserInitializer>, _>(msg.as_slice())
+        {
+            info!(
+                "[{}] received {} users from lost workers",
+                get_worker_id(),
+                initializers.len()
+            );
+            REDISTRIBUTED_USERS.lock().unwrap().extend(initializers);
+            return true;
+        }
+
         let command: GooseUserCommand = serde_cbor::from_reader(msg.as_slice())
             .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
             .expect("invalid message");
@@ -298,3 +393,22 @@ pub fn push_metrics_to_manager(
     }
     true
 }
+
+// Take the users the Manager moved to this Worker from lost Workers, if any.
+pub(crate) fn take_redistributed_users(load_test_hash: u64) -> Vec<GooseUser> {
+    REDISTRIBUTED_USERS
+        .lock()
+        .unwrap()
+        .drain(..)
+        .map(|initializer| {
+            GooseUser::new(
+                initializer.task_sets_index,
+                Url::parse(&initializer.base_url).unwrap(),
+                &initializer.config,
+                load_test_hash,
+            )
+            .map_err(|error| eprintln!("{:?} worker_id({})", error, get_worker_id()))
+            .expect("failed to create user")
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+//! Helpers for load testing write APIs.
+//!
+//! Correctly behaving clients of write APIs don't blindly repeat writes. They send an
+//! idempotency key with each write, so a write that's retried after a timeout isn't
+//! applied twice, and they make updates conditional on the version of the resource they
+//! last read, so concurrent updates don't overwrite each other.
+//!
+//! [`GooseUser::idempotency_key`] returns a random key that stays the same for the rest
+//! of the user's current pass through its tasks, so retries of the same write send the
+//! same key, and a new key is generated for the next pass. It can be sent with
+//! [`GooseRequestBuilder::idempotency_key`], which sets the `Idempotency-Key` header.
+//!
+//! [`GooseUser::remember_etag`] remembers the `ETag` header of a response under a name,
+//! and [`GooseUser::etag`] returns it, so it can be sent with
+//! [`GooseRequestBuilder::if_match`], which sets the `If-Match` header.
+//!
+//! Write APIs answer conflicting writes with `409 Conflict`, and conditional writes made
+//! with a stale ETag with `412 Precondition Failed`. These responses are counted
+//! separately for each request in
+//! [`GooseRequestMetricAggregate::conflicts`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.conflicts)
+//! and
+//! [`GooseRequestMetricAggregate::precondition_failures`](../metrics/struct.GooseRequestMetricAggregate.html#structfield.precondition_failures),
+//! and displayed in their own table.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//!
+//! let mut a_task = task!(update_profile);
+//!
+//! /// Read a profile, then update it unless it changed in the meantime.
+//! async fn update_profile(user: &mut GooseUser) -> GooseTaskResult {
+//!     let goose = user.get("api/profile").await?;
+//!     let etag = user.remember_etag("profile", &goose).unwrap_or_default();
+//!     let key = user.idempotency_key();
+//!
+//!     let request_builder = user
+//!         .get_request_builder(&GooseMethod::Put, "api/profile")?
+//!         .body(r#"{"name": "Goose"}"#);
+//!     let goose_request = GooseRequest::builder()
+//!         .method(GooseMethod::Put)
+//!         .path("api/profile")
+//!         .idempotency_key(&key)
+//!         .if_match(&etag)
+//!         // A stale ETag is expected when other users update the same profile.
+//!         .expect_status_code(412)
+//!         .set_request_builder(request_builder)
+//!         .build();
+//!     let _goose = user.request(goose_request).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`GooseRequestBuilder::idempotency_key`]: ../goose/struct.GooseRequestBuilder.html#method.idempotency_key
+//! [`GooseRequestBuilder::if_match`]: ../goose/struct.GooseRequestBuilder.html#method.if_match
+
+use reqwest::header::ETAG;
+
+use crate::goose::{GooseResponse, GooseUser};
+use crate::random;
+
+impl GooseUser {
+    /// Returns the idempotency key of the user's current pass through its tasks,
+    /// generating a random key the first time it's requested during each pass.
+    ///
+    /// Send it with
+    /// [`GooseRequestBuilder::idempotency_key`](../goose/struct.GooseRequestBuilder.html#method.idempotency_key).
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(place_order);
+    ///
+    /// async fn place_order(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let key = user.idempotency_key();
+    ///     let goose_request = GooseRequest::builder()
+    ///         .method(GooseMethod::Post)
+    ///         .path("api/orders")
+    ///         .idempotency_key(&key)
+    ///         .build();
+    ///     let _goose = user.request(goose_request).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn idempotency_key(&mut self) -> String {
+        match self.idempotency_key.as_ref() {
+            Some(idempotency_key) => idempotency_key.to_string(),
+            None => self.new_idempotency_key(),
+        }
+    }
+
+    /// Generates a new random idempotency key, returned by
+    /// [`GooseUser::idempotency_key`] for the rest of the user's current pass through its
+    /// tasks. Use it when a task makes more than one distinct write.
+    pub fn new_idempotency_key(&mut self) -> String {
+        let idempotency_key = random::uuid(&mut self.rng);
+        self.idempotency_key = Some(idempotency_key.to_string());
+        idempotency_key
+    }
+
+    /// Remembers the `ETag` header of a response under a name, returning it. Returns
+    /// `None` and forgets any ETag remembered under the name if the request failed or the
+    /// response has no `ETag` header.
+    pub fn remember_etag(&mut self, name: &str, goose: &GooseResponse) -> Option<String> {
+        let etag = goose
+            .response
+            .as_ref()
+            .ok()
+            .and_then(|response| response.headers().get(ETAG))
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        match etag.as_ref() {
+            Some(etag) => self.etags.insert(name.to_string(), etag.to_string()),
+            None => self.etags.remove(name),
+        };
+        etag
+    }
+
+    /// Returns the ETag remembered under a name with [`GooseUser::remember_etag`], if
+    /// any. Send it with
+    /// [`GooseRequestBuilder::if_match`](../goose/struct.GooseRequestBuilder.html#method.if_match).
+    pub fn etag(&self, name: &str) -> Option<&str> {
+        self.etags.get(name).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gumdrop::Options;
+
+    use crate::GooseConfiguration;
+
+    const EMPTY_ARGS: Vec<&str> = vec![];
+
+    #[test]
+    fn idempotency_key() {
+        let configuration = GooseConfiguration::parse_args_default(&EMPTY_ARGS).unwrap();
+        let base_url = url::Url::parse("http://127.0.0.1").unwrap();
+        let mut user = GooseUser::single(base_url, &configuration).unwrap();
+
+        // The key is the same until a new one is generated.
+        let key = user.idempotency_key();
+        assert_eq!(key.len(), 36);
+        assert_eq!(user.idempotency_key(), key);
+        let new_key = user.new_idempotency_key();
+        assert_ne!(new_key, key);
+        assert_eq!(user.idempotency_key(), new_key);
+
+        // Each pass through the tasks starts without a key.
+        user.idempotency_key = None;
+        assert_ne!(user.idempotency_key(), new_key);
+    }
+}
@@ -0,0 +1,689 @@
+//! Helpers for load testing XML and SOAP services.
+//!
+//! [`GooseUser::post_xml`] and [`GooseUser::post_soap`] POST XML bodies with the correct
+//! `Content-Type` (and for SOAP, `SOAPAction`) headers, and [`soap_envelope`] wraps a
+//! payload in a SOAP 1.1 envelope.
+//!
+//! Responses can be validated with [`xpath`] and [`xpath_first`], which evaluate a small
+//! subset of XPath against an XML document and return the text content of each match.
+//! Supported expressions are made up of steps separated by `/` (a child) or `//` (any
+//! descendant), where each step is an element name or `*` for any element. The final step
+//! can instead select an attribute, with `@name` or `@*`. Names are matched without their
+//! namespace prefix, so `/Envelope/Body` and `/soap:Envelope/soap:Body` are equivalent.
+//! For example:
+//!  - `/Envelope/Body/GetPriceResponse/Price`: the `Price` element at this exact path
+//!  - `//Price`: all `Price` elements anywhere in the document
+//!  - `//Item/@sku`: the `sku` attribute of all `Item` elements
+//!
+//! Predicates, functions and other axes are not supported.
+//!
+//! Documents and expressions that can't be parsed return a [`GooseXmlError`], which is
+//! converted into a
+//! [`GooseTaskError::InvalidXml`](../goose/enum.GooseTaskError.html#variant.InvalidXml) when
+//! returned from a task with `?`.
+//!
+//! # Example
+//! ```rust
+//! use goose::prelude::*;
+//! use goose::xml;
+//!
+//! let mut a_task = task!(get_price);
+//!
+//! /// Call a SOAP service, and fail the request if it doesn't return a price.
+//! async fn get_price(user: &mut GooseUser) -> GooseTaskResult {
+//!     let body = xml::soap_envelope(
+//!         r#"<GetPrice xmlns="http://example.com/stock"><Item>Goose</Item></GetPrice>"#,
+//!     );
+//!     let mut goose = user
+//!         .post_soap("stock", "http://example.com/stock/GetPrice", body)
+//!         .await?;
+//!
+//!     if let Ok(response) = goose.response {
+//!         let text = response.text().await.unwrap_or_default();
+//!         if xml::xpath_first(&text, "/Envelope/Body/GetPriceResponse/Price")?.is_none() {
+//!             return user.set_failure("price missing", &mut goose.request, None, Some(&text));
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::goose::{GooseMethod, GooseRequest, GooseResponse, GooseTaskError, GooseUser};
+
+/// The `Content-Type` of requests made with [`GooseUser::post_xml`].
+const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// The `Content-Type` of requests made with [`GooseUser::post_soap`], as required by SOAP 1.1.
+const SOAP_CONTENT_TYPE: &str = "text/xml; charset=utf-8";
+
+/// How deeply elements can be nested. Deeper documents are invalid, instead of overflowing
+/// the stack while parsing a response body controlled by the server.
+const MAX_XML_DEPTH: usize = 256;
+
+/// An XML document or XPath expression couldn't be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GooseXmlError {
+    /// Why the XML document or XPath expression couldn't be parsed.
+    pub detail: String,
+}
+impl fmt::Display for GooseXmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid XML ({})", self.detail)
+    }
+}
+impl std::error::Error for GooseXmlError {}
+
+/// A parsed XML element.
+#[derive(Debug, Default)]
+struct XmlElement {
+    /// The element name, without any namespace prefix.
+    name: String,
+    /// The element attributes, with names stripped of any namespace prefix.
+    attributes: Vec<(String, String)>,
+    /// Child elements and text, in document order.
+    children: Vec<XmlNode>,
+}
+impl XmlElement {
+    /// Iterate over the child elements, ignoring text.
+    fn child_elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|child| match child {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// Collect all descendants of this element in document order, optionally including
+    /// this element itself.
+    fn descendants<'a>(&'a self, include_self: bool, descendants: &mut Vec<&'a XmlElement>) {
+        if include_self {
+            descendants.push(self);
+        }
+        for child in self.child_elements() {
+            child.descendants(true, descendants);
+        }
+    }
+
+    /// The text content of this element and all of its descendants.
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            match child {
+                XmlNode::Element(element) => text.push_str(&element.text()),
+                XmlNode::Text(t) => text.push_str(t),
+            }
+        }
+        text
+    }
+}
+
+/// A node contained in an XML element.
+#[derive(Debug)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+/// A single step in an XPath expression.
+#[derive(Debug, PartialEq)]
+struct XPathStep {
+    /// The step selects any descendant (`//`) rather than only children (`/`).
+    descendant: bool,
+    /// The step selects an attribute (`@name`) rather than an element.
+    attribute: bool,
+    /// The element or attribute name, or `*` to match any name.
+    name: String,
+}
+
+// Strip the namespace prefix from a name, if any.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// A minimal non-validating XML parser, building a tree of [`XmlElement`]s.
+struct XmlParser<'a> {
+    xml: &'a str,
+    position: usize,
+}
+impl<'a> XmlParser<'a> {
+    fn remaining(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = self.remaining();
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    // Consume everything up to and including `end`, returning what came before it.
+    fn consume_until(&mut self, end: &str) -> Result<&'a str, String> {
+        match self.remaining().find(end) {
+            Some(index) => {
+                let consumed = &self.remaining()[..index];
+                self.position += index + end.len();
+                Ok(consumed)
+            }
+            None => Err(format!("expected {} before end of document", end)),
+        }
+    }
+
+    // Consume a name, ending at whitespace or a delimiter.
+    fn consume_name(&mut self) -> Result<&'a str, String> {
+        let remaining = self.remaining();
+        let length = remaining
+            .find(|c: char| c.is_whitespace() || "/>=<\"'".contains(c))
+            .unwrap_or(remaining.len());
+        if length == 0 {
+            return Err(format!("expected a name at byte {}", self.position));
+        }
+        self.position += length;
+        Ok(&remaining[..length])
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        if self.remaining().starts_with(expected) {
+            self.position += expected.len();
+            Ok(())
+        } else {
+            Err(format!("expected {} at byte {}", expected, self.position))
+        }
+    }
+
+    // Skip the XML declaration, processing instructions, comments and the doctype, along
+    // with any whitespace around them.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.remaining().starts_with("<?") {
+                self.consume_until("?>")?;
+            } else if self.remaining().starts_with("<!--") {
+                self.consume_until("-->")?;
+            } else if self.remaining().starts_with("<!DOCTYPE") {
+                // Skip the internal subset, if any.
+                let doctype_end = self.remaining().find('>').unwrap_or(0);
+                if self.remaining()[..doctype_end].contains('[') {
+                    self.consume_until("]")?;
+                }
+                self.consume_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<XmlElement, String> {
+        self.skip_misc()?;
+        let root = self.parse_element(1)?;
+        self.skip_misc()?;
+        if !self.remaining().is_empty() {
+            return Err(format!(
+                "unexpected content after root element at byte {}",
+                self.position
+            ));
+        }
+        Ok(root)
+    }
+
+    fn parse_element(&mut self, depth: usize) -> Result<XmlElement, String> {
+        if depth > MAX_XML_DEPTH {
+            return Err(format!(
+                "elements are nested more than {} deep at byte {}",
+                MAX_XML_DEPTH, self.position
+            ));
+        }
+        self.expect("<")?;
+        let qualified_name = self.consume_name()?;
+        let mut element = XmlElement {
+            name: local_name(qualified_name).to_string(),
+            ..Default::default()
+        };
+
+        // Parse attributes until the start tag is closed.
+        loop {
+            self.skip_whitespace();
+            if self.remaining().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            } else if self.remaining().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = self.consume_name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = if self.remaining().starts_with('"') {
+                "\""
+            } else {
+                "'"
+            };
+            self.expect(quote)?;
+            let value = decode_entities(self.consume_until(quote)?)?;
+            element
+                .attributes
+                .push((local_name(name).to_string(), value));
+        }
+
+        // Parse content until the matching end tag.
+        loop {
+            let remaining = self.remaining();
+            if remaining.is_empty() {
+                return Err(format!("{} is not closed", qualified_name));
+            } else if remaining.starts_with("</") {
+                self.position += 2;
+                let closing_name = self.consume_name()?;
+                if closing_name != qualified_name {
+                    return Err(format!(
+                        "expected </{}> but found </{}>",
+                        qualified_name, closing_name
+                    ));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if remaining.starts_with("<!--") {
+                self.consume_until("-->")?;
+            } else if remaining.starts_with("<![CDATA[") {
+                self.position += 9;
+                let text = self.consume_until("]]>")?;
+                element.children.push(XmlNode::Text(text.to_string()));
+            } else if remaining.starts_with("<?") {
+                self.consume_until("?>")?;
+            } else if remaining.starts_with('<') {
+                let child = self.parse_element(depth + 1)?;
+                element.children.push(XmlNode::Element(child));
+            } else {
+                let length = remaining.find('<').unwrap_or(remaining.len());
+                self.position += length;
+                let text = decode_entities(&remaining[..length])?;
+                element.children.push(XmlNode::Text(text));
+            }
+        }
+    }
+}
+
+// Replace predefined entities and character references with the characters they refer to.
+fn decode_entities(text: &str) -> Result<String, String> {
+    let mut decoded = String::with_capacity(text.len());
+    let mut remaining = text;
+    while let Some(start) = remaining.find('&') {
+        decoded.push_str(&remaining[..start]);
+        let end = match remaining[start..].find(';') {
+            Some(end) => start + end,
+            None => return Err(format!("unterminated entity in {}", text)),
+        };
+        let entity = &remaining[start + 1..end];
+        let character = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse::<u32>().ok().and_then(char::from_u32)
+                } else {
+                    None
+                }
+            }
+        };
+        match character {
+            Some(c) => decoded.push(c),
+            None => return Err(format!("unrecognized entity &{};", entity)),
+        }
+        remaining = &remaining[end + 1..];
+    }
+    decoded.push_str(remaining);
+    Ok(decoded)
+}
+
+// Parse an XPath expression into steps.
+fn parse_xpath(path: &str) -> Result<Vec<XPathStep>, String> {
+    let invalid = |detail: &str| format!("invalid XPath {}: {}", path, detail);
+
+    if !path.starts_with('/') {
+        return Err(invalid("must start with / or //"));
+    }
+    let mut steps = Vec::new();
+    let mut remaining = path;
+    while !remaining.is_empty() {
+        let descendant = remaining.starts_with("//");
+        remaining = remaining.trim_start_matches('/');
+        let length = remaining.find('/').unwrap_or(remaining.len());
+        let step = &remaining[..length];
+        remaining = &remaining[length..];
+
+        if step.is_empty() {
+            return Err(invalid("empty step"));
+        }
+        if steps.iter().any(|previous: &XPathStep| previous.attribute) {
+            return Err(invalid("only the last step can select an attribute"));
+        }
+        let (attribute, name) = match step.strip_prefix('@') {
+            Some(name) => (true, name),
+            None => (false, step),
+        };
+        if name.is_empty() || name.contains(|c: char| "[]()".contains(c)) {
+            return Err(invalid(
+                "steps must be a name, *, or @ followed by a name or *",
+            ));
+        }
+        steps.push(XPathStep {
+            descendant,
+            attribute,
+            name: local_name(name).to_string(),
+        });
+    }
+    Ok(steps)
+}
+
+/// Evaluate an XPath-lite expression against an XML document, returning the text content
+/// of every matching element or the value of every matching attribute, in document order.
+/// Returns a [`GooseXmlError`] if the document isn't well-formed XML, nests
+/// elements more than 256 deep, or the expression isn't supported.
+///
+/// # Example
+/// ```rust
+/// use goose::xml;
+///
+/// let document = r#"<items><item sku="A1">Goose</item><item sku="B2">Duck</item></items>"#;
+/// assert_eq!(xml::xpath(document, "/items/item").unwrap(), vec!["Goose", "Duck"]);
+/// assert_eq!(xml::xpath(document, "//item/@sku").unwrap(), vec!["A1", "B2"]);
+/// ```
+pub fn xpath(xml: &str, path: &str) -> Result<Vec<String>, GooseXmlError> {
+    let invalid_xml = |detail| GooseXmlError { detail };
+    let steps = parse_xpath(path).map_err(invalid_xml)?;
+    let root = XmlParser { xml, position: 0 }
+        .parse_document()
+        .map_err(invalid_xml)?;
+
+    // The document node, the parent of the root element.
+    let document = XmlElement {
+        children: vec![XmlNode::Element(root)],
+        ..Default::default()
+    };
+
+    let mut current: Vec<&XmlElement> = vec![&document];
+    for step in &steps {
+        // Find the elements to select from: either the children of the current elements,
+        // or with `//` all of their descendants.
+        let mut candidates: Vec<&XmlElement> = Vec::new();
+        for element in &current {
+            if step.attribute {
+                if step.descendant {
+                    element.descendants(true, &mut candidates);
+                } else {
+                    candidates.push(element);
+                }
+            } else if step.descendant {
+                element.descendants(false, &mut candidates);
+            } else {
+                candidates.extend(element.child_elements());
+            }
+        }
+        // Nested matches of `//` can find the same element more than once.
+        let mut visited: HashSet<*const XmlElement> = HashSet::new();
+        let unique: Vec<&XmlElement> = candidates
+            .into_iter()
+            .filter(|candidate| visited.insert(*candidate as *const XmlElement))
+            .collect();
+
+        if step.attribute {
+            return Ok(unique
+                .iter()
+                .flat_map(|element| element.attributes.iter())
+                .filter(|(name, _)| step.name == "*" || *name == step.name)
+                .map(|(_, value)| value.clone())
+                .collect());
+        }
+        current = unique
+            .into_iter()
+            .filter(|element| step.name == "*" || element.name == step.name)
+            .collect();
+    }
+
+    Ok(current.iter().map(|element| element.text()).collect())
+}
+
+/// Evaluate an XPath-lite expression against an XML document, returning the text content
+/// of the first matching element or the value of the first matching attribute, or
+/// [`None`] if nothing matches. See [`xpath`] for details.
+///
+/// # Example
+/// ```rust
+/// use goose::xml;
+///
+/// let document = "<response><status>OK</status></response>";
+/// assert_eq!(
+///     xml::xpath_first(document, "/response/status").unwrap(),
+///     Some("OK".to_string())
+/// );
+/// assert_eq!(xml::xpath_first(document, "//error").unwrap(), None);
+/// ```
+pub fn xpath_first(xml: &str, path: &str) -> Result<Option<String>, GooseXmlError> {
+    Ok(xpath(xml, path)?.into_iter().next())
+}
+
+/// Wrap a payload in a SOAP 1.1 envelope, for posting with [`GooseUser::post_soap`].
+///
+/// # Example
+/// ```rust
+/// use goose::xml;
+///
+/// let envelope = xml::soap_envelope("<GetPrice><Item>Goose</Item></GetPrice>");
+/// assert!(envelope.contains("<soap:Body><GetPrice><Item>Goose</Item></GetPrice></soap:Body>"));
+/// ```
+pub fn soap_envelope(body: &str) -> String {
+    format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="utf-8"?>"#,
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">"#,
+            "<soap:Body>{}</soap:Body>",
+            "</soap:Envelope>"
+        ),
+        body
+    )
+}
+
+impl GooseUser {
+    /// A helper to make a `POST` request of an XML body on a path and collect relevant
+    /// metrics. Automatically prepends the correct host, and sets the `Content-Type`
+    /// header to `application/xml; charset=utf-8`.
+    ///
+    /// Calls to `post_xml()` return a [`GooseResponse`](../goose/struct.GooseResponse.html)
+    /// object. If the service expects a different content type, refer to
+    /// [`GooseUser::get_request_builder`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    ///
+    /// let mut a_task = task!(post_function);
+    ///
+    /// /// A very simple task that POSTs an XML document.
+    /// async fn post_function(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let _goose = user.post_xml("path/to/foo/", "<foo>bar</foo>").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn post_xml<T: Into<reqwest::Body>>(
+        &mut self,
+        path: &str,
+        body: T,
+    ) -> Result<GooseResponse, GooseTaskError> {
+        let reqwest_request_builder = self
+            .get_request_builder(&GooseMethod::Post, path)?
+            .header(reqwest::header::CONTENT_TYPE, XML_CONTENT_TYPE)
+            .body(body);
+
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Post)
+            .path(path)
+            .set_request_builder(reqwest_request_builder)
+            .build();
+
+        self.request(goose_request).await
+    }
+
+    /// A helper to make a SOAP 1.1 `POST` request on a path and collect relevant metrics.
+    /// Automatically prepends the correct host, sets the `Content-Type` header to
+    /// `text/xml; charset=utf-8`, and sets the `SOAPAction` header to the provided action.
+    ///
+    /// The body must be a complete SOAP envelope, which can be built with
+    /// [`soap_envelope`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use goose::prelude::*;
+    /// use goose::xml;
+    ///
+    /// let mut a_task = task!(get_price);
+    ///
+    /// /// Call the GetPrice action of a SOAP service.
+    /// async fn get_price(user: &mut GooseUser) -> GooseTaskResult {
+    ///     let body = xml::soap_envelope("<GetPrice><Item>Goose</Item></GetPrice>");
+    ///     let _goose = user
+    ///         .post_soap("stock", "http://example.com/stock/GetPrice", body)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn post_soap<T: Into<reqwest::Body>>(
+        &mut self,
+        path: &str,
+        action: &str,
+        body: T,
+    ) -> Result<GooseResponse, GooseTaskError> {
+        let reqwest_request_builder = self
+            .get_request_builder(&GooseMethod::Post, path)?
+            .header(reqwest::header::CONTENT_TYPE, SOAP_CONTENT_TYPE)
+            // SOAP 1.1 requires the action to be quoted.
+            .header("SOAPAction", format!("\"{}\"", action))
+            .body(body);
+
+        let goose_request = GooseRequest::builder()
+            .method(GooseMethod::Post)
+            .path(path)
+            .set_request_builder(reqwest_request_builder)
+            .build();
+
+        self.request(goose_request).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOAP_RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- A typical SOAP response. -->
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <m:GetPriceResponse xmlns:m="http://example.com/stock">
+      <m:Price currency="USD">34.5</m:Price>
+      <m:Item sku="A1">Goose &amp; Gander</m:Item>
+      <m:Item sku='B2'><![CDATA[<Duck>]]></m:Item>
+      <m:Empty/>
+    </m:GetPriceResponse>
+  </soap:Body>
+</soap:Envelope>
+"#;
+
+    #[test]
+    fn xpath_elements() {
+        assert_eq!(
+            xpath(SOAP_RESPONSE, "/Envelope/Body/GetPriceResponse/Price").unwrap(),
+            vec!["34.5"]
+        );
+        // Namespace prefixes are ignored.
+        assert_eq!(
+            xpath(
+                SOAP_RESPONSE,
+                "/soap:Envelope/soap:Body/m:GetPriceResponse/m:Price"
+            )
+            .unwrap(),
+            vec!["34.5"]
+        );
+        assert_eq!(
+            xpath(SOAP_RESPONSE, "//Item").unwrap(),
+            vec!["Goose & Gander", "<Duck>"]
+        );
+        assert_eq!(
+            xpath(SOAP_RESPONSE, "/Envelope/*/*/Empty").unwrap(),
+            vec![""]
+        );
+        assert!(xpath(SOAP_RESPONSE, "/Body").unwrap().is_empty());
+        assert!(xpath(SOAP_RESPONSE, "/Envelope/Price").unwrap().is_empty());
+        assert_eq!(
+            xpath_first(SOAP_RESPONSE, "//Price").unwrap(),
+            Some("34.5".to_string())
+        );
+        assert_eq!(xpath_first(SOAP_RESPONSE, "//Missing").unwrap(), None);
+
+        // Nested descendant steps don't return the same element twice.
+        assert_eq!(
+            xpath("<a><a><b>1</b></a></a>", "//a//b").unwrap(),
+            vec!["1"]
+        );
+    }
+
+    #[test]
+    fn xpath_attributes() {
+        assert_eq!(
+            xpath(SOAP_RESPONSE, "//Item/@sku").unwrap(),
+            vec!["A1", "B2"]
+        );
+        assert_eq!(xpath(SOAP_RESPONSE, "//@sku").unwrap(), vec!["A1", "B2"]);
+        assert_eq!(xpath(SOAP_RESPONSE, "//Price/@*").unwrap(), vec!["USD"]);
+        // Only attributes of the selected element are returned.
+        assert!(xpath(SOAP_RESPONSE, "/Envelope/@sku").unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_xml() {
+        assert!(xpath("", "/a").is_err());
+        assert!(xpath("<a>", "/a").is_err());
+        assert!(xpath("<a></b>", "/a").is_err());
+        assert!(xpath("<a/><b/>", "/a").is_err());
+        assert!(xpath("<a>&unknown;</a>", "/a").is_err());
+        assert!(xpath("<a b=c/>", "/a").is_err());
+        assert_eq!(xpath("<a>&#65;&#x42;</a>", "/a").unwrap(), vec!["AB"]);
+
+        // Errors returned from a task are converted into GooseTaskError::InvalidXml.
+        let error = xpath("<a>", "/a").unwrap_err();
+        assert!(matches!(
+            GooseTaskError::from(error.clone()),
+            GooseTaskError::InvalidXml { detail } if detail == error.detail
+        ));
+
+        // Deeply nested documents are invalid instead of overflowing the stack.
+        let depth = 100_000;
+        let nested = format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        assert!(xpath(&nested, "/a").is_err());
+        let depth = MAX_XML_DEPTH;
+        let nested = format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        assert_eq!(xpath(&nested, "//a").unwrap().len(), depth);
+    }
+
+    #[test]
+    fn invalid_xpath() {
+        assert!(xpath("<a/>", "a").is_err());
+        assert!(xpath("<a/>", "/a/").is_err());
+        assert!(xpath("<a/>", "/a[1]").is_err());
+        assert!(xpath("<a/>", "/a/@b/c").is_err());
+        assert!(xpath("<a/>", "/@").is_err());
+    }
+
+    #[test]
+    fn envelope() {
+        let envelope = soap_envelope("<Ping/>");
+        assert_eq!(xpath(&envelope, "/Envelope/Body/Ping").unwrap(), vec![""]);
+    }
+}
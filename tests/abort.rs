@@ -0,0 +1,92 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Instant;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const REPORT_FILE: &str = "abort-report.html";
+const ABORT_REASON: &str = "data exhausted";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn abort(user: &mut GooseUser) -> GooseTaskResult {
+    user.abort_test(ABORT_REASON);
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// A task can abort the load test, recording why in the metrics and report.
+async fn test_abort_test() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--run-time", "30", "--report-file", REPORT_FILE],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Abort")
+            .register_task(task!(get_index).set_sequence(1))
+            .register_task(task!(abort).set_sequence(2)),
+        None,
+        None,
+    );
+    let started = Instant::now();
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // The load test stopped long before the configured run time.
+    assert!(started.elapsed().as_secs() < 10);
+    assert_eq!(goose_metrics.aborted.as_deref(), Some(ABORT_REASON));
+
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains(&format!("<p>Aborted: <span>{}</span></p>", ABORT_REASON)));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Load tests that aren't aborted don't record a reason.
+async fn test_not_aborted() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec![]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(goose_metrics.aborted.is_none());
+}
@@ -0,0 +1,77 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 3;
+const REPORT_FILE: &str = "active-users-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The number of active users is tracked each second while users are launched and while
+/// the load test runs, and charted in the html report.
+async fn test_active_users() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let users = USERS.to_string();
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &users,
+            "--run-time",
+            "2",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // One user is launched each second, and all users are still running at the end.
+    let active_users = &goose_metrics.active_users;
+    assert!(active_users.len() >= USERS + 2);
+    assert!(active_users[0] < USERS);
+    assert!(active_users.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(*active_users.last().unwrap(), USERS);
+
+    // The active users are charted in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Active Users</h2>"));
+    assert!(report.contains("<polyline"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
@@ -0,0 +1,90 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "4";
+const THROTTLE_REQUESTS: &str = "100";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY. The server allows 5 more
+        // requests in the next second.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200)
+                .header("X-RateLimit-Remaining", "5")
+                .header("X-RateLimit-Reset", "1");
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The adaptive throttle slows down to stay under the rate limit reported by the server.
+async fn test_adaptive_throttle() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--run-time",
+            "3",
+            "--throttle-requests",
+            THROTTLE_REQUESTS,
+            "--adaptive-throttle",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // The server allows 5 requests per second, far fewer than the throttle's maximum.
+    let throttle_rate = goose_metrics.throttle_rate.unwrap();
+    assert!(throttle_rate > 0.0);
+    assert!(throttle_rate < 10.0);
+    assert!(mock_endpoints[INDEX_KEY].hits() < 40);
+}
+
+#[tokio::test]
+#[serial]
+/// The adaptive throttle requires a maximum number of requests per second.
+async fn test_adaptive_throttle_requires_throttle_requests() {
+    let server = MockServer::start();
+
+    let configuration = common::build_configuration(&server, vec!["--adaptive-throttle"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -0,0 +1,98 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: usize = 2;
+
+// Files used in these tests.
+const REPORT_FILE: &str = "annotations-test.html";
+
+// The annotation recorded by each user.
+const ANNOTATION: &str = "user started";
+
+// Test task, annotating the load test when each user starts.
+pub async fn annotate(user: &mut GooseUser) -> GooseTaskResult {
+    user.annotate(ANNOTATION);
+    Ok(())
+}
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Annotations recorded by users are included in the metrics and marked on the report
+/// charts.
+async fn test_annotations() {
+    common::cleanup_files(vec![REPORT_FILE]);
+
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &USERS.to_string(),
+            "--hatch-rate",
+            &USERS.to_string(),
+            "--run-time",
+            "2",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Annotations")
+            .register_task(task!(annotate).set_on_start())
+            .register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Each user recorded an annotation, timestamped during the load test.
+    assert_eq!(goose_metrics.annotations.len(), USERS);
+    let starting = goose_metrics.starting.unwrap().timestamp_millis();
+    for annotation in &goose_metrics.annotations {
+        assert_eq!(annotation.name, ANNOTATION);
+        assert!(annotation.timestamp >= starting);
+        assert!(annotation.elapsed <= goose_metrics.duration);
+    }
+
+    // Annotations are included in the json metrics.
+    let json: serde_json::Value = serde_json::to_value(&goose_metrics).unwrap();
+    assert_eq!(json["annotations"][0]["name"], ANNOTATION);
+
+    // Annotations are marked on the report charts.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains(&format!(">{}</text>", ANNOTATION)));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
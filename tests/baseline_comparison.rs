@@ -0,0 +1,91 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Files used in these tests.
+const BASELINE_FILE: &str = "baseline-comparison-test.json";
+const REPORT_FILE: &str = "baseline-comparison-test.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Build a load test of the index page with the provided options.
+fn build_load_test(server: &MockServer, custom: Vec<&str>) -> GooseAttack {
+    let configuration = common::build_configuration(server, custom);
+    common::build_load_test(
+        configuration,
+        &taskset!("Baseline").register_task(task!(get_index)),
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+#[serial]
+/// The metrics of an earlier load test are loaded as a baseline, and the report compares
+/// requests with it.
+async fn test_baseline_comparison() {
+    common::cleanup_files(vec![BASELINE_FILE, REPORT_FILE]);
+
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Save the metrics of a first load test, the same as `--output json`.
+    let goose_attack = build_load_test(&server, vec![]);
+    let baseline_metrics = common::run_load_test(goose_attack, None).await;
+    std::fs::write(
+        BASELINE_FILE,
+        serde_json::to_string(&baseline_metrics).unwrap(),
+    )
+    .unwrap();
+
+    // Compare a second load test with the first.
+    let goose_attack = build_load_test(
+        &server,
+        vec![
+            "--baseline-file",
+            BASELINE_FILE,
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    let baseline = goose_metrics.baseline.unwrap();
+    assert!(baseline.duration == baseline_metrics.duration);
+    assert!(baseline.requests == baseline_metrics.requests);
+
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Baseline Comparison</h2>"));
+    assert!(report.contains(&format!("<td>GET {}</td>", INDEX_PATH)));
+
+    // Comparing with a missing baseline fails.
+    let goose_attack = build_load_test(&server, vec!["--baseline-file", "missing-baseline.json"]);
+    assert!(goose_attack.execute().await.is_err());
+
+    common::cleanup_files(vec![BASELINE_FILE, REPORT_FILE]);
+}
@@ -0,0 +1,131 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::io::Write;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const GZIP_PATH: &str = "/gzip";
+const IDENTITY_PATH: &str = "/identity";
+
+// Indexes to the above paths.
+const GZIP_KEY: usize = 0;
+const IDENTITY_KEY: usize = 1;
+
+// The uncompressed response body, repetitive so it compresses well.
+const BODY: &str = "<p>Honk honk!</p>";
+const REPEAT: usize = 100;
+
+// Test task.
+pub async fn get_gzip(user: &mut GooseUser) -> GooseTaskResult {
+    get_and_validate(user, GZIP_PATH).await
+}
+
+// Test task.
+pub async fn get_identity(user: &mut GooseUser) -> GooseTaskResult {
+    get_and_validate(user, IDENTITY_PATH).await
+}
+
+// Request a path, failing the request if the body isn't the decompressed body.
+async fn get_and_validate(user: &mut GooseUser, path: &str) -> GooseTaskResult {
+    let mut goose = user.get(path).await?;
+    if let Ok(response) = goose.response {
+        let text = response.text().await.unwrap_or_default();
+        if text != BODY.repeat(REPEAT) {
+            return user.set_failure("unexpected body", &mut goose.request, None, Some(&text));
+        }
+    }
+    Ok(())
+}
+
+// Compress the response body with gzip.
+fn gzip_body() -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(BODY.repeat(REPEAT).as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up GZIP_PATH, store in vector at GZIP_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(GZIP_PATH)
+                .header("accept-encoding", "gzip");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(gzip_body());
+        }),
+        // Next set up IDENTITY_PATH, store in vector at IDENTITY_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(IDENTITY_PATH)
+                .header("accept-encoding", "identity");
+            then.status(200).body(BODY.repeat(REPEAT));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Compressed and decompressed response body sizes are tracked, and tasks receive the
+/// decompressed body.
+async fn test_body_sizes() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration =
+        common::build_configuration(&server, vec!["--body-sizes", "--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Gzip").register_task(task!(get_gzip)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /gzip").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[GZIP_KEY].assert_hits(request.success_count);
+
+    // Every response body was received compressed, and decompressed by Goose.
+    let bodies = request.body_count;
+    assert!(bodies == request.success_count);
+    assert!(request.body_bytes == bodies * gzip_body().len());
+    assert!(request.decompressed_body_bytes == bodies * BODY.len() * REPEAT);
+    assert!(request.body_bytes < request.decompressed_body_bytes);
+}
+
+#[tokio::test]
+#[serial]
+/// The configured Accept-Encoding header is sent, and body sizes aren't tracked unless
+/// enabled.
+async fn test_accept_encoding() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--accept-encoding", "identity", "--no-reset-metrics"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Identity").register_task(task!(get_identity)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /identity").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[IDENTITY_KEY].assert_hits(request.success_count);
+    mock_endpoints[GZIP_KEY].assert_hits(0);
+    assert!(request.body_count == 0);
+}
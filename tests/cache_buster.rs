@@ -0,0 +1,136 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::collections::HashSet;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ABOUT_PATH: &str = "/about/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ABOUT_KEY: usize = 1;
+
+// Load test artifacts.
+const REQUEST_LOG: &str = "cache-buster-requests.json";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_about(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ABOUT_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ABOUT_PATH, store in vector at ABOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ABOUT_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Only requests with the configured names get a unique query parameter, which isn't
+/// included in the request name.
+async fn test_cache_buster_query() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--cache-buster",
+            "cb={}",
+            "--cache-buster-names",
+            INDEX_PATH,
+            "--request-log",
+            REQUEST_LOG,
+            "--request-format",
+            "json",
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Website")
+            .register_task(task!(get_index))
+            .register_task(task!(get_about)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Busted requests are still grouped under the name of the path.
+    assert!(goose_metrics.requests.len() == 2);
+    let index = goose_metrics.requests.get("GET /").unwrap();
+    assert!(index.success_count > 0);
+    mock_endpoints[INDEX_KEY].assert_hits(index.success_count);
+    let about = goose_metrics.requests.get("GET /about/").unwrap();
+    assert!(about.success_count > 0);
+    mock_endpoints[ABOUT_KEY].assert_hits(about.success_count);
+
+    // Every index request has a different cache buster, other requests have none.
+    let request_log = std::fs::read_to_string(REQUEST_LOG).unwrap();
+    let mut cache_busters = HashSet::new();
+    for line in request_log.lines() {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        let url = value["raw"]["url"].as_str().unwrap();
+        if value["name"] == INDEX_PATH {
+            let (_, cache_buster) = url.split_once("?cb=").unwrap();
+            assert!(cache_busters.insert(cache_buster.to_string()));
+        } else {
+            assert!(!url.contains("cb="));
+        }
+    }
+    assert!(cache_busters.len() == index.success_count);
+
+    common::cleanup_files(vec![REQUEST_LOG]);
+}
+
+#[tokio::test]
+#[serial]
+/// Without names, every request gets a unique header.
+async fn test_cache_buster_header() {
+    let server = MockServer::start();
+    // Requests only match if they have the cache buster header.
+    let index = server.mock(|when, then| {
+        when.method(GET)
+            .path(INDEX_PATH)
+            .header_exists("x-cache-buster");
+        then.status(200);
+    });
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--cache-buster", "x-cache-buster: {}", "--no-reset-metrics"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    index.assert_hits(request.success_count);
+}
@@ -0,0 +1,122 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::metrics::GooseMetrics;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const CACHED_PATH: &str = "/cached";
+const AGED_PATH: &str = "/aged";
+const ORIGIN_PATH: &str = "/origin";
+
+// Indexes to the above paths.
+const CACHED_KEY: usize = 0;
+const AGED_KEY: usize = 1;
+const ORIGIN_KEY: usize = 2;
+
+// Test task.
+pub async fn get_cached(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(CACHED_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_aged(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(AGED_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_origin(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ORIGIN_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up CACHED_PATH, store in vector at CACHED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CACHED_PATH);
+            then.status(200)
+                .header("x-cache", "HIT")
+                .header("via", "1.1 varnish");
+        }),
+        // Next set up AGED_PATH, store in vector at AGED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(AGED_PATH);
+            then.status(200).header("age", "30");
+        }),
+        // Finally set up ORIGIN_PATH, store in vector at ORIGIN_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ORIGIN_PATH);
+            then.status(200).header("via", "1.1 varnish");
+        }),
+    ]
+}
+
+// Run a short load test with the provided run-time options.
+async fn run_load_test(server: &MockServer, options: Vec<&str>) -> GooseMetrics {
+    let mut options = options;
+    options.push("--no-reset-metrics");
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Cache")
+            .register_task(task!(get_cached))
+            .register_task(task!(get_aged))
+            .register_task(task!(get_origin)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// Responses served by a cache and by the origin are tracked separately.
+async fn test_cache_status() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(&server, vec!["--cache-status"]).await;
+
+    // Responses reporting a cache hit were served by a cache.
+    let cached = goose_metrics.requests.get("GET /cached").unwrap();
+    assert!(cached.success_count > 0);
+    mock_endpoints[CACHED_KEY].assert_hits(cached.success_count);
+    assert!(cached.cache_data.counter == cached.success_count);
+    assert!(cached.origin_data.counter == 0);
+
+    // Responses with an age were served by a cache.
+    let aged = goose_metrics.requests.get("GET /aged").unwrap();
+    assert!(aged.success_count > 0);
+    mock_endpoints[AGED_KEY].assert_hits(aged.success_count);
+    assert!(aged.cache_data.counter == aged.success_count);
+    assert!(aged.origin_data.counter == 0);
+
+    // Responses forwarded by an intermediary were served by the origin.
+    let origin = goose_metrics.requests.get("GET /origin").unwrap();
+    assert!(origin.success_count > 0);
+    mock_endpoints[ORIGIN_KEY].assert_hits(origin.success_count);
+    assert!(origin.cache_data.counter == 0);
+    assert!(origin.origin_data.counter == origin.success_count);
+}
+
+#[tokio::test]
+#[serial]
+/// Cache status isn't tracked unless enabled.
+async fn test_cache_status_disabled() {
+    let server = MockServer::start();
+    let _mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(&server, vec![]).await;
+
+    for request in goose_metrics.requests.values() {
+        assert!(request.success_count > 0);
+        assert!(request.cache_data.counter == 0);
+        assert!(request.origin_data.counter == 0);
+    }
+}
@@ -0,0 +1,109 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Checkpoint file used in these tests.
+const CHECKPOINT_FILE: &str = "checkpoint-test.json";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Build a load test of the index page with the provided options.
+fn build_load_test(server: &MockServer, custom: Vec<&str>) -> GooseAttack {
+    let configuration = common::build_configuration(server, custom);
+    common::build_load_test(
+        configuration,
+        &taskset!("Checkpoint").register_task(task!(get_index)),
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+#[serial]
+/// Metrics are periodically saved to a checkpoint, and a restarted load test resumes
+/// with the metrics and run time saved in the checkpoint.
+async fn test_checkpoint_and_resume() {
+    common::cleanup_files(vec![CHECKPOINT_FILE]);
+
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Run a load test saving a checkpoint every second.
+    let goose_attack = build_load_test(
+        &server,
+        vec![
+            "--run-time",
+            "3",
+            "--checkpoint-file",
+            CHECKPOINT_FILE,
+            "--checkpoint-interval",
+            "1",
+        ],
+    );
+    common::run_load_test(goose_attack, None).await;
+
+    // A checkpoint was saved with the metrics collected so far.
+    let checkpoint: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(CHECKPOINT_FILE).unwrap()).unwrap();
+    let checkpoint_duration = checkpoint["duration"].as_u64().unwrap() as usize;
+    let checkpoint_success_count = checkpoint["requests"][format!("GET {}", INDEX_PATH)]
+        ["success_count"]
+        .as_u64()
+        .unwrap() as usize;
+    assert!(checkpoint_duration >= 1);
+    assert!(checkpoint_success_count > 0);
+
+    // Resume from the checkpoint.
+    let hits_before_resume = mock_endpoints[INDEX_KEY].hits();
+    let goose_attack = build_load_test(
+        &server,
+        vec!["--run-time", "4", "--resume", CHECKPOINT_FILE],
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // The resumed load test continued from the checkpoint's metrics and run time.
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    assert!(goose_metrics.duration >= checkpoint_duration);
+    assert!(index_metrics.success_count > checkpoint_success_count);
+    assert!(
+        index_metrics.success_count - checkpoint_success_count
+            <= mock_endpoints[INDEX_KEY].hits() - hits_before_resume
+    );
+
+    // Resuming from a missing checkpoint fails.
+    let configuration =
+        common::build_configuration(&server, vec!["--resume", "missing-checkpoint.json"]);
+    let goose_attack = GooseAttack::initialize_with_config(configuration)
+        .unwrap()
+        .register_taskset(taskset!("Checkpoint").register_task(task!(get_index)));
+    assert!(goose_attack.execute().await.is_err());
+
+    common::cleanup_files(vec![CHECKPOINT_FILE]);
+}
@@ -0,0 +1,99 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Run a load test throttled to 2 requests per second, with the custom options.
+async fn run_throttled_load_test(server: &MockServer, custom: Vec<&str>) -> GooseMetrics {
+    let mut options = vec!["--throttle-requests", "2", "--run-time", "2"];
+    options.extend(custom);
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// Requests held up by the throttle start later than the expected cadence, so the
+/// requests that weren't made are backfilled.
+async fn test_co_cadence_backfills_late_requests() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics =
+        run_throttled_load_test(&server, vec!["--co-cadence", "100", "--no-reset-metrics"]).await;
+
+    // Only the requests that were made are in the raw data.
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    mock_endpoints[INDEX_KEY].assert_hits(request.raw_data.counter);
+
+    // Backfilled requests are only in the coordinated omission data.
+    let co_data = request.coordinated_omission_data.as_ref().unwrap();
+    assert!(co_data.counter > request.raw_data.counter);
+    assert!(co_data.maximum_time > request.raw_data.maximum_time);
+}
+
+#[tokio::test]
+#[serial]
+/// Requests that start on the expected cadence aren't backfilled.
+async fn test_co_cadence_on_cadence() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics =
+        run_throttled_load_test(&server, vec!["--co-cadence", "2000", "--no-reset-metrics"]).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    mock_endpoints[INDEX_KEY].assert_hits(request.raw_data.counter);
+    assert!(request.coordinated_omission_data.is_none());
+}
+
+#[tokio::test]
+#[serial]
+/// The expected cadence can't be combined with a Coordinated Omission Mitigation strategy.
+async fn test_co_cadence_requires_co_mitigation_disabled() {
+    let server = MockServer::start();
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--co-cadence", "100", "--co-mitigation", "average"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -0,0 +1,137 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const BETA_PATH: &str = "/beta";
+
+// Indexes to the mock endpoints.
+const BETA_INDEX_KEY: usize = 0;
+const INDEX_KEY: usize = 1;
+const BETA_KEY: usize = 2;
+
+// The header users send their cohort in.
+const COHORT_HEADER: &str = "x-feature-flags";
+
+// Load test configuration.
+const USERS: &str = "4";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task, only loading the beta page if in the beta cohort.
+pub async fn get_beta(user: &mut GooseUser) -> GooseTaskResult {
+    if user.cohort() == Some("beta") {
+        let _goose = user.get(BETA_PATH).await?;
+    }
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the beta index, store in vector at BETA_INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header(COHORT_HEADER, "beta");
+            then.status(200);
+        }),
+        // Next set up the index without a cohort, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH).matches(|request| {
+                !request.headers.as_ref().map_or(false, |headers| {
+                    headers.iter().any(|(name, _)| name == COHORT_HEADER)
+                })
+            });
+            then.status(200);
+        }),
+        // Next set up BETA_PATH, store in vector at BETA_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(BETA_PATH)
+                .header(COHORT_HEADER, "beta");
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users are assigned to cohorts, sending their cohort in a header and reporting
+/// response times per cohort.
+async fn test_cohorts() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+            "--cohorts",
+            "beta:50",
+            "--cohort-header",
+            COHORT_HEADER,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Cohorts")
+            .register_task(task!(get_index))
+            .register_task(task!(get_beta)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Requests made by beta users set the cohort header, and are counted by cohort.
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    assert!(index_metrics.fail_count == 0);
+    let beta_count = index_metrics
+        .dimensions
+        .get("cohort: beta")
+        .map_or(0, |data| data.counter);
+    mock_endpoints[BETA_INDEX_KEY].assert_hits(beta_count);
+    mock_endpoints[INDEX_KEY].assert_hits(index_metrics.success_count - beta_count);
+
+    // Only beta users load the beta page.
+    if let Some(beta_metrics) = goose_metrics.requests.get(&format!("GET {}", BETA_PATH)) {
+        assert!(beta_metrics.fail_count == 0);
+        mock_endpoints[BETA_KEY].assert_hits(beta_metrics.success_count);
+    } else {
+        assert!(beta_count == 0);
+    }
+}
+
+#[tokio::test]
+#[serial]
+/// The cohort header requires cohorts.
+async fn test_cohort_header_requires_cohorts() {
+    let server = MockServer::start();
+
+    let configuration =
+        common::build_configuration(&server, vec!["--cohort-header", COHORT_HEADER]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -37,19 +37,26 @@ pub fn build_configuration(server: &MockServer, custom: Vec<&str>) -> GooseConfi
             configuration.extend_from_slice(&["--host", &server_url]);
         }
 
-        // Default to testing with 1 user if not otherwise configured.
-        if !configuration.contains(&"--users") {
-            configuration.extend_from_slice(&["--users", "1"]);
-        }
-
-        // Default to hatch 1 user per second if not otherwise configured.
-        if !configuration.contains(&"--hatch-rate") {
-            configuration.extend_from_slice(&["--hatch-rate", "1"]);
-        }
-
-        // Default to running for 1 second if not otherwise configured.
-        if !configuration.contains(&"--run-time") {
-            configuration.extend_from_slice(&["--run-time", "1"]);
+        // A test plan, or ramping users up and down, configures how many users run and
+        // for how long.
+        if !["--test-plan", "--ramp-up", "--hold", "--ramp-down"]
+            .iter()
+            .any(|option| configuration.contains(option))
+        {
+            // Default to testing with 1 user if not otherwise configured.
+            if !configuration.contains(&"--users") {
+                configuration.extend_from_slice(&["--users", "1"]);
+            }
+
+            // Default to hatch 1 user per second if not otherwise configured.
+            if !configuration.contains(&"--hatch-rate") {
+                configuration.extend_from_slice(&["--hatch-rate", "1"]);
+            }
+
+            // Default to running for 1 second if not otherwise configured.
+            if !configuration.contains(&"--run-time") {
+                configuration.extend_from_slice(&["--run-time", "1"]);
+            }
         }
 
         // Default to disabling coordinated omission mitigation if not otherwise configured
@@ -0,0 +1,103 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "4";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).delay(Duration::from_millis(100));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// How many tasks were running and requests were in flight is tracked each second.
+async fn test_concurrency() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Each user keeps a request in flight almost all of the time.
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    assert!(index_metrics.concurrency.busy.len() >= 2);
+    assert!(index_metrics.concurrency.average() > 0.0);
+    assert!(index_metrics.concurrency.maximum() > 1.0);
+    assert!(index_metrics.concurrency.maximum() >= index_metrics.concurrency.average());
+    // Each user has at most one request in flight, allowing for rounding to milliseconds.
+    assert!(index_metrics.concurrency.maximum() < 4.1);
+
+    // The task runs as long as its request is in flight.
+    let task_metrics = &goose_metrics.tasks[0][0];
+    assert!(task_metrics.concurrency.maximum() > 1.0);
+}
+
+#[tokio::test]
+#[serial]
+/// Concurrency isn't tracked over time with `--no-time-series`.
+async fn test_no_concurrency() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-time-series"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    assert!(index_metrics.concurrency.busy.is_empty());
+    assert!(goose_metrics.tasks[0][0].concurrency.busy.is_empty());
+}
@@ -402,6 +402,20 @@ async fn run_standalone_test(test_type: TestType) {
                         5 => {
                             assert!(response.starts_with("unrecognized command"));
 
+                            // Extend run_time.
+                            make_request(&mut test_state, "runtime +1h\r\n");
+                        }
+                        // Confirm the run_time is configured.
+                        6 => {
+                            assert!(response.starts_with("run_time configured"));
+
+                            // Shorten run_time.
+                            make_request(&mut test_state, "runtime -1h\r\n");
+                        }
+                        // Confirm the run_time is configured.
+                        7 => {
+                            assert!(response.starts_with("run_time configured"));
+
                             // Set run_time with seconds alone, and no "s".
                             make_request(
                                 &mut test_state,
@@ -523,21 +537,90 @@ async fn run_standalone_test(test_type: TestType) {
                         }
                     }
                 }
+                GooseControllerCommand::Disable => {
+                    match test_state.step {
+                        // Disable the task set on a running load test.
+                        0 => {
+                            make_request(&mut test_state, "disable LoadTest\r\n");
+                        }
+                        // Confirm the task set was disabled.
+                        1 => {
+                            assert!(response.starts_with("task set disabled"));
+
+                            // Try to disable a task set that doesn't exist.
+                            make_request(&mut test_state, "disable NoSuchTaskSet\r\n");
+                        }
+                        // Confirm a task set that doesn't exist can't be disabled.
+                        _ => {
+                            assert!(response.starts_with("failed to disable task set"));
+
+                            // Move onto the next command.
+                            test_state = update_state(Some(test_state), &test_type);
+                        }
+                    }
+                }
+                GooseControllerCommand::Enable => {
+                    match test_state.step {
+                        // Re-enable the disabled task set.
+                        0 => {
+                            make_request(&mut test_state, "enable LoadTest\r\n");
+                        }
+                        // Confirm the task set was enabled.
+                        1 => {
+                            assert!(response.starts_with("task set enabled"));
+
+                            // Try to enable a task set that doesn't exist.
+                            make_request(&mut test_state, "enable NoSuchTaskSet\r\n");
+                        }
+                        // Confirm a task set that doesn't exist can't be enabled.
+                        _ => {
+                            assert!(response.starts_with("failed to enable task set"));
+
+                            // Move onto the next command.
+                            test_state = update_state(Some(test_state), &test_type);
+                        }
+                    }
+                }
+                GooseControllerCommand::Annotate => {
+                    match test_state.step {
+                        // Annotate the running load test.
+                        0 => {
+                            make_request(&mut test_state, "annotate cache flushed\r\n");
+                        }
+                        // Confirm the annotation was recorded.
+                        _ => {
+                            assert!(response.starts_with("annotation recorded"));
+
+                            // Move onto the next command.
+                            test_state = update_state(Some(test_state), &test_type);
+                        }
+                    }
+                }
                 GooseControllerCommand::Stop => {
                     match test_state.step {
-                        // Try to configure users on a running load test.
+                        // Configure users on a running load test.
                         0 => {
-                            make_request(&mut test_state, "users 1\r\n");
+                            make_request(
+                                &mut test_state,
+                                &["users ", &USERS.to_string(), "\r\n"].concat(),
+                            );
                         }
-                        // Confirm users can not be configured on a running load test.
+                        // Confirm users can be configured on a running load test.
                         1 => {
-                            assert!(response.starts_with("load test not idle"));
+                            assert!(response.starts_with("users configured"));
+
+                            // Try to stop all users of a running load test.
+                            make_request(&mut test_state, "users 0\r\n");
+                        }
+                        // Confirm a running load test needs at least one user.
+                        2 => {
+                            assert!(response.starts_with("failed to reconfigure users"));
 
                             // Try to configure host on a running load test.
                             make_request(&mut test_state, "host http://localhost/\r\n");
                         }
                         // Confirm host can not be configured on a running load test.
-                        2 => {
+                        3 => {
                             assert!(response.starts_with("failed to reconfigure host"));
 
                             // Try to stop a running load test.
@@ -611,6 +694,9 @@ fn update_state(test_state: Option<TestState>, test_type: &TestType) -> TestStat
         GooseControllerCommand::ConfigJson,
         GooseControllerCommand::Metrics,
         GooseControllerCommand::MetricsJson,
+        GooseControllerCommand::Disable,
+        GooseControllerCommand::Enable,
+        GooseControllerCommand::Annotate,
         GooseControllerCommand::Stop,
         GooseControllerCommand::Shutdown,
     ];
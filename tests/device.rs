@@ -0,0 +1,129 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::device::GooseDeviceProfile;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const CUSTOM_PATH: &str = "/custom";
+
+// Indexes to the mock endpoints.
+const MOBILE_KEY: usize = 0;
+const BOT_KEY: usize = 1;
+const CUSTOM_KEY: usize = 2;
+
+// A User-Agent header set explicitly by a request.
+const CUSTOM_USER_AGENT: &str = "custom-agent";
+
+// Load test configuration.
+const USERS: &str = "4";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_custom(user: &mut GooseUser) -> GooseTaskResult {
+    let request_builder = user
+        .get_request_builder(&GooseMethod::Get, CUSTOM_PATH)?
+        .header("user-agent", CUSTOM_USER_AGENT);
+    let goose_request = GooseRequest::builder()
+        .path(CUSTOM_PATH)
+        .set_request_builder(request_builder)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the mobile index, store in vector at MOBILE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header("user-agent", GooseDeviceProfile::Mobile.user_agent())
+                .header_exists("accept-language");
+            then.status(200);
+        }),
+        // Next set up the bot index, store in vector at BOT_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header("user-agent", GooseDeviceProfile::Bot.user_agent());
+            then.status(200);
+        }),
+        // Next set up CUSTOM_PATH, store in vector at CUSTOM_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(CUSTOM_PATH)
+                .header("user-agent", CUSTOM_USER_AGENT);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users are assigned device profiles, setting headers on each request that doesn't set
+/// its own, and reporting response times per device.
+async fn test_device_profiles() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+            "--device-profiles",
+            "mobile:50,bot:50",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Devices")
+            .register_task(task!(get_index))
+            .register_task(task!(get_custom)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Every request was made with the headers of a device profile, and is counted by
+    // device.
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    assert!(index_metrics.fail_count == 0);
+    let device_count = |device: &str| {
+        index_metrics
+            .dimensions
+            .get(&format!("device: {}", device))
+            .map_or(0, |data| data.counter)
+    };
+    assert_eq!(
+        device_count("mobile") + device_count("bot"),
+        index_metrics.success_count
+    );
+    mock_endpoints[MOBILE_KEY].assert_hits(device_count("mobile"));
+    mock_endpoints[BOT_KEY].assert_hits(device_count("bot"));
+
+    // Requests setting their own User-Agent header keep it.
+    let custom_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", CUSTOM_PATH))
+        .unwrap();
+    assert!(custom_metrics.fail_count == 0);
+    mock_endpoints[CUSTOM_KEY].assert_hits(custom_metrics.success_count);
+}
@@ -0,0 +1,110 @@
+use httpmock::{prelude::HttpMockRequest, Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const CHALLENGE_KEY: usize = 0;
+const FIRST_NONCE_KEY: usize = 1;
+const NEXT_NONCE_KEY: usize = 2;
+
+// The challenge sent to unauthorized requests.
+const CHALLENGE: &str = r#"Digest realm="goose", qop="auth", nonce="0123456789abcdef""#;
+
+// Test task.
+pub async fn set_credentials(user: &mut GooseUser) -> GooseTaskResult {
+    user.set_digest_credentials("User", "Password");
+    Ok(())
+}
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// The value of the Authorization header, if any.
+fn authorization(request: &HttpMockRequest) -> Option<String> {
+    request
+        .headers
+        .as_ref()?
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+        .map(|(_, value)| value.to_string())
+}
+
+// Whether the request answers the challenge with the given nonce count.
+fn authorized(request: &HttpMockRequest, first: bool) -> bool {
+    match authorization(request) {
+        Some(value) => {
+            value.starts_with(
+                r#"Digest username="User", realm="goose", nonce="0123456789abcdef", uri="/""#,
+            ) && value.contains(", nc=00000001,") == first
+        }
+        None => false,
+    }
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the challenge to unauthorized requests, store in vector at
+        // CHALLENGE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .matches(|request| authorization(request).is_none());
+            then.status(401).header("www-authenticate", CHALLENGE);
+        }),
+        // Next set up the first request answering the challenge, store in vector at
+        // FIRST_NONCE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .matches(|request| authorized(request, true));
+            then.status(200);
+        }),
+        // Finally set up further requests reusing the nonce, store in vector at
+        // NEXT_NONCE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .matches(|request| authorized(request, false));
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The first request is challenged and retried, then further requests are authorized in
+/// advance with an incremented nonce count.
+async fn test_digest() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index")
+            .register_task(task!(set_credentials).set_on_start())
+            .register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 1);
+    assert!(request.fail_count == 0);
+
+    // Only the first request was challenged.
+    mock_endpoints[CHALLENGE_KEY].assert_hits(1);
+    mock_endpoints[FIRST_NONCE_KEY].assert_hits(1);
+    mock_endpoints[NEXT_NONCE_KEY].assert_hits(request.success_count - 1);
+}
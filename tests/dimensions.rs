@@ -0,0 +1,115 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const CACHED_PATH: &str = "/cached.html";
+const UNCACHED_PATH: &str = "/uncached.html";
+
+// Indexes to the above paths.
+const CACHED_KEY: usize = 0;
+const UNCACHED_KEY: usize = 1;
+
+// The response header tracked as a metrics dimension.
+const CACHE_HEADER: &str = "X-Cache";
+
+// Load test configuration.
+const USERS: &str = "2";
+const REPORT_FILE: &str = "dimensions-report.html";
+
+// Test task.
+pub async fn get_cached(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(CACHED_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_uncached(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(UNCACHED_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up CACHED_PATH, store in vector at CACHED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CACHED_PATH);
+            then.status(200).header(CACHE_HEADER, "HIT");
+        }),
+        // Next set up UNCACHED_PATH, store in vector at UNCACHED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(UNCACHED_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Response times are grouped by the values of the configured response headers, in the
+/// metrics and in the html report.
+async fn test_header_dimensions() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+            "--header-dimensions",
+            "X-Cache, CF-Cache-Status",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Dimensions")
+            .register_task(task!(get_cached))
+            .register_task(task!(get_uncached)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let cached_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", CACHED_PATH))
+        .unwrap();
+    let uncached_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", UNCACHED_PATH))
+        .unwrap();
+
+    // Every request to the cached page was a cache hit, and the missing header was
+    // tracked as well.
+    assert_eq!(
+        cached_metrics.dimensions.keys().collect::<Vec<_>>(),
+        vec!["cf-cache-status: (none)", "x-cache: HIT"]
+    );
+    mock_endpoints[CACHED_KEY].assert_hits(cached_metrics.dimensions["x-cache: HIT"].counter);
+
+    // The uncached page never returned the header.
+    assert_eq!(
+        uncached_metrics.dimensions.keys().collect::<Vec<_>>(),
+        vec!["cf-cache-status: (none)", "x-cache: (none)"]
+    );
+    mock_endpoints[UNCACHED_KEY]
+        .assert_hits(uncached_metrics.dimensions["x-cache: (none)"].counter);
+
+    // The dimensions are included in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("Response Time Dimensions"));
+    assert!(report.contains("x-cache: HIT"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
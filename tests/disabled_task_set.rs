@@ -0,0 +1,85 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const ENABLED_PATH: &str = "/enabled";
+const DISABLED_PATH: &str = "/disabled";
+
+// Indexes to the above paths.
+const ENABLED_KEY: usize = 0;
+const DISABLED_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "4";
+
+// Test task.
+pub async fn get_enabled(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ENABLED_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_disabled(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(DISABLED_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up ENABLED_PATH, store in vector at ENABLED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ENABLED_PATH);
+            then.status(200);
+        }),
+        // Next set up DISABLED_PATH, store in vector at DISABLED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(DISABLED_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users running a disabled task set are launched, but idle without running any tasks.
+async fn test_disabled_task_set() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Enabled").register_task(task!(get_enabled)),
+        None,
+        None,
+    )
+    .register_taskset(
+        taskset!("Disabled")
+            .set_disabled()
+            .register_task(task!(get_disabled).set_on_start())
+            .register_task(task!(get_disabled)),
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // All users were launched.
+    assert!(goose_metrics.users == 4);
+
+    // Only users running the enabled task set ran tasks.
+    assert!(mock_endpoints[ENABLED_KEY].hits() > 0);
+    assert!(mock_endpoints[DISABLED_KEY].hits() == 0);
+}
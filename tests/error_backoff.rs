@@ -0,0 +1,80 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const ERROR_PATH: &str = "/error";
+
+// Indexes to the above paths.
+const ERROR_KEY: usize = 0;
+
+// Load test configuration.
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_error(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ERROR_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up ERROR_PATH, store in vector at ERROR_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ERROR_PATH);
+            then.status(503);
+        }),
+    ]
+}
+
+// Run a load test against a failing endpoint, returning how many requests were made.
+async fn run_load_test(task_set: GooseTaskSet) -> usize {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+    let configuration =
+        common::build_configuration(&server, vec!["--run-time", RUN_TIME, "--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(configuration, &task_set, None, None);
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /error").unwrap();
+    assert!(request.success_count == 0);
+    mock_endpoints[ERROR_KEY].assert_hits(request.fail_count);
+    request.fail_count
+}
+
+#[tokio::test]
+#[serial]
+/// Users back off after consecutive failed requests, making far fewer requests than
+/// users that don't back off.
+async fn test_error_backoff() {
+    let backoff_requests = run_load_test(
+        taskset!("Backoff")
+            .register_task(task!(get_error))
+            .set_error_backoff(Duration::from_millis(500), Duration::from_secs(5))
+            .unwrap(),
+    )
+    .await;
+    // The user waits 0.5, 1, 2 and then 4 seconds after each failed request, so can't
+    // make more than 4 requests while starting and running for 2 seconds.
+    assert!(backoff_requests > 0);
+    assert!(backoff_requests <= 4);
+
+    let requests = run_load_test(taskset!("NoBackoff").register_task(task!(get_error))).await;
+    assert!(requests > backoff_requests * 10);
+}
+
+#[tokio::test]
+/// Back off times must be valid.
+async fn test_invalid_error_backoff() {
+    assert!(taskset!("Invalid")
+        .set_error_backoff(Duration::from_millis(0), Duration::from_secs(1))
+        .is_err());
+    assert!(taskset!("Invalid")
+        .set_error_backoff(Duration::from_secs(2), Duration::from_secs(1))
+        .is_err());
+}
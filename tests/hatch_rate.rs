@@ -0,0 +1,121 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Instant;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const FAST_PATH: &str = "/fast";
+const SLOW_PATH: &str = "/slow";
+
+// Indexes to the above paths.
+const FAST_KEY: usize = 0;
+const SLOW_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "4";
+
+// Test task.
+pub async fn get_fast(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(FAST_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_slow(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(SLOW_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up FAST_PATH, store in vector at FAST_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(FAST_PATH);
+            then.status(200);
+        }),
+        // Next set up SLOW_PATH, store in vector at SLOW_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SLOW_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// A task set with its own fast hatch rate launches its users without waiting on the
+/// slower global hatch rate.
+async fn test_task_set_fast_hatch_rate() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Launch 3 Fast users and 1 Slow user, with a global hatch rate of 1 user per second.
+    let configuration =
+        common::build_configuration(&server, vec!["--users", USERS, "--hatch-rate", "1"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Fast")
+            .set_weight(3)
+            .unwrap()
+            .set_hatch_rate("100")
+            .unwrap()
+            .register_task(task!(get_fast)),
+        None,
+        None,
+    )
+    .register_taskset(taskset!("Slow").register_task(task!(get_slow)));
+
+    let started = Instant::now();
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // All users were launched.
+    assert!(goose_metrics.users == 4);
+    assert!(mock_endpoints[FAST_KEY].hits() > 0);
+    assert!(mock_endpoints[SLOW_KEY].hits() > 0);
+
+    // Launching all 4 users at the global hatch rate would have taken 3 seconds before the
+    // 1 second load test even started.
+    assert!(started.elapsed().as_secs() < 3);
+}
+
+#[tokio::test]
+#[serial]
+/// A task set with its own slow hatch rate launches its users more slowly than the
+/// global hatch rate.
+async fn test_task_set_slow_hatch_rate() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Launch 2 Fast users and 2 Slow users, with a global hatch rate of 100 users per
+    // second.
+    let configuration =
+        common::build_configuration(&server, vec!["--users", USERS, "--hatch-rate", "100"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Fast").register_task(task!(get_fast)),
+        None,
+        None,
+    )
+    .register_taskset(
+        taskset!("Slow")
+            .set_hatch_rate("0.5")
+            .unwrap()
+            .register_task(task!(get_slow)),
+    );
+
+    let started = Instant::now();
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // All users were launched.
+    assert!(goose_metrics.users == 4);
+    assert!(mock_endpoints[FAST_KEY].hits() > 0);
+    assert!(mock_endpoints[SLOW_KEY].hits() > 0);
+
+    // The second Slow user was launched 2 seconds after the first, before the 1 second load
+    // test started.
+    assert!(started.elapsed().as_secs() >= 3);
+}
@@ -0,0 +1,150 @@
+use httpmock::{
+    Method::{GET, POST},
+    Mock, MockServer,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const INFLUXDB_V1_PATH: &str = "/write";
+const INFLUXDB_V2_PATH: &str = "/api/v2/write";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const INFLUXDB_V1_KEY: usize = 1;
+const INFLUXDB_V2_KEY: usize = 2;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up INFLUXDB_V1_PATH, store in vector at INFLUXDB_V1_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(INFLUXDB_V1_PATH)
+                .query_param("db", "goose")
+                .query_param("precision", "ms")
+                .body_contains("goose_request,method=GET,name=/,status_code=200,user=");
+            then.status(204);
+        }),
+        // Next set up INFLUXDB_V2_PATH, store in vector at INFLUXDB_V2_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(INFLUXDB_V2_PATH)
+                .query_param("org", "acme")
+                .query_param("bucket", "goose")
+                .query_param("precision", "ms")
+                .header("authorization", "Token secret")
+                .body_contains(",run_id=nightly response_time=");
+            then.status(204);
+        }),
+    ]
+}
+
+// Run a load test with the InfluxDB run-time options, returning the configured endpoints.
+async fn run_load_test<'a>(server: &'a MockServer, custom: Vec<&str>) -> Vec<Mock<'a>> {
+    let mock_endpoints = setup_mock_server_endpoints(server);
+
+    let mut options = vec!["--users", "2", "--hatch-rate", "2", "--run-time", "2"];
+    options.extend(custom);
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest")
+            .set_wait_time(Duration::from_millis(100), Duration::from_millis(200))
+            .unwrap()
+            .register_task(task!(get_index)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    mock_endpoints
+}
+
+#[tokio::test]
+#[serial]
+/// Each request is written to the InfluxDB v1 API.
+async fn test_influxdb_v1() {
+    let server = MockServer::start();
+    let influxdb_url = server.url(format!("{}?db=goose", INFLUXDB_V1_PATH));
+
+    // Write each request on its own, so writes can be compared with requests.
+    let mock_endpoints = run_load_test(
+        &server,
+        vec![
+            "--influxdb-url",
+            &influxdb_url,
+            "--influxdb-batch-size",
+            "1",
+        ],
+    )
+    .await;
+    assert_eq!(
+        mock_endpoints[INFLUXDB_V1_KEY].hits(),
+        mock_endpoints[INDEX_KEY].hits()
+    );
+    assert_eq!(mock_endpoints[INFLUXDB_V2_KEY].hits(), 0);
+}
+
+#[tokio::test]
+#[serial]
+/// Requests are written in batches to the InfluxDB v2 API, authenticated with a token.
+async fn test_influxdb_v2() {
+    let server = MockServer::start();
+    let influxdb_url = server.url(format!("{}?org=acme&bucket=goose", INFLUXDB_V2_PATH));
+
+    let mock_endpoints = run_load_test(
+        &server,
+        vec![
+            "--influxdb-url",
+            &influxdb_url,
+            "--influxdb-token",
+            "secret",
+            "--run-id",
+            "nightly",
+        ],
+    )
+    .await;
+    // Requests are written at least once a second, and at most once per request.
+    let writes = mock_endpoints[INFLUXDB_V2_KEY].hits();
+    assert!(writes > 0);
+    assert!(writes <= mock_endpoints[INDEX_KEY].hits());
+    assert_eq!(mock_endpoints[INFLUXDB_V1_KEY].hits(), 0);
+}
+
+#[tokio::test]
+#[serial]
+/// Requests can't be written to InfluxDB with `--no-metrics`.
+async fn test_influxdb_no_metrics() {
+    let server = MockServer::start();
+    let influxdb_url = server.url(format!("{}?db=goose", INFLUXDB_V1_PATH));
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--influxdb-url", &influxdb_url, "--no-metrics"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    assert!(goose_attack.execute().await.is_err());
+}
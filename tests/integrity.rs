@@ -0,0 +1,112 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const DOWNLOAD_PATH: &str = "/download";
+
+// Indexes to the above paths.
+const DOWNLOAD_KEY: usize = 0;
+
+// The response body, and its SHA-1 checksum.
+const BODY: &str = "<p>Honk honk!</p>";
+const REPEAT: usize = 100;
+const SHA1: &str = "16c180a80e3c19ee1a8a996a9982eb0b8f687ec1";
+
+// Test task.
+pub async fn get_verified(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(DOWNLOAD_PATH)
+        .name("verified")
+        .expect_body_sha1(SHA1)
+        .expect_body_length(BODY.len() * REPEAT, BODY.len() * REPEAT)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_wrong_checksum(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(DOWNLOAD_PATH)
+        .name("wrong checksum")
+        .expect_body_sha1("da39a3ee5e6b4b0d3255bfef95601890afd80709")
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_wrong_length(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(DOWNLOAD_PATH)
+        .name("wrong length")
+        .expect_body_length(0, BODY.len())
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up DOWNLOAD_PATH, store in vector at DOWNLOAD_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(DOWNLOAD_PATH);
+            then.status(200).body(BODY.repeat(REPEAT));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Response bodies are verified against the expected checksum and length, counting
+/// mismatches as failed requests and integrity failures.
+async fn test_body_integrity() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Download")
+            .register_task(task!(get_verified))
+            .register_task(task!(get_wrong_checksum))
+            .register_task(task!(get_wrong_length)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // The intact body passed verification.
+    let verified = goose_metrics.requests.get("GET verified").unwrap();
+    assert!(verified.success_count > 0);
+    assert!(verified.fail_count == 0);
+    assert!(verified.integrity_checks == verified.success_count);
+    assert!(verified.integrity_failures == 0);
+
+    // Unexpected bodies failed verification.
+    let mut requests = verified.success_count;
+    for name in &["GET wrong checksum", "GET wrong length"] {
+        let request = goose_metrics.requests.get(*name).unwrap();
+        assert!(request.success_count == 0);
+        assert!(request.fail_count > 0);
+        assert!(request.integrity_checks == request.fail_count);
+        assert!(request.integrity_failures == request.fail_count);
+        requests += request.fail_count;
+    }
+    mock_endpoints[DOWNLOAD_KEY].assert_hits(requests);
+
+    // Integrity failures are reported as errors.
+    assert!(goose_metrics
+        .errors
+        .keys()
+        .any(|error| error.contains("body checksum mismatch: wrong checksum")));
+    assert!(goose_metrics
+        .errors
+        .keys()
+        .any(|error| error.contains("body length mismatch: wrong length")));
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "json-schema")]
+
+use httpmock::{Method::GET, Mock, MockServer};
+use serde_json::json;
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const VALID_PATH: &str = "/valid";
+const INVALID_PATH: &str = "/invalid";
+const NOT_JSON_PATH: &str = "/not-json";
+
+// Indexes to the above paths.
+const VALID_KEY: usize = 0;
+const INVALID_KEY: usize = 1;
+const NOT_JSON_KEY: usize = 2;
+
+// Test task.
+pub async fn get_valid(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(VALID_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_invalid(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INVALID_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_not_json(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(NOT_JSON_PATH).await?;
+    Ok(())
+}
+
+// The schema all responses must match.
+fn users_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "required": ["users"],
+        "properties": { "users": { "type": "array" } }
+    })
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up VALID_PATH, store in vector at VALID_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(VALID_PATH);
+            then.status(200).json_body(json!({ "users": [] }));
+        }),
+        // Next set up INVALID_PATH, store in vector at INVALID_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INVALID_PATH);
+            then.status(200).json_body(json!({ "users": "none" }));
+        }),
+        // Next set up NOT_JSON_PATH, store in vector at NOT_JSON_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(NOT_JSON_PATH);
+            then.status(200).body("<html></html>");
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Responses are validated against the schema registered for their request name, and
+/// violations are counted separately.
+async fn test_json_schema() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let mut goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Api")
+            .register_task(task!(get_valid))
+            .register_task(task!(get_invalid))
+            .register_task(task!(get_not_json)),
+        None,
+        None,
+    );
+    // All paths share the same schema.
+    for path in [VALID_PATH, INVALID_PATH, NOT_JSON_PATH] {
+        goose_attack = goose_attack
+            .register_json_schema(path, &users_schema())
+            .unwrap();
+    }
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let valid = goose_metrics.requests.get("GET /valid").unwrap();
+    assert!(valid.success_count > 0);
+    assert!(valid.fail_count == 0);
+    assert!(valid.schema_checks == valid.success_count);
+    assert!(valid.schema_violations == 0);
+    mock_endpoints[VALID_KEY].assert_hits(valid.success_count);
+
+    for (key, name) in [
+        (INVALID_KEY, "GET /invalid"),
+        (NOT_JSON_KEY, "GET /not-json"),
+    ] {
+        let invalid = goose_metrics.requests.get(name).unwrap();
+        assert!(invalid.success_count == 0);
+        assert!(invalid.fail_count > 0);
+        assert!(invalid.schema_checks == invalid.fail_count);
+        assert!(invalid.schema_violations == invalid.fail_count);
+        mock_endpoints[key].assert_hits(invalid.fail_count);
+    }
+}
+
+#[tokio::test]
+#[serial]
+/// Invalid schemas can't be registered.
+async fn test_invalid_json_schema() {
+    let server = MockServer::start();
+
+    let configuration = common::build_configuration(&server, vec![]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Api").register_task(task!(get_valid)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack
+        .register_json_schema(VALID_PATH, &json!({ "type": "not-a-type" }))
+        .is_err());
+}
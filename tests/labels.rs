@@ -0,0 +1,104 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "2";
+const REPORT_FILE: &str = "labels-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Labels are included in the metrics, their JSON serialization and the html report.
+async fn test_labels() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--label",
+            "env=staging",
+            "--label",
+            "build=1.2.3",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Labels").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Labels are sorted by key.
+    assert_eq!(
+        goose_metrics.labels.iter().collect::<Vec<_>>(),
+        vec![
+            (&"build".to_string(), &"1.2.3".to_string()),
+            (&"env".to_string(), &"staging".to_string())
+        ]
+    );
+
+    // The labels are included when the metrics are serialized.
+    let json = serde_json::to_value(&goose_metrics).unwrap();
+    assert_eq!(json["labels"]["build"], "1.2.3");
+    assert_eq!(json["labels"]["env"], "staging");
+
+    // The labels are included in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("build=1.2.3, env=staging"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Labels must be formatted as KEY=VALUE.
+async fn test_invalid_label() {
+    let server = MockServer::start();
+
+    let configuration =
+        common::build_configuration(&server, vec!["--label", "staging", "--users", USERS]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Labels").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -1,9 +1,11 @@
 use httpmock::{Method::GET, Mock, MockServer};
 use serial_test::serial;
 use std::fmt;
+use std::sync::{Arc, Mutex};
 
 mod common;
 
+use goose::logger::{GooseLog, GooseLogSink, GooseLogType};
 use goose::prelude::*;
 
 // Paths used in load tests performed during these tests.
@@ -17,6 +19,13 @@ const ERROR_KEY: usize = 1;
 // Load test configuration.
 const EXPECT_WORKERS: usize = 2;
 
+// A log file in a directory that doesn't exist.
+const UNWRITABLE_LOG: &str = "missing-directory/request-log.json";
+
+// A log file that can be opened, but every write to it fails as the device is full.
+#[cfg(target_os = "linux")]
+const FULL_LOG: &str = "/dev/full";
+
 // There are multiple test variations in this file.
 enum TestType {
     // Test with requests log enabled.
@@ -89,7 +98,7 @@ fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock> {
         // Next, set up ERROR_PATH, store in vector at ERROR_KEY.
         server.mock(|when, then| {
             when.method(GET).path(ERROR_PATH);
-            then.status(503);
+            then.status(503).header("set-cookie", "session=secret");
         }),
     ]
 }
@@ -465,6 +474,20 @@ async fn test_requests_logs_pretty_gaggle() {
     run_gaggle_test(TestType::Requests, "pretty").await;
 }
 
+#[tokio::test]
+// Enable combined-formatted requests log.
+async fn test_requests_logs_combined() {
+    run_standalone_test(TestType::Requests, "combined").await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+#[serial]
+#[cfg_attr(not(feature = "gaggle"), ignore)]
+// Enable combined-formatted requests log, in Gaggle mode.
+async fn test_requests_logs_combined_gaggle() {
+    run_gaggle_test(TestType::Requests, "combined").await;
+}
+
 #[tokio::test]
 // Enable json-formatted tasks log.
 async fn test_tasks_logs_json() {
@@ -618,3 +641,338 @@ async fn test_all_logs_pretty() {
 async fn test_all_logs_pretty_gaggle() {
     run_gaggle_test(TestType::All, "pretty").await;
 }
+
+#[tokio::test]
+// Send the debug log to syslog over TCP.
+async fn test_debug_logs_syslog() {
+    // Receive syslog messages on a local TCP port.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let debug_log = format!("syslog:tcp://{}", listener.local_addr().unwrap());
+    let receiver = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut received = String::new();
+        std::io::Read::read_to_string(&mut stream, &mut received).unwrap();
+        received
+    });
+
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--debug-log",
+            &debug_log,
+            "--users",
+            "4",
+            "--hatch-rate",
+            "4",
+        ],
+    );
+
+    // Run the Goose Attack.
+    common::run_load_test(
+        common::build_load_test(configuration, &get_tasks(), None, None),
+        None,
+    )
+    .await;
+
+    // Split the received octet-counted messages.
+    let mut received = receiver.join().unwrap();
+    let mut messages = Vec::new();
+    while let Some((length, rest)) = received.split_once(' ') {
+        let length: usize = length.parse().unwrap();
+        messages.push(rest[..length].to_string());
+        received = rest[length..].to_string();
+    }
+
+    // Each failed request was logged to syslog.
+    assert!(!messages.is_empty());
+    assert!(messages.len() == mock_endpoints[ERROR_KEY].hits());
+    for message in messages {
+        assert!(message.starts_with("<134>1 "));
+        assert!(message.contains(" goose "));
+        assert!(message.contains(" debug - {"));
+    }
+}
+
+#[tokio::test]
+// Rotate the request log by size, keeping the two most recent compressed files.
+async fn test_requests_logs_rotate() {
+    let request_log = "rotate-request-log.csv";
+    let rotated_logs: Vec<String> = (1..=3)
+        .map(|index| format!("{}.{}.gz", request_log, index))
+        .collect();
+
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--request-log",
+            request_log,
+            "--request-format",
+            "csv",
+            "--log-rotate-size",
+            "1K",
+            "--log-rotate-keep",
+            "2",
+            "--log-rotate-compress",
+            "--users",
+            "4",
+            "--hatch-rate",
+            "4",
+            "--run-time",
+            "2",
+        ],
+    );
+    common::run_load_test(
+        common::build_load_test(configuration, &get_tasks(), None, None),
+        None,
+    )
+    .await;
+
+    // Many more than 1K of requests were logged, so the log was rotated, and only two
+    // rotated files were kept.
+    assert!(mock_endpoints[INDEX_KEY].hits() > 100);
+    assert!(std::path::Path::new(&rotated_logs[0]).exists());
+    assert!(std::path::Path::new(&rotated_logs[1]).exists());
+    assert!(!std::path::Path::new(&rotated_logs[2]).exists());
+
+    // Each file starts with the CSV header.
+    let log = std::fs::read_to_string(request_log).unwrap();
+    assert!(log.starts_with("elapsed,"));
+    let mut rotated_log = String::new();
+    std::io::Read::read_to_string(
+        &mut flate2::read::GzDecoder::new(std::fs::File::open(&rotated_logs[0]).unwrap()),
+        &mut rotated_log,
+    )
+    .unwrap();
+    assert!(rotated_log.starts_with("elapsed,"));
+    assert!(rotated_log.len() >= 1024);
+
+    common::cleanup_files(vec![request_log, &rotated_logs[0], &rotated_logs[1]]);
+}
+
+// Captures the messages written to a log in-process.
+#[derive(Clone, Default)]
+struct CapturedLog {
+    messages: Arc<Mutex<Vec<(GooseLog, String)>>>,
+}
+#[async_trait::async_trait]
+impl GooseLogSink for CapturedLog {
+    async fn write(&mut self, message: &GooseLog, formatted: &[u8]) -> std::io::Result<()> {
+        let formatted = String::from_utf8(formatted.to_vec()).unwrap();
+        self.messages
+            .lock()
+            .unwrap()
+            .push((message.clone(), formatted));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+// Capture the debug and request logs in-process with custom log sinks.
+async fn test_custom_log_sink() {
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--users", "2", "--hatch-rate", "2", "--run-time", "2"],
+    );
+    let debug_log = CapturedLog::default();
+    let request_log = CapturedLog::default();
+    let goose_attack = common::build_load_test(configuration, &get_tasks(), None, None)
+        .set_log_sink(GooseLogType::Debug, debug_log.clone())
+        .set_log_sink(GooseLogType::Request, request_log.clone());
+    common::run_load_test(goose_attack, None).await;
+
+    // Each failed request was captured in the debug log.
+    let debug_messages = debug_log.messages.lock().unwrap();
+    assert_eq!(debug_messages.len(), mock_endpoints[ERROR_KEY].hits());
+    for (message, _) in debug_messages.iter() {
+        match message {
+            GooseLog::Debug(debug) => {
+                assert_eq!(debug.tag, "loaded /error and got non-200 message")
+            }
+            _ => panic!("unexpected message in debug log: {:?}", message),
+        }
+    }
+
+    // Each request was captured in the request log, together with the formatted message.
+    let request_messages = request_log.messages.lock().unwrap();
+    let requests = request_messages
+        .iter()
+        .filter(|(message, formatted)| match message {
+            GooseLog::Request(request) => {
+                let value: serde_json::Value = serde_json::from_str(formatted).unwrap();
+                assert_eq!(value["name"], request.name);
+                !request.update
+            }
+            _ => panic!("unexpected message in request log: {:?}", message),
+        })
+        .count();
+    assert_eq!(
+        requests,
+        mock_endpoints[INDEX_KEY].hits() + mock_endpoints[ERROR_KEY].hits()
+    );
+}
+
+#[tokio::test]
+// Sensitive headers are masked before they are logged, unless disabled.
+async fn test_redact_headers() {
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    for (custom, redacted) in [
+        (vec![], true),
+        (vec!["--redact-headers", "x-api-key"], true),
+        (vec!["--no-redact-headers"], false),
+    ] {
+        let mut options = vec!["--users", "2", "--hatch-rate", "2", "--run-time", "1"];
+        options.extend(custom);
+        let configuration = common::build_configuration(&server, options);
+        let debug_log = CapturedLog::default();
+        let request_log = CapturedLog::default();
+        let goose_attack = common::build_load_test(
+            configuration,
+            &get_tasks()
+                .set_default_header("Authorization", "Bearer secret")
+                .unwrap(),
+            None,
+            None,
+        )
+        .set_log_sink(GooseLogType::Debug, debug_log.clone())
+        .set_log_sink(GooseLogType::Request, request_log.clone());
+        common::run_load_test(goose_attack, None).await;
+
+        // The request header is masked in the request log.
+        let request_messages = request_log.messages.lock().unwrap();
+        assert!(!request_messages.is_empty());
+        for (_, formatted) in request_messages.iter() {
+            assert_eq!(formatted.contains("Bearer secret"), !redacted);
+            assert_eq!(formatted.contains("[REDACTED]"), redacted);
+        }
+
+        // The response header is masked in the debug log.
+        let debug_messages = debug_log.messages.lock().unwrap();
+        assert!(!debug_messages.is_empty());
+        for (_, formatted) in debug_messages.iter() {
+            assert_eq!(formatted.contains("session=secret"), !redacted);
+        }
+    }
+    assert!(mock_endpoints[ERROR_KEY].hits() > 0);
+
+    // Only valid header names can be masked.
+    let configuration =
+        common::build_configuration(&server, vec!["--redact-headers", "bad header"]);
+    let goose_attack = common::build_load_test(configuration, &get_tasks(), None, None);
+    assert!(goose_attack.execute().await.is_err());
+}
+
+#[tokio::test]
+// A log file that can't be created fails the load test before it starts.
+async fn test_log_failure() {
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--request-log", UNWRITABLE_LOG]);
+    let goose_attack = common::build_load_test(configuration, &get_tasks(), None, None);
+    assert!(goose_attack.execute().await.is_err());
+
+    // No requests were made.
+    assert!(mock_endpoints[INDEX_KEY].hits() == 0);
+    assert!(mock_endpoints[ERROR_KEY].hits() == 0);
+}
+
+#[tokio::test]
+// With --continue-without-logs, the load test runs without the log.
+async fn test_continue_without_logs() {
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--request-log", UNWRITABLE_LOG, "--continue-without-logs"],
+    );
+    common::run_load_test(
+        common::build_load_test(configuration, &get_tasks(), None, None),
+        None,
+    )
+    .await;
+
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(!std::path::Path::new(UNWRITABLE_LOG).exists());
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+// Messages that can't be written are dropped and counted in the metrics.
+async fn test_log_write_failure_drop() {
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--request-log", FULL_LOG, "--log-failure", "drop"],
+    );
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(configuration, &get_tasks(), None, None),
+        None,
+    )
+    .await;
+
+    // The load test ran, and the failures were counted.
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(
+        goose_metrics
+            .log_failures
+            .get(FULL_LOG)
+            .copied()
+            .unwrap_or(0)
+            > 0
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+// With --log-failure abort, the load test stops when writing to a log fails.
+async fn test_log_write_failure_abort() {
+    const RUN_TIME: usize = 10;
+
+    let server = MockServer::start();
+
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let run_time = RUN_TIME.to_string();
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--request-log",
+            FULL_LOG,
+            "--log-failure",
+            "abort",
+            "--run-time",
+            &run_time,
+        ],
+    );
+    let goose_metrics = common::run_load_test(
+        common::build_load_test(configuration, &get_tasks(), None, None),
+        None,
+    )
+    .await;
+
+    // The load test stopped before the run time elapsed.
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(goose_metrics.duration < RUN_TIME);
+    assert!(goose_metrics.log_failures.contains_key(FULL_LOG));
+}
@@ -0,0 +1,92 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::metrics::GooseMetrics;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Run a short load test with the provided run-time options.
+async fn run_load_test(server: &MockServer, options: Vec<&str>) -> GooseMetrics {
+    let mut options = options;
+    options.extend(vec![
+        "--users",
+        "2",
+        "--run-time",
+        "2",
+        "--no-reset-metrics",
+    ]);
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// Response time histograms and metrics over time are tracked by default.
+async fn test_metrics_tracked() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(&server, vec![]).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    mock_endpoints[INDEX_KEY].assert_hits(request.success_count);
+    assert!(!request.raw_data.times.is_empty());
+    assert!(!goose_metrics.tasks[0][0].times.is_empty());
+    assert!(!goose_metrics.active_users.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+/// Response time histograms and metrics over time can be disabled independently of
+/// the other metrics.
+async fn test_metrics_disabled() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(&server, vec!["--no-histograms", "--no-time-series"]).await;
+
+    // Requests are still counted and timed, but individual times aren't kept.
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 0);
+    mock_endpoints[INDEX_KEY].assert_hits(request.success_count);
+    assert!(request.raw_data.counter == request.success_count);
+    assert!(request.raw_data.times.is_empty());
+
+    // Tasks are still counted, but individual times aren't kept.
+    let task = &goose_metrics.tasks[0][0];
+    assert!(task.success_count == request.success_count);
+    assert!(task.times.is_empty());
+
+    // Active users aren't tracked over time.
+    assert!(goose_metrics.active_users.is_empty());
+}
@@ -0,0 +1,117 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+use goose::export::GooseMetricsExporter;
+use goose::metrics::GooseMetrics;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "2";
+const RUN_TIME: &str = "2";
+
+// What a test exporter received.
+#[derive(Default)]
+struct Exported {
+    intervals: usize,
+    final_requests: Option<usize>,
+}
+
+// Test exporter, recording what it received.
+struct TestExporter {
+    exported: Arc<Mutex<Exported>>,
+}
+#[async_trait::async_trait]
+impl GooseMetricsExporter for TestExporter {
+    async fn export(&mut self, _metrics: &GooseMetrics) -> Result<(), String> {
+        self.exported.lock().unwrap().intervals += 1;
+        Ok(())
+    }
+
+    async fn finish(&mut self, metrics: &GooseMetrics) -> Result<(), String> {
+        let requests = metrics
+            .requests
+            .values()
+            .map(|request| request.success_count + request.fail_count)
+            .sum();
+        self.exported.lock().unwrap().final_requests = Some(requests);
+        Ok(())
+    }
+}
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Registered exporters receive snapshots of all metrics while the load test runs, and
+/// the final metrics once it stops.
+async fn test_metrics_exporter() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--run-time",
+            RUN_TIME,
+        ],
+    );
+    let first = Arc::new(Mutex::new(Exported::default()));
+    let second = Arc::new(Mutex::new(Exported::default()));
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Export").register_task(task!(get_index)),
+        None,
+        None,
+    )
+    .register_metrics_exporter(TestExporter {
+        exported: first.clone(),
+    })
+    .register_metrics_exporter(TestExporter {
+        exported: second.clone(),
+    });
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    let requests = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    let requests = requests.success_count + requests.fail_count;
+    assert!(requests > 0);
+    assert!(mock_endpoints[INDEX_KEY].hits() >= requests);
+
+    // Both exporters received snapshots while the load test ran, and all requests once
+    // it stopped.
+    for exported in [first, second] {
+        let exported = exported.lock().unwrap();
+        assert!(exported.intervals > 0);
+        assert_eq!(exported.final_requests, Some(requests));
+    }
+}
@@ -0,0 +1,91 @@
+use futures::StreamExt;
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::metrics::GooseMetricEvent;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "2";
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Every request and task is streamed while the load test runs, together with
+/// snapshots of all metrics, and the stream ends when the load test finishes.
+async fn test_metrics_stream() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--run-time",
+            RUN_TIME,
+        ],
+    );
+    let mut goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Stream").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    // Consume the stream concurrently with the load test.
+    let mut metrics_stream = goose_attack.metrics_stream();
+    let consumer = tokio::spawn(async move {
+        let (mut requests, mut tasks, mut intervals) = (0, 0, 0);
+        while let Some(event) = metrics_stream.next().await {
+            match event {
+                GooseMetricEvent::Request(request_metric) => {
+                    assert_eq!(request_metric.name, INDEX_PATH);
+                    requests += 1;
+                }
+                GooseMetricEvent::Task(_) => tasks += 1,
+                GooseMetricEvent::Group(_) | GooseMetricEvent::Annotation(_) => (),
+                GooseMetricEvent::Interval(_) => intervals += 1,
+            }
+        }
+        (requests, tasks, intervals)
+    });
+
+    common::run_load_test(goose_attack, None).await;
+    let (requests, tasks, intervals) = consumer.await.unwrap();
+
+    // Every request and task was streamed.
+    assert!(requests > 0);
+    assert_eq!(requests, tasks);
+    mock_endpoints[INDEX_KEY].assert_hits(requests);
+
+    // At least one snapshot was streamed while the load test ran.
+    assert!(intervals > 0);
+}
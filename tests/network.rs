@@ -0,0 +1,117 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::network::GooseNetworkProfile;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const FAST_PATH: &str = "/fast";
+const SLOW_PATH: &str = "/slow";
+
+// Indexes to the above paths.
+const FAST_KEY: usize = 0;
+const SLOW_KEY: usize = 1;
+
+// The response body, 100 bytes long.
+const BODY: &str = "0123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789";
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn get_fast(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(FAST_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_slow(user: &mut GooseUser) -> GooseTaskResult {
+    let mut goose = user.get(SLOW_PATH).await?;
+
+    // The shaped response still has the original URL and body.
+    if let Ok(response) = goose.response {
+        if !response.url().as_str().ends_with(SLOW_PATH) {
+            return user.set_failure("wrong url", &mut goose.request, None, None);
+        }
+        let text = response.text().await.unwrap_or_default();
+        if text != BODY {
+            return user.set_failure("wrong body", &mut goose.request, None, Some(&text));
+        }
+    }
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up FAST_PATH, store in vector at FAST_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(FAST_PATH);
+            then.status(200).body(BODY);
+        }),
+        // Next set up SLOW_PATH, store in vector at SLOW_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SLOW_PATH);
+            then.status(200).body(BODY);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users running a task set with a network profile are delayed by its latency and
+/// bandwidth, while other users are not.
+async fn test_network_profile() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+        ],
+    );
+    // 200 milliseconds of latency, and 100 milliseconds to download the 100 byte body
+    // at 8 kbps.
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Fast").register_task(task!(get_fast)),
+        None,
+        None,
+    )
+    .register_taskset(
+        taskset!("Slow")
+            .set_network_profile(GooseNetworkProfile::new(Duration::from_millis(200), 8, 0))
+            .register_task(task!(get_slow)),
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let fast_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", FAST_PATH))
+        .unwrap();
+    let slow_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", SLOW_PATH))
+        .unwrap();
+
+    // Every slow request took at least 300 milliseconds, and was validated.
+    assert!(slow_metrics.raw_data.minimum_time >= 300);
+    assert!(slow_metrics.fail_count == 0);
+    mock_endpoints[SLOW_KEY].assert_hits(slow_metrics.success_count);
+
+    // The fast user wasn't slowed down, making many more requests.
+    assert!(fast_metrics.raw_data.maximum_time < 300);
+    assert!(fast_metrics.success_count > slow_metrics.success_count * 2);
+    mock_endpoints[FAST_KEY].assert_hits(fast_metrics.success_count);
+}
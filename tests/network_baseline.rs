@@ -0,0 +1,137 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const REPORT_FILE: &str = "network-baseline-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The network round-trip time to the host is measured before the load test starts, and
+/// included in the metrics and the html report.
+async fn test_network_baseline() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "1",
+            "--hatch-rate",
+            "1",
+            "--run-time",
+            "1",
+            "--rtt-samples",
+            "5",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Each connect to the mock server succeeded.
+    assert_eq!(goose_metrics.network_baselines.len(), 1);
+    let baseline = &goose_metrics.network_baselines[0];
+    assert_eq!(baseline.host, server.url("/"));
+    assert_eq!(baseline.address, Some(server.address().to_string()));
+    assert_eq!(baseline.round_trip_times.len(), 5);
+    assert_eq!(baseline.failures, 0);
+    assert!(baseline.minimum() <= baseline.median());
+    assert!(baseline.median() <= baseline.maximum());
+
+    // The round-trip time is displayed with the metrics and in the html report.
+    assert!(goose_metrics.to_string().contains("NETWORK BASELINE"));
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Network Baseline</h2>"));
+    assert!(report.contains(&format!("<td>{}</td>", server.address())));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Connects that fail are counted, without failing the load test.
+async fn test_network_baseline_unreachable() {
+    let server = MockServer::start();
+
+    // Nothing listens on port 1.
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "1",
+            "--hatch-rate",
+            "1",
+            "--run-time",
+            "1",
+            "--rtt-samples",
+            "2",
+            "--host",
+            "http://127.0.0.1:1/",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let baseline = &goose_metrics.network_baselines[0];
+    assert_eq!(baseline.address, Some("127.0.0.1:1".to_string()));
+    assert_eq!(baseline.round_trip_times.len(), 0);
+    assert_eq!(baseline.failures, 2);
+    assert_eq!(baseline.median(), None);
+}
+
+#[tokio::test]
+#[serial]
+/// The network round-trip time can't be measured with `--no-metrics`.
+async fn test_network_baseline_no_metrics() {
+    let server = MockServer::start();
+
+    let configuration =
+        common::build_configuration(&server, vec!["--rtt-samples", "5", "--no-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "ntlm")]
+
+use httpmock::{prelude::HttpMockRequest, Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const CHALLENGE_KEY: usize = 0;
+const NEGOTIATE_KEY: usize = 1;
+const AUTHENTICATE_KEY: usize = 2;
+
+// The start of the base64 encoded negotiate and authenticate messages.
+const NEGOTIATE_MESSAGE: &str = "NTLM TlRMTVNTUAABAAAA";
+const AUTHENTICATE_MESSAGE: &str = "NTLM TlRMTVNTUAADAAAA";
+
+// A base64 encoded challenge message, without target information.
+const CHALLENGE_MESSAGE: &str = "TlRMTVNTUAACAAAAAAAAADAAAAAFgomiASNFZ4mrze8AAAAAAAAAAAAAAAAwAAAA";
+
+// Test task.
+pub async fn set_credentials(user: &mut GooseUser) -> GooseTaskResult {
+    user.set_ntlm_credentials("Domain", "User", "Password");
+    Ok(())
+}
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// The value of the Authorization header, if any.
+fn authorization(request: &HttpMockRequest) -> Option<String> {
+    request
+        .headers
+        .as_ref()?
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+        .map(|(_, value)| value.to_string())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the challenge to unauthenticated requests, store in vector at
+        // CHALLENGE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .matches(|request| authorization(request).is_none());
+            then.status(401)
+                .header("www-authenticate", "Negotiate")
+                .header("www-authenticate", "NTLM");
+        }),
+        // Next set up the response to the negotiate message, store in vector at
+        // NEGOTIATE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH).matches(|request| {
+                authorization(request)
+                    .unwrap_or_default()
+                    .starts_with(NEGOTIATE_MESSAGE)
+            });
+            then.status(401)
+                .header("www-authenticate", format!("NTLM {}", CHALLENGE_MESSAGE));
+        }),
+        // Finally set up the response to the authenticate message, store in vector at
+        // AUTHENTICATE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH).matches(|request| {
+                authorization(request)
+                    .unwrap_or_default()
+                    .starts_with(AUTHENTICATE_MESSAGE)
+            });
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Requests challenged with NTLM authentication complete the handshake, and only the
+/// authenticated response is included in the metrics.
+async fn test_ntlm() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index")
+            .register_task(task!(set_credentials).set_on_start())
+            .register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+
+    // Each request was challenged, then authenticated.
+    mock_endpoints[CHALLENGE_KEY].assert_hits(request.success_count);
+    mock_endpoints[NEGOTIATE_KEY].assert_hits(request.success_count);
+    mock_endpoints[AUTHENTICATE_KEY].assert_hits(request.success_count);
+}
+
+#[tokio::test]
+#[serial]
+/// Without credentials, the challenge is returned.
+async fn test_ntlm_no_credentials() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count == 0);
+    assert!(request.fail_count > 0);
+    mock_endpoints[CHALLENGE_KEY].assert_hits(request.fail_count);
+    mock_endpoints[NEGOTIATE_KEY].assert_hits(0);
+}
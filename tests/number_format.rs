@@ -0,0 +1,84 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const REPORT_FILE: &str = "number-format-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Scaled times are displayed with their own unit in the html report.
+async fn test_scale_units() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--number-locale",
+            "de",
+            "--scale-units",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<th>Average</th>"));
+    assert!(!report.contains(" (ms)</th>"));
+    assert!(report.contains(" ms</td>"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Numbers can only be grouped as in a known locale.
+async fn test_invalid_number_locale() {
+    let server = MockServer::start();
+
+    let configuration =
+        common::build_configuration(&server, vec!["--number-locale", "not-a-locale"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -0,0 +1,77 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // Set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Less verbose output modes only change what is printed, metrics are still collected.
+async fn test_output_modes() {
+    for output in &["summary", "json", "quiet"] {
+        let server = MockServer::start();
+        let mock_endpoints = setup_mock_server_endpoints(&server);
+
+        let configuration =
+            common::build_configuration(&server, vec!["--output", output, "--no-reset-metrics"]);
+        let goose_attack = common::build_load_test(
+            configuration,
+            &taskset!("Index").register_task(task!(get_index)),
+            None,
+            None,
+        );
+        let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+        let hits = mock_endpoints[INDEX_KEY].hits();
+        assert!(hits > 0);
+        let index_metrics = goose_metrics
+            .requests
+            .get(&format!("GET {}", INDEX_PATH))
+            .unwrap();
+        assert_eq!(index_metrics.success_count, hits);
+    }
+}
+
+#[tokio::test]
+#[serial]
+/// Running metrics are only printed with verbose output.
+async fn test_output_running_metrics() {
+    let server = MockServer::start();
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--output", "summary", "--running-metrics", "1"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+
+    assert!(goose_attack.execute().await.is_err());
+}
@@ -0,0 +1,112 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const BROWSE_PATH: &str = "/browse";
+const CHECKOUT_PATH: &str = "/checkout";
+
+// Indexes to the above paths.
+const BROWSE_KEY: usize = 0;
+const CHECKOUT_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "4";
+const HATCH_RATE: &str = "4";
+const PLAN_FILE: &str = "plan.json";
+
+// Test task.
+pub async fn get_browse(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(BROWSE_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_checkout(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(CHECKOUT_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up BROWSE_PATH, store in vector at BROWSE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(BROWSE_PATH);
+            then.status(200);
+        }),
+        // Next set up CHECKOUT_PATH, store in vector at CHECKOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CHECKOUT_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The allocation of users to task sets, the weighted order of tasks and the hatch
+/// schedule are written to the plan file before the load test starts.
+async fn test_plan_file() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            HATCH_RATE,
+            "--plan-file",
+            PLAN_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Browse")
+            .set_weight(3)
+            .unwrap()
+            .register_task(task!(get_browse).set_weight(2).unwrap())
+            .register_task(task!(get_checkout).set_name("checkout").set_on_start()),
+        None,
+        None,
+    )
+    .register_taskset(taskset!("Checkout").register_task(task!(get_checkout)));
+
+    common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[BROWSE_KEY].hits() > 0);
+    assert!(mock_endpoints[CHECKOUT_KEY].hits() > 0);
+
+    let plan: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(PLAN_FILE).unwrap()).unwrap();
+    assert_eq!(plan["users"], 4);
+    assert_eq!(plan["hatch_rate"], 4.0);
+
+    // Users are allocated to task sets by weight.
+    let task_sets = plan["task_sets"].as_array().unwrap();
+    assert_eq!(task_sets.len(), 2);
+    assert_eq!(task_sets[0]["name"], "Browse");
+    assert_eq!(task_sets[0]["users"], 3);
+    assert_eq!(task_sets[1]["name"], "Checkout");
+    assert_eq!(task_sets[1]["users"], 1);
+
+    // Tasks are listed in the weighted order they run.
+    assert_eq!(task_sets[0]["on_start_tasks"][0]["name"], "checkout");
+    assert_eq!(task_sets[0]["tasks"].as_array().unwrap().len(), 2);
+    assert_eq!(task_sets[0]["tasks"][0]["index"], 0);
+
+    // One user is launched every 250 milliseconds.
+    let start_ms: Vec<u64> = plan["hatch_schedule"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["start_ms"].as_u64().unwrap())
+        .collect();
+    assert_eq!(start_ms, vec![0, 250, 500, 750]);
+
+    common::cleanup_files(vec![PLAN_FILE]);
+}
@@ -0,0 +1,96 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// The port live metrics are served on.
+const PROMETHEUS_PORT: &str = "5119";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Live metrics are served to Prometheus while the load test runs.
+async fn test_prometheus() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--prometheus-port",
+            PROMETHEUS_PORT,
+            "--run-time",
+            "3",
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Prometheus")
+            .register_task(task!(get_index))
+            .set_wait_time(
+                std::time::Duration::from_millis(100),
+                std::time::Duration::from_millis(200),
+            )
+            .unwrap(),
+        None,
+        None,
+    );
+    let load_test = tokio::spawn(common::run_load_test(goose_attack, None));
+
+    // Scrape the live metrics while the load test is running.
+    tokio::time::sleep(std::time::Duration::from_millis(2_000)).await;
+    let url = format!("http://127.0.0.1:{}", PROMETHEUS_PORT);
+    let response = reqwest::get(format!("{}/metrics", url)).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let scraped = response.text().await.unwrap();
+    let not_found = reqwest::get(format!("{}/other", url)).await.unwrap();
+    assert_eq!(not_found.status(), 404);
+
+    load_test.await.unwrap();
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // The scraped metrics describe the running load test.
+    assert!(scraped.contains("goose_users 1\n"));
+    assert!(scraped.contains("# TYPE goose_response_time_milliseconds histogram\n"));
+    assert!(scraped.contains(
+        "goose_response_time_milliseconds_bucket{method=\"GET\",name=\"/\",le=\"+Inf\"}"
+    ));
+    assert!(scraped.contains("goose_request_failures_total{method=\"GET\",name=\"/\"} 0\n"));
+    let requests: usize = scraped
+        .lines()
+        .find_map(|line| line.strip_prefix("goose_requests_total{method=\"GET\",name=\"/\"} "))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(requests > 0);
+    assert!(requests < mock_endpoints[INDEX_KEY].hits());
+    assert!(scraped.contains("goose_task_runs_total{taskset=\"Prometheus\",task=\"\"}"));
+
+    // The endpoint stops with the load test.
+    assert!(reqwest::get(format!("{}/metrics", url)).await.is_err());
+}
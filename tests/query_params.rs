@@ -0,0 +1,89 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const SEARCH_PATH: &str = "/search";
+
+// Indexes to the above paths.
+const RUST_KEY: usize = 0;
+const GOOSE_KEY: usize = 1;
+
+// Test task.
+pub async fn search_rust(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user
+        .get_with_params(SEARCH_PATH, &[("q", "rust & cargo"), ("page", "1")])
+        .await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn search_goose(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path("/search?lang=en")
+        .query("q", "goose/gaggle?")
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the search for rust, store in vector at RUST_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(SEARCH_PATH)
+                .query_param("q", "rust & cargo")
+                .query_param("page", "1");
+            then.status(200);
+        }),
+        // Next set up the search for goose, store in vector at GOOSE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(SEARCH_PATH)
+                .query_param("lang", "en")
+                .query_param("q", "goose/gaggle?");
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Query parameters are URL-encoded and appended to the path, and requests are named
+/// after the path without the parameters.
+async fn test_query_params() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Search")
+            .register_task(task!(search_rust))
+            .register_task(task!(search_goose)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Both searches were requested with correctly encoded parameters.
+    let rust_hits = mock_endpoints[RUST_KEY].hits();
+    let goose_hits = mock_endpoints[GOOSE_KEY].hits();
+    assert!(rust_hits > 0);
+    assert!(goose_hits > 0);
+
+    // Searches made with get_with_params() are named after the path.
+    let request = goose_metrics.requests.get("GET /search").unwrap();
+    assert!(request.success_count == rust_hits);
+    assert!(request.fail_count == 0);
+
+    // Parameters added with the GooseRequestBuilder aren't included in the name either.
+    let request = goose_metrics.requests.get("GET /search?lang=en").unwrap();
+    assert!(request.success_count == goose_hits);
+    assert!(request.fail_count == 0);
+}
@@ -0,0 +1,174 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const REPORT_FILE: &str = "ramp-profiles-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn stop_hatching(user: &mut GooseUser) -> GooseTaskResult {
+    // Stop launching users once the second user is running.
+    if user.weighted_users_index > 0 {
+        user.stop_hatching();
+    }
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The planned users follow the global hatch rate and the hatch rate of each task set, and
+/// are reached by the active users.
+async fn test_ramp_profiles() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Launch 2 Fast users 10 milliseconds apart, and 2 Slow users one second apart.
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "4",
+            "--hatch-rate",
+            "1",
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Fast")
+            .set_hatch_rate("100")
+            .unwrap()
+            .register_task(task!(get_index)),
+        None,
+        None,
+    )
+    .register_taskset(taskset!("Slow").register_task(task!(get_index)));
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // All users were planned and running by the end of the load test.
+    let ramp_profiles = &goose_metrics.ramp_profiles;
+    assert_eq!(ramp_profiles.len(), 3);
+    assert_eq!(ramp_profiles[0].taskset_index, None);
+    assert_eq!(ramp_profiles[0].active_users, goose_metrics.active_users);
+    let planned_users = &ramp_profiles[0].planned_users;
+    assert_eq!(planned_users.len(), goose_metrics.active_users.len());
+    assert_eq!(planned_users[..2], [2, 4]);
+    assert_eq!(*planned_users.last().unwrap(), 4);
+    assert_eq!(*goose_metrics.active_users.last().unwrap(), 4);
+
+    // Each task set has its own planned and active users.
+    assert_eq!(ramp_profiles[1].taskset_index, Some(0));
+    assert_eq!(ramp_profiles[1].name, "Fast");
+    assert_eq!(ramp_profiles[1].planned_users[..2], [1, 2]);
+    assert_eq!(*ramp_profiles[1].active_users.last().unwrap(), 2);
+    assert_eq!(ramp_profiles[2].name, "Slow");
+    assert_eq!(ramp_profiles[2].planned_users[..2], [1, 2]);
+    assert_eq!(*ramp_profiles[2].active_users.last().unwrap(), 2);
+
+    // The planned users are charted and compared in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("stroke-dasharray"));
+    assert!(report.contains("<h2>User Ramp</h2>"));
+    assert!(report.contains("<td colspan=\"2\">2: Slow</td>"));
+    assert!(!report.contains("never"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// When fewer users run than planned, the report shows the planned users were never
+/// reached.
+async fn test_ramp_profiles_behind() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "10",
+            "--hatch-rate",
+            "4",
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("StopHatching")
+            .register_task(task!(get_index))
+            .register_task(task!(stop_hatching).set_on_start()),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // More users were planned than were running.
+    let planned_users = &goose_metrics.ramp_profiles[0].planned_users;
+    assert!(*planned_users.iter().max().unwrap() > 2);
+    assert_eq!(goose_metrics.active_users.iter().max(), Some(&2));
+
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<td>never</td>"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Planned users aren't tracked with `--no-time-series`.
+async fn test_ramp_profiles_no_time_series() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--users", "2", "--hatch-rate", "2", "--no-time-series"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    assert!(goose_metrics.ramp_profiles.is_empty());
+}
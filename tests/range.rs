@@ -0,0 +1,154 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+use goose::range::GooseRange;
+
+// Paths used in load tests performed during these tests.
+const VIDEO_PATH: &str = "/video";
+const IGNORED_PATH: &str = "/ignored";
+const TRUNCATED_PATH: &str = "/truncated";
+
+// Indexes to the above paths.
+const VIDEO_START_KEY: usize = 0;
+const VIDEO_END_KEY: usize = 1;
+const IGNORED_KEY: usize = 2;
+const TRUNCATED_KEY: usize = 3;
+
+// The whole file served at each path.
+const FILE: &str = "0123456789";
+
+// Test task.
+pub async fn get_video_start(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user
+        .get_range(VIDEO_PATH, GooseRange::Between(0, 3))
+        .await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_video_end(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(VIDEO_PATH)
+        .name("video end")
+        .range(GooseRange::Last(4))
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_ignored(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user
+        .get_range(IGNORED_PATH, GooseRange::Between(0, 3))
+        .await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_truncated(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user
+        .get_range(TRUNCATED_PATH, GooseRange::Between(0, 3))
+        .await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up the start of VIDEO_PATH, store in vector at VIDEO_START_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(VIDEO_PATH)
+                .header("range", "bytes=0-3")
+                .header("accept-encoding", "identity");
+            then.status(206)
+                .header("content-range", "bytes 0-3/10")
+                .body(&FILE[0..4]);
+        }),
+        // Next set up the end of VIDEO_PATH, store in vector at VIDEO_END_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(VIDEO_PATH)
+                .header("range", "bytes=-4");
+            then.status(206)
+                .header("content-range", "bytes 6-9/10")
+                .body(&FILE[6..]);
+        }),
+        // Next set up IGNORED_PATH, store in vector at IGNORED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(IGNORED_PATH);
+            then.status(200).body(FILE);
+        }),
+        // Last set up TRUNCATED_PATH, store in vector at TRUNCATED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(TRUNCATED_PATH);
+            then.status(206)
+                .header("content-range", "bytes 0-3/10")
+                .body(&FILE[0..2]);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Range requests are verified to return the requested part of the file, counting
+/// responses that don't as failed requests and integrity failures.
+async fn test_range_requests() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Ranges")
+            .register_task(task!(get_video_start))
+            .register_task(task!(get_video_end))
+            .register_task(task!(get_ignored))
+            .register_task(task!(get_truncated)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // The requested parts of the video passed verification.
+    let mut requests = 0;
+    for (name, key) in [
+        ("GET /video", VIDEO_START_KEY),
+        ("GET video end", VIDEO_END_KEY),
+    ] {
+        let request = goose_metrics.requests.get(name).unwrap();
+        assert!(request.success_count > 0);
+        assert!(request.fail_count == 0);
+        assert!(request.integrity_checks == request.success_count);
+        assert!(request.integrity_failures == 0);
+        mock_endpoints[key].assert_hits(request.success_count);
+        requests += request.success_count;
+    }
+
+    // Responses that ignored the range or were truncated failed verification.
+    for (name, key) in [
+        ("GET /ignored", IGNORED_KEY),
+        ("GET /truncated", TRUNCATED_KEY),
+    ] {
+        let request = goose_metrics.requests.get(name).unwrap();
+        assert!(request.success_count == 0);
+        assert!(request.fail_count > 0);
+        assert!(request.integrity_failures == request.fail_count);
+        mock_endpoints[key].assert_hits(request.fail_count);
+        requests += request.fail_count;
+    }
+    assert!(requests > 0);
+
+    // Verification failures are reported as errors.
+    assert!(goose_metrics
+        .errors
+        .keys()
+        .any(|error| error.contains("range ignored: /ignored")));
+    assert!(goose_metrics
+        .errors
+        .keys()
+        .any(|error| error.contains("partial content length mismatch: /truncated")));
+}
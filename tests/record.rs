@@ -0,0 +1,139 @@
+use httpmock::{Method::GET, Method::POST, Mock, MockServer};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const STYLE_PATH: &str = "/style.css";
+const LOGIN_PATH: &str = "/login";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const STYLE_KEY: usize = 1;
+const LOGIN_KEY: usize = 2;
+
+// Recording configuration.
+const RECORD_FILE: &str = "recorded-session.rs";
+const RECORD_PORT: &str = "5128";
+
+// Test task, required for Goose to start.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).body("<html></html>");
+        }),
+        // Next set up STYLE_PATH, store in vector at STYLE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(STYLE_PATH);
+            then.status(200).body("body {}");
+        }),
+        // Next set up LOGIN_PATH, store in vector at LOGIN_KEY.
+        server.mock(|when, then| {
+            when.method(POST).path(LOGIN_PATH).body("name=goose");
+            then.status(302).header("location", INDEX_PATH);
+        }),
+    ]
+}
+
+#[tokio::test]
+/// Requests made through the recording proxy are forwarded, and written to the record file
+/// as a load test.
+async fn test_record() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--record",
+            RECORD_FILE,
+            "--record-port",
+            RECORD_PORT,
+            "--run-time",
+            "2",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let recording = tokio::spawn(goose_attack.execute());
+
+    // Browse through the proxy, as a browser would.
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::http(format!("http://127.0.0.1:{}", RECORD_PORT)).unwrap())
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    // Give the proxy a moment to start.
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    let index = client
+        .get(server.url(INDEX_PATH))
+        .header("accept", "text/html")
+        .send()
+        .await
+        .unwrap();
+    assert!(index.text().await.unwrap() == "<html></html>");
+    client.get(server.url(STYLE_PATH)).send().await.unwrap();
+    let login = client
+        .post(server.url(LOGIN_PATH))
+        .header("accept", "text/html")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body("name=goose")
+        .send()
+        .await
+        .unwrap();
+    // Redirects are passed back to the browser.
+    assert!(login.status() == 302);
+    // Request bodies too large to buffer are rejected without reading them.
+    let mut stream = tokio::net::TcpStream::connect(format!("127.0.0.1:{}", RECORD_PORT))
+        .await
+        .unwrap();
+    stream
+        .write_all(
+            format!(
+                "POST {} HTTP/1.1\r\ncontent-length: {}\r\n\r\n",
+                server.url(LOGIN_PATH),
+                u64::MAX
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 413"));
+
+    recording.await.unwrap().unwrap();
+
+    // The requests were forwarded to the server.
+    assert!(mock_endpoints[INDEX_KEY].hits() == 1);
+    assert!(mock_endpoints[STYLE_KEY].hits() == 1);
+    assert!(mock_endpoints[LOGIN_KEY].hits() == 1);
+
+    // The recorded session was written as a load test with a task for each page.
+    let source = std::fs::read_to_string(RECORD_FILE).unwrap();
+    assert!(source.contains(&format!(
+        ".set_default(GooseDefault::Host, {:?})?",
+        server.base_url()
+    )));
+    assert!(source.contains(r#".register_task(task!(page_1).set_sequence(1).set_name("/"))"#));
+    assert!(source.contains(r#".register_task(task!(page_2).set_sequence(2).set_name("/login"))"#));
+    assert!(source.contains(r#"    let _goose = user.get("/style.css").await?;"#));
+    assert!(source.contains(r#".body("name=goose")"#));
+
+    common::cleanup_files(vec![RECORD_FILE]);
+}
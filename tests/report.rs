@@ -0,0 +1,85 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ABOUT_PATH: &str = "/about.html";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ABOUT_KEY: usize = 1;
+
+// Load test configuration.
+const REPORT_FILE: &str = "report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_about(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ABOUT_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ABOUT_PATH, store in vector at ABOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ABOUT_PATH);
+            then.status(404);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// An html report is written when the load test finishes, with per-request metrics, a
+/// chart of response time percentiles, and status codes.
+async fn test_report() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--status-codes", "--report-file", REPORT_FILE],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Report")
+            .register_task(task!(get_index))
+            .register_task(task!(get_about)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(mock_endpoints[ABOUT_KEY].hits() > 0);
+
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Request Metrics</h2>"));
+    assert!(report.contains("<td>/about.html</td>"));
+
+    // Each request and the aggregate are charted by percentile.
+    assert!(report.contains("<h2>Response Time Percentiles</h2>"));
+    assert!(report.contains("<title>GET /</title>"));
+    assert!(report.contains("<title>GET /about.html</title>"));
+    assert!(report.contains("<title>Aggregated</title>"));
+
+    assert!(report.contains("<h2>Status Code Metrics</h2>"));
+    assert!(report.contains("[404]"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
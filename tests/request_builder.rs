@@ -0,0 +1,105 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const API_PATH: &str = "/api";
+const SLOW_PATH: &str = "/slow";
+
+// Indexes to the above paths.
+const API_KEY: usize = 0;
+const SLOW_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn get_api(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Get)
+        .path(API_PATH)
+        .name("api")
+        .header("Accept", "application/json")
+        .header("X-Api-Key", "goose")
+        .timeout(Duration::from_secs(10))
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_slow(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(SLOW_PATH)
+        .timeout(Duration::from_millis(10))
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up API_PATH, store in vector at API_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(API_PATH)
+                .header("accept", "application/json")
+                .header("x-api-key", "goose");
+            then.status(200);
+        }),
+        // Next set up SLOW_PATH, store in vector at SLOW_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SLOW_PATH);
+            then.status(200).delay(Duration::from_millis(500));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Requests are made with the headers and timeout configured with the request builder.
+async fn test_request_builder() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Builder")
+            .register_task(task!(get_api))
+            .register_task(task!(get_slow)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Requests with the configured headers matched the mock endpoint.
+    let api_metrics = goose_metrics.requests.get("GET api").unwrap();
+    assert!(api_metrics.success_count > 0);
+    assert!(api_metrics.fail_count == 0);
+    mock_endpoints[API_KEY].assert_hits(api_metrics.success_count);
+
+    // Requests to the slow endpoint timed out.
+    let slow_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", SLOW_PATH))
+        .unwrap();
+    assert!(slow_metrics.success_count == 0);
+    assert!(slow_metrics.fail_count > 0);
+    assert!(mock_endpoints[SLOW_KEY].hits() > 0);
+}
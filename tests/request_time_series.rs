@@ -0,0 +1,112 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ERROR_PATH: &str = "/error";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ERROR_KEY: usize = 1;
+
+// Load test configuration.
+const REPORT_FILE: &str = "request-time-series-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_error(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ERROR_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ERROR_PATH, store in vector at ERROR_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ERROR_PATH);
+            then.status(500);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Requests and errors are counted each second, and charted in the html report.
+async fn test_request_time_series() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index")
+            .register_task(task!(get_index))
+            .register_task(task!(get_error)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Every request was counted once, in the second it completed.
+    let seconds = &goose_metrics.request_time_series.seconds;
+    assert!(seconds.len() >= 2);
+    let requests: usize = seconds.iter().map(|second| second.requests()).sum();
+    let errors: usize = seconds.iter().map(|second| second.errors).sum();
+    mock_endpoints[INDEX_KEY].assert_hits(requests - errors);
+    mock_endpoints[ERROR_KEY].assert_hits(errors);
+    assert!(seconds
+        .iter()
+        .filter(|second| second.requests() > 0)
+        .all(|second| second.percentile(0.95).is_some()));
+
+    // The time series is charted in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Requests Per Second</h2>"));
+    assert!(report.contains("<h2>Response Times Over Time</h2>"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Requests aren't tracked over time with `--no-time-series`.
+async fn test_no_request_time_series() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-time-series"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(goose_metrics.request_time_series.seconds.is_empty());
+}
@@ -0,0 +1,107 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const FAST_PATH: &str = "/fast";
+const SLOW_PATH: &str = "/slow";
+
+// Indexes to the above paths.
+const FAST_KEY: usize = 0;
+const SLOW_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn get_fast(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(FAST_PATH)
+        .expect_response_time(Duration::from_secs(10))
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_slow(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(SLOW_PATH)
+        .expect_response_time(Duration::from_millis(10))
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up FAST_PATH, store in vector at FAST_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(FAST_PATH);
+            then.status(200);
+        }),
+        // Next set up SLOW_PATH, store in vector at SLOW_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SLOW_PATH);
+            then.status(200).delay(Duration::from_millis(50));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Responses slower than their response time budget are counted as slow, but not as
+/// failures.
+async fn test_response_time_budget() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Budgets")
+            .register_task(task!(get_fast))
+            .register_task(task!(get_slow)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    let fast_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", FAST_PATH))
+        .unwrap();
+    let slow_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", SLOW_PATH))
+        .unwrap();
+
+    // The configured budgets are included in the metrics.
+    assert!(fast_metrics.response_time_budget == Some(10_000));
+    assert!(slow_metrics.response_time_budget == Some(10));
+
+    // All requests succeeded.
+    mock_endpoints[FAST_KEY].assert_hits(fast_metrics.success_count);
+    mock_endpoints[SLOW_KEY].assert_hits(slow_metrics.success_count);
+    assert!(fast_metrics.fail_count == 0);
+    assert!(slow_metrics.fail_count == 0);
+
+    // Only the requests exceeding their budget were slow.
+    assert!(fast_metrics.slow_count == 0);
+    assert!(slow_metrics.slow_count == slow_metrics.success_count);
+}
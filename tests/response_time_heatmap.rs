@@ -0,0 +1,96 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const REPORT_FILE: &str = "response-time-heatmap-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Response times are counted by response time bucket each second, and charted as a
+/// heatmap in the html report.
+async fn test_response_time_heatmap() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--run-time",
+            "2",
+            "--no-reset-metrics",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Every response was counted once, in a bucket for each second.
+    let heatmap = &goose_metrics.response_time_heatmap;
+    assert!(heatmap.counts.len() >= 2);
+    assert!(heatmap
+        .counts
+        .iter()
+        .all(|counts| counts.len() == heatmap.buckets.len() + 1));
+    let responses: usize = heatmap.counts.iter().flatten().sum();
+    mock_endpoints[INDEX_KEY].assert_hits(responses);
+
+    // The heatmap is charted in the html report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Response Time Heatmap</h2>"));
+    assert!(report.contains("<rect"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// Response times aren't tracked over time with `--no-time-series`.
+async fn test_no_response_time_heatmap() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-time-series"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert!(goose_metrics.response_time_heatmap.counts.is_empty());
+}
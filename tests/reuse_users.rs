@@ -0,0 +1,140 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const LOGIN_PATH: &str = "/login";
+const SESSION_PATH: &str = "/session";
+const NO_SESSION_PATH: &str = "/no-session";
+
+// Indexes to the mock endpoints.
+const LOGIN_KEY: usize = 0;
+const SESSION_KEY: usize = 1;
+const NO_SESSION_KEY: usize = 2;
+
+// The cookie set when logging in.
+const SESSION_COOKIE: &str = "session=goose";
+
+// Session data set when logging in.
+struct Session {
+    token: String,
+}
+
+// Log in during the first load test, storing a cookie and session data.
+pub async fn login(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(LOGIN_PATH).await?;
+    user.set_session_data(Session {
+        token: "goose".to_string(),
+    });
+    Ok(())
+}
+
+// Use the session during the second load test.
+pub async fn use_session(user: &mut GooseUser) -> GooseTaskResult {
+    let path = match user.get_session_data::<Session>() {
+        Some(session) => format!("{}?token={}", SESSION_PATH, session.token),
+        None => NO_SESSION_PATH.to_string(),
+    };
+    let _goose = user.get(&path).await?;
+    Ok(())
+}
+
+// Set up the login endpoint, and the session endpoints which are only matched with or
+// without the session cookie.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up LOGIN_PATH, store in vector at LOGIN_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(LOGIN_PATH);
+            then.status(200).header("set-cookie", SESSION_COOKIE);
+        }),
+        // Next set up SESSION_PATH, store in vector at SESSION_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(SESSION_PATH)
+                .query_param("token", "goose")
+                .header("cookie", SESSION_COOKIE);
+            then.status(200);
+        }),
+        // Last set up NO_SESSION_PATH, store in vector at NO_SESSION_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(NO_SESSION_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Run two load tests one after another, optionally reusing the users of the first.
+async fn run_load_tests(server: &MockServer, reuse_users: bool) -> (GooseMetrics, GooseMetrics) {
+    let configuration = common::build_configuration(server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Login").register_task(task!(login).set_on_start()),
+        None,
+        None,
+    );
+    let (login_metrics, users) = goose_attack.execute_and_keep_users().await.unwrap();
+    assert_eq!(users.len(), 1);
+
+    let configuration = common::build_configuration(server, vec!["--no-reset-metrics"]);
+    let mut goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Session").register_task(task!(use_session)),
+        None,
+        None,
+    );
+    if reuse_users {
+        goose_attack = goose_attack.reuse_users(users);
+    }
+    let session_metrics = common::run_load_test(goose_attack, None).await;
+
+    (login_metrics, session_metrics)
+}
+
+#[tokio::test]
+#[serial]
+/// Users of a load test can be reused by the next load test, keeping their cookies and
+/// session data, while each load test reports its own metrics.
+async fn test_reuse_users() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let (login_metrics, session_metrics) = run_load_tests(&server, true).await;
+
+    // The user logged in once during the first load test.
+    mock_endpoints[LOGIN_KEY].assert_hits(1);
+    assert!(login_metrics.requests.contains_key("GET /login"));
+    assert!(!login_metrics
+        .requests
+        .contains_key("GET /session?token=goose"));
+
+    // The second load test reused the cookie and session data.
+    let session_requests = session_metrics
+        .requests
+        .get("GET /session?token=goose")
+        .unwrap();
+    assert!(session_requests.success_count > 0);
+    assert!(session_requests.fail_count == 0);
+    assert!(!session_metrics.requests.contains_key("GET /login"));
+    mock_endpoints[SESSION_KEY].assert_hits(session_requests.success_count);
+    mock_endpoints[NO_SESSION_KEY].assert_hits(0);
+}
+
+#[tokio::test]
+#[serial]
+/// Without reusing users, the next load test starts with new clients and no session data.
+async fn test_new_users() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let (_login_metrics, session_metrics) = run_load_tests(&server, false).await;
+
+    mock_endpoints[LOGIN_KEY].assert_hits(1);
+    let no_session_requests = session_metrics.requests.get("GET /no-session").unwrap();
+    assert!(no_session_requests.success_count > 0);
+    mock_endpoints[SESSION_KEY].assert_hits(0);
+    mock_endpoints[NO_SESSION_KEY].assert_hits(no_session_requests.success_count);
+}
@@ -0,0 +1,103 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::goose::RUN_ID_HEADER;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// The run ID marking the load test.
+const RUN_ID: &str = "nightly-42";
+
+// Load test artifacts.
+const REQUEST_LOG: &str = "run-id-requests.json";
+const TASK_LOG: &str = "run-id-tasks.csv";
+const REPORT_FILE: &str = "run-id-report.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY. The only user has
+        // index 0.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header(RUN_ID_HEADER, format!("{}; user=0", RUN_ID));
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Requests are marked with the run ID and user index, and the run ID is included in
+/// logs, reports and metrics.
+async fn test_run_id() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--run-id",
+            RUN_ID,
+            "--request-log",
+            REQUEST_LOG,
+            "--request-format",
+            "json",
+            "--task-log",
+            TASK_LOG,
+            "--task-format",
+            "csv",
+            "--report-file",
+            REPORT_FILE,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Requests only match if marked with the run ID.
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[INDEX_KEY].assert_hits(request.success_count);
+    assert_eq!(goose_metrics.run_id, RUN_ID);
+
+    // Every request is logged with the run ID.
+    let request_log = std::fs::read_to_string(REQUEST_LOG).unwrap();
+    assert!(request_log.lines().count() == request.success_count);
+    for line in request_log.lines() {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(value["run_id"], RUN_ID);
+    }
+
+    // The CSV task log has a run_id column.
+    let task_log = std::fs::read_to_string(TASK_LOG).unwrap();
+    let mut lines = task_log.lines();
+    assert!(lines.next().unwrap().ends_with(",run_id"));
+    assert!(lines.all(|line| line.ends_with(&format!(",\"{}\"", RUN_ID))));
+
+    // The report includes the run ID.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains(&format!("<p>Run ID: <span>{}</span></p>", RUN_ID)));
+
+    common::cleanup_files(vec![REQUEST_LOG, TASK_LOG, REPORT_FILE]);
+}
@@ -0,0 +1,104 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::{str, thread, time};
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "2";
+const TELNET_PORT: &str = "5126";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Send a command to the telnet Controller and return its response.
+fn telnet_command(stream: &mut TcpStream, command: &str) -> String {
+    stream
+        .write_all(&[command, "\r\n"].concat().into_bytes())
+        .unwrap();
+    let mut buf = [0; 2048];
+    let length = stream.read(&mut buf).unwrap();
+    str::from_utf8(&buf[..length]).unwrap().to_string()
+}
+
+#[tokio::test]
+#[serial]
+/// Users can be added and removed through the Controller while the load test is running.
+async fn test_running_users() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            "10",
+            "--run-time",
+            "8",
+            "--telnet-port",
+            TELNET_PORT,
+            "--no-websocket",
+            "--no-reset-metrics",
+        ],
+    );
+
+    let controller = thread::spawn(|| {
+        // Wait for the load test to start running.
+        thread::sleep(time::Duration::from_millis(1_500));
+        let mut stream = TcpStream::connect(["127.0.0.1:", TELNET_PORT].concat()).unwrap();
+        // Read the prompt.
+        let mut buf = [0; 2048];
+        let _ = stream.read(&mut buf).unwrap();
+
+        // Add two more users.
+        assert!(telnet_command(&mut stream, "users 4").starts_with("users configured"));
+        thread::sleep(time::Duration::from_millis(1_500));
+
+        // A running load test needs at least one user.
+        assert!(telnet_command(&mut stream, "users 0").starts_with("failed to reconfigure users"));
+
+        // Stop all but one user.
+        assert!(telnet_command(&mut stream, "users 1").starts_with("users configured"));
+    });
+
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    controller.join().unwrap();
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Four users were launched in total, but only one was still running at the end.
+    assert_eq!(goose_metrics.users, 4);
+    assert_eq!(goose_metrics.active_users.iter().max(), Some(&4));
+    assert_eq!(goose_metrics.active_users.last(), Some(&1));
+}
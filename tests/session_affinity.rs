@@ -0,0 +1,147 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ABOUT_PATH: &str = "/about.html";
+const MOVED_PATH: &str = "/moved.html";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ABOUT_KEY: usize = 1;
+const MOVED_KEY: usize = 2;
+
+// The response header identifying the backend.
+const BACKEND_HEADER: &str = "x-backend";
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_about(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ABOUT_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_moved(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(MOVED_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200).header(BACKEND_HEADER, "app-1");
+        }),
+        // Next set up ABOUT_PATH, store in vector at ABOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ABOUT_PATH);
+            then.status(200).header(BACKEND_HEADER, "app-1");
+        }),
+        // Next set up MOVED_PATH, store in vector at MOVED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(MOVED_PATH);
+            then.status(200).header(BACKEND_HEADER, "app-2");
+        }),
+    ]
+}
+
+// Run a load test verifying session affinity.
+async fn run_load_test(server: &MockServer, taskset: GooseTaskSet) -> GooseMetrics {
+    let configuration = common::build_configuration(
+        server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+            "--affinity-header",
+            BACKEND_HEADER,
+        ],
+    );
+    let goose_attack = common::build_load_test(configuration, &taskset, None, None);
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// Users that are always served by the same backend don't violate session affinity.
+async fn test_session_affinity() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(
+        &server,
+        taskset!("Sticky")
+            .register_task(task!(get_index))
+            .register_task(task!(get_about)),
+    )
+    .await;
+
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    let about_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", ABOUT_PATH))
+        .unwrap();
+
+    // Every response was served by the same backend.
+    assert!(index_metrics.backends.len() == 1);
+    assert!(index_metrics.backends.get("app-1") == Some(&mock_endpoints[INDEX_KEY].hits()));
+    assert!(about_metrics.backends.get("app-1") == Some(&mock_endpoints[ABOUT_KEY].hits()));
+    assert!(index_metrics.affinity_violations == 0);
+    assert!(about_metrics.affinity_violations == 0);
+}
+
+#[tokio::test]
+#[serial]
+/// Users that are moved to a different backend violate session affinity.
+async fn test_session_affinity_violations() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(
+        &server,
+        taskset!("Moved")
+            .register_task(task!(get_index))
+            .register_task(task!(get_moved)),
+    )
+    .await;
+
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+    let moved_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", MOVED_PATH))
+        .unwrap();
+
+    // Each backend served its own path.
+    assert!(index_metrics.backends.get("app-1") == Some(&mock_endpoints[INDEX_KEY].hits()));
+    assert!(moved_metrics.backends.get("app-2") == Some(&mock_endpoints[MOVED_KEY].hits()));
+
+    // Users alternate between backends, so every response after each user's first
+    // response is a violation.
+    let total = mock_endpoints[INDEX_KEY].hits() + mock_endpoints[MOVED_KEY].hits();
+    let users: usize = USERS.parse().unwrap();
+    assert!(moved_metrics.affinity_violations > 0);
+    assert!(index_metrics.affinity_violations + moved_metrics.affinity_violations == total - users);
+}
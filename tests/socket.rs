@@ -0,0 +1,192 @@
+use gumdrop::Options;
+use serial_test::serial;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, UdpSocket};
+
+use goose::config::GooseConfiguration;
+use goose::metrics::GooseMetrics;
+use goose::prelude::*;
+
+// Names used for requests made during these tests.
+const PING_NAME: &str = "ping";
+const BAD_PING_NAME: &str = "bad ping";
+
+// Load test configuration.
+const USERS: &str = "2";
+const RUN_TIME: &str = "2";
+
+// The address of the server being load tested, set before each load test starts.
+static PORT: AtomicUsize = AtomicUsize::new(0);
+
+fn address() -> String {
+    format!("127.0.0.1:{}", PORT.load(Ordering::SeqCst))
+}
+
+// Test task.
+pub async fn tcp_ping(user: &mut GooseUser) -> GooseTaskResult {
+    let address = address();
+    let socket_request = GooseSocketRequest::builder()
+        .tcp(&address)
+        .send(b"PING\r\n")
+        .expect_response(b"+PONG")
+        .name(PING_NAME)
+        .build();
+    let _goose = user.socket_request(socket_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn tcp_bad_ping(user: &mut GooseUser) -> GooseTaskResult {
+    let address = address();
+    let socket_request = GooseSocketRequest::builder()
+        .tcp(&address)
+        .send(b"PING\r\n")
+        .expect_response(b"-ERR")
+        .name(BAD_PING_NAME)
+        .build();
+    let _goose = user.socket_request(socket_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn udp_ping(user: &mut GooseUser) -> GooseTaskResult {
+    let address = address();
+    let socket_request = GooseSocketRequest::builder()
+        .udp(&address)
+        .send(b"PING")
+        .expect_response(b"PONG")
+        .timeout(std::time::Duration::from_secs(1))
+        .name(PING_NAME)
+        .build();
+    let _goose = user.socket_request(socket_request).await?;
+    Ok(())
+}
+
+// A minimal TCP server that answers each PING with a PONG, counting requests.
+async fn start_tcp_server(requests: Arc<AtomicUsize>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let requests = requests.clone();
+            tokio::spawn(async move {
+                let mut buffer = [0; 64];
+                if let Ok(bytes) = stream.read(&mut buffer).await {
+                    if bytes > 0 {
+                        requests.fetch_add(1, Ordering::SeqCst);
+                        let _ = stream.write_all(b"+PONG\r\n").await;
+                    }
+                }
+            });
+        }
+    });
+    address
+}
+
+// A minimal UDP server that answers each PING with a PONG, counting requests.
+async fn start_udp_server(requests: Arc<AtomicUsize>) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let address = socket.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buffer = [0; 64];
+        loop {
+            let (_, peer) = socket.recv_from(&mut buffer).await.unwrap();
+            requests.fetch_add(1, Ordering::SeqCst);
+            let _ = socket.send_to(b"PONG", peer).await;
+        }
+    });
+    address
+}
+
+// Run a load test with the given task set.
+async fn run_load_test(taskset: GooseTaskSet) -> GooseMetrics {
+    let configuration = GooseConfiguration::parse_args_default(&[
+        // A host is required, but isn't used by socket requests.
+        "--host",
+        "http://127.0.0.1",
+        "--users",
+        USERS,
+        "--hatch-rate",
+        USERS,
+        "--run-time",
+        RUN_TIME,
+        "--co-mitigation",
+        "disabled",
+        "--no-reset-metrics",
+    ])
+    .unwrap();
+
+    GooseAttack::initialize_with_config(configuration)
+        .unwrap()
+        .register_taskset(taskset)
+        .execute()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+/// Load test a TCP server with raw socket requests.
+async fn test_tcp_socket() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let address = start_tcp_server(requests.clone()).await;
+    PORT.store(address.port() as usize, Ordering::SeqCst);
+
+    let goose_metrics = run_load_test(
+        taskset!("LoadTest")
+            .register_task(task!(tcp_ping))
+            .register_task(task!(tcp_bad_ping)),
+    )
+    .await;
+
+    let ping_metrics = goose_metrics
+        .requests
+        .get(&format!("TCP {}", PING_NAME))
+        .unwrap();
+    let bad_ping_metrics = goose_metrics
+        .requests
+        .get(&format!("TCP {}", BAD_PING_NAME))
+        .unwrap();
+
+    // Confirm that Goose and the server saw the same number of requests.
+    assert!(ping_metrics.success_count > 0);
+    assert!(ping_metrics.fail_count == 0);
+    assert!(
+        ping_metrics.success_count + bad_ping_metrics.fail_count == requests.load(Ordering::SeqCst)
+    );
+
+    // Unexpected responses are recorded as failures.
+    assert!(bad_ping_metrics.success_count == 0);
+    assert!(bad_ping_metrics.fail_count > 0);
+    assert!(!goose_metrics.errors.is_empty());
+
+    // Socket requests are identified by their protocol.
+    assert_eq!(ping_metrics.socket, Some(GooseSocketMethod::Tcp));
+    assert_eq!(ping_metrics.method_name(), "TCP");
+}
+
+#[tokio::test]
+#[serial]
+/// Load test a UDP server with raw socket requests.
+async fn test_udp_socket() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let address = start_udp_server(requests.clone()).await;
+    PORT.store(address.port() as usize, Ordering::SeqCst);
+
+    let goose_metrics = run_load_test(taskset!("LoadTest").register_task(task!(udp_ping))).await;
+
+    let ping_metrics = goose_metrics
+        .requests
+        .get(&format!("UDP {}", PING_NAME))
+        .unwrap();
+
+    // Confirm that Goose and the server saw the same number of requests.
+    assert!(ping_metrics.success_count > 0);
+    assert!(ping_metrics.fail_count == 0);
+    assert!(ping_metrics.success_count == requests.load(Ordering::SeqCst));
+    assert_eq!(ping_metrics.socket, Some(GooseSocketMethod::Udp));
+}
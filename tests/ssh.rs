@@ -0,0 +1,66 @@
+use httpmock::{Method::GET, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Build a load test with the SSH run-time options.
+fn build_load_test(server: &MockServer, custom: Vec<&str>) -> GooseAttack {
+    let mut options = vec!["--users", "1", "--hatch-rate", "1", "--run-time", "1"];
+    options.extend(custom);
+    let configuration = common::build_configuration(server, options);
+    common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+#[serial]
+/// The load test doesn't start if the tunnel through the jump host can't be opened.
+async fn test_ssh_jump_host_unreachable() {
+    let server = MockServer::start();
+    let index = server.mock(|when, then| {
+        when.method(GET).path(INDEX_PATH);
+        then.status(200);
+    });
+
+    // Nothing listens on port 1, so the jump host refuses the connection.
+    let goose_attack = build_load_test(&server, vec!["--ssh-jump-host", "127.0.0.1:1"]);
+    assert!(goose_attack.execute().await.is_err());
+    assert_eq!(index.hits(), 0);
+}
+
+#[tokio::test]
+#[serial]
+/// The jump host and private key are validated before starting the load test.
+async fn test_ssh_invalid_options() {
+    let server = MockServer::start();
+
+    for options in [
+        vec!["--ssh-jump-host", "bastion:ssh"],
+        vec!["--ssh-jump-host", "@bastion"],
+        vec!["--ssh-identity", "Cargo.toml"],
+        vec![
+            "--ssh-jump-host",
+            "bastion",
+            "--ssh-identity",
+            "does-not-exist",
+        ],
+    ] {
+        let goose_attack = build_load_test(&server, options);
+        assert!(goose_attack.execute().await.is_err());
+    }
+}
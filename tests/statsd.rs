@@ -0,0 +1,144 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::net::UdpSocket;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ERROR_PATH: &str = "/error";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ERROR_KEY: usize = 1;
+
+// Load test configuration.
+const STATSD_HOST: &str = "127.0.0.1:5125";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_error(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ERROR_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ERROR_PATH, store in vector at ERROR_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ERROR_PATH);
+            then.status(503);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Request metrics are sent to StatsD as requests complete, tagged with the request and
+/// the run ID.
+async fn test_statsd() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Listen for metrics as StatsD.
+    let statsd = UdpSocket::bind(STATSD_HOST).unwrap();
+    statsd
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .unwrap();
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "2",
+            "--hatch-rate",
+            "2",
+            "--statsd-host",
+            STATSD_HOST,
+            "--run-id",
+            "nightly",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest")
+            .set_wait_time(Duration::from_millis(10), Duration::from_millis(20))
+            .unwrap()
+            .register_task(task!(get_index))
+            .register_task(task!(get_error)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await;
+    let index_hits = mock_endpoints[INDEX_KEY].hits();
+    let error_hits = mock_endpoints[ERROR_KEY].hits();
+    assert!(index_hits > 0);
+    assert!(error_hits > 0);
+
+    // Receive all metrics sent during the load test.
+    let mut metrics = Vec::new();
+    let mut buffer = [0; 1024];
+    while let Ok(length) = statsd.recv(&mut buffer) {
+        let packet = String::from_utf8_lossy(&buffer[..length]).to_string();
+        metrics.extend(packet.lines().map(str::to_string));
+    }
+    let count = |metric: &str| metrics.iter().filter(|m| m.as_str() == metric).count();
+
+    // Each request was counted and timed, and each failed request was counted again.
+    let index_tags = "|#method:GET,name:/,status_code:200,run_id:nightly";
+    let error_tags = "|#method:GET,name:/error,status_code:503,run_id:nightly";
+    assert_eq!(
+        count(&format!("goose.requests:1|c{}", index_tags)),
+        index_hits
+    );
+    assert_eq!(
+        count(&format!("goose.requests:1|c{}", error_tags)),
+        error_hits
+    );
+    assert_eq!(
+        count(&format!("goose.request_failures:1|c{}", error_tags)),
+        error_hits
+    );
+    assert_eq!(
+        count(&format!("goose.request_failures:1|c{}", index_tags)),
+        0
+    );
+    assert_eq!(
+        metrics
+            .iter()
+            .filter(|m| m.starts_with("goose.response_time:") && m.ends_with(index_tags))
+            .count(),
+        index_hits
+    );
+}
+
+#[tokio::test]
+#[serial]
+/// Request metrics can't be sent to StatsD with `--no-metrics`.
+async fn test_statsd_no_metrics() {
+    let server = MockServer::start();
+
+    let configuration =
+        common::build_configuration(&server, vec!["--statsd-host", STATSD_HOST, "--no-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    assert!(goose_attack.execute().await.is_err());
+}
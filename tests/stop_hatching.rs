@@ -0,0 +1,79 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const USERS: &str = "10";
+const HATCH_RATE: &str = "4";
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn stop_hatching(user: &mut GooseUser) -> GooseTaskResult {
+    // Stop launching users once the second user is running.
+    if user.weighted_users_index > 0 {
+        user.stop_hatching();
+    }
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// A task can stop launching more users, holding the load test at the users already running.
+async fn test_stop_hatching() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            HATCH_RATE,
+            "--run-time",
+            RUN_TIME,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("StopHatching")
+            .register_task(task!(get_index))
+            .register_task(task!(stop_hatching).set_on_start()),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // Only two users were launched, and the load test kept running for the run time.
+    assert_eq!(goose_metrics.users, 2);
+    assert_eq!(goose_metrics.active_users.iter().max(), Some(&2));
+    assert_eq!(goose_metrics.duration, 2);
+}
@@ -0,0 +1,98 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const STOP_PATH: &str = "/stop";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const STOP_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: usize = 4;
+const STOP_RATE: &str = "4";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_stop(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(STOP_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up STOP_PATH, store in vector at STOP_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(STOP_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users are stopped gradually at the stop rate, each running its on_stop tasks.
+async fn test_stop_rate() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            &USERS.to_string(),
+            "--hatch-rate",
+            &USERS.to_string(),
+            "--stop-rate",
+            STOP_RATE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("StopRate")
+            .register_task(task!(get_index))
+            .register_task(task!(get_stop).set_on_stop()),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+    assert_eq!(mock_endpoints[STOP_KEY].hits(), USERS);
+
+    // Stopping 4 users at 4 per second pauses 3 times for a quarter second.
+    let stopping = goose_metrics.stopping.unwrap();
+    let stopped = goose_metrics.stopped.unwrap();
+    assert!((stopped - stopping).num_milliseconds() >= 700);
+}
+
+#[tokio::test]
+#[serial]
+/// The stop rate must be greater than 0.
+async fn test_invalid_stop_rate() {
+    let server = MockServer::start();
+
+    let configuration = common::build_configuration(&server, vec!["--stop-rate", "0"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("StopRate").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    assert!(goose_attack.execute().await.is_err());
+}
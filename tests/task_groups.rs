@@ -0,0 +1,146 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::goose::GooseTaskGroup;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ABOUT_PATH: &str = "/about";
+const CART_PATH: &str = "/cart";
+const CHECKOUT_PATH: &str = "/checkout";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ABOUT_KEY: usize = 1;
+const CART_KEY: usize = 2;
+const CHECKOUT_KEY: usize = 3;
+
+// Load test configuration.
+const USERS: &str = "2";
+const HATCH_RATE: &str = "2";
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_about(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ABOUT_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_cart(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(CART_PATH).await?;
+    Ok(())
+}
+
+// Test task, failing as checking out returns an error.
+pub async fn get_checkout(user: &mut GooseUser) -> GooseTaskResult {
+    let mut goose = user.get(CHECKOUT_PATH).await?;
+    user.set_failure("checkout failed", &mut goose.request, None, None)
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ABOUT_PATH, store in vector at ABOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ABOUT_PATH);
+            then.status(200);
+        }),
+        // Next set up CART_PATH, store in vector at CART_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CART_PATH);
+            then.status(200);
+        }),
+        // Next set up CHECKOUT_PATH, store in vector at CHECKOUT_KEY. Checking out fails.
+        server.mock(|when, then| {
+            when.method(GET).path(CHECKOUT_PATH);
+            then.status(500);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Tasks in groups run together as passes weighted by group, and each pass is timed.
+async fn test_task_groups() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            HATCH_RATE,
+            "--run-time",
+            RUN_TIME,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("TaskGroups")
+            .register_group(
+                GooseTaskGroup::new("browse")
+                    .set_weight(3)
+                    .unwrap()
+                    .register_task(task!(get_index).set_sequence(1))
+                    .register_task(task!(get_about).set_sequence(2)),
+            )
+            .register_group(
+                GooseTaskGroup::new("purchase")
+                    .register_task(task!(get_cart).set_sequence(1))
+                    .register_task(task!(get_checkout).set_sequence(2)),
+            )
+            .set_wait_time(
+                std::time::Duration::from_millis(10),
+                std::time::Duration::from_millis(20),
+            )
+            .unwrap(),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Each task ran as often as its group.
+    let (index, about) = (
+        mock_endpoints[INDEX_KEY].hits(),
+        mock_endpoints[ABOUT_KEY].hits(),
+    );
+    let (cart, checkout) = (
+        mock_endpoints[CART_KEY].hits(),
+        mock_endpoints[CHECKOUT_KEY].hits(),
+    );
+    assert!(index > 0 && cart > 0);
+    assert!(index.abs_diff(about) <= 2);
+    assert!(cart.abs_diff(checkout) <= 2);
+    assert!(index > cart);
+
+    // Each complete pass through a group was timed, and passes that checked out failed.
+    let groups = &goose_metrics.groups[0];
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].task_name, "browse");
+    assert_eq!(groups[1].task_name, "purchase");
+    assert!(groups[0].counter > groups[1].counter);
+    assert!(groups[0].counter <= about);
+    assert!(groups[0].counter + 2 >= about);
+    assert_eq!(groups[0].fail_count, 0);
+    assert!(groups[1].counter > 0);
+    assert_eq!(groups[1].fail_count, groups[1].counter);
+    assert!(groups[0].min_time >= 10);
+}
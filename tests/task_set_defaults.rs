@@ -0,0 +1,107 @@
+use httpmock::{prelude::HttpMockRequest, Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const OVERRIDE_PATH: &str = "/override";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const OVERRIDE_KEY: usize = 1;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_override(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .path(OVERRIDE_PATH)
+        .header("X-Test-Run", "custom")
+        .query("tenant", "other")
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// The query parameters of the request.
+fn query(request: &HttpMockRequest) -> Vec<(String, String)> {
+    request.query_params.clone().unwrap_or_default()
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header("X-Test-Run", "42")
+                .matches(|request| {
+                    query(request) == vec![("tenant".to_string(), "loadtest".to_string())]
+                });
+            then.status(200);
+        }),
+        // Next set up OVERRIDE_PATH, store in vector at OVERRIDE_KEY.
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(OVERRIDE_PATH)
+                .header("X-Test-Run", "custom")
+                .matches(|request| {
+                    query(request) == vec![("tenant".to_string(), "other".to_string())]
+                });
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// The default headers and query parameters of a task set are added to each request,
+/// unless the request already sets them.
+async fn test_task_set_defaults() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Defaults")
+            .set_default_header("X-Test-Run", "42")
+            .unwrap()
+            .set_default_query("tenant", "loadtest")
+            .register_task(task!(get_index))
+            .register_task(task!(get_override)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Requests only match if sent with the expected headers and query parameters.
+    let index = goose_metrics.requests.get("GET /").unwrap();
+    assert!(index.success_count > 0);
+    assert!(index.fail_count == 0);
+    mock_endpoints[INDEX_KEY].assert_hits(index.success_count);
+
+    let request = goose_metrics.requests.get("GET /override").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[OVERRIDE_KEY].assert_hits(request.success_count);
+}
+
+#[test]
+/// Invalid default headers are rejected.
+fn test_invalid_default_header() {
+    assert!(taskset!("Defaults")
+        .set_default_header("X Test Run", "42")
+        .is_err());
+    assert!(taskset!("Defaults")
+        .set_default_header("X-Test-Run", "4\n2")
+        .is_err());
+}
@@ -0,0 +1,130 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod common;
+
+use goose::metrics::GooseSetupTeardown;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const SEEDED_PATH: &str = "/seeded";
+
+// Indexes to the mock endpoints.
+const INDEX_KEY: usize = 0;
+const SEEDED_KEY: usize = 1;
+
+// How many records the setup task seeds.
+const SEEDED: usize = 10;
+
+// Set up the endpoints requested by users.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up SEEDED_PATH, store in vector at SEEDED_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(SEEDED_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Build a task set whose setup task seeds records in shared state, whose users read
+// the seeded records, and whose teardown task verifies the records with the provided
+// expected count.
+fn build_task_set(name: &str, records: &Arc<AtomicUsize>, expected: usize) -> GooseTaskSet {
+    let setup_records = records.clone();
+    let setup: GooseTaskFunction = Arc::new(move |_user| {
+        setup_records.store(SEEDED, Ordering::SeqCst);
+        Box::pin(async move { Ok(()) })
+    });
+
+    // Users only find the seeded records if the setup task ran first.
+    let task_records = records.clone();
+    let read: GooseTaskFunction = Arc::new(move |user| {
+        let path = if task_records.load(Ordering::SeqCst) == SEEDED {
+            SEEDED_PATH
+        } else {
+            INDEX_PATH
+        };
+        Box::pin(async move {
+            let _goose = user.get(path).await?;
+            Ok(())
+        })
+    });
+
+    let teardown_records = records.clone();
+    let teardown: GooseTaskFunction = Arc::new(move |_user| {
+        let found = teardown_records.load(Ordering::SeqCst);
+        Box::pin(async move {
+            if found != expected {
+                return Err(GooseTaskError::VerificationFailed {
+                    detail: format!("found {} records, expected {}", found, expected),
+                });
+            }
+            Ok(())
+        })
+    });
+
+    taskset!(name)
+        .set_setup_task(GooseTask::new(setup))
+        .register_task(GooseTask::new(read))
+        .set_teardown_task(GooseTask::new(teardown))
+}
+
+#[tokio::test]
+#[serial]
+/// Task set setup and teardown tasks run once, before and after all users, with their
+/// duration and result reported separately from the task metrics.
+async fn test_task_set_setup_teardown() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let records = Arc::new(AtomicUsize::new(0));
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &build_task_set("Verified", &records, SEEDED),
+        None,
+        None,
+    )
+    .register_taskset(build_task_set("Unverified", &records, SEEDED + 1));
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Users only requested the seeded records.
+    assert!(mock_endpoints[SEEDED_KEY].hits() > 0);
+    mock_endpoints[INDEX_KEY].assert_hits(0);
+
+    // Both setup tasks ran before both teardown tasks, and only the second teardown
+    // task failed verification.
+    let setup_teardown: Vec<(&str, GooseSetupTeardown, bool)> = goose_metrics
+        .setup_teardown
+        .iter()
+        .map(|metric| (metric.taskset_name.as_str(), metric.kind, metric.success))
+        .collect();
+    assert_eq!(
+        setup_teardown,
+        vec![
+            ("Verified", GooseSetupTeardown::Setup, true),
+            ("Unverified", GooseSetupTeardown::Setup, true),
+            ("Verified", GooseSetupTeardown::Teardown, true),
+            ("Unverified", GooseSetupTeardown::Teardown, false),
+        ]
+    );
+    assert!(goose_metrics.setup_teardown[3]
+        .error
+        .as_ref()
+        .unwrap()
+        .contains("found 10 records, expected 11"));
+
+    // Setup and teardown tasks aren't included in the task metrics.
+    for task_set in &goose_metrics.tasks {
+        assert_eq!(task_set.len(), 1);
+    }
+}
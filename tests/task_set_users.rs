@@ -0,0 +1,116 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const CHECKOUT_PATH: &str = "/checkout";
+const BROWSE_PATH: &str = "/browse";
+
+// Indexes to the above paths.
+const CHECKOUT_KEY: usize = 0;
+const BROWSE_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: &str = "6";
+const CHECKOUT_USERS: usize = 2;
+
+// Test task, run once when each user starts.
+pub async fn start_checkout(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(CHECKOUT_PATH).await?;
+    Ok(())
+}
+
+// Test task, run once when each user starts.
+pub async fn start_browse(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(BROWSE_PATH).await?;
+    Ok(())
+}
+
+// Test task, keeps users busy after they start.
+pub async fn idle(_user: &mut GooseUser) -> GooseTaskResult {
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up CHECKOUT_PATH, store in vector at CHECKOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(CHECKOUT_PATH);
+            then.status(200);
+        }),
+        // Next set up BROWSE_PATH, store in vector at BROWSE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(BROWSE_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Build a load test with a Checkout task set running a fixed number of users, and a
+// heavily weighted Browse task set.
+fn build_load_test(server: &MockServer, users: &str) -> GooseAttack {
+    let configuration = common::build_configuration(
+        server,
+        vec![
+            "--users",
+            users,
+            "--hatch-rate",
+            users,
+            "--no-reset-metrics",
+        ],
+    );
+    common::build_load_test(
+        configuration,
+        &taskset!("Checkout")
+            .set_users(CHECKOUT_USERS)
+            .unwrap()
+            .set_wait_time(Duration::from_millis(100), Duration::from_millis(100))
+            .unwrap()
+            .register_task(task!(start_checkout).set_on_start())
+            .register_task(task!(idle)),
+        None,
+        None,
+    )
+    .register_taskset(
+        taskset!("Browse")
+            .set_weight(10)
+            .unwrap()
+            .set_wait_time(Duration::from_millis(100), Duration::from_millis(100))
+            .unwrap()
+            .register_task(task!(start_browse).set_on_start())
+            .register_task(task!(idle)),
+    )
+}
+
+#[tokio::test]
+#[serial]
+/// A task set with a fixed number of users runs exactly that many users, and the remaining
+/// users run the task sets allocated by weight.
+async fn test_task_set_users() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = common::run_load_test(build_load_test(&server, USERS), None).await;
+
+    // All users were launched.
+    assert!(goose_metrics.users == 6);
+
+    // Each user loaded its start page once.
+    assert!(mock_endpoints[CHECKOUT_KEY].hits() == CHECKOUT_USERS);
+    assert!(mock_endpoints[BROWSE_KEY].hits() == 6 - CHECKOUT_USERS);
+}
+
+#[tokio::test]
+#[serial]
+/// Configuring fewer users than are fixed in task sets returns an error.
+async fn test_task_set_users_too_few() {
+    let server = MockServer::start();
+
+    let result = build_load_test(&server, "1").execute().await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,96 @@
+use httpmock::MockServer;
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get("/").await?;
+    Ok(())
+}
+
+// Run a load test with the provided task sets, returning the error it fails with.
+async fn run_invalid_load_test(task_sets: Vec<GooseTaskSet>) -> GooseError {
+    let server = MockServer::start();
+    let configuration = common::build_configuration(&server, vec![]);
+    let mut goose_attack = GooseAttack::initialize_with_config(configuration).unwrap();
+    for task_set in task_sets {
+        goose_attack = goose_attack.register_taskset(task_set);
+    }
+    goose_attack.execute().await.unwrap_err()
+}
+
+// Return the detail of an invalid task set error.
+fn invalid_task_set_detail(error: GooseError) -> String {
+    match error {
+        GooseError::InvalidTaskSet { detail, .. } => detail,
+        _ => panic!("unexpected error: {}", error),
+    }
+}
+
+#[tokio::test]
+#[serial]
+/// Task sets without tasks are rejected before the load test starts.
+async fn test_task_set_without_tasks() {
+    let error = run_invalid_load_test(vec![
+        taskset!("Index").register_task(task!(get_index)),
+        taskset!("Empty"),
+    ])
+    .await;
+    assert_eq!(
+        invalid_task_set_detail(error),
+        "Task set \"Empty\" has no tasks."
+    );
+}
+
+#[tokio::test]
+#[serial]
+/// Task set names and named tasks in the same task set must be unique.
+async fn test_duplicate_names() {
+    let error = run_invalid_load_test(vec![
+        taskset!("Index").register_task(task!(get_index)),
+        taskset!("Index").register_task(task!(get_index)),
+    ])
+    .await;
+    assert!(invalid_task_set_detail(error).contains("used by more than one task set"));
+
+    let error = run_invalid_load_test(vec![taskset!("Index")
+        .register_task(task!(get_index).set_name("index"))
+        .register_task(task!(get_index).set_name("index"))])
+    .await;
+    assert!(invalid_task_set_detail(error).contains("used by more than one task"));
+}
+
+#[tokio::test]
+#[serial]
+/// Weights set directly instead of with `set_weight()` must still be at least 1.
+async fn test_zero_weight() {
+    let mut task_set = taskset!("Index").register_task(task!(get_index));
+    task_set.weight = 0;
+    let error = run_invalid_load_test(vec![task_set]).await;
+    assert!(matches!(error, GooseError::InvalidWeight { weight: 0, .. }));
+
+    let mut task = task!(get_index);
+    task.weight = 0;
+    let error = run_invalid_load_test(vec![taskset!("Index").register_task(task)]).await;
+    assert!(matches!(error, GooseError::InvalidWeight { weight: 0, .. }));
+}
+
+#[tokio::test]
+#[serial]
+/// Setup and teardown tasks can't be sequenced or run when users start or stop.
+async fn test_conflicting_sequence() {
+    let error = run_invalid_load_test(vec![taskset!("Index")
+        .register_task(task!(get_index))
+        .set_setup_task(task!(get_index).set_sequence(1))])
+    .await;
+    assert!(invalid_task_set_detail(error).contains("setup task"));
+
+    let error = run_invalid_load_test(vec![taskset!("Index")
+        .register_task(task!(get_index))
+        .set_teardown_task(task!(get_index).set_on_stop())])
+    .await;
+    assert!(invalid_task_set_detail(error).contains("teardown task"));
+}
@@ -0,0 +1,175 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+
+// Load test configuration.
+const TEST_PLAN: &str = "2,0;2,1s;4,1s;4,1s;1,0;1,1s";
+
+// Files used in these tests.
+const REPORT_FILE: &str = "test-plan-test.html";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Users are launched and stopped following the steps of the test plan.
+async fn test_test_plan() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec!["--test-plan", TEST_PLAN, "--no-reset-metrics"],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // The test plan runs for as long as its steps take.
+    assert_eq!(goose_metrics.duration, 4);
+
+    // Four users were launched in total, but only one was still running at the end.
+    assert_eq!(goose_metrics.users, 4);
+    assert_eq!(goose_metrics.active_users.iter().max(), Some(&4));
+    assert_eq!(goose_metrics.active_users.last(), Some(&1));
+
+    // Each step of the test plan was recorded as a phase.
+    let phases: Vec<&str> = goose_metrics
+        .phases
+        .iter()
+        .map(|phase| phase.name.as_str())
+        .collect();
+    assert_eq!(
+        phases,
+        vec!["step 1", "step 2", "step 3", "step 4", "step 5", "step 6"]
+    );
+}
+
+#[tokio::test]
+#[serial]
+/// Users are ramped up, held, and ramped down, recording when each phase starts and the
+/// requests completed during it.
+async fn test_ramp_hold_ramp() {
+    common::cleanup_files(vec![REPORT_FILE]);
+
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            "4",
+            "--ramp-up",
+            "1s",
+            "--hold",
+            "1s",
+            "--ramp-down",
+            "1s",
+            "--no-reset-metrics",
+            "--report-file",
+            REPORT_FILE,
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+    assert!(mock_endpoints[INDEX_KEY].hits() > 0);
+
+    // The load test runs for as long as the phases take.
+    assert_eq!(goose_metrics.duration, 3);
+    assert_eq!(goose_metrics.active_users.iter().max(), Some(&4));
+
+    // Each phase was recorded in order.
+    let phases: Vec<(&str, usize)> = goose_metrics
+        .phases
+        .iter()
+        .map(|phase| (phase.name.as_str(), phase.users))
+        .collect();
+    assert_eq!(phases, vec![("ramp-up", 4), ("hold", 4), ("ramp-down", 0)]);
+    assert!(goose_metrics
+        .phases
+        .windows(2)
+        .all(|phases| phases[0].timestamp <= phases[1].timestamp
+            && phases[0].elapsed <= phases[1].elapsed));
+
+    // Requests are aggregated by the phase they completed in.
+    let phase_requests: usize = goose_metrics
+        .phases
+        .iter()
+        .map(|phase| phase.requests.requests())
+        .sum();
+    let requests: usize = goose_metrics
+        .requests
+        .values()
+        .map(|request| request.success_count + request.fail_count)
+        .sum();
+    assert!(goose_metrics.phases[1].requests.requests() > 0);
+    assert!(phase_requests > 0 && phase_requests <= requests);
+
+    // Each phase is included in the report.
+    let report = std::fs::read_to_string(REPORT_FILE).unwrap();
+    assert!(report.contains("<h2>Phases</h2>"));
+    assert!(report.contains("<td colspan=\"2\">hold</td>"));
+
+    common::cleanup_files(vec![REPORT_FILE]);
+}
+
+#[tokio::test]
+#[serial]
+/// The test plan can't be combined with options controlling how users are launched.
+async fn test_invalid_test_plan() {
+    let server = MockServer::start();
+
+    for options in vec![
+        vec!["--test-plan", "10"],
+        vec!["--test-plan", "2,1s", "--users", "3"],
+        vec!["--test-plan", "2,1s", "--hatch-rate", "2"],
+        vec!["--test-plan", "2,1s", "--run-time", "2"],
+        vec!["--ramp-up", "1s", "--test-plan", "2,1s"],
+        vec!["--ramp-up", "1s", "--hatch-rate", "2"],
+        vec!["--hold", "1s", "--run-time", "2"],
+    ] {
+        let configuration = common::build_configuration(&server, options);
+        let goose_attack = common::build_load_test(
+            configuration,
+            &taskset!("Index").register_task(task!(get_index)),
+            None,
+            None,
+        );
+        assert!(goose_attack.execute().await.is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use httpmock::MockServer;
+use std::collections::BTreeMap;
+
+mod common;
+
+use goose::metrics::GooseTransportError;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Load test configuration.
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+#[tokio::test]
+/// Requests that fail without a response are counted by kind of transport-level failure.
+async fn test_transport_errors() {
+    // Nothing is listening on a port once its listener is closed, so connections are
+    // refused.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let server = MockServer::start();
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--host",
+            &host,
+            "--run-time",
+            RUN_TIME,
+            "--no-reset-metrics",
+        ],
+    );
+
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Every request failed because the connection was refused.
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.fail_count > 0);
+    assert!(request.success_count == 0);
+    let mut expected = BTreeMap::new();
+    expected.insert(GooseTransportError::ConnectionRefused, request.fail_count);
+    assert_eq!(request.transport_errors, expected);
+}
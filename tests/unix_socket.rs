@@ -0,0 +1,193 @@
+#![cfg(unix)]
+
+use gumdrop::Options;
+use serial_test::serial;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+
+use goose::config::GooseConfiguration;
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Load test configuration.
+const USERS: &str = "2";
+const RUN_TIME: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// A minimal HTTP server listening on a Unix domain socket, counting requests and
+// recording the last Host header received.
+fn start_unix_socket_server(
+    socket_path: &str,
+    requests: Arc<AtomicUsize>,
+    host_header: Arc<Mutex<String>>,
+) {
+    let _ = std::fs::remove_file(socket_path);
+    let listener = UnixListener::bind(socket_path).unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(serve_connection(
+                stream,
+                requests.clone(),
+                host_header.clone(),
+            ));
+        }
+    });
+}
+
+// Respond to each request on a keep-alive connection with a 200.
+async fn serve_connection(
+    mut stream: UnixStream,
+    requests: Arc<AtomicUsize>,
+    host_header: Arc<Mutex<String>>,
+) {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 1024];
+    loop {
+        let bytes = match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return,
+            Ok(b) => b,
+        };
+        buffer.extend_from_slice(&chunk[..bytes]);
+        // Requests are GETs without a body, so each ends with an empty line.
+        while let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            let request = String::from_utf8_lossy(&buffer[..end]).to_string();
+            buffer.drain(..end + 4);
+            for line in request.lines() {
+                if let Some(host) = line.strip_prefix("host: ") {
+                    *host_header.lock().unwrap() = host.to_string();
+                }
+            }
+            requests.fetch_add(1, Ordering::SeqCst);
+            if stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok")
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+// Run a load test against a server listening on a Unix domain socket, returning the
+// number of requests the server received, the Host header, and the metrics.
+async fn run_unix_socket_load_test(
+    socket_path: &str,
+    host: &str,
+) -> (usize, String, goose::metrics::GooseMetrics) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let host_header = Arc::new(Mutex::new(String::new()));
+    start_unix_socket_server(socket_path, requests.clone(), host_header.clone());
+
+    let configuration = GooseConfiguration::parse_args_default(&[
+        "--host",
+        host,
+        "--users",
+        USERS,
+        "--hatch-rate",
+        USERS,
+        "--run-time",
+        RUN_TIME,
+        "--co-mitigation",
+        "disabled",
+        "--no-reset-metrics",
+        "--no-telnet",
+        "--no-websocket",
+    ])
+    .unwrap();
+
+    let goose_metrics = GooseAttack::initialize_with_config(configuration)
+        .unwrap()
+        .register_taskset(taskset!("LoadTest").register_task(task!(get_index)))
+        .execute()
+        .await
+        .unwrap();
+
+    let _ = std::fs::remove_file(socket_path);
+
+    let host_header = host_header.lock().unwrap().clone();
+    (requests.load(Ordering::SeqCst), host_header, goose_metrics)
+}
+
+#[tokio::test]
+#[serial]
+/// Load test a server listening on a Unix domain socket.
+async fn test_unix_socket() {
+    let socket_path = "goose-test-unix-socket.sock";
+    let socket = std::env::current_dir().unwrap().join(socket_path);
+    let host = format!("unix://{}", socket.display());
+
+    let (requests, host_header, goose_metrics) =
+        run_unix_socket_load_test(socket_path, &host).await;
+
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+
+    // Confirm that Goose and the server saw the same number of page loads.
+    assert!(requests > 0);
+    assert!(index_metrics.success_count == requests);
+    assert!(index_metrics.fail_count == 0);
+
+    // Requests are made with the default pseudo-hostname.
+    assert_eq!(host_header, "localhost");
+}
+
+#[tokio::test]
+#[serial]
+/// Load test a server listening on a Unix domain socket with a custom pseudo-hostname.
+async fn test_unix_socket_pseudo_hostname() {
+    let socket_path = "goose-test-unix-socket-pseudo-hostname.sock";
+    let socket = std::env::current_dir().unwrap().join(socket_path);
+    let host = format!("unix://api.internal{}", socket.display());
+
+    let (requests, host_header, goose_metrics) =
+        run_unix_socket_load_test(socket_path, &host).await;
+
+    let index_metrics = goose_metrics
+        .requests
+        .get(&format!("GET {}", INDEX_PATH))
+        .unwrap();
+
+    assert!(requests > 0);
+    assert!(index_metrics.success_count == requests);
+
+    // Requests are made with the configured pseudo-hostname.
+    assert_eq!(host_header, "api.internal");
+}
+
+#[tokio::test]
+#[serial]
+/// Load testing a Unix domain socket that doesn't exist returns an error.
+async fn test_unix_socket_missing() {
+    let configuration = GooseConfiguration::parse_args_default(&[
+        "--host",
+        "unix:///goose-socket-does-not-exist.sock",
+        "--users",
+        "1",
+        "--run-time",
+        "1",
+        "--no-telnet",
+        "--no-websocket",
+    ])
+    .unwrap();
+
+    let result = GooseAttack::initialize_with_config(configuration)
+        .unwrap()
+        .register_taskset(taskset!("LoadTest").register_task(task!(get_index)))
+        .execute()
+        .await;
+
+    assert!(result.is_err());
+}
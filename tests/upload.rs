@@ -0,0 +1,141 @@
+use httpmock::{Method::POST, Mock, MockServer};
+use serial_test::serial;
+use std::path::PathBuf;
+
+mod common;
+
+use goose::metrics::GooseMetrics;
+use goose::prelude::*;
+use goose::upload::GooseUpload;
+
+// Paths used in load tests performed during these tests.
+const FILE_PATH: &str = "/file";
+const GENERATED_PATH: &str = "/generated";
+
+// Indexes to the above paths.
+const FILE_KEY: usize = 0;
+const GENERATED_KEY: usize = 1;
+
+// The size of each uploaded body, and of the chunks it's streamed in.
+const BODY_SIZE: usize = 10_000;
+const CHUNK_SIZE: usize = 1_000;
+
+// The upload rate in bytes per second, so each upload takes about half a second.
+const RATE: u64 = 20_000;
+
+// The file uploaded by these tests.
+fn upload_file() -> PathBuf {
+    std::env::temp_dir().join("goose-upload-test.txt")
+}
+
+// Upload a streamed body to a path.
+async fn post_upload(user: &mut GooseUser, path: &str, upload: GooseUpload) -> GooseTaskResult {
+    let request_builder = user
+        .get_request_builder(&GooseMethod::Post, path)?
+        .body(upload);
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Post)
+        .path(path)
+        .set_request_builder(request_builder)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn post_file(user: &mut GooseUser) -> GooseTaskResult {
+    let upload = GooseUpload::file(upload_file()).set_chunk_size(CHUNK_SIZE);
+    post_upload(user, FILE_PATH, upload).await
+}
+
+// Test task.
+pub async fn post_generated(user: &mut GooseUser) -> GooseTaskResult {
+    let upload = GooseUpload::generator(BODY_SIZE as u64, |chunk| chunk.fill(b'y'))
+        .set_chunk_size(CHUNK_SIZE)
+        .set_rate(RATE);
+    post_upload(user, GENERATED_PATH, upload).await
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up FILE_PATH, store in vector at FILE_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(FILE_PATH)
+                .body("x".repeat(BODY_SIZE));
+            then.status(200);
+        }),
+        // Next set up GENERATED_PATH, store in vector at GENERATED_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(GENERATED_PATH)
+                .body("y".repeat(BODY_SIZE));
+            then.status(200);
+        }),
+    ]
+}
+
+// Run a short load test of a single task.
+async fn run_load_test(server: &MockServer, task: GooseTask) -> GooseMetrics {
+    let configuration = common::build_configuration(server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Upload").register_task(task),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// A body is streamed from a file.
+async fn test_upload_file() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+    std::fs::write(upload_file(), "x".repeat(BODY_SIZE)).unwrap();
+
+    let goose_metrics = run_load_test(&server, task!(post_file)).await;
+    std::fs::remove_file(upload_file()).unwrap();
+
+    let request = goose_metrics.requests.get("POST /file").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[FILE_KEY].assert_hits(request.success_count);
+}
+
+#[tokio::test]
+#[serial]
+/// A generated body is streamed no faster than the configured rate.
+async fn test_upload_generated() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_load_test(&server, task!(post_generated)).await;
+
+    let request = goose_metrics.requests.get("POST /generated").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[GENERATED_KEY].assert_hits(request.success_count);
+
+    // The last chunk is due after all but one chunk was sent.
+    let expected = (BODY_SIZE - CHUNK_SIZE) as u64 * 1_000 / RATE;
+    assert!(request.raw_data.minimum_time as u64 >= expected);
+}
+
+#[tokio::test]
+#[serial]
+/// A missing file fails the request.
+async fn test_upload_missing_file() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+    let _ = std::fs::remove_file(upload_file());
+
+    let goose_metrics = run_load_test(&server, task!(post_file)).await;
+
+    let request = goose_metrics.requests.get("POST /file").unwrap();
+    assert!(request.success_count == 0);
+    assert!(request.fail_count > 0);
+    mock_endpoints[FILE_KEY].assert_hits(0);
+}
@@ -0,0 +1,122 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+use goose::validate;
+
+// Paths used in load tests performed during these tests.
+const VALID_PATH: &str = "/valid";
+const INVALID_PATH: &str = "/invalid";
+
+// Indexes to the above paths.
+const VALID_KEY: usize = 0;
+const INVALID_KEY: usize = 1;
+
+// The body returned by all endpoints, and its SHA-1 checksum.
+const BODY: &str = "The quick brown fox jumps over the lazy dog";
+const BODY_SHA1: &str = "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12";
+
+// Test task, validating the correct checksum.
+pub async fn get_valid(user: &mut GooseUser) -> GooseTaskResult {
+    let goose = user.get(VALID_PATH).await?;
+    user.validate_in_background(goose, validate::sha1(BODY_SHA1))
+        .await?;
+    Ok(())
+}
+
+// Test task, validating the checksum of an empty body.
+pub async fn get_invalid(user: &mut GooseUser) -> GooseTaskResult {
+    let goose = user.get(INVALID_PATH).await?;
+    user.validate_in_background(
+        goose,
+        validate::sha1("da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+    )
+    .await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up VALID_PATH, store in vector at VALID_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(VALID_PATH);
+            then.status(200).body(BODY);
+        }),
+        // Next set up INVALID_PATH, store in vector at INVALID_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INVALID_PATH);
+            then.status(200).body(BODY);
+        }),
+    ]
+}
+
+// Run a load test validating response bodies with the custom options.
+async fn run_validation_load_test(server: &MockServer, custom: Vec<&str>) -> GooseMetrics {
+    let mut options = vec!["--no-reset-metrics"];
+    options.extend(custom);
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Validate")
+            .register_task(task!(get_valid))
+            .register_task(task!(get_invalid)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+// Requests with valid bodies succeed, requests with invalid bodies fail.
+fn validate_metrics(goose_metrics: &GooseMetrics, mock_endpoints: &[Mock]) {
+    let valid = goose_metrics
+        .requests
+        .get(&format!("GET {}", VALID_PATH))
+        .unwrap();
+    assert!(valid.success_count > 0);
+    assert!(valid.fail_count == 0);
+    mock_endpoints[VALID_KEY].assert_hits(valid.success_count);
+
+    let invalid = goose_metrics
+        .requests
+        .get(&format!("GET {}", INVALID_PATH))
+        .unwrap();
+    assert!(invalid.success_count == 0);
+    assert!(invalid.fail_count > 0);
+    mock_endpoints[INVALID_KEY].assert_hits(invalid.fail_count);
+
+    // Each failed validation is recorded as an error.
+    let errors: usize = goose_metrics
+        .errors
+        .values()
+        .filter(|error| error.error.starts_with("validation failed"))
+        .map(|error| error.occurrences)
+        .sum();
+    assert!(errors == invalid.fail_count);
+}
+
+#[tokio::test]
+#[serial]
+/// Response bodies are validated by the default pool of background validators.
+async fn test_validate_in_background() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_validation_load_test(&server, vec![]).await;
+
+    validate_metrics(&goose_metrics, &mock_endpoints);
+}
+
+#[tokio::test]
+#[serial]
+/// Response bodies are validated by a single background validator.
+async fn test_validate_in_background_one_worker() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let goose_metrics = run_validation_load_test(&server, vec!["--validation-workers", "1"]).await;
+
+    validate_metrics(&goose_metrics, &mock_endpoints);
+}
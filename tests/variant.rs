@@ -0,0 +1,115 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the mock endpoints.
+const VARIANT_KEY: usize = 0;
+const BASELINE_KEY: usize = 1;
+
+// The header identifying variant traffic.
+const VARIANT_HEADER: &str = "x-variant: canary";
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Set up the index on the variant server, only matching requests with the variant
+// header, and on the baseline server. Both can be the same server.
+fn setup_mock_server_endpoints<'a>(
+    server: &'a MockServer,
+    variant_server: &'a MockServer,
+) -> Vec<Mock<'a>> {
+    vec![
+        // First set up the variant, store in vector at VARIANT_KEY.
+        variant_server.mock(|when, then| {
+            when.method(GET)
+                .path(INDEX_PATH)
+                .header("x-variant", "canary");
+            then.status(200);
+        }),
+        // Next set up the baseline, store in vector at BASELINE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+// Run a load test splitting traffic between the baseline and a variant.
+async fn run_load_test(server: &MockServer, variant_options: Vec<&str>) -> GooseMetrics {
+    let mut options = vec![
+        "--users",
+        USERS,
+        "--hatch-rate",
+        USERS,
+        "--no-reset-metrics",
+        "--variant-header",
+        VARIANT_HEADER,
+    ];
+    options.extend_from_slice(&variant_options);
+    let configuration = common::build_configuration(server, options);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Variant").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await
+}
+
+#[tokio::test]
+#[serial]
+/// A percentage of requests is sent to the variant host with the variant header, and
+/// reported separately from requests sent to the baseline host.
+async fn test_variant_host() {
+    let server = MockServer::start();
+    let variant_server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server, &variant_server);
+
+    let variant_url = variant_server.base_url();
+    let goose_metrics = run_load_test(
+        &server,
+        vec!["--variant-host", &variant_url, "--variant-percent", "50"],
+    )
+    .await;
+
+    let baseline_metrics = goose_metrics.requests.get("GET /").unwrap();
+    let variant_metrics = goose_metrics.requests.get("GET / [variant]").unwrap();
+
+    // Both hosts were load tested, and every request went to the expected host.
+    assert!(baseline_metrics.success_count > 0);
+    assert!(variant_metrics.success_count > 0);
+    assert!(baseline_metrics.fail_count == 0);
+    assert!(variant_metrics.fail_count == 0);
+    mock_endpoints[BASELINE_KEY].assert_hits(baseline_metrics.success_count);
+    mock_endpoints[VARIANT_KEY].assert_hits(variant_metrics.success_count);
+}
+
+#[tokio::test]
+#[serial]
+/// Without a variant host, variant requests are sent to the same host with the variant
+/// header.
+async fn test_variant_header() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server, &server);
+
+    let goose_metrics = run_load_test(&server, vec!["--variant-percent", "100"]).await;
+
+    // All requests were sent to the variant.
+    assert!(!goose_metrics.requests.contains_key("GET /"));
+    let variant_metrics = goose_metrics.requests.get("GET / [variant]").unwrap();
+    assert!(variant_metrics.success_count > 0);
+    assert!(variant_metrics.fail_count == 0);
+    mock_endpoints[VARIANT_KEY].assert_hits(variant_metrics.success_count);
+}
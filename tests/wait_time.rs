@@ -0,0 +1,88 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+mod common;
+
+use goose::prelude::*;
+use goose::wait::GooseWaitTime;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+const ABOUT_PATH: &str = "/about.html";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const ABOUT_KEY: usize = 1;
+
+// Load test configuration.
+const RUN_TIME: &str = "2";
+
+// How many times the wait time provider was asked how long to wait after the about task.
+static ABOUT_WAITS: AtomicUsize = AtomicUsize::new(0);
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn get_about(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(ABOUT_PATH).await?;
+    Ok(())
+}
+
+// Waits longer than the load test runs after the about task, and not at all otherwise.
+struct AboutWaitTime;
+impl GooseWaitTime for AboutWaitTime {
+    fn wait_time(&self, _user: &GooseUser, task: &GooseTask, _elapsed: Duration) -> Duration {
+        if task.name == "about" {
+            ABOUT_WAITS.fetch_add(1, Ordering::SeqCst);
+            Duration::from_secs(10)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up ABOUT_PATH, store in vector at ABOUT_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(ABOUT_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+/// Users pause after each task for as long as the task set's wait time provider returns.
+async fn test_wait_time_provider() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+    let configuration =
+        common::build_configuration(&server, vec!["--users", "1", "--run-time", RUN_TIME]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("LoadTest")
+            .set_wait_time_provider(AboutWaitTime)
+            .register_task(task!(get_index).set_sequence(1).set_weight(5).unwrap())
+            .register_task(task!(get_about).set_sequence(2).set_name("about")),
+        None,
+        None,
+    );
+    common::run_load_test(goose_attack, None).await;
+
+    // The user didn't wait after loading the index, but waited for longer than the load
+    // test ran after loading the about page.
+    mock_endpoints[INDEX_KEY].assert_hits(5);
+    mock_endpoints[ABOUT_KEY].assert_hits(1);
+    assert!(ABOUT_WAITS.load(Ordering::SeqCst) == 1);
+}
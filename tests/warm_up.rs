@@ -0,0 +1,88 @@
+use httpmock::{
+    Method::{GET, HEAD},
+    Mock, MockServer,
+};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const INDEX_PATH: &str = "/";
+
+// Indexes to the above paths.
+const INDEX_KEY: usize = 0;
+const WARM_UP_KEY: usize = 1;
+
+// Load test configuration.
+const USERS: usize = 2;
+const WARM_UP: usize = 3;
+
+// Test task.
+pub async fn get_index(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.get(INDEX_PATH).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up INDEX_PATH, store in vector at INDEX_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(INDEX_PATH);
+            then.status(200);
+        }),
+        // Next set up the warm-up requests, store in vector at WARM_UP_KEY.
+        server.mock(|when, then| {
+            when.method(HEAD).path(INDEX_PATH);
+            then.status(200);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Each user resolves the host and opens the configured number of connections before
+/// running any task, without including the warm-up requests in the metrics.
+async fn test_warm_up() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    // Connect by hostname, so the host is resolved.
+    let host = format!("http://localhost:{}", server.port());
+    let users = USERS.to_string();
+    let warm_up = WARM_UP.to_string();
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--host",
+            &host,
+            "--users",
+            &users,
+            "--hatch-rate",
+            &users,
+            "--resolve-hosts",
+            "--warm-up",
+            &warm_up,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Index").register_task(task!(get_index)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Each user made the warm-up requests.
+    mock_endpoints[WARM_UP_KEY].assert_hits(USERS * WARM_UP);
+
+    // Requests to the resolved host succeeded, and only task requests are tracked.
+    let request = goose_metrics.requests.get("GET /").unwrap();
+    assert!(request.success_count > 0);
+    assert!(request.fail_count == 0);
+    mock_endpoints[INDEX_KEY].assert_hits(request.success_count);
+    assert_eq!(goose_metrics.requests.len(), 1);
+}
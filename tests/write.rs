@@ -0,0 +1,144 @@
+use httpmock::{
+    Method::{GET, POST, PUT},
+    Mock, MockServer,
+};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+
+// Paths used in load tests performed during these tests.
+const PROFILE_PATH: &str = "/profile";
+const ORDERS_PATH: &str = "/orders";
+
+// Indexes to the above paths.
+const GET_PROFILE_KEY: usize = 0;
+const PUT_PROFILE_KEY: usize = 1;
+const PUT_STALE_PROFILE_KEY: usize = 2;
+const POST_ORDERS_KEY: usize = 3;
+
+// The current ETag of the profile.
+const ETAG: &str = "\"v2\"";
+
+// Test task, updating the profile with the ETag it was read with.
+pub async fn update_profile(user: &mut GooseUser) -> GooseTaskResult {
+    let goose = user.get(PROFILE_PATH).await?;
+    user.remember_etag("profile", &goose);
+    let etag = user.etag("profile").unwrap_or_default().to_string();
+
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Put)
+        .path(PROFILE_PATH)
+        .name("update profile")
+        .if_match(&etag)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task, updating the profile with a stale ETag.
+pub async fn update_stale_profile(user: &mut GooseUser) -> GooseTaskResult {
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Put)
+        .path(PROFILE_PATH)
+        .name("update stale profile")
+        .if_match("\"v1\"")
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// Test task, placing an order that conflicts with an existing one.
+pub async fn place_order(user: &mut GooseUser) -> GooseTaskResult {
+    let key = user.idempotency_key();
+    let goose_request = GooseRequest::builder()
+        .method(GooseMethod::Post)
+        .path(ORDERS_PATH)
+        .idempotency_key(&key)
+        .build();
+    let _goose = user.request(goose_request).await?;
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up GET PROFILE_PATH, store in vector at GET_PROFILE_KEY.
+        server.mock(|when, then| {
+            when.method(GET).path(PROFILE_PATH);
+            then.status(200).header("etag", ETAG);
+        }),
+        // Next set up PUT PROFILE_PATH with the current ETag, store in vector at
+        // PUT_PROFILE_KEY.
+        server.mock(|when, then| {
+            when.method(PUT).path(PROFILE_PATH).header("if-match", ETAG);
+            then.status(200);
+        }),
+        // Next set up PUT PROFILE_PATH with a stale ETag, store in vector at
+        // PUT_STALE_PROFILE_KEY.
+        server.mock(|when, then| {
+            when.method(PUT)
+                .path(PROFILE_PATH)
+                .header("if-match", "\"v1\"");
+            then.status(412);
+        }),
+        // Last set up POST ORDERS_PATH, store in vector at POST_ORDERS_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(ORDERS_PATH)
+                .header_exists("idempotency-key");
+            then.status(409);
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// Writes are sent with idempotency keys and If-Match preconditions, and 409 and 412
+/// responses are counted separately.
+async fn test_write_conflicts() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(&server, vec!["--no-reset-metrics"]);
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("Writes")
+            .register_task(task!(update_profile))
+            .register_task(task!(update_stale_profile))
+            .register_task(task!(place_order)),
+        None,
+        None,
+    );
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Updates with the current ETag succeeded.
+    let update = goose_metrics.requests.get("PUT update profile").unwrap();
+    assert!(update.success_count > 0);
+    assert!(update.fail_count == 0);
+    assert!(update.conflicts == 0);
+    assert!(update.precondition_failures == 0);
+    mock_endpoints[GET_PROFILE_KEY].assert_hits(update.success_count);
+    mock_endpoints[PUT_PROFILE_KEY].assert_hits(update.success_count);
+
+    // Updates with a stale ETag failed their precondition.
+    let stale = goose_metrics
+        .requests
+        .get("PUT update stale profile")
+        .unwrap();
+    assert!(stale.fail_count > 0);
+    assert!(stale.precondition_failures == stale.fail_count);
+    assert!(stale.conflicts == 0);
+    mock_endpoints[PUT_STALE_PROFILE_KEY].assert_hits(stale.fail_count);
+
+    // Orders were sent with an idempotency key, and conflicted.
+    let order = goose_metrics.requests.get("POST /orders").unwrap();
+    assert!(order.fail_count > 0);
+    assert!(order.conflicts == order.fail_count);
+    assert!(order.precondition_failures == 0);
+    mock_endpoints[POST_ORDERS_KEY].assert_hits(order.fail_count);
+
+    // Write conflicts are displayed in their own table.
+    assert!(goose_metrics.to_string().contains("WRITE CONFLICTS"));
+}
@@ -0,0 +1,116 @@
+use httpmock::{Method::POST, Mock, MockServer};
+use serial_test::serial;
+
+mod common;
+
+use goose::prelude::*;
+use goose::xml;
+
+// Paths used in load tests performed during these tests.
+const XML_PATH: &str = "/xml";
+const SOAP_PATH: &str = "/soap";
+
+// Indexes to the above paths.
+const XML_KEY: usize = 0;
+const SOAP_KEY: usize = 1;
+
+// The SOAP action invoked by the load test.
+const SOAP_ACTION: &str = "http://example.com/stock/GetPrice";
+
+// Load test configuration.
+const USERS: &str = "2";
+
+// Test task.
+pub async fn post_xml(user: &mut GooseUser) -> GooseTaskResult {
+    let _goose = user.post_xml(XML_PATH, "<ping/>").await?;
+    Ok(())
+}
+
+// Test task.
+pub async fn post_soap(user: &mut GooseUser) -> GooseTaskResult {
+    let body = xml::soap_envelope("<GetPrice><Item>Goose</Item></GetPrice>");
+    let mut goose = user.post_soap(SOAP_PATH, SOAP_ACTION, body).await?;
+
+    // Validate the response, failing the request if the price is missing.
+    if let Ok(response) = goose.response {
+        let text = response.text().await.unwrap_or_default();
+        if xml::xpath_first(&text, "/Envelope/Body/GetPriceResponse/Price")?
+            != Some("34.5".to_string())
+        {
+            return user.set_failure("price missing", &mut goose.request, None, Some(&text));
+        }
+    }
+    Ok(())
+}
+
+// All tests in this file run against common endpoints.
+fn setup_mock_server_endpoints(server: &MockServer) -> Vec<Mock<'_>> {
+    vec![
+        // First set up XML_PATH, store in vector at XML_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(XML_PATH)
+                .header("content-type", "application/xml; charset=utf-8")
+                .body("<ping/>");
+            then.status(200);
+        }),
+        // Next set up SOAP_PATH, store in vector at SOAP_KEY.
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(SOAP_PATH)
+                .header("content-type", "text/xml; charset=utf-8")
+                .header("soapaction", format!("\"{}\"", SOAP_ACTION))
+                .body_contains("<soap:Body><GetPrice><Item>Goose</Item></GetPrice></soap:Body>");
+            then.status(200).body(xml::soap_envelope(
+                "<m:GetPriceResponse xmlns:m=\"http://example.com/stock\"><m:Price>34.5</m:Price></m:GetPriceResponse>",
+            ));
+        }),
+    ]
+}
+
+#[tokio::test]
+#[serial]
+/// XML and SOAP requests are sent with the correct headers, and SOAP responses can be
+/// validated with XPath.
+async fn test_xml_and_soap_requests() {
+    let server = MockServer::start();
+    let mock_endpoints = setup_mock_server_endpoints(&server);
+
+    let configuration = common::build_configuration(
+        &server,
+        vec![
+            "--users",
+            USERS,
+            "--hatch-rate",
+            USERS,
+            "--no-reset-metrics",
+        ],
+    );
+    let goose_attack = common::build_load_test(
+        configuration,
+        &taskset!("XML")
+            .register_task(task!(post_xml))
+            .register_task(task!(post_soap)),
+        None,
+        None,
+    );
+
+    let goose_metrics = common::run_load_test(goose_attack, None).await;
+
+    // Both endpoints were called with the expected headers and bodies.
+    assert!(mock_endpoints[XML_KEY].hits() > 0);
+    assert!(mock_endpoints[SOAP_KEY].hits() > 0);
+
+    // All requests succeeded, including the XPath validation of the SOAP response.
+    let xml_metrics = goose_metrics
+        .requests
+        .get(&format!("POST {}", XML_PATH))
+        .unwrap();
+    let soap_metrics = goose_metrics
+        .requests
+        .get(&format!("POST {}", SOAP_PATH))
+        .unwrap();
+    assert!(xml_metrics.fail_count == 0);
+    assert!(soap_metrics.fail_count == 0);
+    mock_endpoints[SOAP_KEY].assert_hits(soap_metrics.success_count);
+}